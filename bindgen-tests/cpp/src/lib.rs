@@ -0,0 +1,164 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+extern crate uniffi_bindgen_tests;
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env::{
+            self,
+            consts::{DLL_PREFIX, DLL_SUFFIX},
+        },
+        fs,
+        process::Command,
+        sync::OnceLock,
+    };
+
+    use camino::{Utf8Path, Utf8PathBuf};
+
+    use uniffi::TargetLanguage;
+    use uniffi_bindgen::test_util;
+
+    #[test]
+    fn test_simple_fns() {
+        run_tests(test_dir(), "tests/simple_fns.cpp");
+    }
+
+    #[test]
+    fn test_primitive_types() {
+        run_tests(test_dir(), "tests/primitive_types.cpp");
+    }
+
+    #[test]
+    fn test_records() {
+        run_tests(test_dir(), "tests/records.cpp");
+    }
+
+    #[test]
+    fn test_collections() {
+        run_tests(test_dir(), "tests/collections.cpp");
+    }
+
+    #[test]
+    fn test_options() {
+        run_tests(test_dir(), "tests/options.cpp");
+    }
+
+    #[test]
+    fn test_enums() {
+        run_tests(test_dir(), "tests/enums.cpp");
+    }
+
+    #[test]
+    fn test_interfaces() {
+        run_tests(test_dir(), "tests/interfaces.cpp");
+    }
+
+    #[test]
+    fn test_errors() {
+        run_tests(test_dir(), "tests/errors.cpp");
+    }
+
+    #[test]
+    fn test_defaults() {
+        run_tests(test_dir(), "tests/defaults.cpp");
+    }
+
+    #[test]
+    fn test_references() {
+        run_tests(test_dir(), "tests/references.cpp");
+    }
+
+    #[test]
+    fn test_custom_types() {
+        run_tests(test_dir(), "tests/custom_types.cpp");
+    }
+
+    #[test]
+    fn test_external_types() {
+        run_tests(test_dir(), "tests/external_types.cpp");
+    }
+
+    #[test]
+    fn test_renames() {
+        run_tests(test_dir(), "tests/renames.cpp");
+    }
+
+    #[test]
+    fn test_bytes() {
+        run_tests(test_dir(), "tests/bytes.cpp");
+    }
+
+    #[test]
+    fn test_recursive_types() {
+        run_tests(test_dir(), "tests/recursive_types.cpp");
+    }
+
+    #[test]
+    fn test_time() {
+        run_tests(test_dir(), "tests/time.cpp");
+    }
+
+    #[test]
+    fn test_rust_traits() {
+        run_tests(test_dir(), "tests/rust_traits.cpp");
+    }
+
+    fn test_dir() -> &'static Utf8Path {
+        static TEST_TEMPDIR: OnceLock<Utf8PathBuf> = OnceLock::new();
+        TEST_TEMPDIR.get_or_init(|| {
+            let temp_dir = test_util::setup_test_dir("cpp");
+            let test_package = temp_dir.join("tests/test_package");
+            fs::create_dir_all(&test_package).unwrap();
+            test_util::build_library(
+                &test_package,
+                "uniffi-bindgen-tests-cpp",
+                test_util::LibraryOptions::default(),
+            );
+            test_util::copy_test_sources(&temp_dir, "tests/*.cpp");
+            test_util::generate_sources(&test_package, TargetLanguage::Cpp);
+
+            temp_dir
+        })
+    }
+
+    fn run_tests(tempdir: &Utf8Path, script_filename: &str) {
+        let test_package = tempdir.join("tests/test_package");
+        let script_path = tempdir.join(script_filename);
+        let exe_path = script_path.with_extension("");
+        // Link directly against the cdylib, then run the compiled test.  The compiler is taken
+        // from the `CXX` environment variable, defaulting to `c++`.
+        let library =
+            test_package.join(format!("{DLL_PREFIX}uniffi_bindgen_tests_cpp{DLL_SUFFIX}"));
+        let compiler = env::var("CXX").unwrap_or_else(|_| "c++".to_string());
+        let mut command = Command::new(&compiler);
+        command
+            .current_dir(tempdir)
+            .args(["-std=c++17", "-Wall", "-Werror", "-I"])
+            .arg(&test_package)
+            .arg(&script_path)
+            .arg(&library)
+            .arg(format!("-Wl,-rpath,{test_package}"))
+            .arg("-o")
+            .arg(&exe_path);
+        check_output(&mut command);
+        check_output(Command::new(&exe_path).current_dir(tempdir));
+    }
+
+    fn check_output(command: &mut Command) {
+        let output = command
+            .output()
+            .unwrap_or_else(|e| panic!("Failed to spawn {command:?}: {e}"));
+        // The `output()` call above sets up pipes to print stdout/stderr.  This allows it to be
+        // integrated with the Rust test harness's output handling.
+        print!("{}", String::from_utf8_lossy(&output.stdout));
+        if !output.status.success() {
+            println!("---------------------------------------- STDERR ----------------------------------------");
+            print!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("----------------------------------------------------------------------------------------");
+            panic!("running {command:?} failed");
+        }
+    }
+}
@@ -0,0 +1,32 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // bytes
+    std::vector<uint8_t> data{'t', 'e', 's', 't', '-', 'd', 'a', 't', 'a'};
+    assert(lib::roundtrip_bytes(data) == data);
+
+    // Zero-copy &[u8]
+    assert(lib::sum_bytes({}) == 0);
+    assert(lib::sum_bytes({1, 2, 3}) == 6);
+    assert(!lib::first_byte({}).has_value());
+    assert(lib::first_byte({42}) == 42);
+
+    // Zero-copy &mut [u8].  Rust writes land in place.
+    std::vector<uint8_t> buf(4);
+    lib::fill_bytes(buf);
+    assert((buf == std::vector<uint8_t>{0, 1, 2, 3}));
+
+    std::vector<uint8_t> inc{1, 2, 3};
+    lib::increment_bytes(inc);
+    assert((inc == std::vector<uint8_t>{2, 3, 4}));
+
+    // Empty buffer is handled without crashing.
+    std::vector<uint8_t> empty;
+    lib::fill_bytes(empty);
+    assert(empty.empty());
+    return 0;
+}
@@ -0,0 +1,65 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // vecs
+    assert((lib::roundtrip_vec_i8({1, 2, 3}) == std::vector<int8_t>{1, 2, 3}));
+    assert((lib::roundtrip_vec_u16({1, 2, 3}) == std::vector<uint16_t>{1, 2, 3}));
+    assert((lib::roundtrip_vec_i16({1, 2, 3}) == std::vector<int16_t>{1, 2, 3}));
+    assert((lib::roundtrip_vec_u32({1, 2, 3}) == std::vector<uint32_t>{1, 2, 3}));
+    assert((lib::roundtrip_vec_i32({1, 2, 3}) == std::vector<int32_t>{1, 2, 3}));
+    assert((lib::roundtrip_vec_u64({1, 2, 3}) == std::vector<uint64_t>{1, 2, 3}));
+    assert((lib::roundtrip_vec_i64({1, 2, 3}) == std::vector<int64_t>{1, 2, 3}));
+    assert((lib::roundtrip_vec_f32({1.5, 2.5}) == std::vector<float>{1.5, 2.5}));
+    assert((lib::roundtrip_vec_f64({1.5, 2.5}) == std::vector<double>{1.5, 2.5}));
+    assert((lib::roundtrip_vec_string({"test-string"}) == std::vector<std::string>{"test-string"}));
+    assert((lib::roundtrip_vec_bool({true, false}) == std::vector<bool>{true, false}));
+    auto recs = lib::roundtrip_vec_rec({lib::CollectionsRec{67}});
+    assert(recs.size() == 1 && recs[0].a == 67);
+
+    // hash maps
+    std::unordered_map<std::string, uint32_t> map{{"a", 1}, {"b", 2}};
+    assert(lib::roundtrip_hash_map(map) == map);
+    std::unordered_set<std::string> set{"a", "b", "c"};
+    assert(lib::roundtrip_hash_set(set) == set);
+    std::unordered_map<uint32_t, uint32_t> u32_map{{1, 2}, {2, 4}};
+    assert(lib::roundtrip_hash_map_u32_key(u32_map) == u32_map);
+
+    // record with collections
+    lib::RecWithCollections rec{
+        lib::EnumWithCollections::A{std::nullopt},
+        std::nullopt,
+        {true, false},
+        {{"a", 10}, {"b", 20}},
+    };
+    auto rec2 = lib::roundtrip_rec_with_collections(rec);
+    auto a = std::get_if<lib::EnumWithCollections::A>(&rec2.a.variant);
+    assert(a && !a->v1.has_value());
+    assert(!rec2.b.has_value());
+    assert(rec2.c == rec.c);
+    assert(rec2.d == rec.d);
+
+    // complex
+    std::vector<std::unordered_set<std::string>> sets{{"a", "b"}};
+    assert(lib::roundtrip_vec_hash_set(sets) == sets);
+    assert(!lib::roundtrip_vec_hash_set(std::nullopt).has_value());
+
+    std::vector<std::unordered_map<std::string, lib::CollectionsComplexRec>> complex{{
+        {"a", lib::CollectionsComplexRec{10, "Test", lib::CollectionsEnum::A{100}}},
+        {"b", lib::CollectionsComplexRec{20, "Test2", lib::CollectionsEnum::B{1.0, true}}},
+    }};
+    auto complex2 = lib::roundtrip_complex_collection_type(complex);
+    assert(complex2.has_value() && complex2->size() == 1);
+    const auto& item_a = complex2->at(0).at("a");
+    assert(item_a.a == 10 && item_a.b == "Test");
+    assert(std::get<lib::CollectionsEnum::A>(item_a.c.variant).v1 == 100);
+    const auto& item_b = complex2->at(0).at("b");
+    assert(item_b.a == 20 && item_b.b == "Test2");
+    auto variant_b = std::get<lib::CollectionsEnum::B>(item_b.c.variant);
+    assert(variant_b.a == 1.0 && variant_b.b);
+    assert(!lib::roundtrip_complex_collection_type(std::nullopt).has_value());
+    return 0;
+}
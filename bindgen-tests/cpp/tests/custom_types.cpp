@@ -0,0 +1,16 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // custom type
+    lib::CustomType1 custom1 = 100;
+    assert(lib::roundtrip_custom_type1(custom1) == 100);
+
+    // custom type with config
+    lib::CustomType2 custom2{{"value", 200}};
+    assert(lib::roundtrip_custom_type2(custom2) == custom2);
+    return 0;
+}
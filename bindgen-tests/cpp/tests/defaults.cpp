@@ -0,0 +1,25 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // records
+    lib::RecWithDefault rec{};
+    assert(rec.n == 42);
+    assert(rec.v.empty());
+
+    // enums
+    lib::EnumWithDefault::OtherVariant variant{};
+    assert(variant.a == "default");
+
+    // arguments
+    assert(lib::func_with_default() == "DEFAULT");
+    assert(lib::func_with_default("NON-DEFAULT") == "NON-DEFAULT");
+
+    lib::InterfaceWithDefaults i;
+    assert(i.method_with_default() == "DEFAULT");
+    assert(i.method_with_default("NON-DEFAULT") == "NON-DEFAULT");
+    return 0;
+}
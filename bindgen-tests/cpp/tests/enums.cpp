@@ -0,0 +1,42 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // flat enums
+    assert(lib::roundtrip_enum_no_data(lib::EnumNoData::B) == lib::EnumNoData::B);
+    try {
+        lib::roundtrip_enum_no_data(static_cast<lib::EnumNoData>(5));
+        assert(false);
+    } catch (const std::invalid_argument&) {
+    }
+    assert(static_cast<uint8_t>(lib::ExplicitValuedEnum::Thirteenth) == 13);
+    assert(static_cast<uint8_t>(lib::GappedEnum::Two) == 11);
+
+    // simple enums
+    auto a = lib::roundtrip_enum_with_data(lib::EnumWithData::A{10, 20});
+    auto a_variant = std::get<lib::EnumWithData::A>(a.variant);
+    assert(a_variant.value == 10 && a_variant.value2 == 20);
+    auto b = lib::roundtrip_enum_with_data(lib::EnumWithData::B{"Ten", 10});
+    auto b_variant = std::get<lib::EnumWithData::B>(b.variant);
+    assert(b_variant.v1 == "Ten" && b_variant.v2 == 10);
+    auto c = lib::roundtrip_enum_with_data(lib::EnumWithData::C{});
+    assert(std::holds_alternative<lib::EnumWithData::C>(c.variant));
+
+    // complex enums
+    auto complex_a = lib::roundtrip_complex_enum(lib::ComplexEnum::A{lib::EnumNoData::C});
+    assert(std::get<lib::ComplexEnum::A>(complex_a.variant).value == lib::EnumNoData::C);
+    auto complex_b = lib::roundtrip_complex_enum(lib::ComplexEnum::B{lib::EnumWithData::A{20, 40}});
+    auto inner = std::get<lib::EnumWithData::A>(std::get<lib::ComplexEnum::B>(complex_b.variant).value.variant);
+    assert(inner.value == 20 && inner.value2 == 40);
+    auto complex_c = lib::roundtrip_complex_enum(lib::ComplexEnum::C{lib::SimpleRec{30}});
+    assert(std::get<lib::ComplexEnum::C>(complex_c.variant).value.a == 30);
+
+    // methods
+    lib::EnumWithData method_enum = lib::EnumWithData::A{1, 0};
+    auto roundtripped = std::get<lib::EnumWithData::A>(method_enum.roundtrip().variant);
+    assert(roundtripped.value == 1 && roundtripped.value2 == 0);
+    return 0;
+}
@@ -0,0 +1,70 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // rust error returns
+    try {
+        lib::func_with_error(0);
+        assert(false);
+    } catch (const lib::TestError::Failure1&) {
+    }
+    try {
+        lib::func_with_error(1);
+        assert(false);
+    } catch (const lib::TestError::Failure2& e) {
+        assert(e.data == "DATA");
+    }
+    try {
+        lib::func_with_error(50);
+        assert(false);
+    } catch (const lib::TestError::Failure3& e) {
+        assert(e.v1 == 50);
+    }
+    try {
+        lib::func_with_flat_error(0);
+        assert(false);
+    } catch (const lib::TestFlatError::IoError& e) {
+        // Flat errors carry the message from Rust
+        assert(std::string(e.what()) == "Failure1");
+    }
+    // Errors can also be caught using the base class or `std::exception`
+    try {
+        lib::func_with_error(0);
+        assert(false);
+    } catch (const lib::TestError& e) {
+        assert(std::string(e.what()) == "Failure1");
+    }
+    try {
+        lib::func_with_error(0);
+        assert(false);
+    } catch (const std::exception&) {
+    }
+
+    // These shouldn't throw
+    lib::func_with_error(200);
+    lib::func_with_flat_error(1);
+
+    // rust error no data returns
+    try {
+        lib::func_with_error_no_data(0);
+        assert(false);
+    } catch (const lib::TestErrorNoData::Failure1&) {
+    }
+    try {
+        lib::func_with_error_no_data(1);
+        assert(false);
+    } catch (const lib::TestErrorNoData::Failure2&) {
+    }
+    try {
+        lib::func_with_error_no_data(2);
+        assert(false);
+    } catch (const lib::TestErrorNoData::Failure3&) {
+    }
+
+    // This shouldn't throw
+    lib::func_with_error_no_data(200);
+    return 0;
+}
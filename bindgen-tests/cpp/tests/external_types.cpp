@@ -0,0 +1,14 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+namespace ext = uniffi_bindgen_tests_external_types_source;
+
+int main() {
+    assert(lib::roundtrip_ext_record(ext::ExternalRec{42}).a == 42);
+    assert(lib::roundtrip_ext_enum(ext::ExternalEnum::Two) == ext::ExternalEnum::Two);
+    assert(lib::roundtrip_ext_interface(ext::ExternalInterface(20)).get_value() == 20);
+    assert(lib::roundtrip_ext_custom_type(100) == 100);
+    return 0;
+}
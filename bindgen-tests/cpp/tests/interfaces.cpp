@@ -0,0 +1,52 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // interfaces
+    lib::TestInterface iface(20);
+    assert(iface.get_value() == 20);
+    assert(lib::clone_interface(iface).get_value() == 20);
+
+    // optional interface
+    assert(!lib::roundtrip_optional_interface(std::nullopt).has_value());
+    assert(lib::roundtrip_optional_interface(lib::TestInterface(20))->get_value() == 20);
+
+    // secondary constructor
+    assert(lib::TestInterface::secondary_constructor(20).get_value() == 40);
+
+    // records with interface fields
+    auto swapped = lib::swap_test_interfaces(lib::TwoTestInterfaces{lib::TestInterface(1), lib::TestInterface(2)});
+    assert(swapped.first.get_value() == 2);
+    assert(swapped.second.get_value() == 1);
+
+    // enums with interfaces
+    lib::TestInterfaceEnum en = lib::TestInterfaceEnum::One{lib::TestInterface(1)};
+    assert(std::get<lib::TestInterfaceEnum::One>(en.variant).i.get_value() == 1);
+
+    // interface refcounts
+    {
+        auto clone = lib::clone_interface(iface);
+        assert(iface.ref_count() == 2);
+        // Copies clone the handle
+        auto copy = clone;
+        assert(iface.ref_count() == 3);
+    }
+    // The destructors release the references
+    assert(iface.ref_count() == 1);
+
+    // Moved-from objects can't be used
+    auto moved = std::move(iface);
+    assert(moved.get_value() == 20);
+    try {
+        iface.get_value();
+        assert(false);
+    } catch (const std::logic_error&) {
+    }
+
+    // argument name mapping
+    assert(moved.method_with_multi_word_arg("test") == "test");
+    return 0;
+}
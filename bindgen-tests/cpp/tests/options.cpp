@@ -0,0 +1,35 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    assert(lib::roundtrip_option_u8(67) == 67);
+    assert(!lib::roundtrip_option_u8(std::nullopt).has_value());
+    assert(lib::roundtrip_option_i8(67) == 67);
+    assert(!lib::roundtrip_option_i8(std::nullopt).has_value());
+    assert(lib::roundtrip_option_u16(67) == 67);
+    assert(!lib::roundtrip_option_u16(std::nullopt).has_value());
+    assert(lib::roundtrip_option_i16(67) == 67);
+    assert(!lib::roundtrip_option_i16(std::nullopt).has_value());
+    assert(lib::roundtrip_option_u32(67) == 67u);
+    assert(!lib::roundtrip_option_u32(std::nullopt).has_value());
+    assert(lib::roundtrip_option_i32(67) == 67);
+    assert(!lib::roundtrip_option_i32(std::nullopt).has_value());
+    assert(lib::roundtrip_option_u64(67) == 67u);
+    assert(!lib::roundtrip_option_u64(std::nullopt).has_value());
+    assert(lib::roundtrip_option_i64(67) == 67);
+    assert(!lib::roundtrip_option_i64(std::nullopt).has_value());
+    assert(lib::roundtrip_option_f32(1.5f) == 1.5f);
+    assert(!lib::roundtrip_option_f32(std::nullopt).has_value());
+    assert(lib::roundtrip_option_f64(1.5) == 1.5);
+    assert(!lib::roundtrip_option_f64(std::nullopt).has_value());
+    assert(lib::roundtrip_option_string("test-string") == "test-string");
+    assert(!lib::roundtrip_option_string(std::nullopt).has_value());
+    assert(lib::roundtrip_option_bool(true) == true);
+    assert(!lib::roundtrip_option_bool(std::nullopt).has_value());
+    assert(lib::roundtrip_option_rec(lib::OptionsRec{67})->a == 67);
+    assert(!lib::roundtrip_option_rec(std::nullopt).has_value());
+    return 0;
+}
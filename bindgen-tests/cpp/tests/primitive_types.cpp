@@ -0,0 +1,61 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // input
+    lib::input_u8(42);
+    lib::input_i8(-42);
+    lib::input_u16(42);
+    lib::input_i16(-42);
+    lib::input_u32(42);
+    lib::input_i32(-42);
+    lib::input_u64(42);
+    lib::input_i64(-42);
+    lib::input_f32(0.5);
+    lib::input_f64(-3.5);
+    lib::input_bool(true);
+    lib::input_string("ABC");
+
+    // output
+    assert(lib::output_u8() == 1);
+    assert(lib::output_i8() == 1);
+    assert(lib::output_u16() == 1);
+    assert(lib::output_i16() == 1);
+    assert(lib::output_u32() == 1);
+    assert(lib::output_i32() == 1);
+    assert(lib::output_u64() == 1);
+    assert(lib::output_i64() == 1);
+    assert(lib::output_f32() == 1.0);
+    assert(lib::output_f64() == 1.0);
+    assert(lib::output_bool() == true);
+    assert(lib::output_string() == "test-string");
+    assert(lib::output_u8_max() == UINT8_MAX);
+    assert(lib::output_u16_max() == UINT16_MAX);
+
+    // roundtrip
+    assert(lib::roundtrip_u8(42) == 42);
+    assert(lib::roundtrip_i8(-42) == -42);
+    assert(lib::roundtrip_u16(42) == 42);
+    assert(lib::roundtrip_i16(-42) == -42);
+    assert(lib::roundtrip_u32(42) == 42);
+    assert(lib::roundtrip_i32(-42) == -42);
+    assert(lib::roundtrip_u64(UINT64_MAX) == UINT64_MAX);
+    assert(lib::roundtrip_i64(INT64_MIN) == INT64_MIN);
+    assert(lib::roundtrip_f32(0.5) == 0.5);
+    assert(lib::roundtrip_f64(-3.5) == -3.5);
+    assert(lib::roundtrip_bool(true) == true);
+    assert(lib::roundtrip_string("ABC") == "ABC");
+    // Strings can contain nul bytes and non-ASCII characters
+    assert(lib::roundtrip_string(std::string("a\0b", 3)) == std::string("a\0b", 3));
+    assert(lib::roundtrip_string("é中") == "é中");
+    // The BOM is preserved
+    assert(lib::string_with_bom() == "\xEF\xBB\xBFhello");
+    // Test calling a function with lots of args
+    // This function will sum up all the numbers, then negate the value since we passed in `true`
+    assert(lib::sum_with_many_types(1, -2, 3, -4, 5, -6, 7, -8, 9.5, -10.5, true) == 5);
+    assert(lib::func_with_multi_word_arg(5) == 5);
+    return 0;
+}
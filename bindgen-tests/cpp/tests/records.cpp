@@ -0,0 +1,34 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // simple record
+    assert(lib::roundtrip_simple_rec(lib::SimpleRec{42}).a == 42);
+
+    // unit record
+    lib::UnitRec unit_rec{};
+    (void)unit_rec;
+
+    // complex record
+    lib::ComplexRec rec{0, -1, 2, -3, 4, -5, 6, -7, 8.5, 9.5, "test", lib::SimpleRec{42}};
+    auto result = lib::roundtrip_complex_rec(rec);
+    assert(result.field_u8 == 0);
+    assert(result.field_i8 == -1);
+    assert(result.field_u16 == 2);
+    assert(result.field_i16 == -3);
+    assert(result.field_u32 == 4);
+    assert(result.field_i32 == -5);
+    assert(result.field_u64 == 6);
+    assert(result.field_i64 == -7);
+    assert(result.field_f32 == 8.5);
+    assert(result.field_f64 == 9.5);
+    assert(result.field_string == "test");
+    assert(result.field_rec.a == 42);
+
+    // methods
+    assert(lib::SimpleRec{42}.roundtrip().a == 42);
+    return 0;
+}
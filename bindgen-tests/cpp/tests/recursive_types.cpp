@@ -0,0 +1,86 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+template <typename T, typename V>
+std::shared_ptr<T> boxed(V variant) {
+    return std::make_shared<T>(std::move(variant));
+}
+
+lib::Expr if_zero(int32_t n) {
+    return lib::Expr::If{
+        boxed<lib::BoolExpr>(lib::BoolExpr::IsZero{boxed<lib::Expr>(lib::Expr::Lit{n})}),
+        boxed<lib::Expr>(lib::Expr::Lit{1}),
+        boxed<lib::Expr>(lib::Expr::Lit{2}),
+    };
+}
+
+lib::RoseTree rose_branch(int32_t value, std::vector<lib::RoseTree> children) {
+    return lib::RoseTree::Branch{lib::RoseData{value, std::move(children)}};
+}
+
+int main() {
+    // tree (self-recursive)
+    assert(lib::sum_tree(lib::Tree::Leaf{5}) == 5);
+    assert(lib::sum_tree(lib::Tree::Node{
+        boxed<lib::Tree>(lib::Tree::Leaf{3}),
+        boxed<lib::Tree>(lib::Tree::Leaf{4}),
+    }) == 7);
+    lib::Tree deep = lib::Tree::Node{
+        boxed<lib::Tree>(lib::Tree::Node{
+            boxed<lib::Tree>(lib::Tree::Leaf{1}),
+            boxed<lib::Tree>(lib::Tree::Leaf{2}),
+        }),
+        boxed<lib::Tree>(lib::Tree::Leaf{10}),
+    };
+    assert(lib::sum_tree(deep) == 13);
+
+    // Boxed values can't be null
+    try {
+        lib::sum_tree(lib::Tree::Node{nullptr, nullptr});
+        assert(false);
+    } catch (const std::invalid_argument&) {
+    }
+
+    // expr / bool expr (mutually recursive)
+    assert(lib::eval_expr(lib::Expr::Lit{42}) == 42);
+    assert(lib::eval_expr(if_zero(0)) == 1);
+    assert(lib::eval_expr(if_zero(5)) == 2);
+    assert(!lib::eval_bool(lib::BoolExpr::Not{boxed<lib::BoolExpr>(lib::BoolExpr::True{})}));
+    assert(lib::eval_bool(lib::BoolExpr::Not{boxed<lib::BoolExpr>(lib::BoolExpr::False{})}));
+
+    // linked list (cycle through Optional)
+    assert(lib::list_sum(lib::LinkedList::Nil{}) == 0);
+    assert(lib::list_sum(lib::LinkedList::Cons{5, std::nullopt}) == 5);
+    lib::LinkedList list = lib::LinkedList::Cons{3, boxed<lib::LinkedList>(lib::LinkedList::Cons{4, std::nullopt})};
+    assert(lib::list_sum(list) == 7);
+
+    // trie (cycle through Map value)
+    assert(lib::trie_sum(lib::Trie::Leaf{5}) == 5);
+    assert(lib::trie_sum(lib::Trie::Branch{}) == 0);
+    assert(lib::trie_sum(lib::Trie::Branch{{
+        {"a", boxed<lib::Trie>(lib::Trie::Leaf{1})},
+        {"b", boxed<lib::Trie>(lib::Trie::Leaf{2})},
+    }}) == 3);
+    assert(lib::trie_sum(lib::Trie::Branch{{
+        {"x", boxed<lib::Trie>(lib::Trie::Branch{{{"y", boxed<lib::Trie>(lib::Trie::Leaf{7})}}})},
+    }}) == 7);
+
+    // rose tree (cycle through a record)
+    assert(lib::sum_rose_tree(lib::RoseTree::Leaf{7}) == 7);
+    assert(lib::sum_rose_tree(rose_branch(10, {})) == 10);
+    assert(lib::sum_rose_tree(rose_branch(1, {lib::RoseTree::Leaf{2}, lib::RoseTree::Leaf{3}})) == 6);
+    assert(lib::sum_rose_tree(rose_branch(1, {rose_branch(2, {lib::RoseTree::Leaf{3}}), lib::RoseTree::Leaf{4}})) == 10);
+
+    // recursive error
+    assert(lib::maybe_throw_error(false) == 42);
+    try {
+        lib::maybe_throw_error(true);
+        assert(false);
+    } catch (const lib::EvalError::Nested& e) {
+        assert(dynamic_cast<const lib::EvalError::Overflow*>(e.inner.get()) != nullptr);
+    }
+    return 0;
+}
@@ -0,0 +1,20 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // value ref
+    assert(lib::roundtrip_u8_ref(2) == 2);
+
+    // interface ref
+    lib::ReferenceTestInterface iface;
+    assert(iface.double_value(2) == 4);
+    assert(lib::call_double_value(iface, 3) == 6);
+
+    // trait interface ref
+    auto trait_interface = lib::create_reference_test_trait_interface();
+    assert(lib::call_triple_value_trait_interface(trait_interface, 10) == 30);
+    return 0;
+}
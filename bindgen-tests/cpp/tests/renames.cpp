@@ -0,0 +1,53 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // rename record
+    lib::RenamedRecord rec{42};
+    assert(rec.item == 42);
+
+    // rename enum
+    lib::RenamedEnum renamed_variant = lib::RenamedEnum::RenamedVariant{};
+    assert(std::holds_alternative<lib::RenamedEnum::RenamedVariant>(renamed_variant.variant));
+    auto return_value = lib::renamed_function(rec);
+    assert(std::get<lib::RenamedEnum::Record>(return_value.variant).v1.item == 42);
+
+    // rename object
+    auto obj = lib::RenamedObject::renamed_constructor(123);
+    assert(obj.renamed_method() == 123);
+
+    // trait method
+    auto trait_impl = lib::create_trait_impl(5);
+    assert(trait_impl.renamed_trait_method(10) == 50);
+
+    // cpp rename record
+    lib::CppRecord cpp_rec{100};
+    assert(cpp_rec.cpp_item == 100);
+
+    // cpp rename enum
+    lib::CppEnum cpp_enum = lib::CppEnum::CppRecord{cpp_rec};
+    assert(std::get<lib::CppEnum::CppRecord>(cpp_enum.variant).v1.cpp_item == 100);
+    lib::CppEnumWithFields with_fields = lib::CppEnumWithFields::CppVariantA{1};
+    assert(std::get<lib::CppEnumWithFields::CppVariantA>(with_fields.variant).cpp_int == 1);
+
+    // cpp rename functions
+    auto cpp_return_value = lib::cpp_function(cpp_rec);
+    assert(std::get<lib::CppEnum::CppRecord>(cpp_return_value.variant).v1.cpp_item == 100);
+    try {
+        lib::cpp_function(std::nullopt);
+        assert(false);
+    } catch (const lib::CppError::CppSimple&) {
+    }
+
+    // cpp rename methods
+    lib::CppObject cpp_obj(200);
+    assert(cpp_obj.cpp_method(50) == 250);
+
+    // cpp rename trait
+    auto cpp_trait_impl = lib::create_binding_trait_to_rename_impl(3);
+    assert(cpp_trait_impl.cpp_trait_method(7) == 21);
+    return 0;
+}
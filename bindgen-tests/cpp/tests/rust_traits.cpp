@@ -0,0 +1,41 @@
+#include <cassert>
+#include <sstream>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // debug
+    assert((lib::RustTraitTest{1, 2}.to_debug_string() == "debug-test-string"));
+    assert((lib::RustTraitTest2{1, 2}.to_debug_string() == "debug-test-string"));
+
+    // display
+    lib::RustTraitTest trait_test{1, 2};
+    assert(trait_test.to_string() == "display-test-string");
+    std::ostringstream stream;
+    stream << trait_test;
+    assert(stream.str() == "display-test-string");
+
+    // eq
+    // The Rust code only uses `a` for the equality
+    assert((lib::RustTraitTest{1, 2} == lib::RustTraitTest{1, 3}));
+    assert((lib::RustTraitTest{2, 2} != lib::RustTraitTest{1, 2}));
+
+    // ord
+    // The Rust code only uses `a` for the ordering
+    assert((lib::RustTraitTest{1, 2}.compare(lib::RustTraitTest{2, 3}) < 0));
+    assert((lib::RustTraitTest{1, 2}.compare(lib::RustTraitTest{1, 3}) == 0));
+    assert((lib::RustTraitTest{1, 2} < lib::RustTraitTest{2, 3}));
+    assert((lib::RustTraitTest{1, 2} <= lib::RustTraitTest{1, 3}));
+    assert((lib::RustTraitTest{3, 2} > lib::RustTraitTest{2, 3}));
+
+    // hash
+    // The Rust code only uses `a` for the hash
+    assert((lib::RustTraitTest{1, 2}.hash() == lib::RustTraitTest{1, 3}.hash()));
+    assert((lib::RustTraitTest{2, 2}.hash() != lib::RustTraitTest{1, 2}.hash()));
+    // `std::hash` is specialized, so the records can be used in unordered containers
+    std::unordered_set<lib::RustTraitTest> set{{1, 2}, {1, 3}, {2, 2}};
+    assert(set.size() == 2);
+    return 0;
+}
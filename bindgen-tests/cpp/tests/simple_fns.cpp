@@ -0,0 +1,19 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+int main() {
+    // the test here is just that we can successfully call a function across the FFI
+    lib::test_func();
+
+    // Panics are thrown as `uniffi::InternalError`
+    try {
+        lib::test_unexpected_error_func();
+        assert(false);
+    } catch (const uniffi::InternalError& e) {
+        assert(std::string(e.what()) == "test panic");
+    }
+    return 0;
+}
@@ -0,0 +1,27 @@
+#include <cassert>
+
+#include "uniffi_bindgen_tests.hpp"
+
+namespace lib = uniffi_bindgen_tests;
+
+using namespace std::chrono_literals;
+
+int main() {
+    // duration
+    assert(lib::roundtrip_duration(24h + 2s) == 24h + 2s);
+    assert(lib::roundtrip_duration(1500us) == 1500us);
+    try {
+        lib::roundtrip_duration(-1s);
+        assert(false);
+    } catch (const std::invalid_argument&) {
+    }
+
+    // systemtime
+    // 2000-01-01T00:00:00Z
+    auto time = std::chrono::system_clock::time_point(946684800s);
+    assert(lib::roundtrip_systemtime(time) == time);
+    // Times before the epoch work too
+    auto before_epoch = std::chrono::system_clock::time_point(-1500ms);
+    assert(lib::roundtrip_systemtime(before_epoch) == before_epoch);
+    return 0;
+}
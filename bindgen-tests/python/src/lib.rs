@@ -7,7 +7,10 @@ extern crate uniffi_bindgen_tests;
 #[cfg(test)]
 mod test {
     use std::{
-        env::{self},
+        env::{
+            self,
+            consts::{DLL_PREFIX, DLL_SUFFIX},
+        },
         ffi::OsString,
         fs,
         io::Write,
@@ -120,6 +123,48 @@ mod test {
         run_tests(test_dir(), "tests/rust_traits.py");
     }
 
+    #[test]
+    fn test_type_stubs() {
+        run_tests(test_dir(), "tests/type_stubs.py");
+    }
+
+    #[test]
+    fn test_package() {
+        let temp_dir = test_util::setup_test_dir("python-package");
+        test_util::build_library(
+            &temp_dir,
+            "uniffi-bindgen-tests-python",
+            test_util::LibraryOptions::default(),
+        );
+        test_util::copy_test_sources(&temp_dir, "tests/package.py");
+        uniffi::generate(uniffi::GenerateOptions {
+            languages: vec![TargetLanguage::Python],
+            source: temp_dir.join(format!(
+                "{DLL_PREFIX}uniffi_bindgen_tests_python{DLL_SUFFIX}"
+            )),
+            out_dir: temp_dir.clone(),
+            crate_filter: Some("uniffi_bindgen_tests".to_string()),
+            format: false,
+            package: true,
+            ..uniffi::GenerateOptions::default()
+        })
+        .unwrap();
+        run_tests_with_env(
+            &temp_dir,
+            "tests/package.py",
+            &[("UNIFFI_TEST_CRATE_VERSION", &bindgen_tests_version())],
+        );
+    }
+
+    /// Read the version of `uniffi-bindgen-tests`, which the package version defaults to
+    fn bindgen_tests_version() -> String {
+        let manifest =
+            fs::read_to_string(test_util::workspace_dir().join("bindgen-tests/lib/Cargo.toml"))
+                .unwrap();
+        let manifest: toml::Table = toml::from_str(&manifest).unwrap();
+        manifest["package"]["version"].as_str().unwrap().to_string()
+    }
+
     fn test_dir() -> &'static Utf8Path {
         static TEST_TEMPDIR: OnceLock<Utf8PathBuf> = OnceLock::new();
         TEST_TEMPDIR.get_or_init(|| {
@@ -141,6 +186,10 @@ mod test {
     }
 
     fn run_tests(tempdir: &Utf8Path, script_filename: &str) {
+        run_tests_with_env(tempdir, script_filename, &[])
+    }
+
+    fn run_tests_with_env(tempdir: &Utf8Path, script_filename: &str, envs: &[(&str, &str)]) {
         // Run the test script against compiled bindings
         let pythonpath = env::var_os("PYTHONPATH").unwrap_or_else(|| OsString::from(""));
         let pythonpath = env::join_paths(
@@ -151,6 +200,7 @@ mod test {
         command
             .current_dir(tempdir)
             .env("PYTHONPATH", pythonpath)
+            .envs(envs.iter().copied())
             .arg(script_filename);
         let output = command
             .output()
@@ -0,0 +1,39 @@
+import uniffi_bindgen_tests
+import os
+import platform
+import unittest
+from pathlib import Path
+
+# `uniffi_bindgen_tests` is the package written by `uniffi-bindgen generate --package`, rather than
+# the `test_package` the other tests use.
+PACKAGE_DIR = Path(uniffi_bindgen_tests.__file__).parent
+
+class TestPackage(unittest.TestCase):
+    def test_import(self):
+        # The package re-exports the main module, which loads the cdylib from the package dir
+        uniffi_bindgen_tests.test_func()
+        with self.assertRaises(uniffi_bindgen_tests.InternalError):
+            uniffi_bindgen_tests.test_unexpected_error_func()
+
+    def test_package_files(self):
+        self.assertTrue((PACKAGE_DIR / "py.typed").exists())
+        self.assertTrue((PACKAGE_DIR / "uniffi_bindgen_tests.pyi").exists())
+        # Modules for the other crates in the library are also part of the package
+        self.assertTrue((PACKAGE_DIR / "uniffi_bindgen_tests_external_types_source.py").exists())
+
+    def test_pyproject(self):
+        try:
+            import tomllib
+        except ImportError:
+            self.skipTest("tomllib requires Python 3.11")
+        pyproject = tomllib.loads((PACKAGE_DIR.parent / "pyproject.toml").read_text())
+        self.assertEqual(pyproject["project"]["name"], "uniffi_bindgen_tests")
+        # Defaults to the crate version
+        self.assertEqual(pyproject["project"]["version"], os.environ["UNIFFI_TEST_CRATE_VERSION"])
+        self.assertEqual(pyproject["tool"]["setuptools"]["packages"], ["uniffi_bindgen_tests"])
+        # The wheel is tagged for the platform that the library was built for
+        plat_name = pyproject["tool"]["distutils"]["bdist_wheel"]["plat-name"]
+        self.assertTrue(plat_name.lower().endswith(platform.machine().lower()), plat_name)
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,104 @@
+import test_package.uniffi_bindgen_tests as module
+import ast
+import inspect
+import unittest
+from pathlib import Path
+
+# CI runs `mypy --strict` and pyright on the stubs themselves.  This checks the `.pyi` stub against
+# the runtime module: everything declared in the stub should exist with the same signature and the
+# type annotations should all refer to real types.
+
+STUB = ast.parse(Path(module.__file__).with_suffix(".pyi").read_text())
+
+def stub_classes():
+    return [node for node in STUB.body if isinstance(node, ast.ClassDef)]
+
+def private_class_aliases():
+    """
+    Map the private classes that only exist in the stub to their runtime path
+
+    For example, error variants are defined as `_UniffiEnumVariantMyErrorFoo` and aliased as
+    `MyError.Foo`.
+    """
+    aliases = {}
+    for cls in stub_classes():
+        for node in cls.body:
+            if isinstance(node, ast.Assign) and isinstance(node.value, ast.Name):
+                aliases[node.value.id] = (cls.name, node.targets[0].id)
+    return aliases
+
+def runtime_class(name):
+    if name.startswith("_"):
+        (outer, inner) = private_class_aliases()[name]
+        return getattr(getattr(module, outer), inner)
+    return getattr(module, name)
+
+def stub_param_names(func):
+    args = func.args
+    names = [a.arg for a in args.posonlyargs + args.args + args.kwonlyargs]
+    return names[1:] if names[:1] in (["self"], ["cls"]) else names
+
+class TestTypeStubs(unittest.TestCase):
+    def check_annotation(self, annotation):
+        if annotation is not None:
+            # Raises if the annotation refers to something that doesn't exist
+            eval(ast.unparse(annotation), vars(module))
+
+    def check_function(self, func, runtime_func):
+        with self.subTest(func=func.name):
+            self.assertEqual(
+                isinstance(func, ast.AsyncFunctionDef),
+                inspect.iscoroutinefunction(runtime_func),
+            )
+            for arg in func.args.posonlyargs + func.args.args + func.args.kwonlyargs:
+                self.check_annotation(arg.annotation)
+            self.check_annotation(func.returns)
+            runtime_params = [
+                p for p in inspect.signature(runtime_func).parameters.values()
+                if p.name not in ("self", "cls")
+            ]
+            if any(p.kind == p.VAR_POSITIONAL for p in runtime_params):
+                # Unnamed variant fields, the stub lists them as positional-only arguments
+                return
+            self.assertEqual(stub_param_names(func), [p.name for p in runtime_params])
+
+    def test_all(self):
+        [all_names] = [
+            node.value for node in STUB.body
+            if isinstance(node, ast.Assign) and node.targets[0].id == "__all__"
+        ]
+        self.assertEqual(ast.literal_eval(all_names), module.__all__)
+
+    def test_functions(self):
+        for node in STUB.body:
+            if isinstance(node, (ast.FunctionDef, ast.AsyncFunctionDef)):
+                self.check_function(node, getattr(module, node.name))
+
+    def test_constants(self):
+        for node in STUB.body:
+            if isinstance(node, ast.AnnAssign):
+                self.assertTrue(hasattr(module, node.target.id), node.target.id)
+                self.check_annotation(node.annotation)
+
+    def test_classes(self):
+        for cls in stub_classes():
+            runtime_cls = runtime_class(cls.name)
+            self.assertTrue(inspect.isclass(runtime_cls), cls.name)
+            for node in cls.body:
+                if isinstance(node, (ast.FunctionDef, ast.AsyncFunctionDef)):
+                    runtime_member = inspect.getattr_static(runtime_cls, node.name)
+                    if isinstance(runtime_member, property):
+                        self.check_annotation(node.returns)
+                        continue
+                    if isinstance(runtime_member, (staticmethod, classmethod)):
+                        runtime_member = runtime_member.__func__
+                    if runtime_member is object.__init__:
+                        continue
+                    self.check_function(node, runtime_member)
+                elif isinstance(node, ast.Assign):
+                    self.assertTrue(hasattr(runtime_cls, node.targets[0].id))
+                elif isinstance(node, ast.AnnAssign):
+                    self.check_annotation(node.annotation)
+
+if __name__ == '__main__':
+    unittest.main()
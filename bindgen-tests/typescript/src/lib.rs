@@ -0,0 +1,166 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+extern crate uniffi_bindgen_tests;
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env::{self},
+        ffi::OsString,
+        fs,
+        process::Command,
+        sync::OnceLock,
+    };
+
+    use camino::{Utf8Path, Utf8PathBuf};
+
+    use uniffi::TargetLanguage;
+    use uniffi_bindgen::test_util;
+
+    #[test]
+    fn test_simple_fns() {
+        run_tests(test_dir(), "tests/simple_fns.js");
+    }
+
+    #[test]
+    fn test_primitive_types() {
+        run_tests(test_dir(), "tests/primitive_types.js");
+    }
+
+    #[test]
+    fn test_records() {
+        run_tests(test_dir(), "tests/records.js");
+    }
+
+    #[test]
+    fn test_collections() {
+        run_tests(test_dir(), "tests/collections.js");
+    }
+
+    #[test]
+    fn test_options() {
+        run_tests(test_dir(), "tests/options.js");
+    }
+
+    #[test]
+    fn test_enums() {
+        run_tests(test_dir(), "tests/enums.js");
+    }
+
+    #[test]
+    fn test_interfaces() {
+        run_tests(test_dir(), "tests/interfaces.js");
+    }
+
+    #[test]
+    fn test_errors() {
+        run_tests(test_dir(), "tests/errors.js");
+    }
+
+    #[test]
+    fn test_callback_interfaces() {
+        run_tests(test_dir(), "tests/callback_interfaces.js");
+    }
+
+    #[test]
+    fn test_futures() {
+        run_tests(test_dir(), "tests/futures.js");
+    }
+
+    #[test]
+    fn test_trait_interfaces() {
+        run_tests(test_dir(), "tests/trait_interfaces.js");
+    }
+
+    #[test]
+    fn test_defaults() {
+        run_tests(test_dir(), "tests/defaults.js");
+    }
+
+    #[test]
+    fn test_references() {
+        run_tests(test_dir(), "tests/references.js");
+    }
+
+    #[test]
+    fn test_custom_types() {
+        run_tests(test_dir(), "tests/custom_types.js");
+    }
+
+    #[test]
+    fn test_external_types() {
+        run_tests(test_dir(), "tests/external_types.js");
+    }
+
+    #[test]
+    fn test_renames() {
+        run_tests(test_dir(), "tests/renames.js");
+    }
+
+    #[test]
+    fn test_bytes() {
+        run_tests(test_dir(), "tests/bytes.js");
+    }
+
+    #[test]
+    fn test_recursive_types() {
+        run_tests(test_dir(), "tests/recursive_types.js");
+    }
+
+    #[test]
+    fn test_time() {
+        run_tests(test_dir(), "tests/time.js");
+    }
+
+    #[test]
+    fn test_rust_traits() {
+        run_tests(test_dir(), "tests/rust_traits.js");
+    }
+
+    fn test_dir() -> &'static Utf8Path {
+        static TEST_TEMPDIR: OnceLock<Utf8PathBuf> = OnceLock::new();
+        TEST_TEMPDIR.get_or_init(|| {
+            let temp_dir = test_util::setup_test_dir("typescript");
+            let test_package = temp_dir.join("tests/test_package");
+            fs::create_dir_all(&test_package).unwrap();
+            test_util::build_library(
+                &test_package,
+                "uniffi-bindgen-tests-typescript",
+                test_util::LibraryOptions::default(),
+            );
+            test_util::copy_test_sources(&temp_dir, "tests/*.js");
+            test_util::generate_sources(&test_package, TargetLanguage::TypeScript);
+
+            temp_dir
+        })
+    }
+
+    fn run_tests(tempdir: &Utf8Path, script_filename: &str) {
+        // Run the test script against compiled bindings.  `koffi` needs to be installed somewhere
+        // on `NODE_PATH`.
+        let node_path = env::var_os("NODE_PATH").unwrap_or_else(|| OsString::from(""));
+        let node_path = env::join_paths(
+            env::split_paths(&node_path).chain(vec![tempdir.to_path_buf().into_std_path_buf()]),
+        )
+        .unwrap();
+        let mut command = Command::new("node");
+        command
+            .current_dir(tempdir)
+            .env("NODE_PATH", node_path)
+            .arg(script_filename);
+        let output = command
+            .output()
+            .expect("Failed to spawn `node` when running test script");
+        // The `output()` call above sets up pipes to print stdout/stderr.  This allows it to be
+        // integrated with the Rust test harness's output handling.
+        print!("{}", String::from_utf8_lossy(&output.stdout));
+        if !output.status.success() {
+            println!("---------------------------------------- STDERR ----------------------------------------");
+            print!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("----------------------------------------------------------------------------------------");
+            panic!("running `node` to run test script failed ({:?})", command);
+        }
+    }
+}
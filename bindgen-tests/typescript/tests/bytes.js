@@ -0,0 +1,38 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const {
+    fillBytes,
+    firstByte,
+    incrementBytes,
+    roundtripBytes,
+    sumBytes,
+} = require("./test_package/uniffi_bindgen_tests");
+
+test("bytes", () => {
+    const data = new TextEncoder().encode("test-data");
+    assert.deepStrictEqual(roundtripBytes(data), data);
+});
+
+test("zero-copy bytes", () => {
+    // Zero-copy &[u8]
+    assert.strictEqual(sumBytes(new Uint8Array()), 0);
+    assert.strictEqual(sumBytes(new Uint8Array([1, 2, 3])), 6);
+    assert.strictEqual(firstByte(new Uint8Array()), undefined);
+    assert.strictEqual(firstByte(new Uint8Array([42])), 42);
+});
+
+test("zero-copy bytes mut", () => {
+    // Zero-copy &mut [u8].  Rust writes land in place.
+    const buf = new Uint8Array(4);
+    fillBytes(buf);
+    assert.deepStrictEqual(buf, new Uint8Array([0, 1, 2, 3]));
+
+    const inc = new Uint8Array([1, 2, 3]);
+    incrementBytes(inc);
+    assert.deepStrictEqual(inc, new Uint8Array([2, 3, 4]));
+
+    // Empty buffer is handled without crashing.
+    const empty = new Uint8Array();
+    fillBytes(empty);
+    assert.deepStrictEqual(empty, new Uint8Array());
+});
@@ -0,0 +1,55 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const lib = require("./test_package/uniffi_bindgen_tests");
+const { TestError } = lib;
+
+class CallbackImpl {
+    constructor(value) {
+        this.value = value;
+    }
+
+    noop() {}
+
+    getValue() {
+        return this.value;
+    }
+
+    setValue(value) {
+        this.value = value;
+    }
+
+    throwIfEqual(numbers) {
+        if (numbers.a === numbers.b) {
+            throw new TestError.Failure1();
+        }
+        return numbers;
+    }
+
+    echo(s) {
+        return s;
+    }
+}
+
+test("callback interfaces", () => {
+    // Construct a callback interface to pass to rust
+    const cbi = new CallbackImpl(42);
+    // Test calling callback interface methods, which we can only do indirectly.
+    // Each of these Rust functions inputs a callback interface, calls a method on it, then returns the result.
+    lib.invokeTestCallbackInterfaceNoop(cbi);
+    assert.strictEqual(lib.invokeTestCallbackInterfaceGetValue(cbi), 42);
+    lib.invokeTestCallbackInterfaceSetValue(cbi, 43);
+    assert.strictEqual(lib.invokeTestCallbackInterfaceGetValue(cbi), 43);
+    assert.strictEqual(lib.invokeTestCallbackInterfaceEcho(cbi, "test-string"), "test-string");
+    assert.throws(
+        () => lib.invokeTestCallbackInterfaceThrowIfEqual(cbi, { a: 10, b: 10 }),
+        TestError.Failure1,
+    );
+    assert.deepStrictEqual(
+        lib.invokeTestCallbackInterfaceThrowIfEqual(cbi, { a: 10, b: 11 }),
+        { a: 10, b: 11 },
+    );
+
+    // The previous calls created a bunch of callback interface handles.  Make sure they've been
+    // cleaned up.
+    assert.strictEqual(lib.FfiConverterTypeTestCallbackInterface._handleMap.size, 0);
+});
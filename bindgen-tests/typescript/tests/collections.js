@@ -0,0 +1,49 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const lib = require("./test_package/uniffi_bindgen_tests");
+const { CollectionsComplexRec, CollectionsEnum, EnumWithCollections, RecWithCollections } = lib;
+
+test("vecs", () => {
+    assert.deepStrictEqual(lib.roundtripVecI8([1, 2, 3]), [1, 2, 3]);
+    assert.deepStrictEqual(lib.roundtripVecU16([1, 2, 3]), [1, 2, 3]);
+    assert.deepStrictEqual(lib.roundtripVecI16([1, 2, 3]), [1, 2, 3]);
+    assert.deepStrictEqual(lib.roundtripVecU32([1, 2, 3]), [1, 2, 3]);
+    assert.deepStrictEqual(lib.roundtripVecI32([1, 2, 3]), [1, 2, 3]);
+    assert.deepStrictEqual(lib.roundtripVecU64([1n, 2n, 3n]), [1n, 2n, 3n]);
+    assert.deepStrictEqual(lib.roundtripVecI64([1n, 2n, 3n]), [1n, 2n, 3n]);
+    assert.deepStrictEqual(lib.roundtripVecString(["test-string"]), ["test-string"]);
+    assert.deepStrictEqual(lib.roundtripVecBool([true, false]), [true, false]);
+    assert.deepStrictEqual(lib.roundtripVecRec([{ a: 67 }]), [{ a: 67 }]);
+});
+
+test("hash maps", () => {
+    const map = new Map([["a", 1], ["b", 2]]);
+    assert.deepStrictEqual(lib.roundtripHashMap(map), map);
+    const set = new Set(["a", "b", "c"]);
+    assert.deepStrictEqual(lib.roundtripHashSet(set), set);
+    const u32Map = new Map([[1, 2], [2, 4]]);
+    assert.deepStrictEqual(lib.roundtripHashMapU32Key(u32Map), u32Map);
+});
+
+test("record with collections", () => {
+    const rec = RecWithCollections.create({
+        a: EnumWithCollections.A(undefined),
+        b: undefined,
+        c: [true, false],
+        d: new Map([["a", 10], ["b", 20]]),
+    });
+    assert.deepStrictEqual(lib.roundtripRecWithCollections(rec), rec);
+});
+
+test("complex", () => {
+    assert.deepStrictEqual(lib.roundtripVecHashSet([new Set(["a", "b"])]), [new Set(["a", "b"])]);
+    assert.strictEqual(lib.roundtripVecHashSet(undefined), undefined);
+    const complex = [
+        new Map([
+            ["a", CollectionsComplexRec.create({ a: 10, b: "Test", c: CollectionsEnum.A(100n) })],
+            ["b", CollectionsComplexRec.create({ a: 20, b: "Test2", c: CollectionsEnum.B({ a: 1.0, b: true }) })],
+        ]),
+    ];
+    assert.deepStrictEqual(lib.roundtripComplexCollectionType(complex), complex);
+    assert.strictEqual(lib.roundtripComplexCollectionType(undefined), undefined);
+});
@@ -0,0 +1,11 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const { roundtripCustomType1, roundtripCustomType2 } = require("./test_package/uniffi_bindgen_tests");
+
+test("custom type", () => {
+    assert.strictEqual(roundtripCustomType1(100n), 100n);
+});
+
+test("custom type with config", () => {
+    assert.deepStrictEqual(roundtripCustomType2({ value: 200n }), { value: 200n });
+});
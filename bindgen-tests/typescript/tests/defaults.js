@@ -0,0 +1,28 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const {
+    EnumWithDefault,
+    InterfaceWithDefaults,
+    RecWithDefault,
+    funcWithDefault,
+} = require("./test_package/uniffi_bindgen_tests");
+
+test("records", () => {
+    const r = RecWithDefault.create({});
+    assert.strictEqual(r.n, 42);
+    assert.deepStrictEqual(r.v, []);
+});
+
+test("enums", () => {
+    const e = EnumWithDefault.OtherVariant({});
+    assert.strictEqual(e.a, "default");
+});
+
+test("arguments", () => {
+    assert.strictEqual(funcWithDefault(), "DEFAULT");
+    assert.strictEqual(funcWithDefault("NON-DEFAULT"), "NON-DEFAULT");
+
+    const i = new InterfaceWithDefaults();
+    assert.strictEqual(i.methodWithDefault(), "DEFAULT");
+    assert.strictEqual(i.methodWithDefault("NON-DEFAULT"), "NON-DEFAULT");
+});
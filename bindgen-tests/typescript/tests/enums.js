@@ -0,0 +1,56 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const {
+    ComplexEnum,
+    EnumNoData,
+    EnumWithData,
+    roundtripComplexEnum,
+    roundtripEnumNoData,
+    roundtripEnumWithData,
+} = require("./test_package/uniffi_bindgen_tests");
+
+test("flat enums", () => {
+    assert.strictEqual(EnumNoData.A, "A");
+    assert.strictEqual(roundtripEnumNoData(EnumNoData.B), EnumNoData.B);
+    assert.throws(() => roundtripEnumNoData("D"), TypeError);
+});
+
+test("simple enums", () => {
+    assert.deepStrictEqual(
+        roundtripEnumWithData(EnumWithData.A({ value: 10, value2: 20 })),
+        EnumWithData.A({ value: 10, value2: 20 }),
+    );
+    assert.deepStrictEqual(
+        roundtripEnumWithData(EnumWithData.B("Ten", 10)),
+        EnumWithData.B("Ten", 10),
+    );
+    assert.deepStrictEqual(roundtripEnumWithData(EnumWithData.C), EnumWithData.C);
+});
+
+test("enum fields", () => {
+    const a = EnumWithData.A({ value: 10, value2: 20 });
+    assert.strictEqual(a.tag, "A");
+    assert.strictEqual(a.value, 10);
+    assert.strictEqual(a.value2, 20);
+
+    const b = EnumWithData.B("Ten", 10);
+    assert.strictEqual(b.tag, "B");
+    assert.strictEqual(b.v1, "Ten");
+    assert.strictEqual(b.v2, 10);
+});
+
+test("complex enums", () => {
+    const values = [
+        ComplexEnum.A({ value: EnumNoData.C }),
+        ComplexEnum.B({ value: EnumWithData.A({ value: 20, value2: 40 }) }),
+        ComplexEnum.C({ value: { a: 30 } }),
+    ];
+    for (const value of values) {
+        assert.deepStrictEqual(roundtripComplexEnum(value), value);
+    }
+});
+
+test("methods", () => {
+    const a = EnumWithData.A({ value: 1, value2: 0 });
+    assert.deepStrictEqual(EnumWithData.roundtrip(a), a);
+});
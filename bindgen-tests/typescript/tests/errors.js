@@ -0,0 +1,33 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const {
+    TestError,
+    TestErrorNoData,
+    TestFlatError,
+    funcWithError,
+    funcWithErrorNoData,
+    funcWithFlatError,
+} = require("./test_package/uniffi_bindgen_tests");
+
+test("rust error returns", () => {
+    assert.throws(() => funcWithError(0), TestError.Failure1);
+    assert.throws(() => funcWithError(1), (e) => e instanceof TestError.Failure2 && e.data === "DATA");
+    assert.throws(() => funcWithError(50), (e) => e instanceof TestError.Failure3 && e.v1 === 50);
+    assert.throws(() => funcWithFlatError(0), TestFlatError.IoError);
+    // Errors are also instances of the base class and `Error`
+    assert.throws(() => funcWithError(0), TestError);
+    assert.throws(() => funcWithError(0), Error);
+
+    // These shouldn't throw
+    funcWithError(200);
+    funcWithFlatError(1);
+});
+
+test("rust error no data returns", () => {
+    assert.throws(() => funcWithErrorNoData(0), TestErrorNoData.Failure1);
+    assert.throws(() => funcWithErrorNoData(1), TestErrorNoData.Failure2);
+    assert.throws(() => funcWithErrorNoData(2), TestErrorNoData.Failure3);
+
+    // This shouldn't throw
+    funcWithErrorNoData(200);
+});
@@ -0,0 +1,20 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const {
+    roundtripExtCustomType,
+    roundtripExtEnum,
+    roundtripExtInterface,
+    roundtripExtRecord,
+} = require("./test_package/uniffi_bindgen_tests");
+const {
+    ExternalEnum,
+    ExternalInterface,
+    ExternalRec,
+} = require("./test_package/uniffi_bindgen_tests_external_types_source");
+
+test("external types", () => {
+    assert.deepStrictEqual(roundtripExtRecord(ExternalRec.create({ a: 42 })), { a: 42 });
+    assert.strictEqual(roundtripExtEnum(ExternalEnum.Two), ExternalEnum.Two);
+    assert.strictEqual(roundtripExtInterface(new ExternalInterface(20)).getValue(), 20);
+    assert.strictEqual(roundtripExtCustomType(100n), 100n);
+});
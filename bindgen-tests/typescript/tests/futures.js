@@ -0,0 +1,79 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const lib = require("./test_package/uniffi_bindgen_tests");
+const { AsyncInterface, AsyncRecord, TestError } = lib;
+
+test("simple calls", async () => {
+    assert.strictEqual(await lib.asyncRoundtripU8(42), 42);
+    assert.strictEqual(await lib.asyncRoundtripI8(-42), -42);
+    assert.strictEqual(await lib.asyncRoundtripU16(42), 42);
+    assert.strictEqual(await lib.asyncRoundtripI16(-42), -42);
+    assert.strictEqual(await lib.asyncRoundtripU32(42), 42);
+    assert.strictEqual(await lib.asyncRoundtripI32(-42), -42);
+    assert.strictEqual(await lib.asyncRoundtripU64(42n), 42n);
+    assert.strictEqual(await lib.asyncRoundtripI64(-42n), -42n);
+    assert.strictEqual(await lib.asyncRoundtripF32(0.5), 0.5);
+    assert.strictEqual(await lib.asyncRoundtripF64(-0.5), -0.5);
+    assert.strictEqual(await lib.asyncRoundtripString("hi"), "hi");
+    assert.deepStrictEqual(await lib.asyncRoundtripVec([42]), [42]);
+    const map = new Map([["hello", "world"]]);
+    assert.deepStrictEqual(await lib.asyncRoundtripMap(map), map);
+});
+
+test("errors", async () => {
+    await assert.rejects(lib.asyncThrowError(), TestError.Failure1);
+});
+
+test("methods", async () => {
+    const obj = new AsyncInterface("Alice");
+    assert.strictEqual(await obj.name(), "Alice");
+
+    const obj2 = await lib.asyncRoundtripObj(obj);
+    assert.strictEqual(await obj2.name(), "Alice");
+
+    const rec = AsyncRecord.create({ name: "Bob" });
+    assert.strictEqual(await AsyncRecord.getName(rec), "Bob");
+});
+
+test("async callback interfaces", async () => {
+    class AsyncCallbackImpl {
+        constructor(value) {
+            this.value = value;
+        }
+
+        async noop() {}
+
+        async getValue() {
+            return this.value;
+        }
+
+        async setValue(value) {
+            this.value = value;
+        }
+
+        async throwIfEqual(numbers) {
+            if (numbers.a === numbers.b) {
+                throw new TestError.Failure1();
+            }
+            return numbers;
+        }
+    }
+
+    const cbi = new AsyncCallbackImpl(42);
+    await lib.invokeTestAsyncCallbackInterfaceNoop(cbi);
+    assert.strictEqual(await lib.invokeTestAsyncCallbackInterfaceGetValue(cbi), 42);
+    await lib.invokeTestAsyncCallbackInterfaceSetValue(cbi, 43);
+    assert.strictEqual(await lib.invokeTestAsyncCallbackInterfaceGetValue(cbi), 43);
+    await assert.rejects(
+        lib.invokeTestAsyncCallbackInterfaceThrowIfEqual(cbi, { a: 10, b: 10 }),
+        TestError.Failure1,
+    );
+    assert.deepStrictEqual(
+        await lib.invokeTestAsyncCallbackInterfaceThrowIfEqual(cbi, { a: 10, b: 11 }),
+        { a: 10, b: 11 },
+    );
+
+    // The previous calls created a bunch of callback interface handles.  Make sure they've been
+    // cleaned up.
+    assert.strictEqual(lib.FfiConverterTypeTestAsyncCallbackInterface._handleMap.size, 0);
+});
@@ -0,0 +1,52 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const {
+    TestInterface,
+    TestInterfaceEnum,
+    TwoTestInterfaces,
+    cloneInterface,
+    roundtripOptionalInterface,
+    swapTestInterfaces,
+} = require("./test_package/uniffi_bindgen_tests");
+
+test("interfaces", () => {
+    const iface = new TestInterface(20);
+    assert.strictEqual(iface.getValue(), 20);
+    assert.strictEqual(cloneInterface(iface).getValue(), 20);
+});
+
+test("optional interface", () => {
+    assert.strictEqual(roundtripOptionalInterface(undefined), undefined);
+    assert.strictEqual(roundtripOptionalInterface(new TestInterface(20)).getValue(), 20);
+});
+
+test("secondary constructor", () => {
+    assert.strictEqual(TestInterface.secondaryConstructor(20).getValue(), 40);
+});
+
+test("records with interface fields", () => {
+    const two = TwoTestInterfaces.create({ first: new TestInterface(1), second: new TestInterface(2) });
+    const swapped = swapTestInterfaces(two);
+    assert.strictEqual(swapped.first.getValue(), 2);
+    assert.strictEqual(swapped.second.getValue(), 1);
+});
+
+test("enums with interfaces", () => {
+    const en = TestInterfaceEnum.One({ i: new TestInterface(1) });
+    assert.strictEqual(en.i.getValue(), 1);
+});
+
+test("interface refcounts", () => {
+    const iface = new TestInterface(20);
+    const clone = cloneInterface(iface);
+    assert.strictEqual(iface.refCount(), 2);
+    // Destroying the clone releases its reference right away
+    clone.uniffiDestroy();
+    assert.strictEqual(iface.refCount(), 1);
+    assert.throws(() => clone.getValue());
+});
+
+test("argument name mapping", () => {
+    const iface = new TestInterface(20);
+    iface.methodWithMultiWordArg("test");
+});
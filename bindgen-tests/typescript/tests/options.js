@@ -0,0 +1,28 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const lib = require("./test_package/uniffi_bindgen_tests");
+
+test("options", () => {
+    assert.strictEqual(lib.roundtripOptionU8(67), 67);
+    assert.strictEqual(lib.roundtripOptionU8(undefined), undefined);
+    assert.strictEqual(lib.roundtripOptionI8(67), 67);
+    assert.strictEqual(lib.roundtripOptionI8(undefined), undefined);
+    assert.strictEqual(lib.roundtripOptionU16(67), 67);
+    assert.strictEqual(lib.roundtripOptionU16(undefined), undefined);
+    assert.strictEqual(lib.roundtripOptionI16(67), 67);
+    assert.strictEqual(lib.roundtripOptionI16(undefined), undefined);
+    assert.strictEqual(lib.roundtripOptionU32(67), 67);
+    assert.strictEqual(lib.roundtripOptionU32(undefined), undefined);
+    assert.strictEqual(lib.roundtripOptionI32(67), 67);
+    assert.strictEqual(lib.roundtripOptionI32(undefined), undefined);
+    assert.strictEqual(lib.roundtripOptionU64(67n), 67n);
+    assert.strictEqual(lib.roundtripOptionU64(undefined), undefined);
+    assert.strictEqual(lib.roundtripOptionI64(67n), 67n);
+    assert.strictEqual(lib.roundtripOptionI64(undefined), undefined);
+    assert.strictEqual(lib.roundtripOptionString("test-string"), "test-string");
+    assert.strictEqual(lib.roundtripOptionString(undefined), undefined);
+    assert.strictEqual(lib.roundtripOptionBool(true), true);
+    assert.strictEqual(lib.roundtripOptionBool(undefined), undefined);
+    assert.deepStrictEqual(lib.roundtripOptionRec({ a: 67 }), { a: 67 });
+    assert.strictEqual(lib.roundtripOptionRec(undefined), undefined);
+});
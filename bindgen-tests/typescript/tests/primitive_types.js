@@ -0,0 +1,58 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const lib = require("./test_package/uniffi_bindgen_tests");
+
+test("input", () => {
+    lib.inputU8(42);
+    lib.inputI8(-42);
+    lib.inputU16(42);
+    lib.inputI16(-42);
+    lib.inputU32(42);
+    lib.inputI32(-42);
+    lib.inputU64(42n);
+    lib.inputI64(-42n);
+    lib.inputF32(0.5);
+    lib.inputF64(-3.5);
+    lib.inputBool(true);
+    lib.inputString("ABC");
+});
+
+test("output", () => {
+    assert.strictEqual(lib.outputU8(), 1);
+    assert.strictEqual(lib.outputI8(), 1);
+    assert.strictEqual(lib.outputU16(), 1);
+    assert.strictEqual(lib.outputI16(), 1);
+    assert.strictEqual(lib.outputU32(), 1);
+    assert.strictEqual(lib.outputI32(), 1);
+    assert.strictEqual(lib.outputU64(), 1n);
+    assert.strictEqual(lib.outputI64(), 1n);
+    assert.strictEqual(lib.outputF32(), 1.0);
+    assert.strictEqual(lib.outputF64(), 1.0);
+    assert.strictEqual(lib.outputBool(), true);
+    assert.strictEqual(lib.outputString(), "test-string");
+});
+
+test("roundtrip", () => {
+    assert.strictEqual(lib.roundtripU8(42), 42);
+    assert.strictEqual(lib.roundtripI8(-42), -42);
+    assert.strictEqual(lib.roundtripU16(42), 42);
+    assert.strictEqual(lib.roundtripI16(-42), -42);
+    assert.strictEqual(lib.roundtripU32(42), 42);
+    assert.strictEqual(lib.roundtripI32(-42), -42);
+    assert.strictEqual(lib.roundtripU64(42n), 42n);
+    assert.strictEqual(lib.roundtripI64(-42n), -42n);
+    assert.strictEqual(lib.roundtripF32(0.5), 0.5);
+    assert.strictEqual(lib.roundtripF64(-3.5), -3.5);
+    assert.strictEqual(lib.roundtripBool(true), true);
+    assert.strictEqual(lib.roundtripString("ABC"), "ABC");
+    // Test calling a function with lots of args
+    // This function will sum up all the numbers, then negate the value since we passed in `true`
+    assert.strictEqual(lib.sumWithManyTypes(1, -2, 3, -4, 5, -6, 7n, -8n, 9.5, -10.5, true), 5);
+});
+
+test("out of range values", () => {
+    assert.throws(() => lib.roundtripU8(256), RangeError);
+    assert.throws(() => lib.roundtripI8(1.5), TypeError);
+    assert.throws(() => lib.roundtripU64(-1n), RangeError);
+    assert.throws(() => lib.roundtripU64(1), TypeError);
+});
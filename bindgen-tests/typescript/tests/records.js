@@ -0,0 +1,39 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const {
+    ComplexRec,
+    SimpleRec,
+    UnitRec,
+    roundtripComplexRec,
+    roundtripSimpleRec,
+} = require("./test_package/uniffi_bindgen_tests");
+
+test("simple record", () => {
+    assert.deepStrictEqual(roundtripSimpleRec(SimpleRec.create({ a: 42 })), { a: 42 });
+});
+
+test("unit record", () => {
+    assert.deepStrictEqual(UnitRec.create({}), {});
+});
+
+test("complex record", () => {
+    const rec = ComplexRec.create({
+        fieldU8: 0,
+        fieldI8: -1,
+        fieldU16: 2,
+        fieldI16: -3,
+        fieldU32: 4,
+        fieldI32: -5,
+        fieldU64: 6n,
+        fieldI64: -7n,
+        fieldF32: 8.5,
+        fieldF64: 9.5,
+        fieldString: "test",
+        fieldRec: SimpleRec.create({ a: 42 }),
+    });
+    assert.deepStrictEqual(roundtripComplexRec(rec), rec);
+});
+
+test("methods", () => {
+    assert.deepStrictEqual(SimpleRec.roundtrip({ a: 42 }), { a: 42 });
+});
@@ -0,0 +1,92 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const {
+    BoolExpr,
+    EvalError,
+    Expr,
+    LinkedList,
+    RoseData,
+    RoseTree,
+    Tree,
+    Trie,
+    evalBool,
+    evalExpr,
+    listSum,
+    maybeThrowError,
+    sumRoseTree,
+    sumTree,
+    trieSum,
+} = require("./test_package/uniffi_bindgen_tests");
+
+test("tree (self-recursive)", () => {
+    assert.strictEqual(sumTree(Tree.Leaf(5)), 5);
+    assert.strictEqual(sumTree(Tree.Node({ left: Tree.Leaf(3), right: Tree.Leaf(4) })), 7);
+    const deep = Tree.Node({
+        left: Tree.Node({ left: Tree.Leaf(1), right: Tree.Leaf(2) }),
+        right: Tree.Leaf(10),
+    });
+    assert.strictEqual(sumTree(deep), 13);
+});
+
+test("expr / bool expr (mutually recursive)", () => {
+    assert.strictEqual(evalExpr(Expr.Lit(42)), 42);
+    // `else` is a reserved word, so the `else_` field keeps its trailing underscore
+    const ifZero = (n) => Expr.If({
+        cond: BoolExpr.IsZero(Expr.Lit(n)),
+        then: Expr.Lit(1),
+        else_: Expr.Lit(2),
+    });
+    assert.strictEqual(evalExpr(ifZero(0)), 1);
+    assert.strictEqual(evalExpr(ifZero(5)), 2);
+    assert.strictEqual(evalBool(BoolExpr.Not(BoolExpr.True)), false);
+    assert.strictEqual(evalBool(BoolExpr.Not(BoolExpr.False)), true);
+});
+
+test("linked list (cycle through Optional)", () => {
+    assert.strictEqual(listSum(LinkedList.Nil), 0);
+    assert.strictEqual(listSum(LinkedList.Cons({ head: 5, tail: undefined })), 5);
+    const list = LinkedList.Cons({ head: 3, tail: LinkedList.Cons({ head: 4, tail: undefined }) });
+    assert.strictEqual(listSum(list), 7);
+});
+
+test("trie (cycle through Map value)", () => {
+    assert.strictEqual(trieSum(Trie.Leaf(5)), 5);
+    assert.strictEqual(trieSum(Trie.Branch({ children: new Map() })), 0);
+    assert.strictEqual(
+        trieSum(Trie.Branch({ children: new Map([["a", Trie.Leaf(1)], ["b", Trie.Leaf(2)]]) })),
+        3,
+    );
+    assert.strictEqual(
+        trieSum(Trie.Branch({
+            children: new Map([["x", Trie.Branch({ children: new Map([["y", Trie.Leaf(7)]]) })]]),
+        })),
+        7,
+    );
+});
+
+test("rose tree (cycle through a record)", () => {
+    assert.strictEqual(sumRoseTree(RoseTree.Leaf(7)), 7);
+    assert.strictEqual(sumRoseTree(RoseTree.Branch(RoseData.create({ value: 10, children: [] }))), 10);
+    assert.strictEqual(
+        sumRoseTree(RoseTree.Branch(RoseData.create({
+            value: 1,
+            children: [RoseTree.Leaf(2), RoseTree.Leaf(3)],
+        }))),
+        6,
+    );
+    assert.strictEqual(
+        sumRoseTree(RoseTree.Branch(RoseData.create({
+            value: 1,
+            children: [
+                RoseTree.Branch(RoseData.create({ value: 2, children: [RoseTree.Leaf(3)] })),
+                RoseTree.Leaf(4),
+            ],
+        }))),
+        10,
+    );
+});
+
+test("recursive error", () => {
+    assert.strictEqual(maybeThrowError(false), 42);
+    assert.throws(() => maybeThrowError(true), EvalError);
+});
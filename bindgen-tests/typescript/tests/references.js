@@ -0,0 +1,24 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const {
+    ReferenceTestInterface,
+    callDoubleValue,
+    callTripleValueTraitInterface,
+    createReferenceTestTraitInterface,
+    roundtripU8Ref,
+} = require("./test_package/uniffi_bindgen_tests");
+
+test("value ref", () => {
+    assert.strictEqual(roundtripU8Ref(2), 2);
+});
+
+test("interface ref", () => {
+    const iface = new ReferenceTestInterface();
+    assert.strictEqual(iface.doubleValue(2), 4);
+    assert.strictEqual(callDoubleValue(iface, 3), 6);
+});
+
+test("trait interface ref", () => {
+    const traitInterface = createReferenceTestTraitInterface();
+    assert.strictEqual(callTripleValueTraitInterface(traitInterface, 10), 30);
+});
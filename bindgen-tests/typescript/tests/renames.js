@@ -0,0 +1,70 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const {
+    RenamedEnum,
+    RenamedObject,
+    RenamedRecord,
+    TsEnum,
+    TsEnumWithFields,
+    TsError,
+    TsObject,
+    TsRecord,
+    createBindingTraitToRenameImpl,
+    createTraitImpl,
+    renamedFunction,
+    tsFunction,
+} = require("./test_package/uniffi_bindgen_tests");
+
+test("rename record", () => {
+    const rec = RenamedRecord.create({ item: 42 });
+    assert.strictEqual(rec.item, 42);
+});
+
+test("rename enum", () => {
+    const rec = RenamedRecord.create({ item: 42 });
+    assert.strictEqual(RenamedEnum.RenamedVariant.tag, "RenamedVariant");
+    const returnValue = renamedFunction(rec);
+    assert.strictEqual(returnValue.tag, "Record");
+    assert.deepStrictEqual(returnValue.v1, rec);
+});
+
+test("rename object", () => {
+    const obj = RenamedObject.renamedConstructor(123);
+    assert.strictEqual(obj.renamedMethod(), 123);
+});
+
+test("trait method", () => {
+    const traitImpl = createTraitImpl(5);
+    assert.strictEqual(traitImpl.renamedTraitMethod(10), 50);
+});
+
+test("ts rename record", () => {
+    const rec = TsRecord.create({ tsItem: 100 });
+    assert.strictEqual(rec.tsItem, 100);
+});
+
+test("ts rename enum", () => {
+    const rec = TsRecord.create({ tsItem: 100 });
+    assert.strictEqual(TsEnum.TsVariantA.tag, "TsVariantA");
+    assert.strictEqual(TsEnum.TsRecord(rec).tag, "TsRecord");
+    assert.strictEqual(TsEnumWithFields.TsVariantA({ tsInt: 1 }).tsInt, 1);
+});
+
+test("ts rename functions", () => {
+    const rec = TsRecord.create({ tsItem: 100 });
+    const returnValue = tsFunction(rec);
+    assert.strictEqual(returnValue.tag, "TsRecord");
+    assert.deepStrictEqual(returnValue.v1, rec);
+
+    assert.throws(() => tsFunction(undefined), TsError.TsSimple);
+});
+
+test("ts rename methods", () => {
+    const obj = new TsObject(200);
+    assert.strictEqual(obj.tsMethod(50), 250);
+});
+
+test("ts rename trait", () => {
+    const traitImpl = createBindingTraitToRenameImpl(3);
+    assert.strictEqual(traitImpl.tsTraitMethod(7), 21);
+});
@@ -0,0 +1,37 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const { RustTraitTest } = require("./test_package/uniffi_bindgen_tests");
+
+test("debug", () => {
+    const traitTest = RustTraitTest.create({ a: 1, b: 2 });
+    assert.strictEqual(RustTraitTest.toDebugString(traitTest), "debug-test-string");
+});
+
+test("display", () => {
+    const traitTest = RustTraitTest.create({ a: 1, b: 2 });
+    assert.strictEqual(RustTraitTest.toString(traitTest), "display-test-string");
+});
+
+test("eq", () => {
+    // The Rust code only uses `a` for the equality
+    assert.ok(RustTraitTest.equals({ a: 1, b: 2 }, { a: 1, b: 3 }));
+    assert.ok(!RustTraitTest.equals({ a: 2, b: 2 }, { a: 1, b: 2 }));
+});
+
+test("ord", () => {
+    // The Rust code only uses `a` for the ordering
+    assert.ok(RustTraitTest.compareTo({ a: 1, b: 2 }, { a: 2, b: 3 }) < 0);
+    assert.strictEqual(RustTraitTest.compareTo({ a: 1, b: 2 }, { a: 1, b: 3 }), 0);
+});
+
+test("hash", () => {
+    // The Rust code only uses `a` for the hash
+    assert.strictEqual(
+        RustTraitTest.hashCode({ a: 1, b: 2 }),
+        RustTraitTest.hashCode({ a: 1, b: 3 }),
+    );
+    assert.notStrictEqual(
+        RustTraitTest.hashCode({ a: 2, b: 2 }),
+        RustTraitTest.hashCode({ a: 1, b: 2 }),
+    );
+});
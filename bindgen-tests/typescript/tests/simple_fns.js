@@ -0,0 +1,12 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const { testFunc, testUnexpectedErrorFunc, InternalError } = require("./test_package/uniffi_bindgen_tests");
+
+test("simple function", () => {
+    // the test here is just that we can successfully call a function across the FFI
+    testFunc();
+});
+
+test("unexpected error", () => {
+    assert.throws(() => testUnexpectedErrorFunc(), InternalError);
+});
@@ -0,0 +1,15 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const { roundtripDuration, roundtripSystemtime } = require("./test_package/uniffi_bindgen_tests");
+
+test("duration", () => {
+    // Durations are represented as a number of milliseconds
+    const duration = (24 * 60 * 60 + 2) * 1000;
+    assert.strictEqual(roundtripDuration(duration), duration);
+    assert.strictEqual(roundtripDuration(1.5), 1.5);
+});
+
+test("systemtime", () => {
+    const time = new Date(Date.UTC(2000, 0, 1));
+    assert.deepStrictEqual(roundtripSystemtime(time), time);
+});
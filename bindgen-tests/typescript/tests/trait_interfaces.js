@@ -0,0 +1,218 @@
+const { test } = require("node:test");
+const assert = require("node:assert");
+const lib = require("./test_package/uniffi_bindgen_tests");
+const { EnumWithData, TestError, TestInterface } = lib;
+
+class TraitImpl {
+    constructor(value) {
+        this.value = value;
+    }
+
+    noop() {}
+
+    getValue() {
+        return this.value;
+    }
+
+    setValue(value) {
+        this.value = value;
+    }
+
+    throwIfEqual(numbers) {
+        if (numbers.a === numbers.b) {
+            throw new TestError.Failure1();
+        }
+        return numbers;
+    }
+
+    roundtripRecord(value) {
+        return value;
+    }
+
+    roundtripEnum(value) {
+        return value;
+    }
+
+    roundtripInterface(value) {
+        return value;
+    }
+}
+
+function checkRustImpl(rustTraitImpl) {
+    rustTraitImpl.noop();
+    assert.strictEqual(rustTraitImpl.getValue(), 42);
+    rustTraitImpl.setValue(43);
+    assert.strictEqual(rustTraitImpl.getValue(), 43);
+    assert.throws(() => rustTraitImpl.throwIfEqual({ a: 10, b: 10 }), TestError.Failure1);
+    assert.deepStrictEqual(rustTraitImpl.throwIfEqual({ a: 10, b: 11 }), { a: 10, b: 11 });
+    assert.deepStrictEqual(rustTraitImpl.roundtripRecord({ a: 10 }), { a: 10 });
+    assert.deepStrictEqual(
+        rustTraitImpl.roundtripEnum(EnumWithData.A({ value: 10, value2: 20 })),
+        EnumWithData.A({ value: 10, value2: 20 }),
+    );
+    assert.strictEqual(rustTraitImpl.roundtripInterface(new TestInterface(20)).getValue(), 20);
+}
+
+function checkJsImpl(jsTraitImpl) {
+    lib.invokeTestTraitInterfaceNoop(jsTraitImpl);
+    assert.strictEqual(lib.invokeTestTraitInterfaceGetValue(jsTraitImpl), 42);
+    lib.invokeTestTraitInterfaceSetValue(jsTraitImpl, 43);
+    assert.strictEqual(lib.invokeTestTraitInterfaceGetValue(jsTraitImpl), 43);
+    assert.throws(
+        () => lib.invokeTestTraitInterfaceThrowIfEqual(jsTraitImpl, { a: 10, b: 10 }),
+        TestError.Failure1,
+    );
+    assert.deepStrictEqual(
+        lib.invokeTestTraitInterfaceThrowIfEqual(jsTraitImpl, { a: 10, b: 11 }),
+        { a: 10, b: 11 },
+    );
+    assert.deepStrictEqual(lib.invokeTestTraitInterfaceRoundtripRecord(jsTraitImpl, { a: 10 }), { a: 10 });
+    assert.deepStrictEqual(
+        lib.invokeTestTraitInterfaceRoundtripEnum(jsTraitImpl, EnumWithData.A({ value: 10, value2: 20 })),
+        EnumWithData.A({ value: 10, value2: 20 }),
+    );
+    assert.strictEqual(
+        lib.invokeTestTraitInterfaceRoundtripInterface(jsTraitImpl, new TestInterface(20)).getValue(),
+        20,
+    );
+}
+
+test("rust impl", () => {
+    checkRustImpl(lib.createTestTraitInterface(42));
+});
+
+test("rust impl roundtripped", () => {
+    checkRustImpl(lib.roundtripTestTraitInterface(lib.createTestTraitInterface(42)));
+});
+
+test("rust impl roundtripped list", () => {
+    checkRustImpl(lib.roundtripTestTraitInterfaceList([lib.createTestTraitInterface(42)])[0]);
+});
+
+test("js impl", () => {
+    checkJsImpl(new TraitImpl(42));
+    assert.strictEqual(lib.FfiConverterTypeTestTraitInterface._handleMap.size, 0);
+});
+
+test("js impl roundtripped", () => {
+    const impl = new TraitImpl(42);
+    // JS implementations come back as the same object
+    assert.strictEqual(lib.roundtripTestTraitInterface(impl), impl);
+    checkJsImpl(lib.roundtripTestTraitInterface(impl));
+    assert.strictEqual(lib.FfiConverterTypeTestTraitInterface._handleMap.size, 0);
+});
+
+test("js impl roundtripped list", () => {
+    checkJsImpl(lib.roundtripTestTraitInterfaceList([new TraitImpl(42)])[0]);
+    assert.strictEqual(lib.FfiConverterTypeTestTraitInterface._handleMap.size, 0);
+});
+
+class AsyncTraitImpl {
+    constructor(value) {
+        this.value = value;
+    }
+
+    async noop() {}
+
+    async getValue() {
+        return this.value;
+    }
+
+    async setValue(value) {
+        this.value = value;
+    }
+
+    async throwIfEqual(numbers) {
+        if (numbers.a === numbers.b) {
+            throw new TestError.Failure1();
+        }
+        return numbers;
+    }
+}
+
+async function checkAsyncRustImpl(impl) {
+    await impl.noop();
+    assert.strictEqual(await impl.getValue(), 42);
+    await impl.setValue(43);
+    assert.strictEqual(await impl.getValue(), 43);
+    await assert.rejects(impl.throwIfEqual({ a: 10, b: 10 }), TestError.Failure1);
+    assert.deepStrictEqual(await impl.throwIfEqual({ a: 10, b: 11 }), { a: 10, b: 11 });
+}
+
+async function checkAsyncJsImpl(impl) {
+    await lib.invokeAsyncTestTraitInterfaceNoop(impl);
+    assert.strictEqual(await lib.invokeAsyncTestTraitInterfaceGetValue(impl), 42);
+    await lib.invokeAsyncTestTraitInterfaceSetValue(impl, 43);
+    assert.strictEqual(await lib.invokeAsyncTestTraitInterfaceGetValue(impl), 43);
+    await assert.rejects(
+        lib.invokeAsyncTestTraitInterfaceThrowIfEqual(impl, { a: 10, b: 10 }),
+        TestError.Failure1,
+    );
+    assert.deepStrictEqual(
+        await lib.invokeAsyncTestTraitInterfaceThrowIfEqual(impl, { a: 10, b: 11 }),
+        { a: 10, b: 11 },
+    );
+}
+
+test("async rust impl", async () => {
+    await checkAsyncRustImpl(lib.createAsyncTestTraitInterface(42));
+});
+
+test("async rust impl roundtripped", async () => {
+    await checkAsyncRustImpl(lib.roundtripAsyncTestTraitInterface(lib.createAsyncTestTraitInterface(42)));
+});
+
+test("async rust impl roundtripped list", async () => {
+    await checkAsyncRustImpl(
+        lib.roundtripAsyncTestTraitInterfaceList([lib.createAsyncTestTraitInterface(42)])[0],
+    );
+});
+
+test("async js impl", async () => {
+    await checkAsyncJsImpl(new AsyncTraitImpl(42));
+    assert.strictEqual(lib.FfiConverterTypeAsyncTestTraitInterface._handleMap.size, 0);
+});
+
+test("async js impl roundtripped", async () => {
+    await checkAsyncJsImpl(lib.roundtripAsyncTestTraitInterface(new AsyncTraitImpl(42)));
+    assert.strictEqual(lib.FfiConverterTypeAsyncTestTraitInterface._handleMap.size, 0);
+});
+
+test("async js impl roundtripped list", async () => {
+    await checkAsyncJsImpl(lib.roundtripAsyncTestTraitInterfaceList([new AsyncTraitImpl(42)])[0]);
+    assert.strictEqual(lib.FfiConverterTypeAsyncTestTraitInterface._handleMap.size, 0);
+});
+
+test("rust only impl", () => {
+    const impl = lib.createRustOnlyTestTraitInterface();
+    assert.throws(() => impl.throwIfEqual({ a: 10, b: 10 }), TestError.Failure1);
+    assert.deepStrictEqual(impl.throwIfEqual({ a: 10, b: 11 }), { a: 10, b: 11 });
+});
+
+class ForeignOnlyImpl {
+    throwIfEqual(numbers) {
+        if (numbers.a === numbers.b) {
+            throw new TestError.Failure1();
+        }
+        return numbers;
+    }
+}
+
+function checkForeignOnlyImpl(impl) {
+    assert.throws(
+        () => lib.invokeTestForeignOnlyTraitThrowIfEqual(impl, { a: 10, b: 10 }),
+        TestError.Failure1,
+    );
+    assert.deepStrictEqual(
+        lib.invokeTestForeignOnlyTraitThrowIfEqual(impl, { a: 10, b: 11 }),
+        { a: 10, b: 11 },
+    );
+}
+
+test("foreign only impl", () => {
+    checkForeignOnlyImpl(new ForeignOnlyImpl());
+});
+
+test("foreign only impl roundtripped", () => {
+    checkForeignOnlyImpl(lib.roundtripTestForeignOnlyTrait(new ForeignOnlyImpl()));
+});
@@ -0,0 +1,92 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+type IntFn = dyn Fn(i32) -> i32 + Send + Sync;
+type Listener = dyn Fn(String) + Send + Sync;
+
+/// Call a foreign closure
+#[uniffi::export]
+fn call_closure(f: Box<IntFn>, value: i32) -> i32 {
+    f(value)
+}
+
+/// Call a foreign closure that doesn't input any arguments
+#[uniffi::export]
+fn call_thunk(f: Box<dyn Fn() -> String + Send + Sync>) -> String {
+    f()
+}
+
+/// Call a foreign closure that doesn't return anything, once for each number in `0..count`
+#[uniffi::export]
+fn call_for_each(f: Box<dyn Fn(u32) + Send + Sync>, count: u32) {
+    for i in 0..count {
+        f(i)
+    }
+}
+
+#[uniffi::export]
+fn call_optional_closure(f: Option<Box<IntFn>>, value: i32) -> i32 {
+    match f {
+        Some(f) => f(value),
+        None => value,
+    }
+}
+
+/// Call a foreign closure with multiple arguments of different types
+#[uniffi::export]
+fn call_closure_with_args(
+    f: Box<dyn Fn(String, u8, Vec<i32>) -> Option<String> + Send + Sync>,
+) -> Option<String> {
+    f("numbers".to_string(), 3, vec![1, 2, 3])
+}
+
+/// Return a Rust closure
+#[uniffi::export]
+fn make_adder(amount: i32) -> Box<IntFn> {
+    Box::new(move |value| value + amount)
+}
+
+/// Return a Rust closure that concatenates its arguments
+#[uniffi::export]
+fn make_joiner(separator: String) -> Arc<dyn Fn(String, String) -> String + Send + Sync> {
+    Arc::new(move |a, b| format!("{a}{separator}{b}"))
+}
+
+/// Return a Rust closure that combines two other closures, which can be foreign or Rust closures
+#[uniffi::export]
+fn compose(f: Arc<IntFn>, g: Arc<IntFn>) -> Arc<IntFn> {
+    Arc::new(move |value| g(f(value)))
+}
+
+/// Store closures and call them later
+#[derive(uniffi::Object, Default)]
+pub struct EventEmitter {
+    listeners: Mutex<Vec<Box<Listener>>>,
+}
+
+#[uniffi::export]
+impl EventEmitter {
+    #[uniffi::constructor]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn add_listener(&self, listener: Box<Listener>) {
+        self.listeners.lock().unwrap().push(listener);
+    }
+
+    fn emit(&self, event: String) {
+        for listener in self.listeners.lock().unwrap().iter() {
+            listener(event.clone());
+        }
+    }
+
+    fn clear(&self) {
+        self.listeners.lock().unwrap().clear();
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,39 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.closures.*
+
+// Kotlin closures passed to Rust
+assert(callClosure({ it * 2 }, 21) == 42)
+assert(callThunk { "hello" } == "hello")
+assert(callOptionalClosure({ it + 1 }, 1) == 2)
+assert(callOptionalClosure(null, 1) == 1)
+assert(callClosureWithArgs { s, count, values -> "$s: $count $values" } == "numbers: 3 [1, 2, 3]")
+assert(callClosureWithArgs { _, _, _ -> null } == null)
+
+val seen = mutableListOf<UInt>()
+callForEach({ seen.add(it) }, 3u)
+assert(seen == listOf(0u, 1u, 2u))
+
+// Rust closures passed to Kotlin
+val addFive = makeAdder(5)
+assert(addFive(1) == 6)
+assert(addFive(-5) == 0)
+assert(makeJoiner(", ")("a", "b") == "a, b")
+
+// Round trips
+assert(callClosure(makeAdder(1), 1) == 2)
+assert(compose(makeAdder(1), { it * 10 })(1) == 20)
+assert(compose({ it * 10 }, makeAdder(1))(1) == 11)
+
+// Closures stored on the Rust side
+val emitter = EventEmitter()
+val events = mutableListOf<String>()
+emitter.addListener { events.add(it) }
+emitter.addListener { events.add(it.uppercase()) }
+emitter.emit("click")
+assert(events == listOf("click", "CLICK"))
+emitter.clear()
+emitter.emit("click")
+assert(events == listOf("click", "CLICK"))
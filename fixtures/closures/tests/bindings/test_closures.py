@@ -0,0 +1,54 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import unittest
+from uniffi_closures import *
+
+class TestClosures(unittest.TestCase):
+    def test_foreign_closures(self):
+        self.assertEqual(call_closure(lambda x: x * 2, 21), 42)
+        self.assertEqual(call_thunk(lambda: "hello"), "hello")
+        self.assertEqual(call_optional_closure(lambda x: x + 1, 1), 2)
+        self.assertEqual(call_optional_closure(None, 1), 1)
+        self.assertEqual(
+            call_closure_with_args(lambda s, count, values: f"{s}: {count} {values}"),
+            "numbers: 3 [1, 2, 3]",
+        )
+        self.assertEqual(call_closure_with_args(lambda s, count, values: None), None)
+
+    def test_void_closures(self):
+        seen = []
+        call_for_each(seen.append, 3)
+        self.assertEqual(seen, [0, 1, 2])
+
+    def test_rust_closures(self):
+        add_five = make_adder(5)
+        self.assertEqual(add_five(1), 6)
+        self.assertEqual(add_five(-5), 0)
+        self.assertEqual(make_joiner(", ")("a", "b"), "a, b")
+        with self.assertRaises(TypeError):
+            add_five(1, 2)
+
+    def test_round_trip(self):
+        # Pass a Rust closure back to Rust
+        self.assertEqual(call_closure(make_adder(1), 1), 2)
+        # Compose Rust and Python closures
+        f = compose(make_adder(1), lambda x: x * 10)
+        self.assertEqual(f(1), 20)
+        f = compose(lambda x: x * 10, make_adder(1))
+        self.assertEqual(f(1), 11)
+
+    def test_stored_closures(self):
+        emitter = EventEmitter()
+        events = []
+        emitter.add_listener(events.append)
+        emitter.add_listener(lambda event: events.append(event.upper()))
+        emitter.emit("click")
+        self.assertEqual(events, ["click", "CLICK"])
+        emitter.clear()
+        emitter.emit("click")
+        self.assertEqual(events, ["click", "CLICK"])
+
+if __name__ == '__main__':
+    unittest.main()
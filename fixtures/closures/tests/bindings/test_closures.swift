@@ -0,0 +1,53 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_closures
+
+// Swift closures passed to Rust
+assert(callClosure(f: { $0 * 2 }, value: 21) == 42)
+assert(callThunk(f: { "hello" }) == "hello")
+assert(callOptionalClosure(f: { $0 + 1 }, value: 1) == 2)
+assert(callOptionalClosure(f: nil, value: 1) == 1)
+assert(callClosureWithArgs(f: { s, count, values in "\(s): \(count) \(values)" }) == "numbers: 3 [1, 2, 3]")
+assert(callClosureWithArgs(f: { _, _, _ in nil }) == nil)
+
+final class Recorder<T>: @unchecked Sendable {
+    private let lock = NSLock()
+    private var items: [T] = []
+
+    func record(_ item: T) {
+        lock.withLock { items.append(item) }
+    }
+
+    var recorded: [T] {
+        lock.withLock { items }
+    }
+}
+
+let seen = Recorder<UInt32>()
+callForEach(f: { seen.record($0) }, count: 3)
+assert(seen.recorded == [0, 1, 2])
+
+// Rust closures passed to Swift
+let addFive = makeAdder(amount: 5)
+assert(addFive(1) == 6)
+assert(addFive(-5) == 0)
+assert(makeJoiner(separator: ", ")("a", "b") == "a, b")
+
+// Round trips
+assert(callClosure(f: makeAdder(amount: 1), value: 1) == 2)
+assert(compose(f: makeAdder(amount: 1), g: { $0 * 10 })(1) == 20)
+assert(compose(f: { $0 * 10 }, g: makeAdder(amount: 1))(1) == 11)
+
+// Closures stored on the Rust side
+let emitter = EventEmitter()
+let events = Recorder<String>()
+emitter.addListener(listener: { events.record($0) })
+emitter.addListener(listener: { events.record($0.uppercased()) })
+emitter.emit(event: "click")
+assert(events.recorded == ["click", "CLICK"])
+emitter.clear()
+emitter.emit(event: "click")
+assert(events.recorded == ["click", "CLICK"])
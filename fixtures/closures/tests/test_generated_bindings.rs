@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_closures.kts",
+    "tests/bindings/test_closures.swift",
+    "tests/bindings/test_closures.py",
+);
@@ -60,6 +60,25 @@ fn throw_flat_macro_error() -> Result<(), CoverallFlatMacroError> {
     Err(CoverallFlatMacroError::TooManyVariants { num: 88 })
 }
 
+static OBSERVED_FFI_CALLS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Record each FFI call as `[kind] [name] [status]`, until `stop_observing_ffi_calls` is called
+#[uniffi::export]
+fn start_observing_ffi_calls() {
+    uniffi::set_ffi_call_observer(|event: &uniffi::FfiCallEvent<'_>| {
+        OBSERVED_FFI_CALLS.lock().unwrap().push(format!(
+            "{:?} {} {:?}",
+            event.kind, event.name, event.status
+        ));
+    });
+}
+
+#[uniffi::export]
+fn stop_observing_ffi_calls() -> Vec<String> {
+    uniffi::clear_ffi_call_observer();
+    std::mem::take(&mut *OBSERVED_FFI_CALLS.lock().unwrap())
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum CoverallRichErrorNoVariantData {
     #[error("TooManyPlainVariants")]
@@ -531,5 +531,26 @@ class Annotations(unittest.TestCase):
         self.assertEqual(get_type_hints(NodeTraitImpl.get_parent), {"return":  Optional[NodeTrait]})
         self.assertEqual(get_type_hints(async_bool), {"return":  bool, "b": bool})
 
+class FfiCallObserverTest(unittest.TestCase):
+    def test_ffi_call_observer(self):
+        start_observing_ffi_calls()
+        with self.assertRaises(CoverallMacroError):
+            throw_macro_error()
+        coveralls = Coveralls("test_ffi_call_observer")
+        with self.assertRaises(InternalError):
+            coveralls.panic("expected panic: observed")
+        test_getters(PyGetters())
+        calls = stop_observing_ffi_calls()
+
+        self.assertIn("Scaffolding uniffi_uniffi_coverall_fn_func_throw_macro_error Error", calls)
+        self.assertIn("Scaffolding uniffi_uniffi_coverall_fn_constructor_coveralls_new Success", calls)
+        self.assertIn("Scaffolding uniffi_uniffi_coverall_fn_method_coveralls_panic UnexpectedError", calls)
+        self.assertIn("CallbackInterface Getters::get_bool Success", calls)
+        self.assertIn("CallbackInterface Getters::get_string Error", calls)
+        self.assertIn("CallbackInterface Getters::get_string UnexpectedError", calls)
+        # Neither call is observed, since the observer wasn't installed for the whole call
+        self.assertNotIn("Scaffolding uniffi_uniffi_coverall_fn_func_start_observing_ffi_calls Success", calls)
+        self.assertNotIn("Scaffolding uniffi_uniffi_coverall_fn_func_stop_observing_ffi_calls Success", calls)
+
 if __name__=='__main__':
     unittest.main()
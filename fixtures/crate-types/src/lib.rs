@@ -0,0 +1,60 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use time::OffsetDateTime;
+use url::Url;
+use uuid::Uuid;
+
+#[derive(uniffi::Record)]
+pub struct Resource {
+    pub id: Uuid,
+    pub location: Url,
+    pub created: DateTime<Utc>,
+    pub expires: Option<NaiveDate>,
+}
+
+#[uniffi::export]
+pub fn get_resource() -> Resource {
+    Resource {
+        id: Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8),
+        location: Url::parse("https://example.com/resources/1?lang=en").unwrap(),
+        created: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+        expires: NaiveDate::from_ymd_opt(2030, 12, 31),
+    }
+}
+
+#[uniffi::export]
+pub fn echo_resource(resource: Resource) -> Resource {
+    resource
+}
+
+#[uniffi::export]
+pub fn uuid_to_string(id: Uuid) -> String {
+    id.hyphenated().to_string()
+}
+
+#[uniffi::export]
+pub fn url_host(url: Url) -> Option<String> {
+    url.host_str().map(str::to_string)
+}
+
+#[uniffi::export]
+pub fn date_to_string(date: NaiveDate) -> String {
+    date.to_string()
+}
+
+#[uniffi::export]
+pub fn moon_landing() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1969, 7, 20).unwrap()
+}
+
+#[uniffi::export]
+pub fn add_seconds(time: OffsetDateTime, seconds: i64) -> OffsetDateTime {
+    time + time::Duration::seconds(seconds)
+}
+
+uniffi::use_builtin_types!(uuid, url, chrono, time);
+
+uniffi::setup_scaffolding!();
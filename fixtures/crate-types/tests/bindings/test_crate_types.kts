@@ -0,0 +1,31 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.uniffi_crate_types.*
+import java.net.URI
+import java.time.Instant
+import java.time.LocalDate
+import java.util.UUID
+
+val resource = getResource()
+assert(resource.id == UUID.fromString("67e55044-10b1-426f-9247-bb680e5fe0c8"))
+assert(resource.location == URI("https://example.com/resources/1?lang=en"))
+assert(resource.created == Instant.ofEpochSecond(1_700_000_000))
+assert(resource.expires == LocalDate.of(2030, 12, 31))
+assert(echoResource(resource) == resource)
+
+// Uuid
+val newId = UUID.randomUUID()
+assert(uuidToString(newId) == newId.toString())
+
+// Url
+assert(urlHost(URI("https://mozilla.org/firefox")) == "mozilla.org")
+assert(urlHost(URI("data:text/plain,hello")) == null)
+
+// Date
+assert(dateToString(LocalDate.of(2024, 2, 29)) == "2024-02-29")
+assert(moonLanding() == LocalDate.of(1969, 7, 20))
+
+// OffsetDateTime
+assert(addSeconds(Instant.parse("1969-07-20T20:17:40Z"), 60) == Instant.parse("1969-07-20T20:18:40Z"))
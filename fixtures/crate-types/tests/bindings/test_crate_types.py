@@ -0,0 +1,41 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import datetime
+import uuid
+from uniffi_crate_types import *
+
+resource = get_resource()
+assert resource.id == uuid.UUID("67e55044-10b1-426f-9247-bb680e5fe0c8")
+assert resource.location == "https://example.com/resources/1?lang=en"
+assert resource.created == datetime.datetime(2023, 11, 14, 22, 13, 20, tzinfo=datetime.timezone.utc)
+assert resource.expires == datetime.date(2030, 12, 31)
+assert echo_resource(resource) == resource
+
+# Uuid
+new_id = uuid.uuid4()
+assert uuid_to_string(new_id) == str(new_id)
+try:
+    uuid_to_string("67e55044-10b1-426f-9247-bb680e5fe0c8")
+    raise AssertionError("strings aren't UUIDs")
+except TypeError:
+    pass
+
+# Url
+assert url_host("https://mozilla.org/firefox") == "mozilla.org"
+assert url_host("data:text/plain,hello") is None
+
+# Date
+assert date_to_string(datetime.date(2024, 2, 29)) == "2024-02-29"
+assert date_to_string(datetime.date(1, 1, 1)) == "0001-01-01"
+assert moon_landing() == datetime.date(1969, 7, 20)
+try:
+    date_to_string(datetime.datetime(2024, 2, 29, 12, 0))
+    raise AssertionError("datetimes aren't dates")
+except TypeError:
+    pass
+
+# OffsetDateTime
+start = datetime.datetime(1969, 7, 20, 20, 17, 40, tzinfo=datetime.timezone.utc)
+assert add_seconds(start, 60) == datetime.datetime(1969, 7, 20, 20, 18, 40, tzinfo=datetime.timezone.utc)
@@ -0,0 +1,29 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_crate_types
+
+let resource = getResource()
+assert(resource.id == UUID(uuidString: "67e55044-10b1-426f-9247-bb680e5fe0c8")!)
+assert(resource.location == URL(string: "https://example.com/resources/1?lang=en")!)
+assert(resource.created == Date(timeIntervalSince1970: 1_700_000_000))
+assert(resource.expires == DateComponents(year: 2030, month: 12, day: 31))
+assert(echoResource(resource: resource) == resource)
+
+// Uuid
+let newId = UUID()
+assert(uuidToString(id: newId) == newId.uuidString.lowercased())
+
+// Url
+assert(urlHost(url: URL(string: "https://mozilla.org/firefox")!) == "mozilla.org")
+assert(urlHost(url: URL(string: "data:text/plain,hello")!) == nil)
+
+// Date
+assert(dateToString(date: DateComponents(year: 2024, month: 2, day: 29)) == "2024-02-29")
+assert(moonLanding() == DateComponents(year: 1969, month: 7, day: 20))
+
+// OffsetDateTime
+let start = Date(timeIntervalSince1970: -14_182_940)
+assert(addSeconds(time: start, seconds: 60) == start.addingTimeInterval(60))
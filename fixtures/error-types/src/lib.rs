@@ -131,6 +131,12 @@ impl ProcErrorInterface {
     fn message(&self) -> String {
         self.e.clone()
     }
+
+    // Static methods on an error object, which Kotlin puts in the same companion object as the
+    // error handler
+    fn describe(code: u16) -> String {
+        format!("error code {code}")
+    }
 }
 
 impl std::fmt::Display for ProcErrorInterface {
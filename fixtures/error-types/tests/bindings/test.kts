@@ -20,6 +20,15 @@ try {
     assert(e.toString() == "because uniffi told me so\n\nCaused by:\n    oops")
 }
 
+// Error objects with static methods
+try {
+    throwProcError("eek")
+    throw RuntimeException("Should have failed")
+} catch (e: ProcErrorInterface) {
+    assert(e.message() == "eek")
+}
+assert(ProcErrorInterface.describe(1U) == "error code 1")
+
 try {
     oopsNowrap()
     throw RuntimeException("Should have failed")
@@ -79,6 +79,7 @@ class TestErrorTypes(unittest.TestCase):
             throw_proc_error("eek")
         self.assertEqual(cm.exception.message(), "eek")
         self.assertEqual(str(cm.exception), "ProcErrorInterface(eek)")
+        self.assertEqual(ProcErrorInterface.describe(1), "error code 1")
 
     def test_enum_error(self):
         with self.assertRaises(Error) as cm:
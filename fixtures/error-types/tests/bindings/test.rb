@@ -86,6 +86,10 @@ class TestErrorTypes < Test::Unit::TestCase
     assert_equal 'hello', e.message
   end
 
+  def test_proc_error_static_method
+    assert_equal 'error code 1', ErrorTypes::ProcErrorInterface.describe(1)
+  end
+
   def test_oops_enum_oops
     assert_raises(ErrorTypes::Error::Oops) { ErrorTypes.oops_enum 0 }
   end
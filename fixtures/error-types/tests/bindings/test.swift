@@ -100,6 +100,15 @@ do {
 } catch {
 }
 
+// Error objects with static methods
+do {
+    try throwProcError(e: "eek")
+    fatalError("Should have thrown")
+} catch let e as ProcErrorInterface {
+    assert(e.message() == "eek")
+}
+assert(ProcErrorInterface.describe(code: 1) == "error code 1")
+
 do {
     try oopsNowrap()
     fatalError("Should have thrown")
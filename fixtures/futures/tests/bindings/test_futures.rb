@@ -312,6 +312,47 @@ class TestFutures < Test::Unit::TestCase
     assert_equal Futures::MyError::Foo, error_class
   end
 
+  def test_fiber_foreign_async_trait_interface_methods
+    Futures.uniffi_set_fiber_scheduler { MinimalScheduler.new }
+    trait_obj = RbFiberAsyncParser.new
+
+    assert_equal Futures.try_from_string_using_trait(trait_obj, 1, '42'), 42
+    assert_raises(Futures::ParserError::NotAnInt) do
+      Futures.try_from_string_using_trait(trait_obj, 1, 'fourty-two')
+    end
+
+    # Callbacks run concurrently as non-blocking fibers, all on the same reactor thread
+    t0 = now
+    threads = 2.times.map { |i| Thread.new { Futures.as_string_using_trait(trait_obj, 200, i) } }
+    assert_equal %w[0 1], threads.map(&:value)
+    assert_operator now - t0, :<, 0.4
+    assert_equal 1, trait_obj.callers.map(&:first).uniq.size
+    assert_not_include trait_obj.callers.map(&:first), Thread.current
+    assert trait_obj.callers.none?(&:last), 'callback ran in a blocking fiber'
+
+    completed_delays_before = trait_obj.completed_delays
+    Futures.cancel_delay_using_trait trait_obj, 10
+    sleep 0.1
+    assert_equal trait_obj.completed_delays, completed_delays_before
+
+    assert_equal Futures::UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.size, 0
+    assert_equal Futures::UNIFFI_ASYNC_HANDLE_MAP.size, 0
+  ensure
+    Futures.uniffi_set_fiber_scheduler
+  end
+
+  def test_fiber_scheduler_replaced_during_calls
+    Futures.uniffi_set_fiber_scheduler { MinimalScheduler.new }
+    trait_obj = RbFiberAsyncParser.new
+
+    # Callbacks that are spawned while the reactor is being replaced must still run
+    threads = 8.times.map { |i| Thread.new { Futures.as_string_using_trait(trait_obj, 50, i) } }
+    3.times { Futures.uniffi_set_fiber_scheduler { MinimalScheduler.new } }
+    assert_equal 8.times.map(&:to_s), threads.map { |thread| thread.join(5)&.value }
+  ensure
+    Futures.uniffi_set_fiber_scheduler
+  end
+
   def now
     Process.clock_gettime Process::CLOCK_MONOTONIC
   end
@@ -440,4 +481,19 @@ class TestFutures < Test::Unit::TestCase
       @completed_delays += 1
     end
   end
+
+  # Records where each callback ran, as `[thread, fiber_is_blocking]` pairs
+  class RbFiberAsyncParser < RbAsyncParser
+    attr_reader :callers
+
+    def initialize
+      super
+      @callers = []
+    end
+
+    def as_string(delay_ms, value)
+      @callers << [Thread.current, Fiber.current.blocking?]
+      super
+    end
+  end
 end
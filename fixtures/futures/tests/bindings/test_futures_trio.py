@@ -0,0 +1,189 @@
+from futures import *
+import os
+import unittest
+from datetime import datetime
+import futures
+
+# CI installs trio and anyio, so these tests are only skipped when running locally without them.
+try:
+    import trio
+except ImportError:
+    if os.environ.get("CI"):
+        raise
+    trio = None
+
+try:
+    import anyio
+except ImportError:
+    if os.environ.get("CI"):
+        raise
+    anyio = None
+
+# The fixture sets `async_runtime = "auto"`, which lets the async functions run under trio as well
+# as asyncio.
+
+def now():
+    return datetime.now()
+
+@unittest.skipIf(trio is None, "trio is not installed")
+class TestFuturesTrio(unittest.TestCase):
+    def test_always_ready(self):
+        async def test():
+            self.assertEqual(await always_ready(), True)
+
+        trio.run(test)
+
+    def test_sleep(self):
+        async def test():
+            t0 = now()
+            await sleep(200)
+            t1 = now()
+
+            t_delta = (t1 - t0).total_seconds()
+            self.assertGreater(t_delta, 0.2)
+
+        trio.run(test)
+
+    def test_concurrent_tasks(self):
+        async def test():
+            results = {}
+
+            async def run(ms, who):
+                results[who] = await say_after(ms, who)
+
+            t0 = now()
+            async with trio.open_nursery() as nursery:
+                nursery.start_soon(run, 100, 'Alice')
+                nursery.start_soon(run, 200, 'Bob')
+            t1 = now()
+
+            self.assertEqual(results, {'Alice': 'Hello, Alice!', 'Bob': 'Hello, Bob!'})
+            t_delta = (t1 - t0).total_seconds()
+            self.assertGreater(t_delta, 0.2)
+            self.assertLess(t_delta, 0.3)
+
+        trio.run(test)
+
+    def test_async_methods(self):
+        async def test():
+            megaphone = new_megaphone()
+            self.assertEqual(await megaphone.say_after(200, 'Alice'), 'HELLO, ALICE!')
+
+        trio.run(test)
+
+    def test_fallible(self):
+        async def test():
+            with self.assertRaises(MyError):
+                await fallible_me(True)
+
+        trio.run(test)
+
+    def test_with_tokio_runtime(self):
+        async def test():
+            self.assertEqual(await say_after_with_tokio(200, 'Alice'), 'Hello, Alice (with Tokio)!')
+
+        trio.run(test)
+
+    def test_cancel(self):
+        async def test():
+            t0 = now()
+            # Cancelling the scope cancels the Rust future, rather than waiting for it to finish
+            with trio.move_on_after(0.1) as cancel_scope:
+                await say_after(2000, 'Alice')
+            t1 = now()
+
+            self.assertTrue(cancel_scope.cancelled_caught)
+            self.assertLess((t1 - t0).total_seconds(), 1)
+            # Wait long enough for the Rust timer to fire.  This shouldn't cause an exception,
+            # even though the call is cancelled.
+            await trio.sleep(0.2)
+
+        trio.run(test)
+
+    def test_shared_resource_cancellation(self):
+        async def test():
+            # The cancelled call must release the lock, otherwise the second call times out
+            with trio.move_on_after(0.05):
+                await use_shared_resource(SharedResourceOptions(release_after_ms=5000, timeout_ms=100))
+            await use_shared_resource(SharedResourceOptions(release_after_ms=0, timeout_ms=1000))
+
+        trio.run(test)
+
+    def test_foreign_async_trait_interface_methods(self):
+        class PyAsyncParser(AsyncParser):
+            def __init__(self):
+                self.completed_delays = 0
+
+            async def as_string(self, delay_ms, value):
+                await trio.sleep(delay_ms / 1000.0)
+                return str(value)
+
+            async def try_from_string(self, delay_ms, value):
+                await trio.sleep(delay_ms / 1000.0)
+                try:
+                    return int(value)
+                except:
+                    raise ParserError.NotAnInt()
+
+            async def delay(self, delay_ms):
+                await trio.sleep(delay_ms / 1000.0)
+                self.completed_delays += 1
+
+            async def try_delay(self, delay_ms):
+                await trio.sleep(int(delay_ms) / 1000.0)
+                self.completed_delays += 1
+
+        async def test():
+            trait_obj = PyAsyncParser()
+            self.assertEqual(await as_string_using_trait(trait_obj, 1, 42), "42")
+            self.assertEqual(await try_from_string_using_trait(trait_obj, 1, "42"), 42)
+            with self.assertRaises(ParserError.NotAnInt):
+                await try_from_string_using_trait(trait_obj, 1, "fourty-two")
+            await delay_using_trait(trait_obj, 1)
+
+            completed_delays_before = trait_obj.completed_delays
+            await cancel_delay_using_trait(trait_obj, 10)
+            # sleep long enough so that the `delay()` call would finish if it wasn't cancelled.
+            await trio.sleep(0.1)
+            # If the task was cancelled, then completed_delays won't have increased
+            self.assertEqual(trait_obj.completed_delays, completed_delays_before)
+
+        trio.run(test)
+        # check that all foreign future handles were released
+        self.assertEqual(len(futures._UNIFFI_FOREIGN_FUTURE_HANDLE_MAP), 0)
+
+    def test_stream(self):
+        async def test():
+            items = [item async for item in count_to(5, 10)]
+            self.assertEqual(items, [0, 1, 2, 3, 4])
+
+        trio.run(test)
+
+    def test_stream_cancellation(self):
+        async def test():
+            stream = ticker(10)
+            self.assertEqual(await stream.__anext__(), 0)
+            # Cancel a pending `__anext__` call, then close the stream
+            with trio.move_on_after(0.001):
+                await stream.__anext__()
+                await stream.__anext__()
+            await stream.aclose()
+            self.assertEqual(active_ticker_count(), 0)
+
+        trio.run(test)
+
+@unittest.skipIf(anyio is None, "anyio is not installed")
+class TestFuturesAnyio(unittest.TestCase):
+    def test_backends(self):
+        async def test():
+            self.assertEqual(await say_after(10, 'Alice'), 'Hello, Alice!')
+            with anyio.move_on_after(0.05) as cancel_scope:
+                await say_after(2000, 'Bob')
+            self.assertTrue(cancel_scope.cancel_called)
+
+        anyio.run(test, backend="asyncio")
+        if trio is not None:
+            anyio.run(test, backend="trio")
+
+if __name__ == '__main__':
+    unittest.main()
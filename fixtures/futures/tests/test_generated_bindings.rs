@@ -1,5 +1,6 @@
 uniffi::build_foreign_language_testcases!(
     "tests/bindings/test_futures.py",
+    "tests/bindings/test_futures_trio.py",
     "tests/bindings/test_futures.swift",
     "tests/bindings/test_futures.kts",
     "tests/bindings/test_futures.rb",
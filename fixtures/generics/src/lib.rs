@@ -0,0 +1,124 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
+pub struct User {
+    pub name: String,
+}
+
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
+pub struct Order {
+    pub id: u32,
+    pub total: f64,
+}
+
+/// A page of results
+#[derive(uniffi::Record)]
+#[uniffi::instantiate(Page<User>, Page<Order> = "OrderPage")]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    #[uniffi(default = None)]
+    pub next_cursor: Option<String>,
+}
+
+/// Outcome of a lookup
+#[derive(uniffi::Enum)]
+#[uniffi::instantiate(Lookup<User> = "UserLookup", Lookup<Order> = "OrderLookup")]
+pub enum Lookup<T> {
+    Found { value: T },
+    Missing { key: String },
+}
+
+/// Generic error
+#[derive(uniffi::Error, Debug, thiserror::Error)]
+#[uniffi::instantiate(StoreError<u32> = "OrderStoreError")]
+pub enum StoreError<K: std::fmt::Debug> {
+    #[error("duplicate key: {key:?}")]
+    Duplicate { key: K },
+}
+
+/// A generic object
+#[derive(uniffi::Object)]
+#[uniffi::instantiate(Store<Order> = "OrderStore")]
+pub struct Store<T> {
+    items: Mutex<Vec<T>>,
+}
+
+impl<T: Clone> Store<T> {
+    fn new() -> Self {
+        Self {
+            items: Mutex::new(vec![]),
+        }
+    }
+
+    fn page(&self, start: u32, len: u32) -> Page<T> {
+        let items = self.items.lock().unwrap();
+        let start = (start as usize).min(items.len());
+        let end = (start + len as usize).min(items.len());
+        Page {
+            items: items[start..end].to_vec(),
+            next_cursor: (end < items.len()).then(|| end.to_string()),
+        }
+    }
+}
+
+/// Methods are exported from a type alias for the instantiation
+type OrderStore = Store<Order>;
+
+#[uniffi::export]
+impl OrderStore {
+    #[uniffi::constructor(name = "new")]
+    fn create() -> Arc<Self> {
+        Arc::new(Self::new())
+    }
+
+    fn add(&self, order: Order) -> Result<(), StoreError<u32>> {
+        let mut items = self.items.lock().unwrap();
+        if items.iter().any(|o| o.id == order.id) {
+            return Err(StoreError::Duplicate { key: order.id });
+        }
+        items.push(order);
+        Ok(())
+    }
+
+    fn get(&self, id: u32) -> Lookup<Order> {
+        match self.items.lock().unwrap().iter().find(|o| o.id == id) {
+            Some(order) => Lookup::Found {
+                value: order.clone(),
+            },
+            None => Lookup::Missing {
+                key: id.to_string(),
+            },
+        }
+    }
+
+    fn list(&self, start: u32, len: u32) -> Page<Order> {
+        self.page(start, len)
+    }
+}
+
+#[uniffi::export]
+fn make_user_page(names: Vec<String>) -> Page<User> {
+    Page {
+        items: names.into_iter().map(|name| User { name }).collect(),
+        next_cursor: None,
+    }
+}
+
+#[uniffi::export]
+fn user_names(page: Page<User>) -> Vec<String> {
+    page.items.into_iter().map(|u| u.name).collect()
+}
+
+#[uniffi::export]
+fn find_user(page: Page<User>, name: String) -> Lookup<User> {
+    match page.items.into_iter().find(|u| u.name == name) {
+        Some(value) => Lookup::Found { value },
+        None => Lookup::Missing { key: name },
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,34 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.generics.*
+
+// Records
+val page: PageUser = makeUserPage(listOf("alice", "bob"))
+assert(page.items == listOf(User("alice"), User("bob")))
+assert(page.nextCursor == null)
+assert(userNames(PageUser(listOf(User("carol")))) == listOf("carol"))
+
+// Enums
+assert(findUser(page, "alice") == UserLookup.Found(User("alice")))
+assert(findUser(page, "carol") == UserLookup.Missing("carol"))
+
+// Objects
+val store = OrderStore()
+for (i in 0u..2u) {
+    store.add(Order(i, i.toDouble() * 1.5))
+}
+try {
+    store.add(Order(1u, 0.0))
+    throw RuntimeException("Should have thrown")
+} catch (e: OrderStoreException.Duplicate) {
+    assert(e.key == 1u)
+}
+
+val orders: OrderPage = store.list(0u, 2u)
+assert(orders.items.map { it.id } == listOf(0u, 1u))
+assert(orders.nextCursor == "2")
+assert(store.list(2u, 2u).nextCursor == null)
+assert(store.get(2u) == OrderLookup.Found(Order(2u, 3.0)))
+assert(store.get(5u) == OrderLookup.Missing("5"))
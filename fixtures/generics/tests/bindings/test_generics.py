@@ -0,0 +1,43 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import unittest
+from uniffi_generics import *
+
+class TestGenerics(unittest.TestCase):
+    def test_records(self):
+        page = make_user_page(["alice", "bob"])
+        self.assertIsInstance(page, PageUser)
+        self.assertEqual(page.items, [User(name="alice"), User(name="bob")])
+        self.assertEqual(page.next_cursor, None)
+        self.assertEqual(user_names(PageUser(items=[User(name="carol")])), ["carol"])
+
+    def test_enums(self):
+        page = make_user_page(["alice"])
+        found = find_user(page, "alice")
+        self.assertTrue(found.is_FOUND())
+        self.assertEqual(found.value, User(name="alice"))
+        missing = find_user(page, "bob")
+        self.assertTrue(missing.is_MISSING())
+        self.assertEqual(missing.key, "bob")
+
+    def test_objects(self):
+        store = OrderStore()
+        for i in range(3):
+            store.add(Order(id=i, total=i * 1.5))
+        with self.assertRaises(OrderStoreError.Duplicate) as cm:
+            store.add(Order(id=1, total=0.0))
+        self.assertEqual(cm.exception.key, 1)
+
+        page = store.list(0, 2)
+        self.assertIsInstance(page, OrderPage)
+        self.assertEqual([o.id for o in page.items], [0, 1])
+        self.assertEqual(page.next_cursor, "2")
+        self.assertEqual(store.list(2, 2).next_cursor, None)
+
+        self.assertEqual(store.get(2), OrderLookup.FOUND(Order(id=2, total=3.0)))
+        self.assertEqual(store.get(5), OrderLookup.MISSING("5"))
+
+if __name__=='__main__':
+    unittest.main()
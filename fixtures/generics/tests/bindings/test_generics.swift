@@ -0,0 +1,35 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_generics
+
+// Records
+let page: PageUser = makeUserPage(names: ["alice", "bob"])
+assert(page.items == [User(name: "alice"), User(name: "bob")])
+assert(page.nextCursor == nil)
+assert(userNames(page: PageUser(items: [User(name: "carol")])) == ["carol"])
+
+// Enums
+assert(findUser(page: page, name: "alice") == .found(value: User(name: "alice")))
+assert(findUser(page: page, name: "carol") == .missing(key: "carol"))
+
+// Objects
+let store = OrderStore()
+for i in UInt32(0)..<3 {
+    try! store.add(order: Order(id: i, total: Double(i) * 1.5))
+}
+do {
+    try store.add(order: Order(id: 1, total: 0.0))
+    fatalError("Should have thrown")
+} catch OrderStoreError.Duplicate(let key) {
+    assert(key == 1)
+}
+
+let orders: OrderPage = store.list(start: 0, len: 2)
+assert(orders.items.map { $0.id } == [0, 1])
+assert(orders.nextCursor == "2")
+assert(store.list(start: 2, len: 2).nextCursor == nil)
+assert(store.get(id: 2) == .found(value: Order(id: 2, total: 3.0)))
+assert(store.get(id: 5) == .missing(key: "5"))
@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_generics.kts",
+    "tests/bindings/test_generics.swift",
+    "tests/bindings/test_generics.py",
+);
@@ -0,0 +1,74 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[uniffi::export]
+pub fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
+
+#[derive(uniffi::Record)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[uniffi::export]
+pub fn add_points(a: Point, b: Point) -> Point {
+    Point {
+        x: a.x + b.x,
+        y: a.y + b.y,
+    }
+}
+
+#[derive(uniffi::Enum)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Polygon { points: Vec<Point> },
+}
+
+#[uniffi::export]
+pub fn point_count(shape: Shape) -> u32 {
+    match shape {
+        Shape::Circle { .. } => 0,
+        Shape::Polygon { points } => points.len() as u32,
+    }
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum CounterError {
+    #[error("Counter overflowed at {value}")]
+    Overflow { value: u32 },
+}
+
+#[derive(uniffi::Object)]
+pub struct Counter {
+    value: AtomicU32,
+}
+
+#[uniffi::export]
+impl Counter {
+    #[uniffi::constructor]
+    pub fn new(value: u32) -> Self {
+        Self {
+            value: AtomicU32::new(value),
+        }
+    }
+
+    pub fn increment(&self) -> Result<u32, CounterError> {
+        let value = self.value.load(Ordering::SeqCst);
+        let next = value
+            .checked_add(1)
+            .ok_or(CounterError::Overflow { value })?;
+        self.value.store(next, Ordering::SeqCst);
+        Ok(next)
+    }
+
+    pub fn get(&self) -> u32 {
+        self.value.load(Ordering::SeqCst)
+    }
+}
+
+uniffi::setup_scaffolding!();
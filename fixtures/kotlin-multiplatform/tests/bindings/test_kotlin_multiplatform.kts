@@ -0,0 +1,30 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.kotlin_multiplatform.*
+
+// The bindings were compiled from the `commonMain` and `jvmMain` source sets, so these calls go
+// through the `expect` declarations and their JNA actuals.
+assert(greet("Kotlin") == "Hello, Kotlin!")
+
+val sum = addPoints(Point(1.0, 2.0), Point(3.0, 4.0))
+assert(sum == Point(4.0, 6.0))
+
+assert(pointCount(Shape.Circle(1.0)) == 0u)
+assert(pointCount(Shape.Polygon(listOf(Point(0.0, 0.0), Point(1.0, 0.0), Point(0.0, 1.0)))) == 3u)
+
+Counter(1u).use { counter ->
+    assert(counter.increment() == 2u)
+    assert(counter.get() == 2u)
+}
+
+Counter(UInt.MAX_VALUE).use { counter ->
+    try {
+        counter.increment()
+        throw RuntimeException("Should have thrown an overflow error")
+    } catch (e: CounterException.Overflow) {
+        assert(e.value == UInt.MAX_VALUE)
+    }
+    assert(counter.get() == UInt.MAX_VALUE)
+}
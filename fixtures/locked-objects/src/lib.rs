@@ -0,0 +1,135 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::{
+    cell::Cell,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use uniffi::ObjectLock;
+
+/// Counter wrapped in a mutex
+#[derive(uniffi::Object)]
+#[uniffi(mutex)]
+pub struct Counter {
+    value: u64,
+    // `Cell` isn't `Sync`, which is fine since the mutex never shares the counter between threads
+    reads: Cell<u32>,
+}
+
+#[uniffi::export]
+impl Counter {
+    #[uniffi::constructor]
+    pub fn new(value: u64) -> Self {
+        Self {
+            value,
+            reads: Cell::new(0),
+        }
+    }
+
+    pub fn get(&self) -> u64 {
+        self.reads.set(self.reads.get() + 1);
+        self.value
+    }
+
+    pub fn reads(&self) -> u32 {
+        self.reads.get()
+    }
+
+    pub fn increment(&mut self) -> u64 {
+        self.value += 1;
+        self.value
+    }
+
+    /// Add the value of another counter, which gets locked for the call too
+    pub fn add(&mut self, other: &Counter) {
+        self.value += other.value;
+    }
+
+    /// Set the value to the result of a foreign closure
+    pub fn update(&mut self, f: Box<dyn Fn(u64) -> u64 + Send + Sync>) {
+        self.value = f(self.value);
+    }
+
+    /// Create a new counter, starting at the current value
+    pub fn fork(&self) -> Counter {
+        Counter::new(self.value)
+    }
+}
+
+#[uniffi::export]
+pub fn counter_value(counter: &Counter) -> u64 {
+    counter.value
+}
+
+#[uniffi::export]
+pub fn reset_counter(counter: Arc<ObjectLock<Counter>>) -> Arc<ObjectLock<Counter>> {
+    counter.write().expect("counter is locked").value = 0;
+    counter
+}
+
+/// Future that's pending the first time it's polled, like one waiting for I/O
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// Increment a counter before and after suspending
+///
+/// Async functions can't borrow objects with a lock, since the lock would be held while they're
+/// suspended.  Taking the `ObjectLock` works, as long as the lock isn't held across an `.await`.
+#[uniffi::export]
+pub async fn increment_twice(counter: Arc<ObjectLock<Counter>>) -> u64 {
+    counter.write().expect("counter is locked").value += 1;
+    YieldNow(false).await;
+    let mut counter = counter.write().expect("counter is locked");
+    counter.value += 1;
+    counter.value
+}
+
+/// List of notes wrapped in a read-write lock
+#[derive(Default, uniffi::Object)]
+#[uniffi(rwlock)]
+pub struct Notes {
+    notes: Vec<String>,
+}
+
+#[uniffi::export]
+impl Notes {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, note: String) {
+        self.notes.push(note);
+    }
+
+    pub fn notes(&self) -> Vec<String> {
+        self.notes.clone()
+    }
+
+    /// Call a foreign closure for each note, while holding a read lock
+    pub fn for_each(&self, f: Box<dyn Fn(String) + Send + Sync>) {
+        for note in &self.notes {
+            f(note.clone());
+        }
+    }
+}
+
+uniffi::setup_scaffolding!();
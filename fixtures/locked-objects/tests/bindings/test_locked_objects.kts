@@ -0,0 +1,57 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.uniffi_locked_objects.*
+import kotlinx.coroutines.*
+
+// Objects with a mutex
+val counter = Counter(1uL)
+assert(counter.increment() == 2uL)
+assert(counter.get() == 2uL)
+assert(counter.reads() == 1u)
+counter.add(Counter(3uL))
+assert(counterValue(counter) == 5uL)
+val fork = counter.fork()
+fork.increment()
+assert(counter.get() == 5uL)
+assert(fork.get() == 6uL)
+assert(resetCounter(fork).get() == 0uL)
+
+// The counter isn't locked while an async call on it is suspended, so calls from the same thread
+// still work
+runBlocking {
+    val pending = async { incrementTwice(counter) }
+    yield()
+    assert(counter.increment() == 7uL)
+    assert(pending.await() == 8uL)
+}
+
+// Concurrent calls are serialized
+val shared = Counter(0uL)
+val threads = (1..4).map {
+    Thread {
+        repeat(1000) { shared.increment() }
+    }
+}
+threads.forEach { it.start() }
+threads.forEach { it.join() }
+assert(shared.get() == 4000uL)
+
+// `add` locks both counters, which would deadlock if they're the same object
+try {
+    counter.add(counter)
+    throw RuntimeException("Should have thrown an InternalException")
+} catch (e: InternalException) {
+    // Expected
+}
+assert(counter.get() == 8uL)
+
+// Objects with a read-write lock
+val notes = Notes()
+notes.add("one")
+notes.add("two")
+assert(notes.notes() == listOf("one", "two"))
+val seen = mutableListOf<Pair<String, Int>>()
+notes.forEach { seen.add(Pair(it, notes.notes().size)) }
+assert(seen == listOf(Pair("one", 2), Pair("two", 2)))
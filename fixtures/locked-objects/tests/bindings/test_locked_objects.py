@@ -0,0 +1,78 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import asyncio
+import threading
+import unittest
+from uniffi_locked_objects import *
+
+class TestLockedObjects(unittest.TestCase):
+    def test_mutex(self):
+        counter = Counter(1)
+        self.assertEqual(counter.increment(), 2)
+        self.assertEqual(counter.get(), 2)
+        self.assertEqual(counter.reads(), 1)
+        counter.add(Counter(3))
+        self.assertEqual(counter_value(counter), 5)
+        fork = counter.fork()
+        fork.increment()
+        self.assertEqual(counter.get(), 5)
+        self.assertEqual(fork.get(), 6)
+        self.assertEqual(reset_counter(fork).get(), 0)
+
+    def test_threads(self):
+        counter = Counter(0)
+        def increment():
+            for _ in range(1000):
+                counter.increment()
+        threads = [threading.Thread(target=increment) for _ in range(4)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+        self.assertEqual(counter.get(), 4000)
+
+    def test_async_call_pending(self):
+        async def test():
+            counter = Counter(0)
+            task = asyncio.create_task(increment_twice(counter))
+            # Let the task run until it's suspended, then call the counter from the same thread
+            await asyncio.sleep(0)
+            self.assertEqual(counter.increment(), 2)
+            self.assertEqual(await task, 3)
+
+        asyncio.run(test())
+
+    def test_reentrancy(self):
+        counter = Counter(1)
+        # `add` locks both counters, which deadlocks if they're the same object
+        with self.assertRaisesRegex(InternalError, "already locked by this thread"):
+            counter.add(counter)
+        self.assertEqual(counter.get(), 1)
+
+        # Calling back into the counter from the closure fails, which makes the closure throw and
+        # Rust panic while holding the lock
+        with self.assertRaises(InternalError):
+            counter.update(lambda value: counter.get())
+        with self.assertRaisesRegex(InternalError, "poisoned"):
+            counter.get()
+
+    def test_rwlock(self):
+        notes = Notes()
+        notes.add("one")
+        notes.add("two")
+        self.assertEqual(notes.notes(), ["one", "two"])
+
+        # Nested `&self` calls are fine
+        seen = []
+        notes.for_each(lambda note: seen.append((note, len(notes.notes()))))
+        self.assertEqual(seen, [("one", 2), ("two", 2)])
+
+        # `&mut self` calls aren't, but they don't poison the object
+        with self.assertRaises(InternalError):
+            notes.for_each(lambda note: notes.add(note))
+        self.assertEqual(notes.notes(), ["one", "two"])
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,56 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_locked_objects
+
+final class Recorder<T>: @unchecked Sendable {
+    private let lock = NSLock()
+    private var items: [T] = []
+
+    func record(_ item: T) {
+        lock.withLock { items.append(item) }
+    }
+
+    var recorded: [T] {
+        lock.withLock { items }
+    }
+}
+
+// Objects with a mutex
+do {
+    let counter = Counter(value: 1)
+    assert(counter.increment() == 2)
+    assert(counter.get() == 2)
+    assert(counter.reads() == 1)
+    counter.add(other: Counter(value: 3))
+    assert(counterValue(counter: counter) == 5)
+    let fork = counter.fork()
+    _ = fork.increment()
+    assert(counter.get() == 5)
+    assert(fork.get() == 6)
+    assert(resetCounter(counter: fork).get() == 0)
+}
+
+// Concurrent calls are serialized
+do {
+    let counter = Counter(value: 0)
+    DispatchQueue.concurrentPerform(iterations: 4) { _ in
+        for _ in 0..<1000 {
+            _ = counter.increment()
+        }
+    }
+    assert(counter.get() == 4000)
+}
+
+// Objects with a read-write lock
+do {
+    let notes = Notes()
+    notes.add(note: "one")
+    notes.add(note: "two")
+    assert(notes.notes() == ["one", "two"])
+    let seen = Recorder<String>()
+    notes.forEach(f: { note in seen.record("\(note): \(notes.notes().count)") })
+    assert(seen.recorded == ["one: 2", "two: 2"])
+}
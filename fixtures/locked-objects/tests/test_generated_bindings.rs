@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_locked_objects.kts",
+    "tests/bindings/test_locked_objects.swift",
+    "tests/bindings/test_locked_objects.py",
+);
@@ -0,0 +1,49 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::time::{Duration, SystemTime};
+
+#[uniffi::export]
+fn return_timestamp(a: SystemTime) -> SystemTime {
+    a
+}
+
+#[uniffi::export]
+fn return_duration(a: Duration) -> Duration {
+    a
+}
+
+#[uniffi::export]
+fn add(a: SystemTime, b: Duration) -> SystemTime {
+    a + b
+}
+
+/// 1.000000001 seconds before the epoch
+#[uniffi::export]
+fn get_pre_epoch_timestamp() -> SystemTime {
+    SystemTime::UNIX_EPOCH - Duration::new(1, 1)
+}
+
+/// Nanoseconds since the epoch, computed by Rust so the bindings can check their conversions
+#[uniffi::export]
+fn nanos_since_epoch(a: SystemTime) -> i64 {
+    match a.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(d) => d.as_nanos() as i64,
+        Err(e) => -(e.duration().as_nanos() as i64),
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct Event {
+    name: String,
+    at: SystemTime,
+    took: Option<Duration>,
+}
+
+#[uniffi::export]
+fn return_event(e: Event) -> Event {
+    e
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,51 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import datetime
+from uniffi_lossless_time import *
+
+# Nanoseconds survive the round-trip
+ts = Timestamp(seconds=1_700_000_000, nanoseconds=123_456_789)
+assert return_timestamp(ts) == ts
+assert nanos_since_epoch(ts) == 1_700_000_000_123_456_789
+assert Timestamp.from_ns(ts.to_ns()) == ts
+
+d = Duration(seconds=5, nanoseconds=1)
+assert return_duration(d) == d
+assert add(ts, d) == Timestamp(seconds=1_700_000_005, nanoseconds=123_456_790)
+
+# Times before the epoch count the nanoseconds forwards
+pre_epoch = get_pre_epoch_timestamp()
+assert pre_epoch == Timestamp(seconds=-2, nanoseconds=999_999_999)
+assert pre_epoch.to_ns() == -1_000_000_001
+assert nanos_since_epoch(pre_epoch) == -1_000_000_001
+assert return_timestamp(pre_epoch) == pre_epoch
+assert pre_epoch < ts
+
+# Conversions to and from the datetime types
+dt = datetime.datetime(2023, 11, 14, 22, 13, 20, 123456, tzinfo=datetime.timezone.utc)
+assert Timestamp.from_datetime(dt) == Timestamp(seconds=1_700_000_000, nanoseconds=123_456_000)
+assert ts.to_datetime() == dt
+assert Duration.from_timedelta(datetime.timedelta(seconds=1, microseconds=2)) == Duration(1, 2000)
+
+event = Event(name="start", at=ts, took=d)
+assert return_event(event) == event
+
+try:
+    return_timestamp(dt)
+    raise Exception("datetime should be rejected")
+except TypeError:
+    pass
+
+try:
+    return_duration(Duration(seconds=-1, nanoseconds=0))
+    raise Exception("negative durations should be rejected")
+except ValueError:
+    pass
+
+try:
+    return_duration(Duration(seconds=0, nanoseconds=1_000_000_000))
+    raise Exception("out of range nanoseconds should be rejected")
+except ValueError:
+    pass
@@ -0,0 +1,33 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_lossless_time
+
+// Nanoseconds survive the round-trip
+let ts = UniffiTimestamp(seconds: 1_700_000_000, nanoseconds: 123_456_789)
+assert(returnTimestamp(a: ts) == ts)
+assert(nanosSinceEpoch(a: ts) == 1_700_000_000_123_456_789)
+
+let d = UniffiDuration(seconds: 5, nanoseconds: 1)
+assert(returnDuration(a: d) == d)
+assert(add(a: ts, b: d) == UniffiTimestamp(seconds: 1_700_000_005, nanoseconds: 123_456_790))
+
+// Times before the epoch count the nanoseconds forwards
+let preEpoch = getPreEpochTimestamp()
+assert(preEpoch == UniffiTimestamp(seconds: -2, nanoseconds: 999_999_999))
+assert(nanosSinceEpoch(a: preEpoch) == -1_000_000_001)
+assert(returnTimestamp(a: preEpoch) == preEpoch)
+assert(preEpoch < ts)
+
+// Conversions to and from the Foundation types
+assert(UniffiTimestamp(Date(timeIntervalSince1970: -0.5)) == UniffiTimestamp(seconds: -1, nanoseconds: 500_000_000))
+assert(UniffiTimestamp(seconds: 1, nanoseconds: 500_000_000).date == Date(timeIntervalSince1970: 1.5))
+assert(UniffiDuration(2.25) == UniffiDuration(seconds: 2, nanoseconds: 250_000_000))
+assert(UniffiDuration(seconds: 2, nanoseconds: 250_000_000).timeInterval == 2.25)
+
+let event = Event(name: "start", at: ts, took: d)
+let returned = returnEvent(e: event)
+assert(returned.at == ts)
+assert(returned.took == d)
@@ -0,0 +1,4 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_lossless_time.swift",
+    "tests/bindings/test_lossless_time.py",
+);
@@ -87,6 +87,16 @@ mod error {
     }
 }
 
+mod page {
+    use super::Person;
+
+    #[derive(uniffi::Record, Debug)]
+    #[uniffi::instantiate(Page<Person> = "PersonPage", Page<u8>)]
+    pub struct Page<T> {
+        items: Vec<T>,
+    }
+}
+
 mod calc {
     #[derive(uniffi::Object)]
     #[uniffi(name = "CalculatorRenamed")]
@@ -126,6 +136,7 @@ impl Logger for RealLogger {
 
 pub use calc::Calculator;
 pub use error::FlatError;
+pub use page::Page;
 pub use person::Person;
 pub use state::State;
 
@@ -178,6 +189,14 @@ mod test_type_ids {
             name: "CalculatorRenamed".into(),
             imp: ObjectImpl::Struct,
         });
+        check_type_id::<Page<Person>>(Type::Record {
+            module_path: "uniffi_fixture_metadata::tests::page".into(),
+            name: "PersonPage".into(),
+        });
+        check_type_id::<Page<u8>>(Type::Record {
+            module_path: "uniffi_fixture_metadata::tests::page".into(),
+            name: "PageU8".into(),
+        });
     }
 
     #[test]
@@ -217,6 +236,7 @@ mod test_metadata {
                 module_path: "uniffi_fixture_metadata::tests::person".into(),
                 name: "PersonRenamed".into(),
                 orig_name: Some("Person".into()),
+                generic: None,
                 remote: false,
                 fields: vec![
                     FieldMetadata {
@@ -250,6 +270,60 @@ mod test_metadata {
         );
     }
 
+    #[test]
+    fn test_generic_record() {
+        check_metadata(
+            &page::UNIFFI_META_UNIFFI_FIXTURE_METADATA_RECORD_PERSONPAGE,
+            RecordMetadata {
+                module_path: "uniffi_fixture_metadata::tests::page".into(),
+                name: "PersonPage".into(),
+                orig_name: None,
+                generic: Some(GenericInstanceMetadata {
+                    name: "Page".into(),
+                    args: vec![Type::Record {
+                        module_path: "uniffi_fixture_metadata::tests::person".into(),
+                        name: "PersonRenamed".into(),
+                    }],
+                }),
+                remote: false,
+                fields: vec![FieldMetadata {
+                    name: "items".into(),
+                    orig_name: None,
+                    ty: Type::Sequence {
+                        inner_type: Box::new(Type::Record {
+                            module_path: "uniffi_fixture_metadata::tests::person".into(),
+                            name: "PersonRenamed".into(),
+                        }),
+                    },
+                    default: None,
+                    docstring: None,
+                }],
+                docstring: None,
+            },
+        );
+        check_metadata(
+            &page::UNIFFI_META_UNIFFI_FIXTURE_METADATA_RECORD_PAGEU8,
+            RecordMetadata {
+                module_path: "uniffi_fixture_metadata::tests::page".into(),
+                name: "PageU8".into(),
+                orig_name: None,
+                generic: Some(GenericInstanceMetadata {
+                    name: "Page".into(),
+                    args: vec![Type::UInt8],
+                }),
+                remote: false,
+                fields: vec![FieldMetadata {
+                    name: "items".into(),
+                    orig_name: None,
+                    ty: Type::Bytes,
+                    default: None,
+                    docstring: None,
+                }],
+                docstring: None,
+            },
+        );
+    }
+
     #[test]
     fn test_simple_enum() {
         check_metadata(
@@ -258,6 +332,7 @@ mod test_metadata {
                 module_path: "uniffi_fixture_metadata::tests::weapon".into(),
                 name: "WeaponRenamed".into(),
                 orig_name: Some("Weapon".into()),
+                generic: None,
                 shape: EnumShape::Enum,
                 remote: false,
                 discr_type: None,
@@ -298,6 +373,7 @@ mod test_metadata {
                 module_path: "uniffi_fixture_metadata::tests::state".into(),
                 name: "State".into(),
                 orig_name: None,
+                generic: None,
                 shape: EnumShape::Enum,
                 remote: false,
                 discr_type: None,
@@ -353,6 +429,7 @@ mod test_metadata {
                 module_path: "uniffi_fixture_metadata::tests::enum_repr".into(),
                 name: "ReprU8".into(),
                 orig_name: None,
+                generic: None,
                 shape: EnumShape::Enum,
                 remote: false,
                 discr_type: Some(Type::UInt8),
@@ -393,6 +470,7 @@ mod test_metadata {
                 module_path: "uniffi_fixture_metadata::tests::enum_repr".into(),
                 name: "NoRepr".into(),
                 orig_name: None,
+                generic: None,
                 shape: EnumShape::Enum,
                 remote: false,
                 discr_type: None,
@@ -427,6 +505,7 @@ mod test_metadata {
                     state::uniffi_uniffi_fixture_metadata_checksum_method_state_state_method_renamed(),
                 ),
                 docstring: None,
+                property: None,
             },
         );
     }
@@ -439,6 +518,7 @@ mod test_metadata {
                 module_path: "uniffi_fixture_metadata::tests::error".into(),
                 name: "FlatError".into(),
                 orig_name: None,
+                generic: None,
                 shape: EnumShape::Error { flat: true },
                 remote: false,
                 discr_type: None,
@@ -472,6 +552,7 @@ mod test_metadata {
                 module_path: "uniffi_fixture_metadata::tests::error".into(),
                 name: "ComplexError".into(),
                 orig_name: None,
+                generic: None,
                 shape: EnumShape::Error { flat: false },
                 remote: false,
                 discr_type: None,
@@ -527,6 +608,7 @@ mod test_metadata {
                 module_path: "uniffi_fixture_metadata::tests::calc".into(),
                 name: "CalculatorRenamed".into(),
                 orig_name: Some("Calculator".into()),
+                generic: None,
                 remote: false,
                 imp: ObjectImpl::Struct,
                 docstring: None,
@@ -843,6 +925,7 @@ mod test_function_metadata {
                         .checksum(),
                 ),
                 docstring: None,
+                property: None,
             },
         );
     }
@@ -969,6 +1052,7 @@ mod test_function_metadata {
                         .checksum(),
                 ),
                 docstring: None,
+                property: None,
             },
         );
     }
@@ -981,6 +1065,7 @@ mod test_function_metadata {
                 module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
                 name: "CalculatorDisplay".into(),
                 orig_name: None,
+                generic: None,
                 remote: false,
                 imp: ObjectImpl::Trait(TraitKind::RustOnly),
                 docstring: None,
@@ -996,6 +1081,7 @@ mod test_function_metadata {
                 module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
                 name: "TraitWithForeign".into(),
                 orig_name: None,
+                generic: None,
                 remote: false,
                 imp: ObjectImpl::Trait(TraitKind::Both),
                 docstring: None,
@@ -1027,6 +1113,7 @@ mod test_function_metadata {
                         .checksum(),
                 ),
                 docstring: None,
+                property: None,
             },
         );
     }
@@ -1128,6 +1215,7 @@ mod test_function_metadata {
                 module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
                 name: "TraitExplicitRust".into(),
                 orig_name: None,
+                generic: None,
                 remote: false,
                 imp: ObjectImpl::Trait(TraitKind::RustOnly),
                 docstring: None,
@@ -1169,6 +1257,7 @@ mod test_function_metadata {
                 module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
                 name: "TraitImplsAny".into(),
                 orig_name: None,
+                generic: None,
                 remote: false,
                 imp: ObjectImpl::Trait(TraitKind::Both),
                 docstring: None,
@@ -1209,6 +1298,7 @@ mod test_function_metadata {
                 module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
                 name: "TraitImplsForeign".into(),
                 orig_name: None,
+                generic: None,
                 remote: false,
                 imp: ObjectImpl::Trait(TraitKind::ForeignOnly),
                 docstring: None,
@@ -1249,6 +1339,7 @@ mod test_function_metadata {
                 module_path: "uniffi_fixture_metadata::tests".into(),
                 name: "RealLogger".into(),
                 orig_name: None,
+                generic: None,
                 imp: ObjectImpl::Struct,
                 remote: false,
                 docstring: None,
@@ -4,7 +4,7 @@
 
 use std::{
     collections::{HashMap, HashSet},
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 
 mod callback_interface;
@@ -51,6 +51,17 @@ pub struct Three {
     obj: Arc<Object>,
 }
 
+#[derive(uniffi::Record)]
+pub struct RecordWithTuple {
+    point: (i32, i32),
+}
+
+#[derive(uniffi::Record)]
+pub struct RecordWithArray {
+    key: [u8; 4],
+    names: [String; 2],
+}
+
 #[derive(uniffi::Record, Debug, PartialEq)]
 pub struct RecordWithBytes {
     some_bytes: Vec<u8>,
@@ -138,6 +149,15 @@ impl Object {
         Self
     }
 
+    // No receiver, so this is exported as a static method
+    fn default_heaviness(verbose: bool) -> MaybeBool {
+        if verbose {
+            MaybeBool::Uncertain
+        } else {
+            MaybeBool::False
+        }
+    }
+
     fn is_heavy(&self) -> MaybeBool {
         MaybeBool::Uncertain
     }
@@ -163,11 +183,57 @@ impl Object {
     }
 }
 
+#[derive(uniffi::Object)]
+pub struct Thermostat {
+    target: Mutex<i32>,
+}
+
+#[uniffi::export]
+impl Thermostat {
+    #[uniffi::constructor]
+    fn new() -> Self {
+        Self {
+            target: Mutex::new(20),
+        }
+    }
+
+    /// The target temperature, exposed as a read/write property
+    #[uniffi::getter]
+    fn get_target(&self) -> i32 {
+        *self.target.lock().unwrap()
+    }
+
+    #[uniffi::setter]
+    fn set_target(&self, target: i32) {
+        *self.target.lock().unwrap() = target;
+    }
+
+    // Read-only property
+    #[uniffi::getter]
+    fn unit(&self) -> String {
+        "celsius".to_string()
+    }
+
+    fn is_heating(&self, current: i32) -> bool {
+        current < self.get_target()
+    }
+}
+
 #[uniffi::export]
 fn concat_strings_by_ref(t: &dyn Trait, a: &str, b: &str) -> String {
     t.concat_strings(a, b)
 }
 
+/// How many times to retry
+#[uniffi::export]
+pub const MAX_RETRIES: u32 = 5;
+
+#[uniffi::export]
+pub const DEFAULT_GREETING: &str = "hello";
+
+#[uniffi::export]
+pub const DEFAULT_OFFSET: Option<i32> = Some(-1);
+
 #[uniffi::export]
 fn make_one(inner: i32) -> One {
     One { inner }
@@ -198,6 +264,42 @@ fn return_hash_set(h: HashSet<String>) -> HashSet<String> {
     h
 }
 
+#[uniffi::export]
+fn make_pair(name: String, count: u32) -> (String, u32) {
+    (name, count)
+}
+
+#[uniffi::export]
+fn reverse_tuple4(t: (u8, String, Option<bool>, Vec<i32>)) -> (Vec<i32>, Option<bool>, String, u8) {
+    (t.3, t.2, t.1, t.0)
+}
+
+#[uniffi::export]
+fn swap_point(r: RecordWithTuple) -> RecordWithTuple {
+    RecordWithTuple {
+        point: (r.point.1, r.point.0),
+    }
+}
+
+#[uniffi::export]
+fn xor_key(key: [u8; 4], mask: [u8; 4]) -> [u8; 4] {
+    std::array::from_fn(|i| key[i] ^ mask[i])
+}
+
+#[uniffi::export]
+fn reverse_array(a: [i32; 3]) -> [i32; 3] {
+    [a[2], a[1], a[0]]
+}
+
+#[uniffi::export]
+fn swap_names(r: RecordWithArray) -> RecordWithArray {
+    let [a, b] = r.names;
+    RecordWithArray {
+        key: r.key,
+        names: [b, a],
+    }
+}
+
 #[uniffi::export]
 fn take_record_with_bytes(rwb: RecordWithBytes) -> Vec<u8> {
     rwb.some_bytes
@@ -248,6 +350,21 @@ fn make_record_with_bytes() -> RecordWithBytes {
     }
 }
 
+#[uniffi::export]
+fn make_owned_bytes(len: u8) -> uniffi::OwnedBytes {
+    (0..len).collect::<Vec<u8>>().into()
+}
+
+#[uniffi::export]
+fn sum_owned_bytes(bytes: uniffi::OwnedBytes) -> u32 {
+    bytes.iter().map(|b| *b as u32).sum()
+}
+
+#[uniffi::export]
+fn roundtrip_optional_owned_bytes(bytes: Option<uniffi::OwnedBytes>) -> Option<uniffi::OwnedBytes> {
+    bytes
+}
+
 #[derive(uniffi::Enum)]
 pub enum MaybeBool {
     True,
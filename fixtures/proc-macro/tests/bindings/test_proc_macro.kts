@@ -20,6 +20,19 @@ obj = Object.namedCtor(1u)
 assert(obj.isHeavy() == MaybeBool.UNCERTAIN)
 var obj2 = Object()
 assert(obj.isOtherHeavy(obj2) == MaybeBool.UNCERTAIN)
+assert(Object.defaultHeaviness(true) == MaybeBool.UNCERTAIN)
+assert(Object.defaultHeaviness(false) == MaybeBool.FALSE)
+
+val thermostat = Thermostat()
+assert(thermostat.target == 20)
+assert(thermostat.unit == "celsius")
+thermostat.target = 25
+assert(thermostat.target == 25)
+assert(thermostat.isHeating(22))
+
+assert(MAX_RETRIES == 5u)
+assert(DEFAULT_GREETING == "hello")
+assert(DEFAULT_OFFSET == -1)
 
 assert(enumIdentity(MaybeBool.TRUE) == MaybeBool.TRUE)
 assert(MaybeBool.TRUE.next() == MaybeBool.FALSE)
@@ -31,9 +44,32 @@ val three = Three(obj)
 assert(makeZero().inner == "ZERO")
 assert(makeRecordWithBytes().someBytes.contentEquals(byteArrayOf(0, 1, 2, 3, 4)))
 
+// OwnedBytes are lifted as a direct ByteBuffer over the Rust buffer
+val owned = makeOwnedBytes(5u)
+assert(owned.isDirect())
+assert(owned.remaining() == 5)
+assert(owned.get(4) == 4.toByte())
+assert(makeOwnedBytes(0u).remaining() == 0)
+assert(sumOwnedBytes(java.nio.ByteBuffer.wrap(byteArrayOf(1, 2, 3))) == 6u)
+assert(sumOwnedBytes(owned) == 10u)
+assert(roundtripOptionalOwnedBytes(java.nio.ByteBuffer.wrap(byteArrayOf(7)))!!.get(0) == 7.toByte())
+assert(roundtripOptionalOwnedBytes(null) == null)
+
 assert(makeHashSet("hello") == setOf("hello"))
 val hashSet: Set<String> = setOf("a", "b", "c")
 assert(returnHashSet(hashSet) == hashSet)
+assert(makePair("a", 1u) == Pair("a", 1u))
+assert(reverseTuple4(Tuple4(1u, "two", null, listOf(4))) == Tuple4(listOf(4), null, "two", 1u))
+assert(swapPoint(RecordWithTuple(Pair(1, 2))).point == Pair(2, 1))
+assert(xorKey(byteArrayOf(1, 2, 3, 4), byteArrayOf(-1, 0, -1, 0)).contentEquals(byteArrayOf(-2, 2, -4, 4)))
+assert(reverseArray(listOf(1, 2, 3)) == listOf(3, 2, 1))
+assert(swapNames(RecordWithArray(byteArrayOf(1, 2, 3, 4), listOf("a", "b"))).names == listOf("b", "a"))
+try {
+    reverseArray(listOf(1, 2, 3, 4))
+    throw RuntimeException("Lowering a long array should fail")
+} catch (e: IllegalArgumentException) {
+    // Expected
+}
 assert(join(listOf("a", "b", "c"), ":") == "a:b:c")
 
 try {
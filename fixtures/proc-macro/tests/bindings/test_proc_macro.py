@@ -21,6 +21,24 @@ obj = Object.named_ctor(1)
 assert obj.is_heavy() == MaybeBool.UNCERTAIN
 obj2 = Object()
 assert obj.is_other_heavy(obj2) == MaybeBool.UNCERTAIN
+assert Object.default_heaviness(True) == MaybeBool.UNCERTAIN
+assert Object.default_heaviness(False) == MaybeBool.FALSE
+
+thermostat = Thermostat()
+assert thermostat.target == 20
+assert thermostat.unit == "celsius"
+thermostat.target = 25
+assert thermostat.target == 25
+assert thermostat.is_heating(22)
+try:
+    thermostat.unit = "fahrenheit"
+    raise AssertionError("unit should be read-only")
+except AttributeError:
+    pass
+
+assert MAX_RETRIES == 5
+assert DEFAULT_GREETING == "hello"
+assert DEFAULT_OFFSET == -1
 
 robj = Renamed()
 assert(robj.func())
@@ -47,6 +65,17 @@ three = Three(obj=obj)
 assert(make_zero().inner == "ZERO")
 assert(make_record_with_bytes().some_bytes == bytes([0, 1, 2, 3, 4]))
 
+# OwnedBytes are lifted as a memoryview over the Rust buffer
+owned = make_owned_bytes(5)
+assert isinstance(owned, memoryview)
+assert owned == bytes([0, 1, 2, 3, 4])
+assert len(make_owned_bytes(0)) == 0
+assert sum_owned_bytes(b"\x01\x02\x03") == 6
+assert sum_owned_bytes(bytearray([1, 2, 3])) == 6
+assert sum_owned_bytes(owned) == 10
+assert roundtrip_optional_owned_bytes(b"\x07") == b"\x07"
+assert roundtrip_optional_owned_bytes(None) is None
+
 assert(make_hashmap(1, 2) == {1: 2})
 d = {1: 2}
 assert(return_hashmap(d) == d)
@@ -55,6 +84,24 @@ assert(make_hash_set("hello") == {"hello"})
 s = {"a", "b", "c"}
 assert(return_hash_set(s) == s)
 
+assert make_pair("a", 1) == ("a", 1)
+assert reverse_tuple4((1, "two", None, [4])) == ([4], None, "two", 1)
+assert swap_point(RecordWithTuple(point=(1, 2))).point == (2, 1)
+
+assert xor_key(b"\x01\x02\x03\x04", b"\xff\x00\xff\x00") == b"\xfe\x02\xfc\x04"
+assert reverse_array([1, 2, 3]) == [3, 2, 1]
+assert swap_names(RecordWithArray(key=b"abcd", names=["a", "b"])).names == ["b", "a"]
+try:
+    xor_key(b"\x01", b"\x00\x00\x00\x00")
+    raise Exception("Lowering a short array should fail")
+except ValueError:
+    pass
+try:
+    reverse_array([1, 2, 3, 4])
+    raise Exception("Lowering a long array should fail")
+except ValueError:
+    pass
+
 assert(join(["a", "b", "c"], ":") == "a:b:c")
 
 try:
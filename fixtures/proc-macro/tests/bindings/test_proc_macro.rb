@@ -61,3 +61,50 @@ class TestProcMacroDefaults < Test::Unit::TestCase
     assert_equal 31, obj.add_to_implicit_num(1)
   end
 end
+
+class TestProcMacroStaticMethods < Test::Unit::TestCase
+  def test_static_methods
+    assert_equal ProcMacro::MaybeBool::UNCERTAIN, ProcMacro::Object.default_heaviness(true)
+    assert_equal ProcMacro::MaybeBool::FALSE, ProcMacro::Object.default_heaviness(false)
+  end
+end
+
+class TestProcMacroOwnedBytes < Test::Unit::TestCase
+  def test_owned_bytes
+    owned = ProcMacro.make_owned_bytes(5)
+
+    assert_equal "\x00\x01\x02\x03\x04".b, owned
+    assert_equal Encoding::BINARY, owned.encoding
+    assert_equal ''.b, ProcMacro.make_owned_bytes(0)
+    assert_equal 6, ProcMacro.sum_owned_bytes("\x01\x02\x03".b)
+    assert_equal "\x07".b, ProcMacro.roundtrip_optional_owned_bytes("\x07".b)
+    assert_nil ProcMacro.roundtrip_optional_owned_bytes(nil)
+  end
+end
+
+class TestProcMacroTuples < Test::Unit::TestCase
+  def test_tuples
+    assert_equal ["a", 1], ProcMacro.make_pair("a", 1)
+    assert_equal [[4], nil, "two", 1], ProcMacro.reverse_tuple4([1, "two", nil, [4]])
+    assert_equal [2, 1], ProcMacro.swap_point(ProcMacro::RecordWithTuple.new(point: [1, 2])).point
+  end
+end
+
+class TestProcMacroArrays < Test::Unit::TestCase
+  def test_arrays
+    assert_equal "\xfe\x02\xfc\x04".b, ProcMacro.xor_key("\x01\x02\x03\x04".b, "\xff\x00\xff\x00".b)
+    assert_equal [3, 2, 1], ProcMacro.reverse_array([1, 2, 3])
+    assert_equal %w[b a], ProcMacro.swap_names(ProcMacro::RecordWithArray.new(key: "abcd", names: %w[a b])).names
+    assert_raise ArgumentError do
+      ProcMacro.reverse_array([1, 2, 3, 4])
+    end
+  end
+end
+
+class TestProcMacroConstants < Test::Unit::TestCase
+  def test_constants
+    assert_equal 5, ProcMacro::MAX_RETRIES
+    assert_equal "hello", ProcMacro::DEFAULT_GREETING
+    assert_equal(-1, ProcMacro::DEFAULT_OFFSET)
+  end
+end
@@ -25,6 +25,19 @@ obj = Object.namedCtor(arg: 1)
 assert(obj.isHeavy() == .uncertain)
 let obj2 = Object()
 assert(obj.isOtherHeavy(other: obj2) == .uncertain)
+assert(Object.defaultHeaviness(verbose: true) == .uncertain)
+assert(Object.defaultHeaviness(verbose: false) == .false)
+
+let thermostat = Thermostat()
+assert(thermostat.target == 20)
+assert(thermostat.unit == "celsius")
+thermostat.target = 25
+assert(thermostat.target == 25)
+assert(thermostat.isHeating(current: 22))
+
+assert(maxRetries == 5)
+assert(defaultGreeting == "hello")
+assert(defaultOffset == -1)
 
 let traitImpl = obj.getTrait(inc: nil)
 assert(traitImpl.concatStrings(a: "foo", b: "bar") == "foobar")
@@ -43,9 +56,22 @@ let three = Three(obj: obj)
 assert(makeZero().inner == "ZERO")
 assert(makeRecordWithBytes().someBytes == Data([0, 1, 2, 3, 4]))
 
+// OwnedBytes are lifted as a Data that wraps the Rust buffer
+assert(makeOwnedBytes(len: 5) == Data([0, 1, 2, 3, 4]))
+assert(makeOwnedBytes(len: 0) == Data())
+assert(sumOwnedBytes(bytes: Data([1, 2, 3])) == 6)
+assert(roundtripOptionalOwnedBytes(bytes: Data([7])) == Data([7]))
+assert(roundtripOptionalOwnedBytes(bytes: nil) == nil)
+
 assert(makeHashSet(v: "hello") == Set(["hello"]))
 let s: Set<String> = ["a", "b", "c"]
 assert(returnHashSet(h: s) == s)
+assert(makePair(name: "a", count: 1) == ("a", 1))
+assert(reverseTuple4(t: (1, "two", nil, [4])) == ([4], nil, "two", 1))
+assert(swapPoint(r: RecordWithTuple(point: (1, 2))).point == (2, 1))
+assert(xorKey(key: Data([1, 2, 3, 4]), mask: Data([0xff, 0, 0xff, 0])) == Data([0xfe, 2, 0xfc, 4]))
+assert(reverseArray(a: [1, 2, 3]) == [3, 2, 1])
+assert(swapNames(r: RecordWithArray(key: Data([1, 2, 3, 4]), names: ["a", "b"])).names == ["b", "a"])
 assert(join(parts: ["a", "b", "c"], sep: ":") == "a:b:c")
 
 do {
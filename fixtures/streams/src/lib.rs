@@ -0,0 +1,129 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+    thread,
+    time::Duration,
+};
+
+/// Non-blocking timer future.
+struct TimerFuture {
+    shared_state: Arc<Mutex<SharedState>>,
+}
+
+struct SharedState {
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+impl Future for TimerFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared_state = self.shared_state.lock().unwrap();
+
+        if shared_state.completed {
+            Poll::Ready(())
+        } else {
+            shared_state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl TimerFuture {
+    fn new(duration: Duration) -> Self {
+        let shared_state = Arc::new(Mutex::new(SharedState {
+            completed: false,
+            waker: None,
+        }));
+
+        let thread_shared_state = shared_state.clone();
+        thread::spawn(move || {
+            thread::sleep(duration);
+
+            let mut shared_state = thread_shared_state.lock().unwrap();
+            shared_state.completed = true;
+            // Release the lock before waking, since waking may re-poll the future
+            let waker = shared_state.waker.take();
+            drop(shared_state);
+
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+
+        Self { shared_state }
+    }
+}
+
+/// Stream that yields the numbers `0..count`, waiting `delay_ms` before each one.
+#[uniffi::export]
+pub fn count_to(count: u8, delay_ms: u16) -> uniffi::Stream<u8> {
+    uniffi::Stream::new(futures::stream::unfold(0, move |i| async move {
+        if i == count {
+            return None;
+        }
+        TimerFuture::new(Duration::from_millis(delay_ms.into())).await;
+        Some((i, i + 1))
+    }))
+}
+
+/// Async function that returns a stream of optional values.
+#[uniffi::export]
+pub async fn maybe_names(names: Vec<String>) -> uniffi::Stream<Option<String>> {
+    uniffi::Stream::new(futures::stream::iter(names.into_iter().map(|name| {
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
+    })))
+}
+
+static ACTIVE_STREAM_COUNT: AtomicU32 = AtomicU32::new(0);
+
+/// Guard that tracks how many `ticker` streams are still alive on the Rust side.
+struct ActiveStreamGuard;
+
+impl ActiveStreamGuard {
+    fn new() -> Self {
+        ACTIVE_STREAM_COUNT.fetch_add(1, Ordering::SeqCst);
+        Self
+    }
+}
+
+impl Drop for ActiveStreamGuard {
+    fn drop(&mut self) {
+        ACTIVE_STREAM_COUNT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Stream that never ends.  Used to test cancellation from the foreign side.
+#[uniffi::export]
+pub fn ticker(delay_ms: u16) -> uniffi::Stream<u32> {
+    let state = (0, ActiveStreamGuard::new());
+    uniffi::Stream::new(futures::stream::unfold(
+        state,
+        move |(i, guard)| async move {
+            TimerFuture::new(Duration::from_millis(delay_ms.into())).await;
+            Some((i, (i + 1, guard)))
+        },
+    ))
+}
+
+/// Number of `ticker` streams that haven't been dropped yet.
+#[uniffi::export]
+pub fn active_ticker_count() -> u32 {
+    ACTIVE_STREAM_COUNT.load(Ordering::SeqCst)
+}
+
+uniffi::setup_scaffolding!();
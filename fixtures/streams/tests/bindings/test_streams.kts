@@ -0,0 +1,64 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.uniffi_streams.*
+import kotlinx.coroutines.*
+import kotlinx.coroutines.flow.*
+
+// Test streams.
+runBlocking {
+    assert(countTo(5U, 10U).toList() == listOf<UByte>(0U, 1U, 2U, 3U, 4U))
+    assert(maybeNames(listOf("Alice", "", "Bob")).toList() == listOf("Alice", null, "Bob"))
+    println("streams ... ok")
+}
+
+// Test cancelling streams.
+runBlocking {
+    val stream = ticker(10U)
+    assert(activeTickerCount() == 1U)
+    // `take` stops collecting after 2 items, which drops the Rust stream
+    assert(stream.take(2).toList() == listOf<UInt>(0U, 1U))
+    assert(activeTickerCount() == 0U)
+
+    // Cancel the coroutine while it's waiting for the next item
+    val job = launch { ticker(10U).collect { } }
+    delay(50)
+    assert(activeTickerCount() == 1U)
+    job.cancelAndJoin()
+    assert(activeTickerCount() == 0U)
+    println("stream cancellation ... ok")
+}
+
+// Test that streams which are never collected are freed once they're garbage collected.
+runBlocking {
+    ticker(10U)
+    assert(activeTickerCount() == 1U)
+    for (i in 1..100) {
+        System.gc()
+        if (activeTickerCount() == 0U) {
+            break
+        }
+        delay(10)
+    }
+    assert(activeTickerCount() == 0U)
+    println("uncollected stream ... ok")
+}
+
+// Test that garbage collecting the flow while it's being collected doesn't free the stream.
+runBlocking {
+    val items = mutableListOf<UInt>()
+    val job = launch {
+        ticker(10U).collect {
+            items.add(it)
+            System.gc()
+            if (items.size == 5) {
+                cancel()
+            }
+        }
+    }
+    job.join()
+    assert(items == listOf<UInt>(0U, 1U, 2U, 3U, 4U))
+    assert(activeTickerCount() == 0U)
+    println("stream collected during gc ... ok")
+}
@@ -0,0 +1,45 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import asyncio
+import unittest
+from uniffi_streams import *
+
+class TestStreams(unittest.TestCase):
+    def test_stream(self):
+        async def test():
+            items = [item async for item in count_to(5, 10)]
+            self.assertEqual(items, [0, 1, 2, 3, 4])
+
+        asyncio.run(test())
+
+    def test_async_fn_returning_stream(self):
+        async def test():
+            stream = await maybe_names(["Alice", "", "Bob"])
+            items = [item async for item in stream]
+            self.assertEqual(items, ["Alice", None, "Bob"])
+
+        asyncio.run(test())
+
+    def test_stream_cancellation(self):
+        async def test():
+            stream = ticker(10)
+            self.assertEqual(active_ticker_count(), 1)
+            self.assertEqual(await stream.__anext__(), 0)
+            self.assertEqual(await stream.__anext__(), 1)
+            # Cancel a pending `__anext__` call, then close the stream
+            task = asyncio.create_task(stream.__anext__())
+            await asyncio.sleep(0)
+            task.cancel()
+            with self.assertRaises(asyncio.CancelledError):
+                await task
+            await stream.aclose()
+            self.assertEqual(active_ticker_count(), 0)
+            with self.assertRaises(StopAsyncIteration):
+                await stream.__anext__()
+
+        asyncio.run(test())
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,59 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation // To get `DispatchGroup`
+import uniffi_streams
+
+var counter = DispatchGroup()
+
+// Test streams
+counter.enter()
+Task {
+	var items: [UInt8] = []
+	for try await item in countTo(count: 5, delayMs: 10) {
+		items.append(item)
+	}
+	assert(items == [0, 1, 2, 3, 4])
+
+	let stream = await maybeNames(names: ["Alice", "", "Bob"])
+	var names: [String?] = []
+	for try await name in stream {
+		names.append(name)
+	}
+	assert(names == ["Alice", nil, "Bob"])
+	counter.leave()
+}
+
+// Test stopping and cancelling streams.  The tests run concurrently, so only this one may create
+// `ticker` streams.
+counter.enter()
+Task {
+	do {
+		let stream = ticker(delayMs: 10)
+		assert(activeTickerCount() == 1)
+		var items: [UInt32] = []
+		for try await item in stream {
+			items.append(item)
+			if items.count == 2 {
+				break
+			}
+		}
+		assert(items == [0, 1])
+	}
+	// The Rust stream is dropped once the Swift stream is released
+	assert(activeTickerCount() == 0)
+
+	// Cancel the task while it's waiting for the next item
+	let task = Task {
+		for try await _ in ticker(delayMs: 10) {}
+	}
+	try await Task.sleep(nanoseconds: 50_000_000)
+	assert(activeTickerCount() == 1)
+	task.cancel()
+	_ = await task.result
+	assert(activeTickerCount() == 0)
+	counter.leave()
+}
+
+counter.wait()
@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_streams.py",
+    "tests/bindings/test_streams.kts",
+    "tests/bindings/test_streams.swift",
+);
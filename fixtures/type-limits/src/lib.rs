@@ -30,6 +30,22 @@ fn take_u64(v: u64) -> u64 {
     v
 }
 
+fn take_i128(v: i128) -> i128 {
+    v
+}
+fn take_u128(v: u128) -> u128 {
+    v
+}
+
+pub struct WideIntegers {
+    signed: i128,
+    unsigned: Option<u128>,
+}
+
+fn take_wide_integers(v: WideIntegers) -> WideIntegers {
+    v
+}
+
 fn take_f32(v: f32) -> f32 {
     v
 }
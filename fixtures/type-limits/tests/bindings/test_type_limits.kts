@@ -14,3 +14,22 @@ try {
 assert(takeString("") == "")
 assert(takeString("愛") == "愛")
 assert(takeString("💖") == "💖")
+
+// test_128_bit_ints
+val two = java.math.BigInteger.TWO
+assert(takeI128(two.pow(127).negate()) == two.pow(127).negate())
+assert(takeI128(two.pow(127).subtract(java.math.BigInteger.ONE)) == two.pow(127).subtract(java.math.BigInteger.ONE))
+assert(takeU128(java.math.BigInteger.ZERO) == java.math.BigInteger.ZERO)
+assert(takeU128(two.pow(128).subtract(java.math.BigInteger.ONE)) == two.pow(128).subtract(java.math.BigInteger.ONE))
+try {
+    takeU128(java.math.BigInteger.ONE.negate())
+    throw RuntimeException("Should have thrown an IllegalArgumentException!")
+} catch (e: IllegalArgumentException) {
+    // It's okay!
+}
+try {
+    takeI128(two.pow(127))
+    throw RuntimeException("Should have thrown an IllegalArgumentException!")
+} catch (e: IllegalArgumentException) {
+    // It's okay!
+}
@@ -17,6 +17,8 @@ class TestTypeLimits(unittest.TestCase):
         self.assertRaises(ValueError, lambda: take_u16(-1))
         self.assertRaises(ValueError, lambda: take_u32(-1))
         self.assertRaises(ValueError, lambda: take_u64(-1))
+        self.assertRaises(ValueError, lambda: take_i128(-2**127 - 1))
+        self.assertRaises(ValueError, lambda: take_u128(-1))
 
         self.assertEqual(take_i8(-2**7), -2**7)
         self.assertEqual(take_i16(-2**15), -2**15)
@@ -26,6 +28,8 @@ class TestTypeLimits(unittest.TestCase):
         self.assertEqual(take_u16(0), 0)
         self.assertEqual(take_u32(0), 0)
         self.assertEqual(take_u64(0), 0)
+        self.assertEqual(take_i128(-2**127), -2**127)
+        self.assertEqual(take_u128(0), 0)
 
     def test_strict_upper_bounds(self):
         self.assertRaises(ValueError, lambda: take_i8(2**7))
@@ -36,6 +40,8 @@ class TestTypeLimits(unittest.TestCase):
         self.assertRaises(ValueError, lambda: take_u16(2**16))
         self.assertRaises(ValueError, lambda: take_u32(2**32))
         self.assertRaises(ValueError, lambda: take_u64(2**64))
+        self.assertRaises(ValueError, lambda: take_i128(2**127))
+        self.assertRaises(ValueError, lambda: take_u128(2**128))
 
         self.assertEqual(take_i8(2**7 - 1), 2**7 - 1)
         self.assertEqual(take_i16(2**15 - 1), 2**15 - 1)
@@ -45,6 +51,8 @@ class TestTypeLimits(unittest.TestCase):
         self.assertEqual(take_u16(2**16 - 1), 2**16 - 1)
         self.assertEqual(take_u32(2**32 - 1), 2**32 - 1)
         self.assertEqual(take_u64(2**64 - 1), 2**64 - 1)
+        self.assertEqual(take_i128(2**127 - 1), 2**127 - 1)
+        self.assertEqual(take_u128(2**128 - 1), 2**128 - 1)
 
     def test_larger_numbers(self):
         self.assertRaises(ValueError, lambda: take_i8(10**3))
@@ -64,6 +72,8 @@ class TestTypeLimits(unittest.TestCase):
         self.assertEqual(take_u16(10**4), 10**4)
         self.assertEqual(take_u32(10**9), 10**9)
         self.assertEqual(take_u64(10**19), 10**19)
+        self.assertEqual(take_i128(-10**37), -10**37)
+        self.assertEqual(take_u128(10**38), 10**38)
 
     def test_non_integer(self):
         self.assertRaises(TypeError, lambda: take_i8(None))
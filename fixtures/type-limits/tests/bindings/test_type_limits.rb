@@ -15,6 +15,8 @@ class TestTypeLimits < Test::Unit::TestCase
     assert_raise RangeError do UniffiTypeLimits.take_u16(-1) end
     assert_raise RangeError do UniffiTypeLimits.take_u32(-1) end
     assert_raise RangeError do UniffiTypeLimits.take_u64(-1) end
+    assert_raise RangeError do UniffiTypeLimits.take_i128(-2**127 - 1) end
+    assert_raise RangeError do UniffiTypeLimits.take_u128(-1) end
 
     assert_equal(UniffiTypeLimits.take_i8(-2**7), -2**7)
     assert_equal(UniffiTypeLimits.take_i16(-2**15), -2**15)
@@ -24,6 +26,8 @@ class TestTypeLimits < Test::Unit::TestCase
     assert_equal(UniffiTypeLimits.take_u16(0), 0)
     assert_equal(UniffiTypeLimits.take_u32(0), 0)
     assert_equal(UniffiTypeLimits.take_u64(0), 0)
+    assert_equal(UniffiTypeLimits.take_i128(-2**127), -2**127)
+    assert_equal(UniffiTypeLimits.take_u128(0), 0)
   end
   def test_strict_upper_bounds
     assert_raise RangeError do UniffiTypeLimits.take_i8(2**7) end
@@ -34,6 +38,8 @@ class TestTypeLimits < Test::Unit::TestCase
     assert_raise RangeError do UniffiTypeLimits.take_u16(2**16) end
     assert_raise RangeError do UniffiTypeLimits.take_u32(2**32) end
     assert_raise RangeError do UniffiTypeLimits.take_u64(2**64) end
+    assert_raise RangeError do UniffiTypeLimits.take_i128(2**127) end
+    assert_raise RangeError do UniffiTypeLimits.take_u128(2**128) end
 
     assert_equal(UniffiTypeLimits.take_i8(2**7 - 1), 2**7 - 1)
     assert_equal(UniffiTypeLimits.take_i16(2**15 - 1), 2**15 - 1)
@@ -43,6 +49,8 @@ class TestTypeLimits < Test::Unit::TestCase
     assert_equal(UniffiTypeLimits.take_u16(2**16 - 1), 2**16 - 1)
     assert_equal(UniffiTypeLimits.take_u32(2**32 - 1), 2**32 - 1)
     assert_equal(UniffiTypeLimits.take_u64(2**64 - 1), 2**64 - 1)
+    assert_equal(UniffiTypeLimits.take_i128(2**127 - 1), 2**127 - 1)
+    assert_equal(UniffiTypeLimits.take_u128(2**128 - 1), 2**128 - 1)
   end
   def test_larger_numbers
     assert_raise RangeError do UniffiTypeLimits.take_i8(10**3) end
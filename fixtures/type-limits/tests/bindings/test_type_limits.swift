@@ -12,3 +12,19 @@ do {
     assert(takeString(v: "愛") == "愛")
     assert(takeString(v: "💖") == "💖")
 }
+
+// test_128_bit_integers
+if #available(macOS 15, iOS 18, tvOS 18, watchOS 11, visionOS 2, *) {
+    assert(takeI128(v: Int128.min) == Int128.min)
+    assert(takeI128(v: -1) == -1)
+    assert(takeI128(v: Int128.max) == Int128.max)
+    assert(takeU128(v: 0) == 0)
+    assert(takeU128(v: UInt128(UInt64.max) + 1) == UInt128(UInt64.max) + 1)
+    assert(takeU128(v: UInt128.max) == UInt128.max)
+
+    let wide = WideIntegers(signed: Int128.min, unsigned: UInt128.max)
+    let result = takeWideIntegers(v: wide)
+    assert(result.signed == Int128.min)
+    assert(result.unsigned == UInt128.max)
+    assert(takeWideIntegers(v: WideIntegers(signed: 0, unsigned: nil)).unsigned == nil)
+}
@@ -0,0 +1,23 @@
+fn main() {} /* empty main required by `trybuild` */
+
+// Async functions can't borrow objects with a lock: the lock would be held while the future is
+// suspended, so any other call on the object from the same thread would fail.
+#[derive(uniffi::Object)]
+#[uniffi(mutex)]
+pub struct Counter {
+    value: u64,
+}
+
+#[uniffi::export]
+impl Counter {
+    pub async fn get(&self) -> u64 {
+        self.value
+    }
+}
+
+#[uniffi::export]
+pub async fn counter_value(counter: &Counter) -> u64 {
+    counter.value
+}
+
+uniffi_macros::setup_scaffolding!();
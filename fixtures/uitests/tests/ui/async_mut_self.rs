@@ -0,0 +1,19 @@
+fn main() {} /* empty main required by `trybuild` */
+
+// Async methods can't take `&mut self`: the object's lock would be held while the
+// future is suspended, so other calls on the same thread would fail or block.
+#[derive(uniffi::Object)]
+#[uniffi(mutex)]
+pub struct Counter {
+    value: u64,
+}
+
+#[uniffi::export]
+impl Counter {
+    pub async fn increment(&mut self) -> u64 {
+        self.value += 1;
+        self.value
+    }
+}
+
+uniffi_macros::setup_scaffolding!();
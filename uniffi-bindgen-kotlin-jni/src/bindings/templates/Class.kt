@@ -196,5 +196,13 @@ open class {{ cls.name_kt() }} : {{ cls.base_classes|join(", ") }} {
             {% filter indent(8) %}{%- include "CallableBody.kt" %}{% endfilter %}
         }
         {% endfor %}
+
+        {% for meth in cls.static_methods -%}
+        public {% if meth.callable.is_async %}suspend {% endif %}fun {{ meth.callable.name_kt() }}({{ meth.callable.arg_list_kt() }}): {{ meth.callable.return_type_kt() }} {
+            {%- let jni_method_name = meth.jni_method_name %}
+            {%- let callable = meth.callable %}
+            {% filter indent(8) %}{%- include "CallableBody.kt" %}{% endfilter %}
+        }
+        {% endfor %}
     }
 }
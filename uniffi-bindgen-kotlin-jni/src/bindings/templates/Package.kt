@@ -23,10 +23,17 @@ import {{ import }}
 {%- when TypeDefinition::Map(_) %}
 {%- when TypeDefinition::Set(_) %}
 {%- when TypeDefinition::Duration(_) %}
+{%- when TypeDefinition::UInt128(_) %}
+{%- when TypeDefinition::Int128(_) %}
 {%- when TypeDefinition::Timestamp(_) %}
 {%- endmatch %}
 {%- endfor %}
 
+{%- for c in package.constants %}
+
+public val {{ c.name_kt() }}: {{ c.ty.type_kt }} = {{ c.value.lit_kt }}
+{%- endfor %}
+
 {%- for func in package.functions %}
 {% include "Function.kt" %}
 {%- endfor %}
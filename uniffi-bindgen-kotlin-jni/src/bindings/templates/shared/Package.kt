@@ -44,6 +44,10 @@ class InternalException(message: kotlin.String) : Exception(message)
 {% include "TimestampFfi.kt" %}
 {%- when TypeDefinition::Duration(type_node) %}
 {% include "DurationFfi.kt" %}
+{%- when TypeDefinition::UInt128(type_node) %}
+{% include "UInt128Ffi.kt" %}
+{%- when TypeDefinition::Int128(type_node) %}
+{% include "Int128Ffi.kt" %}
 {%- when TypeDefinition::Interface(_) %}
 {%- endmatch %}
 {%- endfor %}
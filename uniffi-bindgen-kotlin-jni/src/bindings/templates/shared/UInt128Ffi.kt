@@ -0,0 +1,32 @@
+// Deconstructed version of u128: the high and low 64 bits
+class {{ type_node.lowered_type_kt() }}(
+    val v0: kotlin.Long,
+    val v1: kotlin.Long,
+)
+
+fun {{ type_node.lower_fn_kt() }}(value: java.math.BigInteger): {{ type_node.lowered_type_kt() }} {
+    if (value.signum() < 0 || value.bitLength() > 128) {
+        throw IllegalArgumentException("u128 requires 0 <= value < 2^128")
+    }
+    return {{ type_node.lowered_type_kt() }}(value.shiftRight(64).toLong(), value.toLong())
+}
+
+fun {{ type_node.lift_fn_kt() }}(high: kotlin.Long, low: kotlin.Long): java.math.BigInteger {
+    val mask64 = java.math.BigInteger.ONE.shiftLeft(64).subtract(java.math.BigInteger.ONE)
+    return java.math.BigInteger.valueOf(high).and(mask64)
+        .shiftLeft(64)
+        .or(java.math.BigInteger.valueOf(low).and(mask64))
+}
+
+fun {{ type_node.write_fn_kt() }}(buf: java.nio.ByteBuffer, offset: kotlin.Int, value: java.math.BigInteger) {
+    val lowered = {{ type_node.lower_fn_kt() }}(value)
+    writeLong(buf, offset, lowered.v0)
+    writeLong(buf, offset + 8, lowered.v1)
+}
+
+fun {{ type_node.read_fn_kt() }}(buf: java.nio.ByteBuffer, offset: kotlin.Int): java.math.BigInteger {
+    return {{ type_node.lift_fn_kt() }}(
+        readLong(buf, offset),
+        readLong(buf, offset + 8),
+    )
+}
@@ -50,6 +50,37 @@ pub fn map_method(input: general::Method, context: &Context) -> Result<Method> {
     })
 }
 
+pub fn map_static_method(input: general::Method, context: &Context) -> Result<Method> {
+    let self_ty = match &input.callable.kind {
+        general::CallableKind::StaticMethod { self_type } => self_type,
+        _ => bail!(
+            "Invalid static method callable kind: {:?}",
+            input.callable.kind
+        ),
+    };
+    let Some(self_name) = self_ty.ty.orig_name() else {
+        bail!("Invalid Callable self type: {self_ty:?}")
+    };
+    let module_path = context.rust_module_path_for_type(&self_ty.ty)?;
+    let fully_qualified_name_rs = format!(
+        "{module_path}::{}::{}",
+        names::escape_rust(self_name),
+        names::escape_rust(&input.callable.orig_name)
+    );
+    let jni_method_name = format!(
+        "staticMethod{}{}{}",
+        context.current_crate_name()?.to_upper_camel_case(),
+        self_name.to_upper_camel_case(),
+        input.callable.name.to_upper_camel_case()
+    );
+
+    Ok(Method {
+        docstring: input.docstring,
+        jni_method_name,
+        callable: map_callable(input.callable, fully_qualified_name_rs, context)?,
+    })
+}
+
 pub fn map_constructor(input: general::Constructor, context: &Context) -> Result<Constructor> {
     let self_ty = match &input.callable.kind {
         general::CallableKind::Constructor { self_type, .. } => self_type,
@@ -323,6 +354,7 @@ impl Callable {
             CallableKind::Function
                 | CallableKind::Method { .. }
                 | CallableKind::Constructor { .. }
+                | CallableKind::StaticMethod { .. }
                 | CallableKind::VTableMethod {
                     for_callback_interface: false,
                     ..
@@ -24,6 +24,8 @@ pub fn standard_ffi_type_mapping(ty: &Type) -> Option<Vec<FfiType>> {
         Type::String => Some(vec![FfiType::String]),
         // 64-bit seconds, 32-bit nanoseconds
         Type::Duration | Type::Timestamp => Some(vec![FfiType::Int64, FfiType::Int32]),
+        // High 64 bits, low 64 bits
+        Type::UInt128 | Type::Int128 => Some(vec![FfiType::Int64, FfiType::Int64]),
         Type::Bytes => Some(vec![FfiType::ByteArray]),
         Type::Sequence { inner_type } => match &**inner_type {
             Type::Int8 | Type::UInt8 => Some(vec![FfiType::ByteArray]),
@@ -17,6 +17,11 @@ pub fn map_class(int: general::Interface, context: &Context) -> Result<Class> {
         .transpose()?;
 
     let methods = int.methods.map_node(context)?;
+    let static_methods = int
+        .static_methods
+        .into_iter()
+        .map(|meth| callables::map_static_method(meth, context))
+        .collect::<Result<Vec<_>>>()?;
     let self_type = int.self_type.map_node(context)?;
     if self_type.is_used_as_error {
         base_classes.push("kotlin.Exception".into());
@@ -31,6 +36,7 @@ pub fn map_class(int: general::Interface, context: &Context) -> Result<Class> {
         package_name: context.current_package_name()?.to_string(),
         constructors: int.constructors.map_node(context)?,
         methods,
+        static_methods,
         name: int.name,
         module_path: context.normalize_rust_module_path(&int.module_path)?,
         orig_name: int.orig_name,
@@ -28,6 +28,7 @@ pub struct Package {
     pub crate_name: String,
     pub config: Config,
     pub functions: Vec<Function>,
+    pub constants: Vec<Constant>,
     pub type_definitions: Vec<TypeDefinition>,
     pub imports: IndexSet<String>,
 }
@@ -48,6 +49,17 @@ pub enum TypeDefinition {
     Set(SetType),
     Timestamp(TypeNode),
     Duration(TypeNode),
+    UInt128(TypeNode),
+    Int128(TypeNode),
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constant))]
+pub struct Constant {
+    pub name: String,
+    pub ty: TypeNode,
+    pub value: LiteralNode,
+    pub docstring: Option<String>,
 }
 
 #[derive(Debug, Clone, Node, MapNode)]
@@ -102,6 +114,7 @@ pub struct Class {
     pub base_classes: Vec<String>,
     pub constructors: Vec<Constructor>,
     pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
     pub docstring: Option<String>,
     pub crate_name: String,
     pub imp: ObjectImpl,
@@ -240,6 +253,9 @@ pub enum CallableKind {
         self_type: TypeNode,
         primary: bool,
     },
+    StaticMethod {
+        self_type: TypeNode,
+    },
     VTableMethod {
         self_type: TypeNode,
         takes_self_by_arc: bool,
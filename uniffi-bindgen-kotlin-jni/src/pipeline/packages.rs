@@ -20,6 +20,7 @@ pub fn map_namespace(input: general::Namespace, context: &Context) -> Result<Pac
         crate_name: input.name,
         config,
         functions: input.functions.map_node(&context)?,
+        constants: input.constants.map_node(&context)?,
         type_definitions: map_type_definitions(input.type_definitions, &context)?,
         imports,
     })
@@ -65,12 +66,26 @@ pub fn map_type_definitions(
             general::TypeDefinition::Set(set) => {
                 mapped.push(TypeDefinition::Set(set.map_node(context)?));
             }
+            general::TypeDefinition::Stream(_) => {
+                bail!("Streams are not supported by the Kotlin JNI bindings")
+            }
+            general::TypeDefinition::Tuple(_) => {
+                bail!("Tuples are not supported by the Kotlin JNI bindings")
+            }
+            general::TypeDefinition::Array(_) => {
+                bail!("Arrays are not supported by the Kotlin JNI bindings")
+            }
+            general::TypeDefinition::Closure(_) => {
+                bail!("Closures are not supported by the Kotlin JNI bindings")
+            }
             general::TypeDefinition::Box(inner) => {
                 mapped.push(TypeDefinition::Box(inner.map_node(context)?));
             }
             general::TypeDefinition::Simple(inner) => match &inner.ty {
                 Type::Duration => mapped.push(TypeDefinition::Duration(inner.map_node(context)?)),
                 Type::Timestamp => mapped.push(TypeDefinition::Timestamp(inner.map_node(context)?)),
+                Type::UInt128 => mapped.push(TypeDefinition::UInt128(inner.map_node(context)?)),
+                Type::Int128 => mapped.push(TypeDefinition::Int128(inner.map_node(context)?)),
                 _ => (),
             },
             // No need to do anything for external definitions since we generate everything in one
@@ -81,6 +96,12 @@ pub fn map_type_definitions(
     Ok(mapped)
 }
 
+impl Constant {
+    pub fn name_kt(&self) -> String {
+        format!("`{}`", self.name.to_shouty_snake_case())
+    }
+}
+
 impl Package {
     pub fn name_rs(&self) -> String {
         names::escape_rust(&self.crate_name)
@@ -106,6 +127,15 @@ impl Package {
                 )
             })
         });
+        let static_methods = self.classes().flat_map(|c| {
+            c.static_methods.iter().map(|m| {
+                (
+                    m.jni_method_name.as_str(),
+                    JniMethodKind::Function,
+                    &m.callable,
+                )
+            })
+        });
         let constructors = self.classes().flat_map(|c| {
             c.constructors.iter().map(|c| {
                 (
@@ -122,6 +152,7 @@ impl Package {
         functions
             .chain(methods)
             .chain(constructors)
+            .chain(static_methods)
             .chain(trait_methods)
     }
 
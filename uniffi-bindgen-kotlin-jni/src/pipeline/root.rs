@@ -56,9 +56,10 @@ impl Root {
                     TypeDefinition::Custom(c) => c.self_type.id,
                     TypeDefinition::CallbackInterface(c) => c.self_type.id,
                     TypeDefinition::Box(b) => b.self_type.id,
-                    TypeDefinition::Timestamp(type_node) | TypeDefinition::Duration(type_node) => {
-                        type_node.id
-                    }
+                    TypeDefinition::Timestamp(type_node)
+                    | TypeDefinition::Duration(type_node)
+                    | TypeDefinition::UInt128(type_node)
+                    | TypeDefinition::Int128(type_node) => type_node.id,
                     TypeDefinition::Interface(_) => return false,
                 })
             })
@@ -32,6 +32,8 @@ pub fn type_rs(ty: &Type, context: &Context) -> Result<String> {
         Type::Int32 => "::std::primitive::i32".into(),
         Type::UInt64 => "::std::primitive::u64".into(),
         Type::Int64 => "::std::primitive::i64".into(),
+        Type::UInt128 => "::std::primitive::u128".into(),
+        Type::Int128 => "::std::primitive::i128".into(),
         Type::Float32 => "::std::primitive::f32".into(),
         Type::Float64 => "::std::primitive::f64".into(),
         Type::Boolean => "::std::primitive::bool".into(),
@@ -88,6 +90,14 @@ pub fn type_rs(ty: &Type, context: &Context) -> Result<String> {
         Type::Duration => "::std::time::Duration".into(),
         Type::Timestamp => "::std::time::SystemTime".into(),
         Type::Bytes => "::std::vec::Vec<::std::primitive::u8>".into(),
+        Type::Stream { .. } => bail!("Streams are not supported by the Kotlin JNI bindings"),
+        Type::Tuple { .. } => bail!("Tuples are not supported by the Kotlin JNI bindings"),
+        Type::Array { .. } => bail!("Arrays are not supported by the Kotlin JNI bindings"),
+        Type::Uuid | Type::Url | Type::Date => {
+            bail!("{ty:?} is not supported by the Kotlin JNI bindings")
+        }
+        Type::Closure { .. } => bail!("Closures are not supported by the Kotlin JNI bindings"),
+        Type::OwnedBytes => bail!("OwnedBytes is not supported by the Kotlin JNI bindings"),
     })
 }
 
@@ -101,6 +111,7 @@ pub fn type_kt(ty: &Type, context: &Context) -> Result<String> {
         Type::Int32 => "kotlin.Int".into(),
         Type::UInt64 => "kotlin.ULong".into(),
         Type::Int64 => "kotlin.Long".into(),
+        Type::UInt128 | Type::Int128 => "java.math.BigInteger".into(),
         Type::Float32 => "kotlin.Float".into(),
         Type::Float64 => "kotlin.Double".into(),
         Type::Boolean => "kotlin.Boolean".into(),
@@ -147,6 +158,14 @@ pub fn type_kt(ty: &Type, context: &Context) -> Result<String> {
         Type::Duration => "java.time.Duration".into(),
         Type::Timestamp => "java.time.Instant".into(),
         Type::Bytes => "kotlin.ByteArray".into(),
+        Type::Stream { .. } => bail!("Streams are not supported by the Kotlin JNI bindings"),
+        Type::Tuple { .. } => bail!("Tuples are not supported by the Kotlin JNI bindings"),
+        Type::Array { .. } => bail!("Arrays are not supported by the Kotlin JNI bindings"),
+        Type::Uuid | Type::Url | Type::Date => {
+            bail!("{ty:?} is not supported by the Kotlin JNI bindings")
+        }
+        Type::Closure { .. } => bail!("Closures are not supported by the Kotlin JNI bindings"),
+        Type::OwnedBytes => bail!("OwnedBytes is not supported by the Kotlin JNI bindings"),
     })
 }
 
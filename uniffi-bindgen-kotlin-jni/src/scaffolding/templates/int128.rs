@@ -0,0 +1,37 @@
+unsafe fn {{ type_node.lower_fn_rs() }}(
+    _: *mut uniffi_jni::JNIEnv,
+    value: {{ type_node.type_rs }},
+) -> uniffi::Result<(::std::primitive::i64, ::std::primitive::i64)> {
+    Ok(((value >> 64) as ::std::primitive::i64, value as ::std::primitive::i64))
+}
+
+unsafe fn {{ type_node.lift_fn_rs() }}(
+    _: *mut uniffi_jni::JNIEnv,
+    high: ::std::primitive::i64,
+    low: ::std::primitive::i64,
+) -> uniffi::Result<{{ type_node.type_rs }}> {
+    let bits = ((high as ::std::primitive::u64 as ::std::primitive::u128) << 64)
+        | (low as ::std::primitive::u64 as ::std::primitive::u128);
+    Ok(bits as {{ type_node.type_rs }})
+}
+
+unsafe fn {{ type_node.write_fn_rs() }}(
+    ptr: *mut ::std::primitive::u8,
+    value: {{ type_node.type_rs }},
+) -> uniffi::Result<()> {
+    unsafe {
+        uniffi::ffibuffer::write_i64(ptr, (value >> 64) as ::std::primitive::i64)?;
+        uniffi::ffibuffer::write_i64(ptr.add(8), value as ::std::primitive::i64)?;
+        uniffi::Result::Ok(())
+    }
+}
+
+unsafe fn {{ type_node.read_fn_rs() }}(
+    ptr: *mut ::std::primitive::u8,
+) -> uniffi::Result<{{ type_node.type_rs }}> {
+    unsafe {
+        let high = uniffi::ffibuffer::read_u64(ptr)? as ::std::primitive::u128;
+        let low = uniffi::ffibuffer::read_u64(ptr.add(8))? as ::std::primitive::u128;
+        Ok(((high << 64) | low) as {{ type_node.type_rs }})
+    }
+}
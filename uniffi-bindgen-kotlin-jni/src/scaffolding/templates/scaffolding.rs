@@ -51,6 +51,8 @@ mod uniffi_bindgen_kotlin_jni_scaffolding {
     {%- filter indent(4) %}{% include "timestamp.rs" %}{% endfilter %}
     {%- when TypeDefinition::Duration(type_node) %}
     {%- filter indent(4) %}{% include "duration.rs" %}{% endfilter %}
+    {%- when TypeDefinition::UInt128(type_node) | TypeDefinition::Int128(type_node) %}
+    {%- filter indent(4) %}{% include "int128.rs" %}{% endfilter %}
     {%- when TypeDefinition::Interface(_) %}
     {%- endmatch %}
     {%- endfor %}
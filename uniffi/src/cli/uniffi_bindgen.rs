@@ -6,7 +6,8 @@ use camino::Utf8PathBuf;
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::fmt;
 use uniffi_bindgen::{
-    bindings::{generate, python, GenerateOptions, TargetLanguage},
+    bindings::{cpp, generate, kotlin, python, swift, typescript, GenerateOptions, TargetLanguage},
+    metadata_json::MetadataDump,
     BindgenLoader, GlobalConfig,
 };
 use uniffi_pipeline::PrintOptions;
@@ -18,6 +19,10 @@ enum TargetLanguageArg {
     Swift,
     Python,
     Ruby,
+    #[clap(name = "typescript")]
+    TypeScript,
+    #[clap(name = "cpp")]
+    Cpp,
 }
 
 impl fmt::Display for TargetLanguageArg {
@@ -27,6 +32,8 @@ impl fmt::Display for TargetLanguageArg {
             Self::Swift => write!(f, "swift"),
             Self::Python => write!(f, "python"),
             Self::Ruby => write!(f, "ruby"),
+            Self::TypeScript => write!(f, "typescript"),
+            Self::Cpp => write!(f, "cpp"),
         }
     }
 }
@@ -38,10 +45,22 @@ impl From<TargetLanguageArg> for TargetLanguage {
             TargetLanguageArg::Swift => Self::Swift,
             TargetLanguageArg::Python => Self::Python,
             TargetLanguageArg::Ruby => Self::Ruby,
+            TargetLanguageArg::TypeScript => Self::TypeScript,
+            TargetLanguageArg::Cpp => Self::Cpp,
         }
     }
 }
 
+/// Output formats for the `dump` command
+#[derive(Copy, Clone, Default, ValueEnum)]
+enum DumpFormatArg {
+    /// Versioned JSON, suitable for external tools
+    #[default]
+    Json,
+    /// Rust `Debug` output
+    Debug,
+}
+
 // Structs to help our cmdline parsing. Note that docstrings below form part
 // of the "help" output.
 
@@ -118,9 +137,15 @@ enum Commands {
         #[clap(long)]
         no_default_features: bool,
 
-        /// Target triple to use when generating from Rust sources
+        /// Target triple to use when generating from Rust sources.
+        /// With `--package`, this also sets the platform tag of the wheel.
         #[clap(long)]
         target: Option<String>,
+
+        /// Write a Python project that builds a wheel with the bindings and the library.
+        /// `source` must be the path to the cdylib.
+        #[clap(long)]
+        package: bool,
     },
 
     /// Generate Rust scaffolding code
@@ -139,6 +164,30 @@ enum Commands {
 
     /// Inspect the bindings render pipeline
     Pipeline(PipelineArgs),
+
+    /// Print the component interface metadata
+    Dump(DumpArgs),
+
+    /// Check for breaking API changes between two versions of a library
+    ///
+    /// Exits with an error if there are any breaking changes.
+    CheckCompat {
+        /// Path to the old version of the library
+        old_library: Utf8PathBuf,
+
+        /// Path to the new version of the library
+        new_library: Utf8PathBuf,
+
+        /// Only check items from this crate
+        #[clap(long = "crate")]
+        crate_name: Option<String>,
+
+        /// Only fail on source-breaking changes.
+        ///
+        /// Use this if the bindings are always regenerated and shipped together with the library.
+        #[clap(long)]
+        allow_abi_breaks: bool,
+    },
 }
 
 #[derive(Args)]
@@ -186,6 +235,32 @@ struct PipelineArgs {
     filter_name: Option<String>,
 }
 
+#[derive(Args)]
+struct DumpArgs {
+    /// Output format
+    #[clap(long, value_enum, default_value_t)]
+    format: DumpFormatArg,
+
+    /// Only dump metadata for this crate
+    #[clap(long = "crate")]
+    crate_name: Option<String>,
+
+    /// Whether we should exclude dependencies when running "cargo metadata".
+    /// This will mean external types may not be resolved if they are implemented in crates
+    /// outside of this workspace.
+    #[clap(long)]
+    metadata_no_deps: bool,
+
+    /// Source to read the metadata from.
+    ///
+    /// Possible values:
+    ///
+    /// * Path to a UDL file
+    /// * Path to a library file
+    /// * `src:[crate-name]` to read from Rust sources
+    source: Utf8PathBuf,
+}
+
 pub fn run_main() -> anyhow::Result<()> {
     let cli = Cli::parse();
     match cli.command {
@@ -201,6 +276,7 @@ pub fn run_main() -> anyhow::Result<()> {
             no_default_features,
             all_features,
             target,
+            package,
             ..
         } => {
             if language.is_empty() {
@@ -220,6 +296,7 @@ pub fn run_main() -> anyhow::Result<()> {
                 all_features,
                 no_default_features,
                 target,
+                package,
             })?;
         }
         Commands::Scaffolding {
@@ -252,10 +329,62 @@ pub fn run_main() -> anyhow::Result<()> {
                 filter_name: args.filter_name,
             };
             match args.language {
+                TargetLanguageArg::Kotlin => kotlin::pipeline().print_passes(initial_root, opts)?,
                 TargetLanguageArg::Python => python::pipeline().print_passes(initial_root, opts)?,
+                TargetLanguageArg::Swift => swift::pipeline().print_passes(initial_root, opts)?,
+                TargetLanguageArg::TypeScript => {
+                    typescript::pipeline().print_passes(initial_root, opts)?
+                }
+                TargetLanguageArg::Cpp => cpp::pipeline().print_passes(initial_root, opts)?,
                 language => unimplemented!("{language} does not use the bindings IR pipeline yet"),
             };
         }
+        Commands::Dump(args) => {
+            let mut paths = uniffi_bindgen::BindgenPaths::default();
+            #[cfg(feature = "cargo-metadata")]
+            paths.add_cargo_metadata_layer(uniffi_bindgen::CargoMetadataOptions {
+                no_deps: args.metadata_no_deps,
+                ..uniffi_bindgen::CargoMetadataOptions::default()
+            })?;
+            let loader = BindgenLoader::new(paths, GlobalConfig::default());
+            let mut metadata = loader.load_metadata(&args.source)?;
+            if let Some(crate_name) = &args.crate_name {
+                metadata.retain(|name, _| name == crate_name);
+                if metadata.is_empty() {
+                    anyhow::bail!("Crate not found: {crate_name}");
+                }
+            }
+            match args.format {
+                DumpFormatArg::Json => println!("{}", MetadataDump::new(metadata).to_json()?),
+                DumpFormatArg::Debug => {
+                    let metadata: std::collections::BTreeMap<_, _> = metadata.into_iter().collect();
+                    println!("{metadata:#?}");
+                }
+            }
+        }
+        Commands::CheckCompat {
+            old_library,
+            new_library,
+            crate_name,
+            allow_abi_breaks,
+        } => {
+            let report = uniffi_bindgen::compat::check_compat(
+                &old_library,
+                &new_library,
+                crate_name.as_deref(),
+            )?;
+            for change in report.changes.iter() {
+                println!("{change}");
+            }
+            let breaking_count = report
+                .changes
+                .iter()
+                .filter(|c| !c.source_breaking.is_empty() || (c.abi_breaking && !allow_abi_breaks))
+                .count();
+            if breaking_count > 0 {
+                anyhow::bail!("{breaking_count} breaking change(s) found");
+            }
+        }
     };
     Ok(())
 }
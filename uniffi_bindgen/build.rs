@@ -0,0 +1,11 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+fn main() {
+    // Used as the default target when packaging the Python bindings
+    println!(
+        "cargo:rustc-env=UNIFFI_BINDGEN_TARGET={}",
+        std::env::var("TARGET").unwrap()
+    );
+}
@@ -69,6 +69,13 @@ impl BindgenPaths {
             .find_map(|l| l.get_cdylib_name(crate_name))
     }
 
+    /// Get the version of a crate
+    pub fn get_crate_version(&self, crate_name: &str) -> Option<String> {
+        self.layers
+            .iter()
+            .find_map(|l| l.get_crate_version(crate_name))
+    }
+
     /// Get the UDL source for a crate
     pub fn get_udl(&self, crate_name: &str, udl_name: &str) -> Result<String> {
         match self.get_udl_path(crate_name, udl_name) {
@@ -138,6 +145,11 @@ pub trait BindgenPathsLayer {
         None
     }
 
+    /// Get the version of a crate
+    fn get_crate_version(&self, _crate_name: &str) -> Option<String> {
+        None
+    }
+
     /// Get a list of default crate names
     ///
     /// This is used when the `uniffi_bindgen` source is a `Cargo.toml` file
@@ -0,0 +1,98 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+#[derive(Default, Clone)]
+pub struct Context {
+    pub current_config: Option<CppConfig>,
+    pub module_namespace: Option<String>,
+    pub checksum_mode: Option<ChecksumMode>,
+    /// Enums that are rendered as a class with a nested class for each variant, rather than an
+    /// `enum class`.  This is all enums with associated data and all errors.
+    ///
+    /// Stores `(namespace, name)` pairs, using the names from the general pipeline.
+    pub variant_class_enums: HashSet<(String, String)>,
+    /// Names of the structs/classes that are nested inside enums and errors for the current module
+    ///
+    /// Type names that match one of these need to be qualified, since the nested name would
+    /// shadow them inside the enclosing class.
+    pub nested_class_names: HashSet<String>,
+}
+
+impl Context {
+    pub fn update_from_root(&mut self, root: &general::Root) -> Result<()> {
+        self.checksum_mode = Some(root.checksum_mode.clone());
+        for namespace in root.namespaces.values() {
+            namespace.visit(|en: &general::Enum| {
+                if !en.is_flat || en.self_type.is_used_as_error {
+                    self.variant_class_enums
+                        .insert((namespace.name.clone(), en.name.clone()));
+                }
+            });
+        }
+        Ok(())
+    }
+
+    pub fn update_from_namespace(&mut self, namespace: &general::Namespace) -> Result<()> {
+        self.current_config = Some(match &namespace.config_toml {
+            Some(toml) => CppConfig::from_uniffi_toml(toml)?,
+            None => CppConfig::default(),
+        });
+        self.module_namespace = Some(namespace.name.clone());
+        self.nested_class_names.clear();
+        namespace.visit(|en: &general::Enum| {
+            if self
+                .variant_class_enums
+                .contains(&(namespace.name.clone(), en.name.clone()))
+            {
+                self.nested_class_names
+                    .extend(en.variants.iter().map(|v| names::variant_name(&v.name)));
+            }
+        });
+        Ok(())
+    }
+
+    pub fn config(&self) -> Result<&CppConfig> {
+        self.current_config
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.config not set"))
+    }
+
+    pub fn checksum_mode(&self) -> Result<&ChecksumMode> {
+        self.checksum_mode
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.checksum_mode not set"))
+    }
+
+    pub fn module_namespace(&self) -> Result<&str> {
+        self.module_namespace
+            .as_deref()
+            .ok_or_else(|| anyhow!("Context.module_namespace not set"))
+    }
+
+    /// Get the C++ namespace that the current module uses to refer to another module
+    ///
+    /// Returns `None` if `namespace` is the current module.
+    pub fn external_namespace(&self, namespace: &str) -> Result<Option<String>> {
+        Ok(if self.module_namespace()? == namespace {
+            None
+        } else {
+            Some(names::namespace_name(namespace))
+        })
+    }
+
+    /// Is an enum rendered with nested variant classes rather than as an `enum class`?
+    pub fn has_variant_classes(&self, namespace: &str, name: &str) -> bool {
+        self.variant_class_enums
+            .contains(&(namespace.to_string(), name.to_string()))
+    }
+
+    pub fn custom_type_config(
+        &self,
+        custom: &general::CustomType,
+    ) -> Result<Option<CustomTypeConfig>> {
+        Ok(self.config()?.custom_types.get(&custom.orig_name).cloned())
+    }
+}
@@ -0,0 +1,98 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Render a default value as a C++ expression
+///
+/// These are used for both default arguments and default member initializers.
+pub fn render_default(default: &general::DefaultValue, context: &Context) -> Result<String> {
+    Ok(match default {
+        // Value-initialization gives the same defaults as Rust for all the types we support: zero,
+        // false, empty strings/collections, `std::nullopt`, and the default constructor for records
+        // and objects.
+        general::DefaultValue::Default(tn) => format!("{}{{}}", types::type_name(&tn.ty, context)?),
+        general::DefaultValue::Literal(lit) => render_literal(lit, context)?,
+    })
+}
+
+pub fn render_literal(lit: &general::Literal, context: &Context) -> Result<String> {
+    Ok(match lit {
+        general::Literal::Boolean(true) => "true".to_string(),
+        general::Literal::Boolean(false) => "false".to_string(),
+        general::Literal::String(s) => format!("\"{}\"", escape_string(s)),
+        // `-9223372036854775808ll` parses as negating a literal that's too large for `long long`
+        general::Literal::Int(i64::MIN, _, _) => "(-9223372036854775807ll - 1)".to_string(),
+        general::Literal::Int(i, radix, tn) => {
+            let sign = if *i < 0 { "-" } else { "" };
+            format!(
+                "{sign}{}{}",
+                render_digits(i.unsigned_abs(), radix),
+                int_suffix(&tn.ty)
+            )
+        }
+        general::Literal::UInt(i, radix, tn) => {
+            format!("{}{}", render_digits(*i, radix), int_suffix(&tn.ty))
+        }
+        general::Literal::Float(value, _) => value.clone(),
+        general::Literal::EmptySequence
+        | general::Literal::EmptyMap
+        | general::Literal::EmptySet => "{}".to_string(),
+        general::Literal::None => "std::nullopt".to_string(),
+        general::Literal::Some { inner } => render_default(inner, context)?,
+        general::Literal::Enum(variant, ty) => match &ty.ty {
+            Type::Enum {
+                namespace, name, ..
+            } => {
+                let type_name = types::type_name(&ty.ty, context)?;
+                let variant = names::variant_name(variant);
+                if context.has_variant_classes(namespace, name) {
+                    format!("{type_name}{{{type_name}::{variant}{{}}}}")
+                } else {
+                    format!("{type_name}::{variant}")
+                }
+            }
+            type_kind => {
+                bail!("Invalid type for enum literal: {type_kind:?}")
+            }
+        },
+    })
+}
+
+fn render_digits(i: u64, radix: &Radix) -> String {
+    match radix {
+        // C++ octal literals use a plain `0` prefix
+        Radix::Octal => format!("0{i:o}"),
+        Radix::Decimal => format!("{i}"),
+        Radix::Hexadecimal => format!("{i:#x}"),
+    }
+}
+
+/// Suffix needed for integer literals that don't fit in an `int`
+fn int_suffix(ty: &Type) -> &'static str {
+    match ty {
+        Type::UInt32 => "u",
+        Type::Int64 => "ll",
+        Type::UInt64 => "ull",
+        _ => "",
+    }
+}
+
+fn escape_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            // Use an escape for other control characters, ending the string segment so that
+            // following hex digits aren't parsed as part of it.
+            c if c.is_ascii_control() => escaped.push_str(&format!("\\x{:02x}\"\"", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,52 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn map_enum(en: general::Enum, context: &Context) -> Result<Enum> {
+    let kind = if en.self_type.is_used_as_error {
+        EnumKind::Error {
+            flat: matches!(en.shape, EnumShape::Error { flat: true }),
+        }
+    } else if en.is_flat {
+        EnumKind::Flat
+    } else {
+        EnumKind::Data
+    };
+    Ok(Enum {
+        name: names::type_name(&en.name),
+        kind,
+        variants: map_variants(en.variants, context)?,
+        discr_type: en.discr_type.map_node(context)?,
+        docstring: en.docstring,
+        self_type: en.self_type.map_node(context)?,
+        constructors: en.constructors.map_node(context)?,
+        methods: en.methods.map_node(context)?,
+        uniffi_trait_methods: en.uniffi_trait_methods.map_node(context)?,
+    })
+}
+
+pub fn map_variants(variants: Vec<general::Variant>, context: &Context) -> Result<Vec<Variant>> {
+    variants
+        .into_iter()
+        .map(|v| {
+            Ok(Variant {
+                name: names::variant_name(&v.name),
+                discr: v.discr.map_node(context)?,
+                fields_kind: v.fields_kind,
+                fields: fields::map_fields(v.fields, context)?,
+                docstring: v.docstring,
+            })
+        })
+        .collect()
+}
+
+pub fn enum_variant_name(name: &str, ty: &general::TypeNode) -> Result<String> {
+    Ok(match &ty.ty {
+        Type::Enum { .. } => names::variant_name(name),
+        type_kind => {
+            bail!("Invalid type for enum literal: {type_kind:?}")
+        }
+    })
+}
@@ -0,0 +1,33 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// C type for an FFI type
+///
+/// These match the types in the Swift bridging header, minus the nullability annotations which
+/// only Clang understands.
+pub fn ffi_type_name(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        FfiType::Int8 => "int8_t".to_string(),
+        FfiType::UInt8 => "uint8_t".to_string(),
+        FfiType::Int16 => "int16_t".to_string(),
+        FfiType::UInt16 => "uint16_t".to_string(),
+        FfiType::Int32 => "int32_t".to_string(),
+        FfiType::UInt32 => "uint32_t".to_string(),
+        FfiType::Int64 => "int64_t".to_string(),
+        FfiType::UInt64 => "uint64_t".to_string(),
+        FfiType::Float32 => "float".to_string(),
+        FfiType::Float64 => "double".to_string(),
+        FfiType::Handle(_) => "uint64_t".to_string(),
+        FfiType::RustBuffer(_) => "RustBuffer".to_string(),
+        FfiType::RustCallStatus => "RustCallStatus".to_string(),
+        FfiType::ForeignBytes => "ForeignBytes".to_string(),
+        FfiType::Function(name) => name.clone().map_node(context)?.0,
+        FfiType::Struct(name) => name.clone().map_node(context)?.0,
+        FfiType::Reference(inner) => format!("const {}*", ffi_type_name(inner, context)?),
+        FfiType::MutReference(inner) => format!("{}*", ffi_type_name(inner, context)?),
+        FfiType::VoidPointer => "void*".to_string(),
+    })
+}
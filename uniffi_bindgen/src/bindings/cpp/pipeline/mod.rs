@@ -0,0 +1,31 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashSet;
+
+use anyhow::{anyhow, bail, Result};
+use indexmap::IndexSet;
+
+use uniffi_pipeline::{use_prev_node, MapNode, Node, Pipeline};
+mod config;
+mod context;
+mod default;
+mod enums;
+mod ffi_types;
+mod fields;
+mod modules;
+mod names;
+pub mod nodes;
+mod sort;
+mod types;
+
+pub use config::*;
+pub use context::Context;
+pub use nodes::*;
+
+pub use crate::pipeline::{general, initial};
+
+pub fn pipeline() -> Pipeline<initial::Root, Root> {
+    general::pipeline("cpp").pass::<Root, Context>(Context::default())
+}
@@ -0,0 +1,144 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::BTreeSet;
+
+pub use super::*;
+
+pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result<Module> {
+    check_supported(&namespace)?;
+
+    let mut child_context = context.clone();
+    let context = &mut child_context;
+    context.update_from_namespace(&namespace)?;
+
+    Ok(Module {
+        cpp_namespace: names::namespace_name(&namespace.name),
+        checksum_mode: context.checksum_mode()?.clone(),
+        includes: module_includes(&namespace),
+        name: namespace.name.map_node(context)?,
+        crate_name: namespace.crate_name.map_node(context)?,
+        docstring: namespace.docstring.map_node(context)?,
+        functions: namespace.functions.map_node(context)?,
+        constants: namespace.constants.map_node(context)?,
+        type_definitions: sort::sort_type_definitions(
+            namespace.type_definitions.map_node(context)?,
+        ),
+        ffi_definitions: namespace.ffi_definitions.map_node(context)?,
+        checksums: namespace.checksums.map_node(context)?,
+        ffi_rustbuffer_alloc: namespace.ffi_rustbuffer_alloc,
+        ffi_rustbuffer_from_bytes: namespace.ffi_rustbuffer_from_bytes,
+        ffi_rustbuffer_free: namespace.ffi_rustbuffer_free,
+        ffi_rustbuffer_reserve: namespace.ffi_rustbuffer_reserve,
+        ffi_uniffi_contract_version: namespace.ffi_uniffi_contract_version,
+        correct_contract_version: namespace.correct_contract_version,
+    })
+}
+
+/// Check that a namespace only uses features that the C++ bindings support
+///
+/// Anything that needs callbacks from Rust into C++ isn't supported yet, neither are errors that
+/// are also used as regular values.
+fn check_supported(namespace: &general::Namespace) -> Result<()> {
+    let name = &namespace.name;
+    let unsupported = if namespace.has_descendant(|c: &general::Callable| c.is_async()) {
+        Some("async functions")
+    } else if namespace.has_descendant(|_: &general::StreamType| true) {
+        Some("streams")
+    } else if namespace.has_descendant(|_: &general::CallbackInterface| true) {
+        Some("callback interfaces")
+    } else if namespace.has_descendant(|int: &general::Interface| {
+        matches!(int.imp, ObjectImpl::Trait(TraitKind::ForeignOnly))
+    }) {
+        Some("traits that can only be implemented by foreign code")
+    } else if namespace.has_descendant(|_: &general::ClosureType| true) {
+        Some("closures")
+    } else if namespace.has_descendant(|ty: &Type| matches!(ty, Type::Int128 | Type::UInt128)) {
+        Some("128-bit integers")
+    } else if namespace.has_descendant(|ty: &Type| matches!(ty, Type::Date)) {
+        Some("dates")
+    } else if namespace.has_descendant(|int: &general::Interface| int.self_type.is_used_as_error) {
+        Some("interfaces used as errors")
+    } else if namespace.has_descendant(|en: &general::Enum| {
+        en.self_type.is_used_as_error && !(en.methods.is_empty() && en.constructors.is_empty())
+    }) {
+        Some("methods on errors")
+    } else {
+        None
+    };
+    if let Some(unsupported) = unsupported {
+        bail!("{name}: {unsupported} are not supported by the C++ bindings");
+    }
+    check_error_values(namespace)
+}
+
+/// Check that errors aren't used as regular values
+///
+/// Errors are rendered as exception class hierarchies, which can't be stored by value without
+/// slicing.  Boxed errors are allowed, since those are stored in a `std::shared_ptr`.
+fn check_error_values(namespace: &general::Namespace) -> Result<()> {
+    let mut errors = HashSet::new();
+    namespace.visit(|throws_type: &general::ThrowsType| {
+        if let Some(tn) = &throws_type.ty {
+            errors.insert(tn.ty.clone());
+        }
+    });
+    namespace.visit(|en: &general::Enum| {
+        if en.self_type.is_used_as_error {
+            errors.insert(en.self_type.ty.clone());
+        }
+    });
+    let check = |ty: &Type| -> Result<()> {
+        match find_unboxed(ty, &errors) {
+            Some(error_ty) => bail!(
+                "{}: {} is used as both an error and a regular value, which is not supported by the C++ bindings",
+                namespace.name,
+                error_ty.name().unwrap_or_default(),
+            ),
+            None => Ok(()),
+        }
+    };
+    namespace.try_visit(|arg: &general::Argument| check(&arg.ty.ty))?;
+    namespace.try_visit(|return_type: &general::ReturnType| match &return_type.ty {
+        Some(tn) => check(&tn.ty),
+        None => Ok(()),
+    })?;
+    namespace.try_visit(|field: &general::Field| check(&field.ty.ty))?;
+    Ok(())
+}
+
+/// Find a type from `types` inside `ty`, ignoring types inside a `Box`
+fn find_unboxed<'a>(ty: &'a Type, types: &HashSet<Type>) -> Option<&'a Type> {
+    if types.contains(ty) {
+        return Some(ty);
+    }
+    match ty {
+        Type::Optional { inner_type }
+        | Type::Sequence { inner_type }
+        | Type::Array { inner_type, .. }
+        | Type::Set { inner_type } => find_unboxed(inner_type, types),
+        Type::Map {
+            key_type,
+            value_type,
+        } => find_unboxed(key_type, types).or_else(|| find_unboxed(value_type, types)),
+        Type::Tuple { types: elements } => elements.iter().find_map(|t| find_unboxed(t, types)),
+        _ => None,
+    }
+}
+
+/// Headers for the other modules that this module uses types from
+fn module_includes(namespace: &general::Namespace) -> Vec<String> {
+    let mut type_namespaces = BTreeSet::<String>::default();
+    namespace.visit(|ty: &Type| {
+        if let Some(namespace) = ty.namespace() {
+            type_namespaces.insert(namespace.to_string());
+        }
+    });
+    // Don't try to include the current module
+    type_namespaces.remove(&namespace.name);
+    type_namespaces
+        .into_iter()
+        .map(|namespace| format!("{namespace}.hpp"))
+        .collect()
+}
@@ -0,0 +1,183 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use once_cell::sync::Lazy;
+
+use std::collections::HashSet;
+
+use super::*;
+
+// C++ keywords and alternative operator tokens, see
+// https://en.cppreference.com/w/cpp/keyword
+static KEYWORDS: Lazy<HashSet<String>> = Lazy::new(|| {
+    let kwlist = vec![
+        "alignas",
+        "alignof",
+        "and",
+        "and_eq",
+        "asm",
+        "auto",
+        "bitand",
+        "bitor",
+        "bool",
+        "break",
+        "case",
+        "catch",
+        "char",
+        "char8_t",
+        "char16_t",
+        "char32_t",
+        "class",
+        "compl",
+        "concept",
+        "const",
+        "consteval",
+        "constexpr",
+        "constinit",
+        "const_cast",
+        "continue",
+        "co_await",
+        "co_return",
+        "co_yield",
+        "decltype",
+        "default",
+        "delete",
+        "do",
+        "double",
+        "dynamic_cast",
+        "else",
+        "enum",
+        "explicit",
+        "export",
+        "extern",
+        "false",
+        "float",
+        "for",
+        "friend",
+        "goto",
+        "if",
+        "inline",
+        "int",
+        "long",
+        "mutable",
+        "namespace",
+        "new",
+        "noexcept",
+        "not",
+        "not_eq",
+        "nullptr",
+        "operator",
+        "or",
+        "or_eq",
+        "private",
+        "protected",
+        "public",
+        "register",
+        "reinterpret_cast",
+        "requires",
+        "return",
+        "short",
+        "signed",
+        "sizeof",
+        "static",
+        "static_assert",
+        "static_cast",
+        "struct",
+        "switch",
+        "template",
+        "this",
+        "thread_local",
+        "throw",
+        "true",
+        "try",
+        "typedef",
+        "typeid",
+        "typename",
+        "union",
+        "unsigned",
+        "using",
+        "virtual",
+        "void",
+        "volatile",
+        "wchar_t",
+        "while",
+        "xor",
+        "xor_eq",
+    ];
+    HashSet::from_iter(kwlist.into_iter().map(|s| s.to_string()))
+});
+
+/// Fixup a name by ensuring it's not a keyword
+fn fixup_keyword(name: String) -> String {
+    if KEYWORDS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+pub fn map_ffi_function_type_name(
+    ffi_function_type_name: FfiFunctionTypeName,
+    _: &Context,
+) -> Result<FfiFunctionTypeName> {
+    Ok(FfiFunctionTypeName(ffi_definition_name(
+        &ffi_function_type_name.0,
+    )))
+}
+
+pub fn map_ffi_struct_name(ffi_struct_name: FfiStructName, _: &Context) -> Result<FfiStructName> {
+    Ok(FfiStructName(ffi_definition_name(&ffi_struct_name.0)))
+}
+
+/// Name of a C struct/function pointer typedef
+///
+/// This matches the names in the Swift bridging header, so that both headers can be included in
+/// the same translation unit.
+pub fn ffi_definition_name(name: &str) -> String {
+    format!("Uniffi{}", name.to_upper_camel_case())
+}
+
+/// Name of the `#ifndef` guard for an FFI definition
+///
+/// This also matches the Swift bridging header.
+pub fn if_guard_name(name: &str) -> String {
+    format!("UNIFFI_FFIDEF_{}", name.to_shouty_snake_case())
+}
+
+/// C++ namespace for a module
+pub fn namespace_name(namespace: &str) -> String {
+    fixup_keyword(namespace.to_snake_case())
+}
+
+pub fn type_name(name: &str) -> String {
+    fixup_keyword(name.to_upper_camel_case())
+}
+
+pub fn var_name(name: &str) -> String {
+    fixup_keyword(name.to_snake_case())
+}
+
+pub fn function_name(name: &str) -> String {
+    fixup_keyword(name.to_snake_case())
+}
+
+pub fn const_name(name: &str) -> String {
+    fixup_keyword(name.to_shouty_snake_case())
+}
+
+pub fn variant_name(name: &str) -> String {
+    fixup_keyword(name.to_upper_camel_case())
+}
+
+/// Name for a record/variant field
+///
+/// Unnamed fields are named after their position: `v1`, `v2`, etc.
+pub fn field_name(name: &str, index: usize) -> String {
+    if name.is_empty() {
+        format!("v{}", index + 1)
+    } else {
+        var_name(name)
+    }
+}
@@ -0,0 +1,646 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use askama::Template;
+use indexmap::IndexMap;
+
+use uniffi_pipeline::{MapNode, Node};
+
+use crate::{bindings::cpp::filters, pipeline::general};
+
+use super::*;
+
+use_prev_node!(general::Checksum);
+use_prev_node!(general::ChecksumMode);
+use_prev_node!(general::EnumShape);
+use_prev_node!(general::FieldsKind);
+use_prev_node!(general::FfiFunctionKind);
+use_prev_node!(
+    general::FfiFunctionTypeName,
+    names::map_ffi_function_type_name
+);
+use_prev_node!(general::FfiStructName, names::map_ffi_struct_name);
+use_prev_node!(general::FfiType);
+use_prev_node!(general::HandleKind);
+use_prev_node!(general::ObjectImpl);
+use_prev_node!(general::PassBy);
+use_prev_node!(general::Radix);
+use_prev_node!(general::RustFfiFunctionName);
+use_prev_node!(general::TraitKind);
+use_prev_node!(general::Type, types::map_type);
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Root))]
+#[map_node(update_context(context.update_from_root(&self)?))]
+pub struct Root {
+    /// In library mode, the library path the user passed to us
+    pub cdylib: Option<String>,
+    #[map_node(from(namespaces))]
+    pub modules: IndexMap<String, Module>,
+    pub checksum_mode: ChecksumMode,
+}
+
+/// Generated module
+///
+/// Each module is rendered into a single `.hpp` header.  The header starts with the C declarations
+/// for the FFI, followed by the C++ wrappers inside a namespace for the module.
+#[derive(Debug, Clone, Node, MapNode, Template)]
+#[template(syntax = "cpp", escape = "none", path = "Module.hpp")]
+#[map_node(from(general::Namespace))]
+#[map_node(modules::map_namespace)]
+pub struct Module {
+    pub name: String,
+    /// C++ namespace that the wrappers are defined in
+    pub cpp_namespace: String,
+    pub crate_name: String,
+    pub docstring: Option<String>,
+    /// Headers for the other UniFFI modules that this module uses types from
+    pub includes: Vec<String>,
+    pub functions: Vec<Function>,
+    pub constants: Vec<Constant>,
+    pub type_definitions: Vec<TypeDefinition>,
+    pub ffi_definitions: IndexSet<FfiDefinition>,
+    pub checksums: Vec<Checksum>,
+    pub ffi_rustbuffer_alloc: RustFfiFunctionName,
+    pub ffi_rustbuffer_from_bytes: RustFfiFunctionName,
+    pub ffi_rustbuffer_free: RustFfiFunctionName,
+    pub ffi_rustbuffer_reserve: RustFfiFunctionName,
+    pub ffi_uniffi_contract_version: RustFfiFunctionName,
+    // Correct contract version value
+    pub correct_contract_version: String,
+    pub checksum_mode: ChecksumMode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constant))]
+pub struct Constant {
+    #[map_node(names::const_name(&self.name))]
+    pub name: String,
+    pub ty: TypeNode,
+    pub value: LiteralNode,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Function))]
+pub struct Function {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeDefinition))]
+pub enum TypeDefinition {
+    Interface(Interface),
+    /// Callback interfaces aren't supported yet, `modules::check_supported` fails for them
+    CallbackInterface(CallbackInterface),
+    Record(Record),
+    Enum(Enum),
+    Custom(CustomType),
+    /// Type that doesn't contain any other type
+    Simple(TypeNode),
+    /// Compound types
+    Box(BoxedType),
+    Optional(OptionalType),
+    Sequence(SequenceType),
+    Map(MapType),
+    Set(SetType),
+    /// Streams aren't supported yet, `modules::check_supported` fails for them
+    Stream(StreamType),
+    Tuple(TupleType),
+    Array(ArrayType),
+    /// Closures aren't supported yet, `modules::check_supported` fails for them
+    Closure(ClosureType),
+    /// User types that are defined in another crate
+    External(ExternalType),
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constructor))]
+pub struct Constructor {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Method))]
+pub struct Method {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+/// Common data from Function/Method/Constructor
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Callable))]
+pub struct Callable {
+    #[map_node(names::function_name(&self.name))]
+    pub name: String,
+    pub kind: CallableKind,
+    pub arguments: Vec<Argument>,
+    pub return_type: ReturnType,
+    pub throws_type: ThrowsType,
+    pub checksum: Option<u16>,
+    pub ffi_func: RustFfiFunctionName,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::CallableKind))]
+pub enum CallableKind {
+    /// Toplevel function
+    Function,
+    /// Interface/Trait interface method
+    Method {
+        self_type: TypeNode,
+        takes_self_by_arc: bool,
+    },
+    /// Interface constructor
+    Constructor { self_type: TypeNode, primary: bool },
+    /// Interface static method, these don't take a `self` argument
+    StaticMethod { self_type: TypeNode },
+    /// Method inside a VTable or a CallbackInterface
+    VTableMethod {
+        self_type: TypeNode,
+        takes_self_by_arc: bool,
+        for_callback_interface: bool,
+    },
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::ReturnType))]
+#[map_node(types::map_return_type)]
+pub struct ReturnType {
+    pub ty: Option<TypeNode>,
+    pub type_name: String,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::ThrowsType))]
+pub struct ThrowsType {
+    /// Type passed to `uniffi_detail::rust_call` to handle errors returned by Rust
+    #[map_node(types::error_handler(&self, context)?)]
+    pub error_handler: String,
+    pub ty: Option<TypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Argument))]
+pub struct Argument {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// C++ parameter type
+    #[map_node(types::param_type(&self, context)?)]
+    pub param_type: String,
+    pub ty: TypeNode,
+    pub pass_by: PassBy,
+    pub default: Option<DefaultValueNode>,
+}
+
+impl Argument {
+    /// Is this a `&[u8]` or `&mut [u8]` argument?
+    ///
+    /// These are passed to Rust as `ForeignBytes` that borrow the vector's data, rather than
+    /// copying it into a `RustBuffer`.
+    pub fn is_borrowed_bytes(&self) -> bool {
+        matches!(self.pass_by, PassBy::Ref | PassBy::MutRef) && matches!(self.ty.ty, Type::Bytes)
+    }
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::DefaultValue))]
+pub enum DefaultValue {
+    Default(TypeNode),
+    Literal(LiteralNode),
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::DefaultValue))]
+pub struct DefaultValueNode {
+    /// The default value rendered as a C++ expression
+    #[map_node(default::render_default(&self, context)?)]
+    pub cpp_default: String,
+    #[map_node(self.map_node(context)?)]
+    pub default: DefaultValue,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Literal))]
+pub struct LiteralNode {
+    /// The literal rendered as a C++ expression
+    #[map_node(default::render_literal(&self, context)?)]
+    pub cpp_lit: String,
+    #[map_node(self.map_node(context)?)]
+    pub lit: Literal,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Literal))]
+pub enum Literal {
+    Boolean(bool),
+    String(String),
+    // Integers are represented as the widest representation we can.
+    // Number formatting vary with language and radix, so we avoid a lot of parsing and
+    // formatting duplication by using only signed and unsigned variants.
+    UInt(u64, Radix, TypeNode),
+    Int(i64, Radix, TypeNode),
+    // Pass the string representation through as typed in the UDL.
+    // This avoids a lot of uncertainty around precision and accuracy,
+    // though bindings for languages less sophisticated number parsing than WebIDL
+    // will have to do extra work.
+    Float(String, TypeNode),
+    Enum(
+        #[map_node(enums::enum_variant_name(&var0, &var1)?)] String,
+        TypeNode,
+    ),
+    EmptySequence,
+    EmptyMap,
+    EmptySet,
+    None,
+    Some {
+        inner: Box<DefaultValue>,
+    },
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Record))]
+pub struct Record {
+    #[map_node(names::type_name(&self.name))]
+    pub name: String,
+    pub fields_kind: FieldsKind,
+    #[map_node(fields::map_fields(self.fields, context)?)]
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub constructors: Vec<Constructor>,
+    pub methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Field {
+    pub name: String,
+    pub ty: TypeNode,
+    pub default: Option<DefaultValueNode>,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Enum))]
+#[map_node(enums::map_enum)]
+pub struct Enum {
+    pub name: String,
+    /// How the enum is represented in C++
+    pub kind: EnumKind,
+    pub variants: Vec<Variant>,
+    pub discr_type: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub constructors: Vec<Constructor>,
+    pub methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+}
+
+#[derive(Debug, Clone, Node, PartialEq, Eq)]
+pub enum EnumKind {
+    /// Enum without associated data, rendered as an `enum class`
+    Flat,
+    /// Enum with associated data, rendered as a struct with a `std::variant` of the variant
+    /// structs
+    Data,
+    /// Error, rendered as an exception class with a subclass for each variant
+    ///
+    /// Flat errors only have a message, their fields aren't passed across the FFI.
+    Error { flat: bool },
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Variant {
+    pub name: String,
+    pub discr: LiteralNode,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Interface))]
+pub struct Interface {
+    #[map_node(names::type_name(&self.name))]
+    pub name: String,
+    pub docstring: Option<String>,
+    pub constructors: Vec<Constructor>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub imp: ObjectImpl,
+    pub self_type: TypeNode,
+    pub ffi_func_clone: RustFfiFunctionName,
+    pub ffi_func_free: RustFfiFunctionName,
+}
+
+impl Interface {
+    pub fn primary_constructor(&self) -> Option<&Constructor> {
+        self.constructors
+            .iter()
+            .find(|c| c.callable.is_primary_constructor())
+    }
+
+    pub fn secondary_constructors(&self) -> impl Iterator<Item = &Constructor> {
+        self.constructors
+            .iter()
+            .filter(|c| !c.callable.is_primary_constructor())
+    }
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallbackInterface))]
+pub struct CallbackInterface {
+    #[map_node(names::type_name(&self.name))]
+    pub name: String,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CustomType))]
+pub struct CustomType {
+    #[map_node(names::type_name(&self.name))]
+    pub name: String,
+    #[map_node(context.custom_type_config(&self)?)]
+    pub config: Option<CustomTypeConfig>,
+    pub builtin: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::BoxedType))]
+pub struct BoxedType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::OptionalType))]
+pub struct OptionalType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SequenceType))]
+pub struct SequenceType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::MapType))]
+pub struct MapType {
+    pub key: TypeNode,
+    pub value: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SetType))]
+pub struct SetType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TupleType))]
+pub struct TupleType {
+    pub elements: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ArrayType))]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureType))]
+pub struct ClosureType {
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::StreamType))]
+pub struct StreamType {
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ExternalType))]
+pub struct ExternalType {
+    pub namespace: String,
+    pub name: String,
+    pub self_type: TypeNode,
+}
+
+/// Wrap `Type` so that we can add extra fields that are set for all variants.
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::TypeNode))]
+pub struct TypeNode {
+    #[map_node(types::type_name(&self.ty, context)?)]
+    pub type_name: String,
+    #[map_node(types::ffi_converter_name(&self, context)?)]
+    pub ffi_converter_name: String,
+    pub ty: Type,
+    pub canonical_name: String,
+    pub is_used_as_error: bool,
+    pub ffi_type: FfiTypeNode,
+}
+
+/// Like `TypeNode` but for FFI types.
+///
+/// This exists so that language bindings generators can add extra fields
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(FfiType))]
+pub struct FfiTypeNode {
+    /// C type for this FFI type
+    #[map_node(ffi_types::ffi_type_name(&self, context)?)]
+    pub type_name: String,
+    #[map_node(self.map_node(context)?)]
+    pub ty: FfiType,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::UniffiTraitMethods))]
+pub struct UniffiTraitMethods {
+    pub debug_fmt: Option<Method>,
+    pub display_fmt: Option<Method>,
+    pub eq_eq: Option<Method>,
+    pub eq_ne: Option<Method>,
+    pub hash_hash: Option<Method>,
+    pub ord_cmp: Option<Method>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::FfiDefinition))]
+pub enum FfiDefinition {
+    /// FFI Function exported in the Rust library
+    RustFunction(FfiFunction),
+    /// FFI Function definition used in the interface, language, for example a callback interface method.
+    FunctionType(FfiFunctionType),
+    /// Struct definition used in the interface, for example a callback interface Vtable.
+    Struct(FfiStruct),
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunction))]
+pub struct FfiFunction {
+    #[map_node(names::if_guard_name(&self.name.0))]
+    pub if_guard_name: String,
+    pub name: RustFfiFunctionName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+    pub kind: FfiFunctionKind,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunctionType))]
+pub struct FfiFunctionType {
+    #[map_node(names::if_guard_name(&self.name.0))]
+    pub if_guard_name: String,
+    pub name: FfiFunctionTypeName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiReturnType))]
+pub struct FfiReturnType {
+    pub ty: Option<FfiTypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiStruct))]
+pub struct FfiStruct {
+    #[map_node(names::if_guard_name(&self.name.0))]
+    pub if_guard_name: String,
+    pub name: FfiStructName,
+    pub fields: Vec<FfiField>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiField))]
+pub struct FfiField {
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiArgument))]
+pub struct FfiArgument {
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+impl TypeDefinition {
+    pub fn self_type(&self) -> &TypeNode {
+        match self {
+            Self::Interface(int) => &int.self_type,
+            Self::CallbackInterface(cbi) => &cbi.self_type,
+            Self::Record(rec) => &rec.self_type,
+            Self::Enum(en) => &en.self_type,
+            Self::Custom(custom) => &custom.self_type,
+            Self::Simple(type_node) => type_node,
+            Self::Box(boxed) => &boxed.self_type,
+            Self::Optional(opt) => &opt.self_type,
+            Self::Sequence(seq) => &seq.self_type,
+            Self::Map(map) => &map.self_type,
+            Self::Set(set) => &set.self_type,
+            Self::Stream(stream) => &stream.self_type,
+            Self::Tuple(tuple) => &tuple.self_type,
+            Self::Array(array) => &array.self_type,
+            Self::Closure(closure) => &closure.self_type,
+            Self::External(ext) => &ext.self_type,
+        }
+    }
+}
+
+impl Callable {
+    pub fn is_primary_constructor(&self) -> bool {
+        matches!(self.kind, CallableKind::Constructor { primary: true, .. })
+    }
+
+    pub fn self_type(&self) -> Option<TypeNode> {
+        match &self.kind {
+            CallableKind::Method { self_type, .. }
+            | CallableKind::VTableMethod { self_type, .. } => Some(self_type.clone()),
+            _ => None,
+        }
+    }
+
+    /// Default argument for the argument at `index`
+    ///
+    /// C++ only allows default arguments after all the required ones, so this returns `None` if
+    /// any later argument doesn't have a default.
+    pub fn arg_default(&self, index: &usize) -> Option<&str> {
+        if self.arguments[*index..]
+            .iter()
+            .all(|arg| arg.default.is_some())
+        {
+            self.arguments[*index]
+                .default
+                .as_ref()
+                .map(|d| d.cpp_default.as_str())
+        } else {
+            None
+        }
+    }
+}
+
+impl Enum {
+    pub fn is_flat(&self) -> bool {
+        matches!(self.kind, EnumKind::Flat)
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(self.kind, EnumKind::Error { .. })
+    }
+
+    pub fn is_flat_error(&self) -> bool {
+        matches!(self.kind, EnumKind::Error { flat: true })
+    }
+
+    /// Name of the free function for a constructor of a flat enum
+    ///
+    /// `enum class` types can't have static methods, so we prefix the constructor name with the
+    /// enum name instead.
+    pub fn constructor_function_name(&self, callable: &Callable) -> String {
+        format!(
+            "{}_{}",
+            names::function_name(&self.name),
+            callable.name.trim_end_matches('_')
+        )
+    }
+}
+
+impl CustomType {
+    /// C++ type for custom type values, the configured `type_name` or the builtin type
+    pub fn value_type_name(&self) -> &str {
+        self.config
+            .as_ref()
+            .and_then(|config| config.type_name.as_deref())
+            .unwrap_or(&self.builtin.type_name)
+    }
+}
+
+impl CustomTypeConfig {
+    pub fn lift(&self, name: &str) -> String {
+        self.lift.replace("{}", name)
+    }
+    pub fn lower(&self, name: &str) -> String {
+        self.lower.replace("{}", name)
+    }
+}
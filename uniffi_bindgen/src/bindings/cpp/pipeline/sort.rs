@@ -0,0 +1,136 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Sort type definitions so that C++ classes are defined after the types they store by value
+//!
+//! The general pipeline sorts type definitions so that dependencies come first, but it breaks
+//! cycles arbitrarily.  That's a problem for C++, where a class can only be defined once the
+//! types of its fields are complete.  For example, with `RoseTree → RoseData → Vec<RoseTree>`,
+//! `RoseData` needs to be defined first, since `std::vector` allows incomplete element types.
+
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+
+use super::*;
+
+pub fn sort_type_definitions(type_definitions: Vec<TypeDefinition>) -> Vec<TypeDefinition> {
+    let names: HashMap<Type, String> = type_definitions
+        .iter()
+        .map(|type_def| {
+            let self_type = type_def.self_type();
+            (self_type.ty.clone(), self_type.canonical_name.clone())
+        })
+        .collect();
+    let mut unsorted: IndexMap<String, TypeDefinition> = type_definitions
+        .into_iter()
+        .map(|type_def| (type_def.self_type().canonical_name.clone(), type_def))
+        .collect();
+    let mut sorted = vec![];
+    while let Some(name) = unsorted.keys().next() {
+        recurse(name.clone(), &names, &mut unsorted, &mut sorted);
+    }
+    sorted
+}
+
+fn recurse(
+    name: String,
+    names: &HashMap<Type, String>,
+    unsorted: &mut IndexMap<String, TypeDefinition>,
+    sorted: &mut Vec<TypeDefinition>,
+) {
+    let Some(type_def) = unsorted.shift_remove(&name) else {
+        // Already processed
+        return;
+    };
+    for name in dependency_names(&type_def, names) {
+        recurse(name, names, unsorted, sorted);
+    }
+    sorted.push(type_def);
+}
+
+fn dependency_names(type_def: &TypeDefinition, names: &HashMap<Type, String>) -> Vec<String> {
+    let mut types = vec![];
+    match type_def {
+        // Compound types and custom types are aliases for converters that need their inner
+        // converters to be declared first.
+        TypeDefinition::Box(BoxedType { inner, .. })
+        | TypeDefinition::Optional(OptionalType { inner, .. })
+        | TypeDefinition::Sequence(SequenceType { inner, .. })
+        | TypeDefinition::Array(ArrayType { inner, .. })
+        | TypeDefinition::Set(SetType { inner, .. })
+        | TypeDefinition::Custom(CustomType { builtin: inner, .. }) => types.push(&inner.ty),
+        TypeDefinition::Map(MapType { key, value, .. }) => {
+            types.push(&key.ty);
+            types.push(&value.ty);
+        }
+        TypeDefinition::Tuple(TupleType { elements, .. }) => {
+            types.extend(elements.iter().map(|ty| &ty.ty))
+        }
+        // Classes need the types they store by value to be complete
+        TypeDefinition::Record(rec) => {
+            for field in rec.fields.iter() {
+                add_complete_types(&field.ty.ty, &mut types);
+            }
+            add_default_arg_types(&rec.constructors, &rec.methods, &mut types);
+        }
+        TypeDefinition::Enum(en) => {
+            for field in en.variants.iter().flat_map(|v| v.fields.iter()) {
+                add_complete_types(&field.ty.ty, &mut types);
+            }
+            add_default_arg_types(&en.constructors, &en.methods, &mut types);
+        }
+        TypeDefinition::Interface(int) => {
+            let methods = int.methods.iter().chain(int.static_methods.iter());
+            add_default_arg_types(&int.constructors, methods, &mut types);
+        }
+        _ => (),
+    }
+    types
+        .into_iter()
+        .filter_map(|ty| names.get(ty).cloned())
+        .collect()
+}
+
+/// Add the types that need to be complete to store a `ty` value
+fn add_complete_types<'a>(ty: &'a Type, types: &mut Vec<&'a Type>) {
+    match ty {
+        Type::Record { .. } | Type::Enum { .. } | Type::Custom { .. } => types.push(ty),
+        Type::Optional { inner_type }
+        | Type::Array { inner_type, .. }
+        | Type::Set { inner_type } => add_complete_types(inner_type, types),
+        Type::Map {
+            key_type,
+            value_type,
+        } => {
+            add_complete_types(key_type, types);
+            add_complete_types(value_type, types);
+        }
+        Type::Tuple { types: elements } => {
+            for element in elements {
+                add_complete_types(element, types);
+            }
+        }
+        // `std::vector` and `std::shared_ptr` allow incomplete types
+        _ => (),
+    }
+}
+
+/// Default arguments are evaluated when the class is complete, so their types need to be
+/// complete as well.
+fn add_default_arg_types<'a>(
+    constructors: &'a [Constructor],
+    methods: impl IntoIterator<Item = &'a Method>,
+    types: &mut Vec<&'a Type>,
+) {
+    let callables = constructors
+        .iter()
+        .map(|cons| &cons.callable)
+        .chain(methods.into_iter().map(|meth| &meth.callable));
+    for arg in callables.flat_map(|callable| callable.arguments.iter()) {
+        if arg.default.is_some() {
+            add_complete_types(&arg.ty.ty, types);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn map_type(mut ty: Type, _: &Context) -> Result<Type> {
+    rename_type(&mut ty);
+    Ok(ty)
+}
+
+fn rename_type(ty: &mut Type) {
+    match ty {
+        Type::Enum { name, .. }
+        | Type::Record { name, .. }
+        | Type::Interface { name, .. }
+        | Type::CallbackInterface { name, .. }
+        | Type::Custom { name, .. } => {
+            *name = names::type_name(name);
+        }
+        Type::Optional { inner_type }
+        | Type::Sequence { inner_type }
+        | Type::Array { inner_type, .. }
+        | Type::Set { inner_type }
+        | Type::Stream { inner_type }
+        | Type::Box { inner_type } => {
+            rename_type(inner_type);
+        }
+        Type::Map {
+            key_type,
+            value_type,
+        } => {
+            rename_type(key_type);
+            rename_type(value_type);
+        }
+        Type::Tuple { types } => {
+            for ty in types {
+                rename_type(ty);
+            }
+        }
+        _ => (),
+    }
+}
+
+pub fn map_return_type(return_type: general::ReturnType, context: &Context) -> Result<ReturnType> {
+    Ok(match return_type.ty {
+        Some(ty) => {
+            let ty = ty.map_node(context)?;
+            ReturnType {
+                type_name: ty.type_name.clone(),
+                ty: Some(ty),
+            }
+        }
+        None => ReturnType {
+            ty: None,
+            type_name: "void".to_string(),
+        },
+    })
+}
+
+/// C++ type name
+pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Boolean => "bool".to_string(),
+        Type::Int8 => "int8_t".to_string(),
+        Type::Int16 => "int16_t".to_string(),
+        Type::Int32 => "int32_t".to_string(),
+        Type::Int64 => "int64_t".to_string(),
+        Type::UInt8 => "uint8_t".to_string(),
+        Type::UInt16 => "uint16_t".to_string(),
+        Type::UInt32 => "uint32_t".to_string(),
+        Type::UInt64 => "uint64_t".to_string(),
+        Type::Float32 => "float".to_string(),
+        Type::Float64 => "double".to_string(),
+        Type::String | Type::Url => "std::string".to_string(),
+        Type::Bytes | Type::OwnedBytes => "std::vector<uint8_t>".to_string(),
+        Type::Timestamp => "std::chrono::system_clock::time_point".to_string(),
+        Type::Duration => "std::chrono::nanoseconds".to_string(),
+        Type::Uuid => "std::array<uint8_t, 16>".to_string(),
+        Type::Interface {
+            namespace, name, ..
+        }
+        | Type::Record {
+            namespace, name, ..
+        }
+        | Type::Enum {
+            namespace, name, ..
+        }
+        | Type::Custom {
+            namespace, name, ..
+        } => {
+            let type_name = names::type_name(name);
+            match context.external_namespace(namespace)? {
+                Some(cpp_namespace) => format!("::{cpp_namespace}::{type_name}"),
+                // Variant classes nested inside enums/errors shadow top-level types with the same
+                // name, so qualify those.
+                None if context.nested_class_names.contains(&type_name) => {
+                    format!("::{}::{type_name}", names::namespace_name(namespace))
+                }
+                None => type_name,
+            }
+        }
+        Type::Optional { inner_type } => {
+            format!("std::optional<{}>", type_name(inner_type, context)?)
+        }
+        Type::Sequence { inner_type } => {
+            format!("std::vector<{}>", type_name(inner_type, context)?)
+        }
+        Type::Array { inner_type, len } => {
+            format!("std::array<{}, {len}>", type_name(inner_type, context)?)
+        }
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "std::unordered_map<{}, {}>",
+            type_name(key_type, context)?,
+            type_name(value_type, context)?
+        ),
+        Type::Set { inner_type } => {
+            format!("std::unordered_set<{}>", type_name(inner_type, context)?)
+        }
+        // Boxes are needed for recursive types, which C++ can only represent with a pointer.
+        Type::Box { inner_type } => {
+            format!("std::shared_ptr<{}>", type_name(inner_type, context)?)
+        }
+        Type::Tuple { types } => format!(
+            "std::tuple<{}>",
+            types
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        Type::Int128 | Type::UInt128 => {
+            bail!("128-bit integers are not supported by the C++ bindings")
+        }
+        Type::Date => bail!("Dates are not supported by the C++ bindings"),
+        Type::CallbackInterface { .. } => {
+            bail!("Callback interfaces are not supported by the C++ bindings")
+        }
+        Type::Stream { .. } => bail!("Streams are not supported by the C++ bindings"),
+        Type::Closure { .. } => bail!("Closures are not supported by the C++ bindings"),
+    })
+}
+
+/// C++ parameter type for an argument
+///
+/// Small values are passed by value, everything else is passed by const reference.
+pub fn param_type(arg: &general::Argument, context: &Context) -> Result<String> {
+    if arg.is_borrowed_bytes_mut() {
+        return Ok("std::vector<uint8_t>&".to_string());
+    }
+    let type_name = type_name(&arg.ty.ty, context)?;
+    Ok(if is_passed_by_value(&arg.ty.ty, context)? {
+        type_name
+    } else {
+        format!("const {type_name}&")
+    })
+}
+
+fn is_passed_by_value(ty: &Type, context: &Context) -> Result<bool> {
+    Ok(match ty {
+        Type::Boolean
+        | Type::Int8
+        | Type::Int16
+        | Type::Int32
+        | Type::Int64
+        | Type::UInt8
+        | Type::UInt16
+        | Type::UInt32
+        | Type::UInt64
+        | Type::Float32
+        | Type::Float64
+        | Type::Timestamp
+        | Type::Duration => true,
+        // Flat enums are rendered as an `enum class`
+        Type::Enum {
+            namespace, name, ..
+        } => !context.has_variant_classes(namespace, name),
+        // Custom types are aliases for their builtin type, unless they're configured to use a
+        // different type.
+        Type::Custom {
+            namespace,
+            orig_name,
+            builtin,
+            ..
+        } => {
+            context.external_namespace(namespace)?.is_none()
+                && !context.config()?.custom_types.contains_key(orig_name)
+                && is_passed_by_value(builtin, context)?
+        }
+        _ => false,
+    })
+}
+
+pub fn ffi_converter_name(ty: &general::TypeNode, context: &Context) -> Result<String> {
+    let cpp_namespace = match ty.ty.namespace() {
+        Some(namespace) => context.external_namespace(namespace)?,
+        _ => None,
+    };
+    Ok(match cpp_namespace {
+        Some(cpp_namespace) => format!(
+            "::{cpp_namespace}::uniffi_detail::FfiConverter{}",
+            ty.canonical_name
+        ),
+        None => format!("uniffi_detail::FfiConverter{}", ty.canonical_name),
+    })
+}
+
+/// Type passed to `uniffi_detail::rust_call` to handle errors returned by Rust
+pub fn error_handler(throws_type: &general::ThrowsType, context: &Context) -> Result<String> {
+    Ok(match &throws_type.ty {
+        Some(ty) => ffi_converter_name(ty, context)?,
+        None => "uniffi_detail::NoErrorHandler".to_string(),
+    })
+}
@@ -0,0 +1,6 @@
+{#- Parameter list for a declaration, including default arguments -#}
+{%- for arg in callable.arguments -%}
+{{ arg.param_type }} {{ arg.name }}
+{%- if let Some(default) = callable.arg_default(loop.index0) %} = {{ default }}{% endif %}
+{%- if !loop.last %}, {% endif %}
+{%- endfor -%}
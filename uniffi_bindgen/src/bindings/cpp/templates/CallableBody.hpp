@@ -0,0 +1,7 @@
+{#- Body for a function/method, `self_expr` is the expression for the receiver of methods -#}
+{%- match callable.return_type.ty %}
+{%- when Some(return_type) %}
+    return {{ return_type.ffi_converter_name }}::lift({% include "RustCall.hpp" %});
+{%- when None %}
+    {% include "RustCall.hpp" %};
+{%- endmatch %}
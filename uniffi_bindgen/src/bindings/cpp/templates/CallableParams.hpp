@@ -0,0 +1,5 @@
+{#- Parameter list for a definition, default arguments are only given in the declaration -#}
+{%- for arg in callable.arguments -%}
+{{ arg.param_type }} {{ arg.name }}
+{%- if !loop.last %}, {% endif %}
+{%- endfor -%}
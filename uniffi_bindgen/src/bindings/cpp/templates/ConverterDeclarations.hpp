@@ -0,0 +1,123 @@
+{#
+ # Converters for each type used in the module.
+ #
+ # Converters for the user-defined types only declare their functions, so they're declared first.
+ # That way the other converters can refer to them, even for recursive types.  The rest are
+ # declared in dependency order.
+ #}
+{%- for type_def in type_definitions %}
+{%- match type_def %}
+
+{%- when TypeDefinition::Record(rec) %}
+{%- let type_name = rec.name %}
+{%- let converter_name = format!("FfiConverter{}", rec.self_type.canonical_name) %}
+{%- include "RustBufferConverterDeclaration.hpp" %}
+
+{%- when TypeDefinition::Enum(e) %}
+{%- let type_name = e.name %}
+{%- let converter_name = format!("FfiConverter{}", e.self_type.canonical_name) %}
+{%- match e.kind %}
+{%- when EnumKind::Error { .. } %}
+
+struct {{ converter_name }} {
+    using value_type = {{ type_name }};
+    [[noreturn]] static void throw_error(RustBuffer buf);
+    static std::shared_ptr<{{ type_name }}> read_shared(Reader& reader);
+    static void write(const {{ type_name }}& value, Writer& writer);
+    // Read an error and pass it to `f`, using the class for the variant
+    template <typename F>
+    static auto read_with(Reader& reader, F&& f);
+};
+{%- else %}
+{%- include "RustBufferConverterDeclaration.hpp" %}
+{%- endmatch %}
+
+{%- when TypeDefinition::Interface(int) %}
+
+struct FfiConverter{{ int.self_type.canonical_name }} {
+    using value_type = {{ int.name }};
+    static {{ int.name }} lift(uint64_t handle);
+    static uint64_t lower(const {{ int.name }}& value);
+    static {{ int.name }} read(Reader& reader);
+    static void write(const {{ int.name }}& value, Writer& writer);
+};
+
+{%- else %}
+{%- endmatch %}
+{%- endfor %}
+
+{%- for type_def in type_definitions %}
+{%- let converter_name = format!("FfiConverter{}", type_def.self_type().canonical_name) %}
+{%- match type_def %}
+
+{%- when TypeDefinition::Simple(type_node) %}
+{%- match type_node.ty %}
+{%- when Type::Boolean %}
+using {{ converter_name }} = ::uniffi::detail::BooleanConverter;
+{%- when Type::Int8 | Type::Int16 | Type::Int32 | Type::Int64 | Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64 %}
+using {{ converter_name }} = ::uniffi::detail::IntConverter<{{ type_node.type_name }}>;
+{%- when Type::Float32 %}
+using {{ converter_name }} = ::uniffi::detail::FloatConverter;
+{%- when Type::Float64 %}
+using {{ converter_name }} = ::uniffi::detail::DoubleConverter;
+{%- when Type::String %}
+{#- Declared in `Helpers.hpp`, since it's needed to lift panic messages #}
+{%- when Type::Url %}
+using {{ converter_name }} = RustBufferConverter<::uniffi::detail::StringConverter>;
+{%- when Type::Bytes %}
+using {{ converter_name }} = RustBufferConverter<::uniffi::detail::BytesConverter>;
+{%- when Type::OwnedBytes %}
+/// Owned bytes are passed across the FFI as a `RustBuffer` containing the raw data
+struct {{ converter_name }} : ::uniffi::detail::BytesConverter {
+    static std::vector<uint8_t> lift(RustBuffer buf) {
+        std::vector<uint8_t> value(buf.data, buf.data + buf.len);
+        free_rust_buffer(buf);
+        return value;
+    }
+    static RustBuffer lower(const std::vector<uint8_t>& value) {
+        return rust_buffer_from_bytes(value.data(), value.size());
+    }
+};
+{%- when Type::Timestamp %}
+using {{ converter_name }} = RustBufferConverter<::uniffi::detail::TimestampConverter>;
+{%- when Type::Duration %}
+using {{ converter_name }} = RustBufferConverter<::uniffi::detail::DurationConverter>;
+{%- when Type::Uuid %}
+using {{ converter_name }} = RustBufferConverter<::uniffi::detail::UuidConverter>;
+{%- else %}
+{#- `modules::check_supported` fails for any other simple types #}
+{%- endmatch %}
+
+{%- when TypeDefinition::Optional(opt) %}
+using {{ converter_name }} = RustBufferConverter<::uniffi::detail::OptionalConverter<{{ opt.inner.ffi_converter_name }}>>;
+
+{%- when TypeDefinition::Sequence(seq) %}
+using {{ converter_name }} = RustBufferConverter<::uniffi::detail::SequenceConverter<{{ seq.inner.ffi_converter_name }}>>;
+
+{%- when TypeDefinition::Array(array) %}
+using {{ converter_name }} = RustBufferConverter<::uniffi::detail::ArrayConverter<{{ array.inner.ffi_converter_name }}, {{ array.len }}>>;
+
+{%- when TypeDefinition::Map(map) %}
+using {{ converter_name }} = RustBufferConverter<::uniffi::detail::MapConverter<{{ map.key.ffi_converter_name }}, {{ map.value.ffi_converter_name }}>>;
+
+{%- when TypeDefinition::Set(set) %}
+using {{ converter_name }} = RustBufferConverter<::uniffi::detail::SetConverter<{{ set.inner.ffi_converter_name }}>>;
+
+{%- when TypeDefinition::Tuple(tuple) %}
+using {{ converter_name }} = RustBufferConverter<::uniffi::detail::TupleConverter<
+    {%- for element in tuple.elements %}{{ element.ffi_converter_name }}{% if !loop.last %}, {% endif %}{% endfor -%}
+>>;
+
+{%- when TypeDefinition::Box(box_) %}
+{%- if box_.inner.is_used_as_error %}
+using {{ converter_name }} = ::uniffi::detail::ErrorBoxConverter<{{ box_.inner.ffi_converter_name }}>;
+{%- else %}
+using {{ converter_name }} = ::uniffi::detail::BoxConverter<{{ box_.inner.ffi_converter_name }}>;
+{%- endif %}
+
+{%- when TypeDefinition::Custom(custom) %}
+{%- include "CustomTypeConverter.hpp" %}
+
+{%- else %}
+{%- endmatch %}
+{%- endfor %}
@@ -0,0 +1,13 @@
+{%- match custom.config %}
+{%- when None %}
+using {{ converter_name }} = {{ custom.builtin.ffi_converter_name }};
+{%- when Some(config) %}
+
+struct {{ converter_name }} {
+    using value_type = {{ custom.name }};
+    static {{ custom.name }} lift({{ custom.builtin.ffi_type.type_name }} value);
+    static {{ custom.builtin.ffi_type.type_name }} lower(const {{ custom.name }}& value);
+    static {{ custom.name }} read(Reader& reader);
+    static void write(const {{ custom.name }}& value, Writer& writer);
+};
+{%- endmatch %}
@@ -0,0 +1,22 @@
+{%- if let Some(config) = custom.config %}
+{%- let converter_name = format!("FfiConverter{}", custom.self_type.canonical_name) %}
+{%- let builtin_converter = custom.builtin.ffi_converter_name %}
+
+inline {{ custom.name }} uniffi_detail::{{ converter_name }}::lift({{ custom.builtin.ffi_type.type_name }} value) {
+    auto builtin_value = {{ builtin_converter }}::lift(value);
+    return {{ config.lift("builtin_value") }};
+}
+
+inline {{ custom.builtin.ffi_type.type_name }} uniffi_detail::{{ converter_name }}::lower(const {{ custom.name }}& value) {
+    return {{ builtin_converter }}::lower({{ config.lower("value") }});
+}
+
+inline {{ custom.name }} uniffi_detail::{{ converter_name }}::read(Reader& reader) {
+    auto builtin_value = {{ builtin_converter }}::read(reader);
+    return {{ config.lift("builtin_value") }};
+}
+
+inline void uniffi_detail::{{ converter_name }}::write(const {{ custom.name }}& value, Writer& writer) {
+    {{ builtin_converter }}::write({{ config.lower("value") }}, writer);
+}
+{%- endif %}
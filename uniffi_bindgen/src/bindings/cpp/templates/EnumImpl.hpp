@@ -0,0 +1,97 @@
+{%- let type_name = e.name %}
+{%- let converter_name = format!("FfiConverter{}", e.self_type.canonical_name) %}
+{%- include "RustBufferConverterImpl.hpp" %}
+{%- if e.is_flat() %}
+{%- let self_expr = "self" %}
+
+inline {{ type_name }} uniffi_detail::{{ converter_name }}::read(Reader& reader) {
+    switch (reader.read_int<int32_t>()) {
+    {%- for variant in e.variants %}
+    case {{ loop.index }}:
+        return {{ type_name }}::{{ variant.name }};
+    {%- endfor %}
+    default:
+        throw ::uniffi::InternalError("UniFFI: invalid {{ type_name }} variant");
+    }
+}
+
+inline void uniffi_detail::{{ converter_name }}::write(const {{ type_name }}& value, Writer& writer) {
+    switch (value) {
+    {%- for variant in e.variants %}
+    case {{ type_name }}::{{ variant.name }}:
+        writer.write_int<int32_t>({{ loop.index }});
+        return;
+    {%- endfor %}
+    }
+    throw std::invalid_argument("UniFFI: invalid {{ type_name }} value");
+}
+{%- for cons in e.constructors %}
+{%-     let callable = cons.callable %}
+
+inline {{ type_name }} {{ e.constructor_function_name(callable) }}({% include "CallableParams.hpp" %}) {
+{%- include "CallableBody.hpp" %}
+}
+{%- endfor %}
+{%- for meth in e.methods %}
+{%-     let callable = meth.callable %}
+
+inline {{ callable.return_type.type_name }} {{ callable.name }}({{ type_name }} self{% if !callable.arguments.is_empty() %}, {% endif %}{% include "CallableParams.hpp" %}) {
+{%- include "CallableBody.hpp" %}
+}
+{%- endfor %}
+{%- if let Some(meth) = e.uniffi_trait_methods.display_fmt %}
+{%-     let callable = meth.callable %}
+
+inline {{ callable.return_type.type_name }} to_string({{ type_name }} self) {
+{%- include "CallableBody.hpp" %}
+}
+{%- endif %}
+{%- if let Some(meth) = e.uniffi_trait_methods.debug_fmt %}
+{%-     let callable = meth.callable %}
+
+inline {{ callable.return_type.type_name }} to_debug_string({{ type_name }} self) {
+{%- include "CallableBody.hpp" %}
+}
+{%- endif %}
+{%- else %}
+
+inline {{ type_name }} uniffi_detail::{{ converter_name }}::read(Reader& reader) {
+    switch (reader.read_int<int32_t>()) {
+    {%- for variant in e.variants %}
+    case {{ loop.index }}: {
+        {%- for field in variant.fields %}
+        auto uniffi_field{{ loop.index0 }} = {{ field.ty.ffi_converter_name }}::read(reader);
+        {%- endfor %}
+        return {{ type_name }}::{{ variant.name }} {
+            {%- for field in variant.fields %}
+            std::move(uniffi_field{{ loop.index0 }}),
+            {%- endfor %}
+        };
+    }
+    {%- endfor %}
+    default:
+        throw ::uniffi::InternalError("UniFFI: invalid {{ type_name }} variant");
+    }
+}
+
+inline void uniffi_detail::{{ converter_name }}::write(const {{ type_name }}& value, Writer& writer) {
+    {%- for variant in e.variants %}
+    {% if !loop.first %}} else {% endif %}if (auto variant = std::get_if<{{ type_name }}::{{ variant.name }}>(&value.variant)) {
+        writer.write_int<int32_t>({{ loop.index }});
+        {%- for field in variant.fields %}
+        {{ field.ty.ffi_converter_name }}::write(variant->{{ field.name }}, writer);
+        {%- endfor %}
+        {%- if variant.fields.is_empty() %}
+        (void)variant;
+        {%- endif %}
+    {%- endfor %}
+    } else {
+        throw std::invalid_argument("UniFFI: {{ type_name }} is valueless");
+    }
+}
+{%- let constructors = e.constructors %}
+{%- let methods = e.methods %}
+{%- let uniffi_trait_methods = e.uniffi_trait_methods %}
+{%- let self_expr = "*this" %}
+{%- include "MemberDefinitions.hpp" %}
+{%- endif %}
@@ -0,0 +1,57 @@
+{%- let type_name = e.name %}
+{%- let constructors = e.constructors %}
+{%- let methods = e.methods %}
+{%- let uniffi_trait_methods = e.uniffi_trait_methods %}
+{%- if e.is_flat() %}
+{#- The `enum class` is defined in `TypeDeclarations.hpp`, constructors and methods are free functions #}
+{%- for cons in constructors %}
+{%-     let callable = cons.callable %}
+
+{{ cons.docstring|docstring(0) -}}
+{{ type_name }} {{ e.constructor_function_name(callable) }}({% include "CallableArgs.hpp" %});
+{%- endfor %}
+{%- for meth in methods %}
+{%-     let callable = meth.callable %}
+
+{{ meth.docstring|docstring(0) -}}
+{{ callable.return_type.type_name }} {{ callable.name }}({{ type_name }} self{% if !callable.arguments.is_empty() %}, {% endif %}{% include "CallableArgs.hpp" %});
+{%- endfor %}
+{%- if let Some(meth) = uniffi_trait_methods.display_fmt %}
+
+/// Format the value using its Rust `Display` implementation
+{{ meth.callable.return_type.type_name }} to_string({{ type_name }} self);
+
+inline std::ostream& operator<<(std::ostream& stream, {{ type_name }} value) {
+    return stream << to_string(value);
+}
+{%- endif %}
+{%- if let Some(meth) = uniffi_trait_methods.debug_fmt %}
+
+/// Format the value using its Rust `Debug` implementation
+{{ meth.callable.return_type.type_name }} to_debug_string({{ type_name }} self);
+{%- endif %}
+{%- else %}
+
+{{ e.docstring|docstring(0) -}}
+struct {{ type_name }} {
+    {%- for variant in e.variants %}
+
+    {{ variant.docstring|docstring(4) -}}
+    struct {{ variant.name }} {
+        {%- for field in variant.fields %}
+        {{ field.docstring|docstring(8) -}}
+        {{ field.ty.type_name }} {{ field.name }}{% if let Some(default) = field.default %} = {{ default.cpp_default }}{% endif %};
+        {%- endfor %}
+    };
+    {%- endfor %}
+
+    std::variant<
+        {%- for variant in e.variants %}{{ variant.name }}{% if !loop.last %}, {% endif %}{% endfor -%}
+    > variant;
+    {%- for variant in e.variants %}
+
+    {{ type_name }}({{ variant.name }} value) : variant(std::move(value)) {}
+    {%- endfor %}
+    {%- include "MemberDeclarations.hpp" %}
+};
+{%- endif %}
@@ -0,0 +1,61 @@
+{%- let type_name = e.name %}
+{%- let converter_name = format!("FfiConverter{}", e.self_type.canonical_name) %}
+
+template <typename F>
+auto uniffi_detail::{{ converter_name }}::read_with(Reader& reader, F&& f) {
+    switch (reader.read_int<int32_t>()) {
+    {%- for variant in e.variants %}
+    case {{ loop.index }}: {
+        {%- if e.is_flat_error() %}
+        auto message = FfiConverterString::read(reader);
+        return f({{ type_name }}::{{ variant.name }}(message));
+        {%- else %}
+        {%- for field in variant.fields %}
+        auto uniffi_field{{ loop.index0 }} = {{ field.ty.ffi_converter_name }}::read(reader);
+        {%- endfor %}
+        return f({{ type_name }}::{{ variant.name }}(
+            {%- for field in variant.fields %}std::move(uniffi_field{{ loop.index0 }}){% if !loop.last %}, {% endif %}{% endfor -%}
+        ));
+        {%- endif %}
+    }
+    {%- endfor %}
+    default:
+        throw ::uniffi::InternalError("UniFFI: invalid {{ type_name }} variant");
+    }
+}
+
+inline void uniffi_detail::{{ converter_name }}::throw_error(RustBuffer buf) {
+    struct Guard {
+        RustBuffer buf;
+        ~Guard() { free_rust_buffer(buf); }
+    } guard { buf };
+    Reader reader(buf.data, static_cast<size_t>(buf.len));
+    read_with(reader, [](auto&& error) { throw std::move(error); });
+    throw ::uniffi::InternalError("UniFFI: failed to read {{ type_name }}");
+}
+
+inline std::shared_ptr<{{ type_name }}> uniffi_detail::{{ converter_name }}::read_shared(Reader& reader) {
+    return read_with(reader, [](auto&& error) -> std::shared_ptr<{{ type_name }}> {
+        return std::make_shared<std::decay_t<decltype(error)>>(std::move(error));
+    });
+}
+
+inline void uniffi_detail::{{ converter_name }}::write(const {{ type_name }}& value, Writer& writer) {
+    {%- for variant in e.variants %}
+    {% if !loop.first %}} else {% endif %}if (auto variant = dynamic_cast<const {{ type_name }}::{{ variant.name }}*>(&value)) {
+        writer.write_int<int32_t>({{ loop.index }});
+        {%- if e.is_flat_error() %}
+        (void)variant;
+        {%- else %}
+        {%- for field in variant.fields %}
+        {{ field.ty.ffi_converter_name }}::write(variant->{{ field.name }}, writer);
+        {%- endfor %}
+        {%- if variant.fields.is_empty() %}
+        (void)variant;
+        {%- endif %}
+        {%- endif %}
+    {%- endfor %}
+    } else {
+        throw std::invalid_argument("UniFFI: unknown {{ type_name }} subclass");
+    }
+}
@@ -0,0 +1,35 @@
+{%- let type_name = e.name %}
+
+{{ e.docstring|docstring(0) -}}
+class {{ type_name }} : public std::runtime_error {
+public:
+    {%- for variant in e.variants %}
+    class {{ variant.name }};
+    {%- endfor %}
+
+protected:
+    explicit {{ type_name }}(const std::string& message) : std::runtime_error(message) {}
+};
+{%- for variant in e.variants %}
+
+{{ variant.docstring|docstring(0) -}}
+class {{ type_name }}::{{ variant.name }} : public {{ type_name }} {
+public:
+    {%- if e.is_flat_error() %}
+    explicit {{ variant.name }}(const std::string& message) : {{ type_name }}(message) {}
+    {%- else %}
+    {% if variant.fields.len() == 1 %}explicit {% endif %}{{ variant.name }}(
+        {%- for field in variant.fields %}{{ field.ty.type_name }} {{ field.name }}{% if !loop.last %}, {% endif %}{% endfor -%}
+    )
+        : {{ type_name }}("{{ variant.name }}")
+        {%- for field in variant.fields %}
+        , {{ field.name }}(std::move({{ field.name }}))
+        {%- endfor %} {}
+    {%- for field in variant.fields %}
+
+    {{ field.docstring|docstring(4) -}}
+    {{ field.ty.type_name }} {{ field.name }};
+    {%- endfor %}
+    {%- endif %}
+};
+{%- endfor %}
@@ -0,0 +1,85 @@
+// C declarations for the FFI functions exported by the Rust library.
+//
+// These match the declarations in the Swift bridging header, so the shared structs use the same
+// header guard.  Argument names are left out, since they can clash with C++ keywords.
+extern "C" {
+
+#ifdef UNIFFI_SHARED_H
+    // We also try to prevent mixing versions of shared uniffi header structs.
+    // If you add anything to the #else block, you must increment the version suffix in UNIFFI_SHARED_HEADER_V4
+    #ifndef UNIFFI_SHARED_HEADER_V4
+        #error Combining helper code from multiple versions of uniffi is not supported
+    #endif // ndef UNIFFI_SHARED_HEADER_V4
+#else
+#define UNIFFI_SHARED_H
+#define UNIFFI_SHARED_HEADER_V4
+// ⚠️ Attention: If you change this #else block (ending in `#endif // def UNIFFI_SHARED_H`) you *must* ⚠️
+// ⚠️ increment the version suffix in all instances of UNIFFI_SHARED_HEADER_V4 in this file.           ⚠️
+
+typedef struct RustBuffer
+{
+    uint64_t capacity;
+    uint64_t len;
+    uint8_t *data;
+} RustBuffer;
+
+typedef struct ForeignBytes
+{
+    int32_t len;
+    const uint8_t *data;
+} ForeignBytes;
+
+// Error definitions
+typedef struct RustCallStatus {
+    int8_t code;
+    RustBuffer errorBuf;
+} RustCallStatus;
+
+// ⚠️ Attention: If you change this #else block (ending in `#endif // def UNIFFI_SHARED_H`) you *must* ⚠️
+// ⚠️ increment the version suffix in all instances of UNIFFI_SHARED_HEADER_V4 in this file.           ⚠️
+#endif // def UNIFFI_SHARED_H
+
+{%- for def in ffi_definitions %}
+{%- match def %}
+{%- when FfiDefinition::FunctionType(callback) %}
+#ifndef {{ callback.if_guard_name }}
+#define {{ callback.if_guard_name }}
+typedef
+    {%- match callback.return_type.ty %}{% when Some(return_type) %} {{ return_type.type_name }} {% when None %} void {% endmatch -%}
+    (*{{ callback.name.0 }})(
+        {%- for arg in callback.arguments -%}
+        {{ arg.ty.type_name }}
+        {%- if !loop.last || callback.has_rust_call_status_arg %}, {% endif %}
+        {%- endfor -%}
+        {%- if callback.has_rust_call_status_arg %}
+        RustCallStatus *uniffiCallStatus
+        {%- endif %}
+    );
+#endif
+{%- when FfiDefinition::Struct(struct_item) %}
+#ifndef {{ struct_item.if_guard_name }}
+#define {{ struct_item.if_guard_name }}
+typedef struct {{ struct_item.name.0 }} {
+    {%- for field in struct_item.fields %}
+    {{ field.ty.type_name }} {{ field.name }};
+    {%- endfor %}
+} {{ struct_item.name.0 }};
+#endif
+{%- when FfiDefinition::RustFunction(func) %}
+#ifndef {{ func.if_guard_name }}
+#define {{ func.if_guard_name }}
+{% match func.return_type.ty -%}{%- when Some(return_type) %}{{ return_type.type_name }}{% when None %}void{% endmatch %} {{ func.name.0 }}(
+    {%- if !func.arguments.is_empty() %}
+        {%- for arg in func.arguments %}
+            {{- arg.ty.type_name }}{% if !loop.last || func.has_rust_call_status_arg %}, {% endif %}
+        {%- endfor %}
+        {%- if func.has_rust_call_status_arg %}RustCallStatus *{% endif %}
+    {%- else %}
+        {%- if func.has_rust_call_status_arg %}RustCallStatus *{%- else %}void{% endif %}
+    {% endif %}
+);
+#endif
+{%- endmatch %}
+{%- endfor %}
+
+} // extern "C"
@@ -0,0 +1,143 @@
+// Helpers for calling into the Rust library for this module.
+namespace uniffi_detail {
+
+using Reader = ::uniffi::detail::Reader;
+using Writer = ::uniffi::detail::Writer;
+
+/// Tag type for constructing objects from a handle
+struct FromHandle {};
+
+inline void free_rust_buffer(RustBuffer buf) {
+    RustCallStatus status {};
+    {{ ffi_rustbuffer_free.0 }}(buf, &status);
+}
+
+inline RustBuffer rust_buffer_from_bytes(const uint8_t* data, size_t len) {
+    if (len > static_cast<size_t>(std::numeric_limits<int32_t>::max())) {
+        throw std::length_error("UniFFI: value too large to pass to Rust");
+    }
+    ForeignBytes bytes { static_cast<int32_t>(len), data };
+    RustCallStatus status {};
+    RustBuffer buf = {{ ffi_rustbuffer_from_bytes.0 }}(bytes, &status);
+    if (status.code != 0) {
+        throw ::uniffi::InternalError("UniFFI: failed to allocate a RustBuffer");
+    }
+    return buf;
+}
+
+/// Borrow the data from a vector, for `&[u8]` and `&mut [u8]` arguments
+inline ForeignBytes borrow_bytes(const std::vector<uint8_t>& value) {
+    if (value.size() > static_cast<size_t>(std::numeric_limits<int32_t>::max())) {
+        throw std::length_error("UniFFI: value too large to pass to Rust");
+    }
+    return ForeignBytes { static_cast<int32_t>(value.size()), value.data() };
+}
+
+/// Lift a value from a `RustBuffer`, freeing the buffer afterwards
+template <typename C>
+typename C::value_type lift_from_rust_buffer(RustBuffer buf) {
+    struct Guard {
+        RustBuffer buf;
+        ~Guard() { free_rust_buffer(buf); }
+    } guard { buf };
+    Reader reader(buf.data, static_cast<size_t>(buf.len));
+    auto value = C::read(reader);
+    reader.check_finished();
+    return value;
+}
+
+/// Lower a value into a newly allocated `RustBuffer`
+template <typename C>
+RustBuffer lower_into_rust_buffer(const typename C::value_type& value) {
+    Writer writer;
+    C::write(value, writer);
+    return rust_buffer_from_bytes(writer.data(), writer.size());
+}
+
+/// Add `lift`/`lower` to a converter for a type that's passed across the FFI in a `RustBuffer`
+template <typename C>
+struct RustBufferConverter : C {
+    using value_type = typename C::value_type;
+    static value_type lift(RustBuffer buf) {
+        return lift_from_rust_buffer<C>(buf);
+    }
+    static RustBuffer lower(const value_type& value) {
+        return lower_into_rust_buffer<C>(value);
+    }
+};
+
+/// Strings are passed across the FFI as a `RustBuffer` containing the raw UTF-8 data
+struct FfiConverterString : ::uniffi::detail::StringConverter {
+    static std::string lift(RustBuffer buf) {
+        std::string value(reinterpret_cast<const char*>(buf.data), static_cast<size_t>(buf.len));
+        free_rust_buffer(buf);
+        return value;
+    }
+    static RustBuffer lower(const std::string& value) {
+        return rust_buffer_from_bytes(reinterpret_cast<const uint8_t*>(value.data()), value.size());
+    }
+};
+
+/// Error handler for functions that don't throw
+struct NoErrorHandler {
+    [[noreturn]] static void throw_error(RustBuffer buf) {
+        free_rust_buffer(buf);
+        throw ::uniffi::InternalError("UniFFI: unexpected error returned from Rust");
+    }
+};
+
+inline void ensure_initialized() {
+    static const bool initialized = [] {
+        // Check that the scaffolding was generated with the same version of UniFFI
+        if ({{ ffi_uniffi_contract_version.0 }}() != {{ correct_contract_version }}) {
+            throw ::uniffi::InternalError("UniFFI contract version mismatch: try cleaning and rebuilding your project");
+        }
+        {%- match checksum_mode %}
+        {%- when ChecksumMode::Legacy %}
+        {%- for checksum in checksums %}
+        if ({{ checksum.fn_name.0 }}() != {{ checksum.checksum }}) {
+            throw ::uniffi::InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project");
+        }
+        {%- endfor %}
+        {%- when ChecksumMode::Skip %}
+        // ChecksumMode::Skip, skipping checksum verifications
+        {%- endmatch %}
+        return true;
+    }();
+    (void)initialized;
+}
+
+template <typename ErrorHandler>
+void check_call_status(const RustCallStatus& status) {
+    switch (status.code) {
+    case 0:
+        return;
+    case 1:
+        ErrorHandler::throw_error(status.errorBuf);
+    case 2:
+        if (status.errorBuf.len > 0) {
+            throw ::uniffi::InternalError(FfiConverterString::lift(status.errorBuf));
+        }
+        free_rust_buffer(status.errorBuf);
+        throw ::uniffi::InternalError("Unknown rust panic");
+    default:
+        throw ::uniffi::InternalError("UniFFI: unexpected RustCallStatus code");
+    }
+}
+
+/// Call an FFI function, passing it a `RustCallStatus` and checking the status afterwards
+template <typename ErrorHandler, typename F>
+auto rust_call(F&& f) {
+    ensure_initialized();
+    RustCallStatus status {};
+    if constexpr (std::is_void_v<std::invoke_result_t<F, RustCallStatus*>>) {
+        f(&status);
+        check_call_status<ErrorHandler>(status);
+    } else {
+        auto result = f(&status);
+        check_call_status<ErrorHandler>(status);
+        return result;
+    }
+}
+
+} // namespace uniffi_detail
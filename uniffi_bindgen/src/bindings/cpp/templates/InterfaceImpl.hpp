@@ -0,0 +1,55 @@
+{%- let type_name = int.name %}
+{%- let converter_name = format!("FfiConverter{}", int.self_type.canonical_name) %}
+{%- let self_expr = "*this" %}
+
+inline {{ type_name }} uniffi_detail::{{ converter_name }}::lift(uint64_t handle) {
+    return {{ type_name }}(FromHandle {}, handle);
+}
+
+inline uint64_t uniffi_detail::{{ converter_name }}::lower(const {{ type_name }}& value) {
+    return value.uniffi_clone_handle();
+}
+
+inline {{ type_name }} uniffi_detail::{{ converter_name }}::read(Reader& reader) {
+    return lift(reader.read_int<uint64_t>());
+}
+
+inline void uniffi_detail::{{ converter_name }}::write(const {{ type_name }}& value, Writer& writer) {
+    writer.write_int<uint64_t>(lower(value));
+}
+
+inline uint64_t {{ type_name }}::uniffi_clone_handle() const {
+    if (uniffi_handle_ == 0) {
+        throw std::logic_error("UniFFI: {{ type_name }} used after being moved from");
+    }
+    return uniffi_detail::rust_call<uniffi_detail::NoErrorHandler>([&](RustCallStatus* uniffi_status) {
+        return {{ int.ffi_func_clone.0 }}(uniffi_handle_, uniffi_status);
+    });
+}
+{%- if let Some(cons) = int.primary_constructor() %}
+{%-     let callable = cons.callable %}
+
+inline {{ type_name }}::{{ type_name }}({% include "CallableParams.hpp" %})
+    : uniffi_handle_({% include "RustCall.hpp" %}) {}
+{%- endif %}
+{%- for cons in int.secondary_constructors() %}
+{%-     let callable = cons.callable %}
+
+inline {{ type_name }} {{ type_name }}::{{ callable.name }}({% include "CallableParams.hpp" %}) {
+{%- include "CallableBody.hpp" %}
+}
+{%- endfor %}
+{%- for meth in int.methods %}
+{%-     let callable = meth.callable %}
+{%-     let method_name = callable.name %}
+{%-     include "MethodDefinition.hpp" %}
+{%- endfor %}
+{%- for meth in int.static_methods %}
+{%-     let callable = meth.callable %}
+
+inline {{ callable.return_type.type_name }} {{ type_name }}::{{ callable.name }}({% include "CallableParams.hpp" %}) {
+{%- include "CallableBody.hpp" %}
+}
+{%- endfor %}
+{%- let uniffi_trait_methods = int.uniffi_trait_methods %}
+{%- include "UniffiTraitDefinitions.hpp" %}
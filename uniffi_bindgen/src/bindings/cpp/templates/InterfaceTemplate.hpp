@@ -0,0 +1,61 @@
+{%- let type_name = int.name %}
+{%- let constructors = int.constructors %}
+{%- let uniffi_trait_methods = int.uniffi_trait_methods %}
+
+{{ int.docstring|docstring(0) -}}
+class {{ type_name }} {
+public:
+    {%- if let Some(cons) = int.primary_constructor() %}
+    {%- let callable = cons.callable %}
+    {{ cons.docstring|docstring(4) -}}
+    {% if callable.arguments.len() == 1 %}explicit {% endif %}{{ type_name }}({% include "CallableArgs.hpp" %});
+    {%- endif %}
+    {%- for cons in int.secondary_constructors() %}
+    {%- let callable = cons.callable %}
+
+    {{ cons.docstring|docstring(4) -}}
+    static {{ type_name }} {{ callable.name }}({% include "CallableArgs.hpp" %});
+    {%- endfor %}
+
+    {{ type_name }}(const {{ type_name }}& other)
+        : uniffi_handle_(other.uniffi_handle_ == 0 ? 0 : other.uniffi_clone_handle()) {}
+
+    {{ type_name }}({{ type_name }}&& other) noexcept : uniffi_handle_(other.uniffi_handle_) {
+        other.uniffi_handle_ = 0;
+    }
+
+    {{ type_name }}& operator=({{ type_name }} other) noexcept {
+        std::swap(uniffi_handle_, other.uniffi_handle_);
+        return *this;
+    }
+
+    ~{{ type_name }}() {
+        if (uniffi_handle_ != 0) {
+            RustCallStatus status {};
+            {{ int.ffi_func_free.0 }}(uniffi_handle_, &status);
+        }
+    }
+    {%- for meth in int.methods %}
+    {%-     let callable = meth.callable %}
+
+    {{ meth.docstring|docstring(4) -}}
+    {{ callable.return_type.type_name }} {{ callable.name }}({% include "CallableArgs.hpp" %}) const;
+    {%- endfor %}
+    {%- for meth in int.static_methods %}
+    {%-     let callable = meth.callable %}
+
+    {{ meth.docstring|docstring(4) -}}
+    static {{ callable.return_type.type_name }} {{ callable.name }}({% include "CallableArgs.hpp" %});
+    {%- endfor %}
+    {%- include "UniffiTraitDeclarations.hpp" %}
+
+private:
+    {{ type_name }}(uniffi_detail::FromHandle, uint64_t handle) : uniffi_handle_(handle) {}
+
+    // Clone the handle, to pass it to Rust
+    uint64_t uniffi_clone_handle() const;
+
+    uint64_t uniffi_handle_;
+
+    friend struct uniffi_detail::FfiConverter{{ int.self_type.canonical_name }};
+};
@@ -0,0 +1,14 @@
+{#- Constructors and methods for records and enums -#}
+{%- for cons in constructors %}
+{%-     let callable = cons.callable %}
+
+    {{ cons.docstring|docstring(4) -}}
+    static {{ type_name }} {{ callable.name }}({% include "CallableArgs.hpp" %});
+{%- endfor %}
+{%- for meth in methods %}
+{%-     let callable = meth.callable %}
+
+    {{ meth.docstring|docstring(4) -}}
+    {{ callable.return_type.type_name }} {{ callable.name }}({% include "CallableArgs.hpp" %}) const;
+{%- endfor %}
+{%- include "UniffiTraitDeclarations.hpp" %}
@@ -0,0 +1,13 @@
+{%- for cons in constructors %}
+{%-     let callable = cons.callable %}
+
+inline {{ type_name }} {{ type_name }}::{{ callable.name }}({% include "CallableParams.hpp" %}) {
+{%- include "CallableBody.hpp" %}
+}
+{%- endfor %}
+{%- for meth in methods %}
+{%-     let callable = meth.callable %}
+{%-     let method_name = callable.name %}
+{%-     include "MethodDefinition.hpp" %}
+{%- endfor %}
+{%- include "UniffiTraitDefinitions.hpp" %}
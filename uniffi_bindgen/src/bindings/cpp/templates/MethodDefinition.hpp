@@ -0,0 +1,4 @@
+
+inline {{ callable.return_type.type_name }} {{ type_name }}::{{ method_name }}({% include "CallableParams.hpp" %}) const {
+{%- include "CallableBody.hpp" %}
+}
@@ -0,0 +1,64 @@
+// This file was autogenerated by some hot garbage in the `uniffi` crate.
+// Trust me, you don't want to mess with it!
+{%- if docstring.is_some() %}
+
+{{ docstring|docstring(0) -}}
+{%- endif %}
+
+#pragma once
+
+#include <array>
+#include <chrono>
+#include <cstdint>
+#include <cstring>
+#include <functional>
+#include <limits>
+#include <memory>
+#include <optional>
+#include <ostream>
+#include <stdexcept>
+#include <string>
+#include <tuple>
+#include <type_traits>
+#include <unordered_map>
+#include <unordered_set>
+#include <utility>
+#include <variant>
+#include <vector>
+{%- for include in includes %}
+#include "{{ include }}"
+{%- endfor %}
+
+{% include "FfiDeclarations.hpp" %}
+
+{% include "SharedRuntime.hpp" %}
+
+namespace {{ cpp_namespace }} {
+
+{% include "Helpers.hpp" %}
+
+// Public interface members begin here.
+{% include "TypeDeclarations.hpp" %}
+
+namespace uniffi_detail {
+{%- include "ConverterDeclarations.hpp" %}
+} // namespace uniffi_detail
+{%- include "TypeClasses.hpp" %}
+
+} // namespace {{ cpp_namespace }}
+{%- include "StdHashSpecializations.hpp" %}
+
+namespace {{ cpp_namespace }} {
+{%- include "TypeImplementations.hpp" %}
+
+{%- for c in constants %}
+
+{{ c.docstring|docstring(0) -}}
+inline const {{ c.ty.type_name }} {{ c.name }} = {{ c.value.cpp_lit }};
+{%- endfor %}
+
+{%- for func in functions %}
+{%- include "TopLevelFunctionTemplate.hpp" %}
+{%- endfor %}
+
+} // namespace {{ cpp_namespace }}
@@ -0,0 +1,32 @@
+{%- let type_name = rec.name %}
+{%- let converter_name = format!("FfiConverter{}", rec.self_type.canonical_name) %}
+{%- include "RustBufferConverterImpl.hpp" %}
+
+inline {{ type_name }} uniffi_detail::{{ converter_name }}::read(Reader& reader) {
+    {%- for field in rec.fields %}
+    auto uniffi_field{{ loop.index0 }} = {{ field.ty.ffi_converter_name }}::read(reader);
+    {%- endfor %}
+    {%- if rec.fields.is_empty() %}
+    (void)reader;
+    {%- endif %}
+    return {{ type_name }} {
+        {%- for field in rec.fields %}
+        std::move(uniffi_field{{ loop.index0 }}),
+        {%- endfor %}
+    };
+}
+
+inline void uniffi_detail::{{ converter_name }}::write(const {{ type_name }}& value, Writer& writer) {
+    {%- for field in rec.fields %}
+    {{ field.ty.ffi_converter_name }}::write(value.{{ field.name }}, writer);
+    {%- endfor %}
+    {%- if rec.fields.is_empty() %}
+    (void)value;
+    (void)writer;
+    {%- endif %}
+}
+{%- let constructors = rec.constructors %}
+{%- let methods = rec.methods %}
+{%- let uniffi_trait_methods = rec.uniffi_trait_methods %}
+{%- let self_expr = "*this" %}
+{%- include "MemberDefinitions.hpp" %}
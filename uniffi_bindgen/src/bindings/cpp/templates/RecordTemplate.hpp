@@ -0,0 +1,13 @@
+{%- let type_name = rec.name %}
+{%- let constructors = rec.constructors %}
+{%- let methods = rec.methods %}
+{%- let uniffi_trait_methods = rec.uniffi_trait_methods %}
+
+{{ rec.docstring|docstring(0) -}}
+struct {{ type_name }} {
+    {%- for field in rec.fields %}
+    {{ field.docstring|docstring(4) -}}
+    {{ field.ty.type_name }} {{ field.name }}{% if let Some(default) = field.default %} = {{ default.cpp_default }}{% endif %};
+    {%- endfor %}
+    {%- include "MemberDeclarations.hpp" %}
+};
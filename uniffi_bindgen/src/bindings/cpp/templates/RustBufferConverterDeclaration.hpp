@@ -0,0 +1,9 @@
+{#- Converter for a user type that's passed across the FFI in a `RustBuffer` #}
+
+struct {{ converter_name }} {
+    using value_type = {{ type_name }};
+    static {{ type_name }} lift(RustBuffer buf);
+    static RustBuffer lower(const {{ type_name }}& value);
+    static {{ type_name }} read(Reader& reader);
+    static void write(const {{ type_name }}& value, Writer& writer);
+};
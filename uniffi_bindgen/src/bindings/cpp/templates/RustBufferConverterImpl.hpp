@@ -0,0 +1,8 @@
+
+inline {{ type_name }} uniffi_detail::{{ converter_name }}::lift(RustBuffer buf) {
+    return lift_from_rust_buffer<{{ converter_name }}>(buf);
+}
+
+inline RustBuffer uniffi_detail::{{ converter_name }}::lower(const {{ type_name }}& value) {
+    return lower_into_rust_buffer<{{ converter_name }}>(value);
+}
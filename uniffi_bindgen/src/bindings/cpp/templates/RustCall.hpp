@@ -0,0 +1,20 @@
+{#- Call the FFI function for `callable`, the result still needs to be lifted -#}
+uniffi_detail::rust_call<{{ callable.throws_type.error_handler }}>([&](RustCallStatus* uniffi_status) {
+        return {{ callable.ffi_func.0 }}(
+            {%- if let Some(self_type) = callable.self_type() %}
+            {%-     match self_type.ty %}
+            {%-         when Type::Interface { .. } %}
+            uniffi_clone_handle(),
+            {%-         else %}
+            {{ self_type.ffi_converter_name }}::lower({{ self_expr }}),
+            {%-     endmatch %}
+            {%- endif %}
+            {%- for arg in callable.arguments %}
+            {%- if arg.is_borrowed_bytes() %}
+            uniffi_detail::borrow_bytes({{ arg.name }}),
+            {%- else %}
+            {{ arg.ty.ffi_converter_name }}::lower({{ arg.name }}),
+            {%- endif %}
+            {%- endfor %}
+            uniffi_status);
+    })
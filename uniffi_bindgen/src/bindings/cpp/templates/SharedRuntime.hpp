@@ -0,0 +1,397 @@
+// Runtime code shared by all UniFFI modules.
+//
+// Like the FFI structs above, this is declared exactly once, with a header guard.  If you change
+// anything in this block, you must increment the version suffix of UNIFFI_CPP_RUNTIME_V1.
+#ifndef UNIFFI_CPP_RUNTIME_V1
+#define UNIFFI_CPP_RUNTIME_V1
+namespace uniffi {
+
+/// Thrown for unexpected failures inside UniFFI, for example a Rust panic
+class InternalError : public std::runtime_error {
+public:
+    using std::runtime_error::runtime_error;
+};
+
+namespace detail {
+
+/// Writes values in the UniFFI serialization format
+class Writer {
+public:
+    template <typename T>
+    void write_int(T value) {
+        static_assert(std::is_integral_v<T>);
+        using U = std::make_unsigned_t<T>;
+        U bits = static_cast<U>(value);
+        for (size_t i = sizeof(T); i > 0; i--) {
+            bytes_.push_back(static_cast<uint8_t>(bits >> (8 * (i - 1))));
+        }
+    }
+
+    void write_float(float value) {
+        uint32_t bits;
+        std::memcpy(&bits, &value, sizeof(bits));
+        write_int(bits);
+    }
+
+    void write_double(double value) {
+        uint64_t bits;
+        std::memcpy(&bits, &value, sizeof(bits));
+        write_int(bits);
+    }
+
+    void write_length(size_t len) {
+        if (len > static_cast<size_t>(std::numeric_limits<int32_t>::max())) {
+            throw std::length_error("UniFFI: value too large to pass to Rust");
+        }
+        write_int(static_cast<int32_t>(len));
+    }
+
+    void write_bytes(const uint8_t* data, size_t len) {
+        bytes_.insert(bytes_.end(), data, data + len);
+    }
+
+    const uint8_t* data() const { return bytes_.data(); }
+    size_t size() const { return bytes_.size(); }
+
+private:
+    std::vector<uint8_t> bytes_;
+};
+
+/// Reads values in the UniFFI serialization format
+class Reader {
+public:
+    Reader(const uint8_t* data, size_t len) : data_(data), len_(len) {}
+
+    template <typename T>
+    T read_int() {
+        static_assert(std::is_integral_v<T>);
+        using U = std::make_unsigned_t<T>;
+        const uint8_t* bytes = read_bytes(sizeof(T));
+        U bits = 0;
+        for (size_t i = 0; i < sizeof(T); i++) {
+            bits = static_cast<U>((bits << 8) | bytes[i]);
+        }
+        return static_cast<T>(bits);
+    }
+
+    float read_float() {
+        uint32_t bits = read_int<uint32_t>();
+        float value;
+        std::memcpy(&value, &bits, sizeof(value));
+        return value;
+    }
+
+    double read_double() {
+        uint64_t bits = read_int<uint64_t>();
+        double value;
+        std::memcpy(&value, &bits, sizeof(value));
+        return value;
+    }
+
+    size_t read_length() {
+        int32_t len = read_int<int32_t>();
+        if (len < 0) {
+            throw InternalError("UniFFI: negative length");
+        }
+        return static_cast<size_t>(len);
+    }
+
+    const uint8_t* read_bytes(size_t len) {
+        if (len > len_ - pos_) {
+            throw InternalError("UniFFI: buffer underflow");
+        }
+        const uint8_t* bytes = data_ + pos_;
+        pos_ += len;
+        return bytes;
+    }
+
+    void check_finished() const {
+        if (pos_ != len_) {
+            throw InternalError("UniFFI: junk remaining in buffer after lifting");
+        }
+    }
+
+private:
+    const uint8_t* data_;
+    size_t len_;
+    size_t pos_ = 0;
+};
+
+// Converters for the builtin types.
+//
+// Each converter has a `value_type` and static `read`/`write` functions for the serialization
+// format.  Converters for types that are passed directly across the FFI also have `lift`/`lower`
+// functions, the per-module code adds those for types passed in a `RustBuffer`.
+
+template <typename T>
+struct IntConverter {
+    using value_type = T;
+    static T lift(T value) { return value; }
+    static T lower(T value) { return value; }
+    static T read(Reader& reader) { return reader.read_int<T>(); }
+    static void write(T value, Writer& writer) { writer.write_int(value); }
+};
+
+struct FloatConverter {
+    using value_type = float;
+    static float lift(float value) { return value; }
+    static float lower(float value) { return value; }
+    static float read(Reader& reader) { return reader.read_float(); }
+    static void write(float value, Writer& writer) { writer.write_float(value); }
+};
+
+struct DoubleConverter {
+    using value_type = double;
+    static double lift(double value) { return value; }
+    static double lower(double value) { return value; }
+    static double read(Reader& reader) { return reader.read_double(); }
+    static void write(double value, Writer& writer) { writer.write_double(value); }
+};
+
+struct BooleanConverter {
+    using value_type = bool;
+    static bool lift(int8_t value) { return value != 0; }
+    static int8_t lower(bool value) { return value ? 1 : 0; }
+    static bool read(Reader& reader) { return reader.read_int<int8_t>() != 0; }
+    static void write(bool value, Writer& writer) { writer.write_int<int8_t>(lower(value)); }
+};
+
+struct StringConverter {
+    using value_type = std::string;
+    static std::string read(Reader& reader) {
+        size_t len = reader.read_length();
+        const uint8_t* bytes = reader.read_bytes(len);
+        return std::string(reinterpret_cast<const char*>(bytes), len);
+    }
+    static void write(const std::string& value, Writer& writer) {
+        writer.write_length(value.size());
+        writer.write_bytes(reinterpret_cast<const uint8_t*>(value.data()), value.size());
+    }
+};
+
+struct BytesConverter {
+    using value_type = std::vector<uint8_t>;
+    static std::vector<uint8_t> read(Reader& reader) {
+        size_t len = reader.read_length();
+        const uint8_t* bytes = reader.read_bytes(len);
+        return std::vector<uint8_t>(bytes, bytes + len);
+    }
+    static void write(const std::vector<uint8_t>& value, Writer& writer) {
+        writer.write_length(value.size());
+        writer.write_bytes(value.data(), value.size());
+    }
+};
+
+struct TimestampConverter {
+    using value_type = std::chrono::system_clock::time_point;
+    static value_type read(Reader& reader) {
+        int64_t seconds = reader.read_int<int64_t>();
+        uint32_t nanoseconds = reader.read_int<uint32_t>();
+        auto offset = std::chrono::duration_cast<std::chrono::system_clock::duration>(
+            std::chrono::seconds(seconds < 0 ? -seconds : seconds)
+            + std::chrono::nanoseconds(nanoseconds));
+        auto epoch = std::chrono::system_clock::time_point();
+        return seconds >= 0 ? epoch + offset : epoch - offset;
+    }
+    static void write(value_type value, Writer& writer) {
+        auto since_epoch = std::chrono::duration_cast<std::chrono::nanoseconds>(value.time_since_epoch());
+        bool before_epoch = since_epoch.count() < 0;
+        auto offset = before_epoch ? -since_epoch : since_epoch;
+        auto seconds = std::chrono::duration_cast<std::chrono::seconds>(offset);
+        auto nanoseconds = offset - seconds;
+        writer.write_int<int64_t>(before_epoch ? -seconds.count() : seconds.count());
+        writer.write_int<uint32_t>(static_cast<uint32_t>(nanoseconds.count()));
+    }
+};
+
+struct DurationConverter {
+    using value_type = std::chrono::nanoseconds;
+    static value_type read(Reader& reader) {
+        uint64_t seconds = reader.read_int<uint64_t>();
+        uint32_t nanoseconds = reader.read_int<uint32_t>();
+        return std::chrono::seconds(seconds) + std::chrono::nanoseconds(nanoseconds);
+    }
+    static void write(value_type value, Writer& writer) {
+        if (value.count() < 0) {
+            throw std::invalid_argument("UniFFI: durations can't be negative");
+        }
+        auto seconds = std::chrono::duration_cast<std::chrono::seconds>(value);
+        writer.write_int<uint64_t>(static_cast<uint64_t>(seconds.count()));
+        writer.write_int<uint32_t>(static_cast<uint32_t>((value - seconds).count()));
+    }
+};
+
+struct UuidConverter {
+    using value_type = std::array<uint8_t, 16>;
+    static value_type read(Reader& reader) {
+        const uint8_t* bytes = reader.read_bytes(16);
+        value_type value;
+        std::memcpy(value.data(), bytes, 16);
+        return value;
+    }
+    static void write(const value_type& value, Writer& writer) {
+        writer.write_bytes(value.data(), value.size());
+    }
+};
+
+template <typename C>
+struct OptionalConverter {
+    using value_type = std::optional<typename C::value_type>;
+    static value_type read(Reader& reader) {
+        if (reader.read_int<int8_t>() == 0) {
+            return std::nullopt;
+        }
+        return C::read(reader);
+    }
+    static void write(const value_type& value, Writer& writer) {
+        if (value) {
+            writer.write_int<int8_t>(1);
+            C::write(*value, writer);
+        } else {
+            writer.write_int<int8_t>(0);
+        }
+    }
+};
+
+template <typename C>
+struct SequenceConverter {
+    using value_type = std::vector<typename C::value_type>;
+    static value_type read(Reader& reader) {
+        size_t len = reader.read_length();
+        value_type value;
+        value.reserve(len);
+        for (size_t i = 0; i < len; i++) {
+            value.push_back(C::read(reader));
+        }
+        return value;
+    }
+    static void write(const value_type& value, Writer& writer) {
+        writer.write_length(value.size());
+        for (const auto& item : value) {
+            C::write(item, writer);
+        }
+    }
+};
+
+template <typename C, size_t N>
+struct ArrayConverter {
+    using value_type = std::array<typename C::value_type, N>;
+    static value_type read(Reader& reader) {
+        if (reader.read_length() != N) {
+            throw InternalError("UniFFI: unexpected array length");
+        }
+        return read_items(reader, std::make_index_sequence<N>());
+    }
+    static void write(const value_type& value, Writer& writer) {
+        writer.write_length(N);
+        for (const auto& item : value) {
+            C::write(item, writer);
+        }
+    }
+
+private:
+    template <size_t... I>
+    static value_type read_items(Reader& reader, std::index_sequence<I...>) {
+        // Braced initializers are evaluated in order
+        return value_type { ((void)I, C::read(reader))... };
+    }
+};
+
+template <typename K, typename V>
+struct MapConverter {
+    using value_type = std::unordered_map<typename K::value_type, typename V::value_type>;
+    static value_type read(Reader& reader) {
+        size_t len = reader.read_length();
+        value_type value;
+        value.reserve(len);
+        for (size_t i = 0; i < len; i++) {
+            auto key = K::read(reader);
+            value.insert_or_assign(std::move(key), V::read(reader));
+        }
+        return value;
+    }
+    static void write(const value_type& value, Writer& writer) {
+        writer.write_length(value.size());
+        for (const auto& [key, item] : value) {
+            K::write(key, writer);
+            V::write(item, writer);
+        }
+    }
+};
+
+template <typename C>
+struct SetConverter {
+    using value_type = std::unordered_set<typename C::value_type>;
+    static value_type read(Reader& reader) {
+        size_t len = reader.read_length();
+        value_type value;
+        value.reserve(len);
+        for (size_t i = 0; i < len; i++) {
+            value.insert(C::read(reader));
+        }
+        return value;
+    }
+    static void write(const value_type& value, Writer& writer) {
+        writer.write_length(value.size());
+        for (const auto& item : value) {
+            C::write(item, writer);
+        }
+    }
+};
+
+template <typename... C>
+struct TupleConverter {
+    using value_type = std::tuple<typename C::value_type...>;
+    static value_type read(Reader& reader) {
+        // Braced initializers are evaluated in order
+        return value_type { C::read(reader)... };
+    }
+    static void write(const value_type& value, Writer& writer) {
+        std::apply([&](const auto&... items) { (C::write(items, writer), ...); }, value);
+    }
+};
+
+/// Converter for `Box<T>`, which is represented as a `std::shared_ptr`
+///
+/// Boxes are only needed for recursive types, they're serialized the same as the inner type.
+template <typename C>
+struct BoxConverter {
+    using value_type = std::shared_ptr<typename C::value_type>;
+    template <typename FfiType>
+    static value_type lift(FfiType value) {
+        return std::make_shared<typename C::value_type>(C::lift(value));
+    }
+    static auto lower(const value_type& value) {
+        return C::lower(deref(value));
+    }
+    static value_type read(Reader& reader) {
+        return std::make_shared<typename C::value_type>(C::read(reader));
+    }
+    static void write(const value_type& value, Writer& writer) {
+        C::write(deref(value), writer);
+    }
+
+protected:
+    static const typename C::value_type& deref(const value_type& value) {
+        if (!value) {
+            throw std::invalid_argument("UniFFI: boxed values can't be null");
+        }
+        return *value;
+    }
+};
+
+/// Converter for a boxed error
+///
+/// Errors are class hierarchies, so they need to be read using the converter's `read_shared`,
+/// which creates the subclass for the error variant.
+template <typename C>
+struct ErrorBoxConverter : BoxConverter<C> {
+    using value_type = typename BoxConverter<C>::value_type;
+    static value_type read(Reader& reader) {
+        return C::read_shared(reader);
+    }
+};
+
+} // namespace detail
+} // namespace uniffi
+#endif // UNIFFI_CPP_RUNTIME_V1
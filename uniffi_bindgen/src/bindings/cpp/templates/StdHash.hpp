@@ -0,0 +1,7 @@
+
+template <>
+struct std::hash<::{{ cpp_namespace }}::{{ type_name }}> {
+    size_t operator()(const ::{{ cpp_namespace }}::{{ type_name }}& value) const {
+        return static_cast<size_t>(value.hash());
+    }
+};
@@ -0,0 +1,21 @@
+{#- `std::hash` specializations for types that export the Rust `Hash` trait #}
+{%- for type_def in type_definitions %}
+{%- match type_def %}
+{%- when TypeDefinition::Record(rec) %}
+{%- if rec.uniffi_trait_methods.hash_hash.is_some() %}
+{%- let type_name = rec.name %}
+{%- include "StdHash.hpp" %}
+{%- endif %}
+{%- when TypeDefinition::Enum(e) %}
+{%- if e.uniffi_trait_methods.hash_hash.is_some() && !e.is_flat() && !e.is_error() %}
+{%- let type_name = e.name %}
+{%- include "StdHash.hpp" %}
+{%- endif %}
+{%- when TypeDefinition::Interface(int) %}
+{%- if int.uniffi_trait_methods.hash_hash.is_some() %}
+{%- let type_name = int.name %}
+{%- include "StdHash.hpp" %}
+{%- endif %}
+{%- else %}
+{%- endmatch %}
+{%- endfor %}
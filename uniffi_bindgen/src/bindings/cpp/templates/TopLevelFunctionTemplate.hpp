@@ -0,0 +1,7 @@
+{%- let callable = func.callable %}
+{%- let self_expr = "" %}
+
+{{ func.docstring|docstring(0) -}}
+inline {{ callable.return_type.type_name }} {{ callable.name }}({% include "CallableArgs.hpp" %}) {
+{%- include "CallableBody.hpp" %}
+}
@@ -0,0 +1,20 @@
+{#- Class definitions for the user-defined types, in dependency order #}
+{%- for type_def in type_definitions %}
+{%- match type_def %}
+
+{%- when TypeDefinition::Record(rec) %}
+{%- include "RecordTemplate.hpp" %}
+
+{%- when TypeDefinition::Enum(e) %}
+{%- if e.is_error() %}
+{%- include "ErrorTemplate.hpp" %}
+{%- else %}
+{%- include "EnumTemplate.hpp" %}
+{%- endif %}
+
+{%- when TypeDefinition::Interface(int) %}
+{%- include "InterfaceTemplate.hpp" %}
+
+{%- else %}
+{%- endmatch %}
+{%- endfor %}
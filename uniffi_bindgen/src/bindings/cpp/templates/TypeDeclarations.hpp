@@ -0,0 +1,45 @@
+{#
+ # Forward declarations for the user-defined types.
+ #
+ # Flat enums don't depend on anything, so they're defined in full here, along with the aliases
+ # for custom types.  Everything else is defined in `TypeClasses.hpp`, once all the converters
+ # are declared.
+ #}
+{%- for type_def in type_definitions %}
+{%- match type_def %}
+{%- when TypeDefinition::Record(rec) %}
+struct {{ rec.name }};
+{%- when TypeDefinition::Enum(e) %}
+{%- match e.kind %}
+{%- when EnumKind::Flat %}
+{%- when EnumKind::Data %}
+struct {{ e.name }};
+{%- when EnumKind::Error { .. } %}
+class {{ e.name }};
+{%- endmatch %}
+{%- when TypeDefinition::Interface(int) %}
+class {{ int.name }};
+{%- else %}
+{%- endmatch %}
+{%- endfor %}
+
+{%- for type_def in type_definitions %}
+{%- match type_def %}
+{%- when TypeDefinition::Enum(e) %}
+{%- if e.is_flat() %}
+
+{{ e.docstring|docstring(0) -}}
+enum class {{ e.name }} : {{ e.discr_type.type_name }} {
+    {%- for variant in e.variants %}
+    {{ variant.docstring|docstring(4) -}}
+    {{ variant.name }} = {{ variant.discr.cpp_lit }},
+    {%- endfor %}
+};
+{%- endif %}
+{%- when TypeDefinition::Custom(custom) %}
+
+{{ custom.docstring|docstring(0) -}}
+using {{ custom.name }} = {{ custom.value_type_name() }};
+{%- else %}
+{%- endmatch %}
+{%- endfor %}
@@ -0,0 +1,23 @@
+{#- Converter and member function definitions, once all the classes are complete #}
+{%- for type_def in type_definitions %}
+{%- match type_def %}
+
+{%- when TypeDefinition::Record(rec) %}
+{%- include "RecordImpl.hpp" %}
+
+{%- when TypeDefinition::Enum(e) %}
+{%- if e.is_error() %}
+{%- include "ErrorImpl.hpp" %}
+{%- else %}
+{%- include "EnumImpl.hpp" %}
+{%- endif %}
+
+{%- when TypeDefinition::Interface(int) %}
+{%- include "InterfaceImpl.hpp" %}
+
+{%- when TypeDefinition::Custom(custom) %}
+{%- include "CustomTypeImpl.hpp" %}
+
+{%- else %}
+{%- endmatch %}
+{%- endfor %}
@@ -0,0 +1,41 @@
+{#- Member functions for the Rust traits exported with `#[uniffi::export(...)]` -#}
+{%- if let Some(meth) = uniffi_trait_methods.display_fmt %}
+
+    /// Format the value using its Rust `Display` implementation
+    {{ meth.callable.return_type.type_name }} to_string() const;
+
+    friend std::ostream& operator<<(std::ostream& stream, const {{ type_name }}& value) {
+        return stream << value.to_string();
+    }
+{%- endif %}
+{%- if let Some(meth) = uniffi_trait_methods.debug_fmt %}
+
+    /// Format the value using its Rust `Debug` implementation
+    {{ meth.callable.return_type.type_name }} to_debug_string() const;
+{%- endif %}
+{%- if let Some(meth) = uniffi_trait_methods.eq_eq %}
+
+    {{ meth.callable.return_type.type_name }} operator==(const {{ type_name }}& other) const;
+
+    bool operator!=(const {{ type_name }}& other) const {
+        return !(*this == other);
+    }
+{%- endif %}
+{%- if let Some(meth) = uniffi_trait_methods.hash_hash %}
+
+    /// Hash the value using its Rust `Hash` implementation
+    {{ meth.callable.return_type.type_name }} hash() const;
+{%- endif %}
+{%- if let Some(meth) = uniffi_trait_methods.ord_cmp %}
+
+    /// Compare the values using their Rust `Ord` implementation
+    ///
+    /// Returns a negative number if `this` is less than `other`, zero if they're equal and a
+    /// positive number if `this` is greater than `other`.
+    {{ meth.callable.return_type.type_name }} compare(const {{ type_name }}& other) const;
+
+    bool operator<(const {{ type_name }}& other) const { return compare(other) < 0; }
+    bool operator<=(const {{ type_name }}& other) const { return compare(other) <= 0; }
+    bool operator>(const {{ type_name }}& other) const { return compare(other) > 0; }
+    bool operator>=(const {{ type_name }}& other) const { return compare(other) >= 0; }
+{%- endif %}
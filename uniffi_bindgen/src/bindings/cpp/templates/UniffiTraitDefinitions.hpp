@@ -0,0 +1,25 @@
+{%- if let Some(meth) = uniffi_trait_methods.display_fmt %}
+{%-     let callable = meth.callable %}
+{%-     let method_name = "to_string" %}
+{%-     include "MethodDefinition.hpp" %}
+{%- endif %}
+{%- if let Some(meth) = uniffi_trait_methods.debug_fmt %}
+{%-     let callable = meth.callable %}
+{%-     let method_name = "to_debug_string" %}
+{%-     include "MethodDefinition.hpp" %}
+{%- endif %}
+{%- if let Some(meth) = uniffi_trait_methods.eq_eq %}
+{%-     let callable = meth.callable %}
+{%-     let method_name = "operator==" %}
+{%-     include "MethodDefinition.hpp" %}
+{%- endif %}
+{%- if let Some(meth) = uniffi_trait_methods.hash_hash %}
+{%-     let callable = meth.callable %}
+{%-     let method_name = "hash" %}
+{%-     include "MethodDefinition.hpp" %}
+{%- endif %}
+{%- if let Some(meth) = uniffi_trait_methods.ord_cmp %}
+{%-     let callable = meth.callable %}
+{%-     let method_name = "compare" %}
+{%-     include "MethodDefinition.hpp" %}
+{%- endif %}
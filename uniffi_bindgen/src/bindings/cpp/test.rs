@@ -0,0 +1,92 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use crate::bindings::cpp::run_pipeline;
+use crate::bindings::RunScriptOptions;
+use crate::cargo_metadata::CrateConfigSupplier;
+use crate::BindgenLoader;
+use crate::GlobalConfig;
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use std::env;
+use std::process::Command;
+use uniffi_testing::UniFFITestHelper;
+
+/// Run C++ tests for a UniFFI test fixture
+pub fn run_test(tmp_dir: &str, fixture_name: &str, script_file: &str) -> Result<()> {
+    run_script(
+        tmp_dir,
+        fixture_name,
+        script_file,
+        vec![],
+        &RunScriptOptions::default(),
+    )
+}
+
+/// Compile a C++ source file against the generated headers, then run it
+///
+/// The compiler is taken from the `CXX` environment variable, defaulting to `c++`.
+pub fn run_script(
+    tmp_dir: &str,
+    crate_name: &str,
+    script_file: &str,
+    args: Vec<String>,
+    options: &RunScriptOptions,
+) -> Result<()> {
+    let script_path = Utf8Path::new(script_file).canonicalize_utf8()?;
+    let test_helper = UniFFITestHelper::new(crate_name)?;
+    let out_dir = test_helper.create_out_dir(tmp_dir, &script_path)?;
+    let cdylib_path = test_helper.copy_cdylib_to_out_dir(&out_dir)?;
+
+    // Generate bindings
+    let mut paths = crate::BindgenPaths::default();
+    paths.add_layer(CrateConfigSupplier::from_cargo_metadata(
+        test_helper.cargo_metadata(),
+        crate::CargoMetadataOptions::default(),
+    ));
+    let config = GlobalConfig::default();
+    let loader = BindgenLoader::new(paths, config);
+    let metadata = loader.load_metadata(&cdylib_path)?;
+    let root = loader.load_pipeline_initial_root(&cdylib_path, metadata)?;
+    run_pipeline(root, &out_dir, None)?;
+
+    // Compile the script, linking it directly to the cdylib
+    let exe_path = out_dir.join(script_path.file_stem().unwrap_or("script"));
+    let compiler = env::var("CXX").unwrap_or_else(|_| "c++".to_string());
+    let mut command = Command::new(&compiler);
+    command
+        .current_dir(&out_dir)
+        .arg("-std=c++17")
+        .arg("-I")
+        .arg(&out_dir)
+        .arg(&script_path)
+        .arg(&cdylib_path)
+        .arg(format!("-Wl,-rpath,{out_dir}"))
+        .arg("-o")
+        .arg(&exe_path);
+    if !options.show_compiler_messages {
+        command.arg("-w");
+    }
+    let status = command
+        .spawn()
+        .with_context(|| format!("Failed to spawn `{compiler}` when compiling script"))?
+        .wait()
+        .with_context(|| format!("Failed to wait for `{compiler}` when compiling script"))?;
+    if !status.success() {
+        anyhow::bail!("running `{compiler}` failed");
+    }
+
+    // Run the script
+    let status = Command::new(&exe_path)
+        .current_dir(&out_dir)
+        .args(args)
+        .spawn()
+        .context("Failed to spawn the compiled script")?
+        .wait()
+        .context("Failed to wait for the compiled script")?;
+    if !status.success() {
+        anyhow::bail!("running the compiled script failed");
+    }
+    Ok(())
+}
@@ -0,0 +1,41 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+/// Template filters for Askama
+///
+/// In general, prefer adding fields using a pipeline pass to writing filters.
+/// That's allows devs to use the `pipeline` command to follow what's going on.
+///
+/// We currently only use filter functions when we want to implement somewhat complex display
+/// logic, like in the `docstring` filter. Implementing this as a pipeline pass means the pass
+/// would need to know how much each docstring gets indented, which doesn't seem right.
+use askama::Result;
+
+/// Remove the "`" chars we put around function/variable names
+///
+/// These are used to avoid name clashes with kotlin identifiers, but sometimes you want to
+/// render the name unquoted.  One example is the message property for errors where we want to
+/// display the name for the user.
+#[askama::filter_fn]
+pub fn unquote<S: AsRef<str>>(nm: S, _: &dyn askama::Values) -> Result<String> {
+    Ok(nm.as_ref().trim_matches('`').to_string())
+}
+
+/// Get the idiomatic Kotlin rendering of docstring
+#[askama::filter_fn]
+pub fn docstring<S: AsRef<str>>(
+    docstring: S,
+    _: &dyn askama::Values,
+    spaces: &i32,
+) -> Result<String> {
+    let escaped = docstring
+        .as_ref()
+        .replace("*/", "*\\/")
+        .replace("/*", "/\\*");
+    let middle = textwrap::indent(&textwrap::dedent(&escaped), " * ");
+    let wrapped = format!("/**\n{middle}\n */");
+
+    let spaces = usize::try_from(*spaces).unwrap_or_default();
+    Ok(textwrap::indent(&wrapped, &" ".repeat(spaces)))
+}
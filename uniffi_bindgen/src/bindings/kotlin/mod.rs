@@ -2,19 +2,19 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use crate::{
-    bindings::GenerateOptions,
-    interface::{apply_exclusions, rename},
-    BindgenLoader, Component, ComponentInterface, Result,
-};
-use anyhow::bail;
-use camino::{Utf8Path, Utf8PathBuf};
+use anyhow::{bail, Result};
+use askama::Template;
+use camino::Utf8Path;
 use fs_err as fs;
-use std::collections::HashMap;
 use std::process::Command;
 
-mod gen_kotlin;
-use gen_kotlin::{generate_bindings, Config};
+use crate::{bindings::GenerateOptions, BindgenLoader};
+
+pub mod filters;
+mod multiplatform;
+mod pipeline;
+pub use pipeline::{pipeline, Root};
+
 #[cfg(feature = "bindgen-tests")]
 pub mod test;
 
@@ -26,119 +26,172 @@ pub fn generate(loader: &BindgenLoader, options: GenerateOptions) -> Result<()>
             bail!("No UniFFI metadata found for crate {crate_filter}");
         }
     }
+    let root = loader.load_pipeline_initial_root(&options.source, metadata)?;
+    run_pipeline(
+        root,
+        &options.out_dir,
+        options.crate_filter.as_deref(),
+        options.format,
+    )
+}
 
-    let cis = loader.load_cis(&options.source, metadata)?;
-    let cdylib = loader.library_name(&options.source).map(|l| l.to_string());
-    let mut components =
-        loader.load_components(cis, |ci, toml| parse_config(ci, toml, cdylib.clone()))?;
-    apply_renames(&mut components);
-
-    // Check for primary constructors after `apply_renames` is called, so that we honor exclusions.
-    for c in components.iter() {
-        for o in c.ci.object_definitions() {
-            for cons in o.constructors() {
-                if cons.is_async() && cons.is_primary_constructor() {
-                    bail!(
-                        "Async primary constructors not supported but {} has one",
-                        o.name()
-                    );
-                }
-            }
-        }
-    }
-    for c in components.iter_mut() {
-        // Call derive_ffi_functions after `apply_renames`
-        c.ci.derive_ffi_funcs()?;
-    }
-
-    for Component { ci, config, .. } in components {
-        if let Some(crate_filter) = &options.crate_filter {
-            if ci.crate_name() != crate_filter {
+pub fn run_pipeline(
+    initial_root: pipeline::initial::Root,
+    out_dir: &Utf8Path,
+    crate_filter: Option<&str>,
+    format: bool,
+) -> Result<()> {
+    let kotlin_root = pipeline().execute(initial_root)?;
+    for module in kotlin_root.modules.values() {
+        if let Some(crate_filter) = crate_filter {
+            if module.crate_name != crate_filter {
                 continue;
             }
         }
-
-        let mut kt_file = full_bindings_path(&config, &options.out_dir);
-        fs::create_dir_all(&kt_file)?;
-        kt_file.push(format!("{}.kt", ci.namespace()));
-        fs::write(&kt_file, generate_bindings(&config, &ci)?)?;
-        if options.format {
+        let kt_files = if module.multiplatform {
+            multiplatform::write_module(module, out_dir)?
+        } else {
+            let package_dir =
+                out_dir.join(module.package_name.split('.').collect::<Vec<_>>().join("/"));
+            fs::create_dir_all(&package_dir)?;
+            let kt_file = package_dir.join(format!("{}.kt", module.name));
+            fs::write(&kt_file, module.render()?)?;
+            vec![kt_file]
+        };
+        if format {
             println!(
                 "Code generation complete, formatting with ktlint (use --no-format to disable)"
             );
-            if let Err(e) = Command::new("ktlint").arg("-F").arg(&kt_file).output() {
-                println!(
-                    "Warning: Unable to auto-format {} using ktlint: {e:?}",
-                    kt_file.file_name().unwrap(),
-                );
+            for kt_file in kt_files {
+                if let Err(e) = Command::new("ktlint").arg("-F").arg(&kt_file).output() {
+                    println!(
+                        "Warning: Unable to auto-format {} using ktlint: {e:?}",
+                        kt_file.file_name().unwrap(),
+                    );
+                }
             }
         }
     }
     Ok(())
 }
 
-fn full_bindings_path(config: &Config, out_dir: &Utf8Path) -> Utf8PathBuf {
-    let package_path: Utf8PathBuf = config.package_name().split('.').collect();
-    Utf8PathBuf::from(out_dir).join(package_path)
-}
+#[cfg(test)]
+mod test_generation {
+    use uniffi_meta::{FnMetadata, FnParamMetadata, Metadata, NamespaceMetadata, Type};
 
-fn parse_config(
-    ci: &ComponentInterface,
-    root_toml: toml::Value,
-    cdylib: Option<String>,
-) -> Result<Config> {
-    let mut config: Config = match root_toml.get("bindings").and_then(|b| b.get("kotlin")) {
-        Some(v) => v.clone().try_into()?,
-        None => Default::default(),
-    };
-    config
-        .package_name
-        .get_or_insert_with(|| format!("uniffi.{}", ci.namespace()));
-    config.cdylib_name.get_or_insert_with(|| {
-        cdylib
-            .clone()
-            .unwrap_or_else(|| format!("uniffi_{}", ci.namespace()))
-    });
-
-    Ok(config)
-}
+    use super::*;
+    use crate::pipeline::initial::UniffiMetaConverter;
 
-// A helper for renaming items.
-fn apply_renames(components: &mut Vec<Component<Config>>) {
-    // Remove excluded items, this happens before renaming
-    for c in components.iter_mut() {
-        apply_exclusions(&mut c.ci, &c.config.exclude);
+    #[test]
+    fn checksum_functions_use_direct_return_carriers() {
+        let mut converter = UniffiMetaConverter::default();
+        converter
+            .add_metadata_item(Metadata::Namespace(NamespaceMetadata {
+                crate_name: "test_crate".to_string(),
+                name: "test_crate".to_string(),
+            }))
+            .unwrap();
+        converter
+            .add_metadata_item(Metadata::Func(FnMetadata {
+                module_path: "test_crate".to_string(),
+                name: "get_value".to_string(),
+                orig_name: None,
+                is_async: false,
+                inputs: vec![],
+                return_type: Some(Type::UInt16),
+                throws: None,
+                checksum: Some(1234),
+                docstring: None,
+            }))
+            .unwrap();
+        let root = pipeline()
+            .execute(converter.try_into_initial_ir().unwrap())
+            .unwrap();
+        let bindings = root.modules["test_crate"].render().unwrap();
+        let checksum_checks = bindings
+            .split("private fun uniffiCheckApiChecksums")
+            .nth(1)
+            .expect("generated checksum checks")
+            .split("/**")
+            .next()
+            .expect("end of generated checksum checks");
+
+        assert!(
+            bindings
+                .contains("external fun uniffi_test_crate_checksum_func_get_value(\n    ): Int"),
+            "checksum functions should use Int as the JNA direct return carrier"
+        );
+        assert!(
+            !checksum_checks.contains(".toShort())"),
+            "checksum comparisons should compare the widened Int carrier directly"
+        );
     }
 
-    // Collect all rename configurations from all components, keyed by module_path
-    let mut module_renames = HashMap::new();
-    for c in components.iter() {
-        if !c.config.rename.is_empty() {
-            let module_path = c.ci.crate_name().to_string();
-            module_renames.insert(module_path, c.config.rename.clone());
-        }
+    fn multiplatform_root(is_async: bool) -> pipeline::initial::Root {
+        let mut converter = UniffiMetaConverter::default();
+        converter
+            .add_metadata_item(Metadata::Namespace(NamespaceMetadata {
+                crate_name: "test_crate".to_string(),
+                name: "test_crate".to_string(),
+            }))
+            .unwrap();
+        converter
+            .add_metadata_item(Metadata::Func(FnMetadata {
+                module_path: "test_crate".to_string(),
+                name: "greet".to_string(),
+                orig_name: None,
+                is_async,
+                inputs: vec![FnParamMetadata::simple("name", Type::String)],
+                return_type: Some(Type::UInt8),
+                throws: None,
+                checksum: Some(1234),
+                docstring: None,
+            }))
+            .unwrap();
+        converter
+            .add_module_config_toml(
+                "test_crate".to_string(),
+                toml::toml! {
+                    [bindings.kotlin]
+                    multiplatform = true
+                },
+            )
+            .unwrap();
+        converter.try_into_initial_ir().unwrap()
     }
 
-    // Apply rename configurations to all components
-    if !module_renames.is_empty() {
-        for c in &mut *components {
-            rename(&mut c.ci, &module_renames);
-        }
+    #[test]
+    fn multiplatform_source_sets() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let out_dir = Utf8Path::from_path(tempdir.path()).unwrap();
+        run_pipeline(multiplatform_root(false), out_dir, None, false).unwrap();
+        let read = |path: &str| fs::read_to_string(out_dir.join(path)).unwrap();
+
+        let common = read("commonMain/kotlin/uniffi/test_crate/test_crate.kt");
+        assert!(common.contains("internal expect object UniffiLib {"));
+        assert!(common.contains("fun `greet`(`name`: kotlin.String): kotlin.UByte"));
+        assert!(!common.contains("com.sun.jna"));
+        assert!(!common.contains("import java."));
+
+        let jvm = read("jvmMain/kotlin/uniffi/test_crate/test_crate.jvm.kt");
+        assert!(jvm.contains("internal actual object UniffiLib {"));
+        assert!(jvm.contains("Native.register(UniffiJnaLib::class.java"));
+
+        // Only the source sets that CI compiles are generated
+        assert!(!out_dir.join("androidMain").exists());
+        assert!(!out_dir.join("nativeMain").exists());
+        assert!(!out_dir.join("nativeInterop").exists());
     }
-    // We need to update package names
-    let packages = HashMap::<String, String>::from_iter(
-        components
-            .iter()
-            .map(|c| (c.ci.crate_name().to_string(), c.config.package_name())),
-    );
-    for c in components {
-        for (ext_crate, ext_package) in &packages {
-            if ext_crate != c.ci.crate_name() && !c.config.external_packages.contains_key(ext_crate)
-            {
-                c.config
-                    .external_packages
-                    .insert(ext_crate.to_string(), ext_package.clone());
-            }
-        }
+
+    #[test]
+    fn multiplatform_rejects_unsupported_features() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let out_dir = Utf8Path::from_path(tempdir.path()).unwrap();
+        let err = run_pipeline(multiplatform_root(true), out_dir, None, false).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("async functions and streams are not supported"),
+            "unexpected error: {err}"
+        );
     }
 }
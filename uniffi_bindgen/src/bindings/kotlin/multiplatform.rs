@@ -0,0 +1,107 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Write Kotlin Multiplatform sources, for the `multiplatform = true` config option
+//!
+//! The generated layout is:
+//!
+//! ```text
+//! {out_dir}/
+//!     commonMain/kotlin/{package}/{module}.kt               # public API and `expect` declarations
+//!     jvmMain/kotlin/{package}/{module}.jvm.kt              # JNA actuals
+//! ```
+//!
+//! These directories match the default source set layout of a KMP gradle module, so `out_dir`
+//! can point at the module's `src` directory.
+//!
+//! Only the JVM actuals are generated.  `androidMain` and `nativeMain` actuals can be added once
+//! CI can compile them, see `fixtures/kotlin-multiplatform`.
+//!
+//! The common code is the normal `Module` template, with the JVM-specific parts swapped out for
+//! `expect` declarations.  Only a subset of UniFFI is supported, see `check_supported`.
+
+use std::ops::Deref;
+
+use anyhow::{bail, Result};
+use askama::Template;
+use camino::{Utf8Path, Utf8PathBuf};
+use fs_err as fs;
+
+use super::pipeline::{FfiType, Module, Type, TypeDefinition};
+
+/// Platform code for the JVM
+#[derive(Template)]
+#[template(syntax = "kt", escape = "none", path = "multiplatform/jvm.kt")]
+struct JvmModule<'a> {
+    module: &'a Module,
+}
+
+// Deref to the module, so that the template can use its fields like the other Kotlin templates.
+impl Deref for JvmModule<'_> {
+    type Target = Module;
+
+    fn deref(&self) -> &Module {
+        self.module
+    }
+}
+
+/// Write the source sets for a module, returning the paths of the Kotlin files
+pub fn write_module(module: &Module, out_dir: &Utf8Path) -> Result<Vec<Utf8PathBuf>> {
+    check_supported(module)?;
+
+    let package_path = module.package_name.split('.').collect::<Vec<_>>().join("/");
+    let name = &module.name;
+    let kt_files = vec![
+        write_file(
+            out_dir.join(format!("commonMain/kotlin/{package_path}/{name}.kt")),
+            module.render()?,
+        )?,
+        write_file(
+            out_dir.join(format!("jvmMain/kotlin/{package_path}/{name}.jvm.kt")),
+            JvmModule { module }.render()?,
+        )?,
+    ];
+    Ok(kt_files)
+}
+
+/// Check that a module only uses features that the multiplatform bindings support
+///
+/// Anything that needs callbacks from Rust into Kotlin isn't supported yet, since that would
+/// require static C functions on Kotlin/Native.  Neither are the builtin types that map to JVM
+/// classes.
+fn check_supported(module: &Module) -> Result<()> {
+    let name = &module.name;
+    if module.has_async_fns {
+        bail!("{name}: async functions and streams are not supported with `multiplatform = true`");
+    }
+    if module.has_callback_interface {
+        bail!("{name}: callback interfaces and foreign trait implementations are not supported with `multiplatform = true`");
+    }
+    for type_def in module.type_definitions.iter() {
+        let unsupported = match type_def {
+            TypeDefinition::Closure(_) => "closures",
+            TypeDefinition::Simple(type_node) => match type_node.ty {
+                Type::Timestamp => "timestamps",
+                Type::Duration => "durations",
+                Type::Uuid => "uuids",
+                Type::Url => "urls",
+                Type::Date => "dates",
+                Type::Int128 | Type::UInt128 => "128-bit integers",
+                Type::OwnedBytes => "owned bytes",
+                _ => continue,
+            },
+            _ => continue,
+        };
+        bail!("{name}: {unsupported} are not supported with `multiplatform = true`");
+    }
+    Ok(())
+}
+
+fn write_file(path: Utf8PathBuf, content: String) -> Result<Utf8PathBuf> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, content)?;
+    Ok(path)
+}
@@ -0,0 +1,181 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+/// This module contains the serde structs to parse the `uniffi.toml` config.
+use std::collections::HashSet;
+
+use anyhow::{anyhow, bail, Result};
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use uniffi_pipeline::Node;
+
+// These just exist so we can parse the entire `uniffi.toml` file, the codegen only uses the
+// `KotlinConfig` part.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub bindings: BindingsConfig,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BindingsConfig {
+    #[serde(default)]
+    pub kotlin: KotlinConfig,
+}
+
+// Config options to customize the generated Kotlin.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KotlinConfig {
+    pub(super) package_name: Option<String>,
+    pub(super) cdylib_name: Option<String>,
+    generate_immutable_records: Option<bool>,
+    #[serde(default)]
+    mutable_records: HashSet<String>,
+    #[serde(default)]
+    pub omit_checksums: bool,
+    #[serde(default)]
+    pub custom_types: IndexMap<String, CustomTypeConfig>,
+    /// Package names for external crates, keyed by crate name
+    #[serde(default)]
+    pub external_packages: IndexMap<String, String>,
+    #[serde(default)]
+    android: bool,
+    #[serde(default)]
+    android_cleaner: Option<bool>,
+    #[serde(default)]
+    kotlin_target_version: Option<String>,
+    #[serde(default)]
+    pub disable_java_cleaner: bool,
+    /// Generate Kotlin Multiplatform sources rather than a single JVM source file
+    #[serde(default)]
+    pub multiplatform: bool,
+}
+
+#[derive(Debug, Clone, Node, Default, Deserialize)]
+#[serde(default)]
+pub struct CustomTypeConfig {
+    pub imports: Option<Vec<String>>,
+    pub type_name: Option<String>,
+    pub into_custom: String, // b/w compat alias for lift
+    pub lift: String,
+    pub from_custom: String, // b/w compat alias for lower
+    pub lower: String,
+}
+
+impl KotlinConfig {
+    pub fn from_uniffi_toml(toml: &str) -> Result<Self> {
+        let root: Config = toml::from_str(toml)?;
+        Ok(root.bindings.kotlin)
+    }
+
+    /// Package name for a namespace, using the default if it's not configured
+    pub fn package_name(&self, namespace: &str) -> String {
+        match &self.package_name {
+            Some(package_name) => package_name.clone(),
+            None => format!("uniffi.{namespace}"),
+        }
+    }
+
+    pub fn android_cleaner(&self) -> bool {
+        self.android_cleaner.unwrap_or(self.android)
+    }
+
+    pub fn use_enum_entries(&self) -> Result<bool> {
+        Ok(self.kotlin_version()? >= KotlinVersion::new(1, 9, 0))
+    }
+
+    /// Returns a `KotlinVersion` with the contents of `kotlin_target_version`.
+    ///
+    /// If `kotlin_target_version` is not defined, version `0.0.0` will be used as a fallback.
+    fn kotlin_version(&self) -> Result<KotlinVersion> {
+        match &self.kotlin_target_version {
+            Some(v) => KotlinVersion::parse(v)
+                .map_err(|_| anyhow!("Provided Kotlin target version is not valid: {v}")),
+            None => Ok(KotlinVersion::new(0, 0, 0)),
+        }
+    }
+
+    /// Whether a specific record should be generated with immutable fields.
+    ///
+    /// A record is immutable only if `generate_immutable_records` is enabled
+    /// and the record is not listed in `mutable_records`.
+    pub fn is_record_immutable(&self, name: &str) -> bool {
+        self.generate_immutable_records.unwrap_or(false) && !self.mutable_records.contains(name)
+    }
+}
+
+// functions replace literal "{}" in strings with a specified value.
+impl CustomTypeConfig {
+    pub fn lift(&self, name: &str) -> String {
+        let converter = if self.lift.is_empty() {
+            &self.into_custom
+        } else {
+            &self.lift
+        };
+        converter.replace("{}", name)
+    }
+
+    pub fn lower(&self, name: &str) -> String {
+        let converter = if self.lower.is_empty() {
+            &self.from_custom
+        } else {
+            &self.lower
+        };
+        converter.replace("{}", name)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct KotlinVersion((u16, u16, u16));
+
+impl KotlinVersion {
+    fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self((major, minor, patch))
+    }
+
+    fn parse(version: &str) -> Result<Self> {
+        let components = version
+            .split('.')
+            .map(|n| {
+                n.parse::<u16>()
+                    .map_err(|_| anyhow!("Invalid version string ({n} is not an integer)"))
+            })
+            .collect::<Result<Vec<u16>>>()?;
+
+        match components.as_slice() {
+            [major, minor, patch] => Ok(Self((*major, *minor, *patch))),
+            [major, minor] => Ok(Self((*major, *minor, 0))),
+            [major] => Ok(Self((*major, 0, 0))),
+            _ => bail!("Invalid version string (expected 1-3 components): {version}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kotlin_version() {
+        assert_eq!(
+            KotlinVersion::parse("1.2.3").unwrap(),
+            KotlinVersion::new(1, 2, 3)
+        );
+        assert_eq!(
+            KotlinVersion::parse("2.3").unwrap(),
+            KotlinVersion::new(2, 3, 0),
+        );
+        assert_eq!(
+            KotlinVersion::parse("2").unwrap(),
+            KotlinVersion::new(2, 0, 0),
+        );
+        assert!(KotlinVersion::parse("2.").is_err());
+        assert!(KotlinVersion::parse("").is_err());
+        assert!(KotlinVersion::parse("A.B.C").is_err());
+        assert!(KotlinVersion::new(1, 2, 3) > KotlinVersion::new(0, 1, 2));
+        assert!(KotlinVersion::new(1, 2, 3) > KotlinVersion::new(0, 100, 0));
+        assert!(KotlinVersion::new(10, 0, 0) > KotlinVersion::new(1, 10, 0));
+    }
+}
@@ -0,0 +1,206 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{HashMap, HashSet};
+
+use super::*;
+
+#[derive(Default, Clone)]
+pub struct Context {
+    pub cdylib: Option<String>,
+    pub current_config: Option<KotlinConfig>,
+    pub module_namespace: Option<String>,
+    pub builtin_types: Option<BuiltinTypes>,
+    pub checksum_mode: Option<ChecksumMode>,
+    /// Maps namespaces to their crate name and configured Kotlin package
+    pub namespaces: HashMap<String, (String, String)>,
+    /// (namespace, name) pairs for each type that's used as an error
+    pub error_type_names: HashSet<(String, String)>,
+    /// Types directly referenced by each user-defined type, keyed by (namespace, name).
+    ///
+    /// Used to check if a record/enum contains an object reference.
+    pub type_contents: HashMap<(String, String), Vec<Type>>,
+    /// FFI function types, keyed by their name
+    pub ffi_function_types: HashMap<String, general::FfiFunctionType>,
+}
+
+impl Context {
+    pub fn update_from_root(&mut self, root: &general::Root) -> Result<()> {
+        self.cdylib = root.cdylib.clone();
+        self.checksum_mode = Some(root.checksum_mode.clone());
+        for namespace in root.namespaces.values() {
+            let config = match &namespace.config_toml {
+                Some(toml) => KotlinConfig::from_uniffi_toml(toml)?,
+                None => KotlinConfig::default(),
+            };
+            self.namespaces.insert(
+                namespace.name.clone(),
+                (
+                    namespace.crate_name.clone(),
+                    config.package_name(&namespace.name),
+                ),
+            );
+        }
+        root.visit(|type_node: &general::TypeNode| {
+            if type_node.is_used_as_error {
+                if let (Some(namespace), Some(name)) =
+                    (type_node.ty.namespace(), type_node.ty.name())
+                {
+                    self.error_type_names
+                        .insert((namespace.to_string(), name.to_string()));
+                }
+            }
+        });
+        root.visit(|type_def: &general::TypeDefinition| {
+            let ty = type_def.self_type();
+            let (Some(namespace), Some(name)) = (ty.namespace(), ty.name()) else {
+                return;
+            };
+            let mut contents = vec![];
+            match type_def {
+                general::TypeDefinition::Record(rec) => {
+                    rec.visit(|t: &Type| contents.push(t.clone()))
+                }
+                general::TypeDefinition::Enum(en) => en.visit(|t: &Type| contents.push(t.clone())),
+                general::TypeDefinition::Interface(int) => {
+                    int.visit(|t: &Type| contents.push(t.clone()))
+                }
+                general::TypeDefinition::CallbackInterface(cbi) => {
+                    cbi.visit(|t: &Type| contents.push(t.clone()))
+                }
+                _ => return,
+            }
+            self.type_contents
+                .insert((namespace.to_string(), name.to_string()), contents);
+        });
+        root.visit(|ffi_func_type: &general::FfiFunctionType| {
+            self.ffi_function_types
+                .insert(ffi_func_type.name.0.clone(), ffi_func_type.clone());
+        });
+        self.builtin_types = Some(root.builtin_types.clone().map_node(self)?);
+        Ok(())
+    }
+
+    pub fn update_from_namespace(&mut self, namespace: &general::Namespace) -> Result<()> {
+        self.current_config = Some(match &namespace.config_toml {
+            Some(toml) => KotlinConfig::from_uniffi_toml(toml)?,
+            None => KotlinConfig::default(),
+        });
+        self.module_namespace = Some(namespace.name.clone());
+        Ok(())
+    }
+
+    pub fn builtin_types(&self) -> Result<BuiltinTypes> {
+        self.builtin_types
+            .clone()
+            .ok_or_else(|| anyhow!("Context.builtin_types not set"))
+    }
+
+    pub fn module_namespace(&self) -> Result<&str> {
+        self.module_namespace
+            .as_deref()
+            .ok_or_else(|| anyhow!("Context.module_namespace not set"))
+    }
+
+    pub fn config(&self) -> Result<&KotlinConfig> {
+        self.current_config
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.config not set"))
+    }
+
+    pub fn package_name(&self) -> Result<String> {
+        Ok(self.config()?.package_name(self.module_namespace()?))
+    }
+
+    pub fn cdylib(&self) -> Result<String> {
+        Ok(match &self.config()?.cdylib_name {
+            Some(cdylib) => cdylib.clone(),
+            None => match &self.cdylib {
+                Some(cdylib) => cdylib.clone(),
+                None => format!("uniffi_{}", self.module_namespace()?),
+            },
+        })
+    }
+
+    pub fn checksum_mode(&self) -> Result<&ChecksumMode> {
+        self.checksum_mode
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.checksum_mode not set"))
+    }
+
+    /// Is `namespace` a different module than the one currently being generated?
+    pub fn is_external(&self, namespace: &str) -> Result<bool> {
+        Ok(namespace != self.module_namespace()?)
+    }
+
+    /// Get the Kotlin package for another module
+    pub fn external_package_name(&self, namespace: &str) -> Result<String> {
+        // Config overrides are keyed by the crate name
+        if let Some((crate_name, package_name)) = self.namespaces.get(namespace) {
+            Ok(match self.config()?.external_packages.get(crate_name) {
+                Some(package_name) => package_name.clone(),
+                None => package_name.clone(),
+            })
+        } else {
+            Ok(format!("uniffi.{namespace}"))
+        }
+    }
+
+    pub fn is_used_as_error(&self, namespace: &str, name: &str) -> bool {
+        self.error_type_names
+            .contains(&(namespace.to_string(), name.to_string()))
+    }
+
+    /// Get the Kotlin class name for a user-defined type
+    pub fn class_name(&self, namespace: &str, name: &str) -> String {
+        names::class_name(name, self.is_used_as_error(namespace, name))
+    }
+
+    /// Check if a user-defined type contains any (possibly nested) object references.
+    pub fn contains_object_references(&self, ty: &Type) -> bool {
+        let mut seen = HashSet::new();
+        self.contains_object_references_inner(ty, &mut seen)
+    }
+
+    fn contains_object_references_inner(
+        &self,
+        ty: &Type,
+        seen: &mut HashSet<(String, String)>,
+    ) -> bool {
+        let (Some(namespace), Some(name)) = (ty.namespace(), ty.name()) else {
+            return false;
+        };
+        let key = (namespace.to_string(), name.to_string());
+        if !seen.insert(key.clone()) {
+            return false;
+        }
+        let Some(contents) = self.type_contents.get(&key) else {
+            return false;
+        };
+        contents.iter().any(|t| match t {
+            Type::Interface { .. } => true,
+            Type::Record { .. } | Type::Enum { .. } | Type::CallbackInterface { .. } => {
+                self.contains_object_references_inner(t, seen)
+            }
+            _ => false,
+        })
+    }
+
+    pub fn ffi_function_type(
+        &self,
+        name: &FfiFunctionTypeName,
+    ) -> Result<general::FfiFunctionType> {
+        self.ffi_function_types
+            .get(&name.0)
+            .cloned()
+            .ok_or_else(|| anyhow!("FFI function type not found: {}", name.0))
+    }
+
+    pub fn custom_type_config(
+        &self,
+        custom: &general::CustomType,
+    ) -> Result<Option<CustomTypeConfig>> {
+        Ok(self.config()?.custom_types.get(&custom.name).cloned())
+    }
+}
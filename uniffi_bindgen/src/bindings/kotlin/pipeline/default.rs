@@ -0,0 +1,109 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn render_default(default: &general::DefaultValue, context: &Context) -> Result<String> {
+    Ok(match default {
+        general::DefaultValue::Default(tn) => render_default_for_type(&tn.ty, context)?,
+        general::DefaultValue::Literal(lit) => render_literal(lit, context)?,
+    })
+}
+
+fn render_default_for_type(ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Boolean => "false".to_string(),
+        Type::String => "\"\"".to_string(),
+        Type::Bytes => "byteArrayOf()".to_string(),
+        Type::OwnedBytes => "java.nio.ByteBuffer.allocateDirect(0)".to_string(),
+        Type::Int8 => "0.toByte()".to_string(),
+        Type::Int16 | Type::Int32 => "0".to_string(),
+        Type::Int64 => "0L".to_string(),
+        Type::UInt8 | Type::UInt16 | Type::UInt32 => "0U".to_string(),
+        Type::UInt64 => "0UL".to_string(),
+        Type::Float32 => "0.0f".to_string(),
+        Type::Float64 => "0.0".to_string(),
+        Type::UInt128 | Type::Int128 => "java.math.BigInteger.ZERO".to_string(),
+        Type::Optional { .. } => "null".to_string(),
+        Type::Sequence { .. } => "listOf()".to_string(),
+        Type::Map { .. } => "mapOf()".to_string(),
+        Type::Set { .. } => "setOf()".to_string(),
+        Type::Enum { .. } => bail!("Invalid default value for enum type: {ty:?}"),
+        Type::Custom { builtin, .. } => match render_default_for_type(builtin, context) {
+            Ok(default) => default,
+            Err(_) => bail!(
+                "Unsupported default value for {}",
+                types::type_name(ty, context)?
+            ),
+        },
+        Type::Box { inner_type } => render_default_for_type(inner_type, context)?,
+        // Default for named types is to assume a ctor exists.
+        _ => format!("{}()", types::type_name(ty, context)?),
+    })
+}
+
+pub fn render_literal(lit: &general::Literal, context: &Context) -> Result<String> {
+    Ok(match lit {
+        general::Literal::Boolean(v) => format!("{v}"),
+        general::Literal::String(s) => format!("\"{s}\""),
+        general::Literal::Int(i, radix, tn) => render_integer(*i, radix, &tn.ty)?,
+        general::Literal::UInt(i, radix, tn) => render_integer(*i, radix, &tn.ty)?,
+        general::Literal::Float(value, tn) => typed_number(&tn.ty, value.clone())?,
+        general::Literal::EmptySequence => "listOf()".to_string(),
+        general::Literal::EmptyMap => "mapOf()".to_string(),
+        general::Literal::EmptySet => "setOf()".to_string(),
+        general::Literal::None => "null".to_string(),
+        general::Literal::Some { inner } => render_default(inner, context)?,
+        general::Literal::Enum(variant, tn) => match &tn.ty {
+            Type::Enum {
+                namespace, name, ..
+            } => format!(
+                "{}.{}",
+                context.class_name(namespace, name),
+                names::enum_variant_name(variant)
+            ),
+            type_kind => bail!("Invalid type for enum literal: {type_kind:?}"),
+        },
+    })
+}
+
+/// Get the unwrapped type for a number literal
+fn number_type(ty: &Type) -> &Type {
+    match ty {
+        Type::Optional { inner_type } => number_type(inner_type),
+        Type::Custom { builtin, .. } => number_type(builtin),
+        t => t,
+    }
+}
+
+fn render_integer<T: std::fmt::Display + std::fmt::LowerHex>(
+    i: T,
+    radix: &Radix,
+    ty: &Type,
+) -> Result<String> {
+    // 128-bit integers are represented as `BigInteger`, which can't be created from a literal.
+    if matches!(number_type(ty), Type::UInt128 | Type::Int128) {
+        return Ok(format!("java.math.BigInteger(\"{i}\")"));
+    }
+    typed_number(
+        ty,
+        match radix {
+            Radix::Decimal => format!("{i}"),
+            Radix::Octal | Radix::Hexadecimal => format!("{i:#x}"),
+        },
+    )
+}
+
+fn typed_number(ty: &Type, num_str: String) -> Result<String> {
+    Ok(match number_type(ty) {
+        // Bytes, Shorts and Ints can all be inferred from the type.
+        Type::Int8 | Type::Int16 | Type::Int32 => num_str,
+        Type::Int64 => format!("{num_str}L"),
+        Type::UInt8 | Type::UInt16 | Type::UInt32 => format!("{num_str}u"),
+        Type::UInt64 => format!("{num_str}uL"),
+        Type::Float32 => format!("{num_str}f"),
+        Type::Float64 => num_str,
+        _ => bail!("Unexpected literal: {num_str} for type: {ty:?}"),
+    })
+}
@@ -0,0 +1,80 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn map_enum(en: general::Enum, context: &Context) -> Result<Enum> {
+    let is_error = en.self_type.is_used_as_error;
+    if en.is_flat && !is_error {
+        let trait_methods = &en.uniffi_trait_methods;
+        if trait_methods.eq_eq.is_some()
+            || trait_methods.hash_hash.is_some()
+            || trait_methods.ord_cmp.is_some()
+        {
+            bail!(
+                "Kotlin bindings do not support exporting Eq/Ord/Hash for flat enum `{}`",
+                en.name
+            );
+        }
+    }
+
+    Ok(Enum {
+        name: names::class_name(&en.name, is_error),
+        contains_object_references: context.contains_object_references(&en.self_type.ty),
+        variants: map_variants(en.variants, en.is_flat, is_error, &en.discr_type, context)?,
+        is_flat: en.is_flat,
+        shape: en.shape.map_node(context)?,
+        discr_specified: en.discr_specified,
+        discr_type: en.discr_type.map_node(context)?,
+        docstring: en.docstring,
+        self_type: en.self_type.map_node(context)?,
+        constructors: interfaces::map_constructors(&en.name, en.constructors, context)?,
+        methods: en.methods.map_node(context)?,
+        uniffi_trait_methods: en.uniffi_trait_methods.map_node(context)?,
+        recursive: en.recursive,
+    })
+}
+
+fn map_variants(
+    variants: Vec<general::Variant>,
+    is_flat: bool,
+    is_error: bool,
+    discr_type: &general::TypeNode,
+    context: &Context,
+) -> Result<Vec<Variant>> {
+    variants
+        .into_iter()
+        .map(|v| {
+            Ok(Variant {
+                name: if is_error {
+                    names::error_variant_name(&v.name)
+                } else if is_flat {
+                    names::enum_variant_name(&v.name)
+                } else {
+                    names::class_name(&v.name, false)
+                },
+                discr_literal: discr_literal(&v.discr, discr_type)?,
+                fields_kind: v.fields_kind,
+                fields: v.fields.map_node(context)?,
+                docstring: v.docstring,
+            })
+        })
+        .collect()
+}
+
+/// Get the idiomatic Kotlin rendering of an individual enum variant's discriminant
+fn discr_literal(discr: &general::Literal, discr_type: &general::TypeNode) -> Result<String> {
+    let value = match discr {
+        general::Literal::UInt(v, _, _) => v.to_string(),
+        general::Literal::Int(v, _, _) => v.to_string(),
+        _ => bail!("Only ints are supported: {discr:?}"),
+    };
+    // Kotlin doesn't convert between signed and unsigned by default
+    // so we'll need to make sure we define the type as appropriately
+    Ok(match &discr_type.ty {
+        Type::Int8 | Type::Int16 | Type::Int32 | Type::Int64 => value,
+        Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64 => format!("{value}u"),
+        ty => bail!("Only ints are supported for enum literals: {ty:?}"),
+    })
+}
@@ -0,0 +1,137 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn ffi_type_name_by_value(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        FfiType::RustBuffer(_) => format!("{}.ByValue", ffi_type_name(ffi_type, context)?),
+        FfiType::Struct(_) => format!("{}.UniffiByValue", ffi_type_name(ffi_type, context)?),
+        _ => ffi_type_name(ffi_type, context)?,
+    })
+}
+
+/// Kotlin/JNA direct mapping can mis-handle unsigned 8/16-bit direct return values
+/// on some runtimes, so widen the raw carrier to Int and let the generated
+/// converters lift it back into the public UByte/UShort API types.
+pub fn ffi_type_name_for_direct_return(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        FfiType::UInt8 | FfiType::UInt16 => "Int".to_string(),
+        _ => ffi_type_name_by_value(ffi_type, context)?,
+    })
+}
+
+/// FFI type name to use inside structs
+///
+/// The main requirement here is that all types must have default values or else the struct
+/// won't work in some JNA contexts.
+pub fn ffi_type_name_for_ffi_struct(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        // Make callbacks function pointers nullable. This matches the semantics of a C
+        // function pointer better and allows for `null` as a default value.
+        FfiType::Function(_) => format!("{}?", ffi_type_name(ffi_type, context)?),
+        _ => ffi_type_name_by_value(ffi_type, context)?,
+    })
+}
+
+fn ffi_type_name_by_reference(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        FfiType::Int8
+        | FfiType::UInt8
+        | FfiType::Int16
+        | FfiType::UInt16
+        | FfiType::Int32
+        | FfiType::UInt32
+        | FfiType::Int64
+        | FfiType::UInt64
+        | FfiType::Float32
+        | FfiType::Float64
+        | FfiType::Handle(_) => format!("{}ByReference", ffi_type_name(ffi_type, context)?),
+        // JNA structs default to ByReference
+        FfiType::RustBuffer(_) | FfiType::Struct(_) => ffi_type_name(ffi_type, context)?,
+        _ => bail!("{ffi_type:?} by reference is not implemented"),
+    })
+}
+
+fn ffi_type_name(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        // Note that unsigned integers in Kotlin are currently experimental, but java.nio.ByteBuffer does not
+        // support them yet. Thus, we use the signed variants to represent both signed and unsigned
+        // types from the component API.
+        FfiType::Int8 | FfiType::UInt8 => "Byte".to_string(),
+        FfiType::Int16 | FfiType::UInt16 => "Short".to_string(),
+        FfiType::Int32 | FfiType::UInt32 => "Int".to_string(),
+        FfiType::Int64 | FfiType::UInt64 => "Long".to_string(),
+        FfiType::Float32 => "Float".to_string(),
+        FfiType::Float64 => "Double".to_string(),
+        FfiType::Handle(_) => "Long".to_string(),
+        FfiType::RustBuffer(_) => match external_rust_buffer_name(ffi_type, context)? {
+            Some(name) => name,
+            None => "RustBuffer".to_string(),
+        },
+        FfiType::RustCallStatus => "UniffiRustCallStatus.ByValue".to_string(),
+        FfiType::ForeignBytes => "ForeignBytes.ByValue".to_string(),
+        FfiType::Function(name) => name.clone().map_node(context)?.0,
+        FfiType::Struct(name) => name.clone().map_node(context)?.0,
+        FfiType::Reference(inner) | FfiType::MutReference(inner) => {
+            ffi_type_name_by_reference(inner, context)?
+        }
+        FfiType::VoidPointer => "Pointer".to_string(),
+    })
+}
+
+/// Get the local alias for an external module's `RustBuffer` class
+///
+/// Returns `None` for anything other than a `RustBuffer` from another module.
+pub fn external_rust_buffer_name(ffi_type: &FfiType, context: &Context) -> Result<Option<String>> {
+    Ok(match ffi_type {
+        FfiType::RustBuffer(Some(namespace)) if context.is_external(namespace)? => {
+            Some(names::external_rust_buffer_name(namespace))
+        }
+        _ => None,
+    })
+}
+
+/// Default values for FFI
+///
+/// This is used to:
+///   - Set a default return value for error results
+///   - Set a default for structs, which JNA sometimes requires
+pub fn ffi_default_value(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        FfiType::UInt8 | FfiType::Int8 => "0.toByte()".to_string(),
+        FfiType::UInt16 | FfiType::Int16 => "0.toShort()".to_string(),
+        FfiType::UInt32 | FfiType::Int32 => "0".to_string(),
+        FfiType::UInt64 | FfiType::Int64 | FfiType::Handle(_) => "0.toLong()".to_string(),
+        FfiType::Float32 => "0.0f".to_string(),
+        FfiType::Float64 => "0.0".to_string(),
+        FfiType::RustBuffer(_) => format!("{}.ByValue()", ffi_type_name(ffi_type, context)?),
+        FfiType::Function(_) => "null".to_string(),
+        FfiType::RustCallStatus => "UniffiRustCallStatus.ByValue()".to_string(),
+        _ => bail!("No default value for FFI type: {ffi_type:?}"),
+    })
+}
+
+/// Default value to return from a VTable method when the call fails
+pub fn return_ffi_default_value(
+    return_type: &general::ReturnType,
+    context: &Context,
+) -> Result<Option<String>> {
+    return_type
+        .ty
+        .as_ref()
+        .map(|type_node| ffi_default_value(&type_node.ffi_type, context))
+        .transpose()
+}
+
+/// Get the `FfiFunctionType` for a VTable method
+pub fn vtable_method_function_type(
+    ffi_type: &FfiType,
+    context: &Context,
+) -> Result<FfiFunctionType> {
+    match ffi_type {
+        FfiType::Function(name) => context.ffi_function_type(name)?.map_node(context),
+        _ => bail!("Invalid VTable method FFI type: {ffi_type:?}"),
+    }
+}
@@ -0,0 +1,75 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Interface naming
+//!
+//! For a regular `struct Foo` or `trait Foo`, there's `FooInterface` with `Foo` as
+//! the name of the (Rust implemented) object. But if it's a foreign trait:
+//! * The name `Foo` is the name of the interface used by a the Kotlin implementation of the trait.
+//! * The Rust implemented object is `FooImpl`.
+//!
+//! This all impacts what types `FfiConverter.lower()` inputs.  If it's a "foreign trait"
+//! `lower` must lower anything that implements the interface (ie, a kotlin implementation).
+//! If not, then lower only lowers the concrete class (ie, our simple instance with the pointer).
+
+use super::*;
+
+pub fn interface_name(int: &general::Interface) -> String {
+    let class_name = names::class_name(&int.name, int.self_type.is_used_as_error);
+    if int.imp.has_callback_interface() {
+        class_name
+    } else {
+        format!("{class_name}Interface")
+    }
+}
+
+pub fn impl_name(int: &general::Interface) -> String {
+    let class_name = names::class_name(&int.name, int.self_type.is_used_as_error);
+    if int.imp.has_callback_interface() {
+        format!("{class_name}Impl")
+    } else {
+        class_name
+    }
+}
+
+pub fn trait_interface_names(int: &general::Interface, context: &Context) -> Result<Vec<String>> {
+    int.trait_impls
+        .iter()
+        .map(|trait_impl| match &trait_impl.trait_ty.ty {
+            Type::Interface {
+                namespace,
+                name,
+                imp,
+                ..
+            } => {
+                let class_name = context.class_name(namespace, name);
+                Ok(if imp.has_callback_interface() {
+                    class_name
+                } else {
+                    format!("{class_name}Interface")
+                })
+            }
+            Type::CallbackInterface {
+                namespace, name, ..
+            } => Ok(context.class_name(namespace, name)),
+            ty => bail!("Invalid trait_type: {ty:?}"),
+        })
+        .collect()
+}
+
+pub fn map_constructors(
+    interface_name: &str,
+    constructors: Vec<general::Constructor>,
+    context: &Context,
+) -> Result<Vec<Constructor>> {
+    constructors
+        .into_iter()
+        .map(|c| {
+            if c.callable.is_primary_constructor() && c.callable.is_async() {
+                bail!("Async primary constructors not supported but {interface_name} has one");
+            }
+            c.map_node(context)
+        })
+        .collect()
+}
@@ -0,0 +1,194 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::BTreeSet;
+
+use super::*;
+
+pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result<Module> {
+    let mut child_context = context.clone();
+    let context = &mut child_context;
+    context.update_from_namespace(&namespace)?;
+    let config = context.config()?;
+
+    // Rust closures, streams and owned bytes are freed using the same cleaner as interfaces
+    let has_interfaces = namespace.has_descendant(|_: &general::Interface| true)
+        || namespace.closure_vtable.is_some()
+        || namespace.has_descendant(|_: &general::StreamType| true)
+        || namespace.has_descendant(|ty: &Type| matches!(ty, Type::OwnedBytes));
+    let android_cleaner = config.android_cleaner();
+    let disable_java_cleaner = config.disable_java_cleaner;
+
+    Ok(Module {
+        cdylib_name: context.cdylib()?,
+        package_name: context.package_name()?,
+        checksum_mode: context.checksum_mode()?.clone(),
+        omit_checksums: config.omit_checksums
+            || matches!(context.checksum_mode()?, ChecksumMode::Skip),
+        use_enum_entries: config.use_enum_entries()?,
+        android_cleaner,
+        disable_java_cleaner,
+        multiplatform: config.multiplatform,
+        has_async_fns: namespace.has_descendant(|callable: &general::Callable| callable.is_async())
+            || namespace.has_descendant(|_: &general::StreamType| true),
+        has_streams: namespace.has_descendant(|_: &general::StreamType| true),
+        has_callback_interface: has_callback_interface(&namespace),
+        has_async_callback_method: has_async_callback_method(&namespace),
+        has_interfaces,
+        imports: module_imports(&namespace, has_interfaces, context)?,
+        initialization_fns: initialization_fns(&namespace, context)?,
+        tuple_classes: tuple_classes(&namespace),
+        name: namespace.name.map_node(context)?,
+        crate_name: namespace.crate_name.map_node(context)?,
+        docstring: namespace.docstring.map_node(context)?,
+        functions: namespace.functions.map_node(context)?,
+        constants: namespace.constants.map_node(context)?,
+        type_definitions: namespace.type_definitions.map_node(context)?,
+        ffi_definitions: namespace.ffi_definitions.map_node(context)?,
+        checksums: namespace.checksums.map_node(context)?,
+        ffi_rustbuffer_alloc: namespace.ffi_rustbuffer_alloc,
+        ffi_rustbuffer_from_bytes: namespace.ffi_rustbuffer_from_bytes,
+        ffi_rustbuffer_free: namespace.ffi_rustbuffer_free,
+        ffi_rustbuffer_reserve: namespace.ffi_rustbuffer_reserve,
+        ffi_uniffi_contract_version: namespace.ffi_uniffi_contract_version,
+        closure_vtable: namespace.closure_vtable.map_node(context)?,
+        correct_contract_version: namespace.correct_contract_version,
+        builtin_types: context.builtin_types()?,
+    })
+}
+
+fn has_callback_interface(namespace: &general::Namespace) -> bool {
+    namespace.has_descendant(|_: &general::CallbackInterface| true)
+        || namespace.has_descendant(|int: &general::Interface| int.imp.has_callback_interface())
+}
+
+fn has_async_callback_method(namespace: &general::Namespace) -> bool {
+    let callback_interface_async = namespace.has_descendant(|cbi: &general::CallbackInterface| {
+        cbi.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    let trait_interface_async = namespace.has_descendant(|int: &general::Interface| {
+        int.imp.has_callback_interface()
+            && int.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    callback_interface_async || trait_interface_async
+}
+
+fn module_imports(
+    namespace: &general::Namespace,
+    has_interfaces: bool,
+    context: &Context,
+) -> Result<Vec<Import>> {
+    let config = context.config()?;
+    let mut imports = BTreeSet::new();
+
+    for type_def in namespace.type_definitions.iter() {
+        match type_def {
+            general::TypeDefinition::Custom(custom) => {
+                if let Some(custom_config) = context.custom_type_config(custom)? {
+                    for name in custom_config.imports.iter().flatten() {
+                        imports.insert(Import::new(name));
+                    }
+                }
+            }
+            general::TypeDefinition::External(ext) => {
+                let package_name = context.external_package_name(&ext.namespace)?;
+                let class_name = context.class_name(&ext.namespace, &ext.name);
+                imports.insert(Import::new(&format!("{package_name}.{class_name}")));
+                imports.insert(Import::new(&format!(
+                    "{package_name}.{}",
+                    types::ffi_converter_name(&ext.self_type.ty)
+                )));
+                imports.insert(Import {
+                    name: format!("{package_name}.RustBuffer"),
+                    as_name: Some(names::external_rust_buffer_name(&ext.namespace)),
+                });
+            }
+            _ => (),
+        }
+    }
+
+    // In multiplatform mode these are only used by the JVM code, which imports them itself
+    if has_interfaces && !config.multiplatform {
+        imports.insert(Import::new("java.util.concurrent.atomic.AtomicBoolean"));
+        if config.android_cleaner() && !config.disable_java_cleaner {
+            imports.insert(Import::new("android.os.Build"));
+            imports.insert(Import::new("androidx.annotation.RequiresApi"));
+        }
+    }
+
+    // Import types needed for async support
+    if namespace.has_descendant(|callable: &general::Callable| callable.is_async())
+        || namespace.has_descendant(|_: &general::StreamType| true)
+    {
+        for name in [
+            "kotlin.coroutines.resume",
+            "kotlinx.coroutines.launch",
+            "kotlinx.coroutines.suspendCancellableCoroutine",
+            "kotlinx.coroutines.CancellableContinuation",
+            "kotlinx.coroutines.DelicateCoroutinesApi",
+            "kotlinx.coroutines.Job",
+            "kotlinx.coroutines.GlobalScope",
+        ] {
+            imports.insert(Import::new(name));
+        }
+    }
+
+    Ok(imports.into_iter().collect())
+}
+
+/// `TupleN` data classes needed by this module, one for each tuple size above 3
+fn tuple_classes(namespace: &general::Namespace) -> Vec<TupleClass> {
+    let mut sizes = BTreeSet::new();
+    namespace.visit(|tuple: &general::TupleType| {
+        if tuple.elements.len() > 3 {
+            sizes.insert(tuple.elements.len());
+        }
+    });
+    sizes
+        .into_iter()
+        .map(|len| TupleClass {
+            name: types::tuple_class_name(len),
+            type_params: ('A'..='Z').take(len).map(String::from).collect(),
+            property_names: types::tuple_property_names(len),
+        })
+        .collect()
+}
+
+/// Functions to run when the `UniffiLib` object is initialized
+fn initialization_fns(namespace: &general::Namespace, context: &Context) -> Result<Vec<String>> {
+    let mut init_fns = vec!["uniffiEnsureInitialized()".to_string()];
+    namespace.visit(|vtable: &general::VTable| {
+        init_fns.push(format!(
+            "uniffiCallbackInterface{}.register(this)",
+            vtable.interface_name
+        ));
+    });
+    if namespace.closure_vtable.is_some() {
+        init_fns.push("uniffiClosureRuntime.register(this)".to_string());
+    }
+
+    // Also call global initialization function for any external type we use.
+    // For example, we need to make sure that all callback interface vtables are registered
+    // (#2343).
+    let mut external_init_fns = BTreeSet::new();
+    for type_def in namespace.type_definitions.iter() {
+        if let general::TypeDefinition::External(ext) = type_def {
+            external_init_fns.insert(format!(
+                "{}.uniffiEnsureInitialized()",
+                context.external_package_name(&ext.namespace)?
+            ));
+        }
+    }
+    init_fns.extend(external_init_fns);
+    Ok(init_fns)
+}
+
+impl Import {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            as_name: None,
+        }
+    }
+}
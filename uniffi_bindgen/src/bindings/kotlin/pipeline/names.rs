@@ -0,0 +1,98 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
+
+use super::*;
+
+pub fn map_ffi_function_type_name(
+    ffi_function_type_name: FfiFunctionTypeName,
+    _: &Context,
+) -> Result<FfiFunctionTypeName> {
+    Ok(FfiFunctionTypeName(ffi_callback_name(
+        &ffi_function_type_name.0,
+    )))
+}
+
+pub fn map_ffi_struct_name(name: FfiStructName, _: &Context) -> Result<FfiStructName> {
+    Ok(FfiStructName(ffi_struct_name(&name.0)))
+}
+
+/// Get the idiomatic Kotlin rendering of a class name (for enums, records, errors, etc).
+///
+/// Types used as errors get their `Error` suffix replaced with `Exception`.
+pub fn class_name(name: &str, is_used_as_error: bool) -> String {
+    let name = name.to_upper_camel_case();
+    if is_used_as_error {
+        convert_error_suffix(&name)
+    } else {
+        name
+    }
+}
+
+fn convert_error_suffix(name: &str) -> String {
+    match name.strip_suffix("Error") {
+        None => name.to_string(),
+        Some(stripped) => format!("{stripped}Exception"),
+    }
+}
+
+/// Get the idiomatic Kotlin rendering of a function name.
+pub fn fn_name(name: &str) -> String {
+    format!("`{}`", name.to_lower_camel_case())
+}
+
+/// Get the idiomatic Kotlin rendering of a variable name.
+pub fn var_name(name: &str) -> String {
+    format!("`{}`", var_name_raw(name))
+}
+
+/// Get the idiomatic Kotlin rendering of a field name.
+///
+/// Unnamed fields stay empty, the templates name those based on their position.
+pub fn field_name(name: &str) -> String {
+    if name.is_empty() {
+        String::new()
+    } else {
+        var_name(name)
+    }
+}
+
+/// `var_name` without the backticks.  Useful for using in `@Structure.FieldOrder`.
+pub fn var_name_raw(name: &str) -> String {
+    name.to_lower_camel_case()
+}
+
+/// Get the idiomatic Kotlin rendering of a constant name.
+pub fn const_name(name: &str) -> String {
+    name.to_shouty_snake_case()
+}
+
+/// Get the idiomatic Kotlin rendering of an individual enum variant.
+pub fn enum_variant_name(name: &str) -> String {
+    name.to_shouty_snake_case()
+}
+
+/// Get the idiomatic Kotlin rendering of an error variant.
+pub fn error_variant_name(name: &str) -> String {
+    convert_error_suffix(&name.to_upper_camel_case())
+}
+
+/// Get the idiomatic Kotlin rendering of an FFI callback function name
+pub fn ffi_callback_name(name: &str) -> String {
+    format!("Uniffi{}", name.to_upper_camel_case())
+}
+
+/// Get the idiomatic Kotlin rendering of an FFI struct name
+pub fn ffi_struct_name(name: &str) -> String {
+    format!("Uniffi{}", name.to_upper_camel_case())
+}
+
+/// Local name for the `RustBuffer` class of another module
+///
+/// Each module defines its own `RustBuffer` class, so we import the ones from external modules
+/// using an alias.
+pub fn external_rust_buffer_name(namespace: &str) -> String {
+    format!("RustBuffer{}", namespace.to_upper_camel_case())
+}
@@ -0,0 +1,782 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use askama::Template;
+
+use uniffi_pipeline::{MapNode, Node};
+
+use crate::{bindings::kotlin::filters, pipeline::general};
+
+use super::*;
+
+use_prev_node!(general::Checksum);
+use_prev_node!(general::ChecksumMode);
+use_prev_node!(general::EnumShape);
+use_prev_node!(general::FieldsKind);
+use_prev_node!(general::FfiFunctionKind);
+use_prev_node!(
+    general::FfiFunctionTypeName,
+    names::map_ffi_function_type_name
+);
+use_prev_node!(general::FfiStructName, names::map_ffi_struct_name);
+use_prev_node!(general::FfiType);
+use_prev_node!(general::HandleKind);
+use_prev_node!(general::ObjectImpl);
+use_prev_node!(general::PassBy);
+use_prev_node!(general::PropertyKind);
+use_prev_node!(general::PropertyMetadata);
+use_prev_node!(general::Radix);
+use_prev_node!(general::RustFfiFunctionName);
+use_prev_node!(general::TraitKind);
+use_prev_node!(general::Type);
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Root))]
+#[map_node(update_context(context.update_from_root(&self)?))]
+pub struct Root {
+    /// In library mode, the library path the user passed to us
+    pub cdylib: Option<String>,
+    #[map_node(from(namespaces))]
+    pub modules: IndexMap<String, Module>,
+    pub builtin_types: BuiltinTypes,
+    pub checksum_mode: ChecksumMode,
+}
+
+#[derive(Debug, Clone, MapNode, Node)]
+#[map_node(from(general::BuiltinTypes))]
+pub struct BuiltinTypes {
+    pub u8: TypeNode,
+    pub i8: TypeNode,
+    pub u16: TypeNode,
+    pub i16: TypeNode,
+    pub u32: TypeNode,
+    pub i32: TypeNode,
+    pub u64: TypeNode,
+    pub i64: TypeNode,
+    pub f32: TypeNode,
+    pub f64: TypeNode,
+    pub string: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Template)]
+#[template(syntax = "kt", escape = "none", path = "wrapper.kt")]
+#[map_node(from(general::Namespace))]
+#[map_node(modules::map_namespace)]
+pub struct Module {
+    pub name: String,
+    pub crate_name: String,
+    pub package_name: String,
+    pub cdylib_name: String,
+    pub docstring: Option<String>,
+    /// Import statements, sorted and de-duplicated
+    pub imports: Vec<Import>,
+    /// Statements to run when the `UniffiLib` object is initialized
+    pub initialization_fns: Vec<String>,
+    pub has_async_fns: bool,
+    pub has_streams: bool,
+    pub has_async_callback_method: bool,
+    pub has_callback_interface: bool,
+    pub has_interfaces: bool,
+    pub use_enum_entries: bool,
+    pub android_cleaner: bool,
+    pub disable_java_cleaner: bool,
+    /// Render the common code for Kotlin Multiplatform, see the `multiplatform` module
+    pub multiplatform: bool,
+    pub omit_checksums: bool,
+    /// `TupleN` data classes for tuples that don't fit in a `Pair` or `Triple`
+    pub tuple_classes: Vec<TupleClass>,
+    pub functions: Vec<Function>,
+    pub constants: Vec<Constant>,
+    pub type_definitions: Vec<TypeDefinition>,
+    pub ffi_definitions: IndexSet<FfiDefinition>,
+    pub checksums: Vec<Checksum>,
+    pub ffi_rustbuffer_alloc: RustFfiFunctionName,
+    pub ffi_rustbuffer_from_bytes: RustFfiFunctionName,
+    pub ffi_rustbuffer_free: RustFfiFunctionName,
+    pub ffi_rustbuffer_reserve: RustFfiFunctionName,
+    pub ffi_uniffi_contract_version: RustFfiFunctionName,
+    pub closure_vtable: Option<ClosureVTable>,
+    // Correct contract version value
+    pub correct_contract_version: String,
+    // Copy builtin types so that we can use in from the templates
+    pub builtin_types: BuiltinTypes,
+    pub checksum_mode: ChecksumMode,
+}
+
+/// Kotlin import statement
+#[derive(Debug, Clone, Node, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Import {
+    /// The name we are importing.
+    pub name: String,
+    /// Local name for the import, if it's different from the name
+    pub as_name: Option<String>,
+}
+
+/// Generic data class used to represent tuples with more than 3 elements
+#[derive(Debug, Clone, Node)]
+pub struct TupleClass {
+    pub name: String,
+    pub type_params: Vec<String>,
+    pub property_names: Vec<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constant))]
+pub struct Constant {
+    #[map_node(names::const_name(&self.name))]
+    pub name: String,
+    /// Can this be declared with `const val`?  Kotlin only allows that for primitives and strings.
+    #[map_node(types::is_const_val_type(&self.ty.ty))]
+    pub is_const_val: bool,
+    /// The value rendered as a Kotlin expression
+    #[map_node(default::render_literal(&self.value, context)?)]
+    pub kt_value: String,
+    pub ty: TypeNode,
+    pub value: Literal,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Function))]
+pub struct Function {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeDefinition))]
+pub enum TypeDefinition {
+    Interface(Interface),
+    CallbackInterface(CallbackInterface),
+    Record(Record),
+    Enum(Enum),
+    Custom(CustomType),
+    /// Type that doesn't contain any other type
+    Simple(TypeNode),
+    /// Compound types
+    Box(BoxedType),
+    Optional(OptionalType),
+    Sequence(SequenceType),
+    Map(MapType),
+    Set(SetType),
+    Stream(StreamType),
+    Tuple(TupleType),
+    Array(ArrayType),
+    Closure(ClosureType),
+    /// User types that are defined in another crate
+    External(ExternalType),
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constructor))]
+pub struct Constructor {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Method))]
+pub struct Method {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+    pub property: Option<PropertyMetadata>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Property))]
+pub struct Property {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+    pub getter: Method,
+    pub setter: Option<Method>,
+}
+
+/// Common data from Function/Method/Constructor
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Callable))]
+pub struct Callable {
+    #[map_node(names::fn_name(&self.name))]
+    pub name: String,
+    pub async_data: Option<AsyncData>,
+    pub kind: CallableKind,
+    pub arguments: Vec<Argument>,
+    pub return_type: ReturnType,
+    pub throws_type: ThrowsType,
+    pub checksum: Option<u16>,
+    pub ffi_func: RustFfiFunctionName,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::CallableKind))]
+pub enum CallableKind {
+    /// Toplevel function
+    Function,
+    /// Interface/Trait interface method
+    Method {
+        self_type: TypeNode,
+        takes_self_by_arc: bool,
+    },
+    /// Interface constructor
+    Constructor { self_type: TypeNode, primary: bool },
+    /// Interface static method, these don't take a `self` argument
+    StaticMethod { self_type: TypeNode },
+    /// Method inside a VTable or a CallbackInterface
+    ///
+    /// For trait interfaces this only applies to the Callables inside the `vtable.methods` field.
+    /// Callables inside `Interface::methods` will still be `Callable::Method`.
+    VTableMethod {
+        self_type: TypeNode,
+        takes_self_by_arc: bool,
+        for_callback_interface: bool,
+    },
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::AsyncData))]
+pub struct AsyncData {
+    pub ffi_rust_future_poll: RustFfiFunctionName,
+    pub ffi_rust_future_cancel: RustFfiFunctionName,
+    pub ffi_rust_future_free: RustFfiFunctionName,
+    pub ffi_rust_future_complete: RustFfiFunctionName,
+    pub ffi_foreign_future_complete: FfiFunctionTypeName,
+    pub ffi_foreign_future_result: FfiStructName,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::ReturnType))]
+pub struct ReturnType {
+    pub ty: Option<TypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::ThrowsType))]
+pub struct ThrowsType {
+    pub ty: Option<TypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Argument))]
+pub struct Argument {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// Name of the argument in the FFI function type for VTable methods
+    #[map_node(names::var_name(&self.orig_name))]
+    pub ffi_name: String,
+    /// Kotlin type name for the argument when lowering it.
+    ///
+    /// Borrowed `Bytes` are passed as a `java.nio.ByteBuffer` -- the only Kotlin type JNA can
+    /// expose a native pointer to.  Other args use the type's `type_name`.
+    #[map_node(types::argument_type_name(&self, context)?)]
+    pub type_name: String,
+    /// Function to lower the argument.
+    ///
+    /// Borrowed `Bytes` go through `FfiConverterByRefBytes.lower` (zero-copy).
+    #[map_node(types::argument_lower_fn(&self))]
+    pub lower_fn: String,
+    pub ty: TypeNode,
+    pub pass_by: PassBy,
+    pub optional: bool,
+    pub default: Option<DefaultValueNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::DefaultValue))]
+pub enum DefaultValue {
+    Default(TypeNode),
+    Literal(Literal),
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::DefaultValue))]
+pub struct DefaultValueNode {
+    /// The default value rendered as a Kotlin expression
+    #[map_node(default::render_default(&self, context)?)]
+    pub kt_default: String,
+    #[map_node(self.map_node(context)?)]
+    pub default: DefaultValue,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Literal))]
+pub enum Literal {
+    Boolean(bool),
+    String(String),
+    // Integers are represented as the widest representation we can.
+    // Number formatting vary with language and radix, so we avoid a lot of parsing and
+    // formatting duplication by using only signed and unsigned variants.
+    UInt(u64, Radix, TypeNode),
+    Int(i64, Radix, TypeNode),
+    // Pass the string representation through as typed in the UDL.
+    // This avoids a lot of uncertainty around precision and accuracy,
+    // though bindings for languages less sophisticated number parsing than WebIDL
+    // will have to do extra work.
+    Float(String, TypeNode),
+    Enum(String, TypeNode),
+    EmptySequence,
+    EmptyMap,
+    EmptySet,
+    None,
+    Some { inner: Box<DefaultValue> },
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Record))]
+pub struct Record {
+    #[map_node(names::class_name(&self.name, self.self_type.is_used_as_error))]
+    pub name: String,
+    /// Should the fields be `val` rather than `var`?
+    #[map_node(context.config()?.is_record_immutable(&self.name))]
+    pub immutable: bool,
+    #[map_node(context.contains_object_references(&self.self_type.ty))]
+    pub contains_object_references: bool,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    #[map_node(interfaces::map_constructors(&self.name, self.constructors, context)?)]
+    pub constructors: Vec<Constructor>,
+    pub methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Field))]
+pub struct Field {
+    /// Kotlin name for the field, this is empty for unnamed fields
+    #[map_node(names::field_name(&self.name))]
+    pub name: String,
+    pub ty: TypeNode,
+    pub default: Option<DefaultValueNode>,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Enum))]
+#[map_node(enums::map_enum)]
+pub struct Enum {
+    pub name: String,
+    /// Is this a "flat" enum -- one with no associated data
+    pub is_flat: bool,
+    pub shape: EnumShape,
+    /// Was the discriminant type specified by the user?
+    ///
+    /// If so, flat enums get a `value` property with the discriminant.
+    pub discr_specified: bool,
+    pub variants: Vec<Variant>,
+    pub discr_type: TypeNode,
+    pub contains_object_references: bool,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub constructors: Vec<Constructor>,
+    pub methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Variant {
+    /// Variant name.
+    ///
+    /// This depends on the kind of enum: `SHOUTY_CASE` for flat enums, class names for sealed
+    /// classes, and exception names for errors.
+    pub name: String,
+    /// The discriminant, rendered as a Kotlin literal
+    pub discr_literal: String,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Interface))]
+pub struct Interface {
+    #[map_node(names::class_name(&self.name, self.self_type.is_used_as_error))]
+    pub name: String,
+    /// Name of the Kotlin interface that declares the methods
+    #[map_node(interfaces::interface_name(&self))]
+    pub interface_name: String,
+    /// Name of the class that wraps a Rust handle
+    #[map_node(interfaces::impl_name(&self))]
+    pub impl_name: String,
+    /// Kotlin interfaces for the traits this interface implements
+    #[map_node(interfaces::trait_interface_names(&self, context)?)]
+    pub trait_interface_names: Vec<String>,
+    pub docstring: Option<String>,
+    #[map_node(interfaces::map_constructors(&self.name, self.constructors, context)?)]
+    pub constructors: Vec<Constructor>,
+    pub properties: Vec<Property>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub trait_impls: Vec<ObjectTraitImpl>,
+    pub imp: ObjectImpl,
+    pub self_type: TypeNode,
+    pub vtable: Option<VTable>,
+    pub ffi_func_clone: RustFfiFunctionName,
+    pub ffi_func_free: RustFfiFunctionName,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallbackInterface))]
+pub struct CallbackInterface {
+    #[map_node(names::class_name(&self.name, self.self_type.is_used_as_error))]
+    pub name: String,
+    pub docstring: Option<String>,
+    pub vtable: VTable,
+    pub methods: Vec<Method>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTable))]
+pub struct VTable {
+    pub struct_type: FfiTypeNode,
+    pub interface_name: String,
+    pub init_fn: RustFfiFunctionName,
+    pub clone_fn_type: FfiFunctionTypeName,
+    pub free_fn_type: FfiFunctionTypeName,
+    pub methods: Vec<VTableMethod>,
+}
+
+/// Single method in a vtable
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTableMethod))]
+pub struct VTableMethod {
+    /// Default value for the FFI return type, used when the call fails
+    #[map_node(ffi_types::return_ffi_default_value(&self.callable.return_type, context)?)]
+    pub ffi_default_value: Option<String>,
+    /// The FFI function type that the method's callback implements
+    #[map_node(ffi_types::vtable_method_function_type(&self.ffi_type, context)?)]
+    pub ffi_function_type: FfiFunctionType,
+    pub callable: Callable,
+    pub ffi_type: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ObjectTraitImpl))]
+pub struct ObjectTraitImpl {
+    pub ty: TypeNode,
+    pub trait_ty: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CustomType))]
+pub struct CustomType {
+    #[map_node(names::class_name(&self.name, self.self_type.is_used_as_error))]
+    pub name: String,
+    #[map_node(context.custom_type_config(&self)?)]
+    pub config: Option<CustomTypeConfig>,
+    pub builtin: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::BoxedType))]
+pub struct BoxedType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::OptionalType))]
+pub struct OptionalType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SequenceType))]
+pub struct SequenceType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::MapType))]
+pub struct MapType {
+    pub key: TypeNode,
+    pub value: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SetType))]
+pub struct SetType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TupleType))]
+pub struct TupleType {
+    /// Property used to access each element, like `first` or `second`
+    #[map_node(types::tuple_property_names(self.elements.len()))]
+    pub property_names: Vec<String>,
+    pub elements: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ArrayType))]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    pub is_byte_array: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureType))]
+pub struct ClosureType {
+    pub arguments: Vec<TypeNode>,
+    pub return_type: ReturnType,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureVTable))]
+pub struct ClosureVTable {
+    pub struct_type: FfiTypeNode,
+    pub init_fn: RustFfiFunctionName,
+    pub free_fn_type: FfiFunctionTypeName,
+    pub call_fn_type: FfiFunctionTypeName,
+    pub ffi_closure_call: RustFfiFunctionName,
+    pub ffi_closure_free: RustFfiFunctionName,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::StreamType))]
+pub struct StreamType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+    pub ffi_stream_next: RustFfiFunctionName,
+    pub ffi_stream_free: RustFfiFunctionName,
+    pub async_data: AsyncData,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ExternalType))]
+pub struct ExternalType {
+    /// Local alias for the `RustBuffer` class of the external module
+    #[map_node(names::external_rust_buffer_name(&self.namespace))]
+    pub rust_buffer_name: String,
+    pub namespace: String,
+    pub name: String,
+    pub self_type: TypeNode,
+}
+
+/// Wrap `Type` so that we can add extra fields that are set for all variants.
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::TypeNode))]
+pub struct TypeNode {
+    /// Kotlin type name
+    #[map_node(types::type_name(&self.ty, context)?)]
+    pub type_name: String,
+    /// Kotlin type name, with user-defined types qualified by their package name
+    #[map_node(types::qualified_type_name(&self.ty, context)?)]
+    pub qualified_type_name: String,
+    /// Name that can be used as part of another identifier, for example `FfiConverterOptionalInt`
+    #[map_node(types::canonical_name(&self.ty))]
+    pub canonical_name: String,
+    #[map_node(types::ffi_converter_name(&self.ty))]
+    pub ffi_converter_name: String,
+    /// Is this a user-defined type from another module?
+    #[map_node(types::is_external(&self.ty, context)?)]
+    pub is_external: bool,
+    pub ty: Type,
+    pub is_used_as_error: bool,
+    pub ffi_type: FfiTypeNode,
+}
+
+/// Like `TypeNode` but for FFI types.
+///
+/// This exists so that language bindings generators can add extra fields
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(FfiType))]
+pub struct FfiTypeNode {
+    /// Kotlin type name, when passed by value
+    #[map_node(ffi_types::ffi_type_name_by_value(&self, context)?)]
+    pub type_name: String,
+    /// Kotlin type name, when used as a field of a JNA `Structure`
+    #[map_node(ffi_types::ffi_type_name_for_ffi_struct(&self, context)?)]
+    pub struct_field_type_name: String,
+    /// Kotlin type name, when returned from a JNA direct-mapped function
+    #[map_node(ffi_types::ffi_type_name_for_direct_return(&self, context)?)]
+    pub direct_return_type_name: String,
+    /// For `RustBuffer`s from other modules, the local alias for that module's `RustBuffer` class
+    #[map_node(ffi_types::external_rust_buffer_name(&self, context)?)]
+    pub external_rust_buffer_name: Option<String>,
+    #[map_node(self.map_node(context)?)]
+    pub ty: FfiType,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::UniffiTraitMethods))]
+pub struct UniffiTraitMethods {
+    pub debug_fmt: Option<Method>,
+    pub display_fmt: Option<Method>,
+    pub eq_eq: Option<Method>,
+    pub eq_ne: Option<Method>,
+    pub hash_hash: Option<Method>,
+    pub ord_cmp: Option<Method>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::FfiDefinition))]
+pub enum FfiDefinition {
+    /// FFI Function exported in the Rust library
+    RustFunction(FfiFunction),
+    /// FFI Function definition used in the interface, language, for example a callback interface method.
+    FunctionType(FfiFunctionType),
+    /// Struct definition used in the interface, for example a callback interface Vtable.
+    Struct(FfiStruct),
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunction))]
+pub struct FfiFunction {
+    pub name: RustFfiFunctionName,
+    pub async_data: Option<AsyncData>,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+    pub kind: FfiFunctionKind,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunctionType))]
+pub struct FfiFunctionType {
+    pub name: FfiFunctionTypeName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiReturnType))]
+pub struct FfiReturnType {
+    pub ty: Option<FfiTypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiStruct))]
+pub struct FfiStruct {
+    pub name: FfiStructName,
+    pub fields: Vec<FfiField>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiField))]
+pub struct FfiField {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// Name without the backticks, for `@Structure.FieldOrder`
+    #[map_node(names::var_name_raw(&self.name))]
+    pub raw_name: String,
+    /// Default value for the field, JNA requires all struct fields to have one
+    #[map_node(ffi_types::ffi_default_value(&self.ty, context)?)]
+    pub default_value: String,
+    pub ty: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiArgument))]
+pub struct FfiArgument {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+impl Callable {
+    pub fn is_async(&self) -> bool {
+        self.async_data.is_some()
+    }
+
+    pub fn self_type(&self) -> Option<TypeNode> {
+        match &self.kind {
+            CallableKind::Method { self_type, .. }
+            | CallableKind::VTableMethod { self_type, .. } => Some(self_type.clone()),
+            _ => None,
+        }
+    }
+
+    /// Is this a method on an interface?
+    ///
+    /// These are called using the object's handle, rather than lowering `this`.
+    pub fn is_interface_method(&self) -> bool {
+        matches!(
+            self.self_type(),
+            Some(TypeNode {
+                ty: Type::Interface { .. },
+                ..
+            })
+        )
+    }
+
+    pub fn is_primary_constructor(&self) -> bool {
+        matches!(self.kind, CallableKind::Constructor { primary: true, .. })
+    }
+}
+
+impl Interface {
+    pub fn has_callback_interface(&self) -> bool {
+        self.imp.has_callback_interface()
+    }
+
+    pub fn primary_constructor(&self) -> Option<&Constructor> {
+        self.constructors
+            .iter()
+            .find(|c| c.callable.is_primary_constructor())
+    }
+
+    pub fn alternate_constructors(&self) -> Vec<&Constructor> {
+        self.constructors
+            .iter()
+            .filter(|c| !c.callable.is_primary_constructor())
+            .collect()
+    }
+}
+
+impl Variant {
+    pub fn has_fields(&self) -> bool {
+        !self.fields.is_empty()
+    }
+}
+
+impl FfiFunction {
+    /// Is this one of the functions used to check the integrity of the library?
+    ///
+    /// These are declared in `IntegrityCheckingUniffiLib` rather than `UniffiLib`.
+    pub fn is_integrity_check(&self) -> bool {
+        matches!(
+            self.kind,
+            FfiFunctionKind::Checksum | FfiFunctionKind::UniffiContractVersion
+        )
+    }
+}
+
+impl Module {
+    /// FFI functions declared by the multiplatform bindings
+    ///
+    /// `rustbuffer_from_bytes` is left out, since that mode doesn't support `ForeignBytes`.
+    /// Instead, we allocate a `RustBuffer` and copy the bytes into it.
+    pub fn multiplatform_ffi_functions(&self) -> Vec<&FfiFunction> {
+        self.ffi_definitions
+            .iter()
+            .filter_map(|def| match def {
+                FfiDefinition::RustFunction(func)
+                    if !matches!(func.kind, FfiFunctionKind::RustBufferFromBytes) =>
+                {
+                    Some(func)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
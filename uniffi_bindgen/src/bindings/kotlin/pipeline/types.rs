@@ -0,0 +1,299 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Boolean => "kotlin.Boolean".to_string(),
+        Type::String => "kotlin.String".to_string(),
+        Type::Bytes => "kotlin.ByteArray".to_string(),
+        // Owned bytes wrap the Rust allocation without copying, which requires a direct `ByteBuffer`.
+        Type::OwnedBytes => "java.nio.ByteBuffer".to_string(),
+        Type::Int8 => "kotlin.Byte".to_string(),
+        Type::Int16 => "kotlin.Short".to_string(),
+        Type::Int32 => "kotlin.Int".to_string(),
+        Type::Int64 => "kotlin.Long".to_string(),
+        Type::UInt8 => "kotlin.UByte".to_string(),
+        Type::UInt16 => "kotlin.UShort".to_string(),
+        Type::UInt32 => "kotlin.UInt".to_string(),
+        Type::UInt64 => "kotlin.ULong".to_string(),
+        Type::Float32 => "kotlin.Float".to_string(),
+        Type::Float64 => "kotlin.Double".to_string(),
+        // 128-bit integers don't fit in any Kotlin primitive, so they're represented as `BigInteger`.
+        Type::UInt128 | Type::Int128 => "java.math.BigInteger".to_string(),
+        Type::Timestamp => "java.time.Instant".to_string(),
+        Type::Duration => "java.time.Duration".to_string(),
+        Type::Uuid => "java.util.UUID".to_string(),
+        Type::Url => "java.net.URI".to_string(),
+        Type::Date => "java.time.LocalDate".to_string(),
+        Type::Interface {
+            namespace, name, ..
+        }
+        | Type::Record {
+            namespace, name, ..
+        }
+        | Type::Enum {
+            namespace, name, ..
+        }
+        | Type::CallbackInterface {
+            namespace, name, ..
+        }
+        | Type::Custom {
+            namespace, name, ..
+        } => context.class_name(namespace, name),
+        // Function types need parentheses, otherwise the `?` would apply to the return type
+        Type::Optional { inner_type } => match **inner_type {
+            Type::Closure { .. } => format!("({})?", type_name(inner_type, context)?),
+            _ => format!("{}?", type_name(inner_type, context)?),
+        },
+        Type::Sequence { inner_type } => format!("List<{}>", type_name(inner_type, context)?),
+        Type::Array { inner_type, .. } => match **inner_type {
+            Type::UInt8 => "kotlin.ByteArray".to_string(),
+            _ => format!("List<{}>", type_name(inner_type, context)?),
+        },
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "Map<{}, {}>",
+            type_name(key_type, context)?,
+            type_name(value_type, context)?
+        ),
+        Type::Set { inner_type } => format!("Set<{}>", type_name(inner_type, context)?),
+        Type::Stream { inner_type } => format!(
+            "kotlinx.coroutines.flow.Flow<{}>",
+            type_name(inner_type, context)?
+        ),
+        Type::Tuple { types } => format!(
+            "{}<{}>",
+            tuple_class_name(types.len()),
+            types
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        Type::Closure {
+            arguments,
+            return_type,
+        } => format!(
+            "({}) -> {}",
+            arguments
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", "),
+            match return_type {
+                Some(ty) => type_name(ty, context)?,
+                None => "Unit".to_string(),
+            }
+        ),
+        Type::Box { inner_type } => type_name(inner_type, context)?,
+    })
+}
+
+/// Like `type_name`, but user-defined types are qualified with their package name
+pub fn qualified_type_name(ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Interface {
+            namespace, name, ..
+        }
+        | Type::Record {
+            namespace, name, ..
+        }
+        | Type::Enum {
+            namespace, name, ..
+        }
+        | Type::CallbackInterface {
+            namespace, name, ..
+        }
+        | Type::Custom {
+            namespace, name, ..
+        } => {
+            let package_name = if context.is_external(namespace)? {
+                context.external_package_name(namespace)?
+            } else {
+                context.package_name()?
+            };
+            format!("{package_name}.{}", context.class_name(namespace, name))
+        }
+        Type::Optional { inner_type } => match **inner_type {
+            Type::Closure { .. } => format!("({})?", qualified_type_name(inner_type, context)?),
+            _ => format!("{}?", qualified_type_name(inner_type, context)?),
+        },
+        Type::Sequence { inner_type } => {
+            format!("List<{}>", qualified_type_name(inner_type, context)?)
+        }
+        Type::Array { inner_type, .. } if !matches!(**inner_type, Type::UInt8) => {
+            format!("List<{}>", qualified_type_name(inner_type, context)?)
+        }
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "Map<{}, {}>",
+            qualified_type_name(key_type, context)?,
+            qualified_type_name(value_type, context)?
+        ),
+        Type::Set { inner_type } => {
+            format!("Set<{}>", qualified_type_name(inner_type, context)?)
+        }
+        Type::Tuple { types } => {
+            let class_name = match types.len() {
+                2 | 3 => format!("kotlin.{}", tuple_class_name(types.len())),
+                len => format!("{}.{}", context.package_name()?, tuple_class_name(len)),
+            };
+            format!(
+                "{class_name}<{}>",
+                types
+                    .iter()
+                    .map(|ty| qualified_type_name(ty, context))
+                    .collect::<Result<Vec<_>>>()?
+                    .join(", ")
+            )
+        }
+        _ => type_name(ty, context)?,
+    })
+}
+
+/// A representation of the type name that can be used as part of another identifier.
+///
+/// For example `FfiConverterOptionalInt`.
+pub fn canonical_name(ty: &Type) -> String {
+    match ty {
+        Type::Boolean => "Boolean".to_string(),
+        Type::String => "String".to_string(),
+        Type::Bytes => "ByteArray".to_string(),
+        Type::OwnedBytes => "OwnedBytes".to_string(),
+        Type::Int8 => "Byte".to_string(),
+        Type::Int16 => "Short".to_string(),
+        Type::Int32 => "Int".to_string(),
+        Type::Int64 => "Long".to_string(),
+        Type::UInt8 => "UByte".to_string(),
+        Type::UInt16 => "UShort".to_string(),
+        Type::UInt32 => "UInt".to_string(),
+        Type::UInt64 => "ULong".to_string(),
+        Type::Float32 => "Float".to_string(),
+        Type::Float64 => "Double".to_string(),
+        Type::UInt128 => "UInt128".to_string(),
+        Type::Int128 => "Int128".to_string(),
+        Type::Timestamp => "Timestamp".to_string(),
+        Type::Duration => "Duration".to_string(),
+        Type::Uuid => "Uuid".to_string(),
+        Type::Url => "Url".to_string(),
+        Type::Date => "Date".to_string(),
+        Type::Interface { name, .. }
+        | Type::Record { name, .. }
+        | Type::Enum { name, .. }
+        | Type::CallbackInterface { name, .. }
+        | Type::Custom { name, .. } => format!("Type{name}"),
+        Type::Optional { inner_type } => format!("Optional{}", canonical_name(inner_type)),
+        Type::Sequence { inner_type } => format!("Sequence{}", canonical_name(inner_type)),
+        Type::Array { inner_type, len } => format!("Array{len}{}", canonical_name(inner_type)),
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "Map{}{}",
+            canonical_name(key_type),
+            canonical_name(value_type)
+        ),
+        Type::Set { inner_type } => format!("Set{}", canonical_name(inner_type)),
+        Type::Stream { inner_type } => format!("Stream{}", canonical_name(inner_type)),
+        Type::Tuple { types } => format!(
+            "Tuple{}{}",
+            types.len(),
+            types.iter().map(canonical_name).collect::<String>()
+        ),
+        Type::Closure {
+            arguments,
+            return_type,
+        } => format!(
+            "Closure{}{}{}",
+            arguments.len(),
+            arguments.iter().map(canonical_name).collect::<String>(),
+            match return_type {
+                Some(ty) => canonical_name(ty),
+                None => "Void".to_string(),
+            }
+        ),
+        Type::Box { inner_type } => canonical_name(inner_type),
+    }
+}
+
+/// Kotlin class used to represent a tuple with `len` elements
+///
+/// Pairs and triples use the stdlib classes, larger tuples use a generated `TupleN` data class.
+pub fn tuple_class_name(len: usize) -> String {
+    match len {
+        2 => "Pair".to_string(),
+        3 => "Triple".to_string(),
+        _ => format!("Tuple{len}"),
+    }
+}
+
+/// Property names for a tuple's elements, following the `Pair`/`Triple` convention
+pub fn tuple_property_names(len: usize) -> Vec<String> {
+    [
+        "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+        "tenth", "eleventh", "twelfth",
+    ]
+    .into_iter()
+    .take(len)
+    .map(str::to_string)
+    .collect()
+}
+
+/// Name of the FfiConverter
+///
+/// This is the object that contains the lower, write, lift, and read methods for this type.
+pub fn ffi_converter_name(ty: &Type) -> String {
+    format!("FfiConverter{}", canonical_name(ty))
+}
+
+/// Can constants with this type be declared using `const val`?
+pub fn is_const_val_type(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Boolean
+            | Type::String
+            | Type::Int8
+            | Type::Int16
+            | Type::Int32
+            | Type::Int64
+            | Type::UInt8
+            | Type::UInt16
+            | Type::UInt32
+            | Type::UInt64
+            | Type::Float32
+            | Type::Float64
+    )
+}
+
+pub fn is_external(ty: &Type, context: &Context) -> Result<bool> {
+    Ok(match ty.namespace() {
+        Some(namespace) => context.is_external(namespace)?,
+        None => false,
+    })
+}
+
+/// Per-argument override of `type_name` for the foreign->Rust (lower) direction only.
+///
+/// Not used for Rust->Kotlin (callback / lift) positions.
+pub fn argument_type_name(arg: &general::Argument, context: &Context) -> Result<String> {
+    if arg.is_borrowed_bytes() {
+        Ok("java.nio.ByteBuffer".to_string())
+    } else {
+        type_name(&arg.ty.ty, context)
+    }
+}
+
+pub fn argument_lower_fn(arg: &general::Argument) -> String {
+    if arg.is_borrowed_bytes() {
+        "FfiConverterByRefBytes.lower".to_string()
+    } else {
+        format!("{}.lower", ffi_converter_name(&arg.ty.ty))
+    }
+}
@@ -0,0 +1,44 @@
+{%- let inner_type = array.inner %}
+{%- let type_name = array.self_type.type_name %}
+{%- let ffi_converter_name = array.self_type.ffi_converter_name %}
+
+/**
+ * @suppress
+ */
+public object {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
+    override fun read(buf: ByteBuffer): {{ type_name }} {
+        val len = buf.getInt()
+        check(len == {{ array.len }}) { "Expected an array of length {{ array.len }}, got $len" }
+        {%- if array.is_byte_array %}
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr
+        {%- else %}
+        return List<{{ inner_type.type_name }}>(len) {
+            {{ inner_type.ffi_converter_name }}.read(buf)
+        }
+        {%- endif %}
+    }
+
+    override fun allocationSize(value: {{ type_name }}): ULong {
+        require(value.size == {{ array.len }}) { "Expected an array of length {{ array.len }}, got ${value.size}" }
+        {%- if array.is_byte_array %}
+        return 4UL + value.size.toULong()
+        {%- else %}
+        val sizeForItems = value.map { {{ inner_type.ffi_converter_name }}.allocationSize(it) }.sum()
+        return 4UL + sizeForItems
+        {%- endif %}
+    }
+
+    override fun write(value: {{ type_name }}, buf: ByteBuffer) {
+        require(value.size == {{ array.len }}) { "Expected an array of length {{ array.len }}, got ${value.size}" }
+        buf.putInt(value.size)
+        {%- if array.is_byte_array %}
+        buf.put(value)
+        {%- else %}
+        value.iterator().forEach {
+            {{ inner_type.ffi_converter_name }}.write(it, buf)
+        }
+        {%- endif %}
+    }
+}
@@ -39,7 +39,61 @@ internal suspend fun<T, F, E: kotlin.Exception> uniffiRustCallAsync(
     }
 }
 
-{%- if ci.has_async_callback_interface_definition() %}
+{%- if has_streams %}
+
+// Wraps items read from a Rust stream, so that a `null` item can be told apart from the end of the stream.
+internal class UniffiStreamItem<T>(val value: T)
+
+// Create a `Flow` for a stream returned from Rust
+//
+// Each item is fetched by calling `nextFunc`, which returns a Rust future that resolves to the item.
+// The stream handle is freed when the flow completes or the collecting coroutine is cancelled,
+// which drops the Rust stream.  Flows that are never collected are freed by the library cleaner once
+// they're garbage collected.
+internal fun<T> uniffiRustStreamFlow(
+    streamHandle: Long,
+    nextFunc: (Long) -> Long,
+    freeFunc: (Long) -> Unit,
+    pollFunc: (Long, UniffiRustFutureContinuationCallback, Long) -> Unit,
+    completeFunc: (Long, UniffiRustCallStatus) -> RustBuffer.ByValue,
+    futureFreeFunc: (Long) -> Unit,
+    liftItem: (RustBuffer.ByValue) -> UniffiStreamItem<T>?,
+): kotlinx.coroutines.flow.Flow<T> {
+    // Set once collection starts.  From then on the collector owns the stream and frees it when it
+    // stops.  The cleaner only frees streams that were never collected, since the flow can become
+    // unreachable while a collector is still polling the stream.
+    val collected = java.util.concurrent.atomic.AtomicBoolean(false)
+    val flow = kotlinx.coroutines.flow.flow {
+        if (collected.getAndSet(true)) {
+            throw IllegalStateException("Streams returned from Rust can only be collected once")
+        }
+        try {
+            while (true) {
+                val item = uniffiRustCallAsync(
+                    nextFunc(streamHandle),
+                    pollFunc,
+                    completeFunc,
+                    futureFreeFunc,
+                    liftItem,
+                    UniffiNullRustCallStatusErrorHandler,
+                ) ?: break
+                emit(item.value)
+            }
+        } finally {
+            freeFunc(streamHandle)
+        }
+    }
+    // The clean up task must not reference the flow, otherwise it would never become unreachable
+    UniffiLib.CLEANER.register(flow, Runnable {
+        if (!collected.getAndSet(true)) {
+            freeFunc(streamHandle)
+        }
+    })
+    return flow
+}
+{%- endif %}
+
+{%- if has_async_callback_method %}
 internal inline fun<T> uniffiTraitInterfaceCallAsync(
     crossinline makeCall: suspend () -> T,
     crossinline handleSuccess: (T) -> Unit,
@@ -67,7 +121,7 @@ internal inline fun<T> uniffiTraitInterfaceCallAsync(
             handleError(
                 UniffiRustCallStatus.create(
                     UNIFFI_CALL_UNEXPECTED_ERROR,
-                    {{ Type::String.borrow()|lower_fn }}(e.toString()),
+                    {{ builtin_types.string.ffi_converter_name }}.lower(e.toString()),
                 )
             )
             return@coroutineBlock
@@ -104,7 +158,7 @@ internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallAsyncWithEr
                 handleError(
                     UniffiRustCallStatus.create(
                         UNIFFI_CALL_UNEXPECTED_ERROR,
-                        {{ Type::String.borrow()|lower_fn }}(e.toString()),
+                        {{ builtin_types.string.ffi_converter_name }}.lower(e.toString()),
                     )
                 )
             }
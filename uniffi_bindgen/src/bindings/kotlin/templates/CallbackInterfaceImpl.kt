@@ -1,38 +1,41 @@
-{%- let trait_impl=format!("uniffiCallbackInterface{}", name) %}
+{%- let trait_impl=format!("uniffiCallbackInterface{}", vtable.interface_name) %}
 
 // Put the implementation in an object so we don't pollute the top-level namespace
 internal object {{ trait_impl }} {
-    {%- for (ffi_callback, meth) in vtable_methods.iter() %}
-    internal object {{ meth.name()|var_name }}: {{ ffi_callback.name()|ffi_callback_name }} {
+    {%- for vtable_meth in vtable.methods %}
+    {%- let meth = vtable_meth.callable %}
+    {%- let ffi_callback = vtable_meth.ffi_function_type %}
+    internal object {{ meth.name }}: {{ ffi_callback.name.0 }} {
         override fun callback(
-            {%- for arg in ffi_callback.arguments() -%}
-            {{ arg.name().borrow()|var_name }}: {{ arg.type_().borrow()|ffi_type_name_by_value(ci) }},
+            {%- for arg in ffi_callback.arguments -%}
+            {{ arg.name }}: {{ arg.ty.type_name }},
             {%- endfor -%}
-            {%- if ffi_callback.has_rust_call_status_arg() -%}
+            {%- if ffi_callback.has_rust_call_status_arg -%}
             uniffiCallStatus: UniffiRustCallStatus,
             {%- endif -%}
         )
-        {%- if let Some(return_type) = ffi_callback.return_type() %}
-            : {{ return_type|ffi_type_name_by_value(ci) }},
+        {%- if let Some(return_type) = ffi_callback.return_type.ty %}
+            : {{ return_type.type_name }},
         {%- endif %} {
             val uniffiObj = {{ ffi_converter_name }}.handleMap.get(uniffiHandle)
             val makeCall = {% if meth.is_async() %}suspend {% endif %}{ ->
-                uniffiObj.{{ meth.name()|fn_name() }}(
-                    {%- for arg in meth.arguments() %}
-                    {{ arg|lift_fn }}({{ arg.name()|var_name }}),
+                uniffiObj.{{ meth.name }}(
+                    {%- for arg in meth.arguments %}
+                    {{ arg.ty.ffi_converter_name }}.lift({{ arg.ffi_name }}),
                     {%- endfor %}
                 )
             }
-            {%- if !meth.is_async() %}
+            {%- match meth.async_data %}
+            {%- when None %}
 
-            {%- match meth.return_type() %}
+            {%- match meth.return_type.ty %}
             {%- when Some(return_type) %}
-            val writeReturn = { value: {{ return_type|type_name(ci) }} -> uniffiOutReturn.setValue({{ return_type|lower_fn }}(value)) }
+            val writeReturn = { value: {{ return_type.type_name }} -> uniffiOutReturn.setValue({{ return_type.ffi_converter_name }}.lower(value)) }
             {%- when None %}
             val writeReturn = { _: Unit -> Unit }
             {%- endmatch %}
 
-            {%- match meth.throws_type() %}
+            {%- match meth.throws_type.ty %}
             {%- when None %}
             uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
             {%- when Some(error_type) %}
@@ -40,15 +43,15 @@ internal object {{ trait_impl }} {
                 uniffiCallStatus,
                 makeCall,
                 writeReturn,
-                { e: {{error_type|type_name(ci) }} -> {{ error_type|lower_fn }}(e) }
+                { e: {{ error_type.type_name }} -> {{ error_type.ffi_converter_name }}.lower(e) }
             )
             {%- endmatch %}
 
-            {%- else %}
-            val uniffiHandleSuccess = { {% if meth.return_type().is_some() %}returnValue{% else %}_{% endif %}: {% match meth.return_type() %}{%- when Some(return_type) %}{{ return_type|type_name(ci) }}{%- when None %}Unit{% endmatch %} ->
-                val uniffiResult = {{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}.UniffiByValue(
-                    {%- if let Some(return_type) = meth.return_type() %}
-                    {{ return_type|lower_fn }}(returnValue),
+            {%- when Some(async_data) %}
+            val uniffiHandleSuccess = { {% if meth.return_type.ty.is_some() %}returnValue{% else %}_{% endif %}: {% match meth.return_type.ty %}{%- when Some(return_type) %}{{ return_type.type_name }}{%- when None %}Unit{% endmatch %} ->
+                val uniffiResult = {{ async_data.ffi_foreign_future_result.0 }}.UniffiByValue(
+                    {%- if let Some(return_type) = meth.return_type.ty %}
+                    {{ return_type.ffi_converter_name }}.lower(returnValue),
                     {%- endif %}
                     UniffiRustCallStatus.ByValue()
                 )
@@ -58,16 +61,16 @@ internal object {{ trait_impl }} {
             val uniffiHandleError = { callStatus: UniffiRustCallStatus.ByValue ->
                 uniffiFutureCallback.callback(
                     uniffiCallbackData,
-                    {{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}.UniffiByValue(
-                        {%- if let Some(return_type) = meth.return_type() %}
-                        {{ return_type.into()|ffi_default_value }},
+                    {{ async_data.ffi_foreign_future_result.0 }}.UniffiByValue(
+                        {%- if let Some(ffi_default_value) = vtable_meth.ffi_default_value %}
+                        {{ ffi_default_value }},
                         {%- endif %}
                         callStatus,
                     ),
                 )
             }
 
-            {%- match meth.throws_type() %}
+            {%- match meth.throws_type.ty %}
             {%- when None %}
             uniffiTraitInterfaceCallAsync(
                 makeCall,
@@ -80,38 +83,38 @@ internal object {{ trait_impl }} {
                 makeCall,
                 uniffiHandleSuccess,
                 uniffiHandleError,
-                { e: {{error_type|type_name(ci) }} -> {{ error_type|lower_fn }}(e) },
+                { e: {{ error_type.type_name }} -> {{ error_type.ffi_converter_name }}.lower(e) },
                 uniffiOutDroppedCallback
             )
             {%- endmatch %}
-            {%- endif %}
+            {%- endmatch %}
         }
     }
     {%- endfor %}
 
-    internal object uniffiFree: {{ "CallbackInterfaceFree"|ffi_callback_name }} {
+    internal object uniffiFree: {{ vtable.free_fn_type.0 }} {
         override fun callback(handle: Long) {
             {{ ffi_converter_name }}.handleMap.remove(handle)
         }
     }
 
-    internal object uniffiClone: {{ "CallbackInterfaceClone"|ffi_callback_name }} {
+    internal object uniffiClone: {{ vtable.clone_fn_type.0 }} {
         override fun callback(handle: Long): Long {
             return {{ ffi_converter_name }}.handleMap.clone(handle)
         }
     }
 
-    internal var vtable = {{ vtable|ffi_type_name_by_value(ci) }}(
+    internal var vtable = {{ vtable.struct_type.type_name }}(
         uniffiFree,
         uniffiClone,
-        {%- for (ffi_callback, meth) in vtable_methods.iter() %}
-        {{ meth.name()|var_name() }},
+        {%- for vtable_meth in vtable.methods %}
+        {{ vtable_meth.callable.name }},
         {%- endfor %}
     )
 
     // Registers the foreign callback with the Rust side.
     // This method is generated for each callback interface.
     internal fun register(lib: UniffiLib) {
-        lib.{{ ffi_init_callback.name() }}(vtable)
+        lib.{{ vtable.init_fn.0 }}(vtable)
     }
 }
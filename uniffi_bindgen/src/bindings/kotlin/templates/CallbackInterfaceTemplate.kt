@@ -1,10 +1,9 @@
-{%- let cbi = ci.get_callback_interface_definition(name).unwrap() %}
-{%- let ffi_init_callback = cbi.ffi_init_callback() %}
-{%- let interface_name = cbi|type_name(ci) %}
-{%- let interface_docstring = cbi.docstring() %}
-{%- let methods = cbi.methods() %}
-{%- let vtable = cbi.vtable() %}
-{%- let vtable_methods = cbi.vtable_methods() %}
+{%- let interface_name = cbi.name %}
+{%- let interface_docstring = cbi.docstring %}
+{%- let methods = cbi.methods %}
+{%- let properties = Vec::<Property>::new() %}
+{%- let vtable = cbi.vtable %}
+{%- let ffi_converter_name = cbi.self_type.ffi_converter_name %}
 
 {% include "Interface.kt" %}
 {% include "CallbackInterfaceImpl.kt" %}
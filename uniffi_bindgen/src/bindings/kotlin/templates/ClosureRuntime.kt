@@ -0,0 +1,94 @@
+
+// Closures are type-erased at the FFI level: the arguments and return value are serialized into
+// `RustBuffer`s.  This means all closure types share a single vtable.
+
+// Kotlin closures passed to Rust.  The handle map stores functions that input the serialized
+// arguments and return the serialized return value.
+internal object uniffiClosureRuntime {
+    internal val handleMap = UniffiHandleMap<(RustBuffer.ByValue) -> RustBuffer.ByValue>()
+
+    internal object uniffiFree: {{ closure_vtable.free_fn_type.0 }} {
+        override fun callback(handle: Long) {
+            handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiCall: {{ closure_vtable.call_fn_type.0 }} {
+        override fun callback(
+            handle: Long,
+            args: RustBuffer.ByValue,
+            uniffiOutReturn: RustBuffer,
+            uniffiCallStatus: UniffiRustCallStatus,
+        ) {
+            val call = handleMap.get(handle)
+            uniffiTraitInterfaceCall(
+                uniffiCallStatus,
+                { call(args) },
+                { value: RustBuffer.ByValue -> uniffiOutReturn.setValue(value) },
+            )
+        }
+    }
+
+    internal var vtable = {{ closure_vtable.struct_type.type_name }}(
+        uniffiFree,
+        uniffiCall,
+    )
+
+    // Registers the closure vtable with the Rust side.
+    internal fun register(lib: UniffiLib) {
+        lib.{{ closure_vtable.init_fn.0 }}(vtable)
+    }
+}
+
+/**
+ * Closure implemented in Rust.  The Rust closure is freed when this is garbage collected.
+ *
+ * @suppress
+ */
+internal class UniffiRustClosure(private val handle: Long) {
+    init {
+        UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    fun call(args: RustBuffer.ByValue): RustBuffer.ByValue {
+        return uniffiRustCall { status ->
+            UniffiLib.{{ closure_vtable.ffi_closure_call.0 }}(handle, args, status)
+        }
+    }
+
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            UniffiLib.{{ closure_vtable.ffi_closure_free.0 }}(handle)
+        }
+    }
+}
+
+// Serialize closure arguments or a closure return value into a `RustBuffer`
+internal inline fun uniffiWriteClosureBuffer(allocationSize: ULong, write: (ByteBuffer) -> Unit): RustBuffer.ByValue {
+    val rbuf = RustBuffer.alloc(allocationSize)
+    try {
+        val bbuf = rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
+            it.order(ByteOrder.BIG_ENDIAN)
+        }
+        write(bbuf)
+        rbuf.writeField("len", bbuf.position().toLong())
+        return rbuf
+    } catch (e: Throwable) {
+        RustBuffer.free(rbuf)
+        throw e
+    }
+}
+
+// Deserialize closure arguments or a closure return value from a `RustBuffer`, then free it
+internal inline fun <T> uniffiReadClosureBuffer(rbuf: RustBuffer.ByValue, read: (ByteBuffer) -> T): T {
+    val byteBuf = rbuf.asByteBuffer()!!
+    try {
+        val item = read(byteBuf)
+        if (byteBuf.hasRemaining()) {
+            throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
+        }
+        return item
+    } finally {
+        RustBuffer.free(rbuf)
+    }
+}
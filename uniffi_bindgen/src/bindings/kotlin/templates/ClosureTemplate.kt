@@ -0,0 +1,60 @@
+{%- let type_name = closure.self_type.type_name %}
+{%- let ffi_converter_name = closure.self_type.ffi_converter_name %}
+
+/**
+ * @suppress
+ */
+public object {{ ffi_converter_name }}: FfiConverter<{{ type_name }}, Long> {
+    override fun lift(value: Long): {{ type_name }} {
+        val rustClosure = UniffiRustClosure(value)
+        return { {% for arg in closure.arguments %}arg{{ loop.index0 }}: {{ arg.type_name }}{% if !loop.last %}, {% endif %}{% endfor %} ->
+            val uniffiArgs = uniffiWriteClosureBuffer(
+                {%- for arg in closure.arguments %}
+                {{ arg.ffi_converter_name }}.allocationSize(arg{{ loop.index0 }}) +
+                {%- endfor %}
+                0UL
+            ) { {% if closure.arguments.is_empty() %}_{% else %}buf{% endif %} ->
+                {%- for arg in closure.arguments %}
+                {{ arg.ffi_converter_name }}.write(arg{{ loop.index0 }}, buf)
+                {%- endfor %}
+            }
+            uniffiReadClosureBuffer(rustClosure.call(uniffiArgs)) { {% if closure.return_type.ty.is_some() %}buf{% else %}_{% endif %} ->
+                {%- match closure.return_type.ty %}
+                {%- when Some(return_type) %}
+                {{ return_type.ffi_converter_name }}.read(buf)
+                {%- when None %}
+                Unit
+                {%- endmatch %}
+            }
+        }
+    }
+
+    override fun lower(value: {{ type_name }}): Long {
+        return uniffiClosureRuntime.handleMap.insert { uniffiArgs ->
+            {% if closure.return_type.ty.is_some() %}val uniffiReturn = {% endif -%}
+            uniffiReadClosureBuffer(uniffiArgs) { {% if closure.arguments.is_empty() %}_{% else %}buf{% endif %} ->
+                value(
+                    {%- for arg in closure.arguments %}
+                    {{ arg.ffi_converter_name }}.read(buf),
+                    {%- endfor %}
+                )
+            }
+            {%- match closure.return_type.ty %}
+            {%- when Some(return_type) %}
+            uniffiWriteClosureBuffer({{ return_type.ffi_converter_name }}.allocationSize(uniffiReturn)) { buf ->
+                {{ return_type.ffi_converter_name }}.write(uniffiReturn, buf)
+            }
+            {%- when None %}
+            uniffiWriteClosureBuffer(0UL) { _ -> }
+            {%- endmatch %}
+        }
+    }
+
+    override fun read(buf: ByteBuffer) = lift(buf.getLong())
+
+    override fun allocationSize(value: {{ type_name }}) = 8UL
+
+    override fun write(value: {{ type_name }}, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
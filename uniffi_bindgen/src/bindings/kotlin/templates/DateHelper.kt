@@ -0,0 +1,15 @@
+/**
+ * @suppress
+ */
+public object FfiConverterDate: FfiConverterRustBuffer<java.time.LocalDate> {
+    override fun read(buf: ByteBuffer): java.time.LocalDate {
+        // Days since 1970-01-01
+        return java.time.LocalDate.ofEpochDay(buf.getInt().toLong())
+    }
+
+    override fun allocationSize(value: java.time.LocalDate) = 4UL
+
+    override fun write(value: java.time.LocalDate, buf: ByteBuffer) {
+        buf.putInt(Math.toIntExact(value.toEpochDay()))
+    }
+}
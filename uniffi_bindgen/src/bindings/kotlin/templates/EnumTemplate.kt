@@ -5,50 +5,52 @@
 // and `sealed class` for the general case.
 #}
 
-{%- if e.is_flat() %}
+{%- let type_name = e.self_type.type_name %}
+{%- let contains_object_references = e.contains_object_references %}
+
+{%- if e.is_flat %}
 
 {%- call kt::docstring(e, 0) %}{% endcall %}
-{% match e.variant_discr_type() %}
-{% when None %}
+{% if !e.discr_specified %}
 enum class {{ type_name }} {
-    {% for variant in e.variants() -%}
+    {% for variant in e.variants -%}
     {%- call kt::docstring(variant, 4) %}{% endcall %}
-    {{ variant|variant_name }}{% if loop.last %};{% else %},{% endif %}
+    {{ variant.name }}{% if loop.last %};{% else %},{% endif %}
     {%- endfor %}
 
-    {% for meth in e.methods() -%}
+    {% for meth in e.methods -%}
     {%- call kt::func_decl("", meth, 4) %}{% endcall %}
     {% endfor %}
 
-    {%- let uniffi_trait_methods = e.uniffi_trait_methods() %}
+    {%- let uniffi_trait_methods = e.uniffi_trait_methods %}
     {%- call kt::uniffi_trait_impls(uniffi_trait_methods) %}{% endcall %}
 
     companion object
 }
-{% when Some(variant_discr_type) %}
-enum class {{ type_name }}(val value: {{ variant_discr_type|type_name(ci) }}) {
-    {% for variant in e.variants() -%}
+{% else %}
+enum class {{ type_name }}(val value: {{ e.discr_type.type_name }}) {
+    {% for variant in e.variants -%}
     {%- call kt::docstring(variant, 4) %}{% endcall %}
-    {{ variant|variant_name }}({{ e|variant_discr_literal(loop.index0) }}){% if loop.last %};{% else %},{% endif %}
+    {{ variant.name }}({{ variant.discr_literal }}){% if loop.last %};{% else %},{% endif %}
     {%- endfor %}
 
-    {% for meth in e.methods() -%}
+    {% for meth in e.methods -%}
     {%- call kt::func_decl("", meth, 4) %}{% endcall %}
     {% endfor %}
 
-    {%- let uniffi_trait_methods = e.uniffi_trait_methods() %}
+    {%- let uniffi_trait_methods = e.uniffi_trait_methods %}
     {%- call kt::uniffi_trait_impls(uniffi_trait_methods) %}{% endcall %}
 
     companion object
 }
-{% endmatch %}
+{% endif %}
 
 /**
  * @suppress
  */
-public object {{ e|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
+public object {{ e.self_type.ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
     override fun read(buf: ByteBuffer) = try {
-        {% if config.use_enum_entries() %}
+        {% if use_enum_entries %}
         {{ type_name }}.entries[buf.getInt() - 1]
         {% else -%}
         {{ type_name }}.values()[buf.getInt() - 1]
@@ -68,25 +70,25 @@ public object {{ e|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}
 
 {%- call kt::docstring(e, 0) %}{% endcall %}
 sealed class {{ type_name }}{% if contains_object_references %}: Disposable {% endif %}
-{%- let uniffi_trait_methods = e.uniffi_trait_methods() -%}
+{%- let uniffi_trait_methods = e.uniffi_trait_methods -%}
 {%- if uniffi_trait_methods.ord_cmp.is_some() -%}
 {% if contains_object_references %}, {% else %} : {% endif %}Comparable<{{ type_name }}>
 {%- endif %} {
-    {% for variant in e.variants() -%}
+    {% for variant in e.variants -%}
     {%- call kt::docstring(variant, 4) %}{% endcall %}
     {% if !variant.has_fields() -%}
-    object {{ variant|type_name(ci) }} : {{ type_name }}()
+    object {{ variant.name }} : {{ type_name }}()
     {% else -%}
-    data class {{ variant|type_name(ci) }}(
-        {%- for field in variant.fields() -%}
+    data class {{ variant.name }}(
+        {%- for field in variant.fields -%}
         {%- call kt::docstring(field, 8) %}{% endcall %}
-        val {% call kt::field_name(field, loop.index) %}{% endcall %}: {{ field|qualified_type_name(ci, config) }}
-        {%- if let Some(default) = field.default_value() %} = {{ default|render_default(field, ci) }} {% endif %}
+        val {% call kt::field_name(field, loop.index) %}{% endcall %}: {{ field.ty.qualified_type_name }}
+        {%- if let Some(default) = field.default %} = {{ default.kt_default }} {% endif %}
         {%- if loop.last %}{% else %}, {% endif %}
         {%- endfor -%}
     ) : {{ type_name }}()
         {# we need uniffi trait methods on each variant's data-class #}
-        {%- let uniffi_trait_methods = e.uniffi_trait_methods() %}
+        {%- let uniffi_trait_methods = e.uniffi_trait_methods %}
         {%- if uniffi_trait_methods.ord_cmp.is_some() %}
         , Comparable<{{ type_name }}>
         {%- endif %}
@@ -101,8 +103,8 @@ sealed class {{ type_name }}{% if contains_object_references %}: Disposable {% e
     @Suppress("UNNECESSARY_SAFE_CALL") // codegen is much simpler if we unconditionally emit safe calls here
     override fun destroy() {
         when(this) {
-            {%- for variant in e.variants() %}
-            is {{ type_name }}.{{ variant|type_name(ci) }} -> {
+            {%- for variant in e.variants %}
+            is {{ type_name }}.{{ variant.name }} -> {
                 {%- if variant.has_fields() %}
                 {% call kt::destroy_fields(variant) %}{% endcall %}
                 {% else -%}
@@ -116,7 +118,7 @@ sealed class {{ type_name }}{% if contains_object_references %}: Disposable {% e
 
     {# We also need to put methods on the object to support variants with no data.
        We could maybe optimize this so only write when no-data variants actually exist? #}
-    {% for meth in e.methods() -%}
+    {% for meth in e.methods -%}
     {%- call kt::func_decl("", meth, 4) %}{% endcall %}
     {% endfor %}
 
@@ -128,13 +130,13 @@ sealed class {{ type_name }}{% if contains_object_references %}: Disposable {% e
 /**
  * @suppress
  */
-public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>{
+public object {{ e.self_type.ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>{
     override fun read(buf: ByteBuffer): {{ type_name }} {
         return when(buf.getInt()) {
-            {%- for variant in e.variants() %}
-            {{ loop.index }} -> {{ type_name }}.{{ variant|type_name(ci) }}{% if variant.has_fields() %}(
-                {% for field in variant.fields() -%}
-                {{ field|read_fn }}(buf),
+            {%- for variant in e.variants %}
+            {{ loop.index }} -> {{ type_name }}.{{ variant.name }}{% if variant.has_fields() %}(
+                {% for field in variant.fields -%}
+                {{ field.ty.ffi_converter_name }}.read(buf),
                 {% endfor -%}
             ){%- endif -%}
             {%- endfor %}
@@ -143,13 +145,13 @@ public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }
     }
 
     override fun allocationSize(value: {{ type_name }}): ULong = when(value) {
-        {%- for variant in e.variants() %}
-        is {{ type_name }}.{{ variant|type_name(ci) }} -> {
+        {%- for variant in e.variants %}
+        is {{ type_name }}.{{ variant.name }} -> {
             // Add the size for the Int that specifies the variant plus the size needed for all fields
             (
                 4UL
-                {%- for field in variant.fields() %}
-                + {{ field|allocation_size_fn }}(value.{%- call kt::field_name(field, loop.index) %}{% endcall -%})
+                {%- for field in variant.fields %}
+                + {{ field.ty.ffi_converter_name }}.allocationSize(value.{%- call kt::field_name(field, loop.index) %}{% endcall -%})
                 {%- endfor %}
             )
         }
@@ -158,11 +160,11 @@ public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }
 
     override fun write(value: {{ type_name }}, buf: ByteBuffer) {
         when(value) {
-            {%- for variant in e.variants() %}
-            is {{ type_name }}.{{ variant|type_name(ci) }} -> {
+            {%- for variant in e.variants %}
+            is {{ type_name }}.{{ variant.name }} -> {
                 buf.putInt({{ loop.index }})
-                {%- for field in variant.fields() %}
-                {{ field|write_fn }}(value.{%- call kt::field_name(field, loop.index) %}{% endcall -%}, buf)
+                {%- for field in variant.fields %}
+                {{ field.ty.ffi_converter_name }}.write(value.{%- call kt::field_name(field, loop.index) %}{% endcall -%}, buf)
                 {%- endfor %}
                 Unit
             }
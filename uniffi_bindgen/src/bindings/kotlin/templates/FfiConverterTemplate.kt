@@ -36,6 +36,11 @@ public interface FfiConverter<KotlinType, FfiType> {
     // returns are always serialized into a `RustBuffer` regardless of their
     // normal FFI type.
     fun lowerIntoRustBuffer(value: KotlinType): RustBuffer.ByValue {
+        {%- if multiplatform %}
+        val bbuf = ByteBuffer.allocate(allocationSize(value).toInt())
+        write(value, bbuf)
+        return RustBuffer.fromBytes(bbuf.array(), bbuf.position())
+        {%- else %}
         val rbuf = RustBuffer.alloc(allocationSize(value))
         try {
             val bbuf = rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
@@ -48,6 +53,7 @@ public interface FfiConverter<KotlinType, FfiType> {
             RustBuffer.free(rbuf)
             throw e
         }
+        {%- endif %}
     }
 
     // Lift a value from a `RustBuffer`.
@@ -5,6 +5,26 @@ internal const val UNIFFI_CALL_SUCCESS = 0.toByte()
 internal const val UNIFFI_CALL_ERROR = 1.toByte()
 internal const val UNIFFI_CALL_UNEXPECTED_ERROR = 2.toByte()
 
+{% if multiplatform -%}
+// In multiplatform mode, this is a plain class.  The platform code copies it to and from the
+// struct that the FFI functions use.
+internal class UniffiRustCallStatus {
+    var code: Byte = 0
+    var error_buf: RustBuffer.ByValue = RustBuffer.ByValue()
+
+    fun isSuccess(): Boolean {
+        return code == UNIFFI_CALL_SUCCESS
+    }
+
+    fun isError(): Boolean {
+        return code == UNIFFI_CALL_ERROR
+    }
+
+    fun isPanic(): Boolean {
+        return code == UNIFFI_CALL_UNEXPECTED_ERROR
+    }
+}
+{%- else -%}
 @Structure.FieldOrder("code", "error_buf")
 internal open class UniffiRustCallStatus : Structure() {
     @JvmField var code: Byte = 0
@@ -33,6 +53,7 @@ internal open class UniffiRustCallStatus : Structure() {
         }
     }
 }
+{%- endif %}
 
 class InternalException(message: String) : kotlin.Exception(message)
 
@@ -68,7 +89,7 @@ private fun<E: kotlin.Exception> uniffiCheckCallStatus(errorHandler: UniffiRustC
         // with the message.  but if that code panics, then it just sends back
         // an empty buffer.
         if (status.error_buf.len > 0) {
-            throw InternalException({{ Type::String.borrow()|lift_fn }}(status.error_buf))
+            throw InternalException({{ builtin_types.string.ffi_converter_name }}.lift(status.error_buf))
         } else {
             throw InternalException("Rust panic")
         }
@@ -104,7 +125,7 @@ internal inline fun<T> uniffiTraitInterfaceCall(
     } catch(e: kotlin.Throwable) {
         val err = try { e.stackTraceToString() } catch(_: Throwable) { "" }
         callStatus.code = UNIFFI_CALL_UNEXPECTED_ERROR
-        callStatus.error_buf = {{ Type::String.borrow()|lower_fn }}(err)
+        callStatus.error_buf = {{ builtin_types.string.ffi_converter_name }}.lower(err)
     }
 }
 
@@ -123,7 +144,7 @@ internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallWithError(
         } else {
             val err = try { e.stackTraceToString() } catch(_: Throwable) { "" }
             callStatus.code = UNIFFI_CALL_UNEXPECTED_ERROR
-            callStatus.error_buf = {{ Type::String.borrow()|lower_fn }}(err)
+            callStatus.error_buf = {{ builtin_types.string.ffi_converter_name }}.lower(err)
         }
     }
 }
@@ -0,0 +1,25 @@
+/**
+ * @suppress
+ */
+public object FfiConverterInt128: FfiConverterRustBuffer<java.math.BigInteger> {
+    override fun read(buf: ByteBuffer): java.math.BigInteger {
+        val bytes = ByteArray(16)
+        buf.get(bytes)
+        return java.math.BigInteger(bytes)
+    }
+
+    override fun allocationSize(value: java.math.BigInteger) = 16UL
+
+    override fun write(value: java.math.BigInteger, buf: ByteBuffer) {
+        if (value.bitLength() > 127) {
+            throw IllegalArgumentException("i128 requires -2^127 <= value < 2^127")
+        }
+        // `toByteArray()` is the minimal two's-complement representation.  Sign-extend it to
+        // exactly 16 big-endian bytes.
+        val bytes = value.toByteArray()
+        val fill: Byte = if (value.signum() < 0) -1 else 0
+        val padded = ByteArray(16) { fill }
+        System.arraycopy(bytes, 0, padded, 16 - bytes.size, bytes.size)
+        buf.put(padded)
+    }
+}
@@ -1,13 +1,20 @@
 {%- call kt::docstring_value(interface_docstring, 0) %}{% endcall %}
 public interface {{ interface_name }} {
+    {% for prop in properties.iter() -%}
+    {%- call kt::docstring(prop, 4) %}{% endcall %}
+    {% if prop.setter.is_some() %}var{% else %}val{% endif %} {{ prop.name }}: {{ prop.ty.type_name }}
+    {% endfor %}
     {% for meth in methods.iter() -%}
+    {%- if meth.property.is_none() %}
+    {%- let callable = meth.callable %}
     {%- call kt::docstring(meth, 4) %}{% endcall %}
-    {% if meth.is_async() -%}suspend {% endif -%}
-    fun {{ meth.name()|fn_name }}({% call kt::arg_list(meth, true) %}{% endcall %})
-    {%- match meth.return_type() -%}
-    {%- when Some(return_type) %}: {{ return_type|type_name(ci) -}}
+    {% if callable.is_async() -%}suspend {% endif -%}
+    fun {{ callable.name }}({% call kt::arg_list(callable, true) %}{% endcall %})
+    {%- match callable.return_type.ty -%}
+    {%- when Some(return_type) %}: {{ return_type.type_name -}}
     {%- else -%}
     {%- endmatch %}
+    {%- endif %}
     {% endfor %}
     companion object
 }
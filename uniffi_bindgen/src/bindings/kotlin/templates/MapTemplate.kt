@@ -1,5 +1,8 @@
-{%- let key_type_name = key_type|type_name(ci) %}
-{%- let value_type_name = value_type|type_name(ci) %}
+{%- let key_type = map.key %}
+{%- let value_type = map.value %}
+{%- let key_type_name = key_type.type_name %}
+{%- let value_type_name = value_type.type_name %}
+{%- let ffi_converter_name = map.self_type.ffi_converter_name %}
 
 /**
  * @suppress
@@ -9,8 +12,8 @@ public object {{ ffi_converter_name }}: FfiConverterRustBuffer<Map<{{ key_type_n
         val len = buf.getInt()
         return buildMap<{{ key_type_name }}, {{ value_type_name }}>(len) {
             repeat(len) {
-                val k = {{ key_type|read_fn }}(buf)
-                val v = {{ value_type|read_fn }}(buf)
+                val k = {{ key_type.ffi_converter_name }}.read(buf)
+                val v = {{ value_type.ffi_converter_name }}.read(buf)
                 this[k] = v
             }
         }
@@ -19,8 +22,8 @@ public object {{ ffi_converter_name }}: FfiConverterRustBuffer<Map<{{ key_type_n
     override fun allocationSize(value: Map<{{ key_type_name }}, {{ value_type_name }}>): ULong {
         val spaceForMapSize = 4UL
         val spaceForChildren = value.map { (k, v) ->
-            {{ key_type|allocation_size_fn }}(k) +
-            {{ value_type|allocation_size_fn }}(v)
+            {{ key_type.ffi_converter_name }}.allocationSize(k) +
+            {{ value_type.ffi_converter_name }}.allocationSize(v)
         }.sum()
         return spaceForMapSize + spaceForChildren
     }
@@ -31,8 +34,8 @@ public object {{ ffi_converter_name }}: FfiConverterRustBuffer<Map<{{ key_type_n
         // which is important for compatibility with older android devices.
         // Ref https://blog.danlew.net/2017/03/16/kotlin-puzzler-whose-line-is-it-anyways/
         value.forEach { (k, v) ->
-            {{ key_type|write_fn }}(k, buf)
-            {{ value_type|write_fn }}(v, buf)
+            {{ key_type.ffi_converter_name }}.write(k, buf)
+            {{ value_type.ffi_converter_name }}.write(v, buf)
         }
     }
 }
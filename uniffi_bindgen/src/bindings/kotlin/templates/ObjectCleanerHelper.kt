@@ -19,30 +19,6 @@ interface UniffiCleaner {
     companion object
 }
 
-// The fallback Jna cleaner, which is available for both Android, and the JVM.
-private class UniffiJnaCleaner : UniffiCleaner {
-    private val cleaner = com.sun.jna.internal.Cleaner.getCleaner()
-
-    override fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable =
-        UniffiJnaCleanable(cleaner.register(value, cleanUpTask))
-}
-
-private class UniffiJnaCleanable(
-    private val cleanable: com.sun.jna.internal.Cleaner.Cleanable,
-) : UniffiCleaner.Cleanable {
-    override fun clean() = cleanable.clean()
-}
-
-{% if config.disable_java_cleaner() %}
-private fun UniffiCleaner.Companion.create(): UniffiCleaner = UniffiJnaCleaner()
-{% else %}
-// We decide at uniffi binding generation time whether we were
-// using Android or not.
-// There are further runtime checks to chose the correct implementation
-// of the cleaner.
-{% if config.android_cleaner() %}
-{%-   include "ObjectCleanerHelperAndroid.kt" %}
-{%- else %}
-{%-   include "ObjectCleanerHelperJvm.kt" %}
-{%- endif %}
+{% if !multiplatform -%}
+{% include "ObjectCleanerHelperJna.kt" %}
 {%- endif %}
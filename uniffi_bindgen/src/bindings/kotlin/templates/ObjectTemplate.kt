@@ -92,16 +92,16 @@
 // [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
 //
 
-{{ self.add_import("java.util.concurrent.atomic.AtomicBoolean") }}
 
-{%- let obj = ci.get_object_definition(name).unwrap() %}
-{%- let interface_name = self::object_interface_name(ci, obj) %}
-{%- let impl_class_name = self::object_impl_name(ci, obj) %}
-{%- let methods = obj.methods() %}
-{%- let uniffi_trait_methods = obj.uniffi_trait_methods() %}
-{%- let interface_docstring = obj.docstring() %}
-{%- let is_error = ci.is_name_used_as_error(name) %}
-{%- let ffi_converter_name = obj|ffi_converter_name %}
+{%- let type_name = obj.self_type.type_name %}
+{%- let interface_name = obj.interface_name %}
+{%- let impl_class_name = obj.impl_name %}
+{%- let methods = obj.methods %}
+{%- let properties = obj.properties %}
+{%- let uniffi_trait_methods = obj.uniffi_trait_methods %}
+{%- let interface_docstring = obj.docstring %}
+{%- let is_error = obj.self_type.is_used_as_error %}
+{%- let ffi_converter_name = obj.self_type.ffi_converter_name %}
 
 {%- include "Interface.kt" %}
 
@@ -110,8 +110,8 @@
 open class {{ impl_class_name }} : kotlin.Exception, Disposable, AutoCloseable, {{ interface_name }} {
 {% else -%}
 open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }}
-{%- for t in obj.trait_impls() %}
-, {{ self::trait_interface_name(ci, t.trait_ty)? }}
+{%- for trait_interface_name in obj.trait_interface_names %}
+, {{ trait_interface_name }}
 {% endfor %}
 {%- if uniffi_trait_methods.ord_cmp.is_some() %}
 , Comparable<{{ impl_class_name }}>
@@ -143,8 +143,8 @@ open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }
 
     {%- if let Some(cons) = obj.primary_constructor() %}
     {%- call kt::docstring(cons, 4) %}{% endcall %}
-    constructor({% call kt::arg_list(cons, true) -%}{% endcall -%}) :
-        this(UniffiWithHandle, {% call kt::to_ffi_call(cons) %}{% endcall %})
+    constructor({% call kt::arg_list(cons.callable, true) -%}{% endcall -%}) :
+        this(UniffiWithHandle, {% call kt::to_ffi_call(cons.callable) %}{% endcall %})
     {%- endif %}
 
     protected val handle: Long
@@ -168,8 +168,9 @@ open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }
             }
         }
     }
-
+{% if !multiplatform %}
     @Synchronized
+{%- endif %}
     override fun close() {
         this.destroy()
     }
@@ -180,10 +181,10 @@ open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }
         do {
             val c = this.callCounter.get()
             if (c == 0L) {
-                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+                throw IllegalStateException("${this{% if multiplatform %}::class{% else %}.javaClass{% endif %}.simpleName} object has already been destroyed")
             }
             if (c == Long.MAX_VALUE) {
-                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+                throw IllegalStateException("${this{% if multiplatform %}::class{% else %}.javaClass{% endif %}.simpleName} call counter would overflow")
             }
         } while (! this.callCounter.compareAndSet(c, c + 1L))
         // Now we can safely do the method call without the handle being freed concurrently.
@@ -206,7 +207,7 @@ open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }
                 return;
             }
             uniffiRustCall { status ->
-                UniffiLib.{{ obj.ffi_object_free().name() }}(handle, status)
+                UniffiLib.{{ obj.ffi_func_free.0 }}(handle, status)
             }
         }
     }
@@ -219,24 +220,25 @@ open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }
             throw InternalException("uniffiCloneHandle() called on NoHandle object");
         }
         return uniffiRustCall() { status ->
-            UniffiLib.{{ obj.ffi_object_clone().name() }}(handle, status)
+            UniffiLib.{{ obj.ffi_func_clone.0 }}(handle, status)
         }
     }
 
+    {% for prop in properties -%}
+    {%- call kt::property_decl("override", prop, 4) %}{% endcall %}
+    {% endfor %}
+
     {% for meth in methods -%}
+    {%- if meth.property.is_none() %}
     {%- call kt::func_decl("override", meth, 4) %}{% endcall %}
+    {%- endif %}
     {% endfor %}
 
     {% call kt::uniffi_trait_impls(uniffi_trait_methods) %}{% endcall %}
 
-    {# XXX - "companion object" confusion? How to have alternate constructors *and* be an error? #}
-    {% if !obj.alternate_constructors().is_empty() -%}
-    companion object {
-        {% for cons in obj.alternate_constructors() -%}
-        {% call kt::func_decl("", cons, 4) %}{% endcall %}
-        {% endfor %}
-    }
-    {% else if is_error %}
+    {#- Errors need the companion object to be their error handler, so it also contains the
+        alternate constructors and static methods #}
+    {% if is_error -%}
     companion object ErrorHandler : UniffiRustCallStatusErrorHandler<{{ impl_class_name }}> {
         override fun lift(error_buf: RustBuffer.ByValue): {{ impl_class_name }} {
             // Due to some mismatches in the ffi converter mechanisms, errors are a RustBuffer.
@@ -246,6 +248,21 @@ open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }
             }
             return {{ ffi_converter_name }}.read(bb)
         }
+        {% for cons in obj.alternate_constructors() -%}
+        {% call kt::func_decl("", cons, 4) %}{% endcall %}
+        {% endfor %}
+        {% for meth in obj.static_methods -%}
+        {% call kt::func_decl("", meth, 4) %}{% endcall %}
+        {% endfor %}
+    }
+    {% else if !obj.alternate_constructors().is_empty() || !obj.static_methods.is_empty() -%}
+    companion object {
+        {% for cons in obj.alternate_constructors() -%}
+        {% call kt::func_decl("", cons, 4) %}{% endcall %}
+        {% endfor %}
+        {% for meth in obj.static_methods -%}
+        {% call kt::func_decl("", meth, 4) %}{% endcall %}
+        {% endfor %}
     }
     {% else %}
     /**
@@ -286,10 +303,9 @@ public object {{ ffi_converter_name }}: FfiConverter<{{ type_name }}, Long> {
  # * Generate a callback interface implementation to handle the Kotlin side
  # * In the FfiConverter, check which side a handle came from to know how to handle correctly.
 #}
-{%- let vtable = obj.vtable().expect("trait interface should have a vtable") %}
-{%- let vtable_methods = obj.vtable_methods() %}
-{%- let ffi_init_callback = obj.ffi_init_callback() %}
+{%- if let Some(vtable) = obj.vtable %}
 {% include "CallbackInterfaceImpl.kt" %}
+{%- endif %}
 
 /**
  * @suppress
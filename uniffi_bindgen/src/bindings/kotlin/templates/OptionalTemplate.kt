@@ -1,30 +1,32 @@
-{%- let inner_type_name = inner_type|type_name(ci) %}
+{%- let inner_type = opt.inner %}
+{%- let type_name = opt.self_type.type_name %}
+{%- let ffi_converter_name = opt.self_type.ffi_converter_name %}
 
 /**
  * @suppress
  */
-public object {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ inner_type_name }}?> {
-    override fun read(buf: ByteBuffer): {{ inner_type_name }}? {
+public object {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
+    override fun read(buf: ByteBuffer): {{ type_name }} {
         if (buf.get().toInt() == 0) {
             return null
         }
-        return {{ inner_type|read_fn }}(buf)
+        return {{ inner_type.ffi_converter_name }}.read(buf)
     }
 
-    override fun allocationSize(value: {{ inner_type_name }}?): ULong {
+    override fun allocationSize(value: {{ type_name }}): ULong {
         if (value == null) {
             return 1UL
         } else {
-            return 1UL + {{ inner_type|allocation_size_fn }}(value)
+            return 1UL + {{ inner_type.ffi_converter_name }}.allocationSize(value)
         }
     }
 
-    override fun write(value: {{ inner_type_name }}?, buf: ByteBuffer) {
+    override fun write(value: {{ type_name }}, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
             buf.put(1)
-            {{ inner_type|write_fn }}(value, buf)
+            {{ inner_type.ffi_converter_name }}.write(value, buf)
         }
     }
 }
@@ -0,0 +1,39 @@
+/**
+ * @suppress
+ */
+public object FfiConverterOwnedBytes: FfiConverter<java.nio.ByteBuffer, RustBuffer.ByValue> {
+    // Wrap the Rust allocation in a direct `ByteBuffer` rather than copying it.
+    override fun lift(value: RustBuffer.ByValue): java.nio.ByteBuffer {
+        val data = value.data ?: return java.nio.ByteBuffer.allocateDirect(0)
+        val buffer = data.getByteBuffer(0, value.len)
+        // Copy the struct, the cleanup task can't hold on to anything that references `buffer`.
+        val rbuf = RustBuffer.create(value.capacity.toULong(), value.len.toULong(), data)
+        // The library cleaner frees the Rust allocation once the wrapping `ByteBuffer` is unreachable.
+        UniffiLib.CLEANER.register(buffer, Runnable { RustBuffer.free(rbuf) })
+        return buffer
+    }
+
+    // Rust takes ownership of the `RustBuffer`, so this only copies the bytes once.
+    override fun lower(value: java.nio.ByteBuffer): RustBuffer.ByValue {
+        val rbuf = RustBuffer.alloc(value.remaining().toULong())
+        rbuf.asByteBuffer()!!.put(value.duplicate())
+        return rbuf
+    }
+
+    // When nested inside another type, owned bytes are serialized the same way as `ByteArray`.
+    override fun read(buf: ByteBuffer): java.nio.ByteBuffer {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return java.nio.ByteBuffer.wrap(byteArr)
+    }
+
+    override fun allocationSize(value: java.nio.ByteBuffer): ULong {
+        return 4UL + value.remaining().toULong()
+    }
+
+    override fun write(value: java.nio.ByteBuffer, buf: ByteBuffer) {
+        buf.putInt(value.remaining())
+        buf.put(value.duplicate())
+    }
+}
@@ -25,7 +25,7 @@ open class RustBuffer : Structure() {
     companion object {
         internal fun alloc(size: ULong = 0UL) = uniffiRustCall() { status ->
             // Note: need to convert the size to a `Long` value to make this work with JVM.
-            UniffiLib.{{ ci.ffi_rustbuffer_alloc().name() }}(size.toLong(), status)
+            UniffiLib.{{ ffi_rustbuffer_alloc.0 }}(size.toLong(), status)
         }.also {
             if(it.data == null) {
                throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=${size})")
@@ -41,7 +41,7 @@ open class RustBuffer : Structure() {
         }
 
         internal fun free(buf: RustBuffer.ByValue) = uniffiRustCall() { status ->
-            UniffiLib.{{ ci.ffi_rustbuffer_free().name() }}(buf, status)
+            UniffiLib.{{ ffi_rustbuffer_free.0 }}(buf, status)
         }
     }
 
@@ -0,0 +1,47 @@
+{%- let inner_type = stream.inner %}
+{%- let inner_type_name = inner_type.type_name %}
+{%- let ffi_converter_name = stream.self_type.ffi_converter_name %}
+
+/**
+ * @suppress
+ */
+public object {{ ffi_converter_name }}: FfiConverter<kotlinx.coroutines.flow.Flow<{{ inner_type_name }}>, Long> {
+    override fun lift(value: Long): kotlinx.coroutines.flow.Flow<{{ inner_type_name }}> {
+        return uniffiRustStreamFlow(
+            value,
+            { handle -> UniffiLib.{{ stream.ffi_stream_next.0 }}(handle) },
+            { handle -> UniffiLib.{{ stream.ffi_stream_free.0 }}(handle) },
+            { future, callback, continuation -> UniffiLib.{{ stream.async_data.ffi_rust_future_poll.0 }}(future, callback, continuation) },
+            { future, continuation -> UniffiLib.{{ stream.async_data.ffi_rust_future_complete.0 }}(future, continuation) },
+            { future -> UniffiLib.{{ stream.async_data.ffi_rust_future_free.0 }}(future) },
+            { rbuf -> liftItem(rbuf) },
+        )
+    }
+
+    // Items are sent as an `Option<T>`, where `None` signals the end of the stream.
+    private fun liftItem(rbuf: RustBuffer.ByValue): UniffiStreamItem<{{ inner_type_name }}>? {
+        val buf = rbuf.asByteBuffer()!!
+        try {
+            if (buf.get().toInt() == 0) {
+                return null
+            }
+            return UniffiStreamItem({{ inner_type.ffi_converter_name }}.read(buf))
+        } finally {
+            RustBuffer.free(rbuf)
+        }
+    }
+
+    override fun lower(value: kotlinx.coroutines.flow.Flow<{{ inner_type_name }}>): Long {
+        throw UnsupportedOperationException("Streams can't be passed to Rust")
+    }
+
+    override fun read(buf: ByteBuffer): kotlinx.coroutines.flow.Flow<{{ inner_type_name }}> {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: kotlinx.coroutines.flow.Flow<{{ inner_type_name }}>) = 8UL
+
+    override fun write(value: kotlinx.coroutines.flow.Flow<{{ inner_type_name }}>, buf: ByteBuffer) {
+        throw UnsupportedOperationException("Streams can't be passed to Rust")
+    }
+}
@@ -76,30 +76,39 @@ object UniffiWithHandle
  * */
 object NoHandle
 
-{%- if ci.has_callback_definitions() %}
+{%- if has_callback_interface %}
 {%- include "CallbackInterfaceRuntime.kt" %}
 {%- endif %}
 
-{%- if ci.has_object_definitions() %}
+{%- if has_interfaces %}
 {%- include "ObjectCleanerHelper.kt" %}
 {%- endif %}
 
-{%- for type_ in ci.iter_local_types() %}
-{%- let type_name = type_|type_name(ci) %}
-{%- let ffi_converter_name = type_|ffi_converter_name %}
-{%- let canonical_type_name = type_|canonical_name %}
-{%- let contains_object_references = ci.item_contains_object_references(type_) %}
+{%- if let Some(closure_vtable) = closure_vtable %}
+{%- include "ClosureRuntime.kt" %}
+{%- endif %}
+
+{%- for tuple_class in tuple_classes %}
+
+/**
+ * A tuple of {{ tuple_class.type_params.len() }} values
+ */
+public data class {{ tuple_class.name }}<{{ tuple_class.type_params|join(", ") }}>(
+    {%- for property_name in tuple_class.property_names %}
+    val {{ property_name }}: {{ tuple_class.type_params[loop.index0] }},
+    {%- endfor %}
+)
+{%- endfor %}
+
+{%- for type_def in type_definitions %}
 
 {#
- # Map `Type` instances to an include statement for that type.
- #
- # There is a companion match in `KotlinCodeOracle::create_code_type()` which performs a similar function for the
- # Rust code.
- #
- #   - When adding additional types here, make sure to also add a match arm to that function.
- #   - To keep things manageable, let's try to limit ourselves to these 2 mega-matches
+ # Map `TypeDefinition` instances to an include statement for that type.
  #}
-{%- match type_ %}
+{%- match type_def %}
+
+{%- when TypeDefinition::Simple(type_node) %}
+{%- match type_node.ty %}
 
 {%- when Type::Boolean %}
 {%- include "BooleanHelper.kt" %}
@@ -116,6 +125,9 @@ object NoHandle
 {%- when Type::Int64 %}
 {%- include "Int64Helper.kt" %}
 
+{%- when Type::Int128 %}
+{%- include "Int128Helper.kt" %}
+
 {%- when Type::UInt8 %}
 {%- include "UInt8Helper.kt" %}
 
@@ -128,6 +140,9 @@ object NoHandle
 {%- when Type::UInt64 %}
 {%- include "UInt64Helper.kt" %}
 
+{%- when Type::UInt128 %}
+{%- include "UInt128Helper.kt" %}
+
 {%- when Type::Float32 %}
 {%- include "Float32Helper.kt" %}
 
@@ -135,70 +150,83 @@ object NoHandle
 {%- include "Float64Helper.kt" %}
 
 {%- when Type::String %}
+{%- if multiplatform %}
+{%- include "multiplatform/StringHelper.kt" %}
+{%- else %}
 {%- include "StringHelper.kt" %}
+{%- endif %}
 
 {%- when Type::Bytes %}
 {%- include "ByteArrayHelper.kt" %}
 
-{%- when Type::Enum { name, module_path } %}
-{%- let e = ci.get_enum_definition(name).unwrap() %}
-{%- if !ci.is_name_used_as_error(name) %}
+{%- when Type::OwnedBytes %}
+{%- include "OwnedBytesHelper.kt" %}
+
+{%- when Type::Timestamp %}
+{% include "TimestampHelper.kt" %}
+
+{%- when Type::Duration %}
+{% include "DurationHelper.kt" %}
+
+{%- when Type::Uuid %}
+{% include "UuidHelper.kt" %}
+
+{%- when Type::Url %}
+{% include "UrlHelper.kt" %}
+
+{%- when Type::Date %}
+{% include "DateHelper.kt" %}
+
+{%- else %}
+{# Type::Simple shouldn't hold any other Type variants #}
+{%- endmatch %}
+
+{%- when TypeDefinition::Enum(e) %}
+{%- if !e.self_type.is_used_as_error %}
 {% include "EnumTemplate.kt" %}
 {%- else %}
 {% include "ErrorTemplate.kt" %}
 {%- endif -%}
 
-{%- when Type::Object { module_path, name, .. } %}
+{%- when TypeDefinition::Interface(obj) %}
 {% include "ObjectTemplate.kt" %}
 
-{%- when Type::Record { name, module_path } %}
+{%- when TypeDefinition::Record(rec) %}
 {% include "RecordTemplate.kt" %}
 
-{%- when Type::Optional { inner_type } %}
+{%- when TypeDefinition::Optional(opt) %}
 {% include "OptionalTemplate.kt" %}
 
-{%- when Type::Sequence { inner_type } %}
+{%- when TypeDefinition::Sequence(seq) %}
 {% include "SequenceTemplate.kt" %}
 
-{%- when Type::Map { key_type, value_type } %}
+{%- when TypeDefinition::Map(map) %}
 {% include "MapTemplate.kt" %}
 
-{%- when Type::Set { inner_type } %}
+{%- when TypeDefinition::Set(set) %}
 {% include "SetTemplate.kt" %}
 
-{%- when Type::CallbackInterface { module_path, name } %}
-{% include "CallbackInterfaceTemplate.kt" %}
+{%- when TypeDefinition::Stream(stream) %}
+{% include "StreamTemplate.kt" %}
 
-{%- when Type::Timestamp %}
-{% include "TimestampHelper.kt" %}
+{%- when TypeDefinition::Tuple(tuple) %}
+{% include "TupleTemplate.kt" %}
 
-{%- when Type::Duration %}
-{% include "DurationHelper.kt" %}
+{%- when TypeDefinition::Array(array) %}
+{% include "ArrayTemplate.kt" %}
 
-{%- when Type::Custom { module_path, name, builtin } %}
-{%- if ci.is_external(type_) %}
-{% include "ExternalTypeTemplate.kt" %}
-{%- else %}
+{%- when TypeDefinition::Closure(closure) %}
+{% include "ClosureTemplate.kt" %}
+
+{%- when TypeDefinition::CallbackInterface(cbi) %}
+{% include "CallbackInterfaceTemplate.kt" %}
+
+{%- when TypeDefinition::Custom(custom) %}
 {% include "CustomTypeTemplate.kt" %}
-{%- endif %}
+
+{%- when TypeDefinition::External(ext) %}
+{% include "ExternalTypeTemplate.kt" %}
 
 {%- else %}
 {%- endmatch %}
 {%- endfor %}
-
-{%- for type_ in ci.iter_external_types() %}
-{%- let name = type_.name().unwrap() %}
-{%- let module_path = type_.module_path().unwrap() %}
-{% include "ExternalTypeTemplate.kt" %}
-{%- endfor %}
-
-{%- if ci.has_async_fns() %}
-{# Import types needed for async support #}
-{{ self.add_import("kotlin.coroutines.resume") }}
-{{ self.add_import("kotlinx.coroutines.launch") }}
-{{ self.add_import("kotlinx.coroutines.suspendCancellableCoroutine") }}
-{{ self.add_import("kotlinx.coroutines.CancellableContinuation") }}
-{{ self.add_import("kotlinx.coroutines.DelicateCoroutinesApi") }}
-{{ self.add_import("kotlinx.coroutines.Job") }}
-{{ self.add_import("kotlinx.coroutines.GlobalScope") }}
-{%- endif %}
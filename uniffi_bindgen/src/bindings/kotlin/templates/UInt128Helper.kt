@@ -0,0 +1,27 @@
+/**
+ * @suppress
+ */
+public object FfiConverterUInt128: FfiConverterRustBuffer<java.math.BigInteger> {
+    private val MAX_VALUE = java.math.BigInteger.ONE.shiftLeft(128)
+
+    override fun read(buf: ByteBuffer): java.math.BigInteger {
+        val bytes = ByteArray(16)
+        buf.get(bytes)
+        return java.math.BigInteger(1, bytes)
+    }
+
+    override fun allocationSize(value: java.math.BigInteger) = 16UL
+
+    override fun write(value: java.math.BigInteger, buf: ByteBuffer) {
+        if (value.signum() < 0 || value >= MAX_VALUE) {
+            throw IllegalArgumentException("u128 requires 0 <= value < 2^128")
+        }
+        // `toByteArray()` is the minimal two's-complement representation, which may have a
+        // leading sign byte.  Left-pad (or trim) to exactly 16 big-endian bytes.
+        val bytes = value.toByteArray()
+        val padded = ByteArray(16)
+        val len = minOf(bytes.size, 16)
+        System.arraycopy(bytes, bytes.size - len, padded, 16 - len, len)
+        buf.put(padded)
+    }
+}
@@ -0,0 +1,20 @@
+/**
+ * @suppress
+ */
+public object FfiConverterUrl: FfiConverterRustBuffer<java.net.URI> {
+    override fun read(buf: ByteBuffer): java.net.URI {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return java.net.URI(byteArr.toString(Charsets.UTF_8))
+    }
+
+    // URIs only contain ASCII characters, so this is the exact size
+    override fun allocationSize(value: java.net.URI) = 4UL + value.toASCIIString().length.toULong()
+
+    override fun write(value: java.net.URI, buf: ByteBuffer) {
+        val byteArr = value.toASCIIString().toByteArray(Charsets.UTF_8)
+        buf.putInt(byteArr.size)
+        buf.put(byteArr)
+    }
+}
@@ -0,0 +1,17 @@
+/**
+ * @suppress
+ */
+public object FfiConverterUuid: FfiConverterRustBuffer<java.util.UUID> {
+    override fun read(buf: ByteBuffer): java.util.UUID {
+        val mostSigBits = buf.getLong()
+        val leastSigBits = buf.getLong()
+        return java.util.UUID(mostSigBits, leastSigBits)
+    }
+
+    override fun allocationSize(value: java.util.UUID) = 16UL
+
+    override fun write(value: java.util.UUID, buf: ByteBuffer) {
+        buf.putLong(value.mostSignificantBits)
+        buf.putLong(value.leastSignificantBits)
+    }
+}
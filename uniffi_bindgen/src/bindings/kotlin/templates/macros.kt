@@ -5,33 +5,34 @@
 #}
 
 {%- macro to_ffi_call(func) -%}
-    {%- match func.self_type() %}
-    {%- when Some(Type::Object { .. }) %}
+    {%- if func.is_interface_method() %}
     callWithHandle {
         {%- call to_raw_ffi_call(func) %}{% endcall %}
     }
     {% else %}
         {%- call to_raw_ffi_call(func) %}{% endcall %}
-    {% endmatch %}
+    {% endif %}
 {%- endmacro %}
 
 {%- macro to_raw_ffi_call(func) -%}
-    {%- match func.throws_type() %}
+    {%- match func.throws_type.ty %}
     {%- when Some(e) %}
-    {%- if ci.is_external(e) %}
-    uniffiRustCallWithError({{ e|type_name(ci) }}ExternalErrorHandler)
+    {%- if e.is_external %}
+    uniffiRustCallWithError({{ e.type_name }}ExternalErrorHandler)
     {%- else %}
-    uniffiRustCallWithError({{ e|type_name(ci) }})
+    uniffiRustCallWithError({{ e.type_name }})
     {%- endif %}
     {%- else %}
     uniffiRustCall()
     {%- endmatch %} { _status ->
-    UniffiLib.{{ func.ffi_func().name() }}(
+    UniffiLib.{{ func.ffi_func.0 }}(
     {%- match func.self_type() %}
-    {%- when Some(Type::Object { .. }) %}
-        it,
     {%- when Some(t) %}
-        {{- t|lower_fn }}(this),
+    {%- if func.is_interface_method() %}
+        it,
+    {%- else %}
+        {{- t.ffi_converter_name }}.lower(this),
+    {%- endif %}
     {%- when None %}
     {% endmatch %}
         {% call arg_list_lowered(func) %}{% endcall -%}
@@ -39,28 +40,29 @@
 }
 {%- endmacro -%}
 
-{%- macro func_decl(func_decl, callable, indent) %}
-    {%- call docstring(callable, indent) %}{% endcall %}
+{%- macro func_decl(func_decl, func, indent) %}
+    {%- call docstring(func, indent) %}{% endcall %}
+    {%- let callable = func.callable %}
 
-    {%- match callable.throws_type() -%}
+    {%- match callable.throws_type.ty -%}
     {%-     when Some(throwable) %}
-    @Throws({{ throwable|type_name(ci) }}::class)
+    @Throws({{ throwable.type_name }}::class)
     {%-     else -%}
     {%- endmatch -%}
     {%- if callable.is_async() %}
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    {{ func_decl }} suspend fun {{ callable.name()|fn_name }}(
+    {{ func_decl }} suspend fun {{ callable.name }}(
         {%- call arg_list(callable, callable.self_type().is_none()) %}{% endcall -%}
-    ){% match callable.return_type() %}{% when Some(return_type) %} : {{ return_type|type_name(ci) }}{% when None %}{%- endmatch %} {
+    ){% match callable.return_type.ty %}{% when Some(return_type) %} : {{ return_type.type_name }}{% when None %}{%- endmatch %} {
         return {% call call_async(callable) %}{% endcall %}
     }
     {%- else -%}
-    {{ func_decl }} fun {{ callable.name()|fn_name }}(
+    {{ func_decl }} fun {{ callable.name }}(
         {%- call arg_list(callable, callable.self_type().is_none()) %}{% endcall -%}
-    ){%- match callable.return_type() -%}
+    ){%- match callable.return_type.ty -%}
     {%-         when Some(return_type) -%}
-        : {{ return_type|type_name(ci) }} {
-            return {{ return_type|lift_fn }}({% call to_ffi_call(callable) %}{% endcall %})
+        : {{ return_type.type_name }} {
+            return {{ return_type.ffi_converter_name }}.lift({% call to_ffi_call(callable) %}{% endcall %})
     }
     {%-         when None %}
         = {% call to_ffi_call(callable) %}{% endcall %}
@@ -68,42 +70,72 @@
     {% endif %}
 {% endmacro %}
 
+{%- macro property_decl(property_decl, prop, indent) %}
+    {%- call docstring(prop, indent) %}{% endcall %}
+    {%- let getter = prop.getter.callable %}
+    {%- match prop.setter %}
+    {%- when Some(setter) %}
+    {{ property_decl }} var {{ prop.name }}: {{ prop.ty.type_name }}
+        get() = {{ prop.ty.ffi_converter_name }}.lift({% call to_ffi_call(getter) %}{% endcall %})
+        {%- let setter = setter.callable %}
+        set({% for arg in setter.arguments %}{{ arg.name }}{% endfor %}) {
+            {% call to_ffi_call(setter) %}{% endcall %}
+        }
+    {%- when None %}
+    {{ property_decl }} val {{ prop.name }}: {{ prop.ty.type_name }}
+        get() = {{ prop.ty.ffi_converter_name }}.lift({% call to_ffi_call(getter) %}{% endcall %})
+    {%- endmatch %}
+{% endmacro %}
+
 {%- macro call_async(callable) -%}
     uniffiRustCallAsync(
 
+{%- let async_data = callable.async_data.as_ref().unwrap() %}
 {%- match callable.self_type() %}
-{%- when Some(Type::Object { .. }) %}
+{%- when Some(t) %}
+{%- if callable.is_interface_method() %}
         callWithHandle { uniffiHandle ->
-            UniffiLib.{{ callable.ffi_func().name() }}(
+            UniffiLib.{{ callable.ffi_func.0 }}(
                 uniffiHandle,
                 {% call arg_list_lowered(callable) %}{% endcall %}
             )
         },
-{%- when Some(t) %}
-        UniffiLib.{{ callable.ffi_func().name() }}(
-            {{- t|lower_fn }}(this),
+{%- else %}
+        UniffiLib.{{ callable.ffi_func.0 }}(
+            {{- t.ffi_converter_name }}.lower(this),
             {% call arg_list_lowered(callable) %}{% endcall %}
         ),
+{%- endif %}
 {%- else %}
-        UniffiLib.{{ callable.ffi_func().name() }}({% call arg_list_lowered(callable) %}{% endcall %}),
+        UniffiLib.{{ callable.ffi_func.0 }}({% call arg_list_lowered(callable) %}{% endcall %}),
 {%- endmatch %}
-        {{ callable|async_poll(ci) }},
-        {{ callable|async_complete(ci) }},
-        {{ callable|async_free(ci) }},
+        { future, callback, continuation -> UniffiLib.{{ async_data.ffi_rust_future_poll.0 }}(future, callback, continuation) },
+        {%- match callable.return_type.ty %}
+        {%- when Some(return_type) %}
+        {%- match return_type.ffi_type.external_rust_buffer_name %}
+        {%- when Some(rust_buffer_name) %}
+        { future, continuation -> UniffiLib.{{ async_data.ffi_rust_future_complete.0 }}(future, continuation).let { {{ rust_buffer_name }}.create(it.capacity.toULong(), it.len.toULong(), it.data) } },
+        {%- when None %}
+        { future, continuation -> UniffiLib.{{ async_data.ffi_rust_future_complete.0 }}(future, continuation) },
+        {%- endmatch %}
+        {%- when None %}
+        { future, continuation -> UniffiLib.{{ async_data.ffi_rust_future_complete.0 }}(future, continuation) },
+        {%- endmatch %}
+        { future -> UniffiLib.{{ async_data.ffi_rust_future_free.0 }}(future) },
         // lift function
-        {%- match callable.return_type() %}
+        {%- match callable.return_type.ty %}
         {%- when Some(return_type) %}
-        { {{ return_type|lift_fn }}(it) },
+        { {{ return_type.ffi_converter_name }}.lift(it) },
         {%- when None %}
         { },
         {% endmatch %}
         // Error FFI converter
-        {%- match callable.throws_type() %}
+        {%- match callable.throws_type.ty %}
         {%- when Some(e) %}
-        {%- if ci.is_external(e) %}
-        {{ e|type_name(ci) }}ExternalErrorHandler,
+        {%- if e.is_external %}
+        {{ e.type_name }}ExternalErrorHandler,
         {%- else %}
-        {{ e|type_name(ci) }}.ErrorHandler,
+        {{ e.type_name }}.ErrorHandler,
         {%- endif %}
         {%- when None %}
         UniffiNullRustCallStatusErrorHandler,
@@ -112,8 +144,8 @@
 {%- endmacro %}
 
 {%- macro arg_list_lowered(func) %}
-    {%- for arg in func.arguments() %}
-        {{ arg|lower_fn_for_arg }}({{ arg.name()|var_name }}),
+    {%- for arg in func.arguments %}
+        {{ arg.lower_fn }}({{ arg.name }}),
     {%- endfor %}
 {%- endmacro -%}
 
@@ -124,11 +156,11 @@
 -#}
 
 {% macro arg_list(func, is_decl) %}
-{%- for arg in func.arguments() -%}
-        {{ arg.name()|var_name }}: {{ arg|lower_type_name_for_arg(ci) }}
+{%- for arg in func.arguments -%}
+        {{ arg.name }}: {{ arg.type_name }}
 {%-     if is_decl %}
-{%-         match arg.default_value() %}
-{%-             when Some(default) %} = {{ default|render_default(arg, ci) }}
+{%-         match arg.default %}
+{%-             when Some(default) %} = {{ default.kt_default }}
 {%-             else %}
 {%-         endmatch %}
 {%-     endif %}
@@ -141,32 +173,44 @@
 // Note unfiltered name but ffi_type_name filters.
 -#}
 {%- macro arg_list_ffi_decl(func) %}
-    {%- for arg in func.arguments() %}
-        {{- arg.name()|var_name }}: {{ arg.type_().borrow()|ffi_type_name_by_value(ci) -}},
+    {%- for arg in func.arguments %}
+        {{- arg.name }}: {{ arg.ty.type_name -}},
     {%- endfor %}
-    {%- if func.has_rust_call_status_arg() %}uniffi_out_err: UniffiRustCallStatus, {% endif %}
+    {%- if func.has_rust_call_status_arg %}uniffi_out_err: UniffiRustCallStatus, {% endif %}
 {%- endmacro -%}
 
+{#- FFI function signature for the multiplatform `expect`/`actual` declarations #}
+{%- macro ffi_fn_signature(func) -%}
+fun {{ func.name.0 }}(
+{%- for arg in func.arguments %}
+    {{ arg.name }}: {{ arg.ty.type_name }},
+{%- endfor %}
+{%- if func.has_rust_call_status_arg %}
+    uniffi_out_err: UniffiRustCallStatus,
+{%- endif %}
+): {% match func.return_type.ty %}{% when Some(return_type) %}{{ return_type.direct_return_type_name }}{% when None %}Unit{% endmatch %}
+{%- endmacro %}
+
 {% macro field_name(field, field_num) %}
-{%- if field.name().is_empty() -%}
+{%- if field.name.is_empty() -%}
 v{{- field_num -}}
 {%- else -%}
-{{ field.name()|var_name }}
+{{ field.name }}
 {%- endif -%}
 {%- endmacro %}
 
 {% macro field_name_unquoted(field, field_num) %}
-{%- if field.name().is_empty() -%}
+{%- if field.name.is_empty() -%}
 v{{- field_num -}}
 {%- else -%}
-{{ field.name()|var_name|unquote }}
+{{ field.name|unquote }}
 {%- endif -%}
 {%- endmacro %}
 
  // Macro for destroying fields
 {%- macro destroy_fields(member) %}
     Disposable.destroy(
-    {%- for field in member.fields() %}
+    {%- for field in member.fields %}
         this.{%- call field_name(field, loop.index) %}{% endcall -%}{% if loop.last %}{% else %},{% endif -%}
     {%- endfor %}
     )
@@ -181,35 +225,35 @@ v{{- field_num -}}
 {%- endmacro %}
 
 {%- macro docstring(defn, indent_spaces) %}
-{%- call docstring_value(defn.docstring(), indent_spaces) %}{% endcall %}
+{%- call docstring_value(defn.docstring, indent_spaces) %}{% endcall %}
 {%- endmacro %}
 
 // macro for uniffi_trait implementations.
 {% macro uniffi_trait_impls(uniffi_trait_methods) %}
 {# We have 2 display traits, kotlin has 1. Prefer `Display` but use `Debug` otherwise #}
-{%- if let Some(fmt) = uniffi_trait_methods.display_fmt.or(uniffi_trait_methods.debug_fmt.clone()) %}
+{%- if let Some(fmt) = uniffi_trait_methods.display_fmt.clone().or(uniffi_trait_methods.debug_fmt.clone()) %}
     // The local Rust `Display`/`Debug` implementation.
     override fun toString(): String {
-        return {{ fmt.return_type().unwrap()|lift_fn }}({% call to_ffi_call(fmt) %}{% endcall %})
+        return {{ fmt.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({% call to_ffi_call(fmt.callable) %}{% endcall %})
     }
 {%- endif %}
 {%- if let Some(eq) = uniffi_trait_methods.eq_eq %}
     // The local Rust `Eq` implementation - only `eq` is used.
     override fun equals(other: Any?): Boolean {
-        if (other !is {{ eq.object_name()|class_name(ci) }}) return false
-        return {{ eq.return_type().unwrap()|lift_fn }}({% call to_ffi_call(eq) %}{% endcall %})
+        if (other !is {{ eq.callable.self_type().unwrap().type_name }}) return false
+        return {{ eq.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({% call to_ffi_call(eq.callable) %}{% endcall %})
     }
 {%- endif %}
 {%- if let Some(hash) = uniffi_trait_methods.hash_hash %}
     // The local Rust `Hash` implementation
     override fun hashCode(): Int {
-        return {{ hash.return_type().unwrap()|lift_fn }}({%- call to_ffi_call(hash) %}{% endcall %}).toInt()
+        return {{ hash.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({%- call to_ffi_call(hash.callable) %}{% endcall %}).toInt()
     }
 {%- endif %}
 {%- if let Some(cmp) = uniffi_trait_methods.ord_cmp %}
     // The local Rust `Ord` implementation
-    override fun compareTo(other: {{ cmp.object_name()|class_name(ci) }}): Int {
-        return {{ cmp.return_type().unwrap()|lift_fn }}({%- call to_ffi_call(cmp) %}{% endcall %}).toInt()
+    override fun compareTo(other: {{ cmp.callable.self_type().unwrap().type_name }}): Int {
+        return {{ cmp.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({%- call to_ffi_call(cmp.callable) %}{% endcall %}).toInt()
     }
 {%- endif %}
 {%- endmacro %}
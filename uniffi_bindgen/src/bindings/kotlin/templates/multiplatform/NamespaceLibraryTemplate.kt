@@ -0,0 +1,63 @@
+// The FFI functions are declared here and implemented with JNA in `jvmMain`.
+//
+// Like the JVM bindings, the checksum functions are kept in `IntegrityCheckingUniffiLib`
+// and everything else is in `UniffiLib`.  Each object runs the checks or initialization
+// functions when it's initialized.
+internal expect object IntegrityCheckingUniffiLib {
+    fun ensureInitialized()
+    {%- for func in multiplatform_ffi_functions() %}
+    {%- if func.is_integrity_check() %}
+
+    {% filter indent(4) %}{% call kt::ffi_fn_signature(func) %}{% endcall %}{% endfilter %}
+    {%- endif %}
+    {%- endfor %}
+}
+
+internal expect object UniffiLib {
+    {%- if has_interfaces %}
+    // The Cleaner for the whole library
+    val CLEANER: UniffiCleaner
+    {%- endif %}
+
+    fun ensureInitialized()
+    {%- for func in multiplatform_ffi_functions() %}
+    {%- if !func.is_integrity_check() %}
+
+    {% filter indent(4) %}{% call kt::ffi_fn_signature(func) %}{% endcall %}{% endfilter %}
+    {%- endif %}
+    {%- endfor %}
+}
+
+internal fun uniffiCheckContractApiVersion(lib: IntegrityCheckingUniffiLib) {
+    // Get the bindings contract version from our ComponentInterface
+    val bindings_contract_version = {{ correct_contract_version }}
+    // Get the scaffolding contract version by calling the into the dylib
+    val scaffolding_contract_version = lib.{{ ffi_uniffi_contract_version.0 }}()
+    if (bindings_contract_version != scaffolding_contract_version) {
+        throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
+    }
+}
+
+{%- if !omit_checksums %}
+@Suppress("UNUSED_PARAMETER")
+internal fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
+    {%- for checksum in checksums %}
+    {#- please don't delete the mask: https://github.com/mozilla/uniffi-rs/pull/2935 #}
+    if ((lib.{{ checksum.fn_name.0 }}() and 0xFFFF) != {{ checksum.checksum }}) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    {%- endfor %}
+}
+{%- endif %}
+
+/**
+ * @suppress
+ */
+public fun uniffiEnsureInitialized() {
+    // Call arbitrary methods on IntegrityCheckingUniffiLib and UniffiLib to ensure that
+    // their init blocks run. This ensures initialization across crates works as expected.
+    IntegrityCheckingUniffiLib.ensureInitialized()
+    UniffiLib.ensureInitialized()
+}
+
+{%- import "macros.kt" as kt %}
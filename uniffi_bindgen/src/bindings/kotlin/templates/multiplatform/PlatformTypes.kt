@@ -0,0 +1,28 @@
+// Platform types that the common code depends on.
+//
+// On the JVM and Android these are backed by the `java.lang` and `java.util.concurrent` classes
+// that the non-multiplatform bindings use directly.
+
+/**
+ * Public, since it's part of the `UniffiCleaner` interface
+ *
+ * @suppress
+ */
+expect fun interface Runnable {
+    fun run()
+}
+
+internal expect class AtomicBoolean(initialValue: Boolean) {
+    fun get(): Boolean
+    fun compareAndSet(expect: Boolean, update: Boolean): Boolean
+}
+
+internal expect class AtomicLong(initialValue: Long) {
+    fun get(): Long
+    fun compareAndSet(expect: Long, update: Long): Boolean
+    fun decrementAndGet(): Long
+}
+
+// Copy bytes out of/into Rust-owned memory.  `data` is the address of the memory.
+internal expect fun uniffiReadBytes(data: Long, len: Int): ByteArray
+internal expect fun uniffiWriteBytes(data: Long, bytes: ByteArray, len: Int)
@@ -0,0 +1,134 @@
+// This is a helper for safely working with byte buffers returned from the Rust code.
+// A rust-owned buffer is represented by its capacity, its current length, and a
+// pointer to the underlying data.
+//
+// In multiplatform mode, the pointer is stored as an address and the platform code converts
+// the buffer to and from the struct that the FFI functions use.
+
+/**
+ * @suppress
+ */
+open class RustBuffer(
+    // Note: `capacity` and `len` are actually `ULong` values, but we store them as signed values
+    // to match the JVM bindings.  When dealing with these fields, make sure to call `toULong()`.
+    var capacity: Long = 0,
+    var len: Long = 0,
+    var data: Long = 0,
+) {
+    class ByValue(capacity: Long = 0, len: Long = 0, data: Long = 0): RustBuffer(capacity, len, data)
+
+    companion object {
+        internal fun alloc(size: ULong = 0UL) = uniffiRustCall() { status ->
+            UniffiLib.{{ ffi_rustbuffer_alloc.0 }}(size.toLong(), status)
+        }.also {
+            if(it.data == 0L) {
+               throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=${size})")
+           }
+        }
+
+        internal fun create(capacity: ULong, len: ULong, data: Long): RustBuffer.ByValue {
+            return RustBuffer.ByValue(capacity.toLong(), len.toLong(), data)
+        }
+
+        internal fun free(buf: RustBuffer.ByValue) = uniffiRustCall() { status ->
+            UniffiLib.{{ ffi_rustbuffer_free.0 }}(buf, status)
+        }
+
+        // Allocate a `RustBuffer` and copy the first `len` bytes of `bytes` into it.
+        internal fun fromBytes(bytes: ByteArray, len: Int): RustBuffer.ByValue {
+            val rbuf = alloc(len.toULong())
+            try {
+                if (len > 0) {
+                    uniffiWriteBytes(rbuf.data, bytes, len)
+                }
+                rbuf.len = len.toLong()
+                return rbuf
+            } catch (e: Throwable) {
+                free(rbuf)
+                throw e
+            }
+        }
+    }
+
+    internal fun toByteArray(): ByteArray =
+        if (this.len == 0L) ByteArray(0) else uniffiReadBytes(this.data, this.len.toInt())
+
+    fun asByteBuffer(): ByteBuffer? = ByteBuffer.wrap(this.toByteArray())
+}
+
+/**
+ * A big-endian byte buffer, with the subset of the `java.nio.ByteBuffer` API that the
+ * FfiConverters use.
+ *
+ * @suppress
+ */
+class ByteBuffer private constructor(private val bytes: ByteArray) {
+    private var position = 0
+
+    fun position(): Int = position
+
+    fun hasRemaining(): Boolean = position < bytes.size
+
+    internal fun array(): ByteArray = bytes
+
+    fun get(): Byte = bytes[position++]
+
+    fun get(dst: ByteArray) {
+        bytes.copyInto(dst, 0, position, position + dst.size)
+        position += dst.size
+    }
+
+    fun getShort(): Short = ((get().toInt() and 0xFF) shl 8 or (get().toInt() and 0xFF)).toShort()
+
+    fun getInt(): Int {
+        var value = 0
+        repeat(4) { value = (value shl 8) or (get().toInt() and 0xFF) }
+        return value
+    }
+
+    fun getLong(): Long {
+        var value = 0L
+        repeat(8) { value = (value shl 8) or (get().toLong() and 0xFF) }
+        return value
+    }
+
+    fun getFloat(): Float = Float.fromBits(getInt())
+
+    fun getDouble(): Double = Double.fromBits(getLong())
+
+    fun put(value: Byte) {
+        bytes[position++] = value
+    }
+
+    fun put(src: ByteArray) {
+        src.copyInto(bytes, position)
+        position += src.size
+    }
+
+    fun putShort(value: Short) {
+        put((value.toInt() shr 8).toByte())
+        put(value.toByte())
+    }
+
+    fun putInt(value: Int) {
+        for (shift in 24 downTo 0 step 8) {
+            put((value shr shift).toByte())
+        }
+    }
+
+    fun putLong(value: Long) {
+        for (shift in 56 downTo 0 step 8) {
+            put((value shr shift).toByte())
+        }
+    }
+
+    fun putFloat(value: Float) = putInt(value.toRawBits())
+
+    fun putDouble(value: Double) = putLong(value.toRawBits())
+
+    companion object {
+        fun allocate(capacity: Int) = ByteBuffer(ByteArray(capacity))
+
+        fun wrap(bytes: ByteArray) = ByteBuffer(bytes)
+    }
+}
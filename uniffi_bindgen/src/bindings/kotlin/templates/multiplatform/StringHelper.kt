@@ -0,0 +1,47 @@
+/**
+ * @suppress
+ */
+public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
+    // Note: we don't inherit from FfiConverterRustBuffer, because we use a
+    // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
+    // store our length and avoid writing it out to the buffer.
+    override fun lift(value: RustBuffer.ByValue): String {
+        try {
+            return value.toByteArray().decodeToString()
+        } finally {
+            RustBuffer.free(value)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): String {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr.decodeToString()
+    }
+
+    fun toUtf8(value: String): ByteArray {
+        // Make sure we don't have invalid UTF-16, check for lone surrogates.
+        return value.encodeToByteArray(throwOnInvalidSequence = true)
+    }
+
+    override fun lower(value: String): RustBuffer.ByValue {
+        val bytes = toUtf8(value)
+        return RustBuffer.fromBytes(bytes, bytes.size)
+    }
+
+    // We aren't sure exactly how many bytes our string will be once it's UTF-8
+    // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
+    // enough.
+    override fun allocationSize(value: String): ULong {
+        val sizeForLength = 4UL
+        val sizeForString = value.length.toULong() * 3UL
+        return sizeForLength + sizeForString
+    }
+
+    override fun write(value: String, buf: ByteBuffer) {
+        val bytes = toUtf8(value)
+        buf.putInt(bytes.size)
+        buf.put(bytes)
+    }
+}
@@ -0,0 +1,181 @@
+// This file was autogenerated by some hot garbage in the `uniffi` crate.
+// Trust me, you don't want to mess with it!
+
+package {{ package_name }}
+
+// JVM implementation of the platform code for `{{ name }}.kt`.
+//
+// The FFI functions are called using JNA, the same way as the non-multiplatform bindings.
+
+import com.sun.jna.Native
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+{%- if has_interfaces && android_cleaner && !disable_java_cleaner %}
+import android.os.Build
+import androidx.annotation.RequiresApi
+{%- endif %}
+
+actual typealias Runnable = java.lang.Runnable
+
+internal actual class AtomicBoolean actual constructor(initialValue: Boolean) {
+    private val inner = java.util.concurrent.atomic.AtomicBoolean(initialValue)
+
+    actual fun get(): Boolean = inner.get()
+
+    actual fun compareAndSet(expect: Boolean, update: Boolean): Boolean = inner.compareAndSet(expect, update)
+}
+
+internal actual class AtomicLong actual constructor(initialValue: Long) {
+    private val inner = java.util.concurrent.atomic.AtomicLong(initialValue)
+
+    actual fun get(): Long = inner.get()
+
+    actual fun compareAndSet(expect: Long, update: Long): Boolean = inner.compareAndSet(expect, update)
+
+    actual fun decrementAndGet(): Long = inner.decrementAndGet()
+}
+
+internal actual fun uniffiReadBytes(data: Long, len: Int): ByteArray =
+    Pointer(data).getByteArray(0, len)
+
+internal actual fun uniffiWriteBytes(data: Long, bytes: ByteArray, len: Int) =
+    Pointer(data).write(0, bytes, 0, len)
+
+// The JNA structs that the FFI functions use.  These get converted to and from the
+// `RustBuffer` and `UniffiRustCallStatus` classes from the common code.
+@Structure.FieldOrder("capacity", "len", "data")
+internal open class UniffiJnaRustBuffer : Structure() {
+    @JvmField var capacity: Long = 0
+    @JvmField var len: Long = 0
+    @JvmField var data: Pointer? = null
+
+    class ByValue: UniffiJnaRustBuffer(), Structure.ByValue
+}
+
+@Structure.FieldOrder("code", "error_buf")
+internal open class UniffiJnaRustCallStatus : Structure() {
+    @JvmField var code: Byte = 0
+    @JvmField var error_buf: UniffiJnaRustBuffer.ByValue = UniffiJnaRustBuffer.ByValue()
+}
+
+private fun uniffiJnaRustBuffer(capacity: Long, len: Long, data: Long) = UniffiJnaRustBuffer.ByValue().also {
+    it.capacity = capacity
+    it.len = len
+    it.data = if (data == 0L) null else Pointer(data)
+}
+
+private inline fun <T> uniffiJnaCall(status: UniffiRustCallStatus, call: (UniffiJnaRustCallStatus) -> T): T {
+    val jnaStatus = UniffiJnaRustCallStatus()
+    val result = call(jnaStatus)
+    status.code = jnaStatus.code
+    status.error_buf = jnaStatus.error_buf.let { RustBuffer.ByValue(it.capacity, it.len, Pointer.nativeValue(it.data)) }
+    return result
+}
+
+@Synchronized
+private fun findLibraryName(componentName: String): String {
+    val libOverride = System.getProperty("uniffi.component.$componentName.libraryOverride")
+    if (libOverride != null) {
+        return libOverride
+    }
+    return "{{ cdylib_name }}"
+}
+
+// The JNA direct mapping of the FFI functions
+internal object UniffiJnaLib {
+    init {
+        Native.register(UniffiJnaLib::class.java, findLibraryName(componentName = "{{ name }}"))
+    }
+    {%- for func in multiplatform_ffi_functions() %}
+
+    external fun {{ func.name.0 }}(
+        {%- for arg in func.arguments %}
+        {{ arg.name }}: {% call jna_type(arg.ty) %}{% endcall %},
+        {%- endfor %}
+        {%- if func.has_rust_call_status_arg %}
+        uniffi_out_err: UniffiJnaRustCallStatus,
+        {%- endif %}
+    ): {% match func.return_type.ty %}{% when Some(return_type) %}{% call jna_return_type(return_type) %}{% endcall %}{% when None %}Unit{% endmatch %}
+    {%- endfor %}
+}
+
+internal actual object IntegrityCheckingUniffiLib {
+    init {
+        uniffiCheckContractApiVersion(this)
+{%- if !omit_checksums %}
+        uniffiCheckApiChecksums(this)
+{%- endif %}
+    }
+
+    actual fun ensureInitialized() = Unit
+    {%- for func in multiplatform_ffi_functions() %}
+    {%- if func.is_integrity_check() %}
+
+    {% filter indent(4) %}{% call jna_actual_fn(func) %}{% endcall %}{% endfilter %}
+    {%- endif %}
+    {%- endfor %}
+}
+
+internal actual object UniffiLib {
+    {%- if has_interfaces %}
+    // The Cleaner for the whole library
+    actual val CLEANER: UniffiCleaner by lazy {
+        UniffiCleaner.create()
+    }
+    {%- endif %}
+
+    init {
+        {%- for fn_item in initialization_fns %}
+        {{ fn_item }}
+        {%- endfor %}
+    }
+
+    actual fun ensureInitialized() = Unit
+    {%- for func in multiplatform_ffi_functions() %}
+    {%- if !func.is_integrity_check() %}
+
+    {% filter indent(4) %}{% call jna_actual_fn(func) %}{% endcall %}{% endfilter %}
+    {%- endif %}
+    {%- endfor %}
+}
+
+{%- if has_interfaces %}
+
+{% include "ObjectCleanerHelperJna.kt" %}
+{%- endif %}
+
+{#- JNA type for an FFI argument #}
+{%- macro jna_type(ty) %}
+{%- if let FfiType::RustBuffer(_) = ty.ty %}UniffiJnaRustBuffer.ByValue{% else %}{{ ty.type_name }}{% endif %}
+{%- endmacro %}
+
+{#- JNA type for an FFI return value #}
+{%- macro jna_return_type(ty) %}
+{%- if let FfiType::RustBuffer(_) = ty.ty %}UniffiJnaRustBuffer.ByValue{% else %}{{ ty.direct_return_type_name }}{% endif %}
+{%- endmacro %}
+
+{#- Implement an `expect` FFI function by calling the `UniffiJnaLib` function #}
+{%- macro jna_actual_fn(func) -%}
+actual {% call kt::ffi_fn_signature(func) %}{% endcall %} =
+{%- if func.has_rust_call_status_arg %} uniffiJnaCall(uniffi_out_err) { status ->{% endif %}
+    UniffiJnaLib.{{ func.name.0 }}(
+        {%- for arg in func.arguments %}
+        {%- if let FfiType::RustBuffer(_) = arg.ty.ty %}
+        uniffiJnaRustBuffer({{ arg.name }}.capacity, {{ arg.name }}.len, {{ arg.name }}.data),
+        {%- else %}
+        {{ arg.name }},
+        {%- endif %}
+        {%- endfor %}
+        {%- if func.has_rust_call_status_arg %}
+        status,
+        {%- endif %}
+    )
+    {%- if let Some(return_type) = func.return_type.ty %}
+    {%- if let FfiType::RustBuffer(_) = return_type.ty %}.let { {{ return_type.type_name }}(it.capacity, it.len, Pointer.nativeValue(it.data)) }{% endif %}
+    {%- endif %}
+{%- if func.has_rust_call_status_arg %}
+}
+{%- endif %}
+{%- endmacro %}
+
+{%- import "macros.kt" as kt %}
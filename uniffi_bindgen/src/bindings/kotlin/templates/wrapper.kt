@@ -1,11 +1,11 @@
 // This file was autogenerated by some hot garbage in the `uniffi` crate.
 // Trust me, you don't want to mess with it!
 
-{%- call kt::docstring_value(ci.namespace_docstring(), 0) %}{% endcall %}
+{%- call kt::docstring_value(docstring, 0) %}{% endcall %}
 
 @file:Suppress("NAME_SHADOWING")
 
-package {{ config.package_name() }}
+package {{ package_name }}
 
 // Common helper code.
 //
@@ -18,7 +18,7 @@ package {{ config.package_name() }}
 // now that means coming from the exact some version of `uniffi` that was used to
 // compile the Rust component. The easiest way to ensure this is to bundle the Kotlin
 // helpers directly inline like we're doing here.
-
+{% if !multiplatform %}
 import com.sun.jna.Library
 import com.sun.jna.IntegerType
 import com.sun.jna.Native
@@ -32,11 +32,25 @@ import java.nio.CharBuffer
 import java.nio.charset.CodingErrorAction
 import java.util.concurrent.atomic.AtomicLong
 import java.util.concurrent.ConcurrentHashMap
+{%- endif %}
 
-{%- for req in self.imports() %}
-{{ req.render() }}
+{%- for import in imports %}
+import {{ import.name }}{% if let Some(as_name) = import.as_name %} as {{ as_name }}{% endif %}
 {%- endfor %}
 
+{% if multiplatform -%}
+{% include "multiplatform/PlatformTypes.kt" %}
+
+{% include "multiplatform/RustBufferTemplate.kt" %}
+
+{% include "FfiConverterTemplate.kt" %}
+
+{% include "Helpers.kt" %}
+
+// Contains the initialization code and the FFI function declarations,
+// which are implemented by the platform source sets.
+{% include "multiplatform/NamespaceLibraryTemplate.kt" %}
+{%- else -%}
 {% include "RustBufferTemplate.kt" %}
 {% include "FfiConverterTemplate.kt" %}
 {% include "Helpers.kt" %}
@@ -45,16 +59,22 @@ import java.util.concurrent.ConcurrentHashMap
 // Contains loading, initialization code,
 // and the FFI Function declarations in a com.sun.jna.Library.
 {% include "NamespaceLibraryTemplate.kt" %}
+{%- endif %}
 
 // Async support
-{%- if ci.has_async_fns() %}
+{%- if has_async_fns %}
 {% include "Async.kt" %}
 {%- endif %}
 
 // Public interface members begin here.
-{{ type_helper_code }}
+{% include "Types.kt" %}
+
+{%- for c in constants %}
+{% call kt::docstring(c, 0) %}{% endcall %}
+{% if c.is_const_val %}const {% endif %}val {{ c.name }}: {{ c.ty.type_name }} = {{ c.kt_value }}
+{%- endfor %}
 
-{%- for func in ci.function_definitions() %}
+{%- for func in functions %}
 {%- include "TopLevelFunctionTemplate.kt" %}
 {%- endfor %}
 
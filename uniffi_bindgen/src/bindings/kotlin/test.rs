@@ -82,15 +82,28 @@ fn build_jar(
 ) -> Result<Utf8PathBuf> {
     let mut jar_file = Utf8PathBuf::from(out_dir);
     jar_file.push(format!("{crate_name}.jar"));
-    let sources = glob::glob(out_dir.join("**/*.kt").as_str())?
-        .flatten()
-        .map(|p| String::from(p.to_string_lossy()))
-        .collect::<Vec<String>>();
+    // Multiplatform bindings are split into source sets.  Compile the common and JVM ones
+    // together, so that the `expect` declarations are matched with the JNA actuals.
+    let common_dir = out_dir.join("commonMain");
+    let (common_sources, sources) = if common_dir.exists() {
+        let common_sources = find_sources(&common_dir)?;
+        let mut sources = common_sources.clone();
+        sources.extend(find_sources(&out_dir.join("jvmMain"))?);
+        (common_sources, sources)
+    } else {
+        (vec![], find_sources(out_dir)?)
+    };
     if sources.is_empty() {
         bail!("No kotlin sources found in {out_dir}")
     }
 
     let mut command = kotlinc_command(options);
+    if !common_sources.is_empty() {
+        command
+            .arg("-Xmulti-platform")
+            .arg("-Xexpect-actual-classes")
+            .arg(format!("-Xcommon-sources={}", common_sources.join(",")));
+    }
     command
         // Our generated bindings should not produce any warnings; fail tests if they do.
         .arg("-Werror")
@@ -111,6 +124,13 @@ fn build_jar(
     Ok(jar_file)
 }
 
+fn find_sources(dir: &Utf8Path) -> Result<Vec<String>> {
+    Ok(glob::glob(dir.join("**/*.kt").as_str())?
+        .flatten()
+        .map(|p| String::from(p.to_string_lossy()))
+        .collect())
+}
+
 fn kotlinc_command(options: &RunScriptOptions) -> Command {
     let mut command = Command::new("kotlinc");
     if !options.show_compiler_messages {
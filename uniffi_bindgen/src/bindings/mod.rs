@@ -7,22 +7,24 @@
 //! This module contains all the code for generating foreign language bindings,
 //! along with some helpers for executing foreign language scripts or tests.
 
-use std::fs;
+use std::{fmt, fs};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use camino::Utf8PathBuf;
 
 use crate::{BindgenLoader, BindgenPaths, GlobalConfig};
-mod kotlin;
+pub mod cpp;
+pub mod kotlin;
 pub mod python;
 mod ruby;
-mod swift;
+pub mod swift;
+pub mod typescript;
 pub use swift::{generate_swift_bindings, SwiftBindingsOptions};
 
 #[cfg(feature = "bindgen-tests")]
 pub use self::{
-    kotlin::test as kotlin_test, python::test as python_test, ruby::test as ruby_test,
-    swift::test as swift_test,
+    cpp::test as cpp_test, kotlin::test as kotlin_test, python::test as python_test,
+    ruby::test as ruby_test, swift::test as swift_test, typescript::test as typescript_test,
 };
 
 #[cfg(feature = "bindgen-tests")]
@@ -71,6 +73,10 @@ pub fn generate_with_bindgen_paths(
         features: options.features.clone(),
     })?;
 
+    if options.package && options.languages != [TargetLanguage::Python] {
+        bail!("Packaging the bindings is only supported for Python");
+    }
+
     fs::create_dir_all(&options.out_dir)?;
 
     let loader = BindgenLoader::new(paths, global_config).with_target(options.target.clone());
@@ -88,6 +94,12 @@ pub fn generate_with_bindgen_paths(
             TargetLanguage::Ruby => {
                 ruby::generate(&loader, options.clone())?;
             }
+            TargetLanguage::TypeScript => {
+                typescript::generate(&loader, options.clone())?;
+            }
+            TargetLanguage::Cpp => {
+                cpp::generate(&loader, options.clone())?;
+            }
         }
     }
     Ok(())
@@ -122,12 +134,32 @@ pub struct GenerateOptions {
     /// This can be used in environments when all types are in the namespace and fetching
     /// all sub-dependencies causes obscure platform specific problems.
     pub metadata_no_deps: bool,
+    /// Write a ready-to-build package containing the bindings and the library, rather than
+    /// only the bindings.
+    ///
+    /// Only supported for Python.
+    pub package: bool,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TargetLanguage {
     Kotlin,
     Python,
     Ruby,
     Swift,
+    TypeScript,
+    Cpp,
+}
+
+impl fmt::Display for TargetLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Kotlin => write!(f, "Kotlin"),
+            Self::Python => write!(f, "Python"),
+            Self::Ruby => write!(f, "Ruby"),
+            Self::Swift => write!(f, "Swift"),
+            Self::TypeScript => write!(f, "TypeScript"),
+            Self::Cpp => write!(f, "C++"),
+        }
+    }
 }
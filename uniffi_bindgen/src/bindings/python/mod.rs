@@ -10,6 +10,7 @@ use fs_err as fs;
 use crate::{bindings::GenerateOptions, BindgenLoader};
 
 pub mod filters;
+mod package;
 mod pipeline;
 pub use pipeline::{pipeline, Root};
 
@@ -25,7 +26,11 @@ pub fn generate(loader: &BindgenLoader, options: GenerateOptions) -> Result<()>
         }
     }
     let root = loader.load_pipeline_initial_root(&options.source, metadata)?;
-    run_pipeline(root, &options.out_dir, options.crate_filter.as_deref())?;
+    if options.package {
+        package::write_package(loader, &options, root)?;
+    } else {
+        run_pipeline(root, &options.out_dir, options.crate_filter.as_deref())?;
+    }
 
     Ok(())
 }
@@ -36,6 +41,10 @@ pub fn run_pipeline(
     crate_filter: Option<&str>,
 ) -> Result<()> {
     let python_root = pipeline().execute(initial_root)?;
+    write_modules(&python_root, out_dir, crate_filter)
+}
+
+fn write_modules(python_root: &Root, out_dir: &Utf8Path, crate_filter: Option<&str>) -> Result<()> {
     println!("writing out {out_dir}");
     if !out_dir.exists() {
         fs::create_dir_all(out_dir)?;
@@ -50,6 +59,12 @@ pub fn run_pipeline(
         let content = module.render()?;
         println!("writing {path}");
         fs::write(path, content)?;
+        if module.generate_type_stubs {
+            let path = out_dir.join(format!("{}.pyi", module.name));
+            let content = pipeline::ModuleStub { module }.render()?;
+            println!("writing {path}");
+            fs::write(path, content)?;
+        }
     }
     Ok(())
 }
@@ -0,0 +1,275 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Write a Python project for the bindings, for `uniffi-bindgen generate --package`
+//!
+//! The generated layout is:
+//!
+//! ```text
+//! {out_dir}/
+//!     pyproject.toml
+//!     {package}/
+//!         __init__.py    # re-exports the main module
+//!         py.typed
+//!         {module}.py    # one per crate in the library, plus `.pyi` stubs if enabled
+//!         {cdylib}       # copied from the source library
+//! ```
+//!
+//! The modules already load the cdylib from their own directory, so nothing changes for them
+//! inside the package.  The wheel only contains Python code plus a prebuilt library, so it's
+//! tagged as `py3-none-{platform}`, with the platform derived from the target triple.  Like
+//! auditwheel, the glibc version in `manylinux` tags comes from the newest `GLIBC_*` symbol version
+//! that the library needs.
+
+use anyhow::{anyhow, bail, Context, Result};
+use askama::Template;
+use camino::Utf8PathBuf;
+use fs_err as fs;
+
+use super::{pipeline, pipeline::Module, write_modules, Root};
+use crate::{bindings::GenerateOptions, pipeline::initial, BindgenLoader};
+
+pub fn write_package(
+    loader: &BindgenLoader,
+    options: &GenerateOptions,
+    initial_root: initial::Root,
+) -> Result<()> {
+    let source = &options.source;
+    let library_extension = match source.extension() {
+        Some(ext @ ("so" | "dylib" | "dll")) => ext,
+        _ => bail!(
+            "Packaging the bindings requires the path to the cdylib as the source, got {source}"
+        ),
+    };
+    let target = match &options.target {
+        Some(target) => target.clone(),
+        None => host_target(),
+    };
+
+    let python_root = pipeline().execute(initial_root)?;
+    let main_module = main_module(&python_root, options.crate_filter.as_deref())?;
+    let platform_tag = match &main_module.package.platform_tag {
+        Some(platform_tag) => platform_tag.clone(),
+        None => {
+            let glibc_version = match library_extension {
+                "so" => required_glibc_version(&fs::read(source)?)?,
+                _ => None,
+            };
+            wheel_platform_tag(&target, glibc_version)?
+        }
+    };
+    let name = match &main_module.package.name {
+        Some(name) => name.clone(),
+        None => main_module.name.clone(),
+    };
+    let version = match &main_module.package.version {
+        Some(version) => version.clone(),
+        None => loader.crate_version(&main_module.crate_name).ok_or_else(|| {
+            anyhow!(
+                "Can't find the version of {}, set `bindings.python.package.version` in `uniffi.toml`",
+                main_module.crate_name
+            )
+        })?,
+    };
+    let import_name = name.replace(['-', '.'], "_");
+
+    let package_dir = options.out_dir.join(&import_name);
+    fs::create_dir_all(&package_dir)?;
+    // All modules go in the package, since they share the cdylib and import each other.
+    write_modules(&python_root, &package_dir, None)?;
+    write_file(
+        package_dir.join("__init__.py"),
+        format!("from .{} import *\n", main_module.name),
+    )?;
+    write_file(package_dir.join("py.typed"), String::new())?;
+
+    let library_filename = match library_extension {
+        "dll" => format!("{}.dll", main_module.cdylib_name),
+        ext => format!("lib{}.{ext}", main_module.cdylib_name),
+    };
+    let library_path = package_dir.join(library_filename);
+    println!("copying {source} to {library_path}");
+    fs::copy(source, library_path)?;
+
+    let pyproject = PyProjectToml {
+        name,
+        version,
+        import_name,
+        platform_tag,
+    };
+    write_file(
+        options.out_dir.join("pyproject.toml"),
+        pyproject
+            .render()
+            .context("failed to render pyproject.toml")?,
+    )?;
+    Ok(())
+}
+
+fn write_file(path: Utf8PathBuf, content: String) -> Result<()> {
+    println!("writing {path}");
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// Find the module that the package is named after
+///
+/// This is the crate passed with `--crate` or else the crate that built the cdylib.
+fn main_module<'a>(root: &'a Root, crate_filter: Option<&str>) -> Result<&'a Module> {
+    if let Some(crate_name) = crate_filter.or(root.cdylib.as_deref()) {
+        if let Some(module) = root
+            .modules
+            .values()
+            .find(|module| module.crate_name == crate_name)
+        {
+            return Ok(module);
+        }
+    }
+    match root.modules.len() {
+        1 => Ok(root.modules.values().next().unwrap()),
+        _ => bail!("Can't tell which crate to name the package after, use `--crate` to pick one"),
+    }
+}
+
+/// Get the target triple that `uniffi-bindgen` was built for
+fn host_target() -> String {
+    env!("UNIFFI_BINDGEN_TARGET").to_string()
+}
+
+/// Find the newest glibc version that a shared library needs, from its `GLIBC_*` symbol versions
+///
+/// Returns `None` for libraries that don't link to glibc.
+fn required_glibc_version(library: &[u8]) -> Result<Option<(u32, u32)>> {
+    let elf = goblin::elf::Elf::parse(library).context("parsing the library")?;
+    let Some(verneed) = &elf.verneed else {
+        return Ok(None);
+    };
+    let mut newest = None;
+    for need_file in verneed.iter() {
+        let versions = need_file
+            .iter()
+            .filter_map(|need_ver| elf.dynstrtab.get_at(need_ver.vna_name))
+            .filter_map(parse_glibc_version);
+        newest = newest.into_iter().chain(versions).max();
+    }
+    Ok(newest)
+}
+
+/// Parse a `GLIBC_2.17` style symbol version.  Patch versions are ignored, since manylinux tags
+/// don't include them.
+fn parse_glibc_version(symbol_version: &str) -> Option<(u32, u32)> {
+    let mut parts = symbol_version.strip_prefix("GLIBC_")?.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Get the wheel platform tag for a Rust target triple
+///
+/// The minimum OS versions match the oldest ones that Rust supports for each target.  For glibc
+/// targets, `glibc_version` raises the minimum to the version that the library needs.
+fn wheel_platform_tag(target: &str, glibc_version: Option<(u32, u32)>) -> Result<String> {
+    let unsupported = || anyhow!("Can't package the bindings for the {target} target");
+    let (arch, rest) = target.split_once('-').ok_or_else(unsupported)?;
+    if rest.contains("android") {
+        // Android uses the `linux` OS, but there's no wheel tag for it
+        return Err(unsupported());
+    }
+    Ok(if rest.contains("linux") {
+        let arch = match arch {
+            "x86_64" | "aarch64" | "s390x" => arch,
+            "i586" | "i686" => "i686",
+            "armv7" => "armv7l",
+            "powerpc64le" => "ppc64le",
+            "riscv64gc" => "riscv64",
+            _ => return Err(unsupported()),
+        };
+        if rest.contains("musl") {
+            format!("musllinux_1_2_{arch}")
+        } else {
+            let (major, minor) = glibc_version.unwrap_or_default().max((2, 17));
+            format!("manylinux_{major}_{minor}_{arch}")
+        }
+    } else if rest.contains("darwin") {
+        match arch {
+            "x86_64" => "macosx_10_12_x86_64".to_string(),
+            "aarch64" => "macosx_11_0_arm64".to_string(),
+            _ => return Err(unsupported()),
+        }
+    } else if rest.contains("windows") {
+        match arch {
+            "x86_64" => "win_amd64".to_string(),
+            "i586" | "i686" => "win32".to_string(),
+            "aarch64" => "win_arm64".to_string(),
+            _ => return Err(unsupported()),
+        }
+    } else {
+        return Err(unsupported());
+    })
+}
+
+/// Renders `pyproject.toml` for the package
+#[derive(Template)]
+#[template(syntax = "py", escape = "none", path = "package/pyproject.toml")]
+struct PyProjectToml {
+    name: String,
+    version: String,
+    import_name: String,
+    platform_tag: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wheel_platform_tag() {
+        for (target, tag) in [
+            ("x86_64-unknown-linux-gnu", "manylinux_2_17_x86_64"),
+            ("aarch64-unknown-linux-gnu", "manylinux_2_17_aarch64"),
+            ("armv7-unknown-linux-gnueabihf", "manylinux_2_17_armv7l"),
+            ("x86_64-unknown-linux-musl", "musllinux_1_2_x86_64"),
+            ("x86_64-apple-darwin", "macosx_10_12_x86_64"),
+            ("aarch64-apple-darwin", "macosx_11_0_arm64"),
+            ("x86_64-pc-windows-msvc", "win_amd64"),
+            ("i686-pc-windows-gnu", "win32"),
+            ("aarch64-pc-windows-msvc", "win_arm64"),
+        ] {
+            assert_eq!(wheel_platform_tag(target, None).unwrap(), tag);
+        }
+        assert!(wheel_platform_tag("aarch64-linux-android", None).is_err());
+        assert!(wheel_platform_tag("wasm32-unknown-unknown", None).is_err());
+    }
+
+    #[test]
+    fn test_glibc_version() {
+        assert_eq!(
+            wheel_platform_tag("x86_64-unknown-linux-gnu", Some((2, 28))).unwrap(),
+            "manylinux_2_28_x86_64"
+        );
+        // Libraries that only need older versions still get the oldest version Rust supports
+        assert_eq!(
+            wheel_platform_tag("x86_64-unknown-linux-gnu", Some((2, 2))).unwrap(),
+            "manylinux_2_17_x86_64"
+        );
+        assert_eq!(
+            wheel_platform_tag("x86_64-unknown-linux-musl", Some((2, 28))).unwrap(),
+            "musllinux_1_2_x86_64"
+        );
+        assert_eq!(parse_glibc_version("GLIBC_2.17"), Some((2, 17)));
+        assert_eq!(parse_glibc_version("GLIBC_2.3.4"), Some((2, 3)));
+        assert_eq!(parse_glibc_version("GLIBC_PRIVATE"), None);
+        assert_eq!(parse_glibc_version("GCC_3.0"), None);
+    }
+
+    // The test binary links to glibc the same way a cdylib built for the host would
+    #[test]
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    fn test_required_glibc_version() {
+        let exe = fs::read(std::env::current_exe().unwrap()).unwrap();
+        let (major, minor) = required_glibc_version(&exe).unwrap().unwrap();
+        assert_eq!(major, 2);
+        assert!(minor >= 2);
+    }
+}
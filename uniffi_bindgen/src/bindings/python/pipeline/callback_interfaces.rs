@@ -10,6 +10,7 @@ pub fn protocol(cbi: &general::CallbackInterface, context: &Context) -> Result<P
         // suffix.
         name: cbi.name.clone(),
         base_classes: vec!["typing.Protocol".to_string()],
+        properties: vec![],
         methods: cbi.methods.clone().map_node(context)?,
         docstring: cbi.docstring.clone(),
     })
@@ -31,6 +31,19 @@ pub struct PythonConfig {
     pub custom_types: IndexMap<String, CustomTypeConfig>,
     #[serde(default)]
     pub external_packages: IndexMap<String, String>,
+    /// Represent timestamps and durations with nanosecond precision, rather than with
+    /// `datetime` types.
+    #[serde(default)]
+    pub lossless_time_types: bool,
+    /// Write a `.pyi` stub with precise types next to the generated module.
+    #[serde(default)]
+    pub generate_type_stubs: bool,
+    /// Async libraries that async functions and callback methods can run on.
+    #[serde(default)]
+    pub async_runtime: AsyncRuntime,
+    /// Settings for `uniffi-bindgen generate --package`
+    #[serde(default)]
+    pub package: PackageConfig,
 }
 
 #[derive(Debug, Clone, Node, Default, Deserialize)]
@@ -44,6 +57,29 @@ pub struct CustomTypeConfig {
     pub lower: String,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Node, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AsyncRuntime {
+    /// Only support asyncio
+    #[default]
+    Asyncio,
+    /// Detect the running async library with `sniffio`, supporting both asyncio and trio.
+    ///
+    /// anyio runs on top of one of those, so it's supported as well.
+    Auto,
+}
+
+#[derive(Debug, Clone, Node, Default, Deserialize)]
+#[serde(default)]
+pub struct PackageConfig {
+    /// Distribution name, defaults to the module name
+    pub name: Option<String>,
+    /// Package version, defaults to the crate version
+    pub version: Option<String>,
+    /// Wheel platform tag, defaults to one derived from the target and the library
+    pub platform_tag: Option<String>,
+}
+
 impl PythonConfig {
     pub fn from_uniffi_toml(toml: &str) -> Result<Self> {
         let root: Config = toml::from_str(toml)?;
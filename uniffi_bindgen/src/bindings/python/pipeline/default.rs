@@ -30,10 +30,13 @@ pub fn render_default(default: &general::DefaultValue, context: &Context) -> Res
             | Type::Int8
             | Type::Int16
             | Type::Int32
-            | Type::Int64 => "0".to_string(),
+            | Type::Int64
+            | Type::UInt128
+            | Type::Int128 => "0".to_string(),
             Type::Float32 | Type::Float64 => "0.0".to_string(),
             Type::Boolean => "False".to_string(),
             Type::Bytes => "b\"\"".to_string(),
+            Type::OwnedBytes => "memoryview(b\"\")".to_string(),
             Type::String => "\"\"".to_string(),
             Type::Record { .. }
             | Type::Enum { .. }
@@ -108,6 +111,8 @@ pub fn arg_literal(default: &general::DefaultValue, context: &Context) -> Result
             | Type::Int16
             | Type::Int32
             | Type::Int64
+            | Type::UInt128
+            | Type::Int128
             | Type::Float32
             | Type::Float64
             | Type::Boolean
@@ -15,6 +15,7 @@ pub fn protocol(int: &general::Interface, context: &Context) -> Result<Protocol>
             Protocol {
                 name: format!("{}Protocol", names::type_name(&int.name)),
                 base_classes: vec!["typing.Protocol".to_string()],
+                properties: int.properties.clone().map_node(context)?,
                 methods: int.methods.clone().map_node(context)?,
                 docstring: int.docstring.clone(),
             }
@@ -28,6 +29,7 @@ pub fn protocol(int: &general::Interface, context: &Context) -> Result<Protocol>
             Protocol {
                 name: names::type_name(&int.name),
                 base_classes: vec![],
+                properties: int.properties.clone().map_node(context)?,
                 methods: int.methods.clone().map_node(context)?,
                 docstring: int.docstring.clone(),
             }
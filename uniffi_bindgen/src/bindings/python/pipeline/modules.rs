@@ -14,15 +14,21 @@ pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result
     let mut module = Module {
         cdylib_name: context.cdylib()?,
         checksum_mode: context.checksum_mode()?.clone(),
-        has_async_fns: namespace.has_descendant(|callable: &general::Callable| callable.is_async()),
+        has_async_fns: namespace.has_descendant(|callable: &general::Callable| callable.is_async())
+            || namespace.has_descendant(|_: &general::StreamType| true),
         has_callback_interface: namespace.has_descendant(|_: &general::CallbackInterface| true),
         has_async_callback_method: has_async_callback_method(&namespace),
+        lossless_time_types: context.config()?.lossless_time_types,
+        generate_type_stubs: context.config()?.generate_type_stubs,
+        async_runtime: context.config()?.async_runtime,
+        package: context.config()?.package.clone(),
         imports: module_imports(&namespace, context.config()?),
         exported_names: vec![],
         name: namespace.name.map_node(context)?,
         crate_name: namespace.crate_name.map_node(context)?,
         docstring: namespace.docstring.map_node(context)?,
         functions: namespace.functions.map_node(context)?,
+        constants: namespace.constants.map_node(context)?,
         type_definitions: namespace.type_definitions.map_node(context)?,
         ffi_definitions: namespace.ffi_definitions.map_node(context)?,
         checksums: namespace.checksums.map_node(context)?,
@@ -31,6 +37,7 @@ pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result
         ffi_rustbuffer_free: namespace.ffi_rustbuffer_free,
         ffi_rustbuffer_reserve: namespace.ffi_rustbuffer_reserve,
         ffi_uniffi_contract_version: namespace.ffi_uniffi_contract_version,
+        closure_vtable: namespace.closure_vtable.map_node(context)?,
         correct_contract_version: namespace.correct_contract_version,
         builtin_types: context.builtin_types()?,
     };
@@ -40,12 +47,25 @@ pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result
     module.visit(|e: &Enum| exported_names.push(e.name.clone()));
     module.visit(|r: &Record| exported_names.push(r.name.clone()));
     module.visit(|f: &Function| exported_names.push(f.callable.name.clone()));
+    module.visit(|c: &Constant| exported_names.push(c.name.clone()));
     module.visit(|i: &Interface| {
         exported_names.push(i.name.clone());
         exported_names.push(i.protocol.name.clone());
     });
     module.visit(|c: &CallbackInterface| exported_names.push(c.protocol.name.clone()));
     module.visit(|c: &CustomType| exported_names.push(c.name.clone()));
+    if module.lossless_time_types {
+        for type_def in module.type_definitions.iter() {
+            if let TypeDefinition::Simple(type_node) = type_def {
+                match type_node.ty {
+                    Type::Timestamp | Type::Duration => {
+                        exported_names.push(type_node.type_name.clone())
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
     module.exported_names = exported_names;
     Ok(module)
 }
@@ -87,5 +107,12 @@ fn module_imports(namespace: &general::Namespace, config: &PythonConfig) -> Vec<
         }
     });
 
-    config_imports.chain(external_packages_imports).collect()
+    let builtin_imports = namespace
+        .has_descendant(|ty: &Type| matches!(ty, Type::Uuid))
+        .then(|| "uuid".to_string());
+
+    config_imports
+        .chain(external_packages_imports)
+        .chain(builtin_imports)
+        .collect()
 }
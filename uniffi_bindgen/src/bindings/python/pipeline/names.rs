@@ -94,6 +94,10 @@ pub fn function_name(name: &str) -> String {
     fixup_keyword(name.to_snake_case())
 }
 
+pub fn const_name(name: &str) -> String {
+    fixup_keyword(name.to_shouty_snake_case())
+}
+
 pub fn non_error_variant_name(name: &str) -> String {
     // Non error variants get SHOUTY_CASE_NAMES
     fixup_keyword(name.to_shouty_snake_case())
@@ -26,6 +26,8 @@ use_prev_node!(general::FfiType);
 use_prev_node!(general::HandleKind);
 use_prev_node!(general::ObjectImpl);
 use_prev_node!(general::PassBy);
+use_prev_node!(general::PropertyKind);
+use_prev_node!(general::PropertyMetadata);
 use_prev_node!(general::Radix);
 use_prev_node!(general::RustFfiFunctionName);
 use_prev_node!(general::TraitKind);
@@ -69,12 +71,20 @@ pub struct Module {
     pub has_async_fns: bool,
     pub has_callback_interface: bool,
     pub has_async_callback_method: bool,
+    /// Use the nanosecond-precision `Timestamp` and `Duration` classes
+    pub lossless_time_types: bool,
+    /// Also write a `.pyi` stub for the module, see `ModuleStub`
+    pub generate_type_stubs: bool,
+    pub async_runtime: AsyncRuntime,
+    /// Only used when writing a package, see `PackageConfig`
+    pub package: PackageConfig,
     pub imports: Vec<String>,
     pub exported_names: Vec<String>,
     pub name: String,
     pub crate_name: String,
     pub docstring: Option<String>,
     pub functions: Vec<Function>,
+    pub constants: Vec<Constant>,
     pub type_definitions: Vec<TypeDefinition>,
     pub ffi_definitions: IndexSet<FfiDefinition>,
     pub checksums: Vec<Checksum>,
@@ -83,6 +93,7 @@ pub struct Module {
     pub ffi_rustbuffer_free: RustFfiFunctionName,
     pub ffi_rustbuffer_reserve: RustFfiFunctionName,
     pub ffi_uniffi_contract_version: RustFfiFunctionName,
+    pub closure_vtable: Option<ClosureVTable>,
     // Correct contract version value
     pub correct_contract_version: String,
     // Copy builtin types so that we can use in from the `Module.py` template
@@ -90,9 +101,28 @@ pub struct Module {
     pub checksum_mode: ChecksumMode,
 }
 
+/// Type stubs for a `Module`
+///
+/// This is a separate template, since it gets written to a separate `.pyi` file.
+#[derive(Template)]
+#[template(syntax = "py", escape = "none", path = "stubs/Module.pyi")]
+pub struct ModuleStub<'a> {
+    pub module: &'a Module,
+}
+
 // These structs exist so that we can easily deserialize the entire `uniffi.toml` file.
 // We then extract the `PythonConfig`, which is what we actually care about.
 
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constant))]
+pub struct Constant {
+    #[map_node(names::const_name(&self.name))]
+    pub name: String,
+    pub ty: TypeNode,
+    pub value: LiteralNode,
+    pub docstring: Option<String>,
+}
+
 #[derive(Debug, Clone, Node, MapNode)]
 #[map_node(from(general::Function))]
 pub struct Function {
@@ -116,6 +146,10 @@ pub enum TypeDefinition {
     Sequence(SequenceType),
     Map(MapType),
     Set(SetType),
+    Stream(StreamType),
+    Tuple(TupleType),
+    Array(ArrayType),
+    Closure(ClosureType),
     /// User types that are defined in another crate
     External(ExternalType),
 }
@@ -132,6 +166,18 @@ pub struct Constructor {
 pub struct Method {
     pub callable: Callable,
     pub docstring: Option<String>,
+    pub property: Option<PropertyMetadata>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Property))]
+pub struct Property {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+    pub getter: Method,
+    pub setter: Option<Method>,
 }
 
 /// Common data from Function/Method/Constructor
@@ -162,6 +208,8 @@ pub enum CallableKind {
     },
     /// Interface constructor
     Constructor { self_type: TypeNode, primary: bool },
+    /// Interface static method, these don't take a `self` argument
+    StaticMethod { self_type: TypeNode },
     /// Method inside a VTable or a CallbackInterface
     ///
     /// For trait interfaces this only applies to the Callables inside the `vtable.methods` field.
@@ -371,7 +419,9 @@ pub struct Interface {
     pub docstring: Option<String>,
     #[map_node(interfaces::map_constructors(&self.name, self.constructors, context)?)]
     pub constructors: Vec<Constructor>,
+    pub properties: Vec<Property>,
     pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
     pub uniffi_trait_methods: UniffiTraitMethods,
     pub trait_impls: Vec<ObjectTraitImpl>,
     pub imp: ObjectImpl,
@@ -392,6 +442,7 @@ pub struct Protocol {
     pub name: String,
     pub base_classes: Vec<String>,
     pub docstring: Option<String>,
+    pub properties: Vec<Property>,
     pub methods: Vec<Method>,
 }
 
@@ -484,6 +535,51 @@ pub struct SetType {
     pub self_type: TypeNode,
 }
 
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TupleType))]
+pub struct TupleType {
+    pub elements: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ArrayType))]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    pub is_byte_array: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureType))]
+pub struct ClosureType {
+    pub arguments: Vec<TypeNode>,
+    pub return_type: ReturnType,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureVTable))]
+pub struct ClosureVTable {
+    pub struct_type: FfiTypeNode,
+    pub init_fn: RustFfiFunctionName,
+    pub free_fn_type: FfiFunctionTypeName,
+    pub call_fn_type: FfiFunctionTypeName,
+    pub ffi_closure_call: RustFfiFunctionName,
+    pub ffi_closure_free: RustFfiFunctionName,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::StreamType))]
+pub struct StreamType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+    pub ffi_stream_next: RustFfiFunctionName,
+    pub ffi_stream_free: RustFfiFunctionName,
+    pub async_data: AsyncData,
+}
+
 #[derive(Debug, Clone, Node, MapNode)]
 #[map_node(from(general::ExternalType))]
 pub struct ExternalType {
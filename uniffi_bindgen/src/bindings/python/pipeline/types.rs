@@ -20,6 +20,7 @@ fn rename_type(ty: &mut Type) {
         }
         Type::Optional { inner_type }
         | Type::Sequence { inner_type }
+        | Type::Array { inner_type, .. }
         | Type::Box { inner_type } => {
             rename_type(inner_type);
         }
@@ -55,6 +56,8 @@ pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
         Type::Boolean => "bool".to_string(),
         Type::String => "str".to_string(),
         Type::Bytes => "bytes".to_string(),
+        // Owned bytes wrap the Rust allocation without copying.
+        Type::OwnedBytes => "memoryview".to_string(),
         Type::Int8 => "int".to_string(),
         Type::Int16
         | Type::Int32
@@ -62,9 +65,15 @@ pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
         | Type::UInt8
         | Type::UInt16
         | Type::UInt32
-        | Type::UInt64 => "int".to_string(),
+        | Type::UInt64
+        | Type::Int128
+        | Type::UInt128 => "int".to_string(),
         Type::Duration => "Duration".to_string(),
         Type::Timestamp => "Timestamp".to_string(),
+        Type::Uuid => "uuid.UUID".to_string(),
+        // There's no URL type in the standard library, so these are plain strings.
+        Type::Url => "str".to_string(),
+        Type::Date => "datetime.date".to_string(),
         Type::Float32 | Type::Float64 => "float".to_string(),
         Type::Interface {
             namespace, name, ..
@@ -93,6 +102,10 @@ pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
         Type::Sequence { inner_type } => {
             format!("typing.List[{}]", type_name(inner_type, context)?)
         }
+        Type::Array { inner_type, .. } => match **inner_type {
+            Type::UInt8 => "bytes".to_string(),
+            _ => format!("typing.List[{}]", type_name(inner_type, context)?),
+        },
         Type::Map {
             key_type,
             value_type,
@@ -105,6 +118,32 @@ pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
         Type::Set { inner_type } => {
             format!("typing.Set[{}]", type_name(inner_type, context)?)
         }
+        Type::Stream { inner_type } => {
+            format!("typing.AsyncIterator[{}]", type_name(inner_type, context)?)
+        }
+        Type::Tuple { types } => format!(
+            "typing.Tuple[{}]",
+            types
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        Type::Closure {
+            arguments,
+            return_type,
+        } => format!(
+            "typing.Callable[[{}], {}]",
+            arguments
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", "),
+            match return_type {
+                Some(ty) => type_name(ty, context)?,
+                None => "None".to_string(),
+            }
+        ),
     })
 }
 
@@ -124,6 +163,10 @@ pub fn type_annotation(ty: &Type, context: &Context) -> Result<String> {
             "typing.List[{}]",
             type_annotation(inner_type, context)?
         )),
+        Type::Array { inner_type, .. } if !matches!(**inner_type, Type::UInt8) => Ok(format!(
+            "typing.List[{}]",
+            type_annotation(inner_type, context)?
+        )),
         Type::Map {
             key_type,
             value_type,
@@ -136,6 +179,18 @@ pub fn type_annotation(ty: &Type, context: &Context) -> Result<String> {
             "typing.Set[{}]",
             type_annotation(inner_type, context)?
         )),
+        Type::Stream { inner_type } => Ok(format!(
+            "typing.AsyncIterator[{}]",
+            type_annotation(inner_type, context)?
+        )),
+        Type::Tuple { types } => Ok(format!(
+            "typing.Tuple[{}]",
+            types
+                .iter()
+                .map(|ty| type_annotation(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        )),
         _ => type_name(ty, context),
     }
 }
@@ -0,0 +1,38 @@
+class {{ array.self_type.ffi_converter_name }}(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        {%- if array.is_byte_array %}
+        try:
+            memoryview(value)
+        except TypeError:
+            raise TypeError("a bytes-like object is required, not {!r}".format(type(value).__name__))
+        {%- endif %}
+        if len(value) != {{ array.len }}:
+            raise ValueError("expected an array of length {{ array.len }}, got {}".format(len(value)))
+        {%- if !array.is_byte_array %}
+        for item in value:
+            {{ array.inner.ffi_converter_name }}.check_lower(item)
+        {%- endif %}
+
+    @classmethod
+    def write(cls, value, buf):
+        buf.write_i32({{ array.len }})
+        {%- if array.is_byte_array %}
+        buf.write(value)
+        {%- else %}
+        for item in value:
+            {{ array.inner.ffi_converter_name }}.write(item, buf)
+        {%- endif %}
+
+    @classmethod
+    def read(cls, buf):
+        count = buf.read_i32()
+        if count != {{ array.len }}:
+            raise InternalError("Unexpected array length: {}".format(count))
+        {%- if array.is_byte_array %}
+        return buf.read(count)
+        {%- else %}
+        return [
+            {{ array.inner.ffi_converter_name }}.read(buf) for i in range(count)
+        ]
+        {%- endif %}
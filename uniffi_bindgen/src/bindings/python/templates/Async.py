@@ -34,32 +34,51 @@ def _uniffi_get_event_loop():
             return _UNIFFI_GLOBAL_EVENT_LOOP
         raise RuntimeError("UniFFI error: no running event loop and uniffi_set_default_event_loop not called")
 
+{%- if async_runtime == AsyncRuntime::Auto %}
+
+try:
+    import sniffio
+except ImportError:
+    # trio depends on sniffio, so without it asyncio is the only option
+    sniffio = None
+
+def _uniffi_current_async_library():
+    """
+    Get the async library running on this thread, either "asyncio" or "trio"
+
+    anyio runs on top of one of those, so we don't need to handle it separately.  Threads without
+    a running async library use asyncio, which falls back to `uniffi_set_default_event_loop`.
+    """
+    if sniffio is None:
+        return "asyncio"
+    try:
+        return sniffio.current_async_library()
+    except sniffio.AsyncLibraryNotFoundError:
+        return "asyncio"
+{%- endif %}
+
 # Continuation callback for async functions
-# lift the return value or error and resolve the future, causing the async function to resume.
+# Wake up the task awaiting the Rust future, using the `wake` function stored for the handle.  This
+# gets called from whichever thread wakes the Rust future, so `wake` must be thread-safe.
 @_UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK
 def _uniffi_continuation_callback(future_ptr, poll_code):
-    (eventloop, future) = _UniffiContinuationHandleMap.remove(future_ptr)
-    eventloop.call_soon_threadsafe(_uniffi_set_future_result, future, poll_code)
+    wake = _UniffiContinuationHandleMap.remove(future_ptr)
+    wake(poll_code)
 
 def _uniffi_set_future_result(future, poll_code):
     if not future.cancelled():
         future.set_result(poll_code)
 
-async def _uniffi_rust_call_async(rust_future, ffi_poll, ffi_complete, ffi_free, lift_func, error_ffi_converter):
+async def _uniffi_rust_call_async(rust_future, ffi_poll, ffi_complete, ffi_cancel, ffi_free, lift_func, error_ffi_converter):
     try:
-        eventloop = _uniffi_get_event_loop()
-
-        # Loop and poll until we see a _UNIFFI_RUST_FUTURE_POLL_READY value
-        while True:
-            future = eventloop.create_future()
-            ffi_poll(
-                rust_future,
-                _uniffi_continuation_callback,
-                _UniffiContinuationHandleMap.insert((eventloop, future)),
-            )
-            poll_code = await future
-            if poll_code == _UNIFFI_RUST_FUTURE_POLL_READY:
-                break
+        {%- if async_runtime == AsyncRuntime::Auto %}
+        if _uniffi_current_async_library() == "trio":
+            await _uniffi_poll_until_ready_trio(rust_future, ffi_poll, ffi_cancel)
+        else:
+            await _uniffi_poll_until_ready_asyncio(rust_future, ffi_poll)
+        {%- else %}
+        await _uniffi_poll_until_ready_asyncio(rust_future, ffi_poll)
+        {%- endif %}
 
         return lift_func(
             _uniffi_rust_call_with_error(error_ffi_converter, ffi_complete, rust_future)
@@ -67,6 +86,65 @@ async def _uniffi_rust_call_async(rust_future, ffi_poll, ffi_complete, ffi_free,
     finally:
         ffi_free(rust_future)
 
+async def _uniffi_poll_until_ready_asyncio(rust_future, ffi_poll):
+    eventloop = _uniffi_get_event_loop()
+
+    # Loop and poll until we see a _UNIFFI_RUST_FUTURE_POLL_READY value
+    while True:
+        future = eventloop.create_future()
+
+        def wake(poll_code, future=future):
+            eventloop.call_soon_threadsafe(_uniffi_set_future_result, future, poll_code)
+
+        ffi_poll(
+            rust_future,
+            _uniffi_continuation_callback,
+            _UniffiContinuationHandleMap.insert(wake),
+        )
+        poll_code = await future
+        if poll_code == _UNIFFI_RUST_FUTURE_POLL_READY:
+            return
+{%- if async_runtime == AsyncRuntime::Auto %}
+
+async def _uniffi_poll_until_ready_trio(rust_future, ffi_poll, ffi_cancel):
+    import outcome
+    import trio
+
+    token = trio.lowlevel.current_trio_token()
+    task = trio.lowlevel.current_task()
+    # Set by `abort` when the task gets cancelled
+    raise_cancel = None
+
+    def reschedule(poll_code):
+        if raise_cancel is None:
+            trio.lowlevel.reschedule(task, outcome.Value(poll_code))
+        else:
+            trio.lowlevel.reschedule(task, outcome.capture(raise_cancel))
+
+    def wake(poll_code):
+        token.run_sync_soon(reschedule, poll_code)
+
+    def abort(raise_cancel_func):
+        nonlocal raise_cancel
+        raise_cancel = raise_cancel_func
+        # Cancel the Rust future, which calls the pending continuation callback.  That reschedules
+        # the task, which then raises the cancellation exception.  If the callback was already
+        # called, the task gets rescheduled anyway, so this works either way.
+        ffi_cancel(rust_future)
+        return trio.lowlevel.Abort.FAILED
+
+    # Loop and poll until we see a _UNIFFI_RUST_FUTURE_POLL_READY value
+    while True:
+        ffi_poll(
+            rust_future,
+            _uniffi_continuation_callback,
+            _UniffiContinuationHandleMap.insert(wake),
+        )
+        poll_code = await trio.lowlevel.wait_task_rescheduled(abort)
+        if poll_code == _UNIFFI_RUST_FUTURE_POLL_READY:
+            return
+{%- endif %}
+
 {%- if has_async_callback_method %}
 def _uniffi_trait_interface_call_async(make_call, uniffi_out_dropped_callback, handle_success, handle_error):
     async def make_call_and_call_callback():
@@ -89,10 +167,7 @@ def _uniffi_trait_interface_call_async(make_call, uniffi_out_dropped_callback, h
             )
         else:
             handle_success(call_result)
-    eventloop = _uniffi_get_event_loop()
-    task = asyncio.run_coroutine_threadsafe(make_call_and_call_callback(), eventloop)
-    handle = _UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.insert((eventloop, task))
-    uniffi_out_dropped_callback[0] = _UniffiForeignFutureDroppedCallbackStruct(handle, _uniffi_future_dropped_callback)
+    _uniffi_spawn_foreign_future(make_call_and_call_callback, uniffi_out_dropped_callback)
 
 def _uniffi_trait_interface_call_async_with_error(make_call, uniffi_out_dropped_callback, handle_success, handle_error, error_type, lower_error):
     async def make_call_and_call_callback():
@@ -115,19 +190,106 @@ def _uniffi_trait_interface_call_async_with_error(make_call, uniffi_out_dropped_
                 _UniffiRustCallStatus.CALL_UNEXPECTED_ERROR,
                 {{ builtin_types.string.ffi_converter_name }}.lower(repr(e)),
             )
-    eventloop = _uniffi_get_event_loop()
-    task = asyncio.run_coroutine_threadsafe(make_call_and_call_callback(), eventloop)
-    handle = _UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.insert((eventloop, task))
-    uniffi_out_dropped_callback[0] = _UniffiForeignFutureDroppedCallbackStruct(handle, _uniffi_future_dropped_callback)
+    _uniffi_spawn_foreign_future(make_call_and_call_callback, uniffi_out_dropped_callback)
 
+# Stores the function that cancels the task for each foreign future
 _UNIFFI_FOREIGN_FUTURE_HANDLE_MAP = _UniffiHandleMap()
 
-@_UNIFFI_FOREIGN_FUTURE_DROPPED_CALLBACK
-def _uniffi_future_dropped_callback(handle):
-    (eventloop, task) = _UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.remove(handle)
-    eventloop.call_soon(_uniffi_cancel_task, task)
+def _uniffi_spawn_foreign_future(async_fn, uniffi_out_dropped_callback):
+    """
+    Run `async_fn` in a new task and setup `uniffi_out_dropped_callback` to cancel it
+    """
+    {%- if async_runtime == AsyncRuntime::Auto %}
+    if _uniffi_current_async_library() == "trio":
+        cancel = _uniffi_spawn_trio_task(async_fn)
+    else:
+        cancel = _uniffi_spawn_asyncio_task(async_fn)
+    {%- else %}
+    cancel = _uniffi_spawn_asyncio_task(async_fn)
+    {%- endif %}
+    handle = _UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.insert(cancel)
+    uniffi_out_dropped_callback[0] = _UniffiForeignFutureDroppedCallbackStruct(handle, _uniffi_future_dropped_callback)
+
+def _uniffi_spawn_asyncio_task(async_fn):
+    eventloop = _uniffi_get_event_loop()
+    task = asyncio.run_coroutine_threadsafe(async_fn(), eventloop)
+    return lambda: eventloop.call_soon(_uniffi_cancel_task, task)
 
 def _uniffi_cancel_task(task):
     if not task.done():
         task.cancel()
+{%- if async_runtime == AsyncRuntime::Auto %}
+
+def _uniffi_spawn_trio_task(async_fn):
+    import trio
+
+    token = trio.lowlevel.current_trio_token()
+    cancel_scope = trio.CancelScope()
+
+    async def run():
+        with cancel_scope:
+            await async_fn()
+
+    # System tasks don't need a nursery, which we don't have.  This is fine since `async_fn`
+    # handles all exceptions itself.
+    token.run_sync_soon(trio.lowlevel.spawn_system_task, run)
+    return lambda: token.run_sync_soon(cancel_scope.cancel)
 {%- endif %}
+
+@_UNIFFI_FOREIGN_FUTURE_DROPPED_CALLBACK
+def _uniffi_future_dropped_callback(handle):
+    cancel = _UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.remove(handle)
+    cancel()
+{%- endif %}
+
+# Async iterator for streams returned from Rust
+#
+# Each item is fetched by calling `ffi_next`, which returns a Rust future that resolves to the item
+# serialized as an `Option<T>`.  Freeing the stream handle drops the Rust stream, which is how
+# cancellation works.
+class _UniffiStream:
+    def __init__(self, handle, ffi_next, ffi_free, ffi_poll, ffi_complete, ffi_future_cancel, ffi_future_free, lift_item):
+        self._handle = handle
+        self._ffi_next = ffi_next
+        self._ffi_free = ffi_free
+        self._ffi_poll = ffi_poll
+        self._ffi_complete = ffi_complete
+        self._ffi_future_cancel = ffi_future_cancel
+        self._ffi_future_free = ffi_future_free
+        self._lift_item = lift_item
+        self._lock = threading.Lock()
+
+    def __aiter__(self):
+        return self
+
+    async def __anext__(self):
+        with self._lock:
+            handle = self._handle
+        if handle is None:
+            raise StopAsyncIteration
+        (has_item, item) = await _uniffi_rust_call_async(
+            self._ffi_next(handle),
+            self._ffi_poll,
+            self._ffi_complete,
+            self._ffi_future_cancel,
+            self._ffi_future_free,
+            self._lift_item,
+            None,
+        )
+        if not has_item:
+            self._free()
+            raise StopAsyncIteration
+        return item
+
+    async def aclose(self):
+        self._free()
+
+    def _free(self):
+        with self._lock:
+            handle = self._handle
+            self._handle = None
+        if handle is not None:
+            self._ffi_free(handle)
+
+    def __del__(self):
+        self._free()
@@ -0,0 +1,45 @@
+# Closures are type-erased at the FFI level: the arguments and return value are serialized into
+# `_UniffiRustBuffer`s.  This means all closure types share a single vtable.
+
+# Python closures passed to Rust.  This stores a function that inputs the serialized arguments
+# and returns the serialized return value.
+_UNIFFI_CLOSURE_HANDLE_MAP = _UniffiHandleMap()
+
+@{{ closure_vtable.free_fn_type.0 }}
+def _uniffi_closure_free(uniffi_handle):
+    _UNIFFI_CLOSURE_HANDLE_MAP.remove(uniffi_handle)
+
+@{{ closure_vtable.call_fn_type.0 }}
+def _uniffi_closure_call(uniffi_handle, args, uniffi_out_return, uniffi_call_status_ptr):
+    call = _UNIFFI_CLOSURE_HANDLE_MAP.get(uniffi_handle)
+    def write_return_value(v):
+        uniffi_out_return[0] = v
+    _uniffi_trait_interface_call(
+        uniffi_call_status_ptr.contents,
+        lambda: call(args),
+        write_return_value,
+    )
+
+_UNIFFI_CLOSURE_VTABLE = {{ closure_vtable.struct_type.type_name }}(
+    _uniffi_closure_free,
+    _uniffi_closure_call,
+)
+# Send Rust a pointer to the VTable.  Note: this means we need to keep the struct alive forever,
+# or else bad things will happen when Rust tries to access it.
+_UniffiLib.{{ closure_vtable.init_fn.0 }}(ctypes.byref(_UNIFFI_CLOSURE_VTABLE))
+
+class _UniffiRustClosure:
+    """
+    Closure implemented in Rust.  The Rust closure is freed when this is garbage collected.
+    """
+
+    def __init__(self, handle):
+        self._handle = handle
+
+    def __del__(self):
+        handle = getattr(self, "_handle", None)
+        if handle is not None:
+            _UniffiLib.{{ closure_vtable.ffi_closure_free.0 }}(handle)
+
+    def call(self, args):
+        return _uniffi_rust_call(_UniffiLib.{{ closure_vtable.ffi_closure_call.0 }}, self._handle, args)
@@ -0,0 +1,54 @@
+class {{ closure.self_type.ffi_converter_name }}:
+    @staticmethod
+    def check_lower(value):
+        if not callable(value):
+            raise TypeError("expected a callable, not {}".format(type(value).__name__))
+
+    @staticmethod
+    def lower(value):
+        def call(args):
+            with args.consume_with_stream() as buf:
+                uniffi_args = (
+                    {%- for arg in closure.arguments %}
+                    {{ arg.ffi_converter_name }}.read(buf),
+                    {%- endfor %}
+                )
+            uniffi_return = value(*uniffi_args)
+            with _UniffiRustBuffer.alloc_with_builder() as builder:
+                {%- match closure.return_type.ty %}
+                {%- when Some(return_type) %}
+                {{ return_type.ffi_converter_name }}.check_lower(uniffi_return)
+                {{ return_type.ffi_converter_name }}.write(uniffi_return, builder)
+                {%- when None %}
+                {%- endmatch %}
+                return builder.finalize()
+        return _UNIFFI_CLOSURE_HANDLE_MAP.insert(call)
+
+    @staticmethod
+    def lift(handle):
+        rust_closure = _UniffiRustClosure(handle)
+        def uniffi_closure(*args):
+            if len(args) != {{ closure.arguments.len() }}:
+                raise TypeError("expected {{ closure.arguments.len() }} arguments, got {}".format(len(args)))
+            with _UniffiRustBuffer.alloc_with_builder() as builder:
+                {%- for arg in closure.arguments %}
+                {{ arg.ffi_converter_name }}.check_lower(args[{{ loop.index0 }}])
+                {{ arg.ffi_converter_name }}.write(args[{{ loop.index0 }}], builder)
+                {%- endfor %}
+                uniffi_args = builder.finalize()
+            with rust_closure.call(uniffi_args).consume_with_stream() as buf:
+                {%- match closure.return_type.ty %}
+                {%- when Some(return_type) %}
+                return {{ return_type.ffi_converter_name }}.read(buf)
+                {%- when None %}
+                return None
+                {%- endmatch %}
+        return uniffi_closure
+
+    @staticmethod
+    def read(buf):
+        return {{ closure.self_type.ffi_converter_name }}.lift(buf.read_u64())
+
+    @staticmethod
+    def write(value, buf):
+        buf.write_u64({{ closure.self_type.ffi_converter_name }}.lower(value))
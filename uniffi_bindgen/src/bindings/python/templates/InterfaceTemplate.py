@@ -48,13 +48,44 @@ class {{ int.name }}({{ int.base_classes|join(", ") }}):
         inst._handle = handle
         return inst
 
+{%- for prop in int.properties -%}
+{%-     let callable = prop.getter.callable %}
+    @property
+    def {{ prop.name }}(self) -> {{ prop.ty.type_name }}:
+        {{ prop.docstring|docstring(8) -}}
+        {%- filter indent(8) %}
+        {%- include "CallableBody.py" %}
+        {%- endfilter %}
+{%-     if let Some(setter) = prop.setter %}
+{%-         let callable = setter.callable %}
+
+    @{{ prop.name }}.setter
+    def {{ prop.name }}(self, {% include "CallableArgs.py" %}) -> None:
+        {%- filter indent(8) %}
+        {%- include "CallableBody.py" %}
+        {%- endfilter %}
+{%-     endif %}
+{%- endfor %}
+
 {%- for meth in int.methods -%}
+{%-     if meth.property.is_none() %}
 {%-     let callable = meth.callable %}
     {% if callable.is_async() %}async {% endif %}def {{ callable.name }}(self, {% include "CallableArgs.py" %}) -> {{ callable.return_type.type_name }}:
         {{ meth.docstring|docstring(8) -}}
         {%- filter indent(8) %}
         {%- include "CallableBody.py" %}
         {%- endfilter %}
+{%-     endif %}
+{%- endfor %}
+
+{%- for meth in int.static_methods -%}
+{%-     let callable = meth.callable %}
+    @staticmethod
+    {% if callable.is_async() %}async {% endif %}def {{ callable.name }}({% include "CallableArgs.py" %}) -> {{ callable.return_type.type_name }}:
+        {{ meth.docstring|docstring(8) -}}
+        {%- filter indent(8) %}
+        {%- include "CallableBody.py" %}
+        {%- endfilter %}
 {%- endfor %}
 
 {%- let uniffi_trait_methods = int.uniffi_trait_methods %}
@@ -27,6 +27,7 @@ import threading
 import itertools
 import traceback
 import typing
+import weakref
 {%- if has_async_fns %}
 import asyncio
 {%- endif %}
@@ -55,9 +56,22 @@ _DEFAULT = object() # type: typing.Any
 {%- include "Async.py" %}
 {%- endif %}
 
+{%- if let Some(closure_vtable) = closure_vtable %}
+{%- include "ClosureRuntime.py" %}
+{%- endif %}
+
 # Public interface members begin here.
 {% include "Types.py" %}
 
+{%- for c in constants %}
+{%- if loop.first %}
+{% endif %}
+{{ c.name }}: {{ c.ty.type_name }} = {{ c.value.py_lit }}
+{%- if c.docstring.is_some() %}
+{{ c.docstring|docstring(0) -}}
+{%- endif %}
+{%- endfor %}
+
 {%- for func in functions %}
 {%- include "TopLevelFunctionTemplate.py" %}
 {%- endfor %}
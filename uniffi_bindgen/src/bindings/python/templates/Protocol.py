@@ -1,11 +1,25 @@
 {# misnamed - a generic "abstract base class". Used as both a protocol and an ABC for traits. #}
 class {{ protocol.name }}({{ protocol.base_classes|join(", ") }}):
     {{ protocol.docstring|docstring(4) -}}
+    {%- for prop in protocol.properties.iter() %}
+    @property
+    def {{ prop.name }}(self) -> {{ prop.ty.type_name }}:
+        {{ prop.docstring|docstring(8) -}}
+        raise NotImplementedError
+    {%- if let Some(setter) = prop.setter %}
+    {%- let callable = setter.callable %}
+    @{{ prop.name }}.setter
+    def {{ prop.name }}(self, {% include "CallableArgs.py" %}) -> None:
+        raise NotImplementedError
+    {%- endif %}
+    {%- endfor %}
     {%- for meth in protocol.methods.iter() %}
+    {%- if meth.property.is_none() %}
     {%- let callable = meth.callable %}
     {% if callable.is_async() %}async {% endif %}def {{ meth.callable.name }}(self, {% include "CallableArgs.py" %}) -> {{ callable.return_type.type_name }}:
         {{ meth.docstring|docstring(8) -}}
         raise NotImplementedError
+    {%- endif %}
     {%- else %}
     pass
     {%- endfor %}
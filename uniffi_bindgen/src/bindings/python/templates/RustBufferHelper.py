@@ -43,3 +43,7 @@ class _UniffiConverterRustBuffer:
         with _UniffiRustBuffer.alloc_with_builder() as builder:
             cls.write(value, builder)
             return builder.finalize()
+
+# Integers that are too wide to pass directly over the FFI, so go through a _UniffiRustBuffer.
+class _UniffiConverterRustBufferInt(_UniffiConverterRustBuffer, _UniffiConverterPrimitiveInt):
+    pass
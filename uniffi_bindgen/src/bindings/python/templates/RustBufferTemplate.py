@@ -230,6 +230,12 @@ class _UniffiRustBufferStream:
     def read_u64(self):
         return self._unpack_from(8, ">Q")
 
+    def read_i128(self):
+        return int.from_bytes(self.read(16), "big", signed=True)
+
+    def read_u128(self):
+        return int.from_bytes(self.read(16), "big")
+
     def read_float(self):
         v = self._unpack_from(4, ">f")
         return v
@@ -299,6 +305,12 @@ class _UniffiRustBufferBuilder:
     def write_u64(self, v):
         self._pack_into(8, ">Q", v)
 
+    def write_i128(self, v):
+        self.write(v.to_bytes(16, "big", signed=True))
+
+    def write_u128(self, v):
+        self.write(v.to_bytes(16, "big"))
+
     def write_float(self, v):
         self._pack_into(4, ">f", v)
 
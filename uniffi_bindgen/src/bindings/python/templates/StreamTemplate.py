@@ -0,0 +1,32 @@
+class {{ stream.self_type.ffi_converter_name }}:
+    @staticmethod
+    def lift(value):
+        return _UniffiStream(
+            value,
+            _UniffiLib.{{ stream.ffi_stream_next.0 }},
+            _UniffiLib.{{ stream.ffi_stream_free.0 }},
+            _UniffiLib.{{ stream.async_data.ffi_rust_future_poll.0 }},
+            _UniffiLib.{{ stream.async_data.ffi_rust_future_complete.0 }},
+            _UniffiLib.{{ stream.async_data.ffi_rust_future_cancel.0 }},
+            _UniffiLib.{{ stream.async_data.ffi_rust_future_free.0 }},
+            {{ stream.self_type.ffi_converter_name }}._lift_item,
+        )
+
+    @staticmethod
+    def _lift_item(rbuf):
+        with rbuf.consume_with_stream() as buf:
+            flag = buf.read_u8()
+            if flag == 0:
+                return (False, None)
+            elif flag == 1:
+                return (True, {{ stream.inner.ffi_converter_name }}.read(buf))
+            else:
+                raise InternalError("Unexpected flag byte for stream item")
+
+    @staticmethod
+    def check_lower(value):
+        raise TypeError("Streams can't be passed to Rust")
+
+    @staticmethod
+    def lower(value):
+        raise TypeError("Streams can't be passed to Rust")
@@ -0,0 +1,22 @@
+class {{ tuple.self_type.ffi_converter_name }}(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if len(value) != {{ tuple.elements.len() }}:
+            raise ValueError("expected a tuple of length {{ tuple.elements.len() }}")
+        {%- for element in tuple.elements %}
+        {{ element.ffi_converter_name }}.check_lower(value[{{ loop.index0 }}])
+        {%- endfor %}
+
+    @classmethod
+    def write(cls, value, buf):
+        {%- for element in tuple.elements %}
+        {{ element.ffi_converter_name }}.write(value[{{ loop.index0 }}], buf)
+        {%- endfor %}
+
+    @classmethod
+    def read(cls, buf):
+        return (
+            {%- for element in tuple.elements %}
+            {{ element.ffi_converter_name }}.read(buf),
+            {%- endfor %}
+        )
@@ -29,6 +29,9 @@
 {%- when Type::Int64 %}
 {%- include "builtins/Int64.py" %}
 
+{%- when Type::Int128 %}
+{%- include "builtins/Int128.py" %}
+
 {%- when Type::UInt8 %}
 {%- include "builtins/UInt8.py" %}
 
@@ -41,6 +44,9 @@
 {%- when Type::UInt64 %}
 {%- include "builtins/UInt64.py" %}
 
+{%- when Type::UInt128 %}
+{%- include "builtins/UInt128.py" %}
+
 {%- when Type::Float32 %}
 {%- include "builtins/Float32.py" %}
 
@@ -53,11 +59,31 @@
 {%- when Type::Bytes %}
 {%- include "builtins/Bytes.py" %}
 
+{%- when Type::OwnedBytes %}
+{%- include "builtins/OwnedBytes.py" %}
+
 {%- when Type::Timestamp %}
+{%- if lossless_time_types %}
+{%- include "builtins/LosslessTimestamp.py" %}
+{%- else %}
 {%- include "builtins/Timestamp.py" %}
+{%- endif %}
 
 {%- when Type::Duration %}
+{%- if lossless_time_types %}
+{%- include "builtins/LosslessDuration.py" %}
+{%- else %}
 {%- include "builtins/Duration.py" %}
+{%- endif %}
+
+{%- when Type::Uuid %}
+{%- include "builtins/Uuid.py" %}
+
+{%- when Type::Url %}
+{%- include "builtins/Url.py" %}
+
+{%- when Type::Date %}
+{%- include "builtins/Date.py" %}
 
 {%- else %}
 {# Type::Simple shouldn't hold any other Type variants #}
@@ -78,6 +104,18 @@
 {%- when TypeDefinition::Set(set) %}
 {%- include "SetTemplate.py" %}
 
+{%- when TypeDefinition::Stream(stream) %}
+{%- include "StreamTemplate.py" %}
+
+{%- when TypeDefinition::Tuple(tuple) %}
+{%- include "TupleTemplate.py" %}
+
+{%- when TypeDefinition::Array(array) %}
+{%- include "ArrayTemplate.py" %}
+
+{%- when TypeDefinition::Closure(closure) %}
+{%- include "ClosureTemplate.py" %}
+
 {%- when TypeDefinition::Enum(e) %}
 {# For enums, there are either an error *or* an enum, they can't be both. #}
 {%- if e.self_type.is_used_as_error %}
@@ -0,0 +1,17 @@
+# Dates are passed as the number of days since 1970-01-01.
+class {{ type_node.ffi_converter_name }}(_UniffiConverterRustBuffer):
+    EPOCH = datetime.date(1970, 1, 1)
+
+    @staticmethod
+    def read(buf):
+        return {{ type_node.ffi_converter_name }}.EPOCH + datetime.timedelta(days=buf.read_i32())
+
+    @staticmethod
+    def check_lower(value):
+        # `datetime` is a subclass of `date`, but passing one would silently drop the time
+        if not isinstance(value, datetime.date) or isinstance(value, datetime.datetime):
+            raise TypeError("argument must be datetime.date, not {}".format(type(value).__name__))
+
+    @staticmethod
+    def write(value, buf):
+        buf.write_i32((value - {{ type_node.ffi_converter_name }}.EPOCH).days)
@@ -0,0 +1,12 @@
+class {{ type_node.ffi_converter_name }}(_UniffiConverterRustBufferInt):
+    CLASS_NAME = "i128"
+    VALUE_MIN = -2**127
+    VALUE_MAX = 2**127
+
+    @staticmethod
+    def read(buf):
+        return buf.read_i128()
+
+    @staticmethod
+    def write(value, buf):
+        buf.write_i128(value)
@@ -0,0 +1,45 @@
+# The Duration type, with `lossless_time_types` enabled.
+class Duration(typing.NamedTuple):
+    """A non-negative span of time, accurate to the nanosecond."""
+
+    seconds: int
+    nanoseconds: int
+
+    @classmethod
+    def from_ns(cls, ns: int) -> Duration:
+        return cls(*divmod(ns, 1_000_000_000))
+
+    def to_ns(self) -> int:
+        return self.seconds * 1_000_000_000 + self.nanoseconds
+
+    @classmethod
+    def from_timedelta(cls, value: datetime.timedelta) -> Duration:
+        seconds = value.seconds + value.days * 24 * 3600
+        return cls(seconds, value.microseconds * 1000)
+
+    def to_timedelta(self) -> datetime.timedelta:
+        """Convert to a `timedelta`, this truncates the duration to the microsecond"""
+        return datetime.timedelta(seconds=self.seconds, microseconds=self.nanoseconds // 1000)
+
+class {{ type_node.ffi_converter_name }}(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        seconds = buf.read_u64()
+        nanoseconds = buf.read_u32()
+        return Duration(seconds, nanoseconds)
+
+    @staticmethod
+    def check_lower(value):
+        if not isinstance(value, Duration):
+            raise TypeError("'{}' is not a Duration".format(type(value).__name__))
+        if value.seconds < 0:
+            raise ValueError("Invalid duration, must be non-negative")
+        if not 0 <= value.nanoseconds < 1_000_000_000:
+            raise ValueError("Duration nanoseconds must be between 0 and 999999999")
+        if value.seconds >= 2**64:
+            raise ValueError("Duration overflow, exceeds max bounds supported by Uniffi")
+
+    @staticmethod
+    def write(value, buf):
+        buf.write_u64(value.seconds)
+        buf.write_u32(value.nanoseconds)
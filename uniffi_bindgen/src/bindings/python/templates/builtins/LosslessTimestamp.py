@@ -0,0 +1,61 @@
+# The Timestamp type, with `lossless_time_types` enabled.
+class Timestamp(typing.NamedTuple):
+    """
+    A point in time, accurate to the nanosecond.
+
+    `seconds` counts from the Unix epoch and `nanoseconds` is always added to it, so half a
+    second before the epoch is `Timestamp(seconds=-1, nanoseconds=500_000_000)`.  This means
+    that timestamps compare in chronological order.
+    """
+
+    seconds: int
+    nanoseconds: int
+
+    @classmethod
+    def from_ns(cls, ns: int) -> Timestamp:
+        """Create a timestamp from a number of nanoseconds since the epoch, like `time.time_ns()`"""
+        return cls(*divmod(ns, 1_000_000_000))
+
+    def to_ns(self) -> int:
+        return self.seconds * 1_000_000_000 + self.nanoseconds
+
+    @classmethod
+    def from_datetime(cls, value: datetime.datetime) -> Timestamp:
+        """Create a timestamp from a timezone-aware `datetime`"""
+        delta = value - datetime.datetime.fromtimestamp(0, datetime.timezone.utc)
+        seconds = delta.seconds + delta.days * 24 * 3600
+        return cls(seconds, delta.microseconds * 1000)
+
+    def to_datetime(self) -> datetime.datetime:
+        """Convert to a UTC `datetime`, this truncates the timestamp to the microsecond"""
+        return datetime.datetime.fromtimestamp(0, datetime.timezone.utc) + datetime.timedelta(seconds=self.seconds, microseconds=self.nanoseconds // 1000)
+
+class {{ type_node.ffi_converter_name }}(_UniffiConverterRustBuffer):
+    # Rust sends the seconds and nanoseconds from the epoch to the time, so times before the
+    # epoch have both parts counted backwards.  We count the nanoseconds forwards, like Python's
+    # `divmod()`, so we need to convert between the two.
+    @staticmethod
+    def read(buf):
+        seconds = buf.read_i64()
+        nanoseconds = buf.read_u32()
+        if seconds < 0 and nanoseconds != 0:
+            return Timestamp(seconds - 1, 1_000_000_000 - nanoseconds)
+        return Timestamp(seconds, nanoseconds)
+
+    @staticmethod
+    def check_lower(value):
+        if not isinstance(value, Timestamp):
+            raise TypeError("'{}' is not a Timestamp".format(type(value).__name__))
+        if not 0 <= value.nanoseconds < 1_000_000_000:
+            raise ValueError("Timestamp nanoseconds must be between 0 and 999999999")
+        if not -2**63 <= value.seconds < 2**63:
+            raise ValueError("Timestamp overflow, exceeds max bounds supported by Uniffi")
+
+    @staticmethod
+    def write(value, buf):
+        seconds, nanoseconds = value
+        if seconds < 0 and nanoseconds != 0:
+            seconds += 1
+            nanoseconds = 1_000_000_000 - nanoseconds
+        buf.write_i64(seconds)
+        buf.write_u32(nanoseconds)
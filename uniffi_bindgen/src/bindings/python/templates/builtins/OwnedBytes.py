@@ -0,0 +1,39 @@
+class {{ type_node.ffi_converter_name }}:
+    @staticmethod
+    def check_lower(value):
+        try:
+            memoryview(value)
+        except TypeError:
+            raise TypeError("a bytes-like object is required, not {!r}".format(type(value).__name__))
+
+    # When nested inside another type, owned bytes are serialized the same way as `bytes`.
+    @staticmethod
+    def read(buf):
+        size = buf.read_i32()
+        if size < 0:
+            raise InternalError("Unexpected negative byte string length")
+        return memoryview(buf.read(size))
+
+    @staticmethod
+    def write(value, buf):
+        value = value if isinstance(value, bytes) else bytes(value)
+        buf.write_i32(len(value))
+        buf.write(value)
+
+    # Wrap the Rust allocation rather than copying it.
+    # The `RustBuffer` is freed once the memoryview, and any views derived from it, are garbage collected.
+    @staticmethod
+    def lift(rbuf):
+        if not rbuf.data:
+            return memoryview(b"")
+        array = (ctypes.c_ubyte * rbuf.len).from_address(ctypes.addressof(rbuf.data.contents))
+        weakref.finalize(array, rbuf.free)
+        return memoryview(array).cast("B")
+
+    # Rust takes ownership of the `RustBuffer`, so `bytes` values are only copied once.
+    @staticmethod
+    def lower(value):
+        value = value if isinstance(value, bytes) else bytes(value)
+        with _UniffiRustBuffer.alloc_with_builder() as builder:
+            builder.write(value)
+            return builder.finalize()
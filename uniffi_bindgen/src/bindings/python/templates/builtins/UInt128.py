@@ -0,0 +1,12 @@
+class {{ type_node.ffi_converter_name }}(_UniffiConverterRustBufferInt):
+    CLASS_NAME = "u128"
+    VALUE_MIN = 0
+    VALUE_MAX = 2**128
+
+    @staticmethod
+    def read(buf):
+        return buf.read_u128()
+
+    @staticmethod
+    def write(value, buf):
+        buf.write_u128(value)
@@ -0,0 +1,13 @@
+class {{ type_node.ffi_converter_name }}(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return uuid.UUID(bytes=buf.read(16))
+
+    @staticmethod
+    def check_lower(value):
+        if not isinstance(value, uuid.UUID):
+            raise TypeError("argument must be uuid.UUID, not {}".format(type(value).__name__))
+
+    @staticmethod
+    def write(value, buf):
+        buf.write(value.bytes)
@@ -0,0 +1,9 @@
+{#- expects vars `callable`, `docstring` and `receiver`, which is `self`, `cls` or empty -#}
+{% if callable.is_async() %}async {% endif %}def {{ callable.name }}({{ receiver }}
+{%- if !receiver.is_empty() && !callable.arguments.is_empty() %}, {% endif %}
+{%- include "stubs/CallableArgs.pyi" %}) -> {{ callable.return_type.type_name }}:
+{%- if docstring.is_some() %}
+    {{ docstring|docstring(4) -}}
+    ...
+{%- else %} ...
+{%- endif %}
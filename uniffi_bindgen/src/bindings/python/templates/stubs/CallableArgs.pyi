@@ -0,0 +1,5 @@
+{%- for arg in callable.arguments -%}
+    {{ arg.name }}: {{ arg.param_type_name() }}
+    {%- if arg.default.is_some() %} = ...{% endif %}
+    {%- if !loop.last %}, {% endif -%}
+{%- endfor %}
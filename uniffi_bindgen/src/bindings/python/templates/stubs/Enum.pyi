@@ -0,0 +1,60 @@
+{%- let type_name = e.self_type.type_name %}
+{%- if e.is_flat %}
+
+class {{ type_name }}(enum.Enum):
+    {%- if e.docstring.is_some() %}
+    {{ e.docstring|docstring(4)|trim }}
+    {%- endif %}
+    {%- for variant in e.variants %}
+    {{ variant.name }} = {{ variant.discr.py_lit }}
+    {%- if variant.docstring.is_some() %}
+    {{ variant.docstring|docstring(4)|trim }}
+    {%- endif %}
+    {%- else %}
+    ...
+    {%- endfor %}
+
+    {%- for meth in e.methods %}
+    {%- let callable = meth.callable %}
+    {%- let docstring = meth.docstring %}
+    {%- let receiver = "self" %}
+    {% filter indent(4) %}{% include "stubs/Callable.pyi" %}{% endfilter %}
+    {%- endfor %}
+{%- else %}
+
+class {{ type_name }}:
+    {%- if e.docstring.is_some() %}
+    {{ e.docstring|docstring(4)|trim }}
+    {%- endif %}
+    {%- for variant in e.variants %}
+    {{ variant.name }} = {{ variant.class_name_py }}
+    {%- else %}
+    ...
+    {%- endfor %}
+
+    {%- for variant in e.variants %}
+    def is_{{ variant.name }}(self) -> bool: ...
+    {%- if variant.name != variant.name.to_snake_case() %}
+    def is_{{ variant.name.to_snake_case() }}(self) -> bool: ...
+    {%- endif %}
+    {%- endfor %}
+
+    {%- for meth in e.methods %}
+    {%- let callable = meth.callable %}
+    {%- let docstring = meth.docstring %}
+    {%- let receiver = "self" %}
+    {% filter indent(4) %}{% include "stubs/Callable.pyi" %}{% endfilter %}
+    {%- endfor %}
+
+{%- let uniffi_trait_methods = e.uniffi_trait_methods %}
+{%- let with_defaults = true %}
+{%- for variant in e.variants %}
+
+class {{ variant.class_name_py }}({{ type_name }}):
+    {%- if variant.docstring.is_some() %}
+    {{ variant.docstring|docstring(4)|trim }}
+    {%- endif %}
+    {%- include "stubs/VariantFields.pyi" %}
+    {%- include "stubs/UniffiTraitImpls.pyi" %}
+{%- endfor %}
+{%- endif %}
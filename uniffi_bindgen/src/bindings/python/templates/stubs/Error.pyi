@@ -0,0 +1,37 @@
+{%- let type_name = e.self_type.type_name %}
+{#-
+ # The variant classes are nested in the error class at runtime, which type checkers can't
+ # describe in a single class statement.  Instead, we define each variant at the top-level, with a
+ # private name, and alias it in the error class.
+ #}
+
+class {{ type_name }}(Exception):
+    {%- if e.docstring.is_some() %}
+    {{ e.docstring|docstring(4)|trim }}
+    {%- endif %}
+    {%- for variant in e.variants %}
+    {{ variant.name }} = _{{ variant.class_name_py }}
+    {%- else %}
+    ...
+    {%- endfor %}
+
+{%- let with_defaults = false %}
+{%- for variant in e.variants %}
+
+class _{{ variant.class_name_py }}({{ type_name }}):
+    {%- if variant.docstring.is_some() %}
+    {{ variant.docstring|docstring(4)|trim }}
+    {%- endif %}
+    {%- if e.is_flat %}
+    ...
+    {%- else %}
+    {%- include "stubs/VariantFields.pyi" %}
+
+    {%- for meth in e.methods %}
+    {%- let callable = meth.callable %}
+    {%- let docstring = meth.docstring %}
+    {%- let receiver = "self" %}
+    {% filter indent(4) %}{% include "stubs/Callable.pyi" %}{% endfilter %}
+    {%- endfor %}
+    {%- endif %}
+{%- endfor %}
@@ -0,0 +1,62 @@
+{%- let protocol = int.protocol %}
+{%- include "stubs/Protocol.pyi" %}
+
+class {{ int.name }}({{ int.base_classes|join(", ") }}):
+    {%- if int.docstring.is_some() %}
+    {{ int.docstring|docstring(4)|trim }}
+    {%- endif %}
+    {%- if int.constructors.is_empty() && int.properties.is_empty() && int.methods.is_empty() && int.static_methods.is_empty() %}
+    ...
+    {%- endif %}
+    {%- for cons in int.constructors %}
+    {%- let callable = cons.callable %}
+    {%- let docstring = cons.docstring %}
+    {%- if callable.is_primary_constructor() %}
+    def __init__(self
+    {%- if !callable.arguments.is_empty() %}, {% include "stubs/CallableArgs.pyi" %}{% endif %}) -> None:
+    {%- if docstring.is_some() %}
+        {{ docstring|docstring(8) -}}
+        ...
+    {%- else %} ...
+    {%- endif %}
+    {%- else %}
+    {%- let receiver = "cls" %}
+    @classmethod
+    {% filter indent(4) %}{% include "stubs/Callable.pyi" %}{% endfilter %}
+    {%- endif %}
+    {%- endfor %}
+
+    {%- for prop in int.properties %}
+    @property
+    def {{ prop.name }}(self) -> {{ prop.ty.type_name }}:
+    {%- if prop.docstring.is_some() %}
+        {{ prop.docstring|docstring(8) -}}
+        ...
+    {%- else %} ...
+    {%- endif %}
+    {%- if let Some(setter) = prop.setter %}
+    {%- let callable = setter.callable %}
+    @{{ prop.name }}.setter
+    def {{ prop.name }}(self, {% include "stubs/CallableArgs.pyi" %}) -> None: ...
+    {%- endif %}
+    {%- endfor %}
+
+    {%- for meth in int.methods %}
+    {%- if meth.property.is_none() %}
+    {%- let callable = meth.callable %}
+    {%- let docstring = meth.docstring %}
+    {%- let receiver = "self" %}
+    {% filter indent(4) %}{% include "stubs/Callable.pyi" %}{% endfilter %}
+    {%- endif %}
+    {%- endfor %}
+
+    {%- for meth in int.static_methods %}
+    {%- let callable = meth.callable %}
+    {%- let docstring = meth.docstring %}
+    {%- let receiver = "" %}
+    @staticmethod
+    {% filter indent(4) %}{% include "stubs/Callable.pyi" %}{% endfilter %}
+    {%- endfor %}
+
+    {%- let uniffi_trait_methods = int.uniffi_trait_methods %}
+    {%- include "stubs/UniffiTraitImpls.pyi" %}
@@ -0,0 +1,41 @@
+{{ module.docstring|docstring(0) -}}
+# This file was autogenerated by some hot garbage in the `uniffi` crate.
+# Trust me, you don't want to mess with it!
+
+# Type stubs for `{{ module.name }}.py`.
+#
+# Type checkers read these instead of the module itself, which lets us describe the public API
+# with precise types without having to annotate all of the FFI helper code.
+
+import datetime
+import enum
+import typing
+{%- for import in module.imports %}
+{{ import|import_statement }}
+{%- endfor %}
+
+class InternalError(Exception): ...
+{%- include "stubs/Types.pyi" %}
+
+{%- for c in module.constants %}
+{%- if loop.first %}
+{% endif %}
+{{ c.name }}: {{ c.ty.type_name }}
+{%- if c.docstring.is_some() %}
+{{ c.docstring|docstring(0) -}}
+{%- endif %}
+{%- endfor %}
+
+{%- for func in module.functions %}
+{%- let callable = func.callable %}
+{%- let docstring = func.docstring %}
+{%- let receiver = "" %}
+
+{% include "stubs/Callable.pyi" %}
+{%- endfor %}
+
+__all__ = [
+    {%- for name in module.exported_names %}
+    "{{ name }}",
+    {%- endfor %}
+]
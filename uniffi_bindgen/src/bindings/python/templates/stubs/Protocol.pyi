@@ -0,0 +1,26 @@
+{#- expects var `protocol` #}
+
+class {{ protocol.name }}
+{%- if !protocol.base_classes.is_empty() %}({{ protocol.base_classes|join(", ") }}){% endif %}:
+    {%- if protocol.docstring.is_some() %}
+    {{ protocol.docstring|docstring(4)|trim }}
+    {%- endif %}
+    {%- for prop in protocol.properties %}
+    @property
+    def {{ prop.name }}(self) -> {{ prop.ty.type_name }}: ...
+    {%- if let Some(setter) = prop.setter %}
+    {%- let callable = setter.callable %}
+    @{{ prop.name }}.setter
+    def {{ prop.name }}(self, {% include "stubs/CallableArgs.pyi" %}) -> None: ...
+    {%- endif %}
+    {%- endfor %}
+    {%- for meth in protocol.methods %}
+    {%- if meth.property.is_none() %}
+    {%- let callable = meth.callable %}
+    {%- let docstring = meth.docstring %}
+    {%- let receiver = "self" %}
+    {% filter indent(4) %}{% include "stubs/Callable.pyi" %}{% endfilter %}
+    {%- endif %}
+    {%- else %}
+    ...
+    {%- endfor %}
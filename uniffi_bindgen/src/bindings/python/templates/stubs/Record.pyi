@@ -0,0 +1,25 @@
+
+
+class {{ rec.self_type.type_name }}:
+    {%- if rec.docstring.is_some() %}
+    {{ rec.docstring|docstring(4)|trim }}
+    {%- endif %}
+    {%- for field in rec.fields %}
+    {{ field.name }}: {{ field.ty.type_name }}
+    {%- endfor %}
+    def __init__(self
+    {%- if !rec.fields.is_empty() %}, *
+    {%- for field in rec.fields %}, {{ field.name }}: {{ field.ty.type_name }}
+    {%- if field.default.is_some() %} = ...{% endif %}
+    {%- endfor %}
+    {%- endif %}) -> None: ...
+
+    {%- for meth in rec.methods %}
+    {%- let callable = meth.callable %}
+    {%- let docstring = meth.docstring %}
+    {%- let receiver = "self" %}
+    {% filter indent(4) %}{% include "stubs/Callable.pyi" %}{% endfilter %}
+    {%- endfor %}
+
+    {%- let uniffi_trait_methods = rec.uniffi_trait_methods %}
+    {%- include "stubs/UniffiTraitImpls.pyi" %}
@@ -0,0 +1,84 @@
+{%- for type_def in module.type_definitions %}
+{#-
+ # Only user-facing types need a stub, the FFI converters are private to the module.
+ #}
+{%- match type_def %}
+
+{%- when TypeDefinition::Simple(type_node) %}
+{%- match type_node.ty %}
+
+{%- when Type::Timestamp %}
+{%- if module.lossless_time_types %}
+
+class Timestamp(typing.NamedTuple):
+    seconds: int
+    nanoseconds: int
+    @classmethod
+    def from_ns(cls, ns: int) -> Timestamp: ...
+    def to_ns(self) -> int: ...
+    @classmethod
+    def from_datetime(cls, value: datetime.datetime) -> Timestamp: ...
+    def to_datetime(self) -> datetime.datetime: ...
+{%- else %}
+
+Timestamp = datetime.datetime
+{%- endif %}
+
+{%- when Type::Duration %}
+{%- if module.lossless_time_types %}
+
+class Duration(typing.NamedTuple):
+    seconds: int
+    nanoseconds: int
+    @classmethod
+    def from_ns(cls, ns: int) -> Duration: ...
+    def to_ns(self) -> int: ...
+    @classmethod
+    def from_timedelta(cls, value: datetime.timedelta) -> Duration: ...
+    def to_timedelta(self) -> datetime.timedelta: ...
+{%- else %}
+
+Duration = datetime.timedelta
+{%- endif %}
+
+{%- else %}
+{%- endmatch %}
+
+{%- when TypeDefinition::Enum(e) %}
+{%- if e.self_type.is_used_as_error %}
+{%- include "stubs/Error.pyi" %}
+{%- else %}
+{%- include "stubs/Enum.pyi" %}
+{%- endif %}
+
+{%- when TypeDefinition::Record(rec) %}
+{%- include "stubs/Record.pyi" %}
+
+{%- when TypeDefinition::Interface(int) %}
+{%- include "stubs/Interface.pyi" %}
+
+{%- when TypeDefinition::CallbackInterface(cbi) %}
+{%- let protocol = cbi.protocol %}
+{%- include "stubs/Protocol.pyi" %}
+
+{%- when TypeDefinition::Custom(custom) %}
+
+{% match custom.config %}
+{%- when None %}
+{{- custom.name }} = {{ custom.builtin.type_name }}
+{%- when Some(config) %}
+{%- match config.type_name %}
+{%- when Some(type_name) %}
+{{- custom.name }} = {{ type_name }}
+{%- when None %}
+{#- The lifted type is only known at runtime #}
+{{- custom.name }} = typing.Any
+{%- endmatch %}
+{%- endmatch %}
+{%- if custom.docstring.is_some() %}
+{{ custom.docstring|docstring(0)|trim }}
+{%- endif %}
+
+{%- else %}
+{%- endmatch %}
+{%- endfor %}
@@ -0,0 +1,23 @@
+{#- expects var `uniffi_trait_methods`, renders methods indented for a class body #}
+{%- if let Some(fmt) = uniffi_trait_methods.debug_fmt %}
+    def __repr__(self) -> {{ fmt.callable.return_type.type_name }}: ...
+{%- endif %}
+{%- if let Some(fmt) = uniffi_trait_methods.display_fmt %}
+    def __str__(self) -> {{ fmt.callable.return_type.type_name }}: ...
+{%- endif %}
+{%- if let Some(eq) = uniffi_trait_methods.eq_eq %}
+    def __eq__(self, other: object) -> {{ eq.callable.return_type.type_name }}: ...
+{%- endif %}
+{%- if let Some(ne) = uniffi_trait_methods.eq_ne %}
+    def __ne__(self, other: object) -> {{ ne.callable.return_type.type_name }}: ...
+{%- endif %}
+{%- if let Some(hash) = uniffi_trait_methods.hash_hash %}
+    def __hash__(self) -> {{ hash.callable.return_type.type_name }}: ...
+{%- endif %}
+{%- if let Some(cmp) = uniffi_trait_methods.ord_cmp %}
+{%-     let self_type_name = cmp.callable.self_type().unwrap().type_name %}
+    def __lt__(self, other: {{ self_type_name }}) -> bool: ...
+    def __le__(self, other: {{ self_type_name }}) -> bool: ...
+    def __gt__(self, other: {{ self_type_name }}) -> bool: ...
+    def __ge__(self, other: {{ self_type_name }}) -> bool: ...
+{%- endif %}
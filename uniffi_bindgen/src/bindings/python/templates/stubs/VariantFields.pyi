@@ -0,0 +1,14 @@
+{#- expects vars `variant` and `with_defaults`, renders its fields and constructor indented for a class body #}
+{%- if variant.has_unnamed_fields() %}
+    def __init__(self
+    {%- for field in variant.fields %}, _{{ loop.index0 }}: {{ field.ty.type_name }}{% endfor %}, /) -> None: ...
+    def __getitem__(self, index: int) -> typing.Any: ...
+{%- else %}
+    {%- for field in variant.fields %}
+    {{ field.name }}: {{ field.ty.type_name }}
+    {%- endfor %}
+    def __init__(self
+    {%- for field in variant.fields %}, {{ field.name }}: {{ field.ty.type_name }}
+    {%- if with_defaults && field.default.is_some() %} = ...{% endif %}
+    {%- endfor %}) -> None: ...
+{%- endif %}
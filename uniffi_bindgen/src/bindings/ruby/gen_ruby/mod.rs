@@ -23,6 +23,13 @@ fn is_reserved_word(word: &str) -> bool {
     RESERVED_WORDS.contains(&word)
 }
 
+/// Is this the `u8` element type of a byte array?
+///
+/// Fixed-size arrays of bytes are represented as binary strings, like `Bytes`.
+pub fn is_byte(t: &Type) -> bool {
+    matches!(t, Type::UInt8)
+}
+
 /// Get the canonical, unique-within-this-component name for a type.
 ///
 /// When generating helper code for foreign language bindings, it's sometimes useful to be
@@ -40,10 +47,13 @@ pub fn canonical_name(t: &Type) -> String {
         Type::UInt32 => "u32".into(),
         Type::Int64 => "i64".into(),
         Type::UInt64 => "u64".into(),
+        Type::Int128 => "i128".into(),
+        Type::UInt128 => "u128".into(),
         Type::Float32 => "f32".into(),
         Type::Float64 => "f64".into(),
         Type::String => "string".into(),
         Type::Bytes => "bytes".into(),
+        Type::OwnedBytes => "owned_bytes".into(),
         Type::Boolean => "bool".into(),
         // API defined types.
         // Note that these all get unique names, and the parser ensures that the names do not
@@ -57,6 +67,9 @@ pub fn canonical_name(t: &Type) -> String {
         Type::CallbackInterface { name, .. } => format!("CallbackInterface{name}"),
         Type::Timestamp => "Timestamp".into(),
         Type::Duration => "Duration".into(),
+        Type::Uuid => "Uuid".into(),
+        Type::Url => "Url".into(),
+        Type::Date => "Date".into(),
         // Recursive types.
         // These add a prefix to the name of the underlying type.
         // The component API definition cannot give names to recursive types, so as long as the
@@ -64,7 +77,27 @@ pub fn canonical_name(t: &Type) -> String {
         // acccidentally generating name collisions.
         Type::Optional { inner_type } => format!("Optional{}", canonical_name(inner_type)),
         Type::Sequence { inner_type } => format!("Sequence{}", canonical_name(inner_type)),
+        Type::Array { inner_type, len } => format!(
+            "Array{len}{}",
+            canonical_name(inner_type).to_upper_camel_case()
+        ),
         Type::Set { inner_type } => format!("Set{}", canonical_name(inner_type)),
+        Type::Stream { inner_type } => format!("Stream{}", canonical_name(inner_type)),
+        Type::Closure {
+            arguments,
+            return_type,
+        } => format!(
+            "Closure{}{}{}",
+            arguments.len(),
+            arguments
+                .iter()
+                .map(|t| canonical_name(t).to_upper_camel_case())
+                .collect::<String>(),
+            match return_type {
+                Some(t) => canonical_name(t).to_upper_camel_case(),
+                None => "Void".to_string(),
+            }
+        ),
         Type::Map {
             key_type,
             value_type,
@@ -73,6 +106,14 @@ pub fn canonical_name(t: &Type) -> String {
             canonical_name(key_type).to_upper_camel_case(),
             canonical_name(value_type).to_upper_camel_case()
         ),
+        Type::Tuple { types } => format!(
+            "Tuple{}{}",
+            types.len(),
+            types
+                .iter()
+                .map(|t| canonical_name(t).to_upper_camel_case())
+                .collect::<String>()
+        ),
         Type::Custom { name, .. } => format!("Type{name}"),
         Type::Box { inner_type } => canonical_name(inner_type),
     }
@@ -305,13 +346,15 @@ mod filters {
             | Type::Int32
             | Type::UInt32
             | Type::Int64
-            | Type::UInt64 => "0".to_string(),
+            | Type::UInt64
+            | Type::Int128
+            | Type::UInt128 => "0".to_string(),
             Type::Float32 | Type::Float64 => "0.0".to_string(),
             Type::Boolean => "false".to_string(),
             Type::String => "\"\"".to_string(),
             Type::Optional { .. } => "nil".to_string(),
             Type::Sequence { .. } => "[]".to_string(),
-            Type::Bytes => "\"\".b".to_string(),
+            Type::Bytes | Type::OwnedBytes => "\"\".b".to_string(),
             Type::Map { .. } => "{}".to_string(),
             Type::Set { .. } => "Set.new".to_string(),
             // Named types with no-arg constructors
@@ -328,6 +371,12 @@ mod filters {
         })
     }
 
+    /// Render a literal, for example the value of a constant.
+    #[askama::filter_fn]
+    pub fn literal_rb(literal: &Literal, _: &dyn askama::Values) -> Result<String, askama::Error> {
+        literal_rb_inner(literal)
+    }
+
     /// Render the Ruby default value for a field, handling both `Default` and `Literal` variants.
     #[askama::filter_fn]
     pub fn field_default_rb(
@@ -413,6 +462,8 @@ mod filters {
             Type::UInt16 => format!("::{ns}::uniffi_in_range({nm}, \"u16\", 0, 2**16)"),
             Type::UInt32 => format!("::{ns}::uniffi_in_range({nm}, \"u32\", 0, 2**32)"),
             Type::UInt64 => format!("::{ns}::uniffi_in_range({nm}, \"u64\", 0, 2**64)"),
+            Type::Int128 => format!("::{ns}::uniffi_in_range({nm}, \"i128\", -2**127, 2**127)"),
+            Type::UInt128 => format!("::{ns}::uniffi_in_range({nm}, \"u128\", 0, 2**128)"),
             Type::Float32
             | Type::Float64
             | Type::Object { .. }
@@ -420,10 +471,13 @@ mod filters {
             | Type::Record { .. }
             | Type::Timestamp
             | Type::Duration
+            | Type::Uuid
+            | Type::Url
+            | Type::Date
             | Type::CallbackInterface { .. } => nm.to_string(),
             Type::Boolean => format!("{nm} ? true : false"),
             Type::String => format!("::{ns}::uniffi_utf8({nm})"),
-            Type::Bytes => format!("::{ns}::uniffi_bytes({nm})"),
+            Type::Bytes | Type::OwnedBytes => format!("::{ns}::uniffi_bytes({nm})"),
             Type::Optional { inner_type: t } => {
                 format!(
                     "({nm} ? {} : nil)",
@@ -438,6 +492,17 @@ mod filters {
                     format!("{nm}.map {{ |v| {coerce_code} }}")
                 }
             }
+            Type::Array { inner_type: t, .. } if is_byte(t) => {
+                format!("::{ns}::uniffi_bytes({nm})")
+            }
+            Type::Array { inner_type: t, .. } => {
+                let coerce_code = coerce_rb_inner("v", ns, t, custom_types)?;
+                if coerce_code == "v" {
+                    nm.to_string()
+                } else {
+                    format!("{nm}.map {{ |v| {coerce_code} }}")
+                }
+            }
             Type::Set { inner_type: t } => {
                 let coerce_code = coerce_rb_inner("v", ns, t, custom_types)?;
                 if coerce_code == "v" {
@@ -446,6 +511,22 @@ mod filters {
                     format!("{nm}.map {{ |v| {coerce_code} }}.to_set")
                 }
             }
+            Type::Tuple { types } => {
+                let coerce_codes = types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, t)| coerce_rb_inner(format!("{nm}[{i}]"), ns, t, custom_types))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if coerce_codes
+                    .iter()
+                    .enumerate()
+                    .all(|(i, code)| *code == format!("{nm}[{i}]"))
+                {
+                    nm.to_string()
+                } else {
+                    format!("[{}]", coerce_codes.join(", "))
+                }
+            }
             Type::Map {
                 key_type: kt,
                 value_type: vt,
@@ -462,6 +543,8 @@ mod filters {
                 }
             }
             Type::Box { inner_type } => coerce_rb_inner(nm, ns, inner_type, custom_types)?,
+            Type::Stream { .. } => return Err(streams_not_supported()),
+            Type::Closure { .. } => return Err(closures_not_supported()),
             Type::Custom { name, builtin, .. } => {
                 // For config-backed custom types, the user passes a custom-typed values;
                 // skip builtin coercion (the lower expression handles conversion).
@@ -490,7 +573,9 @@ mod filters {
             | Type::Record { .. }
             | Type::Optional { .. }
             | Type::Sequence { .. }
+            | Type::Array { .. }
             | Type::Set { .. }
+            | Type::Tuple { .. }
             | Type::Map { .. } => {
                 format!("RustBuffer.check_lower_{}({})", canonical_name(type_), nm)
             }
@@ -571,15 +656,25 @@ mod filters {
             | Type::Record { .. }
             | Type::Optional { .. }
             | Type::Sequence { .. }
+            | Type::Array { .. }
             | Type::Set { .. }
             | Type::Timestamp
             | Type::String
             | Type::Bytes
+            | Type::OwnedBytes
             | Type::Duration
+            | Type::Uuid
+            | Type::Url
+            | Type::Date
+            | Type::Int128
+            | Type::UInt128
+            | Type::Tuple { .. }
             | Type::Map { .. } => {
                 format!("RustBuffer.alloc_from_{}({})", canonical_name(type_), nm)
             }
             Type::Box { .. } => unreachable!(),
+            Type::Stream { .. } => return Err(streams_not_supported()),
+            Type::Closure { .. } => return Err(closures_not_supported()),
             Type::Custom { .. } => unreachable!("Custom types should be handled before dispatch"),
         })
     }
@@ -660,13 +755,23 @@ mod filters {
             Type::Record { .. }
             | Type::Optional { .. }
             | Type::Sequence { .. }
+            | Type::Array { .. }
             | Type::Set { .. }
             | Type::Timestamp
             | Type::String
             | Type::Bytes
+            | Type::OwnedBytes
             | Type::Duration
+            | Type::Uuid
+            | Type::Url
+            | Type::Date
+            | Type::Int128
+            | Type::UInt128
+            | Type::Tuple { .. }
             | Type::Map { .. } => format!("{nm}.consume_into_{}", canonical_name(type_)),
             Type::Box { .. } => unreachable!(),
+            Type::Stream { .. } => return Err(streams_not_supported()),
+            Type::Closure { .. } => return Err(closures_not_supported()),
             Type::Custom { name, builtin, .. } => {
                 let lifted = lift_rb_inner(nm, builtin, custom_types)?;
                 if let Some(cfg) = custom_types.get(name) {
@@ -678,6 +783,14 @@ mod filters {
         })
     }
 
+    fn streams_not_supported() -> askama::Error {
+        askama::Error::Custom(anyhow::anyhow!("Streams are not supported by Ruby bindings").into())
+    }
+
+    fn closures_not_supported() -> askama::Error {
+        askama::Error::Custom(anyhow::anyhow!("Closures are not supported by Ruby bindings").into())
+    }
+
     #[askama::filter_fn]
     pub fn lift_rb(
         nm: &str,
@@ -735,6 +848,7 @@ mod test_type {
         assert_eq!(canonical_name(&Type::UInt8), "u8");
         assert_eq!(canonical_name(&Type::String), "string");
         assert_eq!(canonical_name(&Type::Bytes), "bytes");
+        assert_eq!(canonical_name(&Type::OwnedBytes), "owned_bytes");
         assert_eq!(
             canonical_name(&Type::Optional {
                 inner_type: Box::new(Type::Sequence {
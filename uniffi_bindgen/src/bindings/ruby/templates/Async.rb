@@ -100,9 +100,14 @@ class UniffiInternalCancelled < RuntimeError; end
 # User callback that raises it will be considered a Rust-side cancellation.
 private_constant :UniffiInternalCancelled
 
-# Handle map for storing Threads executing foreign async callbacks.
+# Handle map for storing the workers (Threads or UniffiCallbackTasks) executing foreign async callbacks.
 UNIFFI_FOREIGN_FUTURE_HANDLE_MAP = UniffiHandleMap.new
 
+# Reactor used to run foreign async callbacks, see `uniffi_set_fiber_scheduler`.
+# When this is nil, each callback gets its own Thread.
+UNIFFI_CALLBACK_REACTOR_LOCK = Mutex.new
+@uniffi_callback_reactor = nil
+
 # One-shot claim flag: the first caller to `claim!` wins; all subsequent callers
 # are no-ops. Used to enforce the at-most-once contract on uniffi_future_callback.
 class UniffiOnceFlag
@@ -121,22 +126,155 @@ class UniffiOnceFlag
   end
 end
 
+# A foreign async callback running in a fiber on a UniffiCallbackReactor.
+# Implements the parts of the Thread interface that UNIFFI_FOREIGN_FUTURE_DROPPED_CALLBACK uses.
+class UniffiCallbackTask
+  attr_reader :fiber
+
+  def initialize(reactor, block)
+    @reactor = reactor
+    @block = block
+    @fiber = nil
+  end
+
+  # Called on the reactor thread, inside the fiber scheduled for this task.
+  def run
+    @fiber = Fiber.current
+    @block.call
+  end
+
+  def alive?
+    @fiber.nil? || @fiber.alive?
+  end
+
+  # Mirrors Thread#raise. The exception is raised in the task's fiber by the reactor thread,
+  # since fibers can't be resumed from other threads.
+  def raise(exception_class, message)
+    @reactor.interrupt(self, exception_class.new(message))
+  end
+end
+
+# Runs foreign async callbacks as non-blocking fibers on a single thread, driven by a Fiber::Scheduler.
+#
+# Rust invokes callbacks from arbitrary threads, so work is posted to a queue and the reactor
+# thread is woken up through a pipe. Waiting on the pipe goes through the scheduler's io_wait hook,
+# which every Fiber::Scheduler implements, so the reactor doesn't depend on the block/unblock hooks
+# working across threads.
+class UniffiCallbackReactor
+  def initialize(scheduler_factory)
+    @jobs = Thread::Queue.new
+    # Guards `@shut_down`, so that no task can be posted after the `:shutdown` job.
+    @lock = Mutex.new
+    @shut_down = false
+    @rd, @wr = IO.pipe
+    @wr.sync = true
+    @thread = Thread.new do
+      Fiber.set_scheduler scheduler_factory.call
+      Fiber.schedule { dispatch }
+      # The scheduler runs its event loop when this thread exits. It returns once `shutdown` was
+      # called and all in-flight tasks have finished.
+    end
+  end
+
+  # Run `block` in a new fiber on the reactor thread. Safe to call from any thread.
+  # Once the reactor was shut down, the block runs in a new Thread instead.
+  def spawn(&block)
+    @lock.synchronize do
+      return Thread.new(&block) if @shut_down
+
+      task = UniffiCallbackTask.new(self, block)
+      post :spawn, task
+      task
+    end
+  end
+
+  def interrupt(task, exception)
+    post :interrupt, task, exception
+  end
+
+  # Stop accepting tasks. Tasks that were already spawned are left to complete.
+  def shutdown
+    @lock.synchronize do
+      @shut_down = true
+      post :shutdown
+    end
+  end
+
+  private
+
+  def post(*job)
+    @jobs << job
+    # If the pipe is full, the reactor already has a pending wakeup, so dropping this byte is fine.
+    @wr.write_nonblock('.', exception: false)
+  end
+
+  def dispatch
+    loop do
+      @rd.wait_readable
+      @rd.read_nonblock(1024, exception: false)
+
+      # Only this fiber pops from the queue, so `pop` never blocks here. Jobs are handled in order,
+      # so every task spawned before `shutdown` is scheduled before the loop exits.
+      until @jobs.empty?
+        action, task, exception = @jobs.pop
+        case action
+        when :spawn
+          Fiber.schedule { task.run }
+        when :interrupt
+          cancel_task task, exception
+        when :shutdown
+          return
+        end
+      end
+    end
+  end
+
+  def cancel_task(task, exception)
+    fiber = task.fiber
+    # If the fiber hasn't started or has already finished, the once flag is enough to discard the result.
+    return unless fiber&.alive?
+
+    # Schedulers without the fiber_interrupt hook (Ruby 3.4+) can't safely interrupt a waiting fiber.
+    # In that case the task runs to completion and its result is discarded.
+    scheduler = Fiber.scheduler
+    scheduler.fiber_interrupt(fiber, exception) if scheduler.respond_to?(:fiber_interrupt)
+  end
+end
+
+# Run foreign async callback methods as fibers on a dedicated thread instead of spawning a
+# Thread for each call.
+#
+# The block is called once, on that thread, to create the Fiber::Scheduler that drives the
+# callbacks. For example, to use the `async` gem:
+#
+#   uniffi_set_fiber_scheduler { Async::Scheduler.new }
+#
+# Call without a block to go back to a Thread per call. Callbacks that are already running
+# are left to complete on the previous scheduler.
+def self.uniffi_set_fiber_scheduler(&scheduler_factory)
+  UNIFFI_CALLBACK_REACTOR_LOCK.synchronize do
+    @uniffi_callback_reactor&.shutdown
+    @uniffi_callback_reactor = scheduler_factory && UniffiCallbackReactor.new(scheduler_factory)
+  end
+end
+
 # Called by Rust when the foreign future is dropped (i.e. canceled or completed successfully).
-# Raises UniffiInternalCancelled in the worker thread so make_call can exit early,
-# but only if the thread hasn't already completed and claimed the once flag.
+# Raises UniffiInternalCancelled in the worker so make_call can exit early,
+# but only if the worker hasn't already completed and claimed the once flag.
 # Stored as a constant to prevent GC from collecting the Proc while Rust holds the pointer.
 UNIFFI_FOREIGN_FUTURE_DROPPED_CALLBACK = Proc.new do |handle|
-  thread, once = UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.remove handle
-  thread.raise(UniffiInternalCancelled, 'Future was canceled') if once.claim! && thread&.alive?
+  worker, once = UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.remove handle
+  worker.raise(UniffiInternalCancelled, 'Future was canceled') if once.claim! && worker&.alive?
 end
 
-# Execute a foreign async callback method in a background thread.
+# Execute a foreign async callback method, either on the reactor set by `uniffi_set_fiber_scheduler`
+# or in a background thread.
 # Enforces the at-most-once guarantee on handle_success / handle_error: whichever
 # fires first (normal completion or Rust-side drop) suppresses the other.
 def self.uniffi_trait_interface_call_async(make_call, uniffi_out_dropped_callback, handle_success, handle_error, error_type = nil, lower_error = nil)
   once = UniffiOnceFlag.new
 
-  thread = Thread.new do
+  run = Proc.new do
     begin
       # Phase 1: run the user's async method.
       # UniffiInternalCancelled exits silently. Other exceptions are forwarded as errors.
@@ -160,7 +298,7 @@ def self.uniffi_trait_interface_call_async(make_call, uniffi_out_dropped_callbac
       # Phase 2: deliver the result to Rust. Skipped if dropped_callback already fired.
       handle_success.call(result) if once.claim!
     rescue UniffiInternalCancelled
-      # Thread#raise landed between phases or during Phase 2 - silently exit.
+      # The cancellation landed between phases or during Phase 2 - silently exit.
       # Rust already dropped the future (that's why dropped_callback fired), so no response needed.
     rescue Exception => e
       # handle_success/handle_error/lower_error raised - send a generic error so Rust doesn't hang.
@@ -174,10 +312,13 @@ def self.uniffi_trait_interface_call_async(make_call, uniffi_out_dropped_callbac
     end
   end
 
-  # Note: the thread may have already completed by this point, but that's safe.
+  reactor = UNIFFI_CALLBACK_REACTOR_LOCK.synchronize { @uniffi_callback_reactor }
+  worker = reactor.nil? ? Thread.new(&run) : reactor.spawn(&run)
+
+  # Note: the worker may have already completed by this point, but that's safe.
   # Rust cannot invoke dropped_callback until this function returns.
   # possesses the ForeignFuture struct we're populating here.
-  handle = UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.insert([thread, once])
+  handle = UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.insert([worker, once])
   uniffi_out_dropped_callback[:handle] = handle
   uniffi_out_dropped_callback[:free] = UNIFFI_FOREIGN_FUTURE_DROPPED_CALLBACK
 end
@@ -152,6 +152,31 @@ class {{ obj.name()|class_name_rb }}{% if ci.is_name_used_as_error(obj.name()) %
   {% endmatch %}
   {%- endif %}
   {% endfor %}
+
+  {% for func in obj.static_methods() -%}
+  {%- if func.is_async() %}
+  def self.{{ func.name()|fn_name_rb }}({% call rb::arg_list_decl(func) %}{% endcall %})
+    {%- call rb::setup_args_extra_indent(func) %}{% endcall %}
+    {% call rb::to_ffi_call_async(func) %}{% endcall %}
+  end
+  {%- else %}
+  {%- match func.return_type() -%}
+
+  {%- when Some with (return_type) -%}
+  def self.{{ func.name()|fn_name_rb }}({% call rb::arg_list_decl(func) %}{% endcall %})
+    {%- call rb::setup_args_extra_indent(func) %}{% endcall %}
+    result = {% call rb::to_ffi_call(func) %}{% endcall %}
+    return {{ "result"|lift_rb(return_type, config) }}
+  end
+
+  {%- when None -%}
+  def self.{{ func.name()|fn_name_rb }}({% call rb::arg_list_decl(func) %}{% endcall %})
+      {%- call rb::setup_args_extra_indent(func) %}{% endcall %}
+      {% call rb::to_ffi_call(func) %}{% endcall %}
+  end
+  {% endmatch %}
+  {%- endif %}
+  {% endfor %}
   {%- let trait_methods = obj.uniffi_trait_methods() %}
   {%- include "UniffiTraitImpls.rb" %}
 end
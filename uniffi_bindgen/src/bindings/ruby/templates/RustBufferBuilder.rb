@@ -87,6 +87,24 @@ class RustBufferBuilder
     pack_into(8, 'Q>', v)
   end
 
+  {% when Type::Int128 -%}
+  # 128-bit integers are written as two 64-bit halves, most significant first.
+
+  def write_{{ canonical_type_name }}(v)
+    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_in_range(v, "i128", -2**127, 2**127)
+    pack_into(8, 'q>', v >> 64)
+    pack_into(8, 'Q>', v & (2**64 - 1))
+  end
+
+  {% when Type::UInt128 -%}
+  # 128-bit integers are written as two 64-bit halves, most significant first.
+
+  def write_{{ canonical_type_name }}(v)
+    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_in_range(v, "u128", 0, 2**128)
+    pack_into(8, 'Q>', v >> 64)
+    pack_into(8, 'Q>', v & (2**64 - 1))
+  end
+
   {% when Type::Float32 -%}
 
   def write_{{ canonical_type_name }}(v)
@@ -121,6 +139,14 @@ class RustBufferBuilder
     write v
   end
 
+  {% when Type::OwnedBytes -%}
+
+  def write_{{ canonical_type_name }}(v)
+    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_bytes(v)
+    pack_into 4, 'l>', v.bytes.size
+    write v
+  end
+
   {% when Type::Timestamp -%}
   # The Timestamp type.
   ONE_SECOND_IN_NANOSECONDS = 10**9
@@ -159,6 +185,33 @@ class RustBufferBuilder
     pack_into 4, 'L>', nanoseconds
   end
 
+  {% when Type::Uuid -%}
+  # The Uuid type, passed as a String like "67e55044-10b1-426f-9247-bb680e5fe0c8".
+
+  def write_{{ canonical_type_name }}(v)
+    hex = v.to_s.delete('-')
+    raise ArgumentError, "Invalid UUID: #{v}" unless hex.match?(/\A\h{32}\z/)
+
+    write [hex].pack('H*')
+  end
+
+  {% when Type::Url -%}
+  # The Url type.
+
+  def write_{{ canonical_type_name }}(v)
+    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_utf8(v.to_s)
+    pack_into 4, 'l>', v.bytes.size
+    write v
+  end
+
+  {% when Type::Date -%}
+  # The Date type.
+  UNIX_EPOCH_DATE = Date.new(1970, 1, 1)
+
+  def write_{{ canonical_type_name }}(v)
+    pack_into 4, 'l>', (v - UNIX_EPOCH_DATE).to_i
+  end
+
   {% when Type::Object with { name: object_name, .. } -%}
   # The Object type {{ object_name }}.
 
@@ -253,6 +306,23 @@ class RustBufferBuilder
     end
   end
 
+  {% when Type::Array { inner_type, len } -%}
+  # The fixed-size array type for {{ self::canonical_name(inner_type) }}.
+
+  def write_{{ canonical_type_name }}(items)
+    {%- if self::is_byte(inner_type) %}
+    items = ::{{ ci.namespace()|class_name_rb }}::uniffi_bytes(items)
+    pack_into(4, 'l>', items.bytesize)
+    write items
+    {%- else %}
+    pack_into(4, 'l>', items.size)
+
+    items.each do |item|
+      self.write_{{ self::canonical_name(inner_type) }}(item)
+    end
+    {%- endif %}
+  end
+
   {% when Type::Set { inner_type } -%}
   # The Set<T> type for {{ self::canonical_name(inner_type) }}.
 
@@ -264,6 +334,15 @@ class RustBufferBuilder
     end
   end
 
+  {% when Type::Tuple { types } -%}
+  # The tuple type for {{ canonical_type_name }}.
+
+  def write_{{ canonical_type_name }}(v)
+    {%- for t in types %}
+    self.write_{{ self::canonical_name(t) }}(v[{{ loop.index0 }}])
+    {%- endfor %}
+  end
+
   {% when Type::Map { key_type: k, value_type: v } -%}
   # The Map<T> type for {{ canonical_type_name }}.
 
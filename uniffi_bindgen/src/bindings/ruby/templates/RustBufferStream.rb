@@ -73,6 +73,22 @@ class RustBufferStream
     unpack_from 8, 'Q>'
   end
 
+  {% when Type::Int128 -%}
+
+  def read_{{ self::canonical_name(typ) }}
+    high = unpack_from 8, 'q>'
+    low = unpack_from 8, 'Q>'
+    (high << 64) | low
+  end
+
+  {% when Type::UInt128 -%}
+
+  def read_{{ self::canonical_name(typ) }}
+    high = unpack_from 8, 'Q>'
+    low = unpack_from 8, 'Q>'
+    (high << 64) | low
+  end
+
   {% when Type::Float32 -%}
 
   def read_{{ self::canonical_name(typ) }}
@@ -116,6 +132,16 @@ class RustBufferStream
     read(size).force_encoding(Encoding::BINARY)
   end
 
+  {% when Type::OwnedBytes -%}
+
+  def read_{{ self::canonical_name(typ) }}
+    size = unpack_from 4, 'l>'
+
+    raise InternalError, 'Unexpected negative byte string length' if size.negative?
+
+    read(size).force_encoding(Encoding::BINARY)
+  end
+
   {% when Type::Timestamp -%}
   # The Timestamp type.
   ONE_SECOND_IN_NANOSECONDS = 10**9
@@ -150,6 +176,33 @@ class RustBufferStream
     Time.at(seconds, nanoseconds, :nanosecond, in: '+00:00').utc
   end
 
+  {% when Type::Uuid -%}
+  # The Uuid type.
+
+  def read_{{ canonical_type_name }}
+    hex = read(16).unpack1('H*')
+    [hex[0, 8], hex[8, 4], hex[12, 4], hex[16, 4], hex[20, 12]].join('-')
+  end
+
+  {% when Type::Url -%}
+  # The Url type.
+
+  def read_{{ canonical_type_name }}
+    size = unpack_from 4, 'l>'
+
+    raise InternalError, 'Unexpected negative string length' if size.negative?
+
+    URI(read(size).force_encoding(Encoding::UTF_8))
+  end
+
+  {% when Type::Date -%}
+  # The Date type.
+  UNIX_EPOCH_DATE = Date.new(1970, 1, 1)
+
+  def read_{{ canonical_type_name }}
+    UNIX_EPOCH_DATE + unpack_from(4, 'l>')
+  end
+
   {% when Type::Object with { name: object_name, .. } -%}
   # The Object type {{ object_name }}.
 
@@ -276,6 +329,28 @@ class RustBufferStream
     items
   end
 
+  {% when Type::Array { inner_type, len } -%}
+  # The fixed-size array type for {{ self::canonical_name(inner_type) }}.
+
+  def read_{{ canonical_type_name }}
+    count = unpack_from 4, 'l>'
+
+    raise InternalError, "Unexpected array length #{count}" unless count == {{ len }}
+    {%- if self::is_byte(inner_type) %}
+
+    read(count).force_encoding(Encoding::BINARY)
+    {%- else %}
+
+    items = []
+
+    count.times do
+      items.append read_{{ self::canonical_name(inner_type) }}
+    end
+
+    items
+    {%- endif %}
+  end
+
   {% when Type::Set { inner_type } -%}
   # The Set<T> type for {{ self::canonical_name(inner_type) }}.
 
@@ -293,6 +368,17 @@ class RustBufferStream
     items
   end
 
+  {% when Type::Tuple { types } -%}
+  # The tuple type for {{ canonical_type_name }}.
+
+  def read_{{ canonical_type_name }}
+    [
+      {%- for t in types %}
+      read_{{ self::canonical_name(t) }}{% if !loop.last %},{% endif %}
+      {%- endfor %}
+    ]
+  end
+
   {% when Type::Map { key_type: k, value_type: v } -%}
   # The Map<T> type for {{ canonical_type_name }}.
 
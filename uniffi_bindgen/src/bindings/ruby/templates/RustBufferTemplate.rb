@@ -96,6 +96,23 @@ class RustBuffer < FFI::Struct
     end
   end
 
+  {% when Type::OwnedBytes -%}
+  # The OwnedBytes type. Like String, the buffer holds the raw bytes without a length prefix.
+  # Ruby has no way to borrow foreign memory as a String, so the bytes are copied out.
+
+  def self.alloc_from_{{ canonical_type_name }}(value)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write ::{{ ci.namespace()|class_name_rb }}::uniffi_bytes(value)
+      return builder.finalize
+    end
+  end
+
+  def consume_into_{{ canonical_type_name }}
+    consumeWithStream do |stream|
+      return stream.read(stream.remaining).force_encoding(Encoding::BINARY)
+    end
+  end
+
   {% when Type::Timestamp -%}
   def self.alloc_from_{{ canonical_type_name }}(v)
     RustBuffer.allocWithBuilder do |builder|
@@ -110,7 +127,35 @@ class RustBuffer < FFI::Struct
     end
   end
 
-  {% when Type::Duration -%}
+  {% when Type::Int128 -%}
+  def self.alloc_from_{{ canonical_type_name }}(v)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write_{{ canonical_type_name }}(v)
+      return builder.finalize
+    end
+  end
+
+  def consume_into_{{ canonical_type_name }}
+    consumeWithStream do |stream|
+      return stream.read_{{ canonical_type_name }}
+    end
+  end
+
+  {% when Type::UInt128 -%}
+  def self.alloc_from_{{ canonical_type_name }}(v)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write_{{ canonical_type_name }}(v)
+      return builder.finalize
+    end
+  end
+
+  def consume_into_{{ canonical_type_name }}
+    consumeWithStream do |stream|
+      return stream.read_{{ canonical_type_name }}
+    end
+  end
+
+  {% when Type::Duration | Type::Uuid | Type::Url | Type::Date -%}
   def self.alloc_from_{{ canonical_type_name }}(v)
     RustBuffer.allocWithBuilder do |builder|
       builder.write_{{ canonical_type_name }}(v)
@@ -243,6 +288,33 @@ class RustBuffer < FFI::Struct
     end
   end
 
+  {% when Type::Array { inner_type, len } -%}
+  # The fixed-size array type for {{ self::canonical_name(inner_type) }}.
+
+  def self.check_lower_{{ canonical_type_name }}(v)
+    {%- if self::is_byte(inner_type) %}
+    raise ArgumentError, "Expected {{ len }} bytes, got #{v.bytesize}" unless v.bytesize == {{ len }}
+    {%- else %}
+    raise ArgumentError, "Expected an array of length {{ len }}, got #{v.size}" unless v.size == {{ len }}
+    v.each do |item|
+      {{ "item"|check_lower_rb(inner_type.borrow(), config) }}
+    end
+    {%- endif %}
+  end
+
+  def self.alloc_from_{{ canonical_type_name }}(v)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write_{{ canonical_type_name }}(v)
+      return builder.finalize()
+    end
+  end
+
+  def consume_into_{{ canonical_type_name }}
+    consumeWithStream do |stream|
+      return stream.read_{{ canonical_type_name }}
+    end
+  end
+
   {% when Type::Set { inner_type } -%}
   # The Set<T> type for {{ self::canonical_name(inner_type) }}.
 
@@ -265,6 +337,29 @@ class RustBuffer < FFI::Struct
     end
   end
 
+  {% when Type::Tuple { types } -%}
+  # The tuple type for {{ canonical_type_name }}.
+
+  def self.check_lower_{{ canonical_type_name }}(v)
+    raise TypeError, "Expected an array of length {{ types.len() }}" unless v.is_a?(Array) && v.size == {{ types.len() }}
+    {%- for t in types %}
+    {{ "v[{}]"|format(loop.index0)|check_lower_rb(t, config) }}
+    {%- endfor %}
+  end
+
+  def self.alloc_from_{{ canonical_type_name }}(v)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write_{{ canonical_type_name }}(v)
+      return builder.finalize()
+    end
+  end
+
+  def consume_into_{{ canonical_type_name }}
+    consumeWithStream do |stream|
+      return stream.read_{{ canonical_type_name }}
+    end
+  end
+
   {% when Type::Map { key_type: k, value_type: v } %}
   # The Map<T> type for {{ canonical_type_name }}.
 
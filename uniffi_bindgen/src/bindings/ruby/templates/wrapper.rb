@@ -15,6 +15,8 @@
 
 require 'ffi'
 require 'set'
+require 'date'
+require 'uri'
 
 {%- if ci.has_callback_definitions() || ci.has_async_fns() %}
 require 'monitor'
@@ -81,6 +83,10 @@ module {{ ci.namespace()|class_name_rb }}
   {% include "RecordTemplate.rb" %}
   {% endfor %}
 
+  {%- for constant in ci.constant_definitions() %}
+  {{ constant.name()|enum_name_rb }} = {{ constant.value()|literal_rb }}
+  {%- endfor %}
+
   {% for func in ci.function_definitions() %}
   {% include "TopLevelFunctionTemplate.rb" %}
   {% endfor %}
@@ -4,7 +4,7 @@
 
 //! # Swift bindings backend for UniFFI
 //!
-//! This module generates Swift bindings from a [`crate::ComponentInterface`] definition,
+//! This module generates Swift bindings using the bindings IR pipeline,
 //! using Swift's builtin support for loading C header files.
 //!
 //! Conceptually, the generated bindings are split into two Swift modules, one for the low-level
@@ -17,7 +17,7 @@
 //!   * A Swift source file `example.swift` that imports the `exampleFFI` module and wraps it
 //!     to provide the higher-level Swift API.
 //!
-//! Most of the concepts in a [`crate::ComponentInterface`] have an obvious counterpart in Swift,
+//! Most of the concepts in the bindings IR have an obvious counterpart in Swift,
 //! with the details documented in inline comments where appropriate.
 //!
 //! To handle lifting/lowering/serializing types across the FFI boundary, the Swift code
@@ -29,78 +29,56 @@
 //!  * How to read from and write into a byte buffer.
 //!
 
-use crate::{
-    bindings::GenerateOptions,
-    interface::{apply_exclusions, rename},
-    BindgenLoader, BindgenPaths, Component, ComponentInterface, GlobalConfig,
-};
-use anyhow::{bail, Result};
-use camino::Utf8PathBuf;
+use anyhow::{bail, Context, Result};
+use askama::Template;
+use camino::{Utf8Path, Utf8PathBuf};
 use fs_err as fs;
-use std::collections::HashMap;
 use std::process::Command;
 
-mod gen_swift;
-use gen_swift::{generate_bindings, generate_header, generate_modulemap, generate_swift, Config};
+use crate::{bindings::GenerateOptions, BindgenLoader, BindgenPaths, GlobalConfig};
+
+pub mod filters;
+mod pipeline;
+pub use pipeline::{pipeline, Root};
+use pipeline::{FfiDefinition, Module};
 
 #[cfg(feature = "bindgen-tests")]
 pub mod test;
 
-/// The Swift bindings generated from a [`crate::ComponentInterface`].
-///
-struct Bindings {
-    /// The contents of the generated `.swift` file, as a string.
-    library: String,
-    /// The contents of the generated `.h` file, as a string.
-    header: String,
-    /// The contents of the generated `.modulemap` file, as a string.
-    modulemap: Option<String>,
-}
-
 /// Generate Swift bindings
 ///
-/// Returns the components generated
-pub fn generate(
-    loader: &BindgenLoader,
-    options: GenerateOptions,
-) -> Result<Vec<Component<Config>>> {
+/// Returns the root node of the Swift IR, which describes the modules generated
+pub fn generate(loader: &BindgenLoader, options: GenerateOptions) -> Result<Root> {
     let metadata = loader.load_metadata(&options.source)?;
     if let Some(crate_filter) = &options.crate_filter {
         if !metadata.contains_key(crate_filter) {
             bail!("No UniFFI metadata found for crate {crate_filter}");
         }
     }
-    let cis = loader.load_cis(&options.source, metadata)?;
-    let mut components = loader.load_components(cis, parse_config)?;
-    apply_renames(&mut components);
-    for c in components.iter_mut() {
-        // Call derive_ffi_functions after `apply_renames`
-        c.ci.derive_ffi_funcs()?;
-    }
-
-    for Component { ci, config, .. } in components.iter_mut() {
+    let initial_root = loader.load_pipeline_initial_root(&options.source, metadata)?;
+    let swift_root = pipeline().execute(initial_root)?;
+    for module in swift_root.modules.values() {
         if let Some(crate_filter) = &options.crate_filter {
-            if ci.crate_name() != crate_filter {
+            if module.crate_name != *crate_filter {
                 continue;
             }
         }
-        let Bindings {
-            header,
-            library,
-            modulemap,
-        } = generate_bindings(config, ci)?;
-
-        let source_file = options
-            .out_dir
-            .join(format!("{}.swift", config.module_name()));
-        fs::write(&source_file, library)?;
-
-        let header_file = options.out_dir.join(config.header_filename());
-        fs::write(header_file, header)?;
-
-        if let Some(modulemap) = modulemap {
-            let modulemap_file = options.out_dir.join(config.modulemap_filename());
-            fs::write(modulemap_file, modulemap)?;
+        let source_file = write_swift_source(module, &options.out_dir)?;
+        write_header(module, &options.out_dir)?;
+        if module.generate_module_map {
+            let modulemap = ModuleMap {
+                module_name: module.ffi_module_name.clone(),
+                header_filenames: vec![module.header_filename.clone()],
+                xcframework: false,
+                link_frameworks: module.link_frameworks.clone(),
+            };
+            let modulemap_file = options.out_dir.join(&module.modulemap_filename);
+            fs::write(
+                modulemap_file,
+                modulemap
+                    .render()
+                    .context("failed to render Swift modulemap")?,
+            )?;
         }
 
         if options.format {
@@ -129,7 +107,7 @@ pub fn generate(
             }
         }
     }
-    Ok(components)
+    Ok(swift_root)
 }
 
 /// Generate Swift bindings (specialized version)
@@ -139,10 +117,6 @@ pub fn generate(
 /// In the future, we may want to replace the generalized `uniffi-bindgen` with a set of
 /// specialized `uniffi-bindgen-[language]` commands.
 pub fn generate_swift_bindings(options: SwiftBindingsOptions) -> Result<()> {
-    #[cfg(not(feature = "cargo-metadata"))]
-    let mut paths = BindgenPaths::default();
-
-    #[cfg(feature = "cargo-metadata")]
     let mut paths = BindgenPaths::default();
 
     let global_config = if let Some(ref path) = options.config {
@@ -167,25 +141,16 @@ pub fn generate_swift_bindings(options: SwiftBindingsOptions) -> Result<()> {
 
     let loader = BindgenLoader::new(paths, global_config);
     let metadata = loader.load_metadata(&options.source)?;
-    let cis = loader.load_cis(&options.source, metadata)?;
-    let mut components = loader.load_components(cis, parse_config)?;
-    apply_renames(&mut components);
-    // Call derive_ffi_funcs after apply_renames()
-    for Component { ci, .. } in components.iter_mut() {
-        ci.derive_ffi_funcs()?;
-    }
+    let initial_root = loader.load_pipeline_initial_root(&options.source, metadata)?;
+    let swift_root = pipeline().execute(initial_root)?;
 
-    for Component { ci, config } in &components {
+    for module in swift_root.modules.values() {
         if options.generate_swift_sources {
-            let source_file = options
-                .out_dir
-                .join(format!("{}.swift", config.module_name()));
-            fs::write(&source_file, generate_swift(config, ci)?)?;
+            write_swift_source(module, &options.out_dir)?;
         }
 
         if options.generate_headers {
-            let header_file = options.out_dir.join(config.header_filename());
-            fs::write(header_file, generate_header(config, ci)?)?;
+            write_header(module, &options.out_dir)?;
         }
     }
 
@@ -200,33 +165,76 @@ pub fn generate_swift_bindings(options: SwiftBindingsOptions) -> Result<()> {
         .unwrap_or_else(|| format!("{source_basename}.modulemap"));
 
     if options.generate_modulemap {
-        let mut header_filenames: Vec<_> = components
-            .iter()
-            .map(|Component { config, .. }| config.header_filename())
+        let mut header_filenames: Vec<_> = swift_root
+            .modules
+            .values()
+            .map(|module| module.header_filename.clone())
             .collect();
         header_filenames.sort();
-        let modulemap_source = generate_modulemap(
+        let modulemap = ModuleMap {
             module_name,
             header_filenames,
-            options.xcframework,
-            options.link_frameworks,
-        )?;
+            xcframework: options.xcframework,
+            link_frameworks: options.link_frameworks,
+        };
         let modulemap_path = options.out_dir.join(modulemap_filename);
-        fs::write(modulemap_path, modulemap_source)?;
+        fs::write(
+            modulemap_path,
+            modulemap
+                .render()
+                .context("failed to render Swift modulemap")?,
+        )?;
     }
 
     Ok(())
 }
 
-fn parse_config(ci: &ComponentInterface, root_toml: toml::Value) -> Result<Config> {
-    let mut config: Config = match root_toml.get("bindings").and_then(|b| b.get("swift")) {
-        Some(v) => v.clone().try_into()?,
-        None => Default::default(),
-    };
-    config
-        .module_name
-        .get_or_insert_with(|| ci.namespace().into());
-    Ok(config)
+/// Write the `.swift` source file for a module, returning its path
+fn write_swift_source(module: &Module, out_dir: &Utf8Path) -> Result<Utf8PathBuf> {
+    let source_file = out_dir.join(format!("{}.swift", module.module_name));
+    fs::write(
+        &source_file,
+        module.render().context("failed to render Swift library")?,
+    )?;
+    Ok(source_file)
+}
+
+/// Write the bridging header for a module
+fn write_header(module: &Module, out_dir: &Utf8Path) -> Result<()> {
+    let header_file = out_dir.join(&module.header_filename);
+    fs::write(
+        header_file,
+        BridgingHeader { module }
+            .render()
+            .context("failed to render Swift bridging header")?,
+    )?;
+    Ok(())
+}
+
+/// Template for generating the `.h` file that defines the low-level C FFI.
+///
+/// This file defines only the low-level structs and functions that are exposed
+/// by the compiled Rust code. It gets wrapped into a higher-level API by the
+/// code from the module template.
+#[derive(Template)]
+#[template(syntax = "c", escape = "none", path = "BridgingHeaderTemplate.h")]
+struct BridgingHeader<'a> {
+    module: &'a Module,
+}
+
+/// Template for generating the `.modulemap` file that exposes the low-level C FFI.
+///
+/// This file defines how the low-level C FFI from [`BridgingHeader`] gets exposed
+/// as a Swift module that can be called by other Swift code. In our case, its only
+/// job is to define the *name* of the Swift module that will contain the FFI functions
+/// so that it can be imported by the higher-level code.
+#[derive(Template)]
+#[template(syntax = "c", escape = "none", path = "ModuleMapTemplate.modulemap")]
+struct ModuleMap {
+    module_name: String,
+    header_filenames: Vec<String>,
+    xcframework: bool,
+    link_frameworks: Vec<String>,
 }
 
 #[derive(Debug, Default)]
@@ -243,27 +251,3 @@ pub struct SwiftBindingsOptions {
     pub link_frameworks: Vec<String>,
     pub config: Option<Utf8PathBuf>,
 }
-
-// A helper for renaming items.
-fn apply_renames(components: &mut Vec<Component<Config>>) {
-    // Remove excluded items, this happens before renaming
-    for c in components.iter_mut() {
-        apply_exclusions(&mut c.ci, &c.config.exclude);
-    }
-
-    let mut module_renames = HashMap::new();
-    // Collect all rename configurations from all components, keyed by module_path
-    for c in components.iter() {
-        if !c.config.rename.is_empty() {
-            let module_path = c.ci.crate_name().to_string();
-            module_renames.insert(module_path, c.config.rename.clone());
-        }
-    }
-
-    // Apply rename configurations to all components
-    if !module_renames.is_empty() {
-        for c in &mut *components {
-            rename(&mut c.ci, &module_renames);
-        }
-    }
-}
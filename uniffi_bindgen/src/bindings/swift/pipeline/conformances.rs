@@ -0,0 +1,111 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Protocol conformances for generated types
+//!
+//! These are rendered as a comma-separated list, which is empty if there are no conformances.
+
+use super::*;
+
+/// Conformances shared by records, enums and errors
+///
+/// We auto-generate `Equatable, Hashable`, but only if we have no objects. We could do better -
+/// see #2409
+fn value_type_conformances(
+    trait_methods: &general::UniffiTraitMethods,
+    contains_object_references: bool,
+    include_string_conversions: bool,
+) -> Vec<&'static str> {
+    let mut conformances = vec![];
+    if !contains_object_references || trait_methods.eq_eq.is_some() {
+        conformances.push("Equatable");
+    }
+    if !contains_object_references || trait_methods.hash_hash.is_some() {
+        conformances.push("Hashable");
+    }
+    if trait_methods.ord_cmp.is_some() {
+        conformances.push("Comparable");
+    }
+    if include_string_conversions {
+        if trait_methods.debug_fmt.is_some() {
+            conformances.push("CustomDebugStringConvertible");
+        }
+        if trait_methods.display_fmt.is_some() {
+            conformances.push("CustomStringConvertible");
+        }
+    }
+    conformances
+}
+
+pub fn record_conformances(rec: &general::Record, context: &Context) -> Result<String> {
+    let config = context.config()?;
+    let contains_object_references = context.contains_object_references(&rec.self_type.ty);
+    let mut conformances =
+        value_type_conformances(&rec.uniffi_trait_methods, contains_object_references, true);
+    // Objects can't be Codable at the moment, so we can't derive `Codable` conformance if this
+    // Record references one
+    if !contains_object_references && config.generate_codable_conformance() {
+        conformances.push("Codable");
+    }
+    Ok(conformances.join(", "))
+}
+
+/// Conformances for an enum
+///
+/// For errors, this is the list of additional conformances aside from the default `Swift.Error`.
+pub fn enum_conformances(en: &general::Enum, context: &Context) -> Result<String> {
+    let config = context.config()?;
+    let contains_object_references = context.contains_object_references(&en.self_type.ty);
+    let is_error = en.self_type.is_used_as_error;
+    let contains_variant_fields = en.variants.iter().any(|v| !v.fields.is_empty());
+    let mut conformances = value_type_conformances(
+        &en.uniffi_trait_methods,
+        contains_object_references,
+        !is_error,
+    );
+    // Objects can't be Codable at the moment, so we can't derive `Codable` conformance if this
+    // Enum references one
+    if !contains_object_references && config.generate_codable_conformance() {
+        conformances.push("Codable");
+    }
+    if is_error {
+        if !config.omit_localized_error_conformance() {
+            conformances.push("Foundation.LocalizedError");
+        }
+        if config.generate_case_iterable_conformance() && !en.is_flat && !contains_variant_fields {
+            conformances.push("CaseIterable");
+        }
+    } else if config.generate_case_iterable_conformance() && !contains_variant_fields {
+        conformances.push("CaseIterable");
+    }
+    Ok(conformances.join(", "))
+}
+
+pub fn interface_conformances(int: &general::Interface, context: &Context) -> Result<String> {
+    let config = context.config()?;
+    let trait_methods = &int.uniffi_trait_methods;
+    let mut conformances = vec!["@unchecked Sendable"];
+    if int.self_type.is_used_as_error {
+        conformances.push("Swift.Error");
+        if !config.omit_localized_error_conformance() {
+            conformances.push("Foundation.LocalizedError");
+        }
+    }
+    if trait_methods.eq_eq.is_some() {
+        conformances.push("Equatable");
+    }
+    if trait_methods.hash_hash.is_some() {
+        conformances.push("Hashable");
+    }
+    if trait_methods.ord_cmp.is_some() {
+        conformances.push("Comparable");
+    }
+    if trait_methods.debug_fmt.is_some() {
+        conformances.push("CustomDebugStringConvertible");
+    }
+    if trait_methods.display_fmt.is_some() {
+        conformances.push("CustomStringConvertible");
+    }
+    Ok(conformances.join(", "))
+}
@@ -0,0 +1,222 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{HashMap, HashSet};
+
+use super::*;
+
+#[derive(Default, Clone)]
+pub struct Context {
+    pub current_config: Option<SwiftConfig>,
+    pub module_namespace: Option<String>,
+    pub builtin_types: Option<BuiltinTypes>,
+    pub checksum_mode: Option<ChecksumMode>,
+    /// Maps namespaces to their crate name
+    pub crate_names: HashMap<String, String>,
+    /// Types directly referenced by each user-defined type, keyed by (namespace, name).
+    ///
+    /// Used to check if a record/enum contains an object reference or a 128-bit integer.
+    pub type_contents: HashMap<(String, String), Vec<Type>>,
+    /// FFI function types, keyed by their name
+    pub ffi_function_types: HashMap<String, general::FfiFunctionType>,
+}
+
+impl Context {
+    pub fn update_from_root(&mut self, root: &general::Root) -> Result<()> {
+        self.checksum_mode = Some(root.checksum_mode.clone());
+        for namespace in root.namespaces.values() {
+            self.crate_names
+                .insert(namespace.name.clone(), namespace.crate_name.clone());
+        }
+        root.visit(|type_def: &general::TypeDefinition| {
+            let ty = type_def.self_type();
+            let (Some(namespace), Some(name)) = (ty.namespace(), ty.name()) else {
+                return;
+            };
+            let mut contents = vec![];
+            match type_def {
+                general::TypeDefinition::Record(rec) => {
+                    rec.visit(|t: &Type| contents.push(t.clone()))
+                }
+                general::TypeDefinition::Enum(en) => en.visit(|t: &Type| contents.push(t.clone())),
+                general::TypeDefinition::Interface(int) => {
+                    int.visit(|t: &Type| contents.push(t.clone()))
+                }
+                general::TypeDefinition::CallbackInterface(cbi) => {
+                    cbi.visit(|t: &Type| contents.push(t.clone()))
+                }
+                _ => return,
+            }
+            self.type_contents
+                .insert((namespace.to_string(), name.to_string()), contents);
+        });
+        root.visit(|ffi_func_type: &general::FfiFunctionType| {
+            self.ffi_function_types
+                .insert(ffi_func_type.name.0.clone(), ffi_func_type.clone());
+        });
+        self.builtin_types = Some(root.builtin_types.clone().map_node(self)?);
+        Ok(())
+    }
+
+    pub fn update_from_namespace(&mut self, namespace: &general::Namespace) -> Result<()> {
+        self.current_config = Some(match &namespace.config_toml {
+            Some(toml) => SwiftConfig::from_uniffi_toml(toml)?,
+            None => SwiftConfig::default(),
+        });
+        self.module_namespace = Some(namespace.name.clone());
+        Ok(())
+    }
+
+    pub fn builtin_types(&self) -> Result<BuiltinTypes> {
+        self.builtin_types
+            .clone()
+            .ok_or_else(|| anyhow!("Context.builtin_types not set"))
+    }
+
+    pub fn module_namespace(&self) -> Result<&str> {
+        self.module_namespace
+            .as_deref()
+            .ok_or_else(|| anyhow!("Context.module_namespace not set"))
+    }
+
+    pub fn config(&self) -> Result<&SwiftConfig> {
+        self.current_config
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.config not set"))
+    }
+
+    pub fn checksum_mode(&self) -> Result<&ChecksumMode> {
+        self.checksum_mode
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.checksum_mode not set"))
+    }
+
+    /// Is `namespace` a different module than the one currently being generated?
+    pub fn is_external(&self, namespace: &str) -> Result<bool> {
+        Ok(namespace != self.module_namespace()?)
+    }
+
+    /// Get the crate name for a namespace
+    pub fn crate_name(&self, namespace: &str) -> Result<&str> {
+        self.crate_names
+            .get(namespace)
+            .map(String::as_str)
+            .ok_or_else(|| anyhow!("Unknown namespace: {namespace}"))
+    }
+
+    /// Check if a user-defined type contains any (possibly nested) object references.
+    ///
+    /// Tuples and closures are counted as well, since Swift can't synthesize `Equatable`,
+    /// `Hashable` or `Codable` for them either.
+    pub fn contains_object_references(&self, ty: &Type) -> bool {
+        let mut seen = HashSet::new();
+        self.contains_object_references_inner(ty, &mut seen)
+    }
+
+    fn contains_object_references_inner(
+        &self,
+        ty: &Type,
+        seen: &mut HashSet<(String, String)>,
+    ) -> bool {
+        let (Some(namespace), Some(name)) = (ty.namespace(), ty.name()) else {
+            return false;
+        };
+        let key = (namespace.to_string(), name.to_string());
+        if !seen.insert(key.clone()) {
+            return false;
+        }
+        let Some(contents) = self.type_contents.get(&key) else {
+            return false;
+        };
+        contents.iter().any(|t| match t {
+            Type::Interface { .. } | Type::Tuple { .. } | Type::Closure { .. } => true,
+            Type::Record { .. } | Type::Enum { .. } | Type::CallbackInterface { .. } => {
+                self.contains_object_references_inner(t, seen)
+            }
+            _ => false,
+        })
+    }
+
+    /// Check if a type uses `Int128` or `UInt128`, possibly nested inside another type.
+    ///
+    /// Those types require a recent platform deployment target, so declarations that use them are
+    /// marked with an `@available` attribute.  Objects are only checked if they have a foreign
+    /// implementation, for other objects the individual methods are marked instead.
+    pub fn requires_int128(&self, ty: &Type) -> bool {
+        let mut seen = HashSet::new();
+        self.requires_int128_inner(ty, &mut seen)
+    }
+
+    fn requires_int128_inner(&self, ty: &Type, seen: &mut HashSet<(String, String)>) -> bool {
+        match ty {
+            Type::Int128 | Type::UInt128 => true,
+            Type::Box { inner_type }
+            | Type::Optional { inner_type }
+            | Type::Sequence { inner_type }
+            | Type::Set { inner_type }
+            | Type::Stream { inner_type }
+            | Type::Array { inner_type, .. } => self.requires_int128_inner(inner_type, seen),
+            Type::Map {
+                key_type,
+                value_type,
+            } => {
+                self.requires_int128_inner(key_type, seen)
+                    || self.requires_int128_inner(value_type, seen)
+            }
+            Type::Tuple { types } => types.iter().any(|t| self.requires_int128_inner(t, seen)),
+            Type::Closure {
+                arguments,
+                return_type,
+            } => {
+                arguments
+                    .iter()
+                    .any(|t| self.requires_int128_inner(t, seen))
+                    || return_type
+                        .iter()
+                        .any(|t| self.requires_int128_inner(t, seen))
+            }
+            Type::Custom { builtin, .. } => self.requires_int128_inner(builtin, seen),
+            Type::Interface { imp, .. } if !imp.has_callback_interface() => false,
+            Type::Interface {
+                namespace, name, ..
+            }
+            | Type::Record {
+                namespace, name, ..
+            }
+            | Type::Enum {
+                namespace, name, ..
+            }
+            | Type::CallbackInterface {
+                namespace, name, ..
+            } => {
+                let key = (namespace.clone(), name.clone());
+                if !seen.insert(key.clone()) {
+                    return false;
+                }
+                let Some(contents) = self.type_contents.get(&key) else {
+                    return false;
+                };
+                contents.iter().any(|t| self.requires_int128_inner(t, seen))
+            }
+            _ => false,
+        }
+    }
+
+    pub fn ffi_function_type(
+        &self,
+        name: &FfiFunctionTypeName,
+    ) -> Result<general::FfiFunctionType> {
+        self.ffi_function_types
+            .get(&name.0)
+            .cloned()
+            .ok_or_else(|| anyhow!("FFI function type not found: {}", name.0))
+    }
+
+    pub fn custom_type_config(
+        &self,
+        custom: &general::CustomType,
+    ) -> Result<Option<CustomTypeConfig>> {
+        Ok(self.config()?.custom_types.get(&custom.name).cloned())
+    }
+}
@@ -0,0 +1,108 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn render_default(default: &general::DefaultValue, context: &Context) -> Result<String> {
+    Ok(match default {
+        general::DefaultValue::Default(tn) => render_default_for_type(&tn.ty, context)?,
+        general::DefaultValue::Literal(lit) => render_literal(lit, context)?,
+    })
+}
+
+fn render_default_for_type(ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Boolean => "false".to_string(),
+        Type::String => "\"\"".to_string(),
+        Type::Bytes | Type::OwnedBytes => "Data([])".to_string(),
+        Type::Int32 => "0".to_string(),
+        Type::Int8
+        | Type::Int16
+        | Type::Int64
+        | Type::Int128
+        | Type::UInt8
+        | Type::UInt16
+        | Type::UInt32
+        | Type::UInt64
+        | Type::UInt128
+        | Type::Float32
+        | Type::Float64 => format!("{}(0)", types::type_name(ty, context)?),
+        Type::Optional { .. } => "nil".to_string(),
+        Type::Sequence { .. } => "[]".to_string(),
+        Type::Map { .. } => "[:]".to_string(),
+        Type::Set { .. } => "Set()".to_string(),
+        Type::Enum { .. } => bail!("Invalid default value for enum type: {ty:?}"),
+        Type::Custom { builtin, .. } => match render_default_for_type(builtin, context) {
+            Ok(default) => default,
+            Err(_) => bail!(
+                "Unsupported default value for {}",
+                types::type_name(ty, context)?
+            ),
+        },
+        Type::Box { inner_type } => render_default_for_type(inner_type, context)?,
+        // Default for named types is to assume a ctor exists.
+        _ => format!("{}()", types::type_name(ty, context)?),
+    })
+}
+
+pub fn render_literal(lit: &general::Literal, context: &Context) -> Result<String> {
+    Ok(match lit {
+        general::Literal::Boolean(v) => format!("{v}"),
+        general::Literal::String(s) => format!("\"{s}\""),
+        general::Literal::Int(i, radix, tn) => {
+            typed_number(&tn.ty, render_integer(*i, radix), context)?
+        }
+        general::Literal::UInt(i, radix, tn) => {
+            typed_number(&tn.ty, render_integer(*i, radix), context)?
+        }
+        general::Literal::Float(value, tn) => typed_number(&tn.ty, value.clone(), context)?,
+        general::Literal::EmptySequence => "[]".to_string(),
+        general::Literal::EmptyMap => "[:]".to_string(),
+        general::Literal::EmptySet => "Set()".to_string(),
+        general::Literal::None => "nil".to_string(),
+        general::Literal::Some { inner } => render_default(inner, context)?,
+        general::Literal::Enum(variant, _) => format!(".{}", names::enum_variant_name(variant)),
+    })
+}
+
+fn render_integer<T: std::fmt::Display + std::fmt::Octal + std::fmt::LowerHex>(
+    i: T,
+    radix: &Radix,
+) -> String {
+    match radix {
+        Radix::Octal => format!("0o{i:o}"),
+        Radix::Decimal => format!("{i}"),
+        Radix::Hexadecimal => format!("{i:#x}"),
+    }
+}
+
+/// Get the unwrapped type for a number literal
+fn number_type(ty: &Type) -> &Type {
+    match ty {
+        Type::Optional { inner_type } => number_type(inner_type),
+        Type::Custom { builtin, .. } => number_type(builtin),
+        t => t,
+    }
+}
+
+fn typed_number(ty: &Type, num_str: String, context: &Context) -> Result<String> {
+    let ty = number_type(ty);
+    Ok(match ty {
+        // special case Int32.
+        Type::Int32 => num_str,
+        // otherwise use constructor e.g. UInt8(x)
+        Type::Int8
+        | Type::UInt8
+        | Type::Int16
+        | Type::UInt16
+        | Type::UInt32
+        | Type::Int64
+        | Type::UInt64
+        | Type::Int128
+        | Type::UInt128
+        | Type::Float32
+        | Type::Float64 => format!("{}({num_str})", types::type_name(ty, context)?),
+        _ => bail!("Unexpected literal: {num_str} for type: {ty:?}"),
+    })
+}
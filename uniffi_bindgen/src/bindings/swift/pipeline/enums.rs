@@ -0,0 +1,55 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn map_enum(en: general::Enum, context: &Context) -> Result<Enum> {
+    let is_error = en.self_type.is_used_as_error;
+    Ok(Enum {
+        name: names::class_name(&en.name),
+        conformances: conformances::enum_conformances(&en, context)?,
+        variants: map_variants(en.variants, is_error, context)?,
+        is_flat: en.is_flat,
+        shape: en.shape.map_node(context)?,
+        discr_specified: en.discr_specified,
+        discr_type: en.discr_type.map_node(context)?,
+        docstring: en.docstring,
+        self_type: en.self_type.map_node(context)?,
+        methods: en.methods.map_node(context)?,
+        uniffi_trait_methods: en.uniffi_trait_methods.map_node(context)?,
+        recursive: en.recursive,
+    })
+}
+
+fn map_variants(
+    variants: Vec<general::Variant>,
+    is_error: bool,
+    context: &Context,
+) -> Result<Vec<Variant>> {
+    variants
+        .into_iter()
+        .map(|v| {
+            Ok(Variant {
+                name: if is_error {
+                    names::error_variant_name(&v.name)
+                } else {
+                    names::enum_variant_name(&v.name)
+                },
+                discr_literal: discr_literal(&v.discr)?,
+                fields_kind: v.fields_kind.map_node(context)?,
+                fields: v.fields.map_node(context)?,
+                docstring: v.docstring,
+            })
+        })
+        .collect()
+}
+
+/// Get the idiomatic Swift rendering of an individual enum variant's discriminant
+fn discr_literal(discr: &general::Literal) -> Result<String> {
+    Ok(match discr {
+        general::Literal::UInt(v, _, _) => v.to_string(),
+        general::Literal::Int(v, _, _) => v.to_string(),
+        _ => bail!("Only ints are supported: {discr:?}"),
+    })
+}
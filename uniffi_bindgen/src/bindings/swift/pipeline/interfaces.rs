@@ -0,0 +1,49 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Interface naming
+//!
+//! If an interface supports foreign implementations (i.e. it has a callback interface), the
+//! protocol name is the interface name, and the class name is derived from that.
+//! Otherwise, the class name is the interface name and the protocol name is derived from that.
+//!
+//! This split determines what types `FfiConverter.lower()` inputs.  If we support callback
+//! interfaces, `lower` must lower anything that implements the protocol.  If not, then lower
+//! only lowers the concrete class.
+
+use super::*;
+
+fn protocol_name_for(name: &str, imp: &ObjectImpl) -> String {
+    let class_name = names::class_name(name);
+    if imp.has_callback_interface() {
+        class_name
+    } else {
+        format!("{class_name}Protocol")
+    }
+}
+
+pub fn protocol_name(int: &general::Interface) -> String {
+    protocol_name_for(&int.name, &int.imp)
+}
+
+pub fn impl_name(int: &general::Interface) -> String {
+    let class_name = names::class_name(&int.name);
+    if int.imp.has_callback_interface() {
+        format!("{class_name}Impl")
+    } else {
+        class_name
+    }
+}
+
+/// Swift protocols for the traits this interface implements
+pub fn trait_protocol_names(int: &general::Interface) -> Result<Vec<String>> {
+    int.trait_impls
+        .iter()
+        .map(|trait_impl| match &trait_impl.trait_ty.ty {
+            Type::Interface { name, imp, .. } => Ok(protocol_name_for(name, imp)),
+            Type::CallbackInterface { name, .. } => Ok(names::class_name(name)),
+            ty => bail!("Invalid trait_type: {ty:?}"),
+        })
+        .collect()
+}
@@ -0,0 +1,116 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::BTreeSet;
+
+use super::*;
+
+pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result<Module> {
+    let mut child_context = context.clone();
+    let context = &mut child_context;
+    context.update_from_namespace(&namespace)?;
+    let config = context.config()?;
+
+    let module_name = config.module_name(&namespace.name);
+    let ffi_module_name = config.ffi_module_name(&namespace.name);
+    let ffi_module_filename = config.ffi_module_filename(&namespace.name);
+
+    Ok(Module {
+        header_filename: format!("{ffi_module_filename}.h"),
+        modulemap_filename: format!("{ffi_module_filename}.modulemap"),
+        module_name,
+        ffi_module_name,
+        ffi_module_filename,
+        generate_module_map: config.generate_module_map(),
+        link_frameworks: config.link_frameworks.clone(),
+        checksum_mode: context.checksum_mode()?.clone(),
+        omit_checksums: config.omit_checksums
+            || matches!(context.checksum_mode()?, ChecksumMode::Skip),
+        omit_argument_labels: config.omit_argument_labels(),
+        omit_localized_error_conformance: config.omit_localized_error_conformance(),
+        lossless_time_types: config.lossless_time_types(),
+        has_async_fns: namespace.has_descendant(|callable: &general::Callable| callable.is_async())
+            || namespace.has_descendant(|_: &general::StreamType| true),
+        has_streams: namespace.has_descendant(|_: &general::StreamType| true),
+        has_callback_interface: has_callback_interface(&namespace),
+        has_async_callback_method: has_async_callback_method(&namespace),
+        imports: module_imports(&namespace, context)?,
+        initialization_fns: initialization_fns(&namespace, context)?,
+        ensure_init_fn_name: names::ensure_init_fn_name(&namespace.crate_name),
+        foreign_future_handle_count_fn: format!(
+            "uniffiForeignFutureHandleCount{}",
+            names::class_name(&namespace.name)
+        ),
+        name: namespace.name.map_node(context)?,
+        crate_name: namespace.crate_name.map_node(context)?,
+        docstring: namespace.docstring.map_node(context)?,
+        functions: namespace.functions.map_node(context)?,
+        constants: namespace.constants.map_node(context)?,
+        type_definitions: namespace.type_definitions.map_node(context)?,
+        ffi_definitions: namespace.ffi_definitions.map_node(context)?,
+        checksums: namespace.checksums.map_node(context)?,
+        ffi_rustbuffer_alloc: namespace.ffi_rustbuffer_alloc,
+        ffi_rustbuffer_from_bytes: namespace.ffi_rustbuffer_from_bytes,
+        ffi_rustbuffer_free: namespace.ffi_rustbuffer_free,
+        ffi_rustbuffer_reserve: namespace.ffi_rustbuffer_reserve,
+        ffi_uniffi_contract_version: namespace.ffi_uniffi_contract_version,
+        closure_vtable: namespace.closure_vtable.map_node(context)?,
+        correct_contract_version: namespace.correct_contract_version,
+        builtin_types: context.builtin_types()?,
+    })
+}
+
+fn has_callback_interface(namespace: &general::Namespace) -> bool {
+    namespace.has_descendant(|_: &general::CallbackInterface| true)
+        || namespace.has_descendant(|int: &general::Interface| int.imp.has_callback_interface())
+}
+
+fn has_async_callback_method(namespace: &general::Namespace) -> bool {
+    let callback_interface_async = namespace.has_descendant(|cbi: &general::CallbackInterface| {
+        cbi.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    let trait_interface_async = namespace.has_descendant(|int: &general::Interface| {
+        int.imp.has_callback_interface()
+            && int.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    callback_interface_async || trait_interface_async
+}
+
+/// Modules to import, sorted and de-duplicated
+fn module_imports(namespace: &general::Namespace, context: &Context) -> Result<Vec<String>> {
+    let mut imports = BTreeSet::new();
+    for type_def in namespace.type_definitions.iter() {
+        if let general::TypeDefinition::Custom(custom) = type_def {
+            if let Some(custom_config) = context.custom_type_config(custom)? {
+                imports.extend(custom_config.imports.iter().flatten().cloned());
+            }
+        }
+    }
+    Ok(imports.into_iter().collect())
+}
+
+/// Functions to run when the module is initialized
+fn initialization_fns(namespace: &general::Namespace, context: &Context) -> Result<Vec<String>> {
+    let mut init_fns = vec![];
+    namespace.visit(|vtable: &general::VTable| {
+        init_fns.push(format!("uniffiCallbackInit{}", vtable.interface_name));
+    });
+    if namespace.closure_vtable.is_some() {
+        init_fns.push("uniffiClosureInit".to_string());
+    }
+
+    // Also call global initialization function for any external type we use.
+    // For example, we need to make sure that all callback interface vtables are registered
+    // (#2343).
+    let mut external_init_fns = BTreeSet::new();
+    for type_def in namespace.type_definitions.iter() {
+        if let general::TypeDefinition::External(ext) = type_def {
+            external_init_fns.insert(names::ensure_init_fn_name(
+                context.crate_name(&ext.namespace)?,
+            ));
+        }
+    }
+    init_fns.extend(external_init_fns);
+    Ok(init_fns)
+}
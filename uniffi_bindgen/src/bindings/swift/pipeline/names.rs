@@ -0,0 +1,176 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashSet;
+
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
+use once_cell::sync::Lazy;
+
+use super::*;
+
+/// From <https://docs.swift.org/swift-book/documentation/the-swift-programming-language/lexicalstructure/#Keywords-and-Punctuation>
+static KEYWORDS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    HashSet::from([
+        // Keywords used in declarations:
+        "associatedtype",
+        "class",
+        "deinit",
+        "enum",
+        "extension",
+        "fileprivate",
+        "func",
+        "import",
+        "init",
+        "inout",
+        "internal",
+        "let",
+        "open",
+        "operator",
+        "private",
+        "precedencegroup",
+        "protocol",
+        "public",
+        "rethrows",
+        "static",
+        "struct",
+        "subscript",
+        "typealias",
+        "var",
+        // Keywords used in statements:
+        "break",
+        "case",
+        "catch",
+        "continue",
+        "default",
+        "defer",
+        "do",
+        "else",
+        "fallthrough",
+        "for",
+        "guard",
+        "if",
+        "in",
+        "repeat",
+        "return",
+        "throw",
+        "switch",
+        "where",
+        "while",
+        // Keywords used in expressions and types:
+        "Any",
+        "as",
+        "await",
+        "false",
+        "is",
+        "nil",
+        "self",
+        "Self",
+        "super",
+        "throws",
+        "true",
+        "try",
+    ])
+});
+
+/// Per <https://docs.swift.org/swift-book/documentation/the-swift-programming-language/lexicalstructure/#Keywords-and-Punctuation>
+/// subset of keywords which need quoting in arg context.
+static ARG_KEYWORDS: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| HashSet::from(["inout", "var", "let"]));
+
+/// Quote a name for use in a context where keywords must be quoted
+fn quote_general_keyword(nm: String) -> String {
+    if KEYWORDS.contains(nm.as_str()) {
+        format!("`{nm}`")
+    } else {
+        nm
+    }
+}
+
+/// Quote a name for use in arg context where fewer keywords must be quoted
+fn quote_arg_keyword(nm: String) -> String {
+    if ARG_KEYWORDS.contains(nm.as_str()) {
+        format!("`{nm}`")
+    } else {
+        nm
+    }
+}
+
+pub fn map_ffi_function_type_name(
+    ffi_function_type_name: FfiFunctionTypeName,
+    _: &Context,
+) -> Result<FfiFunctionTypeName> {
+    Ok(FfiFunctionTypeName(ffi_callback_name(
+        &ffi_function_type_name.0,
+    )))
+}
+
+pub fn map_ffi_struct_name(name: FfiStructName, _: &Context) -> Result<FfiStructName> {
+    Ok(FfiStructName(ffi_struct_name(&name.0)))
+}
+
+/// Get the idiomatic Swift rendering of a class name (for enums, records, errors, etc).
+pub fn class_name(name: &str) -> String {
+    name.to_upper_camel_case()
+}
+
+/// Get the idiomatic Swift rendering of a function name.
+pub fn fn_name(name: &str) -> String {
+    quote_general_keyword(name.to_lower_camel_case())
+}
+
+/// Get the idiomatic Swift rendering of a variable name.
+pub fn var_name(name: &str) -> String {
+    quote_general_keyword(name.to_lower_camel_case())
+}
+
+/// Get the idiomatic Swift rendering of a field name.
+///
+/// Unnamed fields stay empty, the templates name those based on their position.
+pub fn field_name(name: &str) -> String {
+    if name.is_empty() {
+        String::new()
+    } else {
+        var_name(name)
+    }
+}
+
+/// Get the idiomatic Swift rendering of an argument label.
+///
+/// This is the same as the var name but fewer keywords need quoting.
+pub fn arg_name(name: &str) -> String {
+    quote_arg_keyword(name.to_lower_camel_case())
+}
+
+/// Get the idiomatic Swift rendering of an individual enum variant.
+pub fn enum_variant_name(name: &str) -> String {
+    quote_general_keyword(name.to_lower_camel_case())
+}
+
+/// Get the idiomatic Swift rendering of an error variant.
+pub fn error_variant_name(name: &str) -> String {
+    quote_general_keyword(name.to_upper_camel_case())
+}
+
+/// Get the idiomatic Swift rendering of an FFI callback function name
+pub fn ffi_callback_name(name: &str) -> String {
+    format!("Uniffi{}", name.to_upper_camel_case())
+}
+
+/// Get the idiomatic Swift rendering of an FFI struct name
+pub fn ffi_struct_name(name: &str) -> String {
+    format!("Uniffi{}", name.to_upper_camel_case())
+}
+
+/// Get the name of the `#ifndef` guard for an FFI definition in the bridging header
+pub fn if_guard_name(name: &str) -> String {
+    format!("UNIFFI_FFIDEF_{}", name.to_shouty_snake_case())
+}
+
+/// Name of the function that other modules call to make sure this one is initialized
+pub fn ensure_init_fn_name(crate_name: &str) -> String {
+    format!(
+        "uniffiEnsure{}Initialized",
+        crate_name.to_upper_camel_case()
+    )
+}
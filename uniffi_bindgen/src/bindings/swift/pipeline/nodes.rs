@@ -0,0 +1,776 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use askama::Template;
+
+use uniffi_pipeline::{MapNode, Node};
+
+use crate::{bindings::swift::filters, pipeline::general};
+
+use super::*;
+
+use_prev_node!(general::Checksum);
+use_prev_node!(general::ChecksumMode);
+use_prev_node!(general::EnumShape);
+use_prev_node!(general::FieldsKind);
+use_prev_node!(general::FfiFunctionKind);
+use_prev_node!(
+    general::FfiFunctionTypeName,
+    names::map_ffi_function_type_name
+);
+use_prev_node!(general::FfiStructName, names::map_ffi_struct_name);
+use_prev_node!(general::FfiType);
+use_prev_node!(general::HandleKind);
+use_prev_node!(general::ObjectImpl);
+use_prev_node!(general::PassBy);
+use_prev_node!(general::PropertyKind);
+use_prev_node!(general::PropertyMetadata);
+use_prev_node!(general::Radix);
+use_prev_node!(general::RustFfiFunctionName);
+use_prev_node!(general::TraitKind);
+use_prev_node!(general::Type);
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Root))]
+#[map_node(update_context(context.update_from_root(&self)?))]
+pub struct Root {
+    /// In library mode, the library path the user passed to us
+    pub cdylib: Option<String>,
+    #[map_node(from(namespaces))]
+    pub modules: IndexMap<String, Module>,
+    pub builtin_types: BuiltinTypes,
+    pub checksum_mode: ChecksumMode,
+}
+
+#[derive(Debug, Clone, MapNode, Node)]
+#[map_node(from(general::BuiltinTypes))]
+pub struct BuiltinTypes {
+    pub u8: TypeNode,
+    pub i8: TypeNode,
+    pub u16: TypeNode,
+    pub i16: TypeNode,
+    pub u32: TypeNode,
+    pub i32: TypeNode,
+    pub u64: TypeNode,
+    pub i64: TypeNode,
+    pub f32: TypeNode,
+    pub f64: TypeNode,
+    pub string: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Template)]
+#[template(syntax = "swift", escape = "none", path = "wrapper.swift")]
+#[map_node(from(general::Namespace))]
+#[map_node(modules::map_namespace)]
+pub struct Module {
+    pub name: String,
+    pub crate_name: String,
+    pub docstring: Option<String>,
+    /// Name of the Swift module containing the high-level bindings
+    pub module_name: String,
+    /// Name of the lower-level C module containing the FFI declarations
+    pub ffi_module_name: String,
+    /// Filename stem for the lower-level C module
+    pub ffi_module_filename: String,
+    pub header_filename: String,
+    pub modulemap_filename: String,
+    pub generate_module_map: bool,
+    /// Extra frameworks to link this module against in the modulemap
+    pub link_frameworks: Vec<String>,
+    /// Modules to import, sorted and de-duplicated
+    pub imports: Vec<String>,
+    /// Functions to run when the module is initialized
+    pub initialization_fns: Vec<String>,
+    /// Public function that other modules call to make sure this one is initialized
+    pub ensure_init_fn_name: String,
+    /// Public function that counts the pending foreign futures, used for testing
+    pub foreign_future_handle_count_fn: String,
+    pub has_async_fns: bool,
+    pub has_streams: bool,
+    pub has_async_callback_method: bool,
+    pub has_callback_interface: bool,
+    pub omit_checksums: bool,
+    pub omit_argument_labels: bool,
+    pub omit_localized_error_conformance: bool,
+    /// Use the nanosecond-precision `UniffiTimestamp` and `UniffiDuration` structs
+    pub lossless_time_types: bool,
+    pub functions: Vec<Function>,
+    pub constants: Vec<Constant>,
+    pub type_definitions: Vec<TypeDefinition>,
+    pub ffi_definitions: IndexSet<FfiDefinition>,
+    pub checksums: Vec<Checksum>,
+    pub ffi_rustbuffer_alloc: RustFfiFunctionName,
+    pub ffi_rustbuffer_from_bytes: RustFfiFunctionName,
+    pub ffi_rustbuffer_free: RustFfiFunctionName,
+    pub ffi_rustbuffer_reserve: RustFfiFunctionName,
+    pub ffi_uniffi_contract_version: RustFfiFunctionName,
+    pub closure_vtable: Option<ClosureVTable>,
+    // Correct contract version value
+    pub correct_contract_version: String,
+    // Copy builtin types so that we can use in from the templates
+    pub builtin_types: BuiltinTypes,
+    pub checksum_mode: ChecksumMode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constant))]
+pub struct Constant {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// The value rendered as a Swift expression
+    #[map_node(default::render_literal(&self.value, context)?)]
+    pub swift_value: String,
+    pub ty: TypeNode,
+    pub value: Literal,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Function))]
+pub struct Function {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeDefinition))]
+pub enum TypeDefinition {
+    Interface(Interface),
+    CallbackInterface(CallbackInterface),
+    Record(Record),
+    Enum(Enum),
+    Custom(CustomType),
+    /// Type that doesn't contain any other type
+    Simple(TypeNode),
+    /// Compound types
+    Box(BoxedType),
+    Optional(OptionalType),
+    Sequence(SequenceType),
+    Map(MapType),
+    Set(SetType),
+    Stream(StreamType),
+    Tuple(TupleType),
+    Array(ArrayType),
+    Closure(ClosureType),
+    /// User types that are defined in another crate
+    External(ExternalType),
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constructor))]
+pub struct Constructor {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Method))]
+pub struct Method {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+    pub property: Option<PropertyMetadata>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Property))]
+pub struct Property {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+    pub getter: Method,
+    pub setter: Option<Method>,
+}
+
+/// Common data from Function/Method/Constructor
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Callable))]
+pub struct Callable {
+    #[map_node(names::fn_name(&self.name))]
+    pub name: String,
+    /// `@available` attribute, set if the arguments or return type use a 128-bit integer
+    #[map_node(types::callable_availability(&self, context))]
+    pub availability: Option<String>,
+    pub async_data: Option<AsyncData>,
+    pub kind: CallableKind,
+    pub arguments: Vec<Argument>,
+    pub return_type: ReturnType,
+    pub throws_type: ThrowsType,
+    pub checksum: Option<u16>,
+    pub ffi_func: RustFfiFunctionName,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::CallableKind))]
+pub enum CallableKind {
+    /// Toplevel function
+    Function,
+    /// Interface/Trait interface method
+    Method {
+        self_type: TypeNode,
+        takes_self_by_arc: bool,
+    },
+    /// Interface constructor
+    Constructor { self_type: TypeNode, primary: bool },
+    /// Interface static method, these don't take a `self` argument
+    StaticMethod { self_type: TypeNode },
+    /// Method inside a VTable or a CallbackInterface
+    ///
+    /// For trait interfaces this only applies to the Callables inside the `vtable.methods` field.
+    /// Callables inside `Interface::methods` will still be `Callable::Method`.
+    VTableMethod {
+        self_type: TypeNode,
+        takes_self_by_arc: bool,
+        for_callback_interface: bool,
+    },
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::AsyncData))]
+pub struct AsyncData {
+    pub ffi_rust_future_poll: RustFfiFunctionName,
+    pub ffi_rust_future_cancel: RustFfiFunctionName,
+    pub ffi_rust_future_free: RustFfiFunctionName,
+    pub ffi_rust_future_complete: RustFfiFunctionName,
+    pub ffi_foreign_future_complete: FfiFunctionTypeName,
+    pub ffi_foreign_future_result: FfiStructName,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::ReturnType))]
+pub struct ReturnType {
+    pub ty: Option<TypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::ThrowsType))]
+pub struct ThrowsType {
+    /// Function to lift the error from the `RustBuffer` stored in `RustCallStatus`
+    #[map_node(self.ty.as_ref().map(|ty| types::error_lift_fn(&ty.ty)))]
+    pub error_lift_fn: Option<String>,
+    pub ty: Option<TypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Argument))]
+pub struct Argument {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// Argument label, when calling a function.  This needs fewer keywords quoted than `name`.
+    #[map_node(names::arg_name(&self.name))]
+    pub label: String,
+    /// Name of the argument in the FFI function type for VTable methods
+    #[map_node(names::var_name(&self.orig_name))]
+    pub ffi_name: String,
+    /// Swift expression to pass the argument to an FFI call
+    #[map_node(types::argument_lower_expr(&self))]
+    pub lower_expr: String,
+    /// Is this a `&[u8]` / borrowed `Bytes` argument?
+    ///
+    /// These are passed across the FFI as a `ForeignBytes` without copying the underlying
+    /// buffer.
+    #[map_node(self.is_borrowed_bytes())]
+    pub is_borrowed_bytes: bool,
+    /// Is this a `&mut [u8]` argument?  These are passed as `inout Data`.
+    #[map_node(self.is_borrowed_bytes_mut())]
+    pub is_borrowed_bytes_mut: bool,
+    /// Is this a closure argument?  These are marked `@escaping`, since they're stored in a
+    /// handle map when passed to Rust.
+    #[map_node(matches!(self.ty.ty, Type::Closure { .. }))]
+    pub is_closure: bool,
+    pub ty: TypeNode,
+    pub pass_by: PassBy,
+    pub optional: bool,
+    pub default: Option<DefaultValueNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::DefaultValue))]
+pub enum DefaultValue {
+    Default(TypeNode),
+    Literal(Literal),
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::DefaultValue))]
+pub struct DefaultValueNode {
+    /// The default value rendered as a Swift expression
+    #[map_node(default::render_default(&self, context)?)]
+    pub swift_default: String,
+    #[map_node(self.map_node(context)?)]
+    pub default: DefaultValue,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::Literal))]
+pub enum Literal {
+    Boolean(bool),
+    String(String),
+    // Integers are represented as the widest representation we can.
+    // Number formatting vary with language and radix, so we avoid a lot of parsing and
+    // formatting duplication by using only signed and unsigned variants.
+    UInt(u64, Radix, TypeNode),
+    Int(i64, Radix, TypeNode),
+    // Pass the string representation through as typed in the UDL.
+    // This avoids a lot of uncertainty around precision and accuracy,
+    // though bindings for languages less sophisticated number parsing than WebIDL
+    // will have to do extra work.
+    Float(String, TypeNode),
+    Enum(String, TypeNode),
+    EmptySequence,
+    EmptyMap,
+    EmptySet,
+    None,
+    Some { inner: Box<DefaultValue> },
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Record))]
+pub struct Record {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    /// Should the fields be `let` rather than `var`?
+    #[map_node(context.config()?.is_record_immutable(&self.name))]
+    pub immutable: bool,
+    /// Protocol conformances, comma-separated
+    #[map_node(conformances::record_conformances(&self, context)?)]
+    pub conformances: String,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Field))]
+pub struct Field {
+    /// Swift name for the field, this is empty for unnamed fields
+    #[map_node(names::field_name(&self.name))]
+    pub name: String,
+    /// Argument label for the field, when passing it to an initializer
+    #[map_node(names::arg_name(&self.name))]
+    pub label: String,
+    pub ty: TypeNode,
+    pub default: Option<DefaultValueNode>,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Enum))]
+#[map_node(enums::map_enum)]
+pub struct Enum {
+    pub name: String,
+    /// Is this a "flat" enum -- one with no associated data
+    pub is_flat: bool,
+    pub shape: EnumShape,
+    /// Was the discriminant type specified by the user?
+    ///
+    /// If so, the enum gets a raw value with the discriminant.
+    pub discr_specified: bool,
+    pub variants: Vec<Variant>,
+    pub discr_type: TypeNode,
+    /// Protocol conformances, comma-separated
+    ///
+    /// For errors, this doesn't include the `Swift.Error` conformance that all errors have.
+    pub conformances: String,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Variant {
+    /// Variant name.
+    ///
+    /// This depends on the kind of enum: `lowerCamelCase` for regular enums and
+    /// `UpperCamelCase` for errors.
+    pub name: String,
+    /// The discriminant, rendered as a Swift literal
+    pub discr_literal: String,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Interface))]
+pub struct Interface {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    /// Name of the Swift protocol that declares the methods
+    #[map_node(interfaces::protocol_name(&self))]
+    pub protocol_name: String,
+    /// Name of the class that wraps a Rust handle
+    #[map_node(interfaces::impl_name(&self))]
+    pub impl_name: String,
+    /// Swift protocols for the traits this interface implements
+    #[map_node(interfaces::trait_protocol_names(&self)?)]
+    pub trait_protocol_names: Vec<String>,
+    /// Protocol conformances for the class, comma-separated
+    #[map_node(conformances::interface_conformances(&self, context)?)]
+    pub conformances: String,
+    pub docstring: Option<String>,
+    pub constructors: Vec<Constructor>,
+    pub properties: Vec<Property>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub trait_impls: Vec<ObjectTraitImpl>,
+    pub imp: ObjectImpl,
+    pub self_type: TypeNode,
+    pub vtable: Option<VTable>,
+    pub ffi_func_clone: RustFfiFunctionName,
+    pub ffi_func_free: RustFfiFunctionName,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallbackInterface))]
+pub struct CallbackInterface {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    pub docstring: Option<String>,
+    pub vtable: VTable,
+    pub methods: Vec<Method>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTable))]
+pub struct VTable {
+    pub struct_type: FfiTypeNode,
+    pub interface_name: String,
+    pub init_fn: RustFfiFunctionName,
+    pub clone_fn_type: FfiFunctionTypeName,
+    pub free_fn_type: FfiFunctionTypeName,
+    pub methods: Vec<VTableMethod>,
+}
+
+/// Single method in a vtable
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTableMethod))]
+pub struct VTableMethod {
+    /// Name of the VTable struct field that stores the function pointer for this method
+    #[map_node(names::var_name(&self.ffi_field_name))]
+    pub ffi_field_name: String,
+    /// Default value for the FFI return type, used when the call fails
+    #[map_node(ffi_types::return_ffi_default_value(&self.callable.return_type)?)]
+    pub ffi_default_value: Option<String>,
+    /// The FFI function type that the method's callback implements
+    #[map_node(ffi_types::vtable_method_function_type(&self.ffi_type, context)?)]
+    pub ffi_function_type: FfiFunctionType,
+    pub callable: Callable,
+    pub ffi_type: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ObjectTraitImpl))]
+pub struct ObjectTraitImpl {
+    pub ty: TypeNode,
+    pub trait_ty: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CustomType))]
+pub struct CustomType {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    #[map_node(context.custom_type_config(&self)?)]
+    pub config: Option<CustomTypeConfig>,
+    pub builtin: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::BoxedType))]
+pub struct BoxedType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::OptionalType))]
+pub struct OptionalType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SequenceType))]
+pub struct SequenceType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::MapType))]
+pub struct MapType {
+    pub key: TypeNode,
+    pub value: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SetType))]
+pub struct SetType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TupleType))]
+pub struct TupleType {
+    pub elements: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ArrayType))]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    pub is_byte_array: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureType))]
+pub struct ClosureType {
+    pub arguments: Vec<TypeNode>,
+    pub return_type: ReturnType,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureVTable))]
+pub struct ClosureVTable {
+    pub struct_type: FfiTypeNode,
+    pub init_fn: RustFfiFunctionName,
+    pub free_fn_type: FfiFunctionTypeName,
+    pub call_fn_type: FfiFunctionTypeName,
+    pub ffi_closure_call: RustFfiFunctionName,
+    pub ffi_closure_free: RustFfiFunctionName,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::StreamType))]
+pub struct StreamType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+    pub ffi_stream_next: RustFfiFunctionName,
+    pub ffi_stream_free: RustFfiFunctionName,
+    pub async_data: AsyncData,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ExternalType))]
+pub struct ExternalType {
+    pub namespace: String,
+    pub name: String,
+    pub self_type: TypeNode,
+}
+
+/// Wrap `Type` so that we can add extra fields that are set for all variants.
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::TypeNode))]
+pub struct TypeNode {
+    /// Swift type name
+    #[map_node(types::type_name(&self.ty, context)?)]
+    pub type_name: String,
+    /// Name that can be used as part of another identifier, for example `FfiConverterOptionInt32`
+    #[map_node(types::canonical_name(&self.ty))]
+    pub canonical_name: String,
+    #[map_node(types::ffi_converter_name(&self.ty))]
+    pub ffi_converter_name: String,
+    /// Function to lower values of this type
+    #[map_node(types::lower_fn(&self.ty))]
+    pub lower_fn: String,
+    /// Function to lift values of this type
+    #[map_node(types::lift_fn(&self.ty))]
+    pub lift_fn: String,
+    /// Is this a user-defined type from another module?
+    #[map_node(types::is_external(&self.ty, context)?)]
+    pub is_external: bool,
+    /// `@available` attribute for declarations that use this type, set for 128-bit integers
+    #[map_node(types::availability(&self.ty, context))]
+    pub availability: Option<String>,
+    pub ty: Type,
+    pub is_used_as_error: bool,
+    pub ffi_type: FfiTypeNode,
+}
+
+/// Like `TypeNode` but for FFI types.
+///
+/// This exists so that language bindings generators can add extra fields
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(FfiType))]
+pub struct FfiTypeNode {
+    /// Swift type name
+    #[map_node(ffi_types::ffi_type_name(&self))]
+    pub type_name: String,
+    /// C type name, for the bridging header
+    #[map_node(ffi_types::header_ffi_type_name(&self))]
+    pub header_type_name: String,
+    #[map_node(self.map_node(context)?)]
+    pub ty: FfiType,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::UniffiTraitMethods))]
+pub struct UniffiTraitMethods {
+    pub debug_fmt: Option<Method>,
+    pub display_fmt: Option<Method>,
+    pub eq_eq: Option<Method>,
+    pub eq_ne: Option<Method>,
+    pub hash_hash: Option<Method>,
+    pub ord_cmp: Option<Method>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::FfiDefinition))]
+pub enum FfiDefinition {
+    /// FFI Function exported in the Rust library
+    RustFunction(FfiFunction),
+    /// FFI Function definition used in the interface, language, for example a callback interface method.
+    FunctionType(FfiFunctionType),
+    /// Struct definition used in the interface, for example a callback interface Vtable.
+    Struct(FfiStruct),
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunction))]
+pub struct FfiFunction {
+    /// Name of the `#ifndef` guard in the bridging header
+    #[map_node(names::if_guard_name(&self.name.0))]
+    pub if_guard_name: String,
+    pub name: RustFfiFunctionName,
+    pub async_data: Option<AsyncData>,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+    pub kind: FfiFunctionKind,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunctionType))]
+pub struct FfiFunctionType {
+    /// Name of the `#ifndef` guard in the bridging header
+    #[map_node(names::if_guard_name(&self.name.0))]
+    pub if_guard_name: String,
+    pub name: FfiFunctionTypeName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiReturnType))]
+pub struct FfiReturnType {
+    pub ty: Option<FfiTypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiStruct))]
+pub struct FfiStruct {
+    /// Name of the `#ifndef` guard in the bridging header
+    #[map_node(names::if_guard_name(&self.name.0))]
+    pub if_guard_name: String,
+    pub name: FfiStructName,
+    pub fields: Vec<FfiField>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiField))]
+pub struct FfiField {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiArgument))]
+pub struct FfiArgument {
+    /// Name for the argument in Swift closures that implement an FFI function type
+    #[map_node(names::var_name(&self.name))]
+    pub var_name: String,
+    /// Argument name, as declared in the bridging header
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+impl Callable {
+    pub fn is_async(&self) -> bool {
+        self.async_data.is_some()
+    }
+
+    pub fn throws(&self) -> bool {
+        self.throws_type.ty.is_some()
+    }
+
+    pub fn self_type(&self) -> Option<TypeNode> {
+        match &self.kind {
+            CallableKind::Method { self_type, .. }
+            | CallableKind::VTableMethod { self_type, .. } => Some(self_type.clone()),
+            _ => None,
+        }
+    }
+
+    /// Is this a method on an interface?
+    ///
+    /// These are called using the object's handle, rather than lowering `self`.
+    pub fn is_interface_method(&self) -> bool {
+        matches!(
+            self.self_type(),
+            Some(TypeNode {
+                ty: Type::Interface { .. },
+                ..
+            })
+        )
+    }
+
+    pub fn is_primary_constructor(&self) -> bool {
+        matches!(self.kind, CallableKind::Constructor { primary: true, .. })
+    }
+}
+
+impl Interface {
+    pub fn has_callback_interface(&self) -> bool {
+        self.imp.has_callback_interface()
+    }
+
+    pub fn primary_constructor(&self) -> Option<&Constructor> {
+        self.constructors
+            .iter()
+            .find(|c| c.callable.is_primary_constructor())
+    }
+
+    pub fn alternate_constructors(&self) -> Vec<&Constructor> {
+        self.constructors
+            .iter()
+            .filter(|c| !c.callable.is_primary_constructor())
+            .collect()
+    }
+}
+
+impl Record {
+    pub fn has_fields(&self) -> bool {
+        !self.fields.is_empty()
+    }
+}
+
+impl Variant {
+    pub fn has_fields(&self) -> bool {
+        !self.fields.is_empty()
+    }
+
+    pub fn has_nameless_fields(&self) -> bool {
+        matches!(self.fields_kind, FieldsKind::Unnamed)
+    }
+}
@@ -0,0 +1,227 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Timestamp if context.config()?.lossless_time_types() => "UniffiTimestamp".to_string(),
+        Type::Duration if context.config()?.lossless_time_types() => "UniffiDuration".to_string(),
+        Type::Timestamp => "Date".to_string(),
+        Type::Duration => "TimeInterval".to_string(),
+        Type::Uuid => "UUID".to_string(),
+        Type::Url => "URL".to_string(),
+        // Foundation doesn't have a date-only type, use the year, month and day components
+        Type::Date => "DateComponents".to_string(),
+        Type::Interface { name, .. }
+        | Type::Record { name, .. }
+        | Type::Enum { name, .. }
+        | Type::CallbackInterface { name, .. }
+        | Type::Custom { name, .. } => names::class_name(name),
+        // Closure types need parentheses, otherwise the `?` would apply to the return type
+        Type::Optional { inner_type } => match **inner_type {
+            Type::Closure { .. } => format!("({})?", type_name(inner_type, context)?),
+            _ => format!("{}?", type_name(inner_type, context)?),
+        },
+        Type::Sequence { inner_type } => format!("[{}]", type_name(inner_type, context)?),
+        Type::Array { inner_type, .. } => match **inner_type {
+            Type::UInt8 => "Data".to_string(),
+            _ => format!("[{}]", type_name(inner_type, context)?),
+        },
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "[{}: {}]",
+            type_name(key_type, context)?,
+            type_name(value_type, context)?
+        ),
+        Type::Set { inner_type } => format!("Set<{}>", type_name(inner_type, context)?),
+        Type::Stream { inner_type } => format!(
+            "AsyncThrowingStream<{}, Swift.Error>",
+            type_name(inner_type, context)?
+        ),
+        Type::Tuple { types } => format!(
+            "({})",
+            types
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        Type::Closure {
+            arguments,
+            return_type,
+        } => format!(
+            "@Sendable ({}) -> {}",
+            arguments
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", "),
+            match return_type {
+                Some(ty) => type_name(ty, context)?,
+                None => "Void".to_string(),
+            }
+        ),
+        Type::Box { inner_type } => type_name(inner_type, context)?,
+        _ => primitive_type_name(ty),
+    })
+}
+
+/// Swift type name for the primitive types, these don't depend on the config
+fn primitive_type_name(ty: &Type) -> String {
+    match ty {
+        Type::Boolean => "Bool".to_string(),
+        Type::String => "String".to_string(),
+        Type::Bytes | Type::OwnedBytes => "Data".to_string(),
+        Type::Int8 => "Int8".to_string(),
+        Type::Int16 => "Int16".to_string(),
+        Type::Int32 => "Int32".to_string(),
+        Type::Int64 => "Int64".to_string(),
+        Type::Int128 => "Int128".to_string(),
+        Type::UInt8 => "UInt8".to_string(),
+        Type::UInt16 => "UInt16".to_string(),
+        Type::UInt32 => "UInt32".to_string(),
+        Type::UInt64 => "UInt64".to_string(),
+        Type::UInt128 => "UInt128".to_string(),
+        Type::Float32 => "Float".to_string(),
+        Type::Float64 => "Double".to_string(),
+        _ => unreachable!("{ty:?} is not a primitive type"),
+    }
+}
+
+/// A representation of the type name that can be used as part of another identifier.
+///
+/// For example `FfiConverterOptionInt32`.
+pub fn canonical_name(ty: &Type) -> String {
+    match ty {
+        Type::Timestamp => "Timestamp".to_string(),
+        Type::Duration => "Duration".to_string(),
+        Type::Uuid => "Uuid".to_string(),
+        Type::Url => "Url".to_string(),
+        Type::Date => "Date".to_string(),
+        Type::OwnedBytes => "OwnedData".to_string(),
+        Type::Interface { name, .. }
+        | Type::Record { name, .. }
+        | Type::Enum { name, .. }
+        | Type::Custom { name, .. } => format!("Type{name}"),
+        Type::CallbackInterface { name, .. } => {
+            format!("CallbackInterface{}", names::class_name(name))
+        }
+        Type::Optional { inner_type } => format!("Option{}", canonical_name(inner_type)),
+        Type::Sequence { inner_type } => format!("Sequence{}", canonical_name(inner_type)),
+        Type::Array { inner_type, len } => format!("Array{len}{}", canonical_name(inner_type)),
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "Dictionary{}{}",
+            canonical_name(key_type),
+            canonical_name(value_type)
+        ),
+        Type::Set { inner_type } => format!("Set{}", canonical_name(inner_type)),
+        Type::Stream { inner_type } => format!("Stream{}", canonical_name(inner_type)),
+        Type::Tuple { types } => format!(
+            "Tuple{}{}",
+            types.len(),
+            types.iter().map(canonical_name).collect::<String>()
+        ),
+        Type::Closure {
+            arguments,
+            return_type,
+        } => format!(
+            "Closure{}{}{}",
+            arguments.len(),
+            arguments.iter().map(canonical_name).collect::<String>(),
+            match return_type {
+                Some(ty) => canonical_name(ty),
+                None => "Void".to_string(),
+            }
+        ),
+        Type::Box { inner_type } => canonical_name(inner_type),
+        _ => primitive_type_name(ty),
+    }
+}
+
+/// Name of the FfiConverter
+///
+/// This is the struct that contains the lower, write, lift, and read methods for this type.
+pub fn ffi_converter_name(ty: &Type) -> String {
+    format!("FfiConverter{}", canonical_name(ty))
+}
+
+// To better support external types, we always call the "public" lift and lower functions for
+// "named" types, regardless of whether they are being called from a type in the same crate
+// (ie, a "local" type) or from a different crate (ie, an "external" type)
+pub fn lower_fn(ty: &Type) -> String {
+    match ty.name() {
+        Some(_) => format!("{}_lower", ffi_converter_name(ty)),
+        None => format!("{}.lower", ffi_converter_name(ty)),
+    }
+}
+
+/// See `lower_fn` - we always use the public version for named types.
+pub fn lift_fn(ty: &Type) -> String {
+    match ty.name() {
+        Some(_) => format!("{}_lift", ffi_converter_name(ty)),
+        None => format!("{}.lift", ffi_converter_name(ty)),
+    }
+}
+
+/// Function to lift an error from a `RustBuffer`
+///
+/// Objects are always passed as handles, except when they're thrown as errors.  In that case
+/// they're serialized into a `RustBuffer` and lifted with a special converter.
+pub fn error_lift_fn(ty: &Type) -> String {
+    match ty {
+        Type::Interface { .. } => format!("{}__as_error_lift", ffi_converter_name(ty)),
+        _ => lift_fn(ty),
+    }
+}
+
+/// Availability of the `Int128` and `UInt128` types
+const INT128_AVAILABILITY: &str =
+    "@available(macOS 15, iOS 18, tvOS 18, watchOS 11, visionOS 2, *)";
+
+/// `@available` attribute for declarations that use `ty`, if it needs one
+pub fn availability(ty: &Type, context: &Context) -> Option<String> {
+    context
+        .requires_int128(ty)
+        .then(|| INT128_AVAILABILITY.to_string())
+}
+
+/// `@available` attribute for a function/method, if any of its types need one
+pub fn callable_availability(callable: &general::Callable, context: &Context) -> Option<String> {
+    let mut types = callable
+        .arguments
+        .iter()
+        .map(|arg| &arg.ty.ty)
+        .chain(callable.return_type.ty.iter().map(|ty| &ty.ty))
+        .chain(callable.throws_type.ty.iter().map(|ty| &ty.ty));
+    types
+        .any(|ty| context.requires_int128(ty))
+        .then(|| INT128_AVAILABILITY.to_string())
+}
+
+pub fn is_external(ty: &Type, context: &Context) -> Result<bool> {
+    Ok(match ty.namespace() {
+        Some(namespace) => context.is_external(namespace)?,
+        None => false,
+    })
+}
+
+/// Swift expression to emit at an argument's position in an FFI call.
+///
+/// For borrowed `Bytes` (`&[u8]`), this is a bare reference to the `{name}Fb` variable bound by
+/// the outer `FfiConverterByRefBytes.lower` scope (see the `to_ffi_call` macro).  For every other
+/// argument, this is the standard lower function applied to the argument.
+pub fn argument_lower_expr(arg: &general::Argument) -> String {
+    let name = names::var_name(&arg.name);
+    if arg.is_borrowed_bytes() {
+        format!("{name}Fb")
+    } else {
+        format!("{}({name})", lower_fn(&arg.ty.ty))
+    }
+}
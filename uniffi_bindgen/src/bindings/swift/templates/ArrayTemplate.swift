@@ -0,0 +1,35 @@
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+{% call swift::availability(array.self_type.availability) %}{% endcall %}fileprivate struct {{ array.self_type.ffi_converter_name }}: FfiConverterRustBuffer {
+    typealias SwiftType = {{ array.self_type.type_name }}
+
+    public static func write(_ value: {{ array.self_type.type_name }}, into buf: inout [UInt8]) {
+        precondition(value.count == {{ array.len }}, "Expected an array of length {{ array.len }}, got \(value.count)")
+        writeInt(&buf, Int32({{ array.len }}))
+        {%- if array.is_byte_array %}
+        writeBytes(&buf, value)
+        {%- else %}
+        for item in value {
+            {{ array.inner.ffi_converter_name }}.write(item, into: &buf)
+        }
+        {%- endif %}
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ array.self_type.type_name }} {
+        let len: Int32 = try readInt(&buf)
+        if len != {{ array.len }} {
+            throw UniffiInternalError.unexpectedArrayLength
+        }
+        {%- if array.is_byte_array %}
+        return Data(try readBytes(&buf, count: Int(len)))
+        {%- else %}
+        var seq = {{ array.self_type.type_name }}()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try {{ array.inner.ffi_converter_name }}.read(from: &buf))
+        }
+        return seq
+        {%- endif %}
+    }
+}
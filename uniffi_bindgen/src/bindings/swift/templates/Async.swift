@@ -47,7 +47,57 @@ fileprivate func uniffiFutureContinuationCallback(handle: UInt64, pollResult: In
     }
 }
 
-{%- if ci.has_async_callback_interface_definition() %}
+{%- if has_streams %}
+
+// Owns a stream handle returned from Rust.  The Rust stream is dropped once this is deinitialized.
+fileprivate final class UniffiStreamHandle: @unchecked Sendable {
+    let handle: UInt64
+    let freeFunc: (UInt64) -> ()
+
+    init(_ handle: UInt64, freeFunc: @escaping (UInt64) -> ()) {
+        self.handle = handle
+        self.freeFunc = freeFunc
+    }
+
+    deinit {
+        freeFunc(handle)
+    }
+}
+
+// Create an `AsyncThrowingStream` for a stream returned from Rust
+//
+// Each item is fetched by calling `nextFunc`, which returns a Rust future that resolves to the
+// item.  `liftItem` returns `nil` once the Rust stream is exhausted.
+fileprivate func uniffiRustStream<T>(
+    handle: UInt64,
+    nextFunc: @escaping (UInt64) -> UInt64,
+    freeStreamFunc: @escaping (UInt64) -> (),
+    pollFunc: @escaping (UInt64, @escaping UniffiRustFutureContinuationCallback, UInt64) -> (),
+    completeFunc: @escaping (UInt64, UnsafeMutablePointer<RustCallStatus>) -> RustBuffer,
+    freeFunc: @escaping (UInt64) -> (),
+    liftItem: @escaping (RustBuffer) throws -> T??
+) -> AsyncThrowingStream<T, Swift.Error> {
+    let streamHandle = UniffiStreamHandle(handle, freeFunc: freeStreamFunc)
+    return AsyncThrowingStream(unfolding: {
+        let item = try await uniffiRustCallAsync(
+            rustFutureFunc: { nextFunc(streamHandle.handle) },
+            pollFunc: pollFunc,
+            completeFunc: completeFunc,
+            freeFunc: freeFunc,
+            liftFunc: liftItem,
+            errorHandler: nil
+        )
+        switch item {
+        case .none:
+            return nil
+        case .some(let value):
+            return value
+        }
+    })
+}
+{%- endif %}
+
+{%- if has_async_callback_method %}
 private func uniffiTraitInterfaceCallAsync<T>(
     makeCall: @escaping @Sendable () async throws -> T,
     handleSuccess: @escaping @Sendable (T) -> (),
@@ -67,7 +117,7 @@ private func uniffiTraitInterfaceCallAsync<T>(
         do {
             callResult = try await makeCall()
         } catch {
-            handleError(CALL_UNEXPECTED_ERROR, {{ Type::String.borrow()|lower_fn }}(String(describing: error)))
+            handleError(CALL_UNEXPECTED_ERROR, {{ builtin_types.string.lower_fn }}(String(describing: error)))
             return
         }
         handleSuccess(callResult)
@@ -96,7 +146,7 @@ private func uniffiTraitInterfaceCallAsyncWithError<T, E>(
             handleError(CALL_ERROR, lowerError(error))
             return
         } catch {
-            handleError(CALL_UNEXPECTED_ERROR, {{ Type::String.borrow()|lower_fn }}(String(describing: error)))
+            handleError(CALL_UNEXPECTED_ERROR, {{ builtin_types.string.lower_fn }}(String(describing: error)))
             return
         }
         handleSuccess(callResult)
@@ -135,7 +185,7 @@ private func uniffiForeignFutureDroppedCallback(handle: UInt64) {
 }
 
 // For testing
-public func uniffiForeignFutureHandleCount{{ ci.namespace()|class_name }}() -> Int {
+public func {{ foreign_future_handle_count_fn }}() -> Int {
     UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.count
 }
 
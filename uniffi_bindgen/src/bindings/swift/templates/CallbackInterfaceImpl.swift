@@ -1,66 +1,68 @@
-{%- let trait_impl=format!("UniffiCallbackInterface{}", name) %}
+{%- let trait_impl = format!("UniffiCallbackInterface{}", vtable.interface_name) %}
 
 // Put the implementation in a struct so we don't pollute the top-level namespace
-fileprivate struct {{ trait_impl }} {
+{% call swift::availability(availability) %}{% endcall %}fileprivate struct {{ trait_impl }} {
 
     // Create the VTable using a series of closures.
     // Swift automatically converts these into C callback functions.
     //
     // Store the vtable directly.
-    static let vtable: {{ vtable|ffi_type_name }} = {{ vtable|ffi_type_name }}(
+    static let vtable: {{ vtable.struct_type.type_name }} = {{ vtable.struct_type.type_name }}(
         uniffiFree: { (uniffiHandle: UInt64) -> () in
             do {
                 try {{ ffi_converter_name }}.handleMap.remove(handle: uniffiHandle)
             } catch {
-                print("Uniffi callback interface {{ name }}: handle missing in uniffiFree")
+                print("Uniffi callback interface {{ vtable.interface_name }}: handle missing in uniffiFree")
             }
         },
         uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
             do {
                 return try {{ ffi_converter_name }}.handleMap.clone(handle: uniffiHandle)
             } catch {
-                fatalError("Uniffi callback interface {{ name }}: handle missing in uniffiClone")
+                fatalError("Uniffi callback interface {{ vtable.interface_name }}: handle missing in uniffiClone")
             }
         },
-        {%- for (ffi_callback, meth) in vtable_methods %}
-        {{ meth.name()|fn_name }}: { (
-            {%- for arg in ffi_callback.arguments() %}
-            {{ arg.name()|var_name }}: {{ arg.type_().borrow()|ffi_type_name }}{% if !loop.last || ffi_callback.has_rust_call_status_arg() %},{% endif %}
+        {%- for vtable_method in vtable.methods %}
+        {%- let ffi_callback = vtable_method.ffi_function_type %}
+        {%- let meth = vtable_method.callable %}
+        {{ vtable_method.ffi_field_name }}: { (
+            {%- for arg in ffi_callback.arguments %}
+            {{ arg.var_name }}: {{ arg.ty.type_name }}{% if !loop.last || ffi_callback.has_rust_call_status_arg %},{% endif %}
             {%- endfor -%}
-            {%- if ffi_callback.has_rust_call_status_arg() %}
+            {%- if ffi_callback.has_rust_call_status_arg %}
             uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
             {%- endif %}
         ) in
-            {%- for arg in ffi_callback.arguments() %}
-            {%- if let FfiType::RustBuffer(_) = arg.type_() %}
+            {%- for arg in ffi_callback.arguments %}
+            {%- if let FfiType::RustBuffer(_) = arg.ty.ty %}
             {# RustBuffer is not a Sendable type because it stores a pointer.
              # However, we coordinate with the Rust side of the FFI to ensure it's used in a thread-safe manner.
              -#}
-            nonisolated(unsafe) let {{ arg.name()|var_name }} = {{ arg.name()|var_name }}
+            nonisolated(unsafe) let {{ arg.var_name }} = {{ arg.var_name }}
             {%- endif %}
             {%- endfor %}
 
-            let makeCall: @Sendable () {% if meth.is_async() %}async {% endif %}throws -> {{ meth.return_type()|return_type_name }} = {
-                () {% if meth.is_async() %}async {% endif %}throws -> {% match meth.return_type() %}{% when Some(t) %}{{ t|type_name }}{% when None %}(){% endmatch %} in
+            let makeCall: @Sendable () {% if meth.is_async() %}async {% endif %}throws -> {% call swift::return_type_name(meth) %}{% endcall %} = {
+                () {% if meth.is_async() %}async {% endif %}throws -> {% call swift::return_type_name(meth) %}{% endcall %} in
                 guard let uniffiObj = try? {{ ffi_converter_name }}.handleMap.get(handle: uniffiHandle) else {
                     throw UniffiInternalError.unexpectedStaleHandle
                 }
-                return {% if meth.throws() %}try {% endif %}{% if meth.is_async() %}await {% endif %}uniffiObj.{{ meth.name()|fn_name }}(
-                    {%- for arg in meth.arguments() %}
-                    {% if !config.omit_argument_labels() %} {{ arg.name()|arg_name }}: {% endif %}try {{ arg|lift_fn }}({{ arg.name()|var_name }}){% if !loop.last %},{% endif %}
+                return {% if meth.throws() %}try {% endif %}{% if meth.is_async() %}await {% endif %}uniffiObj.{{ meth.name }}(
+                    {%- for arg in meth.arguments %}
+                    {% if !omit_argument_labels %} {{ arg.label }}: {% endif %}try {{ arg.ty.lift_fn }}({{ arg.ffi_name }}){% if !loop.last %},{% endif %}
                     {%- endfor %}
                 )
             }
             {%- if !meth.is_async() %}
 
-            {% match meth.return_type() %}
+            {% match meth.return_type.ty %}
             {%- when Some(t) %}
-            let writeReturn = { uniffiOutReturn.pointee = {{ t|lower_fn }}($0) }
+            let writeReturn = { uniffiOutReturn.pointee = {{ t.lower_fn }}($0) }
             {%- when None %}
             let writeReturn = { () }
             {%- endmatch %}
 
-            {%- match meth.throws_type() %}
+            {%- match meth.throws_type.ty %}
             {%- when None %}
             uniffiTraitInterfaceCall(
                 callStatus: uniffiCallStatus,
@@ -72,17 +74,18 @@ fileprivate struct {{ trait_impl }} {
                 callStatus: uniffiCallStatus,
                 makeCall: makeCall,
                 writeReturn: writeReturn,
-                lowerError: {{ error_type|lower_fn }}
+                lowerError: {{ error_type.lower_fn }}
             )
             {%- endmatch %}
             {%- else %}
 
-            let uniffiHandleSuccess: @Sendable ({{ meth.return_type()|return_type_name }}) -> () = { (returnValue) in
+            {%- let async_data = meth.async_data.as_ref().unwrap() %}
+            let uniffiHandleSuccess: @Sendable ({% call swift::return_type_name(meth) %}{% endcall %}) -> () = { (returnValue) in
                 uniffiFutureCallback(
                     uniffiCallbackData,
-                    {{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}(
-                        {%- if let Some(return_type) = meth.return_type() %}
-                        returnValue: {{ return_type|lower_fn }}(returnValue),
+                    {{ async_data.ffi_foreign_future_result.0 }}(
+                        {%- if let Some(return_type) = meth.return_type.ty %}
+                        returnValue: {{ return_type.lower_fn }}(returnValue),
                         {%- endif %}
                         callStatus: RustCallStatus()
                     )
@@ -91,16 +94,16 @@ fileprivate struct {{ trait_impl }} {
             let uniffiHandleError: @Sendable (Int8, RustBuffer) -> () = { (statusCode, errorBuf) in
                 uniffiFutureCallback(
                     uniffiCallbackData,
-                    {{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}(
-                        {%- if let Some(return_type) = meth.return_type() %}
-                        returnValue: {{ meth.return_type().map(FfiType::from)|ffi_default_value }},
+                    {{ async_data.ffi_foreign_future_result.0 }}(
+                        {%- if let Some(ffi_default_value) = vtable_method.ffi_default_value %}
+                        returnValue: {{ ffi_default_value }},
                         {%- endif %}
                         callStatus: RustCallStatus(code: statusCode, errorBuf: errorBuf)
                     )
                 )
             }
 
-            {%- match meth.throws_type() %}
+            {%- match meth.throws_type.ty %}
             {%- when None %}
             uniffiTraitInterfaceCallAsync(
                 makeCall: makeCall,
@@ -113,7 +116,7 @@ fileprivate struct {{ trait_impl }} {
                 makeCall: makeCall,
                 handleSuccess: uniffiHandleSuccess,
                 handleError: uniffiHandleError,
-                lowerError: {{ error_type|lower_fn }},
+                lowerError: {{ error_type.lower_fn }},
                 droppedCallback: uniffiOutDroppedCallback
             )
             {%- endmatch %}
@@ -128,13 +131,20 @@ fileprivate struct {{ trait_impl }} {
     // `nonisolated(unsafe)` is needed under Swift 6 strict concurrency.
     // This is safe because the pointee is initialized once during static init
     // and never mutated by either side of the FFI.  Its fields are C function pointers.
-    nonisolated(unsafe) static let vtablePtr: UnsafePointer<{{ vtable|ffi_type_name }}> = {
-        let ptr = UnsafeMutablePointer<{{ vtable|ffi_type_name }}>.allocate(capacity: 1)
+    nonisolated(unsafe) static let vtablePtr: UnsafePointer<{{ vtable.struct_type.type_name }}> = {
+        let ptr = UnsafeMutablePointer<{{ vtable.struct_type.type_name }}>.allocate(capacity: 1)
         ptr.initialize(to: vtable)
         return UnsafePointer(ptr)
     }()
 }
 
-private func {{ callback_init }}() {
-    {{ ffi_init_callback.name() }}({{ trait_impl }}.vtablePtr)
+private func uniffiCallbackInit{{ vtable.interface_name }}() {
+    {%- if let Some(availability) = availability %}
+    // The implementation can only be registered on platforms that support 128-bit integers
+    if {{ availability.replace("@available", "#available") }} {
+        {{ vtable.init_fn.0 }}({{ trait_impl }}.vtablePtr)
+    }
+    {%- else %}
+    {{ vtable.init_fn.0 }}({{ trait_impl }}.vtablePtr)
+    {%- endif %}
 }
@@ -1,12 +1,11 @@
-{%- let cbi = ci.get_callback_interface_definition(name).unwrap() %}
-{%- let callback_handler = format!("uniffiCallbackHandler{}", name) %}
-{%- let callback_init = format!("uniffiCallbackInit{}", name) %}
-{%- let methods = cbi.methods() %}
+{%- let type_name = cbi.self_type.type_name.clone() %}
+{%- let ffi_converter_name = cbi.self_type.ffi_converter_name.clone() %}
+{%- let methods = cbi.methods.clone() %}
+{%- let properties = Vec::<Property>::new() %}
 {%- let protocol_name = type_name.clone() %}
-{%- let protocol_docstring = cbi.docstring() %}
-{%- let vtable = cbi.vtable() %}
-{%- let vtable_methods = cbi.vtable_methods() %}
-{%- let ffi_init_callback = cbi.ffi_init_callback() %}
+{%- let protocol_docstring = cbi.docstring.clone() %}
+{%- let availability = cbi.self_type.availability.clone() %}
+{%- let vtable = cbi.vtable.clone() %}
 
 {% include "Protocol.swift" %}
 {% include "CallbackInterfaceImpl.swift" %}
@@ -15,14 +14,14 @@
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct {{ ffi_converter_name }} {
+{% call swift::availability(availability) %}{% endcall %}fileprivate struct {{ ffi_converter_name }} {
     fileprivate static let handleMap = UniffiHandleMap<{{ type_name }}>()
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-extension {{ ffi_converter_name }} : FfiConverter {
+{% call swift::availability(availability) %}{% endcall %}extension {{ ffi_converter_name }} : FfiConverter {
     typealias SwiftType = {{ type_name }}
     typealias FfiType = UInt64
 
@@ -63,13 +62,13 @@ an external type by another crate.
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func {{ ffi_converter_name }}_lift(_ handle: UInt64) throws -> {{ type_name }} {
+{% call swift::availability(availability) %}{% endcall %}public func {{ ffi_converter_name }}_lift(_ handle: UInt64) throws -> {{ type_name }} {
     return try {{ ffi_converter_name }}.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-@Sendable public func {{ ffi_converter_name }}_lower(_ v: {{ type_name }}) -> UInt64 {
+{% call swift::availability(availability) %}{% endcall %}@Sendable public func {{ ffi_converter_name }}_lower(_ v: {{ type_name }}) -> UInt64 {
     return {{ ffi_converter_name }}.lower(v)
 }
@@ -0,0 +1,76 @@
+
+// Closures are type-erased at the FFI level: the arguments and return value are serialized into
+// `RustBuffer`s.  This means all closure types share a single vtable.
+
+// Swift closures passed to Rust.  The handle map stores functions that input the serialized
+// arguments and return the serialized return value.
+fileprivate let uniffiClosureHandleMap = UniffiHandleMap<@Sendable (RustBuffer) throws -> RustBuffer>()
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiClosureRuntime {
+    static let vtable: {{ closure_vtable.struct_type.type_name }} = {{ closure_vtable.struct_type.type_name }}(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try uniffiClosureHandleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi closure: handle missing in uniffiFree")
+            }
+        },
+        call: { (
+            uniffiHandle: UInt64,
+            args: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) -> () in
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: { try uniffiClosureHandleMap.get(handle: uniffiHandle)(args) },
+                writeReturn: { uniffiOutReturn.pointee = $0 }
+            )
+        }
+    )
+
+    // Rust stores this pointer for future closure calls, so it must live for the process
+    // lifetime.  See `CallbackInterfaceImpl.swift` for why `nonisolated(unsafe)` is safe here.
+    nonisolated(unsafe) static let vtablePtr: UnsafePointer<{{ closure_vtable.struct_type.type_name }}> = {
+        let ptr = UnsafeMutablePointer<{{ closure_vtable.struct_type.type_name }}>.allocate(capacity: 1)
+        ptr.initialize(to: vtable)
+        return UnsafePointer(ptr)
+    }()
+}
+
+private func uniffiClosureInit() {
+    {{ closure_vtable.init_fn.0 }}(UniffiClosureRuntime.vtablePtr)
+}
+
+// Closure implemented in Rust.  The Rust closure is freed when this is deinitialized.
+fileprivate final class UniffiRustClosure: @unchecked Sendable {
+    private let handle: UInt64
+
+    init(handle: UInt64) {
+        self.handle = handle
+    }
+
+    deinit {
+        {{ closure_vtable.ffi_closure_free.0 }}(handle)
+    }
+
+    // Rust closures can't return errors, so this only fails if the Rust code panicked.
+    func call(_ args: RustBuffer) -> RustBuffer {
+        try! rustCall { {{ closure_vtable.ffi_closure_call.0 }}(handle, args, $0) }
+    }
+}
+
+// Deserialize closure arguments or a closure return value from a `RustBuffer`, then free it
+fileprivate func uniffiReadClosureBuffer<T>(
+    _ buf: RustBuffer,
+    _ read: (inout (data: Data, offset: Data.Index)) throws -> T
+) throws -> T {
+    defer { buf.deallocate() }
+    var reader = createReader(data: Data(rustBuffer: buf))
+    let value = try read(&reader)
+    if hasRemaining(reader) {
+        throw UniffiInternalError.incompleteData
+    }
+    return value
+}
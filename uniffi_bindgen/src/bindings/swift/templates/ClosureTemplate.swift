@@ -0,0 +1,61 @@
+{%- let type_name = closure.self_type.type_name %}
+{%- let ffi_converter_name = closure.self_type.ffi_converter_name %}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+{% call swift::availability(closure.self_type.availability) %}{% endcall %}fileprivate struct {{ ffi_converter_name }}: FfiConverter {
+    typealias SwiftType = {{ type_name }}
+    typealias FfiType = UInt64
+
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        let rustClosure = UniffiRustClosure(handle: handle)
+        return { ({% for arg in closure.arguments %}arg{{ loop.index0 }}{% if !loop.last %}, {% endif %}{% endfor %}) in
+            {%- if closure.arguments.is_empty() %}
+            let uniffiReturn = rustClosure.call(RustBuffer(bytes: []))
+            {%- else %}
+            var writer = createWriter()
+            {%- for arg in closure.arguments %}
+            {{ arg.ffi_converter_name }}.write(arg{{ loop.index0 }}, into: &writer)
+            {%- endfor %}
+            let uniffiReturn = rustClosure.call(RustBuffer(bytes: writer))
+            {%- endif %}
+            {%- match closure.return_type.ty %}
+            {%- when Some(return_type) %}
+            return try! uniffiReadClosureBuffer(uniffiReturn) { try {{ return_type.ffi_converter_name }}.read(from: &$0) }
+            {%- when None %}
+            try! uniffiReadClosureBuffer(uniffiReturn) { _ in () }
+            {%- endmatch %}
+        }
+    }
+
+    public static func lower(_ value: @escaping SwiftType) -> UInt64 {
+        return uniffiClosureHandleMap.insert(obj: { (uniffiArgs: RustBuffer) throws -> RustBuffer in
+            {% if closure.return_type.ty.is_some() %}let uniffiReturn = {% endif -%}
+            try uniffiReadClosureBuffer(uniffiArgs) { {% if closure.arguments.is_empty() %}_{% else %}reader{% endif %} in
+                value(
+                    {%- for arg in closure.arguments %}
+                    try {{ arg.ffi_converter_name }}.read(from: &reader){% if !loop.last %},{% endif %}
+                    {%- endfor %}
+                )
+            }
+            {%- match closure.return_type.ty %}
+            {%- when Some(return_type) %}
+            var writer = createWriter()
+            {{ return_type.ffi_converter_name }}.write(uniffiReturn, into: &writer)
+            return RustBuffer(bytes: writer)
+            {%- when None %}
+            return RustBuffer(bytes: [])
+            {%- endmatch %}
+        })
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: @escaping SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
@@ -0,0 +1,31 @@
+fileprivate let uniffiGregorianUtc: Calendar = {
+    var calendar = Calendar(identifier: .gregorian)
+    calendar.timeZone = TimeZone(identifier: "UTC")!
+    return calendar
+}()
+
+fileprivate let uniffiUnixEpochDate = DateComponents(year: 1970, month: 1, day: 1)
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDate: FfiConverterRustBuffer {
+    typealias SwiftType = DateComponents
+
+    // Dates are passed as the number of days since 1970-01-01
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DateComponents {
+        let days: Int32 = try readInt(&buf)
+        let date = uniffiGregorianUtc.date(byAdding: .day, value: Int(days), to: Date(timeIntervalSince1970: 0))!
+        return uniffiGregorianUtc.dateComponents([.year, .month, .day], from: date)
+    }
+
+    public static func write(_ value: DateComponents, into buf: inout [UInt8]) {
+        let components = DateComponents(year: value.year, month: value.month, day: value.day)
+        guard let days = uniffiGregorianUtc.dateComponents(
+            [.day], from: uniffiUnixEpochDate, to: components
+        ).day else {
+            fatalError("Invalid date, year, month and day must be set")
+        }
+        writeInt(&buf, Int32(days))
+    }
+}
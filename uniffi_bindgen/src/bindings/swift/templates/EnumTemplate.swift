@@ -1,57 +1,55 @@
 {%- call swift::docstring(e, 0) %}{% endcall %}
-{%- let uniffi_trait_methods = e.uniffi_trait_methods() %}
-{% match e.variant_discr_type() %}
-{% when None %}
-public {% if ci.is_recursive(e.name()) %}indirect {% endif %}
-{%- if config.enum_has_conformances(e, contains_object_references) -%}
-enum {{ type_name }}: {{ config.conformance_list_for_enum(e, contains_object_references) }} {
+{% if !e.discr_specified %}
+{% call swift::availability(e.self_type.availability) %}{% endcall %}public {% if e.recursive %}indirect {% endif %}
+{%- if !e.conformances.is_empty() -%}
+enum {{ e.name }}: {{ e.conformances }} {
 {%- else -%}
-enum {{ type_name }} {
+enum {{ e.name }} {
 {%- endif %}
-    {% for variant in e.variants() %}
+    {% for variant in e.variants %}
     {%- call swift::docstring(variant, 4) %}{% endcall %}
-    case {{ variant.name()|enum_variant_swift_quoted }}{% if variant.fields().len() > 0 %}(
+    case {{ variant.name }}{% if variant.has_fields() %}(
         {%- call swift::field_list_decl(variant, variant.has_nameless_fields()) %}{% endcall %}
     ){% endif -%}
     {% endfor %}
-{% when Some(variant_discr_type) %}
-public {% if ci.is_recursive(e.name()) %}indirect {% endif -%}
-enum {{ type_name }}: {{ variant_discr_type|type_name }}, {{ config.conformance_list_for_enum(e, contains_object_references) }} {
-    {% for variant in e.variants() %}
+{% else %}
+{% call swift::availability(e.self_type.availability) %}{% endcall %}public {% if e.recursive %}indirect {% endif -%}
+enum {{ e.name }}: {{ e.discr_type.type_name }}, {{ e.conformances }} {
+    {% for variant in e.variants %}
     {%- call swift::docstring(variant, 4) %}{% endcall %}
-    case {{ variant.name()|enum_variant_swift_quoted }} = {{ e|variant_discr_literal(loop.index0) }}{% if variant.fields().len() > 0 %}(
+    case {{ variant.name }} = {{ variant.discr_literal }}{% if variant.has_fields() %}(
         {%- call swift::field_list_decl(variant, variant.has_nameless_fields()) %}{% endcall %}
     ){% endif -%}
     {% endfor %}
-{% endmatch %}
+{% endif %}
 
-{% for meth in e.methods() -%}
+{% for meth in e.methods -%}
 {%- call swift::func_decl("public func", meth, 4) %}{% endcall %}
 {% endfor %}
 
-{% call swift::uniffi_trait_impls(uniffi_trait_methods) %}{% endcall %}
+{% call swift::uniffi_trait_impls(e.uniffi_trait_methods) %}{% endcall %}
 }
 
 #if compiler(>=6)
-extension {{ type_name }}: Sendable {}
+{% call swift::availability(e.self_type.availability) %}{% endcall %}extension {{ e.name }}: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
-    typealias SwiftType = {{ type_name }}
+{% call swift::availability(e.self_type.availability) %}{% endcall %}public struct {{ e.self_type.ffi_converter_name }}: FfiConverterRustBuffer {
+    typealias SwiftType = {{ e.name }}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ e.name }} {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-        {% for variant in e.variants() %}
-        case {{ loop.index }}: return .{{ variant.name()|enum_variant_swift_quoted }}{% if variant.has_fields() %}(
-            {%- for field in variant.fields() %}
+        {% for variant in e.variants %}
+        case {{ loop.index }}: return .{{ variant.name }}{% if variant.has_fields() %}(
+            {%- for field in variant.fields %}
             {%- if variant.has_nameless_fields() -%}
-            try {{ field|read_fn }}(from: &buf)
+            try {{ field.ty.ffi_converter_name }}.read(from: &buf)
             {%- else -%}
-            {{ field.name()|arg_name }}: try {{ field|read_fn }}(from: &buf)
+            {{ field.label }}: try {{ field.ty.ffi_converter_name }}.read(from: &buf)
             {%- endif -%}
             {%- if !loop.last %}, {% endif %}
             {%- endfor %}
@@ -61,17 +59,17 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
         }
     }
 
-    public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
+    public static func write(_ value: {{ e.name }}, into buf: inout [UInt8]) {
         switch value {
-        {% for variant in e.variants() %}
+        {% for variant in e.variants %}
         {% if variant.has_fields() %}
-        case let .{{ variant.name()|enum_variant_swift_quoted }}({% for field in variant.fields() %}{%- call swift::field_name(field, loop.index) %}{% endcall -%}{%- if loop.last -%}{%- else -%},{%- endif -%}{% endfor %}):
+        case let .{{ variant.name }}({% for field in variant.fields %}{%- call swift::field_name(field, loop.index) %}{% endcall -%}{%- if loop.last -%}{%- else -%},{%- endif -%}{% endfor %}):
             writeInt(&buf, Int32({{ loop.index }}))
-            {% for field in variant.fields() -%}
-            {{ field|write_fn }}({% call swift::field_name(field, loop.index) %}{% endcall %}, into: &buf)
+            {% for field in variant.fields -%}
+            {{ field.ty.ffi_converter_name }}.write({% call swift::field_name(field, loop.index) %}{% endcall %}, into: &buf)
             {% endfor -%}
         {% else %}
-        case .{{ variant.name()|enum_variant_swift_quoted }}:
+        case .{{ variant.name }}:
             writeInt(&buf, Int32({{ loop.index }}))
         {% endif %}
         {%- endfor %}
@@ -86,13 +84,13 @@ an external type by another crate.
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func {{ ffi_converter_name }}_lift(_ buf: RustBuffer) throws -> {{ type_name }} {
-    return try {{ ffi_converter_name }}.lift(buf)
+{% call swift::availability(e.self_type.availability) %}{% endcall %}public func {{ e.self_type.ffi_converter_name }}_lift(_ buf: RustBuffer) throws -> {{ e.name }} {
+    return try {{ e.self_type.ffi_converter_name }}.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-@Sendable public func {{ ffi_converter_name }}_lower(_ value: {{ type_name }}) -> RustBuffer {
-    return {{ ffi_converter_name }}.lower(value)
+{% call swift::availability(e.self_type.availability) %}{% endcall %}@Sendable public func {{ e.self_type.ffi_converter_name }}_lower(_ value: {{ e.name }}) -> RustBuffer {
+    return {{ e.self_type.ffi_converter_name }}.lower(value)
 }
@@ -1,34 +1,34 @@
 {%- call swift::docstring(e, 0) %}{% endcall %}
-public {% if ci.is_recursive(e.name()) %}indirect {% endif %}
-{%- if config.error_has_additional_conformances(e, contains_object_references) %}
-enum {{ type_name }}: Swift.Error, {{ config.additional_conformance_list_for_error(e, contains_object_references) }} {
+{% call swift::availability(e.self_type.availability) %}{% endcall %}public {% if e.recursive %}indirect {% endif %}
+{%- if !e.conformances.is_empty() %}
+enum {{ e.name }}: Swift.Error, {{ e.conformances }} {
 {%- else %}
-enum {{ type_name }}: Swift.Error {
+enum {{ e.name }}: Swift.Error {
 {%- endif %}
 
-    {% if e.is_flat() %}
-    {% for variant in e.variants() %}
+    {% if e.is_flat %}
+    {% for variant in e.variants %}
     {%- call swift::docstring(variant, 4) %}{% endcall %}
-    case {{ variant.name()|class_name }}(message: String)
+    case {{ variant.name }}(message: String)
     {% endfor %}
 
     {%- else %}
-    {% for variant in e.variants() %}
+    {% for variant in e.variants %}
     {%- call swift::docstring(variant, 4) %}{% endcall %}
-    case {{ variant.name()|class_name }}{% if variant.fields().len() > 0 %}(
+    case {{ variant.name }}{% if variant.has_fields() %}(
         {%- call swift::field_list_decl(variant, variant.has_nameless_fields()) %}{% endcall %}
     ){% endif -%}
     {% endfor %}
 
     {%- endif %}
 
-    {% for meth in e.methods() -%}
+    {% for meth in e.methods -%}
     {%- call swift::func_decl("public func", meth, 4) %}{% endcall %}
     {% endfor %}
 
-    {% call swift::uniffi_trait_impls(e.uniffi_trait_methods()) %}{% endcall %}
+    {% call swift::uniffi_trait_impls(e.uniffi_trait_methods) %}{% endcall %}
 
-    {% if !config.omit_localized_error_conformance() %}
+    {% if !omit_localized_error_conformance %}
     public var errorDescription: String? {
         String(reflecting: self)
     }
@@ -36,36 +36,36 @@ enum {{ type_name }}: Swift.Error {
 }
 
 #if compiler(>=6)
-extension {{ type_name }}: Sendable {}
+{% call swift::availability(e.self_type.availability) %}{% endcall %}extension {{ e.name }}: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
-    typealias SwiftType = {{ type_name }}
+{% call swift::availability(e.self_type.availability) %}{% endcall %}public struct {{ e.self_type.ffi_converter_name }}: FfiConverterRustBuffer {
+    typealias SwiftType = {{ e.name }}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ e.name }} {
         let variant: Int32 = try readInt(&buf)
         switch variant {
 
-        {% if e.is_flat() %}
+        {% if e.is_flat %}
 
-        {% for variant in e.variants() %}
-        case {{ loop.index }}: return .{{ variant.name()|class_name }}(
-            message: try {{ Type::String.borrow()|read_fn }}(from: &buf)
+        {% for variant in e.variants %}
+        case {{ loop.index }}: return .{{ variant.name }}(
+            message: try {{ builtin_types.string.ffi_converter_name }}.read(from: &buf)
         )
         {% endfor %}
 
         {% else %}
 
-        {% for variant in e.variants() %}
-        case {{ loop.index }}: return .{{ variant.name()|error_variant_swift_quoted }}{% if variant.has_fields() %}(
-            {% for field in variant.fields() -%}
+        {% for variant in e.variants %}
+        case {{ loop.index }}: return .{{ variant.name }}{% if variant.has_fields() %}(
+            {% for field in variant.fields -%}
             {%-     if variant.has_nameless_fields() -%}
-            try {{ field|read_fn }}(from: &buf)
+            try {{ field.ty.ffi_converter_name }}.read(from: &buf)
             {%-     else -%}
-            {{ field.name()|var_name }}: try {{ field|read_fn }}(from: &buf)
+            {{ field.name }}: try {{ field.ty.ffi_converter_name }}.read(from: &buf)
             {%-     endif -%}
             {%- if !loop.last %}, {% endif %}
             {% endfor -%}
@@ -77,27 +77,27 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
         }
     }
 
-    public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
+    public static func write(_ value: {{ e.name }}, into buf: inout [UInt8]) {
         switch value {
 
-        {% if e.is_flat() %}
+        {% if e.is_flat %}
 
-        {% for variant in e.variants() %}
-        case .{{ variant.name()|class_name }}(_ /* message is ignored*/):
+        {% for variant in e.variants %}
+        case .{{ variant.name }}(_ /* message is ignored*/):
             writeInt(&buf, Int32({{ loop.index }}))
         {%- endfor %}
 
         {% else %}
 
-        {% for variant in e.variants() %}
+        {% for variant in e.variants %}
         {% if variant.has_fields() %}
-        case let .{{ variant.name()|error_variant_swift_quoted }}({% for field in variant.fields() %}{%- call swift::field_name(field, loop.index) %}{% endcall -%}{%- if loop.last -%}{%- else -%},{%- endif -%}{% endfor %}):
+        case let .{{ variant.name }}({% for field in variant.fields %}{%- call swift::field_name(field, loop.index) %}{% endcall -%}{%- if loop.last -%}{%- else -%},{%- endif -%}{% endfor %}):
             writeInt(&buf, Int32({{ loop.index }}))
-            {% for field in variant.fields() -%}
-            {{ field|write_fn }}({% call swift::field_name(field, loop.index) %}{% endcall %}, into: &buf)
+            {% for field in variant.fields -%}
+            {{ field.ty.ffi_converter_name }}.write({% call swift::field_name(field, loop.index) %}{% endcall %}, into: &buf)
             {% endfor -%}
         {% else %}
-        case .{{ variant.name()|class_name }}:
+        case .{{ variant.name }}:
             writeInt(&buf, Int32({{ loop.index }}))
         {% endif %}
         {%- endfor %}
@@ -114,13 +114,13 @@ an external type by another crate.
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func {{ ffi_converter_name }}_lift(_ buf: RustBuffer) throws -> {{ type_name }} {
-    return try {{ ffi_converter_name }}.lift(buf)
+{% call swift::availability(e.self_type.availability) %}{% endcall %}public func {{ e.self_type.ffi_converter_name }}_lift(_ buf: RustBuffer) throws -> {{ e.name }} {
+    return try {{ e.self_type.ffi_converter_name }}.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-@Sendable public func {{ ffi_converter_name }}_lower(_ value: {{ type_name }}) -> RustBuffer {
-    return {{ ffi_converter_name }}.lower(value)
+{% call swift::availability(e.self_type.availability) %}{% endcall %}@Sendable public func {{ e.self_type.ffi_converter_name }}_lower(_ value: {{ e.name }}) -> RustBuffer {
+    return {{ e.self_type.ffi_converter_name }}.lower(value)
 }
@@ -4,7 +4,9 @@ fileprivate enum UniffiInternalError: LocalizedError {
     case bufferOverflow
     case incompleteData
     case unexpectedOptionalTag
+    case unexpectedArrayLength
     case unexpectedEnumCase
+    case unexpectedUrl
     case unexpectedNullPointer
     case unexpectedRustCallStatusCode
     case unexpectedRustCallError
@@ -16,7 +18,9 @@ fileprivate enum UniffiInternalError: LocalizedError {
         case .bufferOverflow: return "Reading the requested value would read past the end of the buffer"
         case .incompleteData: return "The buffer still has data after lifting its containing value"
         case .unexpectedOptionalTag: return "Unexpected optional tag; should be 0 or 1"
+        case .unexpectedArrayLength: return "Array length doesn't match the fixed-size Rust array"
         case .unexpectedEnumCase: return "Raw enum value doesn't match any cases"
+        case .unexpectedUrl: return "Rust URL couldn't be parsed by Foundation"
         case .unexpectedNullPointer: return "Raw pointer value was null"
         case .unexpectedRustCallStatusCode: return "Unexpected RustCallStatus code"
         case .unexpectedRustCallError: return "CALL_ERROR but no errorClass specified"
@@ -95,7 +99,7 @@ private func uniffiCheckCallStatus<E: Swift.Error>(
             // with the message.  But if that code panics, then it just sends back
             // an empty buffer.
             if callStatus.errorBuf.len > 0 {
-                throw UniffiInternalError.rustPanic(try {{ Type::String.borrow()|lift_fn }}(callStatus.errorBuf))
+                throw UniffiInternalError.rustPanic(try {{ builtin_types.string.lift_fn }}(callStatus.errorBuf))
             } else {
                 callStatus.errorBuf.deallocate()
                 throw UniffiInternalError.rustPanic("Rust panic")
@@ -118,7 +122,7 @@ private func uniffiTraitInterfaceCall<T>(
         try writeReturn(makeCall())
     } catch let error {
         callStatus.pointee.code = CALL_UNEXPECTED_ERROR
-        callStatus.pointee.errorBuf = {{ Type::String.borrow()|lower_fn }}(String(describing: error))
+        callStatus.pointee.errorBuf = {{ builtin_types.string.lower_fn }}(String(describing: error))
     }
 }
 
@@ -135,6 +139,6 @@ private func uniffiTraitInterfaceCallWithError<T, E>(
         callStatus.pointee.errorBuf = lowerError(error)
     } catch {
         callStatus.pointee.code = CALL_UNEXPECTED_ERROR
-        callStatus.pointee.errorBuf = {{ Type::String.borrow()|lower_fn }}(String(describing: error))
+        callStatus.pointee.errorBuf = {{ builtin_types.string.lower_fn }}(String(describing: error))
     }
 }
@@ -0,0 +1,54 @@
+/**
+ * A span of time, accurate to the nanosecond.
+ */
+public struct UniffiDuration: Equatable, Hashable, Comparable, Codable {
+    public let seconds: UInt64
+    public let nanoseconds: UInt32
+
+    public init(seconds: UInt64, nanoseconds: UInt32) {
+        precondition(nanoseconds < 1_000_000_000, "Duration nanoseconds must be less than 1_000_000_000")
+        self.seconds = seconds
+        self.nanoseconds = nanoseconds
+    }
+
+    /// Convert a `TimeInterval`, this is only as precise as the floating-point value
+    public init(_ timeInterval: TimeInterval) {
+        precondition(timeInterval >= 0, "Invalid duration, must be non-negative")
+        let seconds = timeInterval.rounded(.down)
+        self.init(
+            seconds: UInt64(seconds),
+            nanoseconds: min(UInt32((timeInterval - seconds) * 1.0e9), 999_999_999)
+        )
+    }
+
+    /// Convert to a `TimeInterval`, which loses precision
+    public var timeInterval: TimeInterval {
+        return Double(seconds) + (Double(nanoseconds) / 1.0e9)
+    }
+
+    public static func < (lhs: UniffiDuration, rhs: UniffiDuration) -> Bool {
+        return (lhs.seconds, lhs.nanoseconds) < (rhs.seconds, rhs.nanoseconds)
+    }
+}
+
+#if compiler(>=6)
+extension UniffiDuration: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDuration: FfiConverterRustBuffer {
+    typealias SwiftType = UniffiDuration
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UniffiDuration {
+        let seconds: UInt64 = try readInt(&buf)
+        let nanoseconds: UInt32 = try readInt(&buf)
+        return UniffiDuration(seconds: seconds, nanoseconds: nanoseconds)
+    }
+
+    public static func write(_ value: UniffiDuration, into buf: inout [UInt8]) {
+        writeInt(&buf, value.seconds)
+        writeInt(&buf, value.nanoseconds)
+    }
+}
@@ -0,0 +1,70 @@
+/**
+ * A point in time, accurate to the nanosecond.
+ *
+ * `seconds` counts from the Unix epoch and `nanoseconds` is always added to it, so half a second
+ * before the epoch is `UniffiTimestamp(seconds: -1, nanoseconds: 500_000_000)`.  This means that
+ * timestamps compare in chronological order.
+ */
+public struct UniffiTimestamp: Equatable, Hashable, Comparable, Codable {
+    public let seconds: Int64
+    public let nanoseconds: UInt32
+
+    public init(seconds: Int64, nanoseconds: UInt32) {
+        precondition(nanoseconds < 1_000_000_000, "Timestamp nanoseconds must be less than 1_000_000_000")
+        self.seconds = seconds
+        self.nanoseconds = nanoseconds
+    }
+
+    /// Convert a `Date`, this is only as precise as the `Date`'s floating-point value
+    public init(_ date: Date) {
+        let interval = date.timeIntervalSince1970
+        let seconds = interval.rounded(.down)
+        self.init(
+            seconds: Int64(seconds),
+            nanoseconds: min(UInt32((interval - seconds) * 1.0e9), 999_999_999)
+        )
+    }
+
+    /// Convert to a `Date`, which loses precision
+    public var date: Date {
+        return Date(timeIntervalSince1970: Double(seconds))
+            .addingTimeInterval(Double(nanoseconds) / 1.0e9)
+    }
+
+    public static func < (lhs: UniffiTimestamp, rhs: UniffiTimestamp) -> Bool {
+        return (lhs.seconds, lhs.nanoseconds) < (rhs.seconds, rhs.nanoseconds)
+    }
+}
+
+#if compiler(>=6)
+extension UniffiTimestamp: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterTimestamp: FfiConverterRustBuffer {
+    typealias SwiftType = UniffiTimestamp
+
+    // Rust sends the seconds and nanoseconds from the epoch to the time, so times before the epoch
+    // have both parts counted backwards.  `UniffiTimestamp` counts the nanoseconds forwards, so we
+    // need to convert between the two.
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UniffiTimestamp {
+        let seconds: Int64 = try readInt(&buf)
+        let nanoseconds: UInt32 = try readInt(&buf)
+        if seconds < 0 && nanoseconds != 0 {
+            return UniffiTimestamp(seconds: seconds - 1, nanoseconds: 1_000_000_000 - nanoseconds)
+        }
+        return UniffiTimestamp(seconds: seconds, nanoseconds: nanoseconds)
+    }
+
+    public static func write(_ value: UniffiTimestamp, into buf: inout [UInt8]) {
+        if value.seconds < 0 && value.nanoseconds != 0 {
+            writeInt(&buf, value.seconds + 1)
+            writeInt(&buf, 1_000_000_000 - value.nanoseconds)
+        } else {
+            writeInt(&buf, value.seconds)
+            writeInt(&buf, value.nanoseconds)
+        }
+    }
+}
@@ -1,14 +1,18 @@
-{%- let obj = ci.get_object_definition(name).unwrap() %}
-{%- let (protocol_name, impl_class_name) = obj|object_names %}
-{%- let methods = obj.methods() %}
-{%- let protocol_docstring = obj.docstring() %}
+{%- let type_name = obj.self_type.type_name.clone() %}
+{%- let ffi_converter_name = obj.self_type.ffi_converter_name.clone() %}
+{%- let protocol_name = obj.protocol_name.clone() %}
+{%- let impl_class_name = obj.impl_name.clone() %}
+{%- let methods = obj.methods.clone() %}
+{%- let properties = obj.properties.clone() %}
+{%- let protocol_docstring = obj.docstring.clone() %}
+{%- let availability = obj.self_type.availability.clone() %}
 
-{%- let is_error = ci.is_name_used_as_error(name) %}
+{%- let is_error = obj.self_type.is_used_as_error %}
 
 {% include "Protocol.swift" %}
 
 {%- call swift::docstring(obj, 0) %}{% endcall %}
-open class {{ impl_class_name }}: {{ protocol_name }}, {{ config.conformance_list_for_object(obj, is_error) }} {
+{% call swift::availability(availability) %}{% endcall %}open class {{ impl_class_name }}: {{ protocol_name }}, {{ obj.conformances }} {
     fileprivate let handle: UInt64
 
     /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
@@ -45,7 +49,7 @@ open class {{ impl_class_name }}: {{ protocol_name }}, {{ config.conformance_lis
     @_documentation(visibility: private)
 #endif
     public func uniffiCloneHandle() -> UInt64 {
-        return try! rustCall { {{ obj.ffi_object_clone().name() }}(self.handle, $0) }
+        return try! rustCall { {{ obj.ffi_func_clone.0 }}(self.handle, $0) }
     }
 
     {%- match obj.primary_constructor() %}
@@ -61,21 +65,31 @@ open class {{ impl_class_name }}: {{ protocol_name }}, {{ config.conformance_lis
             return
         }
 
-        try! rustCall { {{ obj.ffi_object_free().name() }}(handle, $0) }
+        try! rustCall { {{ obj.ffi_func_free.0 }}(handle, $0) }
     }
 
     {% for cons in obj.alternate_constructors() %}
     {%- call swift::func_decl("public static func", cons, 4) %}{% endcall %}
     {% endfor %}
 
-    {% for meth in obj.methods() -%}
+    {% for prop in obj.properties -%}
+    {%- call swift::property_decl("open var", prop, 4) %}{% endcall %}
+    {% endfor %}
+
+    {% for meth in obj.methods -%}
+    {%- if meth.property.is_none() %}
     {%- call swift::func_decl("open func", meth, 4) %}{% endcall %}
+    {%- endif %}
+    {% endfor %}
+
+    {% for meth in obj.static_methods -%}
+    {%- call swift::func_decl("public static func", meth, 4) %}{% endcall %}
     {% endfor %}
 
-    {% call swift::uniffi_trait_impls(obj.uniffi_trait_methods()) %}{% endcall %}
+    {% call swift::uniffi_trait_impls(obj.uniffi_trait_methods) %}{% endcall %}
 
     {%- if is_error %}
-    {% if !config.omit_localized_error_conformance() %}
+    {% if !omit_localized_error_conformance %}
     public var errorDescription: String? {
         String(reflecting: self)
     }
@@ -89,7 +103,7 @@ open class {{ impl_class_name }}: {{ protocol_name }}, {{ config.conformance_lis
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct {{ ffi_converter_name }}: FfiConverter {
+{% call swift::availability(availability) %}{% endcall %}public struct {{ ffi_converter_name }}: FfiConverter {
     typealias FfiType = UInt64
     typealias SwiftType = {{ type_name }}
 
@@ -116,17 +130,13 @@ public struct {{ ffi_converter_name }}: FfiConverter {
  # * Generate a callback interface implementation to handle the Swift side
  # * In the FfiConverter, check which side a handle came from to know how to handle correctly.
 #}
-{%- let callback_handler = format!("uniffiCallbackInterface{}", name) %}
-{%- let callback_init = format!("uniffiCallbackInit{}", name) %}
-{%- let vtable = obj.vtable().expect("trait interface should have a vtable") %}
-{%- let vtable_methods = obj.vtable_methods() %}
-{%- let ffi_init_callback = obj.ffi_init_callback() %}
+{%- let vtable = obj.vtable.clone().expect("trait interface should have a vtable") %}
 {% include "CallbackInterfaceImpl.swift" %}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct {{ ffi_converter_name }}: FfiConverter {
+{% call swift::availability(availability) %}{% endcall %}public struct {{ ffi_converter_name }}: FfiConverter {
     fileprivate static let handleMap = UniffiHandleMap<{{ type_name }}>()
 
     typealias FfiType = UInt64
@@ -165,8 +175,8 @@ public struct {{ ffi_converter_name }}: FfiConverter {
 
 {%- endif %}
 
-{%- for t in obj.trait_impls() %}
-extension {{impl_class_name}}: {{ self::trait_protocol_name(ci, t.trait_ty)? }} {}
+{%- for trait_protocol_name in obj.trait_protocol_names %}
+{% call swift::availability(availability) %}{% endcall %}extension {{impl_class_name}}: {{ trait_protocol_name }} {}
 {% endfor %}
 
 {#
@@ -176,14 +186,14 @@ an external type by another crate.
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func {{ ffi_converter_name }}_lift(_ handle: UInt64) throws -> {{ type_name }} {
+{% call swift::availability(availability) %}{% endcall %}public func {{ ffi_converter_name }}_lift(_ handle: UInt64) throws -> {{ type_name }} {
     return try {{ ffi_converter_name }}.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-@Sendable public func {{ ffi_converter_name }}_lower(_ value: {{ type_name }}) -> UInt64 {
+{% call swift::availability(availability) %}{% endcall %}@Sendable public func {{ ffi_converter_name }}_lower(_ value: {{ type_name }}) -> UInt64 {
     return {{ ffi_converter_name }}.lower(value)
 }
 
@@ -194,7 +204,7 @@ public func {{ ffi_converter_name }}_lift(_ handle: UInt64) throws -> {{ type_na
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct {{ ffi_converter_name }}__as_error: FfiConverterRustBuffer {
+{% call swift::availability(availability) %}{% endcall %}public struct {{ ffi_converter_name }}__as_error: FfiConverterRustBuffer {
     public static func lift(_ buf: RustBuffer) throws -> {{ type_name }} {
         var reader = createReader(data: Data(rustBuffer: buf))
         return try {{ ffi_converter_name }}.read(from: &reader)
@@ -217,14 +227,14 @@ public struct {{ ffi_converter_name }}__as_error: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func {{ ffi_converter_name }}__as_error_lift(_ buf: RustBuffer) throws -> {{ type_name }} {
+{% call swift::availability(availability) %}{% endcall %}public func {{ ffi_converter_name }}__as_error_lift(_ buf: RustBuffer) throws -> {{ type_name }} {
     return try {{ ffi_converter_name }}__as_error.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-@Sendable public func {{ ffi_converter_name }}__as_error_lower(_ value: {{ type_name }}) -> RustBuffer {
+{% call swift::availability(availability) %}{% endcall %}@Sendable public func {{ ffi_converter_name }}__as_error_lower(_ value: {{ type_name }}) -> RustBuffer {
     return {{ ffi_converter_name }}__as_error.lower(value)
 }
 
@@ -1,8 +1,8 @@
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
-    typealias SwiftType = {{ type_name }}
+{% call swift::availability(opt.self_type.availability) %}{% endcall %}fileprivate struct {{ opt.self_type.ffi_converter_name }}: FfiConverterRustBuffer {
+    typealias SwiftType = {{ opt.self_type.type_name }}
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -10,13 +10,13 @@ fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        {{ inner_type|write_fn }}(value, into: &buf)
+        {{ opt.inner.ffi_converter_name }}.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try {{ inner_type|read_fn }}(from: &buf)
+        case 1: return try {{ opt.inner.ffi_converter_name }}.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
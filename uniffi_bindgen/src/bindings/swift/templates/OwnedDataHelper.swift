@@ -0,0 +1,39 @@
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOwnedData: FfiConverter {
+    typealias SwiftType = Data
+    typealias FfiType = RustBuffer
+
+    // Wrap the Rust allocation rather than copying it.
+    // The `RustBuffer` is freed when the returned `Data` is deallocated.
+    public static func lift(_ value: RustBuffer) throws -> Data {
+        guard let data = value.data else {
+            return Data()
+        }
+        return Data(
+            bytesNoCopy: data,
+            count: Int(value.len),
+            deallocator: .custom { _, _ in value.deallocate() }
+        )
+    }
+
+    // Rust takes ownership of the `RustBuffer`, so this only copies the bytes once.
+    public static func lower(_ value: Data) -> RustBuffer {
+        return value.withUnsafeBytes { ptr in
+            RustBuffer.from(ptr.bindMemory(to: UInt8.self))
+        }
+    }
+
+    // When nested inside another type, owned bytes are serialized the same way as `Data`.
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Data {
+        let len: Int32 = try readInt(&buf)
+        return Data(try readBytes(&buf, count: Int(len)))
+    }
+
+    public static func write(_ value: Data, into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        writeBytes(&buf, value)
+    }
+}
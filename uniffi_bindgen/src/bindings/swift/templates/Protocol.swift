@@ -1,11 +1,18 @@
 {%- call swift::docstring_value(protocol_docstring, 0) %}{% endcall %}
-public protocol {{ protocol_name }}: AnyObject, Sendable {
+{% call swift::availability(availability) %}{% endcall %}public protocol {{ protocol_name }}: AnyObject, Sendable {
+    {% for prop in properties.iter() -%}
+    {%- call swift::docstring(prop, 4) %}{% endcall %}
+    {% call swift::availability(prop.ty.availability) %}{% endcall %}var {{ prop.name }}: {{ prop.ty.type_name }} { get{% if prop.setter.is_some() %} set{% endif %} }
+    {% endfor %}
     {% for meth in methods.iter() -%}
+    {%- if meth.property.is_none() %}
     {%- call swift::docstring(meth, 4) %}{% endcall %}
-    func {{ meth.name()|fn_name }}({% call swift::arg_list_protocol(meth) %}{% endcall %}) {% call swift::is_async(meth) -%}{% endcall %}{% call swift::throws(meth) %}{% endcall -%}
-    {%- match meth.return_type() -%}
-    {%- when Some with (return_type) %} -> {{ return_type|type_name -}}
+    {%- let callable = meth.callable %}
+    {% call swift::availability(callable.availability) %}{% endcall %}func {{ callable.name }}({% call swift::arg_list_protocol(callable) %}{% endcall %}) {% call swift::is_async(callable) -%}{% endcall %}{% call swift::throws(callable) %}{% endcall -%}
+    {%- match callable.return_type.ty -%}
+    {%- when Some(return_type) %} -> {{ return_type.type_name -}}
     {%- else -%}
     {%- endmatch %}
+    {%- endif %}
     {% endfor %}
 }
@@ -1,55 +1,53 @@
-{%- let rec = ci.get_record_definition(name).unwrap() %}
-{%- let uniffi_trait_methods = rec.uniffi_trait_methods() %}
 {%- call swift::docstring(rec, 0) %}{% endcall %}
-{%- if config.record_has_conformances(rec, contains_object_references) %}
-public struct {{ type_name }}: {{ config.conformance_list_for_record(rec, contains_object_references) }} {
+{%- if !rec.conformances.is_empty() %}
+{% call swift::availability(rec.self_type.availability) %}{% endcall %}public struct {{ rec.name }}: {{ rec.conformances }} {
 {%- else %}
-public struct {{ type_name }} {
+{% call swift::availability(rec.self_type.availability) %}{% endcall %}public struct {{ rec.name }} {
 {%- endif %}
-    {%- for field in rec.fields() %}
+    {%- for field in rec.fields %}
     {%- call swift::docstring(field, 4) %}{% endcall %}
-    public {% if config.is_record_immutable(name) %}let{% else %}var{% endif %} {{ field.name()|var_name }}: {{ field|type_name }}
+    public {% if rec.immutable %}let{% else %}var{% endif %} {{ field.name }}: {{ field.ty.type_name }}
     {%- endfor %}
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init({% call swift::field_list_decl(rec, false) %}{% endcall %}) {
-        {%- for field in rec.fields() %}
-        self.{{ field.name()|var_name }} = {{ field.name()|var_name }}
+        {%- for field in rec.fields %}
+        self.{{ field.name }} = {{ field.name }}
         {%- endfor %}
     }
 
-    {% for meth in rec.methods() -%}
+    {% for meth in rec.methods -%}
     {%- call swift::func_decl("public func", meth, 4) %}{% endcall %}
     {% endfor %}
 
-    {% call swift::uniffi_trait_impls(uniffi_trait_methods) %}{% endcall %}
+    {% call swift::uniffi_trait_impls(rec.uniffi_trait_methods) %}{% endcall %}
 }
 
 #if compiler(>=6)
-extension {{ type_name }}: Sendable {}
+{% call swift::availability(rec.self_type.availability) %}{% endcall %}extension {{ rec.name }}: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+{% call swift::availability(rec.self_type.availability) %}{% endcall %}public struct {{ rec.self_type.ffi_converter_name }}: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ rec.name }} {
         return {%- if rec.has_fields() %}
-            try {{ type_name }}(
-            {%- for field in rec.fields() %}
-                {{ field.name()|arg_name }}: {{ field|read_fn }}(from: &buf)
+            try {{ rec.name }}(
+            {%- for field in rec.fields %}
+                {{ field.label }}: {{ field.ty.ffi_converter_name }}.read(from: &buf)
                 {%- if !loop.last %}, {% endif %}
             {%- endfor %}
         )
         {%- else %}
-            {{ type_name }}()
+            {{ rec.name }}()
         {%- endif %}
     }
 
-    public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
-        {%- for field in rec.fields() %}
-        {{ field|write_fn }}(value.{{ field.name()|var_name }}, into: &buf)
+    public static func write(_ value: {{ rec.name }}, into buf: inout [UInt8]) {
+        {%- for field in rec.fields %}
+        {{ field.ty.ffi_converter_name }}.write(value.{{ field.name }}, into: &buf)
         {%- endfor %}
     }
 }
@@ -61,13 +59,13 @@ an external type by another crate.
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func {{ ffi_converter_name }}_lift(_ buf: RustBuffer) throws -> {{ type_name }} {
-    return try {{ ffi_converter_name }}.lift(buf)
+{% call swift::availability(rec.self_type.availability) %}{% endcall %}public func {{ rec.self_type.ffi_converter_name }}_lift(_ buf: RustBuffer) throws -> {{ rec.name }} {
+    return try {{ rec.self_type.ffi_converter_name }}.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-@Sendable public func {{ ffi_converter_name }}_lower(_ value: {{ type_name }}) -> RustBuffer {
-    return {{ ffi_converter_name }}.lower(value)
+{% call swift::availability(rec.self_type.availability) %}{% endcall %}@Sendable public func {{ rec.self_type.ffi_converter_name }}_lower(_ value: {{ rec.name }}) -> RustBuffer {
+    return {{ rec.self_type.ffi_converter_name }}.lower(value)
 }
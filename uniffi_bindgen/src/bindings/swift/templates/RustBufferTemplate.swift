@@ -12,13 +12,13 @@ fileprivate extension RustBuffer {
     }
 
     static func from(_ ptr: UnsafeBufferPointer<UInt8>) -> RustBuffer {
-        try! rustCall { {{ ci.ffi_rustbuffer_from_bytes().name() }}(ForeignBytes(bufferPointer: ptr), $0) }
+        try! rustCall { {{ ffi_rustbuffer_from_bytes.0 }}(ForeignBytes(bufferPointer: ptr), $0) }
     }
 
     // Frees the buffer in place.
     // The buffer must not be used after this is called.
     func deallocate() {
-        try! rustCall { {{ ci.ffi_rustbuffer_free().name() }}(self, $0) }
+        try! rustCall { {{ ffi_rustbuffer_free.0 }}(self, $0) }
     }
 }
 
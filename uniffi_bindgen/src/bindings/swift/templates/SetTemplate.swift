@@ -1,21 +1,21 @@
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
-    public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
+{% call swift::availability(set.self_type.availability) %}{% endcall %}fileprivate struct {{ set.self_type.ffi_converter_name }}: FfiConverterRustBuffer {
+    public static func write(_ value: {{ set.self_type.type_name }}, into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            {{ inner_type|write_fn }}(item, into: &buf)
+            {{ set.inner.ffi_converter_name }}.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ set.self_type.type_name }} {
         let len: Int32 = try readInt(&buf)
-        var set = {{ type_name }}()
+        var set = {{ set.self_type.type_name }}()
         set.reserveCapacity(Int(len))
         for _ in 0..<len {
-            let item = try {{ inner_type|read_fn }}(from: &buf)
+            let item = try {{ set.inner.ffi_converter_name }}.read(from: &buf)
             set.insert(item)
         }
         return set
@@ -0,0 +1,45 @@
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+{% call swift::availability(stream.self_type.availability) %}{% endcall %}fileprivate struct {{ stream.self_type.ffi_converter_name }}: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = {{ stream.self_type.type_name }}
+
+    public static func lift(_ handle: UInt64) throws -> {{ stream.self_type.type_name }} {
+        return uniffiRustStream(
+            handle: handle,
+            nextFunc: {{ stream.ffi_stream_next.0 }},
+            freeStreamFunc: {{ stream.ffi_stream_free.0 }},
+            pollFunc: {{ stream.async_data.ffi_rust_future_poll.0 }},
+            completeFunc: {{ stream.async_data.ffi_rust_future_complete.0 }},
+            freeFunc: {{ stream.async_data.ffi_rust_future_free.0 }},
+            liftItem: liftItem
+        )
+    }
+
+    public static func lower(_ value: {{ stream.self_type.type_name }}) -> UInt64 {
+        fatalError("Streams can't be passed to Rust")
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ stream.self_type.type_name }} {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: {{ stream.self_type.type_name }}, into buf: inout [UInt8]) {
+        fatalError("Streams can't be passed to Rust")
+    }
+
+    // Items are sent as an `Option<T>`, where `None` signals the end of the stream.
+    private static func liftItem(_ rbuf: RustBuffer) throws -> {{ stream.inner.type_name }}?? {
+        defer {
+            rbuf.deallocate()
+        }
+        var reader = createReader(data: Data(rustBuffer: rbuf))
+        switch try readInt(&reader) as Int8 {
+        case 0: return .none
+        case 1: return .some(try {{ stream.inner.ffi_converter_name }}.read(from: &reader))
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
@@ -0,0 +1,18 @@
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+{% call swift::availability(tuple.self_type.availability) %}{% endcall %}fileprivate struct {{ tuple.self_type.ffi_converter_name }}: FfiConverterRustBuffer {
+    public static func write(_ value: {{ tuple.self_type.type_name }}, into buf: inout [UInt8]) {
+        {%- for element in tuple.elements %}
+        {{ element.ffi_converter_name }}.write(value.{{ loop.index0 }}, into: &buf)
+        {%- endfor %}
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ tuple.self_type.type_name }} {
+        return (
+            {%- for element in tuple.elements %}
+            try {{ element.ffi_converter_name }}.read(from: &buf){% if !loop.last %},{% endif %}
+            {%- endfor %}
+        )
+    }
+}
@@ -1,25 +1,22 @@
 {%- import "macros.swift" as swift %}
 
-{%- if ci.has_callback_definitions() %}
+{%- if has_callback_interface %}
 {%- include "CallbackInterfaceRuntime.swift" %}
 {%- endif %}
 
-{%- for type_ in ci.iter_local_types() %}
-{%- let type_name = type_|type_name %}
-{%- let ffi_converter_name = type_|ffi_converter_name %}
-{%- let canonical_type_name = type_|canonical_name %}
-{%- let contains_object_references = ci.item_contains_object_references(type_) %}
+{%- if let Some(closure_vtable) = closure_vtable %}
+{%- include "ClosureRuntime.swift" %}
+{%- endif %}
+
+{%- for type_def in type_definitions %}
 
 {#
- # Map `Type` instances to an include statement for that type.
- #
- # There is a companion match in `KotlinCodeOracle::create_code_type()` which performs a similar function for the
- # Rust code.
- #
- #   - When adding additional types here, make sure to also add a match arm to that function.
- #   - To keep things manageable, let's try to limit ourselves to these 2 mega-matches
+ # Map `TypeDefinition` instances to an include statement for that type.
  #}
-{%- match type_ %}
+{%- match type_def %}
+
+{%- when TypeDefinition::Simple(type_node) %}
+{%- match type_node.ty %}
 
 {%- when Type::Boolean %}
 {%- include "BooleanHelper.swift" %}
@@ -30,6 +27,9 @@
 {%- when Type::Bytes %}
 {%- include "DataHelper.swift" %}
 
+{%- when Type::OwnedBytes %}
+{%- include "OwnedDataHelper.swift" %}
+
 {%- when Type::Int8 %}
 {%- include "Int8Helper.swift" %}
 
@@ -42,6 +42,9 @@
 {%- when Type::Int64 %}
 {%- include "Int64Helper.swift" %}
 
+{%- when Type::Int128 %}
+{%- include "Int128Helper.swift" %}
+
 {%- when Type::UInt8 %}
 {%- include "UInt8Helper.swift" %}
 
@@ -54,6 +57,9 @@
 {%- when Type::UInt64 %}
 {%- include "UInt64Helper.swift" %}
 
+{%- when Type::UInt128 %}
+{%- include "UInt128Helper.swift" %}
+
 {%- when Type::Float32 %}
 {%- include "Float32Helper.swift" %}
 
@@ -61,45 +67,76 @@
 {%- include "Float64Helper.swift" %}
 
 {%- when Type::Timestamp %}
+{%- if lossless_time_types %}
+{%- include "LosslessTimestampHelper.swift" %}
+{%- else %}
 {%- include "TimestampHelper.swift" %}
+{%- endif %}
 
 {%- when Type::Duration %}
+{%- if lossless_time_types %}
+{%- include "LosslessDurationHelper.swift" %}
+{%- else %}
 {%- include "DurationHelper.swift" %}
+{%- endif %}
+
+{%- when Type::Uuid %}
+{%- include "UuidHelper.swift" %}
 
-{%- when Type::CallbackInterface { name, module_path } %}
+{%- when Type::Url %}
+{%- include "UrlHelper.swift" %}
+
+{%- when Type::Date %}
+{%- include "DateHelper.swift" %}
+
+{%- else %}
+{# Type::Simple shouldn't hold any other Type variants #}
+{%- endmatch %}
+
+{%- when TypeDefinition::CallbackInterface(cbi) %}
 {%- include "CallbackInterfaceTemplate.swift" %}
 
-{%- when Type::Custom { name, module_path, builtin } %}
-{%- if !ci.is_external(type_) %}
+{%- when TypeDefinition::Custom(custom) %}
 {%- include "CustomType.swift" %}
-{%- endif %}
 
-{%- when Type::Enum { name, module_path } %}
-{%- let e = ci.get_enum_definition(name).unwrap() %}
-{%- if ci.is_name_used_as_error(name) %}
+{%- when TypeDefinition::Enum(e) %}
+{%- if e.self_type.is_used_as_error %}
 {%- include "ErrorTemplate.swift" %}
 {%- else %}
 {%- include "EnumTemplate.swift" %}
 {% endif %}
 
-{%- when Type::Object{ name, module_path, .. } %}
+{%- when TypeDefinition::Interface(obj) %}
 {%- include "ObjectTemplate.swift" %}
 
-{%- when Type::Record { name, module_path } %}
+{%- when TypeDefinition::Record(rec) %}
 {%- include "RecordTemplate.swift" %}
 
-{%- when Type::Optional { inner_type } %}
+{%- when TypeDefinition::Optional(opt) %}
 {%- include "OptionalTemplate.swift" %}
 
-{%- when Type::Sequence { inner_type } %}
+{%- when TypeDefinition::Sequence(seq) %}
 {%- include "SequenceTemplate.swift" %}
 
-{%- when Type::Map { key_type, value_type } %}
+{%- when TypeDefinition::Map(map) %}
 {%- include "MapTemplate.swift" %}
 
-{%- when Type::Set { inner_type } %}
+{%- when TypeDefinition::Set(set) %}
 {%- include "SetTemplate.swift" %}
 
+{%- when TypeDefinition::Stream(stream) %}
+{%- include "StreamTemplate.swift" %}
+
+{%- when TypeDefinition::Tuple(tuple) %}
+{%- include "TupleTemplate.swift" %}
+
+{%- when TypeDefinition::Array(array) %}
+{%- include "ArrayTemplate.swift" %}
+
+{%- when TypeDefinition::Closure(closure) %}
+{%- include "ClosureTemplate.swift" %}
+
+{#- External types are defined in the module for their crate, so there's nothing to render here #}
 {%- else %}
 {%- endmatch %}
 {%- endfor %}
@@ -0,0 +1,17 @@
+// 128-bit integers are passed in a RustBuffer, since there's no portable C representation.
+// `UInt128` requires Swift 6 and a recent platform deployment target, so all declarations that use it
+// are marked `@available`.
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+{% call swift::availability(type_node.availability) %}{% endcall %}fileprivate struct FfiConverterUInt128: FfiConverterRustBuffer {
+    typealias SwiftType = UInt128
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt128 {
+        return try readInt(&buf)
+    }
+
+    public static func write(_ value: UInt128, into buf: inout [UInt8]) {
+        writeInt(&buf, value)
+    }
+}
@@ -0,0 +1,21 @@
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUrl: FfiConverterRustBuffer {
+    typealias SwiftType = URL
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> URL {
+        let len: Int32 = try readInt(&buf)
+        let string = String(decoding: try readBytes(&buf, count: Int(len)), as: UTF8.self)
+        guard let url = URL(string: string) else {
+            throw UniffiInternalError.unexpectedUrl
+        }
+        return url
+    }
+
+    public static func write(_ value: URL, into buf: inout [UInt8]) {
+        let string = value.absoluteString
+        writeInt(&buf, Int32(string.utf8.count))
+        writeBytes(&buf, string.utf8)
+    }
+}
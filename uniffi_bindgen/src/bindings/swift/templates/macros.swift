@@ -6,8 +6,8 @@
 
 {%- macro to_ffi_call(func) -%}
     {%- call is_try(func) %}{% endcall -%}
-    {%- if let(Some(e)) = func.throws_type() -%}
-        rustCallWithError({{ e|ffi_error_converter_name }}_lift) {
+    {%- if let Some(error_lift_fn) = func.throws_type.error_lift_fn -%}
+        rustCallWithError({{ error_lift_fn }}) {
     {%- else -%}
         rustCall() {
     {%- endif %}
@@ -18,27 +18,29 @@
     {#- Open nested `FfiConverterByRefBytes.lower` scopes for any `&[u8]`
         args. The `ForeignBytes` value is only guaranteed valid inside the
         scope, so the full FFI call runs inside the innermost closure. #}
-    {%- for arg in func.arguments() -%}
-    {%-     if arg|is_borrowed_bytes_mut %}
-        FfiConverterByMutRefBytes.lower(&{{ arg.name()|var_name }}) { {{ arg.name()|var_name }}Fb in
-    {%-     else if arg|is_borrowed_bytes %}
-        FfiConverterByRefBytes.lower({{ arg.name()|var_name }}) { {{ arg.name()|var_name }}Fb in
+    {%- for arg in func.arguments -%}
+    {%-     if arg.is_borrowed_bytes_mut %}
+        FfiConverterByMutRefBytes.lower(&{{ arg.name }}) { {{ arg.name }}Fb in
+    {%-     else if arg.is_borrowed_bytes %}
+        FfiConverterByRefBytes.lower({{ arg.name }}) { {{ arg.name }}Fb in
     {%-     endif %}
     {%- endfor %}
-    {{ func.ffi_func().name() }}(
+    {{ func.ffi_func.0 }}(
         {%- match func.self_type() %}
-        {%-     when Some(Type::Object { .. }) %}
+        {%-     when Some(self_type) %}
+        {%-         if func.is_interface_method() %}
             self.uniffiCloneHandle(),
-        {%-     when Some(t) %}
-            {{ t|lower_fn }}(self),
+        {%-         else %}
+            {{ self_type.lower_fn }}(self),
+        {%-         endif %}
         {%-     when None %}
         {%- endmatch %}
         {%- call arg_list_lowered(func) %}{% endcall -%}
          uniffiCallStatus
     )
     {#- Close nested `withUnsafeBytes` closures. #}
-    {%- for arg in func.arguments() -%}
-    {%-     if arg|is_borrowed_bytes %}
+    {%- for arg in func.arguments -%}
+    {%-     if arg.is_borrowed_bytes %}
         }
     {%-     endif %}
     {%- endfor %}
@@ -46,21 +48,37 @@
 {%- endmacro -%}
 
 // eg, `public func foo_bar() { body }`
-{%- macro func_decl(func_decl, callable, indent) %}
-{%- call docstring(callable, indent) %}{% endcall %}
-{{ func_decl }} {{ callable.name()|fn_name }}(
+{%- macro func_decl(func_decl, func, indent) %}
+{%- call docstring(func, indent) %}{% endcall %}
+{%- let callable = func.callable %}
+{% call availability(callable.availability) %}{% endcall %}{{ func_decl }} {{ callable.name }}(
     {%- call arg_list_decl(callable) %}{% endcall -%})
     {%- call is_async(callable) %}{% endcall %}
     {%- call throws(callable) %}{% endcall %}
-    {%- if let Some(return_type) = callable.return_type() %} -> {{ return_type|type_name }} {%- endif %}  {
+    {%- if let Some(return_type) = callable.return_type.ty %} -> {{ return_type.type_name }} {%- endif %}  {
     {%- call call_body(callable) %}{% endcall %}
 }
 {%- endmacro %}
 
+{%- macro property_decl(property_decl, prop, indent) %}
+{%- call docstring(prop, indent) %}{% endcall %}
+{% call availability(prop.ty.availability) %}{% endcall %}{{ property_decl }} {{ prop.name }}: {{ prop.ty.type_name }} {
+    get {
+        {%- call call_body(prop.getter.callable) %}{% endcall %}
+    }
+    {%- if let Some(setter) = prop.setter %}
+    set({% for arg in setter.callable.arguments %}{{ arg.name }}{% endfor %}) {
+        {%- call call_body(setter.callable) %}{% endcall %}
+    }
+    {%- endif %}
+}
+{%- endmacro %}
+
 // primary ctor - no name, no return-type.
-{%- macro ctor_decl(callable, indent) %}
-{%- call docstring(callable, indent) %}{% endcall %}
-public convenience init(
+{%- macro ctor_decl(cons, indent) %}
+{%- call docstring(cons, indent) %}{% endcall %}
+{%- let callable = cons.callable %}
+{% call availability(callable.availability) %}{% endcall %}public convenience init(
     {%- call arg_list_decl(callable) %}{% endcall -%}) {%- call is_async(callable) %}{% endcall %} {%- call throws(callable) %}{% endcall %} {
     {%- if callable.is_async() %}
     let handle =
@@ -82,9 +100,9 @@ public convenience init(
 {%- if callable.is_async() %}
     return {%- call call_async(callable) %}{% endcall %}
 {%- else %}
-{%-     match callable.return_type() -%}
+{%-     match callable.return_type.ty -%}
 {%-         when Some(return_type) %}
-    return {% call is_try(callable) %}{% endcall %} {{ return_type|lift_fn }}({% call to_ffi_call(callable) %}{% endcall %})
+    return {% call is_try(callable) %}{% endcall %} {{ return_type.lift_fn }}({% call to_ffi_call(callable) %}{% endcall %})
 {%-         when None %}
 {%-             call to_ffi_call(callable) %}{% endcall %}
 {%-     endmatch %}
@@ -95,31 +113,34 @@ public convenience init(
 {%- macro call_async(callable) %}
         {% call is_try(callable) %}{% endcall %} await uniffiRustCallAsync(
             rustFutureFunc: {
-                {{ callable.ffi_func().name() }}(
+                {{ callable.ffi_func.0 }}(
                     {%- match callable.self_type() %}
-                    {%-     when Some(Type::Object { .. }) %}
-                        self.uniffiCloneHandle(){% if !callable.arguments().is_empty() %},{% endif %}
-                    {%-     when Some(t) %}
-                        {{ t|lower_fn }}(self){% if !callable.arguments().is_empty() %},{% endif %}
+                    {%-     when Some(self_type) %}
+                    {%-         if callable.is_interface_method() %}
+                        self.uniffiCloneHandle(){% if !callable.arguments.is_empty() %},{% endif %}
+                    {%-         else %}
+                        {{ self_type.lower_fn }}(self){% if !callable.arguments.is_empty() %},{% endif %}
+                    {%-         endif %}
                     {%-     when None %}
                     {%- endmatch %}
-                    {%- for arg in callable.arguments() -%}
-                    {{ arg|lower_fn }}({{ arg.name()|var_name }}){% if !loop.last %},{% endif %}
+                    {%- for arg in callable.arguments -%}
+                    {{ arg.ty.lower_fn }}({{ arg.name }}){% if !loop.last %},{% endif %}
                     {%- endfor %}
                 )
             },
-            pollFunc: {{ callable.ffi_rust_future_poll(ci) }},
-            completeFunc: {{ callable.ffi_rust_future_complete(ci) }},
-            freeFunc: {{ callable.ffi_rust_future_free(ci) }},
-            {%- match callable.return_type() %}
+            {%- let async_data = callable.async_data.as_ref().unwrap() %}
+            pollFunc: {{ async_data.ffi_rust_future_poll.0 }},
+            completeFunc: {{ async_data.ffi_rust_future_complete.0 }},
+            freeFunc: {{ async_data.ffi_rust_future_free.0 }},
+            {%- match callable.return_type.ty %}
             {%- when Some(return_type) %}
-            liftFunc: {{ return_type|lift_fn }},
+            liftFunc: {{ return_type.lift_fn }},
             {%- when None %}
             liftFunc: { $0 },
             {%- endmatch %}
-            {%- match callable.throws_type() %}
-            {%- when Some with (e) %}
-            errorHandler: {{ e|ffi_error_converter_name }}_lift
+            {%- match callable.throws_type.error_lift_fn %}
+            {%- when Some(error_lift_fn) %}
+            errorHandler: {{ error_lift_fn }}
             {%- else %}
             errorHandler: nil
             {% endmatch %}
@@ -127,8 +148,8 @@ public convenience init(
 {%- endmacro %}
 
 {%- macro arg_list_lowered(func) %}
-    {%- for arg in func.arguments() %}
-        {{ arg|arg_expr }},
+    {%- for arg in func.arguments %}
+        {{ arg.lower_expr }},
     {%- endfor %}
 {%- endmacro -%}
 
@@ -138,10 +159,10 @@ public convenience init(
 -#}
 
 {% macro arg_list_decl(func) %}
-    {%- for arg in func.arguments() -%}
-        {% if config.omit_argument_labels() %}_ {% endif %}{{ arg.name()|var_name }}: {% if arg|is_borrowed_bytes_mut %}inout {% endif %}{{ arg|type_name -}}
-        {%- match arg.default_value() %}
-        {%- when Some with(default) %} = {{ default|default_swift(arg) }}
+    {%- for arg in func.arguments -%}
+        {% if omit_argument_labels %}_ {% endif %}{{ arg.name }}: {% if arg.is_borrowed_bytes_mut %}inout {% endif %}{% if arg.is_closure %}@escaping {% endif %}{{ arg.ty.type_name -}}
+        {%- match arg.default %}
+        {%- when Some(default) %} = {{ default.swift_default }}
         {%- else %}
         {%- endmatch %}
         {%- if !loop.last %}, {% endif -%}
@@ -153,15 +174,15 @@ public convenience init(
 // Note the var_name and type_name filters.
 -#}
 {% macro field_list_decl(item, has_nameless_fields) %}
-    {%- for field in item.fields() -%}
+    {%- for field in item.fields -%}
         {%- call docstring(field, 8) %}{% endcall %}
         {%- if has_nameless_fields %}
-        {{- field|type_name -}}
+        {{- field.ty.type_name -}}
         {%- if !loop.last -%}, {%- endif -%}
         {%- else -%}
-        {{ field.name()|var_name }}: {{ field|type_name -}}
-        {%- match field.default_value() %}
-            {%- when Some with(default) %} = {{ default|default_swift(field) }}
+        {{ field.name }}: {{ field.ty.type_name -}}
+        {%- match field.default %}
+            {%- when Some(default) %} = {{ default.swift_default }}
             {%- else %}
         {%- endmatch -%}
         {% if !loop.last %}, {% endif %}
@@ -170,20 +191,24 @@ public convenience init(
 {%- endmacro %}
 
 {% macro field_name(field, field_num) %}
-{%- if field.name().is_empty() -%}
+{%- if field.name.is_empty() -%}
 v{{- field_num -}}
 {%- else -%}
-{{ field.name()|var_name }}
+{{ field.name }}
 {%- endif -%}
 {%- endmacro %}
 
 {% macro arg_list_protocol(func) %}
-    {%- for arg in func.arguments() -%}
-        {% if config.omit_argument_labels() %}_ {% endif %}{{ arg.name()|var_name }}: {{ arg|type_name -}}
+    {%- for arg in func.arguments -%}
+        {% if omit_argument_labels %}_ {% endif %}{{ arg.name }}: {% if arg.is_closure %}@escaping {% endif %}{{ arg.ty.type_name -}}
         {%- if !loop.last %}, {% endif -%}
     {%- endfor %}
 {%- endmacro %}
 
+{%- macro return_type_name(func) %}
+{%- match func.return_type.ty %}{% when Some(return_type) %}{{ return_type.type_name }}{% when None %}(){% endmatch %}
+{%- endmacro -%}
+
 {%- macro is_async(func) %}
 {%- if func.is_async() %}async {% endif %}
 {%- endmacro -%}
@@ -204,8 +229,13 @@ v{{- field_num -}}
 {%- endmatch %}
 {%- endmacro %}
 
+// `@available` attribute for declarations that use 128-bit integers, followed by a space
+{%- macro availability(availability) %}
+{%- if let Some(availability) = availability %}{{ availability }} {% endif %}
+{%- endmacro %}
+
 {%- macro docstring(defn, indent_spaces) %}
-{%- call docstring_value(defn.docstring(), indent_spaces) %}{% endcall %}
+{%- call docstring_value(defn.docstring, indent_spaces) %}{% endcall %}
 {%- endmacro %}
 
 // macro for uniffi_trait implementations.
@@ -213,41 +243,43 @@ v{{- field_num -}}
 {%- if let Some(fmt) = uniffi_trait_methods.debug_fmt %}
 // The local Rust `Debug` implementation.
 public var debugDescription: String {
-    return {% call is_try(fmt) %}{% endcall %} {{ fmt.return_type().unwrap()|lift_fn }}(
-        {% call to_ffi_call(fmt) %}{% endcall %}
+    return {% call is_try(fmt.callable) %}{% endcall %} {{ fmt.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(fmt.callable) %}{% endcall %}
     )
 }
 {%- endif %}
 {%- if let Some(fmt) = uniffi_trait_methods.display_fmt %}
 // The local Rust `Display` implementation.
 public var description: String {
-    return {% call is_try(fmt) %}{% endcall %} {{ fmt.return_type().unwrap()|lift_fn }}(
-        {% call to_ffi_call(fmt) %}{% endcall %}
+    return {% call is_try(fmt.callable) %}{% endcall %} {{ fmt.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(fmt.callable) %}{% endcall %}
     )
 }
 {%- endif %}
 {%- if let Some(eq) = uniffi_trait_methods.eq_eq %}
+{%- let self_type_name = eq.callable.self_type().unwrap().type_name %}
 // The local Rust `Eq` implementation - only `eq` is used.
-public static func == (self: {{ eq.object_name() | class_name }}, other: {{ eq.object_name() | class_name }}) -> Bool {
-    return {% call is_try(eq) %}{% endcall %} {{ eq.return_type().unwrap()|lift_fn }}(
-        {% call to_ffi_call(eq) %}{% endcall %}
+public static func == (self: {{ self_type_name }}, other: {{ self_type_name }}) -> Bool {
+    return {% call is_try(eq.callable) %}{% endcall %} {{ eq.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(eq.callable) %}{% endcall %}
     )
 }
 {%- endif %}
 {%- if let Some(hash) = uniffi_trait_methods.hash_hash %}
 // The local Rust `Hash` implementation
 public func hash(into hasher: inout Hasher) {
-    let val = {% call is_try(hash) %}{% endcall %} {{ hash.return_type().unwrap()|lift_fn }}(
-        {% call to_ffi_call(hash) %}{% endcall %}
+    let val = {% call is_try(hash.callable) %}{% endcall %} {{ hash.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(hash.callable) %}{% endcall %}
     )
     hasher.combine(val)
 }
 {%- endif %}
 {%- if let Some(cmp) = uniffi_trait_methods.ord_cmp %}
+{%- let self_type_name = cmp.callable.self_type().unwrap().type_name %}
 // The local Rust `Ord` implementation
-public static func < (self: {{ cmp.object_name() | class_name }}, other: {{ cmp.object_name() | class_name }}) -> Bool {
-    return {% call is_try(cmp) %}{% endcall %} {{ cmp.return_type().unwrap()|lift_fn }}(
-        {% call to_ffi_call(cmp) %}{% endcall %}
+public static func < (self: {{ self_type_name }}, other: {{ self_type_name }}) -> Bool {
+    return {% call is_try(cmp.callable) %}{% endcall %} {{ cmp.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(cmp.callable) %}{% endcall %}
     ) < 0
 }
 {%- endif %}
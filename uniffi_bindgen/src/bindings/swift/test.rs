@@ -142,7 +142,7 @@ impl GeneratedSources {
         let mut paths = BindgenPaths::default();
         paths.add_cargo_metadata_layer(crate::CargoMetadataOptions::default())?;
         let loader = BindgenLoader::new(paths, GlobalConfig::default());
-        let sources = generate(
+        let swift_root = generate(
             &loader,
             GenerateOptions {
                 languages: vec![TargetLanguage::Swift],
@@ -152,11 +152,13 @@ impl GeneratedSources {
             },
         )?;
 
-        let main_source = sources
-            .iter()
-            .find(|s| s.ci.crate_name() == crate_name)
-            .unwrap();
-        let main_module = main_source.config.module_name();
+        let main_module = swift_root
+            .modules
+            .values()
+            .find(|module| module.crate_name == crate_name)
+            .unwrap()
+            .module_name
+            .clone();
         let modulemap_glob = glob(&out_dir.join("*.modulemap"))?;
         let module_map = match modulemap_glob.len() {
             0 => bail!("No modulemap files found in {out_dir}"),
@@ -0,0 +1,37 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+/// Template filters for Askama
+///
+/// In general, prefer adding fields using a pipeline pass to writing filters.
+/// That's allows devs to use the `pipeline` command to follow what's going on.
+use askama::Result;
+
+/// Get the idiomatic JS rendering of a docstring
+///
+/// If the docstring is set, this returns an indented JSDoc comment with a trailing newline. If
+/// not, it returns the empty string.
+///
+/// This makes it so the template code can use something like
+/// `{{ item.docstring|docstring(4) -}}` to render the correct docstring in both cases.
+#[askama::filter_fn]
+pub fn docstring(
+    docstring: &Option<String>,
+    _: &dyn askama::Values,
+    indent: usize,
+) -> Result<String> {
+    let Some(docstring) = docstring.as_deref() else {
+        return Ok("".to_string());
+    };
+    let docstring = textwrap::dedent(docstring);
+    let indent = " ".repeat(indent);
+    // Escape the comment terminator to avoid a syntax error
+    let escaped = docstring.replace("*/", "*\\/");
+    let lines = escaped
+        .lines()
+        .map(|line| format!("{indent} * {line}").trim_end().to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(format!("/**\n{lines}\n{indent} */\n{indent}"))
+}
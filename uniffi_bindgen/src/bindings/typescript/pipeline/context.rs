@@ -0,0 +1,85 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+#[derive(Default, Clone)]
+pub struct Context {
+    pub cdylib: Option<String>,
+    pub current_config: Option<TypeScriptConfig>,
+    pub module_namespace: Option<String>,
+    pub builtin_types: Option<BuiltinTypes>,
+    pub checksum_mode: Option<ChecksumMode>,
+}
+
+impl Context {
+    pub fn update_from_root(&mut self, root: &general::Root) -> Result<()> {
+        self.cdylib = root.cdylib.clone();
+        self.checksum_mode = Some(root.checksum_mode.clone());
+        self.builtin_types = Some(root.builtin_types.clone().map_node(self)?);
+        Ok(())
+    }
+
+    pub fn update_from_namespace(&mut self, namespace: &general::Namespace) -> Result<()> {
+        self.current_config = Some(match &namespace.config_toml {
+            Some(toml) => TypeScriptConfig::from_uniffi_toml(toml)?,
+            None => TypeScriptConfig::default(),
+        });
+        self.module_namespace = Some(namespace.name.clone());
+        Ok(())
+    }
+
+    pub fn builtin_types(&self) -> Result<BuiltinTypes> {
+        self.builtin_types
+            .clone()
+            .ok_or_else(|| anyhow!("Context.builtin_types not set"))
+    }
+
+    pub fn config(&self) -> Result<&TypeScriptConfig> {
+        self.current_config
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.config not set"))
+    }
+
+    pub fn cdylib(&self) -> Result<String> {
+        let default_cdylib = self.cdylib.as_deref().unwrap_or("uniffi");
+
+        Ok(match &self.config()?.cdylib_name {
+            Some(cdylib) => cdylib.clone(),
+            None => default_cdylib.to_string(),
+        })
+    }
+
+    pub fn checksum_mode(&self) -> Result<&ChecksumMode> {
+        self.checksum_mode
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.checksum_mode not set"))
+    }
+
+    /// Get the name that the current module uses to refer to another module
+    ///
+    /// Returns `None` if `namespace` is the current module.
+    pub fn external_module_name(&self, namespace: &str) -> Result<Option<String>> {
+        match &self.module_namespace {
+            None => bail!("Context.module_namespace not set"),
+            Some(current_namespace) if current_namespace == namespace => Ok(None),
+            _ => Ok(Some(names::module_var_name(namespace))),
+        }
+    }
+
+    /// Get the module specifier used to `require`/`import` another module
+    pub fn external_module_specifier(&self, namespace: &str) -> Result<String> {
+        Ok(match self.config()?.external_packages.get(namespace) {
+            Some(specifier) if !specifier.is_empty() => specifier.clone(),
+            _ => format!("./{namespace}"),
+        })
+    }
+
+    pub fn custom_type_config(
+        &self,
+        custom: &general::CustomType,
+    ) -> Result<Option<CustomTypeConfig>> {
+        Ok(self.config()?.custom_types.get(&custom.orig_name).cloned())
+    }
+}
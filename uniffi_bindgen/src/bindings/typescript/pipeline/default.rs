@@ -0,0 +1,95 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Render a default value as a JS expression
+///
+/// JS evaluates default parameter expressions on each call, so there's no need to special case
+/// mutable values like Python does.
+pub fn render_default(default: &general::DefaultValue, context: &Context) -> Result<String> {
+    Ok(match default {
+        general::DefaultValue::Default(tn) => match &tn.ty {
+            Type::UInt8
+            | Type::UInt16
+            | Type::UInt32
+            | Type::Int8
+            | Type::Int16
+            | Type::Int32
+            | Type::Float32
+            | Type::Float64 => "0".to_string(),
+            Type::UInt64 | Type::Int64 | Type::UInt128 | Type::Int128 => "0n".to_string(),
+            Type::Boolean => "false".to_string(),
+            Type::Bytes | Type::OwnedBytes => "new Uint8Array()".to_string(),
+            Type::String => "\"\"".to_string(),
+            Type::Record { .. } => format!("{}.create({{}})", types::type_name(&tn.ty, context)?),
+            Type::Interface { .. } => format!("new {}()", types::type_name(&tn.ty, context)?),
+            Type::Optional { .. } => "undefined".to_string(),
+            Type::Map { .. } => "new Map()".to_string(),
+            Type::Sequence { .. } => "[]".to_string(),
+            Type::Set { .. } => "new Set()".to_string(),
+            Type::Custom { builtin, .. } => {
+                return render_default(
+                    &general::DefaultValue::Default(general::TypeNode {
+                        ty: *builtin.clone(),
+                        ..tn.clone()
+                    }),
+                    context,
+                )
+                .map_err(|_err| anyhow!("Default values not supported for {:?}", tn.ty))
+            }
+            _ => bail!("Default values not supported for {:?}", tn.ty),
+        },
+        general::DefaultValue::Literal(lit) => render_literal(lit, context)?,
+    })
+}
+
+pub fn render_literal(lit: &general::Literal, context: &Context) -> Result<String> {
+    Ok(match lit {
+        general::Literal::Boolean(true) => "true".to_string(),
+        general::Literal::Boolean(false) => "false".to_string(),
+        general::Literal::String(s) => format!("\"{s}\""),
+        general::Literal::Int(i, radix, tn) => {
+            let sign = if *i < 0 { "-" } else { "" };
+            let i = i.unsigned_abs();
+            let digits = match radix {
+                Radix::Octal => format!("{i:#o}"),
+                Radix::Decimal => format!("{i}"),
+                Radix::Hexadecimal => format!("{i:#x}"),
+            };
+            format!("{sign}{digits}{}", bigint_suffix(&tn.ty))
+        }
+        general::Literal::UInt(i, radix, tn) => {
+            let digits = match radix {
+                Radix::Octal => format!("{i:#o}"),
+                Radix::Decimal => format!("{i}"),
+                Radix::Hexadecimal => format!("{i:#x}"),
+            };
+            format!("{digits}{}", bigint_suffix(&tn.ty))
+        }
+        general::Literal::Float(value, _) => value.clone(),
+        general::Literal::EmptySequence => "[]".to_string(),
+        general::Literal::EmptyMap => "new Map()".to_string(),
+        general::Literal::EmptySet => "new Set()".to_string(),
+        general::Literal::None => "undefined".to_string(),
+        general::Literal::Some { inner } => render_default(inner, context)?,
+        general::Literal::Enum(variant, ty) => match &ty.ty {
+            Type::Enum { .. } => {
+                let variant = names::variant_name(variant);
+                format!("{}.{variant}", types::type_name(&ty.ty, context)?)
+            }
+            type_kind => {
+                bail!("Invalid type for enum literal: {type_kind:?}")
+            }
+        },
+    })
+}
+
+/// Suffix needed for integer literals of types that are represented by a `bigint`
+fn bigint_suffix(ty: &Type) -> &'static str {
+    match ty {
+        Type::UInt64 | Type::Int64 | Type::UInt128 | Type::Int128 => "n",
+        _ => "",
+    }
+}
@@ -0,0 +1,60 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Koffi type for an FFI type
+///
+/// This is either a quoted koffi primitive type name or an expression that evaluates to a koffi
+/// type object.
+pub fn ffi_type_name(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        FfiType::Int8 => "'int8_t'".to_string(),
+        FfiType::UInt8 => "'uint8_t'".to_string(),
+        FfiType::Int16 => "'int16_t'".to_string(),
+        FfiType::UInt16 => "'uint16_t'".to_string(),
+        FfiType::Int32 => "'int32_t'".to_string(),
+        FfiType::UInt32 => "'uint32_t'".to_string(),
+        FfiType::Int64 => "'int64_t'".to_string(),
+        FfiType::UInt64 => "'uint64_t'".to_string(),
+        FfiType::Float32 => "'float'".to_string(),
+        FfiType::Float64 => "'double'".to_string(),
+        FfiType::Handle(_) => "'uint64_t'".to_string(),
+        // RustBuffers are plain JS objects once they're decoded, so the local struct definition
+        // works for buffers from any module.
+        FfiType::RustBuffer(_) => "UniffiRustBuffer".to_string(),
+        FfiType::RustCallStatus => "UniffiRustCallStatus".to_string(),
+        FfiType::ForeignBytes => "UniffiForeignBytes".to_string(),
+        FfiType::Function(name) => {
+            format!("koffi.pointer({})", name.clone().map_node(context)?.0)
+        }
+        FfiType::Struct(name) => name.clone().map_node(context)?.0,
+        FfiType::Reference(inner) | FfiType::MutReference(inner) => {
+            format!("koffi.pointer({})", ffi_type_name(inner, context)?)
+        }
+        FfiType::VoidPointer => "'void *'".to_string(),
+    })
+}
+
+pub fn ffi_default_value(return_type: &general::ReturnType) -> Result<String> {
+    Ok(match &return_type.ty {
+        Some(type_node) => match &type_node.ffi_type {
+            FfiType::UInt8
+            | FfiType::Int8
+            | FfiType::UInt16
+            | FfiType::Int16
+            | FfiType::UInt32
+            | FfiType::Int32
+            | FfiType::UInt64
+            | FfiType::Int64
+            | FfiType::Handle(_) => "0".to_string(),
+            FfiType::Float32 | FfiType::Float64 => "0.0".to_string(),
+            FfiType::RustBuffer(_) => "uniffiRustBufferDefault()".to_string(),
+            ffi_type => bail!("Invalid VTable return type: {ffi_type:?}"),
+        },
+        // When we need to use a value for void returns, we use a `u8` placeholder and `0` as
+        // the default.
+        None => "0".to_string(),
+    })
+}
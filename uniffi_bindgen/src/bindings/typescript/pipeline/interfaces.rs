@@ -0,0 +1,60 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+use uniffi_meta::TraitKind;
+
+pub fn name(int: &general::Interface) -> String {
+    match &int.imp {
+        ObjectImpl::Struct | ObjectImpl::Trait(TraitKind::RustOnly) => names::type_name(&int.name),
+        // Trait interfaces that can be implemented in JS get the main name for their TS interface.
+        // The class that wraps Rust implementations gets the `Impl` suffix.
+        ObjectImpl::Trait(TraitKind::Both | TraitKind::ForeignOnly) => {
+            names::type_name(&format!("{}Impl", int.name))
+        }
+    }
+}
+
+/// Name of the TS interface for trait interfaces that can be implemented in JS
+pub fn trait_name(int: &general::Interface) -> Option<String> {
+    match &int.imp {
+        ObjectImpl::Struct | ObjectImpl::Trait(TraitKind::RustOnly) => None,
+        ObjectImpl::Trait(TraitKind::Both | TraitKind::ForeignOnly) => {
+            Some(names::type_name(&int.name))
+        }
+    }
+}
+
+/// Types listed in the `implements` clause of the class declaration
+pub fn implements(int: &general::Interface, context: &Context) -> Result<Vec<String>> {
+    let mut implements = vec![];
+    if let Some(trait_name) = trait_name(int) {
+        implements.push(trait_name);
+    }
+    for t in int.trait_impls.iter() {
+        match &t.trait_ty.ty {
+            Type::Interface { .. } | Type::CallbackInterface { .. } => {
+                implements.push(types::type_name(&t.trait_ty.ty, context)?)
+            }
+            _ => bail!("trait_ty {:?} isn't a trait", t),
+        }
+    }
+    Ok(implements)
+}
+
+pub fn map_constructors(
+    interface_name: &str,
+    constructors: Vec<general::Constructor>,
+    context: &Context,
+) -> Result<Vec<Constructor>> {
+    constructors
+        .into_iter()
+        .map(|c| {
+            if c.callable.is_primary_constructor() && c.callable.is_async() {
+                bail!("Async primary constructors not supported but {interface_name} has one");
+            }
+            c.map_node(context)
+        })
+        .collect()
+}
@@ -0,0 +1,95 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::BTreeSet;
+
+pub use super::*;
+
+pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result<Module> {
+    let mut child_context = context.clone();
+    let context = &mut child_context;
+    context.update_from_namespace(&namespace)?;
+
+    let mut module = Module {
+        cdylib_name: context.cdylib()?,
+        checksum_mode: context.checksum_mode()?.clone(),
+        has_async_fns: namespace.has_descendant(|callable: &general::Callable| callable.is_async())
+            || namespace.has_descendant(|_: &general::StreamType| true),
+        has_callback_interface: namespace.has_descendant(|_: &general::CallbackInterface| true)
+            || namespace
+                .has_descendant(|int: &general::Interface| int.imp.has_callback_interface()),
+        has_async_callback_method: has_async_callback_method(&namespace),
+        lossless_time_types: context.config()?.lossless_time_types,
+        imports: module_imports(&namespace, context)?,
+        exported_names: vec![],
+        name: namespace.name.map_node(context)?,
+        crate_name: namespace.crate_name.map_node(context)?,
+        docstring: namespace.docstring.map_node(context)?,
+        functions: namespace.functions.map_node(context)?,
+        constants: namespace.constants.map_node(context)?,
+        type_definitions: namespace.type_definitions.map_node(context)?,
+        ffi_definitions: namespace.ffi_definitions.map_node(context)?,
+        checksums: namespace.checksums.map_node(context)?,
+        ffi_rustbuffer_alloc: namespace.ffi_rustbuffer_alloc,
+        ffi_rustbuffer_from_bytes: namespace.ffi_rustbuffer_from_bytes,
+        ffi_rustbuffer_free: namespace.ffi_rustbuffer_free,
+        ffi_rustbuffer_reserve: namespace.ffi_rustbuffer_reserve,
+        ffi_uniffi_contract_version: namespace.ffi_uniffi_contract_version,
+        correct_contract_version: namespace.correct_contract_version,
+        builtin_types: context.builtin_types()?,
+    };
+    // Generate exported names after mapping everything else.  This way we're sure all the renames
+    // have taken effect.
+    let mut exported_names = vec!["InternalError".to_string()];
+    module.visit(|e: &Enum| exported_names.push(e.name.clone()));
+    module.visit(|r: &Record| exported_names.push(r.name.clone()));
+    module.visit(|f: &Function| exported_names.push(f.callable.name.clone()));
+    module.visit(|c: &Constant| exported_names.push(c.name.clone()));
+    module.visit(|i: &Interface| exported_names.push(i.name.clone()));
+    // Converters are exported so that other modules can use them for external types
+    module.visit(|type_def: &TypeDefinition| {
+        if !matches!(type_def, TypeDefinition::External(_)) {
+            exported_names.push(type_def.self_type().ffi_converter_name.clone());
+        }
+        if let TypeDefinition::Interface(int) = type_def {
+            if int.self_type.is_used_as_error {
+                exported_names.push(format!("{}AsError", int.self_type.ffi_converter_name));
+            }
+        }
+    });
+    module.exported_names = exported_names;
+    Ok(module)
+}
+
+fn has_async_callback_method(namespace: &general::Namespace) -> bool {
+    let callback_interface_async = namespace.has_descendant(|cbi: &general::CallbackInterface| {
+        cbi.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    let trait_interface_async = namespace.has_descendant(|int: &general::Interface| {
+        int.imp.has_callback_interface()
+            && int.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    callback_interface_async || trait_interface_async
+}
+
+fn module_imports(namespace: &general::Namespace, context: &Context) -> Result<Vec<ModuleImport>> {
+    let mut type_namespaces = BTreeSet::<String>::default();
+    namespace.visit(|ty: &Type| {
+        if let Some(namespace) = ty.namespace() {
+            type_namespaces.insert(namespace.to_string());
+        }
+    });
+    // Don't try to import the current module
+    type_namespaces.remove(&namespace.name);
+
+    type_namespaces
+        .into_iter()
+        .map(|namespace| {
+            Ok(ModuleImport {
+                name: names::module_var_name(&namespace),
+                specifier: context.external_module_specifier(&namespace)?,
+            })
+        })
+        .collect()
+}
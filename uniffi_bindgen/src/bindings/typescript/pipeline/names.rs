@@ -0,0 +1,129 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use once_cell::sync::Lazy;
+
+use std::collections::HashSet;
+
+use super::*;
+
+// Reserved words from the ECMAScript spec, plus the TypeScript ones that can't be used as
+// identifiers in declaration files.
+static KEYWORDS: Lazy<HashSet<String>> = Lazy::new(|| {
+    let kwlist = vec![
+        "arguments",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "debugger",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "enum",
+        "eval",
+        "export",
+        "extends",
+        "false",
+        "finally",
+        "for",
+        "function",
+        "if",
+        "implements",
+        "import",
+        "in",
+        "instanceof",
+        "interface",
+        "let",
+        "new",
+        "null",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "return",
+        "static",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "with",
+        "yield",
+    ];
+    HashSet::from_iter(kwlist.into_iter().map(|s| s.to_string()))
+});
+
+/// Fixup a name by ensuring it's not a keyword
+fn fixup_keyword(name: String) -> String {
+    if KEYWORDS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+pub fn map_ffi_function_type_name(
+    ffi_function_type_name: FfiFunctionTypeName,
+    _: &Context,
+) -> Result<FfiFunctionTypeName> {
+    Ok(FfiFunctionTypeName(ffi_definition_name(
+        &ffi_function_type_name.0,
+    )))
+}
+
+pub fn map_ffi_struct_name(ffi_struct_name: FfiStructName, _: &Context) -> Result<FfiStructName> {
+    Ok(FfiStructName(ffi_definition_name(&ffi_struct_name.0)))
+}
+
+/// Name of the JS variable that stores a koffi struct/prototype definition
+pub fn ffi_definition_name(name: &str) -> String {
+    format!("Uniffi{}", name.to_upper_camel_case())
+}
+
+pub fn type_name(name: &str) -> String {
+    fixup_keyword(name.to_upper_camel_case())
+}
+
+pub fn var_name(name: &str) -> String {
+    fixup_keyword(name.to_lower_camel_case())
+}
+
+pub fn function_name(name: &str) -> String {
+    fixup_keyword(name.to_lower_camel_case())
+}
+
+pub fn const_name(name: &str) -> String {
+    fixup_keyword(name.to_shouty_snake_case())
+}
+
+pub fn variant_name(name: &str) -> String {
+    fixup_keyword(name.to_upper_camel_case())
+}
+
+/// Name for a record/variant field
+///
+/// Unnamed fields are named after their position: `v1`, `v2`, etc.
+pub fn field_name(name: &str, index: usize) -> String {
+    if name.is_empty() {
+        format!("v{}", index + 1)
+    } else {
+        var_name(name)
+    }
+}
+
+/// Name of the variable that stores an external module
+pub fn module_var_name(namespace: &str) -> String {
+    fixup_keyword(namespace.to_snake_case())
+}
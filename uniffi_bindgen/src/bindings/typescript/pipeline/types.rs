@@ -0,0 +1,148 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn map_type(mut ty: Type, _: &Context) -> Result<Type> {
+    rename_type(&mut ty);
+    Ok(ty)
+}
+
+fn rename_type(ty: &mut Type) {
+    match ty {
+        Type::Enum { name, .. }
+        | Type::Record { name, .. }
+        | Type::Interface { name, .. }
+        | Type::CallbackInterface { name, .. }
+        | Type::Custom { name, .. } => {
+            *name = names::type_name(name);
+        }
+        Type::Optional { inner_type }
+        | Type::Sequence { inner_type }
+        | Type::Array { inner_type, .. }
+        | Type::Set { inner_type }
+        | Type::Stream { inner_type }
+        | Type::Box { inner_type } => {
+            rename_type(inner_type);
+        }
+        Type::Map {
+            key_type,
+            value_type,
+        } => {
+            rename_type(key_type);
+            rename_type(value_type);
+        }
+        _ => (),
+    }
+}
+
+pub fn map_return_type(return_type: general::ReturnType, context: &Context) -> Result<ReturnType> {
+    Ok(match return_type.ty {
+        Some(ty) => {
+            let ty = ty.map_node(context)?;
+            ReturnType {
+                type_name: ty.type_name.clone(),
+                ty: Some(ty),
+            }
+        }
+        None => ReturnType {
+            ty: None,
+            type_name: "void".to_string(),
+        },
+    })
+}
+
+/// TypeScript type name
+pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Boolean => "boolean".to_string(),
+        Type::String => "string".to_string(),
+        Type::Bytes | Type::OwnedBytes => "Uint8Array".to_string(),
+        Type::Int8
+        | Type::Int16
+        | Type::Int32
+        | Type::UInt8
+        | Type::UInt16
+        | Type::UInt32
+        | Type::Float32
+        | Type::Float64 => "number".to_string(),
+        // These don't fit in a JS number without losing precision
+        Type::Int64 | Type::UInt64 | Type::Int128 | Type::UInt128 => "bigint".to_string(),
+        Type::Timestamp if context.config()?.lossless_time_types => "UniffiTimestamp".to_string(),
+        Type::Duration if context.config()?.lossless_time_types => "UniffiDuration".to_string(),
+        Type::Timestamp => "Date".to_string(),
+        // Durations are represented as a number of milliseconds
+        Type::Duration => "number".to_string(),
+        Type::Uuid => "string".to_string(),
+        Type::Url => "URL".to_string(),
+        // Dates are represented as midnight UTC
+        Type::Date => "Date".to_string(),
+        Type::Interface {
+            namespace, name, ..
+        }
+        | Type::Record {
+            namespace, name, ..
+        }
+        | Type::Enum {
+            namespace, name, ..
+        }
+        | Type::CallbackInterface {
+            namespace, name, ..
+        }
+        | Type::Custom {
+            namespace, name, ..
+        } => {
+            let type_name = names::type_name(name);
+            match context.external_module_name(namespace)? {
+                None => type_name,
+                Some(module) => format!("{module}.{type_name}"),
+            }
+        }
+        Type::Optional { inner_type } => {
+            format!("{} | undefined", type_name(inner_type, context)?)
+        }
+        Type::Sequence { inner_type } => {
+            format!("Array<{}>", type_name(inner_type, context)?)
+        }
+        Type::Array { inner_type, .. } => match **inner_type {
+            Type::UInt8 => "Uint8Array".to_string(),
+            _ => format!("Array<{}>", type_name(inner_type, context)?),
+        },
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "Map<{}, {}>",
+            type_name(key_type, context)?,
+            type_name(value_type, context)?
+        ),
+        Type::Box { inner_type } => type_name(inner_type, context)?,
+        Type::Set { inner_type } => {
+            format!("Set<{}>", type_name(inner_type, context)?)
+        }
+        Type::Stream { inner_type } => {
+            format!("AsyncIterableIterator<{}>", type_name(inner_type, context)?)
+        }
+        Type::Tuple { types } => format!(
+            "[{}]",
+            types
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        Type::Closure { .. } => bail!("Closures are not supported by the TypeScript bindings"),
+    })
+}
+
+pub fn ffi_converter_name(ty: &general::TypeNode, context: &Context) -> Result<String> {
+    let ext_module = match ty.ty.namespace() {
+        Some(namespace) => context.external_module_name(namespace)?,
+        _ => None,
+    };
+    Ok(match ext_module {
+        Some(module) => format!("{module}.FfiConverter{}", ty.canonical_name),
+        None => format!("FfiConverter{}", ty.canonical_name),
+    })
+}
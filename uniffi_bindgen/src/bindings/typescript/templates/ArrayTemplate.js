@@ -0,0 +1,46 @@
+const {{ array.self_type.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        {%- if array.is_byte_array %}
+        if (!(value instanceof Uint8Array)) {
+            throw new TypeError("argument must be a Uint8Array");
+        }
+        {%- else %}
+        if (!Array.isArray(value)) {
+            throw new TypeError("argument must be an Array");
+        }
+        {%- endif %}
+        if (value.length !== {{ array.len }}) {
+            throw new RangeError(`argument must have length {{ array.len }}, got ${value.length}`);
+        }
+        {%- if !array.is_byte_array %}
+        for (const item of value) {
+            {{ array.inner.ffi_converter_name }}.checkLower(item);
+        }
+        {%- endif %}
+    },
+    read(reader) {
+        const count = reader.readInt32();
+        if (count !== {{ array.len }}) {
+            throw new InternalError(`Unexpected array length: ${count}`);
+        }
+        {%- if array.is_byte_array %}
+        return reader.readBytes(count);
+        {%- else %}
+        const items = [];
+        for (let i = 0; i < count; i++) {
+            items.push({{ array.inner.ffi_converter_name }}.read(reader));
+        }
+        return items;
+        {%- endif %}
+    },
+    write(value, writer) {
+        writer.writeInt32(value.length);
+        {%- if array.is_byte_array %}
+        writer.writeBytes(value);
+        {%- else %}
+        for (const item of value) {
+            {{ array.inner.ffi_converter_name }}.write(item, writer);
+        }
+        {%- endif %}
+    },
+});
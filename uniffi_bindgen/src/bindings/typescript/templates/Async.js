@@ -0,0 +1,143 @@
+// RustFuturePoll values
+const UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const UNIFFI_RUST_FUTURE_POLL_WAKE = 1;
+
+// Stores the `resolve` functions for pending polls
+const uniffiContinuationHandleMap = new UniffiHandleMap();
+
+// Continuation callback for async functions
+// Resolve the promise for the poll, causing the async function to resume.
+const uniffiContinuationCallback = koffi.register((data, pollResult) => {
+    uniffiContinuationHandleMap.remove(data)(pollResult);
+}, koffi.pointer(UniffiRustFutureContinuationCallback));
+
+// Rust may wake up futures from other threads, which koffi doesn't count as pending work for the
+// event loop.  Keep the process alive with a timer while there are futures pending.
+let uniffiPendingFutureCount = 0;
+let uniffiKeepAliveTimer = undefined;
+
+function uniffiFutureStarted() {
+    if (uniffiPendingFutureCount++ === 0) {
+        uniffiKeepAliveTimer = setInterval(() => {}, 1 << 30);
+    }
+}
+
+function uniffiFutureFinished() {
+    if (--uniffiPendingFutureCount === 0) {
+        clearInterval(uniffiKeepAliveTimer);
+        uniffiKeepAliveTimer = undefined;
+    }
+}
+
+async function uniffiRustCallAsync(rustFuture, ffiPoll, ffiComplete, ffiFree, liftFunc, errorFfiConverter) {
+    uniffiFutureStarted();
+    try {
+        // Loop and poll until we see a UNIFFI_RUST_FUTURE_POLL_READY value
+        while (true) {
+            const pollResult = await new Promise((resolve) => {
+                ffiPoll(rustFuture, uniffiContinuationCallback, uniffiContinuationHandleMap.insert(resolve));
+            });
+            if (pollResult === UNIFFI_RUST_FUTURE_POLL_READY) {
+                break;
+            }
+        }
+        return liftFunc(uniffiRustCallWithError(errorFfiConverter, ffiComplete, rustFuture));
+    } finally {
+        ffiFree(rustFuture);
+        uniffiFutureFinished();
+    }
+}
+
+{%- if has_async_callback_method %}
+
+// JS promises can't be cancelled, so there's nothing to do when Rust drops a foreign future.
+// The result will still be sent to Rust, which will ignore it.
+const uniffiForeignFutureDroppedCallback = koffi.register((handle) => {},
+    koffi.pointer(UniffiForeignFutureDroppedCallback));
+
+function uniffiTraitInterfaceCallAsync(makeCall, outDroppedCallback, handleSuccess, handleError) {
+    uniffiTraitInterfaceCallAsyncWithError(makeCall, outDroppedCallback, handleSuccess, handleError, undefined, undefined);
+}
+
+function uniffiTraitInterfaceCallAsyncWithError(makeCall, outDroppedCallback, handleSuccess, handleError, errorType, lowerError) {
+    koffi.encode(outDroppedCallback, UniffiForeignFutureDroppedCallbackStruct, {
+        handle: 0,
+        free: uniffiForeignFutureDroppedCallback,
+    });
+    // Note: it's important we call either `handleSuccess` or `handleError` exactly once.  Each
+    // call consumes an Arc reference, which means there should be no possibility of a double call.
+    Promise.resolve()
+        .then(makeCall)
+        .then(
+            (result) => handleSuccess(result),
+            (e) => {
+                if (errorType !== undefined && e instanceof errorType) {
+                    handleError(UNIFFI_CALL_ERROR, lowerError(e));
+                } else {
+                    console.error("UniFFI: Unhandled exception in trait interface call", e);
+                    handleError(
+                        UNIFFI_CALL_UNEXPECTED_ERROR,
+                        {{ builtin_types.string.ffi_converter_name }}.lower(String(e)),
+                    );
+                }
+            },
+        );
+}
+{%- endif %}
+
+// Async iterator for streams returned from Rust
+//
+// Each item is fetched by calling `ffiNext`, which returns a Rust future that resolves to the item
+// serialized as an `Option<T>`.  Freeing the stream handle drops the Rust stream, which is how
+// cancellation works.
+const uniffiStreamRegistry = new FinalizationRegistry(({ handle, ffiFree }) => ffiFree(handle));
+
+class UniffiStream {
+    constructor(handle, ffiNext, ffiFree, ffiPoll, ffiComplete, ffiFutureFree, liftItem) {
+        this._handle = handle;
+        this._ffiNext = ffiNext;
+        this._ffiFree = ffiFree;
+        this._ffiPoll = ffiPoll;
+        this._ffiComplete = ffiComplete;
+        this._ffiFutureFree = ffiFutureFree;
+        this._liftItem = liftItem;
+        uniffiStreamRegistry.register(this, { handle, ffiFree }, this);
+    }
+
+    [Symbol.asyncIterator]() {
+        return this;
+    }
+
+    async next() {
+        if (this._handle === undefined) {
+            return { done: true, value: undefined };
+        }
+        const [hasItem, item] = await uniffiRustCallAsync(
+            this._ffiNext(this._handle),
+            this._ffiPoll,
+            this._ffiComplete,
+            this._ffiFutureFree,
+            this._liftItem,
+            undefined,
+        );
+        if (!hasItem) {
+            this._free();
+            return { done: true, value: undefined };
+        }
+        return { done: false, value: item };
+    }
+
+    async return(value) {
+        this._free();
+        return { done: true, value };
+    }
+
+    _free() {
+        const handle = this._handle;
+        this._handle = undefined;
+        if (handle !== undefined) {
+            uniffiStreamRegistry.unregister(this);
+            this._ffiFree(handle);
+        }
+    }
+}
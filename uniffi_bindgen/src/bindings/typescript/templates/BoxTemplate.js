@@ -0,0 +1,2 @@
+// Boxes are transparent, they're represented by the inner type
+const {{ box_.self_type.ffi_converter_name }} = uniffiForwardingConverter(() => {{ box_.inner.ffi_converter_name }});
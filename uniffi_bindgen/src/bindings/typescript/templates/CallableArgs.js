@@ -0,0 +1,5 @@
+{%- for arg in callable.arguments -%}
+{{ arg.name }}
+{%- if let Some(default) = arg.default %} = {{ default.js_default }}{% endif %}
+{%- if !loop.last %}, {% endif %}
+{%- endfor %}
@@ -0,0 +1,80 @@
+{%- for arg in callable.arguments %}
+{{ arg.ffi_converter_name() }}.checkLower({{ arg.name }});
+{%- endfor %}
+{%- for arg in callable.arguments %}
+{%- if arg.is_borrowed_bytes() %}
+const uniffiBorrowed{{ loop.index }} = {{ arg.ffi_converter_name() }}.lower({{ arg.name }});
+{%- endif %}
+{%- endfor %}
+const uniffiLoweredArgs = [
+    {%- if let Some(self_type) = callable.self_type() %}
+    {%-     match self_type.ty %}
+    {%-         when Type::Interface { .. } %}
+    this.uniffiCloneHandle(),
+    {%-         else %}
+    {{ self_type.ffi_converter_name }}.lower(self),
+    {%-     endmatch %}
+    {%- endif %}
+    {%- for arg in callable.arguments %}
+    {%- if arg.is_borrowed_bytes() %}
+    uniffiBorrowed{{ loop.index }},
+    {%- else %}
+    {{ arg.ffi_converter_name() }}.lower({{ arg.name }}),
+    {%- endif %}
+    {%- endfor %}
+];
+
+{%- match callable.return_type.ty %}
+{%- when Some(return_type) %}
+const uniffiLiftReturn = (value) => {{ return_type.ffi_converter_name }}.lift(value);
+{%- when None %}
+const uniffiLiftReturn = (value) => undefined;
+{%- endmatch %}
+
+{%- match callable.throws_type.ty %}
+{%- when Some(e) %}
+{%-    if callable.throws_type.from_interface %}
+const uniffiErrorConverter = {{ e.ffi_converter_name }}AsError;
+{%-    else %}
+const uniffiErrorConverter = {{ e.ffi_converter_name }};
+{%-    endif %}
+{%- when None %}
+const uniffiErrorConverter = undefined;
+{%- endmatch %}
+
+{%- if callable.has_borrowed_bytes_args() %}
+try {
+{%- endif %}
+{%- filter indent(callable.borrowed_bytes_indent()) %}
+{%- match callable.async_data %}
+{%- when None %}
+const uniffiFfiResult = uniffiRustCallWithError(
+    uniffiErrorConverter,
+    UniffiLib.{{ callable.ffi_func.0 }},
+    ...uniffiLoweredArgs,
+);
+{%- if callable.is_primary_constructor() %}
+this._uniffiInit(uniffiFfiResult);
+{%- else %}
+return uniffiLiftReturn(uniffiFfiResult);
+{%- endif %}
+{%- when Some(async_data) %}
+return await uniffiRustCallAsync(
+    UniffiLib.{{ callable.ffi_func.0 }}(...uniffiLoweredArgs),
+    UniffiLib.{{ async_data.ffi_rust_future_poll.0 }},
+    UniffiLib.{{ async_data.ffi_rust_future_complete.0 }},
+    UniffiLib.{{ async_data.ffi_rust_future_free.0 }},
+    uniffiLiftReturn,
+    uniffiErrorConverter,
+);
+{%- endmatch %}
+{%- endfilter %}
+{%- if callable.has_borrowed_bytes_args() %}
+} finally {
+    {%- for arg in callable.arguments %}
+    {%- if arg.is_borrowed_bytes() %}
+    {{ arg.ffi_converter_name() }}.release(uniffiBorrowed{{ loop.index }}, {{ arg.name }});
+    {%- endif %}
+    {%- endfor %}
+}
+{%- endif %}
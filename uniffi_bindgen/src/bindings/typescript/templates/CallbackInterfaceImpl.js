@@ -0,0 +1,104 @@
+// Register the JS callbacks for each vtable method and send the vtable to Rust
+(() => {
+    // Koffi callbacks need to stay registered for the lifetime of the process, since Rust may
+    // call them at any time.
+    const uniffiVTable = {
+        uniffi_free: koffi.register((uniffiHandle) => {
+            {{ ffi_converter_name }}._handleMap.remove(uniffiHandle);
+        }, koffi.pointer({{ vtable.free_fn_type.0 }})),
+        uniffi_clone: koffi.register((uniffiHandle) => {
+            return {{ ffi_converter_name }}._handleMap.clone(uniffiHandle);
+        }, koffi.pointer({{ vtable.clone_fn_type.0 }})),
+
+        {%- for meth in vtable.methods %}
+        {%- let callable = meth.callable %}
+        {{ meth.ffi_field_name }}: koffi.register((
+            uniffiHandle,
+            {%- for arg in callable.arguments %}
+            {{ arg.name }},
+            {%- endfor %}
+            {%- if !callable.is_async() %}
+            uniffiOutReturn,
+            uniffiCallStatusPtr,
+            {%- else %}
+            uniffiFutureCallback,
+            uniffiCallbackData,
+            uniffiOutDroppedCallback,
+            {%- endif %}
+        ) => {
+            const uniffiObj = {{ ffi_converter_name }}._handleMap.get(uniffiHandle);
+            const makeCall = () => uniffiObj.{{ callable.name }}(
+                {%- for arg in callable.arguments -%}
+                {{ arg.ty.ffi_converter_name }}.lift({{ arg.name }})
+                {%- if !loop.last %}, {% endif %}
+                {%- endfor -%}
+            );
+
+            {%- match callable.async_data %}
+            {%- when None %}
+            {%- match callable.return_type.ty %}
+            {%- when Some(return_type) %}
+            const writeReturnValue = (v) => {
+                koffi.encode(uniffiOutReturn, {{ return_type.ffi_type.type_name }}, {{ return_type.ffi_converter_name }}.lower(v));
+            };
+            {%- when None %}
+            const writeReturnValue = (v) => {};
+            {%- endmatch %}
+
+            {%- match callable.throws_type.ty %}
+            {%- when None %}
+            uniffiTraitInterfaceCall(uniffiCallStatusPtr, makeCall, writeReturnValue);
+            {%- when Some(error) %}
+            uniffiTraitInterfaceCallWithError(
+                uniffiCallStatusPtr,
+                makeCall,
+                writeReturnValue,
+                {{ error.type_name }},
+                (e) => {{ error.ffi_converter_name }}.lower(e),
+            );
+            {%- endmatch %}
+            {%- when Some(async_data) %}
+            const uniffiComplete = (result) => {
+                koffi.call(uniffiFutureCallback, {{ async_data.ffi_foreign_future_complete.0 }}, uniffiCallbackData, result);
+            };
+            const handleSuccess = (returnValue) => {
+                uniffiComplete({
+                    {%- if let Some(return_type) = callable.return_type.ty %}
+                    return_value: {{ return_type.ffi_converter_name }}.lower(returnValue),
+                    {%- endif %}
+                    call_status: uniffiRustCallStatusDefault(),
+                });
+            };
+            const handleError = (statusCode, rustBuffer) => {
+                uniffiComplete({
+                    {%- if callable.return_type.ty.is_some() %}
+                    return_value: {{ meth.ffi_default_value }},
+                    {%- endif %}
+                    call_status: { code: statusCode, error_buf: rustBuffer },
+                });
+            };
+
+            {%- match callable.throws_type.ty %}
+            {%- when None %}
+            uniffiTraitInterfaceCallAsync(makeCall, uniffiOutDroppedCallback, handleSuccess, handleError);
+            {%- when Some(error) %}
+            uniffiTraitInterfaceCallAsyncWithError(
+                makeCall,
+                uniffiOutDroppedCallback,
+                handleSuccess,
+                handleError,
+                {{ error.type_name }},
+                (e) => {{ error.ffi_converter_name }}.lower(e),
+            );
+            {%- endmatch %}
+            {%- endmatch %}
+        }, {{ meth.ffi_type.type_name }}),
+        {%- endfor %}
+    };
+
+    // Send Rust a pointer to the VTable.  Note: this means we need to keep the struct alive
+    // forever, or else bad things will happen when Rust tries to access it.
+    const uniffiVTablePtr = koffi.alloc({{ vtable.struct_type.type_name }}, 1);
+    koffi.encode(uniffiVTablePtr, {{ vtable.struct_type.type_name }}, uniffiVTable);
+    UniffiLib.{{ vtable.init_fn.0 }}(uniffiVTablePtr);
+})();
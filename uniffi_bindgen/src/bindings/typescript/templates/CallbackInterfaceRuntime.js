@@ -0,0 +1,34 @@
+// Helpers for calls from Rust into JS implementations of callback interfaces and trait
+// interfaces.
+
+function uniffiWriteCallStatus(callStatusPtr, code, errorBuf) {
+    koffi.encode(callStatusPtr, UniffiRustCallStatus, { code, error_buf: errorBuf });
+}
+
+function uniffiTraitInterfaceCall(callStatusPtr, makeCall, writeReturnValue) {
+    try {
+        writeReturnValue(makeCall());
+    } catch (e) {
+        uniffiWriteCallStatus(
+            callStatusPtr,
+            UNIFFI_CALL_UNEXPECTED_ERROR,
+            {{ builtin_types.string.ffi_converter_name }}.lower(String(e)),
+        );
+    }
+}
+
+function uniffiTraitInterfaceCallWithError(callStatusPtr, makeCall, writeReturnValue, errorType, lowerError) {
+    try {
+        writeReturnValue(makeCall());
+    } catch (e) {
+        if (e instanceof errorType) {
+            uniffiWriteCallStatus(callStatusPtr, UNIFFI_CALL_ERROR, lowerError(e));
+        } else {
+            uniffiWriteCallStatus(
+                callStatusPtr,
+                UNIFFI_CALL_UNEXPECTED_ERROR,
+                {{ builtin_types.string.ffi_converter_name }}.lower(String(e)),
+            );
+        }
+    }
+}
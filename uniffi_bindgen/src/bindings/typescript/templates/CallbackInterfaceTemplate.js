@@ -0,0 +1,25 @@
+{%- let ffi_converter_name = cbi.self_type.ffi_converter_name %}
+{%- let vtable = cbi.vtable -%}
+
+const {{ ffi_converter_name }} = Object.freeze({
+    _handleMap: new UniffiHandleMap(),
+    lift(value) {
+        return this._handleMap.get(value);
+    },
+    checkLower(value) {
+        if (typeof value !== "object" || value === null) {
+            throw new TypeError("Expected {{ cbi.name }} implementation");
+        }
+    },
+    lower(value) {
+        return this._handleMap.insert(value);
+    },
+    read(reader) {
+        return this.lift(reader.readUInt64());
+    },
+    write(value, writer) {
+        writer.writeUInt64(this.lower(value));
+    },
+});
+
+{% include "CallbackInterfaceImpl.js" %}
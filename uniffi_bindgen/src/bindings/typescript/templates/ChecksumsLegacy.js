@@ -0,0 +1,20 @@
+function uniffiCheckContractApiVersion() {
+    // Get the bindings contract version from our ComponentInterface
+    const bindingsContractVersion = {{ correct_contract_version }};
+    // Get the scaffolding contract version by calling the into the dylib
+    const scaffoldingContractVersion = UniffiLib.{{ ffi_uniffi_contract_version.0 }}();
+    if (bindingsContractVersion !== scaffoldingContractVersion) {
+        throw new InternalError("UniFFI contract version mismatch: try cleaning and rebuilding your project");
+    }
+}
+
+function uniffiCheckApiChecksums() {
+    {%- for checksum in checksums %}
+    if (UniffiLib.{{ checksum.fn_name.0 }}() !== {{ checksum.checksum }}) {
+        throw new InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project");
+    }
+    {%- endfor %}
+}
+
+uniffiCheckContractApiVersion();
+uniffiCheckApiChecksums();
@@ -0,0 +1,27 @@
+{%- let builtin = custom.builtin %}
+{%- match custom.config %}
+{%- when None %}
+{#- No custom type config, just use the converter for our builtin type -#}
+const {{ custom.self_type.ffi_converter_name }} = uniffiForwardingConverter(() => {{ builtin.ffi_converter_name }});
+{%- when Some(config) %}
+{#- Custom type config supplied, use it to convert the builtin type -#}
+const {{ custom.self_type.ffi_converter_name }} = Object.freeze({
+    checkLower(value) {
+        {{ builtin.ffi_converter_name }}.checkLower({{ config.lower("value") }});
+    },
+    lift(value) {
+        const builtinValue = {{ builtin.ffi_converter_name }}.lift(value);
+        return {{ config.lift("builtinValue") }};
+    },
+    lower(value) {
+        return {{ builtin.ffi_converter_name }}.lower({{ config.lower("value") }});
+    },
+    read(reader) {
+        const builtinValue = {{ builtin.ffi_converter_name }}.read(reader);
+        return {{ config.lift("builtinValue") }};
+    },
+    write(value, writer) {
+        {{ builtin.ffi_converter_name }}.write({{ config.lower("value") }}, writer);
+    },
+});
+{%- endmatch %}
@@ -0,0 +1,128 @@
+{%- let type_name = e.self_type.type_name %}
+{{- e.docstring|docstring(0) -}}
+const {{ type_name }} = Object.freeze({
+{%- for variant in e.variants %}
+    {{ variant.docstring|docstring(4) }}
+{%-     if e.is_flat -%}
+    {{ variant.name }}: "{{ variant.name }}",
+{%-     else if variant.fields.is_empty() -%}
+    {{ variant.name }}: Object.freeze({ tag: "{{ variant.name }}" }),
+{%-     else if variant.has_unnamed_fields() -%}
+    {{ variant.name }}({% for field in variant.fields %}{{ field.name }}{% if let Some(default) = field.default %} = {{ default.js_default }}{% endif %}{% if !loop.last %}, {% endif %}{% endfor %}) {
+        return { tag: "{{ variant.name }}"{% for field in variant.fields %}, {{ field.name }}{% endfor %} };
+    },
+{%-     else -%}
+    {{ variant.name }}(fields) {
+        return {
+            tag: "{{ variant.name }}",
+            {%- for field in variant.fields %}
+            {%- match field.default %}
+            {%- when Some(default) %}
+            {{ field.name }}: fields.{{ field.name }} === undefined ? {{ default.js_default }} : fields.{{ field.name }},
+            {%- when None %}
+            {{ field.name }}: fields.{{ field.name }},
+            {%- endmatch %}
+            {%- endfor %}
+        };
+    },
+{%-     endif %}
+{%- endfor %}
+
+{%- for cons in e.constructors %}
+{%-     let callable = cons.callable %}
+
+    {{ cons.docstring|docstring(4) -}}
+    {% if callable.is_async() %}async {% endif %}{{ callable.name }}({% include "CallableArgs.js" %}) {
+        {%- filter indent(8) %}
+        {%- include "CallableBody.js" %}
+        {%- endfilter %}
+    },
+{%- endfor %}
+
+{%- for meth in e.methods %}
+{%-     let callable = meth.callable %}
+
+    {{ meth.docstring|docstring(4) -}}
+    {% if callable.is_async() %}async {% endif %}{{ callable.name }}(self{% if !callable.arguments.is_empty() %}, {% endif %}{% include "CallableArgs.js" %}) {
+        {%- filter indent(8) %}
+        {%- include "CallableBody.js" %}
+        {%- endfilter %}
+    },
+{%- endfor %}
+
+    {%- let uniffi_trait_methods = e.uniffi_trait_methods %}
+    {%- let self_arg = "self" %}
+    {%- let member_sep = "," %}
+    {%- filter indent(4) %}
+    {%- include "UniffiTraitImpls.js" %}
+    {%- endfilter %}
+});
+
+const {{ e.self_type.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        {%- if e.is_flat %}
+        {%- if !e.variants.is_empty() %}
+        switch (value) {
+            {%- for variant in e.variants %}
+            case {{ type_name }}.{{ variant.name }}:
+            {%- endfor %}
+                return;
+        }
+        {%- endif %}
+        throw new TypeError(`Invalid {{ type_name }} value: ${value}`);
+        {%- else %}
+        switch (value.tag) {
+            {%- for variant in e.variants %}
+            case "{{ variant.name }}":
+                {%- for field in variant.fields %}
+                {{ field.ty.ffi_converter_name }}.checkLower(value.{{ field.name }});
+                {%- endfor %}
+                return;
+            {%- endfor %}
+        }
+        throw new TypeError(`Invalid {{ type_name }} tag: ${value.tag}`);
+        {%- endif %}
+    },
+    read(reader) {
+        switch (reader.readInt32()) {
+            {%- for variant in e.variants %}
+            case {{ loop.index }}:
+                {%- if e.is_flat || variant.fields.is_empty() %}
+                return {{ type_name }}.{{ variant.name }};
+                {%- else %}
+                return {
+                    tag: "{{ variant.name }}",
+                    {%- for field in variant.fields %}
+                    {{ field.name }}: {{ field.ty.ffi_converter_name }}.read(reader),
+                    {%- endfor %}
+                };
+                {%- endif %}
+            {%- endfor %}
+            default:
+                throw new InternalError("Raw enum value doesn't match any cases");
+        }
+    },
+    write(value, writer) {
+        {%- if e.is_flat %}
+        switch (value) {
+            {%- for variant in e.variants %}
+            case {{ type_name }}.{{ variant.name }}:
+                writer.writeInt32({{ loop.index }});
+                return;
+            {%- endfor %}
+        }
+        {%- else %}
+        switch (value.tag) {
+            {%- for variant in e.variants %}
+            case "{{ variant.name }}":
+                writer.writeInt32({{ loop.index }});
+                {%- for field in variant.fields %}
+                {{ field.ty.ffi_converter_name }}.write(value.{{ field.name }}, writer);
+                {%- endfor %}
+                return;
+            {%- endfor %}
+        }
+        {%- endif %}
+        throw new InternalError("Invalid {{ type_name }} value");
+    },
+});
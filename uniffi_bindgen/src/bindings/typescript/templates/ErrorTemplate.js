@@ -0,0 +1,92 @@
+{%- let type_name = e.self_type.type_name %}
+{{- e.docstring|docstring(0) -}}
+class {{ type_name }} extends Error {
+    constructor(tag, message) {
+        super(message);
+        this.tag = tag;
+        this.name = `{{ type_name }}.${tag}`;
+    }
+{%- for variant in e.variants %}
+
+    {{ variant.docstring|docstring(4) -}}
+    static {{ variant.name }} = class {{ variant.name }} extends {{ type_name }} {
+{%-     if e.is_flat %}
+        constructor(message) {
+            super("{{ variant.name }}", message);
+        }
+{%-     else if variant.has_unnamed_fields() %}
+        constructor({% for field in variant.fields %}{{ field.name }}{% if !loop.last %}, {% endif %}{% endfor %}) {
+            super("{{ variant.name }}", [{% for field in variant.fields %}{{ field.name }}{% if !loop.last %}, {% endif %}{% endfor %}].join(", "));
+            {%- for field in variant.fields %}
+            this.{{ field.name }} = {{ field.name }};
+            {%- endfor %}
+        }
+{%-     else %}
+        constructor(fields{% if variant.fields.is_empty() %} = {}{% endif %}) {
+            super("{{ variant.name }}", `{% for field in variant.fields %}{{ field.name }}=${fields.{{ field.name }}}{% if !loop.last %}, {% endif %}{% endfor %}`);
+            {%- for field in variant.fields %}
+            this.{{ field.name }} = fields.{{ field.name }};
+            {%- endfor %}
+        }
+{%-     endif %}
+    };
+{%- endfor %}
+
+{%- for meth in e.methods %}
+{%-     let callable = meth.callable %}
+
+    {{ meth.docstring|docstring(4) -}}
+    {% if callable.is_async() %}async {% endif %}{{ callable.name }}({% include "CallableArgs.js" %}) {
+        const self = this;
+        {%- filter indent(8) %}
+        {%- include "CallableBody.js" %}
+        {%- endfilter %}
+    }
+{%- endfor %}
+}
+
+const {{ e.self_type.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (!(value instanceof {{ type_name }})) {
+            throw new TypeError("Expected {{ type_name }} instance");
+        }
+    },
+    read(reader) {
+        switch (reader.readInt32()) {
+            {%- for variant in e.variants %}
+            case {{ loop.index }}:
+                {%- if e.is_flat %}
+                return new {{ type_name }}.{{ variant.name }}({{ builtin_types.string.ffi_converter_name }}.read(reader));
+                {%- else if variant.has_unnamed_fields() %}
+                return new {{ type_name }}.{{ variant.name }}(
+                    {%- for field in variant.fields %}
+                    {{ field.ty.ffi_converter_name }}.read(reader),
+                    {%- endfor %}
+                );
+                {%- else %}
+                return new {{ type_name }}.{{ variant.name }}({
+                    {%- for field in variant.fields %}
+                    {{ field.name }}: {{ field.ty.ffi_converter_name }}.read(reader),
+                    {%- endfor %}
+                });
+                {%- endif %}
+            {%- endfor %}
+            default:
+                throw new InternalError("Raw enum value doesn't match any cases");
+        }
+    },
+    write(value, writer) {
+        {%- for variant in e.variants %}
+        if (value instanceof {{ type_name }}.{{ variant.name }}) {
+            writer.writeInt32({{ loop.index }});
+            {%- if !e.is_flat %}
+            {%- for field in variant.fields %}
+            {{ field.ty.ffi_converter_name }}.write(value.{{ field.name }}, writer);
+            {%- endfor %}
+            {%- endif %}
+            return;
+        }
+        {%- endfor %}
+        throw new InternalError("Invalid {{ type_name }} value");
+    },
+});
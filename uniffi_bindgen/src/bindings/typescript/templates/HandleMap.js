@@ -0,0 +1,43 @@
+// Initial value and increment amount for handles.
+// These ensure that JS-generated handles always have the lowest bit set
+const UNIFFI_HANDLEMAP_INITIAL = 1n;
+const UNIFFI_HANDLEMAP_DELTA = 2n;
+
+// Maps handles that we pass to Rust to JS objects
+//
+// Handles are always normalized to `bigint`, since koffi may pass us a `number` for small values.
+class UniffiHandleMap {
+    constructor() {
+        this._map = new Map();
+        this._counter = UNIFFI_HANDLEMAP_INITIAL;
+    }
+
+    insert(obj) {
+        const handle = this._counter;
+        this._counter += UNIFFI_HANDLEMAP_DELTA;
+        this._map.set(handle, obj);
+        return handle;
+    }
+
+    get(handle) {
+        handle = BigInt(handle);
+        if (!this._map.has(handle)) {
+            throw new InternalError(`UniffiHandleMap.get: Invalid handle ${handle}`);
+        }
+        return this._map.get(handle);
+    }
+
+    clone(handle) {
+        return this.insert(this.get(handle));
+    }
+
+    remove(handle) {
+        const obj = this.get(handle);
+        this._map.delete(BigInt(handle));
+        return obj;
+    }
+
+    get size() {
+        return this._map.size;
+    }
+}
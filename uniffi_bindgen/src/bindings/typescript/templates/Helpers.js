@@ -0,0 +1,56 @@
+// A handful of classes and functions to support the generated data structures.
+
+class InternalError extends Error {
+    constructor(message) {
+        super(message);
+        this.name = "InternalError";
+    }
+}
+
+// These match the values from the uniffi::rustcalls module
+const UNIFFI_CALL_SUCCESS = 0;
+const UNIFFI_CALL_ERROR = 1;
+const UNIFFI_CALL_UNEXPECTED_ERROR = 2;
+
+function uniffiRustCallStatusDefault() {
+    return { code: UNIFFI_CALL_SUCCESS, error_buf: uniffiRustBufferDefault() };
+}
+
+function uniffiRustCall(fn, ...args) {
+    // Call a rust function
+    return uniffiRustCallWithError(undefined, fn, ...args);
+}
+
+function uniffiRustCallWithError(errorFfiConverter, fn, ...args) {
+    // Call a rust function and handle any errors
+    //
+    // This function is used for rust calls that return Result<> and therefore can set the
+    // CALL_ERROR status code.  errorFfiConverter must be set to the FfiConverter for the error
+    // type that corresponds to the result.
+    const callStatus = uniffiRustCallStatusDefault();
+    const result = fn(...args, callStatus);
+    uniffiCheckCallStatus(errorFfiConverter, callStatus);
+    return result;
+}
+
+function uniffiCheckCallStatus(errorFfiConverter, callStatus) {
+    switch (callStatus.code) {
+        case UNIFFI_CALL_SUCCESS:
+            return;
+        case UNIFFI_CALL_ERROR:
+            if (errorFfiConverter === undefined) {
+                uniffiConsumeRustBuffer(callStatus.error_buf);
+                throw new InternalError("uniffiRustCallWithError: CALL_ERROR, but errorFfiConverter is undefined");
+            }
+            throw errorFfiConverter.lift(callStatus.error_buf);
+        case UNIFFI_CALL_UNEXPECTED_ERROR:
+            // When the rust code sees a panic, it tries to construct a RustBuffer with the
+            // message.  But if that code panics, then it just sends back an empty buffer.
+            if (Number(callStatus.error_buf.len) > 0) {
+                throw new InternalError({{ builtin_types.string.ffi_converter_name }}.lift(callStatus.error_buf));
+            }
+            throw new InternalError("Unknown rust panic");
+        default:
+            throw new InternalError(`Invalid RustCallStatus code: ${callStatus.code}`);
+    }
+}
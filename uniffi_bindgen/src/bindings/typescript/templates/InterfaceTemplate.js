@@ -0,0 +1,195 @@
+{%- let ffi_converter_name = int.self_type.ffi_converter_name %}
+{{- int.docstring|docstring(0) -}}
+class {{ int.name }}{% if int.self_type.is_used_as_error %} extends Error{% endif %} {
+    // Frees the Rust object once the JS object is garbage collected
+    static _uniffiRegistry = new FinalizationRegistry((handle) => {
+        uniffiRustCall(UniffiLib.{{ int.ffi_func_free.0 }}, handle);
+    });
+
+{%- for cons in int.constructors %}
+{%-     let callable = cons.callable %}
+{%-     if callable.is_primary_constructor() %}
+
+    {{ cons.docstring|docstring(4) -}}
+    constructor({% include "CallableArgs.js" %}) {
+        {%- if int.self_type.is_used_as_error %}
+        super();
+        {%- endif %}
+        {%- filter indent(8) %}
+        {%- include "CallableBody.js" %}
+        {%- endfilter %}
+    }
+{%-     else %}
+
+    {{ cons.docstring|docstring(4) -}}
+    static {% if callable.is_async() %}async {% endif %}{{ callable.name }}({% include "CallableArgs.js" %}) {
+        {%- filter indent(8) %}
+        {%- include "CallableBody.js" %}
+        {%- endfilter %}
+    }
+{%-     endif %}
+{%- endfor %}
+
+{%- if !int.has_primary_constructor() %}
+
+    constructor() {
+        {%- if int.self_type.is_used_as_error %}
+        super();
+        {%- endif %}
+        throw new TypeError("{{ int.name }} has no primary constructor");
+    }
+{%- endif %}
+
+    _uniffiInit(handle) {
+        this._uniffiHandle = handle;
+        {{ int.name }}._uniffiRegistry.register(this, handle, this);
+    }
+
+    // Used by alternative constructors or any methods which return this type.
+    static _uniffiMakeInstance(handle) {
+        // Bypass the usual constructor logic and just create a new instance with the required
+        // handle.
+        const inst = Object.create({{ int.name }}.prototype);
+        inst._uniffiInit(handle);
+        return inst;
+    }
+
+    uniffiCloneHandle() {
+        if (this._uniffiHandle === undefined) {
+            throw new InternalError("{{ int.name }} object has already been destroyed");
+        }
+        return uniffiRustCall(UniffiLib.{{ int.ffi_func_clone.0 }}, this._uniffiHandle);
+    }
+
+    // Release the Rust object now, rather than waiting for the garbage collector.
+    //
+    // The object can't be used after this is called.
+    uniffiDestroy() {
+        const handle = this._uniffiHandle;
+        if (handle !== undefined) {
+            this._uniffiHandle = undefined;
+            {{ int.name }}._uniffiRegistry.unregister(this);
+            uniffiRustCall(UniffiLib.{{ int.ffi_func_free.0 }}, handle);
+        }
+    }
+
+{%- for meth in int.methods %}
+{%-     let callable = meth.callable %}
+
+    {{ meth.docstring|docstring(4) -}}
+    {% if callable.is_async() %}async {% endif %}{{ callable.name }}({% include "CallableArgs.js" %}) {
+        {%- filter indent(8) %}
+        {%- include "CallableBody.js" %}
+        {%- endfilter %}
+    }
+{%- endfor %}
+
+{%- for meth in int.static_methods %}
+{%-     let callable = meth.callable %}
+
+    {{ meth.docstring|docstring(4) -}}
+    static {% if callable.is_async() %}async {% endif %}{{ callable.name }}({% include "CallableArgs.js" %}) {
+        {%- filter indent(8) %}
+        {%- include "CallableBody.js" %}
+        {%- endfilter %}
+    }
+{%- endfor %}
+
+    {%- let uniffi_trait_methods = int.uniffi_trait_methods %}
+    {%- let self_arg = "" %}
+    {%- let member_sep = "" %}
+    {%- filter indent(4) %}
+    {%- include "UniffiTraitImpls.js" %}
+    {%- endfilter %}
+}
+
+{#- Objects as error #}
+{%- if int.self_type.is_used_as_error %}
+
+// Due to some mismatches in the ffi converter mechanisms, errors are forced to be a RustBuffer
+const {{ ffi_converter_name }}AsError = Object.freeze({
+    lift(value) {
+        // Errors are always a rust buffer holding a handle - which is a "read"
+        const reader = new UniffiRustBufferReader(uniffiConsumeRustBuffer(value));
+        return {{ ffi_converter_name }}.read(reader);
+    },
+    lower(value) {
+        throw new InternalError("not implemented");
+    },
+});
+{%- endif %}
+
+{%- match int.vtable %}
+{%- when None %}
+{#- simple case: the interface can only be implemented in Rust #}
+
+const {{ ffi_converter_name }} = Object.freeze({
+    lift(value) {
+        return {{ int.name }}._uniffiMakeInstance(value);
+    },
+    checkLower(value) {
+        if (!(value instanceof {{ int.name }})) {
+            throw new TypeError("Expected {{ int.name }} instance");
+        }
+    },
+    lower(value) {
+        return value.uniffiCloneHandle();
+    },
+    read(reader) {
+        const handle = reader.readUInt64();
+        if (handle === 0n) {
+            throw new InternalError("Raw handle value was null");
+        }
+        return this.lift(handle);
+    },
+    write(value, writer) {
+        writer.writeUInt64(this.lower(value));
+    },
+});
+{%- when Some(vtable) %}
+{#-
+ # The interface can be implemented in Rust or JS
+ #
+ # * Generate a callback interface implementation to handle the JS side
+ # * In the FfiConverter, check which side a handle came from to know how to handle correctly.
+ #}
+
+const {{ ffi_converter_name }} = Object.freeze({
+    _handleMap: new UniffiHandleMap(),
+    lift(value) {
+        const handle = BigInt(value);
+        if ((handle & 1n) === 0n) {
+            // Rust-generated handle, construct a new object that uses the handle to implement the
+            // interface
+            return {{ int.name }}._uniffiMakeInstance(handle);
+        }
+        // JS-generated handle, get the object from the handle map
+        return this._handleMap.remove(handle);
+    },
+    checkLower(value) {
+        if (typeof value !== "object" || value === null) {
+            throw new TypeError("Expected {{ int.self_type.type_name }} implementation");
+        }
+    },
+    lower(value) {
+        if (value instanceof {{ int.name }}) {
+            // Rust-implemented object.  Clone the handle and return it
+            return value.uniffiCloneHandle();
+        }
+        // JS-implemented object, generate a new vtable handle and return that.
+        return this._handleMap.insert(value);
+    },
+    read(reader) {
+        const handle = reader.readUInt64();
+        if (handle === 0n) {
+            throw new InternalError("Raw handle value was null");
+        }
+        return this.lift(handle);
+    },
+    write(value, writer) {
+        writer.writeUInt64(this.lower(value));
+    },
+});
+
+{% include "CallbackInterfaceImpl.js" %}
+{%- endmatch %}
@@ -0,0 +1,30 @@
+const {{ map.self_type.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (!(value instanceof Map)) {
+            throw new TypeError("argument must be a Map");
+        }
+        for (const [key, item] of value) {
+            {{ map.key.ffi_converter_name }}.checkLower(key);
+            {{ map.value.ffi_converter_name }}.checkLower(item);
+        }
+    },
+    read(reader) {
+        const count = reader.readInt32();
+        if (count < 0) {
+            throw new InternalError("Unexpected negative map size");
+        }
+        const items = new Map();
+        for (let i = 0; i < count; i++) {
+            const key = {{ map.key.ffi_converter_name }}.read(reader);
+            items.set(key, {{ map.value.ffi_converter_name }}.read(reader));
+        }
+        return items;
+    },
+    write(value, writer) {
+        writer.writeInt32(value.size);
+        for (const [key, item] of value) {
+            {{ map.key.ffi_converter_name }}.write(key, writer);
+            {{ map.value.ffi_converter_name }}.write(item, writer);
+        }
+    },
+});
@@ -0,0 +1,69 @@
+{{ module.docstring|docstring(0) -}}
+// This file was autogenerated by some hot garbage in the `uniffi` crate.
+// Trust me, you don't want to mess with it!
+{%- for import in module.imports %}
+import * as {{ import.name }} from "{{ import.specifier }}";
+{%- endfor %}
+
+/** Unexpected error from the UniFFI runtime or the Rust library */
+export declare class InternalError extends Error {}
+
+{%- for type_def in module.type_definitions %}
+{%- match type_def %}
+{%- when TypeDefinition::Enum(e) %}
+{%- if e.self_type.is_used_as_error %}
+{%- include "declarations/ErrorTemplate.d.ts" %}
+{%- else %}
+{%- include "declarations/EnumTemplate.d.ts" %}
+{%- endif %}
+{%- when TypeDefinition::Record(rec) %}
+{%- include "declarations/RecordTemplate.d.ts" %}
+{%- when TypeDefinition::Interface(int) %}
+{%- include "declarations/InterfaceTemplate.d.ts" %}
+{%- when TypeDefinition::CallbackInterface(cbi) %}
+{%- include "declarations/CallbackInterfaceTemplate.d.ts" %}
+{%- when TypeDefinition::Simple(type_node) %}
+{%- if module.lossless_time_types %}
+{%- match type_node.ty %}
+{%- when Type::Timestamp %}
+
+/**
+ * A point in time, accurate to the nanosecond.
+ *
+ * `seconds` counts from the Unix epoch and `nanoseconds` is always added to it, so half a second
+ * before the epoch is `{ seconds: -1n, nanoseconds: 500000000 }`.
+ */
+export interface UniffiTimestamp {
+    seconds: bigint;
+    nanoseconds: number;
+}
+{%- when Type::Duration %}
+
+/** A span of time, accurate to the nanosecond. */
+export interface UniffiDuration {
+    seconds: bigint;
+    nanoseconds: number;
+}
+{%- else %}
+{%- endmatch %}
+{%- endif %}
+{%- when TypeDefinition::Custom(custom) %}
+
+{{ custom.docstring|docstring(0) -}}
+export type {{ custom.name }} = {% match custom.config %}{% when Some(config) %}{{ config.type_name.as_deref().unwrap_or(custom.builtin.type_name.as_str()) }}{% when None %}{{ custom.builtin.type_name }}{% endmatch %};
+{%- else %}
+{%- endmatch %}
+{%- endfor %}
+
+{%- for c in module.constants %}
+
+{{ c.docstring|docstring(0) -}}
+export declare const {{ c.name }}: {{ c.ty.type_name }};
+{%- endfor %}
+
+{%- for func in module.functions %}
+{%-     let callable = func.callable %}
+
+{{ func.docstring|docstring(0) -}}
+export declare function {{ callable.name }}({% include "declarations/CallableArgs.d.ts" %}): {{ callable.ts_return_type() }};
+{%- endfor %}
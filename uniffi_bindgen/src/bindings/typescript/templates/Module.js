@@ -0,0 +1,55 @@
+{{ docstring|docstring(0) -}}
+// This file was autogenerated by some hot garbage in the `uniffi` crate.
+// Trust me, you don't want to mess with it!
+
+// Common helper code.
+//
+// Ideally this would live in a separate package where it can be unittested etc in isolation.
+//
+// However, it's important that the details of how this helper code works (e.g. the way that
+// different builtin types are passed across the FFI) exactly match what's expected by the Rust
+// code on the other side of the interface. In practice right now that means coming from the exact
+// same version of `uniffi` that was used to compile the Rust component. The easiest way to ensure
+// this is to bundle the JS helpers directly inline like we're doing here.
+
+"use strict";
+
+const koffi = require("koffi");
+const path = require("path");
+{%- for import in imports %}
+const {{ import.name }} = require("{{ import.specifier }}");
+{%- endfor %}
+
+{% include "RustBufferTemplate.js" %}
+{% include "Helpers.js" %}
+{% include "HandleMap.js" %}
+{%- if has_callback_interface %}
+{% include "CallbackInterfaceRuntime.js" %}
+{%- endif %}
+
+// Contains loading, initialization code, and the FFI Function declarations.
+{% include "NamespaceLibraryTemplate.js" %}
+{#- Async support comes after NamespaceLibraryTemplate, since it depends on the FFI types #}
+{%- if has_async_fns %}
+
+{% include "Async.js" %}
+{%- endif %}
+
+// Public interface members begin here.
+{%- include "Types.js" %}
+
+{%- for c in constants %}
+
+{{ c.docstring|docstring(0) -}}
+const {{ c.name }} = {{ c.value.js_lit }};
+{%- endfor %}
+
+{%- for func in functions %}
+{%- include "TopLevelFunctionTemplate.js" %}
+{%- endfor %}
+
+module.exports = {
+    {%- for name in exported_names %}
+    {{ name }},
+    {%- endfor %}
+};
@@ -0,0 +1,76 @@
+// This is how we find and load the dynamic library provided by the component.
+// For now we just look it up by name, next to this file.
+function uniffiLoadIndirect() {
+    let libname;
+    if (process.platform === "darwin") {
+        libname = "lib{{ cdylib_name }}.dylib";
+    } else if (process.platform === "win32") {
+        libname = "{{ cdylib_name }}.dll";
+    } else {
+        // Anything else must be an ELF platform - Linux, *BSD, Solaris/illumos
+        libname = "lib{{ cdylib_name }}.so";
+    }
+    return koffi.load(path.join(__dirname, libname));
+}
+
+const uniffiLib = uniffiLoadIndirect();
+
+// Koffi type names are global, so we prefix them with the module name to avoid conflicts with
+// other UniFFI modules.
+const UniffiRustBuffer = koffi.struct("{{ name }}_UniffiRustBuffer", {
+    capacity: "uint64_t",
+    len: "uint64_t",
+    data: "uint8_t *",
+});
+
+const UniffiForeignBytes = koffi.struct("{{ name }}_UniffiForeignBytes", {
+    len: "int32_t",
+    data: "uint8_t *",
+});
+
+const UniffiRustCallStatus = koffi.struct("{{ name }}_UniffiRustCallStatus", {
+    code: "int8_t",
+    error_buf: UniffiRustBuffer,
+});
+
+// The extern-C FFI definitions.
+// This is an implementation detail which will be called internally by the public API.
+const UniffiLib = {};
+
+{%- for def in ffi_definitions %}
+{%- match def %}
+{%- when FfiDefinition::FunctionType(function_type) %}
+const {{ function_type.name.0 }} = koffi.proto("{{ name }}_{{ function_type.name.0 }}", {% match function_type.return_type.ty %}{% when Some(return_type) %}{{ return_type.type_name }}{% when None %}"void"{% endmatch %}, [
+    {%- for arg in function_type.arguments %}
+    {{ arg.ty.type_name }},
+    {%- endfor %}
+    {%- if function_type.has_rust_call_status_arg %}
+    koffi.pointer(UniffiRustCallStatus),
+    {%- endif %}
+]);
+{%- when FfiDefinition::Struct(ffi_struct) %}
+const {{ ffi_struct.name.0 }} = koffi.struct("{{ name }}_{{ ffi_struct.name.0 }}", {
+    {%- for field in ffi_struct.fields %}
+    {{ field.name }}: {{ field.ty.type_name }},
+    {%- endfor %}
+});
+{%- when FfiDefinition::RustFunction(func) %}
+UniffiLib.{{ func.name.0 }} = uniffiLib.func("{{ func.name.0 }}", {% match func.return_type.ty %}{% when Some(ffi_type) %}{{ ffi_type.type_name }}{% when None %}"void"{% endmatch %}, [
+    {%- for arg in func.arguments %}
+    {{ arg.ty.type_name }},
+    {%- endfor %}
+    {%- if func.has_rust_call_status_arg %}
+    koffi.inout(koffi.pointer(UniffiRustCallStatus)),
+    {%- endif %}
+]);
+{%- endmatch %}
+{%- endfor %}
+{#- Ensure to call the contract verification only after we defined all functions. #}
+{%- match checksum_mode %}
+{%- when ChecksumMode::Legacy %}
+
+{% include "ChecksumsLegacy.js" %}
+{%- when ChecksumMode::Skip %}
+
+// ChecksumMode::Skip, skipping checksum verifications
+{%- endmatch %}
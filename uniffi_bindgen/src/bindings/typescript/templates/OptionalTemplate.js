@@ -0,0 +1,24 @@
+const {{ opt.self_type.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (value !== undefined && value !== null) {
+            {{ opt.inner.ffi_converter_name }}.checkLower(value);
+        }
+    },
+    read(reader) {
+        const flag = reader.readInt8();
+        if (flag === 0) {
+            return undefined;
+        } else if (flag === 1) {
+            return {{ opt.inner.ffi_converter_name }}.read(reader);
+        }
+        throw new InternalError("Unexpected flag byte for optional");
+    },
+    write(value, writer) {
+        if (value === undefined || value === null) {
+            writer.writeInt8(0);
+        } else {
+            writer.writeInt8(1);
+            {{ opt.inner.ffi_converter_name }}.write(value, writer);
+        }
+    },
+});
@@ -0,0 +1,66 @@
+{%- let type_name = rec.self_type.type_name %}
+{{- rec.docstring|docstring(0) -}}
+const {{ type_name }} = Object.freeze({
+    // Create a `{{ type_name }}`, filling in the default values for any missing fields
+    create(fields) {
+        return {
+            {%- for field in rec.fields %}
+            {%- match field.default %}
+            {%- when Some(default) %}
+            {{ field.name }}: fields.{{ field.name }} === undefined ? {{ default.js_default }} : fields.{{ field.name }},
+            {%- when None %}
+            {{ field.name }}: fields.{{ field.name }},
+            {%- endmatch %}
+            {%- endfor %}
+        };
+    },
+
+{%- for cons in rec.constructors %}
+{%-     let callable = cons.callable %}
+
+    {{ cons.docstring|docstring(4) -}}
+    {% if callable.is_async() %}async {% endif %}{{ callable.name }}({% include "CallableArgs.js" %}) {
+        {%- filter indent(8) %}
+        {%- include "CallableBody.js" %}
+        {%- endfilter %}
+    },
+{%- endfor %}
+
+{%- for meth in rec.methods %}
+{%-     let callable = meth.callable %}
+
+    {{ meth.docstring|docstring(4) -}}
+    {% if callable.is_async() %}async {% endif %}{{ callable.name }}(self{% if !callable.arguments.is_empty() %}, {% endif %}{% include "CallableArgs.js" %}) {
+        {%- filter indent(8) %}
+        {%- include "CallableBody.js" %}
+        {%- endfilter %}
+    },
+{%- endfor %}
+
+    {%- let uniffi_trait_methods = rec.uniffi_trait_methods %}
+    {%- let self_arg = "self" %}
+    {%- let member_sep = "," %}
+    {%- filter indent(4) %}
+    {%- include "UniffiTraitImpls.js" %}
+    {%- endfilter %}
+});
+
+const {{ rec.self_type.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        {%- for field in rec.fields %}
+        {{ field.ty.ffi_converter_name }}.checkLower(value.{{ field.name }});
+        {%- endfor %}
+    },
+    read(reader) {
+        return {
+            {%- for field in rec.fields %}
+            {{ field.name }}: {{ field.ty.ffi_converter_name }}.read(reader),
+            {%- endfor %}
+        };
+    },
+    write(value, writer) {
+        {%- for field in rec.fields %}
+        {{ field.ty.ffi_converter_name }}.write(value.{{ field.name }}, writer);
+        {%- endfor %}
+    },
+});
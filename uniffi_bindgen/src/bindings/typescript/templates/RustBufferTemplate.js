@@ -0,0 +1,284 @@
+// Reads values from a RustBuffer that's been copied into a Uint8Array
+//
+// All values are big-endian, to match the `uniffi::RustBuffer` serialization format.
+class UniffiRustBufferReader {
+    constructor(bytes) {
+        this.bytes = bytes;
+        this.view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+        this.pos = 0;
+    }
+
+    remaining() {
+        return this.bytes.byteLength - this.pos;
+    }
+
+    _advance(size) {
+        if (this.pos + size > this.bytes.byteLength) {
+            throw new InternalError("read past the end of the buffer");
+        }
+        const pos = this.pos;
+        this.pos += size;
+        return pos;
+    }
+
+    readInt8() {
+        return this.view.getInt8(this._advance(1));
+    }
+
+    readUInt8() {
+        return this.view.getUint8(this._advance(1));
+    }
+
+    readInt16() {
+        return this.view.getInt16(this._advance(2));
+    }
+
+    readUInt16() {
+        return this.view.getUint16(this._advance(2));
+    }
+
+    readInt32() {
+        return this.view.getInt32(this._advance(4));
+    }
+
+    readUInt32() {
+        return this.view.getUint32(this._advance(4));
+    }
+
+    readInt64() {
+        return this.view.getBigInt64(this._advance(8));
+    }
+
+    readUInt64() {
+        return this.view.getBigUint64(this._advance(8));
+    }
+
+    readInt128() {
+        return BigInt.asIntN(128, this.readUInt128());
+    }
+
+    readUInt128() {
+        const high = this.readUInt64();
+        const low = this.readUInt64();
+        return (high << 64n) | low;
+    }
+
+    readFloat32() {
+        return this.view.getFloat32(this._advance(4));
+    }
+
+    readFloat64() {
+        return this.view.getFloat64(this._advance(8));
+    }
+
+    readBytes(size) {
+        const pos = this._advance(size);
+        return this.bytes.slice(pos, pos + size);
+    }
+}
+
+// Writes values into a growable buffer, which can then be copied into a RustBuffer
+class UniffiRustBufferWriter {
+    constructor() {
+        this.bytes = new Uint8Array(64);
+        this.view = new DataView(this.bytes.buffer);
+        this.pos = 0;
+    }
+
+    _reserve(size) {
+        if (this.pos + size > this.bytes.byteLength) {
+            const bytes = new Uint8Array(Math.max(this.bytes.byteLength * 2, this.pos + size));
+            bytes.set(this.bytes);
+            this.bytes = bytes;
+            this.view = new DataView(bytes.buffer);
+        }
+        const pos = this.pos;
+        this.pos += size;
+        return pos;
+    }
+
+    writeInt8(value) {
+        this.view.setInt8(this._reserve(1), value);
+    }
+
+    writeUInt8(value) {
+        this.view.setUint8(this._reserve(1), value);
+    }
+
+    writeInt16(value) {
+        this.view.setInt16(this._reserve(2), value);
+    }
+
+    writeUInt16(value) {
+        this.view.setUint16(this._reserve(2), value);
+    }
+
+    writeInt32(value) {
+        this.view.setInt32(this._reserve(4), value);
+    }
+
+    writeUInt32(value) {
+        this.view.setUint32(this._reserve(4), value);
+    }
+
+    writeInt64(value) {
+        this.view.setBigInt64(this._reserve(8), BigInt(value));
+    }
+
+    writeUInt64(value) {
+        this.view.setBigUint64(this._reserve(8), BigInt(value));
+    }
+
+    writeInt128(value) {
+        this.writeUInt128(BigInt.asUintN(128, BigInt(value)));
+    }
+
+    writeUInt128(value) {
+        this.writeUInt64(value >> 64n);
+        this.writeUInt64(BigInt.asUintN(64, value));
+    }
+
+    writeFloat32(value) {
+        this.view.setFloat32(this._reserve(4), value);
+    }
+
+    writeFloat64(value) {
+        this.view.setFloat64(this._reserve(8), value);
+    }
+
+    writeBytes(bytes) {
+        this.bytes.set(bytes, this._reserve(bytes.byteLength));
+    }
+
+    finish() {
+        return this.bytes.subarray(0, this.pos);
+    }
+}
+
+function uniffiRustBufferDefault() {
+    return { capacity: 0, len: 0, data: null };
+}
+
+// Copy the contents of a RustBuffer into a Uint8Array, then free the RustBuffer
+function uniffiConsumeRustBuffer(rbuf) {
+    try {
+        const len = Number(rbuf.len);
+        if (len === 0) {
+            return new Uint8Array(0);
+        }
+        return koffi.decode(rbuf.data, koffi.array("uint8_t", len, "Typed"));
+    } finally {
+        uniffiRustCall(UniffiLib.{{ ffi_rustbuffer_free.0 }}, rbuf);
+    }
+}
+
+// Allocate a RustBuffer and copy `bytes` into it
+function uniffiRustBufferFromBytes(bytes) {
+    const rbuf = uniffiRustCall(UniffiLib.{{ ffi_rustbuffer_alloc.0 }}, bytes.byteLength);
+    if (bytes.byteLength > 0) {
+        koffi.encode(rbuf.data, koffi.array("uint8_t", bytes.byteLength, "Typed"), bytes);
+    }
+    rbuf.len = bytes.byteLength;
+    return rbuf;
+}
+
+// Create an FfiConverter for a type that's passed across the FFI as a serialized RustBuffer.
+//
+// `converter` must define `read`, `write` and `checkLower`.
+function uniffiRustBufferConverter(converter) {
+    if (converter.lift === undefined) {
+        converter.lift = (rbuf) => {
+            const reader = new UniffiRustBufferReader(uniffiConsumeRustBuffer(rbuf));
+            const value = converter.read(reader);
+            if (reader.remaining() !== 0) {
+                throw new InternalError("junk remaining in buffer after lifting");
+            }
+            return value;
+        };
+    }
+    if (converter.lower === undefined) {
+        converter.lower = (value) => {
+            const writer = new UniffiRustBufferWriter();
+            converter.write(value, writer);
+            return uniffiRustBufferFromBytes(writer.finish());
+        };
+    }
+    return Object.freeze(converter);
+}
+
+// Create an FfiConverter for a type that's passed directly across the FFI
+function uniffiPrimitiveConverter(converter) {
+    if (converter.lift === undefined) {
+        converter.lift = (value) => value;
+    }
+    if (converter.lower === undefined) {
+        converter.lower = (value) => value;
+    }
+    return Object.freeze(converter);
+}
+
+function uniffiCheckInteger(value, typeName, min, max) {
+    if (!Number.isInteger(value)) {
+        throw new TypeError(`${typeName} value must be an integer, not ${value}`);
+    }
+    if (value < min || value > max) {
+        throw new RangeError(`${typeName} value out of range: ${value}`);
+    }
+}
+
+function uniffiCheckBigInt(value, typeName, min, max) {
+    if (typeof value !== "bigint") {
+        throw new TypeError(`${typeName} value must be a bigint, not ${typeof value}`);
+    }
+    if (value < min || value > max) {
+        throw new RangeError(`${typeName} value out of range: ${value}`);
+    }
+}
+
+// Create an FfiConverter that forwards to another converter
+//
+// The other converter is looked up lazily, since it may be defined later in the module.
+function uniffiForwardingConverter(getConverter) {
+    return Object.freeze({
+        checkLower: (value) => getConverter().checkLower(value),
+        lift: (value) => getConverter().lift(value),
+        lower: (value) => getConverter().lower(value),
+        read: (reader) => getConverter().read(reader),
+        write: (value, writer) => getConverter().write(value, writer),
+    });
+}
+
+// Converters for `&[u8]` and `&mut [u8]` arguments.
+//
+// These are passed as a `ForeignBytes` that borrows memory owned by the caller.  We copy the
+// bytes into memory allocated by koffi, which is released once the call returns.
+const FfiConverterByRefBytes = Object.freeze({
+    checkLower(value) {
+        if (!(value instanceof Uint8Array)) {
+            throw new TypeError("argument must be a Uint8Array");
+        }
+    },
+    lower(value) {
+        const len = value.byteLength;
+        const data = koffi.alloc("uint8_t", Math.max(len, 1));
+        if (len > 0) {
+            koffi.encode(data, koffi.array("uint8_t", len, "Typed"), value);
+        }
+        return { len, data };
+    },
+    release(foreignBytes, value) {
+        koffi.free(foreignBytes.data);
+    },
+});
+
+const FfiConverterByMutRefBytes = Object.freeze({
+    checkLower: FfiConverterByRefBytes.checkLower,
+    lower: FfiConverterByRefBytes.lower,
+    release(foreignBytes, value) {
+        // Copy any changes made by Rust back into the caller's array
+        if (foreignBytes.len > 0) {
+            value.set(koffi.decode(foreignBytes.data, koffi.array("uint8_t", foreignBytes.len, "Typed")));
+        }
+        koffi.free(foreignBytes.data);
+    },
+});
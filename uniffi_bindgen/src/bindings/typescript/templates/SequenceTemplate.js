@@ -0,0 +1,27 @@
+const {{ seq.self_type.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (!Array.isArray(value)) {
+            throw new TypeError("argument must be an Array");
+        }
+        for (const item of value) {
+            {{ seq.inner.ffi_converter_name }}.checkLower(item);
+        }
+    },
+    read(reader) {
+        const count = reader.readInt32();
+        if (count < 0) {
+            throw new InternalError("Unexpected negative sequence length");
+        }
+        const items = [];
+        for (let i = 0; i < count; i++) {
+            items.push({{ seq.inner.ffi_converter_name }}.read(reader));
+        }
+        return items;
+    },
+    write(value, writer) {
+        writer.writeInt32(value.length);
+        for (const item of value) {
+            {{ seq.inner.ffi_converter_name }}.write(item, writer);
+        }
+    },
+});
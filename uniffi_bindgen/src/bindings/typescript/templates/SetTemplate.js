@@ -0,0 +1,27 @@
+const {{ set.self_type.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (!(value instanceof Set)) {
+            throw new TypeError("argument must be a Set");
+        }
+        for (const item of value) {
+            {{ set.inner.ffi_converter_name }}.checkLower(item);
+        }
+    },
+    read(reader) {
+        const count = reader.readInt32();
+        if (count < 0) {
+            throw new InternalError("Unexpected negative set length");
+        }
+        const items = new Set();
+        for (let i = 0; i < count; i++) {
+            items.add({{ set.inner.ffi_converter_name }}.read(reader));
+        }
+        return items;
+    },
+    write(value, writer) {
+        writer.writeInt32(value.size);
+        for (const item of value) {
+            {{ set.inner.ffi_converter_name }}.write(item, writer);
+        }
+    },
+});
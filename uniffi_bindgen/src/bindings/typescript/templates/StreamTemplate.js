@@ -0,0 +1,29 @@
+const {{ stream.self_type.ffi_converter_name }} = Object.freeze({
+    lift(value) {
+        return new UniffiStream(
+            value,
+            UniffiLib.{{ stream.ffi_stream_next.0 }},
+            UniffiLib.{{ stream.ffi_stream_free.0 }},
+            UniffiLib.{{ stream.async_data.ffi_rust_future_poll.0 }},
+            UniffiLib.{{ stream.async_data.ffi_rust_future_complete.0 }},
+            UniffiLib.{{ stream.async_data.ffi_rust_future_free.0 }},
+            (rbuf) => {{ stream.self_type.ffi_converter_name }}._liftItem(rbuf),
+        );
+    },
+    _liftItem(rbuf) {
+        const reader = new UniffiRustBufferReader(uniffiConsumeRustBuffer(rbuf));
+        const flag = reader.readInt8();
+        if (flag === 0) {
+            return [false, undefined];
+        } else if (flag === 1) {
+            return [true, {{ stream.inner.ffi_converter_name }}.read(reader)];
+        }
+        throw new InternalError("Unexpected flag byte for stream item");
+    },
+    checkLower(value) {
+        throw new TypeError("Streams can't be passed to Rust");
+    },
+    lower(value) {
+        throw new TypeError("Streams can't be passed to Rust");
+    },
+});
@@ -0,0 +1,8 @@
+{%- let callable = func.callable %}
+
+{{ func.docstring|docstring(0) -}}
+{% if callable.is_async() %}async {% endif %}function {{ callable.name }}({% include "CallableArgs.js" %}) {
+    {%- filter indent(4) %}
+    {%- include "CallableBody.js" %}
+    {%- endfilter %}
+}
@@ -0,0 +1,22 @@
+const {{ tuple.self_type.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (!Array.isArray(value) || value.length !== {{ tuple.elements.len() }}) {
+            throw new TypeError("argument must be an array of length {{ tuple.elements.len() }}");
+        }
+        {%- for element in tuple.elements %}
+        {{ element.ffi_converter_name }}.checkLower(value[{{ loop.index0 }}]);
+        {%- endfor %}
+    },
+    read(reader) {
+        return [
+            {%- for element in tuple.elements %}
+            {{ element.ffi_converter_name }}.read(reader),
+            {%- endfor %}
+        ];
+    },
+    write(value, writer) {
+        {%- for element in tuple.elements %}
+        {{ element.ffi_converter_name }}.write(value[{{ loop.index0 }}], writer);
+        {%- endfor %}
+    },
+});
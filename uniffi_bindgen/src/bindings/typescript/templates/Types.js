@@ -0,0 +1,142 @@
+{%- for type_def in type_definitions %}
+{%- if let TypeDefinition::External(_) = type_def %}
+{#- External types are defined in their own module, see the imports in `Module.js` #}
+{%- else %}
+
+{#
+ # Map `Type` instances to an include statement for that type.
+ #
+ # There is a companion match in ``types::type_name()`` which performs a similar function for the
+ # Rust code.
+ #
+ #   - When adding additional types here, make sure to also add a match arm to that function.
+ #   - To keep things manageable, let's try to limit ourselves to these 2 mega-matches
+ #}
+{%- match type_def %}
+
+{%- when TypeDefinition::Simple(type_node) %}
+{%- match type_node.ty %}
+
+{%- when Type::Boolean %}
+{%- include "builtins/Boolean.js" %}
+
+{%- when Type::Int8 %}
+{%- include "builtins/Int8.js" %}
+
+{%- when Type::Int16 %}
+{%- include "builtins/Int16.js" %}
+
+{%- when Type::Int32 %}
+{%- include "builtins/Int32.js" %}
+
+{%- when Type::Int64 %}
+{%- include "builtins/Int64.js" %}
+
+{%- when Type::Int128 %}
+{%- include "builtins/Int128.js" %}
+
+{%- when Type::UInt8 %}
+{%- include "builtins/UInt8.js" %}
+
+{%- when Type::UInt16 %}
+{%- include "builtins/UInt16.js" %}
+
+{%- when Type::UInt32 %}
+{%- include "builtins/UInt32.js" %}
+
+{%- when Type::UInt64 %}
+{%- include "builtins/UInt64.js" %}
+
+{%- when Type::UInt128 %}
+{%- include "builtins/UInt128.js" %}
+
+{%- when Type::Float32 %}
+{%- include "builtins/Float32.js" %}
+
+{%- when Type::Float64 %}
+{%- include "builtins/Float64.js" %}
+
+{%- when Type::String %}
+{%- include "builtins/String.js" %}
+
+{%- when Type::Bytes %}
+{%- include "builtins/Bytes.js" %}
+
+{%- when Type::OwnedBytes %}
+{%- include "builtins/OwnedBytes.js" %}
+
+{%- when Type::Timestamp %}
+{%- if lossless_time_types %}
+{%- include "builtins/LosslessTimestamp.js" %}
+{%- else %}
+{%- include "builtins/Timestamp.js" %}
+{%- endif %}
+
+{%- when Type::Duration %}
+{%- if lossless_time_types %}
+{%- include "builtins/LosslessDuration.js" %}
+{%- else %}
+{%- include "builtins/Duration.js" %}
+{%- endif %}
+
+{%- when Type::Uuid %}
+{%- include "builtins/Uuid.js" %}
+
+{%- when Type::Url %}
+{%- include "builtins/Url.js" %}
+
+{%- when Type::Date %}
+{%- include "builtins/Date.js" %}
+
+{%- else %}
+{# Type::Simple shouldn't hold any other Type variants #}
+{%- endmatch %}
+
+{%- when TypeDefinition::Box(box_) %}
+{%- include "BoxTemplate.js" %}
+
+{%- when TypeDefinition::Optional(opt) %}
+{%- include "OptionalTemplate.js" %}
+
+{%- when TypeDefinition::Sequence(seq) %}
+{%- include "SequenceTemplate.js" %}
+
+{%- when TypeDefinition::Map(map) %}
+{%- include "MapTemplate.js" %}
+
+{%- when TypeDefinition::Set(set) %}
+{%- include "SetTemplate.js" %}
+
+{%- when TypeDefinition::Stream(stream) %}
+{%- include "StreamTemplate.js" %}
+
+{%- when TypeDefinition::Tuple(tuple) %}
+{%- include "TupleTemplate.js" %}
+
+{%- when TypeDefinition::Array(array) %}
+{%- include "ArrayTemplate.js" %}
+
+{%- when TypeDefinition::Enum(e) %}
+{#- For enums, there are either an error *or* an enum, they can't be both. #}
+{%- if e.self_type.is_used_as_error %}
+{%- include "ErrorTemplate.js" %}
+{%- else %}
+{%- include "EnumTemplate.js" %}
+{%- endif %}
+
+{%- when TypeDefinition::Record(rec) %}
+{%- include "RecordTemplate.js" %}
+
+{%- when TypeDefinition::Interface(int) %}
+{%- include "InterfaceTemplate.js" %}
+
+{%- when TypeDefinition::CallbackInterface(cbi) %}
+{%- include "CallbackInterfaceTemplate.js" %}
+
+{%- when TypeDefinition::Custom(custom) %}
+{%- include "CustomType.js" %}
+
+{%- else %}
+{%- endmatch %}
+{%- endif %}
+{%- endfor %}
@@ -0,0 +1,55 @@
+{#
+ # expects vars `uniffi_trait_methods`, `self_arg` and `member_sep`.
+ #
+ # `self_arg` is the leading argument for functions on records/enums, which are plain JS objects
+ # and can't have methods.  It's empty for interface methods.
+ #}
+{%- if let Some(fmt) = uniffi_trait_methods.display_fmt %}
+{%-    let callable = fmt.callable %}
+// The Rust `Display::fmt` implementation.
+toString({{ self_arg }}) {
+    {%- filter indent(4) %}
+    {%- include "CallableBody.js" %}
+    {%- endfilter %}
+}{{ member_sep }}
+{%- endif %}
+
+{%- if let Some(fmt) = uniffi_trait_methods.debug_fmt %}
+{%-    let callable = fmt.callable %}
+// The Rust `Debug::fmt` implementation.
+toDebugString({{ self_arg }}) {
+    {%- filter indent(4) %}
+    {%- include "CallableBody.js" %}
+    {%- endfilter %}
+}{{ member_sep }}
+{%- endif %}
+
+{%- if let Some(eq) = uniffi_trait_methods.eq_eq %}
+{%-    let callable = eq.callable %}
+// The Rust `Eq::eq` implementation.
+equals({% if !self_arg.is_empty() %}{{ self_arg }}, {% endif %}other) {
+    {%- filter indent(4) %}
+    {%- include "CallableBody.js" %}
+    {%- endfilter %}
+}{{ member_sep }}
+{%- endif %}
+
+{%- if let Some(hash) = uniffi_trait_methods.hash_hash %}
+{%-    let callable = hash.callable %}
+// The Rust `Hash::hash` implementation.
+hashCode({{ self_arg }}) {
+    {%- filter indent(4) %}
+    {%- include "CallableBody.js" %}
+    {%- endfilter %}
+}{{ member_sep }}
+{%- endif %}
+
+{%- if let Some(cmp) = uniffi_trait_methods.ord_cmp %}
+{%-    let callable = cmp.callable %}
+// The Rust `Ord::cmp` implementation.
+compareTo({% if !self_arg.is_empty() %}{{ self_arg }}, {% endif %}other) {
+    {%- filter indent(4) %}
+    {%- include "CallableBody.js" %}
+    {%- endfilter %}
+}{{ member_sep }}
+{%- endif %}
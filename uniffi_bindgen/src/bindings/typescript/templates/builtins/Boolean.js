@@ -0,0 +1,19 @@
+const {{ type_node.ffi_converter_name }} = uniffiPrimitiveConverter({
+    checkLower(value) {
+        if (typeof value !== "boolean") {
+            throw new TypeError(`bool value must be a boolean, not ${typeof value}`);
+        }
+    },
+    lift(value) {
+        return value !== 0;
+    },
+    lower(value) {
+        return value ? 1 : 0;
+    },
+    read(reader) {
+        return this.lift(reader.readInt8());
+    },
+    write(value, writer) {
+        writer.writeInt8(this.lower(value));
+    },
+});
@@ -0,0 +1,18 @@
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (!(value instanceof Uint8Array)) {
+            throw new TypeError("argument must be a Uint8Array");
+        }
+    },
+    read(reader) {
+        const size = reader.readInt32();
+        if (size < 0) {
+            throw new InternalError("Unexpected negative byte string length");
+        }
+        return reader.readBytes(size);
+    },
+    write(value, writer) {
+        writer.writeInt32(value.byteLength);
+        writer.writeBytes(value);
+    },
+});
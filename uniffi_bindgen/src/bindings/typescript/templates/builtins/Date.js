@@ -0,0 +1,16 @@
+// Dates are represented with a `Date` at midnight UTC, the time of day is ignored when lowering.
+const uniffiMillisPerDay = 24 * 60 * 60 * 1000;
+
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (!(value instanceof Date)) {
+            throw new TypeError("argument must be a Date");
+        }
+    },
+    read(reader) {
+        return new Date(reader.readInt32() * uniffiMillisPerDay);
+    },
+    write(value, writer) {
+        writer.writeInt32(Math.floor(value.getTime() / uniffiMillisPerDay));
+    },
+});
@@ -0,0 +1,21 @@
+// Durations are represented as a number of milliseconds, which may have a fractional part.
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (typeof value !== "number") {
+            throw new TypeError(`Duration value must be a number, not ${typeof value}`);
+        }
+        if (value < 0) {
+            throw new RangeError("Invalid duration, must be non-negative");
+        }
+    },
+    read(reader) {
+        const seconds = reader.readUInt64();
+        const nanoseconds = reader.readUInt32();
+        return Number(seconds) * 1000 + nanoseconds / 1e6;
+    },
+    write(value, writer) {
+        const seconds = Math.floor(value / 1000);
+        writer.writeUInt64(BigInt(seconds));
+        writer.writeUInt32(Math.min(Math.round((value - seconds * 1000) * 1e6), 999999999));
+    },
+});
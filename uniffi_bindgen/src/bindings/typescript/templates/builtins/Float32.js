@@ -0,0 +1,13 @@
+const {{ type_node.ffi_converter_name }} = uniffiPrimitiveConverter({
+    checkLower(value) {
+        if (typeof value !== "number") {
+            throw new TypeError(`f32 value must be a number, not ${typeof value}`);
+        }
+    },
+    read(reader) {
+        return reader.readFloat32();
+    },
+    write(value, writer) {
+        writer.writeFloat32(value);
+    },
+});
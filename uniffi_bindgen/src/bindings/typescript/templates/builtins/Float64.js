@@ -0,0 +1,13 @@
+const {{ type_node.ffi_converter_name }} = uniffiPrimitiveConverter({
+    checkLower(value) {
+        if (typeof value !== "number") {
+            throw new TypeError(`f64 value must be a number, not ${typeof value}`);
+        }
+    },
+    read(reader) {
+        return reader.readFloat64();
+    },
+    write(value, writer) {
+        writer.writeFloat64(value);
+    },
+});
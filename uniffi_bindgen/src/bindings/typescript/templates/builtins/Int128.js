@@ -0,0 +1,12 @@
+// 128-bit integers don't have a portable C representation, so they're passed as a RustBuffer
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        uniffiCheckBigInt(value, "i128", -(2n ** 127n), 2n ** 127n - 1n);
+    },
+    read(reader) {
+        return reader.readInt128();
+    },
+    write(value, writer) {
+        writer.writeInt128(value);
+    },
+});
@@ -0,0 +1,11 @@
+const {{ type_node.ffi_converter_name }} = uniffiPrimitiveConverter({
+    checkLower(value) {
+        uniffiCheckInteger(value, "i16", -32768, 32767);
+    },
+    read(reader) {
+        return reader.readInt16();
+    },
+    write(value, writer) {
+        writer.writeInt16(value);
+    },
+});
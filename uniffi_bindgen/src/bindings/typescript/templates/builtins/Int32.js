@@ -0,0 +1,11 @@
+const {{ type_node.ffi_converter_name }} = uniffiPrimitiveConverter({
+    checkLower(value) {
+        uniffiCheckInteger(value, "i32", -2147483648, 2147483647);
+    },
+    read(reader) {
+        return reader.readInt32();
+    },
+    write(value, writer) {
+        writer.writeInt32(value);
+    },
+});
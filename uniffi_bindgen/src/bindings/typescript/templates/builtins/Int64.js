@@ -0,0 +1,14 @@
+const {{ type_node.ffi_converter_name }} = uniffiPrimitiveConverter({
+    checkLower(value) {
+        uniffiCheckBigInt(value, "i64", -(2n ** 63n), 2n ** 63n - 1n);
+    },
+    lift(value) {
+        return BigInt(value);
+    },
+    read(reader) {
+        return reader.readInt64();
+    },
+    write(value, writer) {
+        writer.writeInt64(value);
+    },
+});
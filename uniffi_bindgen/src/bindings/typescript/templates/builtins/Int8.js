@@ -0,0 +1,11 @@
+const {{ type_node.ffi_converter_name }} = uniffiPrimitiveConverter({
+    checkLower(value) {
+        uniffiCheckInteger(value, "i8", -128, 127);
+    },
+    read(reader) {
+        return reader.readInt8();
+    },
+    write(value, writer) {
+        writer.writeInt8(value);
+    },
+});
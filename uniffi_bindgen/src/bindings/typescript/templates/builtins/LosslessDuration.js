@@ -0,0 +1,25 @@
+// Durations are represented with `{ seconds, nanoseconds }` objects, which are accurate to the
+// nanosecond.
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (typeof value !== "object" || value === null || typeof value.seconds !== "bigint") {
+            throw new TypeError("argument must be a UniffiDuration");
+        }
+        if (value.seconds < 0n) {
+            throw new RangeError("Invalid duration, must be non-negative");
+        }
+        if (!Number.isInteger(value.nanoseconds) || value.nanoseconds < 0 || value.nanoseconds >= 1e9) {
+            throw new RangeError("Duration nanoseconds must be between 0 and 999999999");
+        }
+        if (BigInt.asUintN(64, value.seconds) !== value.seconds) {
+            throw new RangeError("Duration overflow, exceeds max bounds supported by Uniffi");
+        }
+    },
+    read(reader) {
+        return { seconds: reader.readUInt64(), nanoseconds: reader.readUInt32() };
+    },
+    write(value, writer) {
+        writer.writeUInt64(value.seconds);
+        writer.writeUInt32(value.nanoseconds);
+    },
+});
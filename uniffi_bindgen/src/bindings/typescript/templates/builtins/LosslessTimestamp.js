@@ -0,0 +1,34 @@
+// Timestamps are represented with `{ seconds, nanoseconds }` objects, which are accurate to the
+// nanosecond.  Rust sends the seconds and nanoseconds from the epoch to the time, so times before
+// the epoch have both parts counted backwards.  We count the nanoseconds forwards, so we need to
+// convert between the two.
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (typeof value !== "object" || value === null || typeof value.seconds !== "bigint") {
+            throw new TypeError("argument must be a UniffiTimestamp");
+        }
+        if (!Number.isInteger(value.nanoseconds) || value.nanoseconds < 0 || value.nanoseconds >= 1e9) {
+            throw new RangeError("Timestamp nanoseconds must be between 0 and 999999999");
+        }
+        if (BigInt.asIntN(64, value.seconds) !== value.seconds) {
+            throw new RangeError("Timestamp overflow, exceeds max bounds supported by Uniffi");
+        }
+    },
+    read(reader) {
+        const seconds = reader.readInt64();
+        const nanoseconds = reader.readUInt32();
+        if (seconds < 0n && nanoseconds !== 0) {
+            return { seconds: seconds - 1n, nanoseconds: 1e9 - nanoseconds };
+        }
+        return { seconds, nanoseconds };
+    },
+    write(value, writer) {
+        if (value.seconds < 0n && value.nanoseconds !== 0) {
+            writer.writeInt64(value.seconds + 1n);
+            writer.writeUInt32(1e9 - value.nanoseconds);
+        } else {
+            writer.writeInt64(value.seconds);
+            writer.writeUInt32(value.nanoseconds);
+        }
+    },
+});
@@ -0,0 +1,28 @@
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (!(value instanceof Uint8Array)) {
+            throw new TypeError("argument must be a Uint8Array");
+        }
+    },
+    read(reader) {
+        const size = reader.readInt32();
+        if (size < 0) {
+            throw new InternalError("Unexpected negative byte string length");
+        }
+        return reader.readBytes(size);
+    },
+    write(value, writer) {
+        writer.writeInt32(value.byteLength);
+        writer.writeBytes(value);
+    },
+    // Top-level owned bytes are passed as the raw bytes, without a length prefix.
+    //
+    // koffi can't hand out a Uint8Array that borrows foreign memory, so unlike the other
+    // bindings this copies the data out of the RustBuffer.
+    lift(rbuf) {
+        return uniffiConsumeRustBuffer(rbuf);
+    },
+    lower(value) {
+        return uniffiRustBufferFromBytes(value);
+    },
+});
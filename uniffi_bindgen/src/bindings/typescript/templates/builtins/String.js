@@ -0,0 +1,29 @@
+const uniffiTextEncoder = new TextEncoder();
+const uniffiTextDecoder = new TextDecoder("utf-8");
+
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (typeof value !== "string") {
+            throw new TypeError(`argument must be a string, not ${typeof value}`);
+        }
+    },
+    read(reader) {
+        const size = reader.readInt32();
+        if (size < 0) {
+            throw new InternalError("Unexpected negative string length");
+        }
+        return uniffiTextDecoder.decode(reader.readBytes(size));
+    },
+    write(value, writer) {
+        const utf8Bytes = uniffiTextEncoder.encode(value);
+        writer.writeInt32(utf8Bytes.byteLength);
+        writer.writeBytes(utf8Bytes);
+    },
+    // Top-level strings are passed as the raw UTF-8 bytes, without a length prefix
+    lift(rbuf) {
+        return uniffiTextDecoder.decode(uniffiConsumeRustBuffer(rbuf));
+    },
+    lower(value) {
+        return uniffiRustBufferFromBytes(uniffiTextEncoder.encode(value));
+    },
+});
@@ -0,0 +1,22 @@
+// Timestamps are represented with `Date`, which is only accurate to the millisecond.
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (!(value instanceof Date)) {
+            throw new TypeError("argument must be a Date");
+        }
+    },
+    read(reader) {
+        const seconds = reader.readInt64();
+        const nanoseconds = reader.readUInt32();
+        // Negative timestamps are stored as the seconds/nanoseconds before the epoch
+        const sign = seconds < 0n ? -1 : 1;
+        return new Date(Number(seconds) * 1000 + sign * Math.floor(nanoseconds / 1e6));
+    },
+    write(value, writer) {
+        const millis = value.getTime();
+        const sign = millis < 0 ? -1 : 1;
+        const absMillis = Math.abs(millis);
+        writer.writeInt64(BigInt(sign * Math.floor(absMillis / 1000)));
+        writer.writeUInt32((absMillis % 1000) * 1e6);
+    },
+});
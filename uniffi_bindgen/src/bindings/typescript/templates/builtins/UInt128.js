@@ -0,0 +1,12 @@
+// 128-bit integers don't have a portable C representation, so they're passed as a RustBuffer
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        uniffiCheckBigInt(value, "u128", 0n, 2n ** 128n - 1n);
+    },
+    read(reader) {
+        return reader.readUInt128();
+    },
+    write(value, writer) {
+        writer.writeUInt128(value);
+    },
+});
@@ -0,0 +1,11 @@
+const {{ type_node.ffi_converter_name }} = uniffiPrimitiveConverter({
+    checkLower(value) {
+        uniffiCheckInteger(value, "u16", 0, 65535);
+    },
+    read(reader) {
+        return reader.readUInt16();
+    },
+    write(value, writer) {
+        writer.writeUInt16(value);
+    },
+});
@@ -0,0 +1,11 @@
+const {{ type_node.ffi_converter_name }} = uniffiPrimitiveConverter({
+    checkLower(value) {
+        uniffiCheckInteger(value, "u32", 0, 4294967295);
+    },
+    read(reader) {
+        return reader.readUInt32();
+    },
+    write(value, writer) {
+        writer.writeUInt32(value);
+    },
+});
@@ -0,0 +1,14 @@
+const {{ type_node.ffi_converter_name }} = uniffiPrimitiveConverter({
+    checkLower(value) {
+        uniffiCheckBigInt(value, "u64", 0n, 2n ** 64n - 1n);
+    },
+    lift(value) {
+        return BigInt(value);
+    },
+    read(reader) {
+        return reader.readUInt64();
+    },
+    write(value, writer) {
+        writer.writeUInt64(value);
+    },
+});
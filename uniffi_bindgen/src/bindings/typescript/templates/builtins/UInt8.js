@@ -0,0 +1,11 @@
+const {{ type_node.ffi_converter_name }} = uniffiPrimitiveConverter({
+    checkLower(value) {
+        uniffiCheckInteger(value, "u8", 0, 255);
+    },
+    read(reader) {
+        return reader.readUInt8();
+    },
+    write(value, writer) {
+        writer.writeUInt8(value);
+    },
+});
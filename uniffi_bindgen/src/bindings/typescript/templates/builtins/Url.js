@@ -0,0 +1,19 @@
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (!(value instanceof URL)) {
+            throw new TypeError("argument must be a URL");
+        }
+    },
+    read(reader) {
+        const size = reader.readInt32();
+        if (size < 0) {
+            throw new InternalError("Unexpected negative string length");
+        }
+        return new URL(new TextDecoder("utf-8").decode(reader.readBytes(size)));
+    },
+    write(value, writer) {
+        const utf8Bytes = new TextEncoder().encode(value.href);
+        writer.writeInt32(utf8Bytes.byteLength);
+        writer.writeBytes(utf8Bytes);
+    },
+});
@@ -0,0 +1,18 @@
+// UUIDs are represented as strings, like "67e55044-10b1-426f-9247-bb680e5fe0c8".
+const {{ type_node.ffi_converter_name }} = uniffiRustBufferConverter({
+    checkLower(value) {
+        if (typeof value !== "string" || !/^[0-9a-f]{8}-?([0-9a-f]{4}-?){3}[0-9a-f]{12}$/i.test(value)) {
+            throw new TypeError(`argument must be a UUID string, not ${value}`);
+        }
+    },
+    read(reader) {
+        const hex = Array.from(reader.readBytes(16), (b) => b.toString(16).padStart(2, "0")).join("");
+        return `${hex.slice(0, 8)}-${hex.slice(8, 12)}-${hex.slice(12, 16)}-${hex.slice(16, 20)}-${hex.slice(20)}`;
+    },
+    write(value, writer) {
+        const hex = value.replaceAll("-", "");
+        for (let i = 0; i < 32; i += 2) {
+            writer.writeUInt8(parseInt(hex.slice(i, i + 2), 16));
+        }
+    },
+});
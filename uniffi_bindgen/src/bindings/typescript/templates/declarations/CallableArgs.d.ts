@@ -0,0 +1,4 @@
+{%- for arg in callable.arguments -%}
+{{ arg.name }}{% if callable.ts_arg_optional(loop.index0) %}?{% endif %}: {{ arg.ty.type_name }}
+{%- if !loop.last %}, {% endif %}
+{%- endfor %}
@@ -0,0 +1,9 @@
+
+{{ cbi.docstring|docstring(0) -}}
+export interface {{ cbi.name }} {
+{%- for meth in cbi.methods %}
+{%-     let callable = meth.callable %}
+    {{ meth.docstring|docstring(4) -}}
+    {{ callable.name }}({% include "declarations/CallableArgs.d.ts" %}): {{ callable.ts_return_type() }};
+{%- endfor %}
+}
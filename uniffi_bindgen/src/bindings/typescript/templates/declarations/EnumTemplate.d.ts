@@ -0,0 +1,36 @@
+{%- let type_name = e.self_type.type_name %}
+
+{{ e.docstring|docstring(0) }}
+{%- if e.is_flat -%}
+export type {{ type_name }} ={% if e.variants.is_empty() %} never{% endif %}
+{%- for variant in e.variants %}
+    | "{{ variant.name }}"
+{%- endfor %};
+{%- else -%}
+export type {{ type_name }} ={% if e.variants.is_empty() %} never{% endif %}
+{%- for variant in e.variants %}
+    | { readonly tag: "{{ variant.name }}"{% for field in variant.fields %}; {{ field.name }}: {{ field.ty.type_name }}{% endfor %} }
+{%- endfor %};
+{%- endif %}
+
+export declare const {{ type_name }}: {
+{%- for variant in e.variants %}
+    {{ variant.docstring|docstring(4) }}
+{%-     if e.is_flat -%}
+    readonly {{ variant.name }}: "{{ variant.name }}";
+{%-     else if variant.fields.is_empty() -%}
+    readonly {{ variant.name }}: { readonly tag: "{{ variant.name }}" };
+{%-     else if variant.has_unnamed_fields() -%}
+    {{ variant.name }}({% for field in variant.fields %}{{ field.name }}{% if field.default.is_some() %}?{% endif %}: {{ field.ty.type_name }}{% if !loop.last %}, {% endif %}{% endfor %}): {{ type_name }};
+{%-     else -%}
+    {{ variant.name }}(fields: { {% for field in variant.fields %}{{ field.name }}{% if field.default.is_some() %}?{% endif %}: {{ field.ty.type_name }}; {% endfor %}}): {{ type_name }};
+{%-     endif %}
+{%- endfor %}
+    {%- let constructors = e.constructors %}
+    {%- let methods = e.methods %}
+    {%- let uniffi_trait_methods = e.uniffi_trait_methods %}
+    {%- let self_arg = format!("self: {type_name}") %}
+    {%- filter indent(4) %}
+    {%- include "declarations/Methods.d.ts" %}
+    {%- endfilter %}
+};
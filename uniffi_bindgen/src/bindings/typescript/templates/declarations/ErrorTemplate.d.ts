@@ -0,0 +1,30 @@
+{%- let type_name = e.self_type.type_name %}
+
+{{ e.docstring|docstring(0) -}}
+export declare class {{ type_name }} extends Error {
+    readonly tag: string;
+{%- for meth in e.methods %}
+{%-     let callable = meth.callable %}
+    {{ meth.docstring|docstring(4) -}}
+    {{ callable.name }}({% include "declarations/CallableArgs.d.ts" %}): {{ callable.ts_return_type() }};
+{%- endfor %}
+}
+
+export declare namespace {{ type_name }} {
+{%- for variant in e.variants %}
+    {{ variant.docstring|docstring(4) -}}
+    class {{ variant.name }} extends {{ type_name }} {
+        readonly tag: "{{ variant.name }}";
+{%-     if e.is_flat %}
+        constructor(message?: string);
+{%-     else if variant.has_unnamed_fields() %}
+        constructor({% for field in variant.fields %}{{ field.name }}: {{ field.ty.type_name }}{% if !loop.last %}, {% endif %}{% endfor %});
+{%-     else %}
+        constructor(fields{% if variant.fields.is_empty() %}?{% endif %}: { {% for field in variant.fields %}{{ field.name }}: {{ field.ty.type_name }}; {% endfor %}});
+{%-     endif %}
+{%-     for field in variant.fields %}
+        {{ field.name }}: {{ field.ty.type_name }};
+{%-     endfor %}
+    }
+{%- endfor %}
+}
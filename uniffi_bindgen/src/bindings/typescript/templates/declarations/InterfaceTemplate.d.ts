@@ -0,0 +1,44 @@
+{%- if let Some(trait_name) = int.trait_name %}
+
+{{ int.docstring|docstring(0) -}}
+export interface {{ trait_name }} {
+{%- for meth in int.methods %}
+{%-     let callable = meth.callable %}
+    {{ meth.docstring|docstring(4) -}}
+    {{ callable.name }}({% include "declarations/CallableArgs.d.ts" %}): {{ callable.ts_return_type() }};
+{%- endfor %}
+}
+{%- endif %}
+
+{{ int.docstring|docstring(0) -}}
+export declare class {{ int.name }}{% if int.self_type.is_used_as_error %} extends Error{% endif %}{% if !int.implements.is_empty() %} implements {{ int.implements|join(", ") }}{% endif %} {
+{%- for cons in int.constructors %}
+{%-     let callable = cons.callable %}
+    {{ cons.docstring|docstring(4) }}
+{%-     if callable.is_primary_constructor() -%}
+    constructor({% include "declarations/CallableArgs.d.ts" %});
+{%-     else -%}
+    static {{ callable.name }}({% include "declarations/CallableArgs.d.ts" %}): {{ callable.ts_return_type() }};
+{%-     endif %}
+{%- endfor %}
+{%- if !int.has_primary_constructor() %}
+    private constructor();
+{%- endif %}
+{%- for meth in int.methods %}
+{%-     let callable = meth.callable %}
+    {{ meth.docstring|docstring(4) -}}
+    {{ callable.name }}({% include "declarations/CallableArgs.d.ts" %}): {{ callable.ts_return_type() }};
+{%- endfor %}
+{%- for meth in int.static_methods %}
+{%-     let callable = meth.callable %}
+    {{ meth.docstring|docstring(4) -}}
+    static {{ callable.name }}({% include "declarations/CallableArgs.d.ts" %}): {{ callable.ts_return_type() }};
+{%- endfor %}
+    /** Release the Rust object now, rather than waiting for the garbage collector */
+    uniffiDestroy(): void;
+    {%- let uniffi_trait_methods = int.uniffi_trait_methods %}
+    {%- let self_arg = "" %}
+    {%- filter indent(4) %}
+    {%- include "declarations/UniffiTraitImpls.d.ts" %}
+    {%- endfilter %}
+}
@@ -0,0 +1,17 @@
+{#
+ # Constructors and methods for records and enums, which are declared as properties of the
+ # `declare const` object.
+ #
+ # expects vars `constructors`, `methods`, `uniffi_trait_methods` and `self_arg`.
+ #}
+{%- for cons in constructors %}
+{%-     let callable = cons.callable %}
+{{ cons.docstring|docstring(0) -}}
+{{ callable.name }}({% include "declarations/CallableArgs.d.ts" %}): {{ callable.ts_return_type() }};
+{%- endfor %}
+{%- for meth in methods %}
+{%-     let callable = meth.callable %}
+{{ meth.docstring|docstring(0) -}}
+{{ callable.name }}({{ self_arg }}{% if !callable.arguments.is_empty() %}, {% endif %}{% include "declarations/CallableArgs.d.ts" %}): {{ callable.ts_return_type() }};
+{%- endfor %}
+{%- include "declarations/UniffiTraitImpls.d.ts" %}
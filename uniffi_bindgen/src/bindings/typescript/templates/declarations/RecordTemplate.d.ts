@@ -0,0 +1,25 @@
+{%- let type_name = rec.self_type.type_name %}
+
+{{ rec.docstring|docstring(0) -}}
+export interface {{ type_name }} {
+    {%- for field in rec.fields %}
+    {{ field.docstring|docstring(4) -}}
+    {{ field.name }}: {{ field.ty.type_name }};
+    {%- endfor %}
+}
+
+export declare const {{ type_name }}: {
+    /** Create a `{{ type_name }}`, filling in the default values for any missing fields */
+    create(fields: {
+        {%- for field in rec.fields %}
+        {{ field.name }}{% if field.default.is_some() %}?{% endif %}: {{ field.ty.type_name }};
+        {%- endfor %}
+    }): {{ type_name }};
+    {%- let constructors = rec.constructors %}
+    {%- let methods = rec.methods %}
+    {%- let uniffi_trait_methods = rec.uniffi_trait_methods %}
+    {%- let self_arg = format!("self: {type_name}") %}
+    {%- filter indent(4) %}
+    {%- include "declarations/Methods.d.ts" %}
+    {%- endfilter %}
+};
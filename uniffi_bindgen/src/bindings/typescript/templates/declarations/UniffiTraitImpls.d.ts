@@ -0,0 +1,18 @@
+{#
+ # expects vars `uniffi_trait_methods` and `self_arg`.
+ #}
+{%- if uniffi_trait_methods.display_fmt.is_some() %}
+toString({{ self_arg }}): string;
+{%- endif %}
+{%- if uniffi_trait_methods.debug_fmt.is_some() %}
+toDebugString({{ self_arg }}): string;
+{%- endif %}
+{%- if let Some(eq) = uniffi_trait_methods.eq_eq %}
+equals({% if !self_arg.is_empty() %}{{ self_arg }}, {% endif %}other: {{ eq.callable.arguments[0].ty.type_name }}): boolean;
+{%- endif %}
+{%- if uniffi_trait_methods.hash_hash.is_some() %}
+hashCode({{ self_arg }}): bigint;
+{%- endif %}
+{%- if let Some(cmp) = uniffi_trait_methods.ord_cmp %}
+compareTo({% if !self_arg.is_empty() %}{{ self_arg }}, {% endif %}other: {{ cmp.callable.arguments[0].ty.type_name }}): number;
+{%- endif %}
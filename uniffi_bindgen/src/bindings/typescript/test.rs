@@ -0,0 +1,76 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use crate::bindings::typescript::run_pipeline;
+use crate::bindings::RunScriptOptions;
+use crate::cargo_metadata::CrateConfigSupplier;
+use crate::BindgenLoader;
+use crate::GlobalConfig;
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use std::env;
+use std::ffi::OsString;
+use std::process::Command;
+use uniffi_testing::UniFFITestHelper;
+
+/// Run TypeScript/Node.js tests for a UniFFI test fixture
+pub fn run_test(tmp_dir: &str, fixture_name: &str, script_file: &str) -> Result<()> {
+    run_script(
+        tmp_dir,
+        fixture_name,
+        script_file,
+        vec![],
+        &RunScriptOptions::default(),
+    )
+}
+
+/// Run a JS script with Node.js
+///
+/// This function will set things up so that the script can `require()` the UniFFI bindings for a crate
+pub fn run_script(
+    tmp_dir: &str,
+    crate_name: &str,
+    script_file: &str,
+    args: Vec<String>,
+    _options: &RunScriptOptions,
+) -> Result<()> {
+    let script_path = Utf8Path::new(script_file).canonicalize_utf8()?;
+    let test_helper = UniFFITestHelper::new(crate_name)?;
+    let out_dir = test_helper.create_out_dir(tmp_dir, &script_path)?;
+    let cdylib_path = test_helper.copy_cdylib_to_out_dir(&out_dir)?;
+
+    // Generate bindings
+    let mut paths = crate::BindgenPaths::default();
+    paths.add_layer(CrateConfigSupplier::from_cargo_metadata(
+        test_helper.cargo_metadata(),
+        crate::CargoMetadataOptions::default(),
+    ));
+    let config = GlobalConfig::default();
+    let loader = BindgenLoader::new(paths, config);
+    let metadata = loader.load_metadata(&cdylib_path)?;
+    let root = loader.load_pipeline_initial_root(&cdylib_path, metadata)?;
+    run_pipeline(root, &out_dir, None)?;
+
+    // Run the script.  `koffi` needs to be installed somewhere on `NODE_PATH`.
+    let node_path = env::var_os("NODE_PATH").unwrap_or_else(|| OsString::from(""));
+    let node_path = env::join_paths(
+        env::split_paths(&node_path).chain(vec![out_dir.to_path_buf().into_std_path_buf()]),
+    )?;
+
+    let mut command = Command::new("node");
+    command
+        .current_dir(out_dir)
+        .env("NODE_PATH", node_path)
+        .arg(script_path)
+        .args(args);
+    let status = command
+        .spawn()
+        .context("Failed to spawn `node` when running script")?
+        .wait()
+        .context("Failed to wait for `node` when running script")?;
+    if !status.success() {
+        anyhow::bail!("running `node` failed");
+    }
+    Ok(())
+}
@@ -94,6 +94,13 @@ impl BindgenPathsLayer for CrateConfigSupplier {
             .map(|t| t.name.clone())
     }
 
+    fn get_crate_version(&self, crate_name: &str) -> Option<String> {
+        let pkg = self
+            .get_package(crate_name)
+            .or_else(|| self.get_package_by_library_name(crate_name))?;
+        Some(pkg.version.to_string())
+    }
+
     fn get_source_crates(&self, source_crate: &str) -> Option<Vec<SourceCrate>> {
         // Packages to process
         let mut todo_stack = vec![source_crate];
@@ -0,0 +1,1154 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Check for API changes between two versions of a library
+//!
+//! This powers `uniffi-bindgen check-compat`.  We extract the metadata from both libraries, match up
+//! items by name and classify each difference:
+//!
+//!   - ABI-breaking changes mean that bindings generated for the old library can't be used with the
+//!     new library.  For example, any change to a function's metadata changes its checksum, which
+//!     the bindings check when they're loaded.  Adding a record field changes how records are
+//!     serialized.
+//!   - Source-breaking changes mean that foreign code written against the old bindings needs to be
+//!     updated once the bindings are regenerated.  These are tracked per-language, for example
+//!     renaming an argument only breaks languages where callers can pass arguments by name.
+//!   - Changes that are neither are additive.
+//!
+//! Record fields and enum variants are compared by position, since that's how they're serialized.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use anyhow::{bail, Result};
+use camino::Utf8Path;
+use uniffi_meta::{
+    CallbackInterfaceMetadata, ConstantMetadata, ConstructorMetadata, CustomTypeMetadata,
+    EnumMetadata, FieldMetadata, FnMetadata, FnParamMetadata, Metadata, MethodMetadata,
+    ObjectMetadata, RecordMetadata, StaticMethodMetadata, TraitMethodMetadata, Type,
+    UniffiTraitMetadata, VariantMetadata,
+};
+
+use crate::{bindings::TargetLanguage, macro_metadata};
+
+const ALL_LANGUAGES: &[TargetLanguage] = &[
+    TargetLanguage::Kotlin,
+    TargetLanguage::Python,
+    TargetLanguage::Ruby,
+    TargetLanguage::Swift,
+    TargetLanguage::TypeScript,
+    TargetLanguage::Cpp,
+];
+
+/// Languages where callers can pass arguments by name
+const NAMED_ARGUMENT_LANGUAGES: &[TargetLanguage] = &[
+    TargetLanguage::Kotlin,
+    TargetLanguage::Python,
+    TargetLanguage::Swift,
+];
+
+/// Languages where matching on an enum must be exhaustive
+const EXHAUSTIVE_MATCH_LANGUAGES: &[TargetLanguage] =
+    &[TargetLanguage::Kotlin, TargetLanguage::Swift];
+
+/// Languages where `try` is required to call a throwing function
+const CHECKED_ERROR_LANGUAGES: &[TargetLanguage] = &[TargetLanguage::Swift];
+
+/// Languages where builtin traits map to protocols/interfaces that user code can depend on
+const TRAIT_CONFORMANCE_LANGUAGES: &[TargetLanguage] = &[
+    TargetLanguage::Kotlin,
+    TargetLanguage::Swift,
+    TargetLanguage::Cpp,
+];
+
+/// A single difference between the old and new library
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// Item that changed, for example `fn my_crate::add`
+    pub item: String,
+    pub description: String,
+    /// Bindings generated for the old library won't work with the new library
+    pub abi_breaking: bool,
+    /// Languages where code written against the old bindings needs to be updated
+    pub source_breaking: Vec<TargetLanguage>,
+}
+
+impl Change {
+    pub fn is_additive(&self) -> bool {
+        !self.abi_breaking && self.source_breaking.is_empty()
+    }
+
+    /// Short description of the change's compatibility
+    pub fn classification(&self) -> String {
+        let languages = || {
+            if self.source_breaking.len() == ALL_LANGUAGES.len() {
+                "all languages".to_string()
+            } else {
+                self.source_breaking
+                    .iter()
+                    .map(|l| l.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        };
+        match (self.abi_breaking, self.source_breaking.is_empty()) {
+            (false, true) => "additive".to_string(),
+            (true, true) => "ABI-breaking".to_string(),
+            (false, false) => format!("source-breaking: {}", languages()),
+            (true, false) => format!("ABI-breaking, source-breaking: {}", languages()),
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {}: {}",
+            self.classification(),
+            self.item,
+            self.description
+        )
+    }
+}
+
+/// All changes between two libraries
+#[derive(Debug, Default)]
+pub struct CompatReport {
+    pub changes: Vec<Change>,
+}
+
+impl CompatReport {
+    pub fn abi_breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|c| c.abi_breaking)
+    }
+
+    pub fn source_breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(|c| !c.source_breaking.is_empty())
+    }
+
+    fn push(
+        &mut self,
+        item: &str,
+        description: impl Into<String>,
+        abi_breaking: bool,
+        source_breaking: &[TargetLanguage],
+    ) {
+        self.changes.push(Change {
+            item: item.to_string(),
+            description: description.into(),
+            abi_breaking,
+            source_breaking: source_breaking.to_vec(),
+        })
+    }
+}
+
+/// Compare the UniFFI interfaces of two libraries
+///
+/// If `crate_filter` is set, only items from that crate are compared.
+pub fn check_compat(
+    old_library: &Utf8Path,
+    new_library: &Utf8Path,
+    crate_filter: Option<&str>,
+) -> Result<CompatReport> {
+    let old = macro_metadata::extract_from_library(old_library)?;
+    let new = macro_metadata::extract_from_library(new_library)?;
+    compare_metadata(old, new, crate_filter)
+}
+
+/// Compare two sets of metadata items, see [check_compat]
+pub fn compare_metadata(
+    old: Vec<Metadata>,
+    new: Vec<Metadata>,
+    crate_filter: Option<&str>,
+) -> Result<CompatReport> {
+    // Libraries only store the name of their UDL file, not the items defined in it.  We can't
+    // read those from the crate sources either, since the UDL file for the old library usually
+    // isn't around anymore, so fail rather than skipping them.
+    for item in old.iter().chain(new.iter()) {
+        if let Metadata::UdlFile(meta) = item {
+            let crate_name = uniffi_meta::crate_name(&meta.module_path);
+            if crate_filter.is_some_and(|filter| filter != crate_name) {
+                continue;
+            }
+            bail!(
+                "`{crate_name}` defines items in `{}.udl`, which check-compat doesn't support.  \
+                 Only items defined with proc-macros can be checked, use `--crate` to check \
+                 other crates.",
+                meta.file_stub
+            );
+        }
+    }
+
+    let old = Interface::new(old, crate_filter);
+    let new = Interface::new(new, crate_filter);
+    let mut report = CompatReport::default();
+
+    compare_items(
+        &mut report,
+        "fn",
+        &old.functions,
+        &new.functions,
+        |_| false,
+        |report, item, old, new| compare_callable(report, item, old.into(), new.into()),
+    );
+    compare_items(
+        &mut report,
+        "object",
+        &old.objects,
+        &new.objects,
+        |_| false,
+        compare_objects,
+    );
+    compare_items(
+        &mut report,
+        "constructor",
+        &old.constructors,
+        &new.constructors,
+        |_| false,
+        |report, item, old, new| compare_callable(report, item, old.into(), new.into()),
+    );
+    compare_items(
+        &mut report,
+        "method",
+        &old.methods,
+        &new.methods,
+        |_| false,
+        |report, item, old, new| compare_callable(report, item, old.into(), new.into()),
+    );
+    compare_items(
+        &mut report,
+        "static method",
+        &old.static_methods,
+        &new.static_methods,
+        |_| false,
+        |report, item, old, new| compare_callable(report, item, old.into(), new.into()),
+    );
+    compare_items(
+        &mut report,
+        "callback interface",
+        &old.callback_interfaces,
+        &new.callback_interfaces,
+        |_| false,
+        |_, _, _: &CallbackInterfaceMetadata, _| (),
+    );
+    // Adding a method to a trait that foreign code implements breaks those implementations.
+    compare_items(
+        &mut report,
+        "trait method",
+        &old.trait_methods,
+        &new.trait_methods,
+        |meta| new.is_foreign_implementable(&meta.trait_name),
+        |report, item, old_meth, new_meth| {
+            if old_meth.index != new_meth.index
+                && new.is_foreign_implementable(&new_meth.trait_name)
+            {
+                // The index is the position in the vtable
+                report.push(
+                    item,
+                    format!(
+                        "moved from position {} to {}",
+                        old_meth.index, new_meth.index
+                    ),
+                    true,
+                    &[],
+                );
+            }
+            compare_callable(report, item, old_meth.into(), new_meth.into())
+        },
+    );
+    compare_items(
+        &mut report,
+        "record",
+        &old.records,
+        &new.records,
+        |_| false,
+        |report, item, old: &RecordMetadata, new| {
+            compare_fields(report, item, &old.fields, &new.fields)
+        },
+    );
+    compare_items(
+        &mut report,
+        "enum",
+        &old.enums,
+        &new.enums,
+        |_| false,
+        compare_enums,
+    );
+    compare_items(
+        &mut report,
+        "custom type",
+        &old.custom_types,
+        &new.custom_types,
+        |_| false,
+        |report, item, old: &CustomTypeMetadata, new| {
+            if old.builtin != new.builtin {
+                report.push(
+                    item,
+                    format!(
+                        "builtin type changed from {} to {}",
+                        type_name(&old.builtin),
+                        type_name(&new.builtin)
+                    ),
+                    true,
+                    ALL_LANGUAGES,
+                );
+            }
+        },
+    );
+    // Constants are rendered into the bindings, so changing them never breaks the ABI.
+    for (name, old_const) in &old.constants {
+        let item = format!("const {name}");
+        match new.constants.get(name) {
+            None => report.push(&item, "removed", false, ALL_LANGUAGES),
+            Some(new_const) if old_const.ty != new_const.ty => report.push(
+                &item,
+                format!(
+                    "type changed from {} to {}",
+                    type_name(&old_const.ty),
+                    type_name(&new_const.ty)
+                ),
+                false,
+                ALL_LANGUAGES,
+            ),
+            Some(new_const) if old_const.value != new_const.value => {
+                report.push(&item, "value changed", false, &[])
+            }
+            Some(_) => (),
+        }
+    }
+    for name in new.constants.keys() {
+        if !old.constants.contains_key(name) {
+            report.push(&format!("const {name}"), "added", false, &[]);
+        }
+    }
+    for name in old.uniffi_traits.difference(&new.uniffi_traits) {
+        report.push(
+            &format!("trait impl {name}"),
+            "removed",
+            true,
+            TRAIT_CONFORMANCE_LANGUAGES,
+        );
+    }
+    for name in new.uniffi_traits.difference(&old.uniffi_traits) {
+        report.push(&format!("trait impl {name}"), "added", false, &[]);
+    }
+    Ok(report)
+}
+
+/// Metadata items, keyed by their qualified names
+///
+/// Qualified names start with the crate name, for example `my_crate::MyObject::my_method`.
+#[derive(Default)]
+struct Interface {
+    functions: BTreeMap<String, FnMetadata>,
+    objects: BTreeMap<String, ObjectMetadata>,
+    constructors: BTreeMap<String, ConstructorMetadata>,
+    methods: BTreeMap<String, MethodMetadata>,
+    static_methods: BTreeMap<String, StaticMethodMetadata>,
+    callback_interfaces: BTreeMap<String, CallbackInterfaceMetadata>,
+    trait_methods: BTreeMap<String, TraitMethodMetadata>,
+    records: BTreeMap<String, RecordMetadata>,
+    enums: BTreeMap<String, EnumMetadata>,
+    custom_types: BTreeMap<String, CustomTypeMetadata>,
+    constants: BTreeMap<String, ConstantMetadata>,
+    // `[qualified object name]: [trait name]`
+    uniffi_traits: BTreeSet<String>,
+}
+
+impl Interface {
+    fn new(items: Vec<Metadata>, crate_filter: Option<&str>) -> Self {
+        let mut interface = Self::default();
+        for item in items {
+            let crate_name = uniffi_meta::crate_name(item.module_path()).to_string();
+            if crate_filter.is_some_and(|filter| filter != crate_name) {
+                continue;
+            }
+            match item {
+                Metadata::Func(meta) => {
+                    interface
+                        .functions
+                        .insert(format!("{crate_name}::{}", meta.name), meta);
+                }
+                Metadata::Object(meta) => {
+                    interface
+                        .objects
+                        .insert(format!("{crate_name}::{}", meta.name), meta);
+                }
+                Metadata::Constructor(meta) => {
+                    interface.constructors.insert(
+                        format!("{crate_name}::{}::{}", meta.self_name, meta.name),
+                        meta,
+                    );
+                }
+                Metadata::Method(meta) => {
+                    interface.methods.insert(
+                        format!("{crate_name}::{}::{}", meta.self_name, meta.name),
+                        meta,
+                    );
+                }
+                Metadata::StaticMethod(meta) => {
+                    interface.static_methods.insert(
+                        format!("{crate_name}::{}::{}", meta.self_name, meta.name),
+                        meta,
+                    );
+                }
+                Metadata::CallbackInterface(meta) => {
+                    interface
+                        .callback_interfaces
+                        .insert(format!("{crate_name}::{}", meta.name), meta);
+                }
+                Metadata::TraitMethod(meta) => {
+                    interface.trait_methods.insert(
+                        format!("{crate_name}::{}::{}", meta.trait_name, meta.name),
+                        meta,
+                    );
+                }
+                Metadata::Record(meta) => {
+                    interface
+                        .records
+                        .insert(format!("{crate_name}::{}", meta.name), meta);
+                }
+                Metadata::Enum(meta) => {
+                    interface
+                        .enums
+                        .insert(format!("{crate_name}::{}", meta.name), meta);
+                }
+                Metadata::CustomType(meta) => {
+                    interface
+                        .custom_types
+                        .insert(format!("{crate_name}::{}", meta.name), meta);
+                }
+                Metadata::Constant(meta) => {
+                    interface
+                        .constants
+                        .insert(format!("{crate_name}::{}", meta.name), meta);
+                }
+                Metadata::UniffiTrait(meta) => {
+                    let trait_name = match meta {
+                        UniffiTraitMetadata::Debug { .. } => "Debug",
+                        UniffiTraitMetadata::Display { .. } => "Display",
+                        UniffiTraitMetadata::Eq { .. } => "Eq",
+                        UniffiTraitMetadata::Hash { .. } => "Hash",
+                        UniffiTraitMetadata::Ord { .. } => "Ord",
+                    };
+                    interface
+                        .uniffi_traits
+                        .insert(format!("{crate_name}::{}: {trait_name}", meta.self_name()));
+                }
+                Metadata::Namespace(_)
+                | Metadata::UdlFile(_)
+                | Metadata::ObjectTraitImpl(_)
+                | Metadata::FromUnexpectedCallbackErrorImpl(_) => (),
+            }
+        }
+        interface
+    }
+
+    /// Can foreign code implement this trait?
+    fn is_foreign_implementable(&self, trait_name: &str) -> bool {
+        self.callback_interfaces
+            .values()
+            .any(|cbi| cbi.name == trait_name)
+            || self
+                .objects
+                .values()
+                .any(|obj| obj.name == trait_name && obj.imp.has_callback_interface())
+    }
+}
+
+/// Compare items that were matched up by name
+///
+/// Removed items are always breaking.  Added items are additive, unless `added_is_breaking`
+/// returns true.
+fn compare_items<T>(
+    report: &mut CompatReport,
+    kind: &str,
+    old: &BTreeMap<String, T>,
+    new: &BTreeMap<String, T>,
+    added_is_breaking: impl Fn(&T) -> bool,
+    compare: impl Fn(&mut CompatReport, &str, &T, &T),
+) {
+    for (name, old_item) in old {
+        let item = format!("{kind} {name}");
+        match new.get(name) {
+            Some(new_item) => compare(report, &item, old_item, new_item),
+            None => report.push(&item, "removed", true, ALL_LANGUAGES),
+        }
+    }
+    for (name, new_item) in new {
+        if !old.contains_key(name) {
+            let item = format!("{kind} {name}");
+            if added_is_breaking(new_item) {
+                report.push(&item, "added", true, ALL_LANGUAGES);
+            } else {
+                report.push(&item, "added", false, &[]);
+            }
+        }
+    }
+}
+
+/// The parts of functions, methods and constructors that we compare
+struct Callable<'a> {
+    is_async: bool,
+    inputs: &'a [FnParamMetadata],
+    return_type: Option<&'a Type>,
+    throws: Option<&'a Type>,
+    checksum: Option<u16>,
+}
+
+impl<'a> From<&'a FnMetadata> for Callable<'a> {
+    fn from(meta: &'a FnMetadata) -> Self {
+        Self {
+            is_async: meta.is_async,
+            inputs: &meta.inputs,
+            return_type: meta.return_type.as_ref(),
+            throws: meta.throws.as_ref(),
+            checksum: meta.checksum,
+        }
+    }
+}
+
+impl<'a> From<&'a ConstructorMetadata> for Callable<'a> {
+    fn from(meta: &'a ConstructorMetadata) -> Self {
+        Self {
+            is_async: meta.is_async,
+            inputs: &meta.inputs,
+            return_type: None,
+            throws: meta.throws.as_ref(),
+            checksum: meta.checksum,
+        }
+    }
+}
+
+impl<'a> From<&'a MethodMetadata> for Callable<'a> {
+    fn from(meta: &'a MethodMetadata) -> Self {
+        Self {
+            is_async: meta.is_async,
+            inputs: &meta.inputs,
+            return_type: meta.return_type.as_ref(),
+            throws: meta.throws.as_ref(),
+            checksum: meta.checksum,
+        }
+    }
+}
+
+impl<'a> From<&'a StaticMethodMetadata> for Callable<'a> {
+    fn from(meta: &'a StaticMethodMetadata) -> Self {
+        Self {
+            is_async: meta.is_async,
+            inputs: &meta.inputs,
+            return_type: meta.return_type.as_ref(),
+            throws: meta.throws.as_ref(),
+            checksum: meta.checksum,
+        }
+    }
+}
+
+impl<'a> From<&'a TraitMethodMetadata> for Callable<'a> {
+    fn from(meta: &'a TraitMethodMetadata) -> Self {
+        Self {
+            is_async: meta.is_async,
+            inputs: &meta.inputs,
+            return_type: meta.return_type.as_ref(),
+            throws: meta.throws.as_ref(),
+            checksum: meta.checksum,
+        }
+    }
+}
+
+fn compare_callable(report: &mut CompatReport, item: &str, old: Callable<'_>, new: Callable<'_>) {
+    let mut changes = CompatReport::default();
+    if old.is_async != new.is_async {
+        let description = if new.is_async {
+            "is now async"
+        } else {
+            "is no longer async"
+        };
+        changes.push(item, description, false, ALL_LANGUAGES);
+    }
+    if old.return_type != new.return_type {
+        changes.push(
+            item,
+            format!(
+                "return type changed from {} to {}",
+                optional_type_name(old.return_type),
+                optional_type_name(new.return_type),
+            ),
+            false,
+            ALL_LANGUAGES,
+        );
+    }
+    match (old.throws, new.throws) {
+        (None, Some(ty)) => changes.push(
+            item,
+            format!("now throws {}", type_name(ty)),
+            false,
+            CHECKED_ERROR_LANGUAGES,
+        ),
+        (Some(ty), None) => changes.push(
+            item,
+            format!("no longer throws {}", type_name(ty)),
+            false,
+            &[],
+        ),
+        (Some(old_ty), Some(new_ty)) if old_ty != new_ty => changes.push(
+            item,
+            format!(
+                "error type changed from {} to {}",
+                type_name(old_ty),
+                type_name(new_ty)
+            ),
+            false,
+            ALL_LANGUAGES,
+        ),
+        _ => (),
+    }
+    for i in 0..old.inputs.len().max(new.inputs.len()) {
+        match (old.inputs.get(i), new.inputs.get(i)) {
+            (Some(old_arg), Some(new_arg)) => {
+                if old_arg.name != new_arg.name {
+                    changes.push(
+                        item,
+                        format!("argument `{}` renamed to `{}`", old_arg.name, new_arg.name),
+                        false,
+                        NAMED_ARGUMENT_LANGUAGES,
+                    );
+                }
+                if old_arg.ty != new_arg.ty {
+                    changes.push(
+                        item,
+                        format!(
+                            "argument `{}` type changed from {} to {}",
+                            new_arg.name,
+                            type_name(&old_arg.ty),
+                            type_name(&new_arg.ty)
+                        ),
+                        false,
+                        ALL_LANGUAGES,
+                    );
+                }
+                if old_arg.default.is_some() && new_arg.default.is_none() {
+                    changes.push(
+                        item,
+                        format!("argument `{}` no longer has a default", new_arg.name),
+                        false,
+                        ALL_LANGUAGES,
+                    );
+                }
+            }
+            (None, Some(new_arg)) => {
+                let source_breaking = match new_arg.default {
+                    Some(_) => &[],
+                    None => ALL_LANGUAGES,
+                };
+                changes.push(
+                    item,
+                    format!("argument `{}` added", new_arg.name),
+                    false,
+                    source_breaking,
+                );
+            }
+            (Some(old_arg), None) => changes.push(
+                item,
+                format!("argument `{}` removed", old_arg.name),
+                false,
+                ALL_LANGUAGES,
+            ),
+            (None, None) => unreachable!(),
+        }
+    }
+
+    // Any change to the metadata changes the checksum, which the bindings check at load time.
+    // Fall back to comparing the changes we found when the checksum is missing.
+    let abi_breaking = match (old.checksum, new.checksum) {
+        (Some(old_checksum), Some(new_checksum)) => old_checksum != new_checksum,
+        _ => !changes.changes.is_empty(),
+    };
+    if abi_breaking && changes.changes.is_empty() {
+        changes.push(
+            item,
+            "checksum changed, for example because the docstring changed",
+            false,
+            &[],
+        );
+    }
+    for mut change in changes.changes {
+        change.abi_breaking = abi_breaking;
+        report.changes.push(change);
+    }
+}
+
+fn compare_objects(
+    report: &mut CompatReport,
+    item: &str,
+    old: &ObjectMetadata,
+    new: &ObjectMetadata,
+) {
+    if old.imp != new.imp {
+        report.push(
+            item,
+            format!("implementation changed from {:?} to {:?}", old.imp, new.imp),
+            true,
+            ALL_LANGUAGES,
+        );
+    }
+}
+
+fn compare_enums(report: &mut CompatReport, item: &str, old: &EnumMetadata, new: &EnumMetadata) {
+    if old.shape != new.shape {
+        report.push(item, "changed between enum and error", true, ALL_LANGUAGES);
+        return;
+    }
+    // Flat errors only send the variant over the FFI, not the fields.
+    let compare_variant_fields = !matches!(new.shape, uniffi_meta::EnumShape::Error { flat: true });
+    for i in 0..old.variants.len().max(new.variants.len()) {
+        match (old.variants.get(i), new.variants.get(i)) {
+            (Some(old_variant), Some(new_variant)) => compare_variants(
+                report,
+                item,
+                old_variant,
+                new_variant,
+                compare_variant_fields,
+            ),
+            (None, Some(new_variant)) => {
+                let source_breaking = if new.non_exhaustive {
+                    &[]
+                } else {
+                    EXHAUSTIVE_MATCH_LANGUAGES
+                };
+                report.push(
+                    item,
+                    format!("variant `{}` added", new_variant.name),
+                    true,
+                    source_breaking,
+                );
+            }
+            (Some(old_variant), None) => report.push(
+                item,
+                format!("variant `{}` removed", old_variant.name),
+                true,
+                ALL_LANGUAGES,
+            ),
+            (None, None) => unreachable!(),
+        }
+    }
+}
+
+fn compare_variants(
+    report: &mut CompatReport,
+    item: &str,
+    old: &VariantMetadata,
+    new: &VariantMetadata,
+    compare_fields_too: bool,
+) {
+    if old.name != new.name {
+        report.push(
+            item,
+            format!("variant `{}` renamed to `{}`", old.name, new.name),
+            false,
+            ALL_LANGUAGES,
+        );
+    }
+    if compare_fields_too {
+        compare_fields(
+            report,
+            &format!("{item}::{}", new.name),
+            &old.fields,
+            &new.fields,
+        );
+    }
+}
+
+fn compare_fields(
+    report: &mut CompatReport,
+    item: &str,
+    old: &[FieldMetadata],
+    new: &[FieldMetadata],
+) {
+    for i in 0..old.len().max(new.len()) {
+        match (old.get(i), new.get(i)) {
+            (Some(old_field), Some(new_field)) => {
+                if old_field.name != new_field.name {
+                    report.push(
+                        item,
+                        format!("field `{}` renamed to `{}`", old_field.name, new_field.name),
+                        false,
+                        ALL_LANGUAGES,
+                    );
+                }
+                if old_field.ty != new_field.ty {
+                    report.push(
+                        item,
+                        format!(
+                            "field `{}` type changed from {} to {}",
+                            new_field.name,
+                            type_name(&old_field.ty),
+                            type_name(&new_field.ty)
+                        ),
+                        true,
+                        ALL_LANGUAGES,
+                    );
+                }
+                if old_field.default.is_some() && new_field.default.is_none() {
+                    report.push(
+                        item,
+                        format!("field `{}` no longer has a default", new_field.name),
+                        false,
+                        ALL_LANGUAGES,
+                    );
+                }
+            }
+            (None, Some(new_field)) => {
+                let source_breaking = match new_field.default {
+                    Some(_) => &[],
+                    None => ALL_LANGUAGES,
+                };
+                report.push(
+                    item,
+                    format!("field `{}` added", new_field.name),
+                    true,
+                    source_breaking,
+                );
+            }
+            (Some(old_field), None) => report.push(
+                item,
+                format!("field `{}` removed", old_field.name),
+                true,
+                ALL_LANGUAGES,
+            ),
+            (None, None) => unreachable!(),
+        }
+    }
+}
+
+fn optional_type_name(ty: Option<&Type>) -> String {
+    match ty {
+        Some(ty) => type_name(ty),
+        None => "()".to_string(),
+    }
+}
+
+fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Optional { inner_type } => format!("Optional<{}>", type_name(inner_type)),
+        Type::Sequence { inner_type } => format!("Sequence<{}>", type_name(inner_type)),
+        Type::Array { inner_type, len } => format!("[{}; {len}]", type_name(inner_type)),
+        Type::Set { inner_type } => format!("Set<{}>", type_name(inner_type)),
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!("Map<{}, {}>", type_name(key_type), type_name(value_type)),
+        Type::Tuple { types } => format!(
+            "({})",
+            types.iter().map(type_name).collect::<Vec<_>>().join(", ")
+        ),
+        Type::Closure {
+            arguments,
+            return_type,
+        } => format!(
+            "Fn({}) -> {}",
+            arguments
+                .iter()
+                .map(type_name)
+                .collect::<Vec<_>>()
+                .join(", "),
+            optional_type_name(return_type.as_deref())
+        ),
+        _ => match ty.name() {
+            Some(name) => name.to_string(),
+            None => format!("{ty:?}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use uniffi_meta::{DefaultValueMetadata, EnumShape, LiteralMetadata};
+
+    fn func(name: &str, inputs: Vec<FnParamMetadata>, checksum: u16) -> Metadata {
+        Metadata::Func(FnMetadata {
+            module_path: "my_crate".into(),
+            name: name.into(),
+            orig_name: None,
+            is_async: false,
+            inputs,
+            return_type: None,
+            throws: None,
+            checksum: Some(checksum),
+            docstring: None,
+        })
+    }
+
+    fn record(fields: Vec<FieldMetadata>) -> Metadata {
+        Metadata::Record(RecordMetadata {
+            module_path: "my_crate".into(),
+            name: "Point".into(),
+            orig_name: None,
+            generic: None,
+            remote: false,
+            fields,
+            docstring: None,
+        })
+    }
+
+    fn field(name: &str, ty: Type, default: Option<DefaultValueMetadata>) -> FieldMetadata {
+        FieldMetadata {
+            name: name.into(),
+            orig_name: None,
+            ty,
+            default,
+            docstring: None,
+        }
+    }
+
+    fn enum_(variants: &[&str], non_exhaustive: bool) -> Metadata {
+        Metadata::Enum(EnumMetadata {
+            module_path: "my_crate".into(),
+            name: "Color".into(),
+            orig_name: None,
+            generic: None,
+            shape: EnumShape::Enum,
+            remote: false,
+            variants: variants
+                .iter()
+                .map(|name| VariantMetadata {
+                    name: name.to_string(),
+                    orig_name: None,
+                    discr: None,
+                    fields: vec![],
+                    docstring: None,
+                })
+                .collect(),
+            discr_type: None,
+            non_exhaustive,
+            docstring: None,
+        })
+    }
+
+    fn changes(old: Vec<Metadata>, new: Vec<Metadata>) -> Vec<String> {
+        compare_metadata(old, new, None)
+            .unwrap()
+            .changes
+            .iter()
+            .map(|c| c.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_no_changes() {
+        let items = vec![func("add", vec![], 1), record(vec![])];
+        assert_eq!(changes(items.clone(), items), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_added_and_removed() {
+        assert_eq!(
+            changes(vec![func("old", vec![], 1)], vec![func("new", vec![], 2)]),
+            vec![
+                "[ABI-breaking, source-breaking: all languages] fn my_crate::old: removed",
+                "[additive] fn my_crate::new: added",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_arguments() {
+        let default = Some(DefaultValueMetadata::Literal(LiteralMetadata::new_uint(0)));
+        let old = func("add", vec![FnParamMetadata::simple("a", Type::UInt32)], 1);
+        let renamed = func("add", vec![FnParamMetadata::simple("b", Type::UInt32)], 2);
+        let mut defaulted_arg = FnParamMetadata::simple("c", Type::UInt32);
+        defaulted_arg.default = default;
+        let added_defaulted = func(
+            "add",
+            vec![FnParamMetadata::simple("a", Type::UInt32), defaulted_arg],
+            3,
+        );
+        let retyped = func("add", vec![FnParamMetadata::simple("a", Type::UInt64)], 4);
+
+        assert_eq!(
+            changes(vec![old.clone()], vec![renamed]),
+            vec!["[ABI-breaking, source-breaking: Kotlin, Python, Swift] fn my_crate::add: argument `a` renamed to `b`"]
+        );
+        assert_eq!(
+            changes(vec![old.clone()], vec![added_defaulted]),
+            vec!["[ABI-breaking] fn my_crate::add: argument `c` added"]
+        );
+        assert_eq!(
+            changes(vec![old], vec![retyped]),
+            vec!["[ABI-breaking, source-breaking: all languages] fn my_crate::add: argument `a` type changed from UInt32 to UInt64"]
+        );
+    }
+
+    #[test]
+    fn test_checksum_only() {
+        assert_eq!(
+            changes(vec![func("add", vec![], 1)], vec![func("add", vec![], 2)]),
+            vec!["[ABI-breaking] fn my_crate::add: checksum changed, for example because the docstring changed"]
+        );
+    }
+
+    #[test]
+    fn test_record_fields() {
+        let default = Some(DefaultValueMetadata::Default);
+        let old = record(vec![field("x", Type::Int32, None)]);
+        assert_eq!(
+            changes(
+                vec![old.clone()],
+                vec![record(vec![
+                    field("x", Type::Int32, None),
+                    field("y", Type::Int32, default)
+                ])]
+            ),
+            vec!["[ABI-breaking] record my_crate::Point: field `y` added"]
+        );
+        assert_eq!(
+            changes(
+                vec![old.clone()],
+                vec![record(vec![
+                    field("x", Type::Int32, None),
+                    field("y", Type::Int32, None)
+                ])]
+            ),
+            vec!["[ABI-breaking, source-breaking: all languages] record my_crate::Point: field `y` added"]
+        );
+        assert_eq!(
+            changes(vec![old], vec![record(vec![field("z", Type::Int32, None)])]),
+            vec![
+                "[source-breaking: all languages] record my_crate::Point: field `x` renamed to `z`"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enum_variants() {
+        assert_eq!(
+            changes(
+                vec![enum_(&["Red"], false)],
+                vec![enum_(&["Red", "Green"], false)]
+            ),
+            vec!["[ABI-breaking, source-breaking: Kotlin, Swift] enum my_crate::Color: variant `Green` added"]
+        );
+        assert_eq!(
+            changes(
+                vec![enum_(&["Red"], true)],
+                vec![enum_(&["Red", "Green"], true)]
+            ),
+            vec!["[ABI-breaking] enum my_crate::Color: variant `Green` added"]
+        );
+    }
+
+    #[test]
+    fn test_foreign_trait_methods() {
+        let cbi = Metadata::CallbackInterface(CallbackInterfaceMetadata {
+            module_path: "my_crate".into(),
+            name: "Listener".into(),
+            docstring: None,
+        });
+        let method = Metadata::TraitMethod(TraitMethodMetadata {
+            module_path: "my_crate".into(),
+            trait_name: "Listener".into(),
+            index: 0,
+            name: "on_event".into(),
+            orig_name: None,
+            is_async: false,
+            inputs: vec![],
+            return_type: None,
+            throws: None,
+            takes_self_by_arc: false,
+            checksum: Some(1),
+            docstring: None,
+        });
+        assert_eq!(
+            changes(vec![cbi.clone()], vec![cbi, method]),
+            vec!["[ABI-breaking, source-breaking: all languages] trait method my_crate::Listener::on_event: added"]
+        );
+    }
+
+    #[test]
+    fn test_constants() {
+        let constant = |ty: Type, value: u64| {
+            Metadata::Constant(ConstantMetadata {
+                module_path: "my_crate".into(),
+                name: "MAX_RETRIES".into(),
+                ty,
+                value: LiteralMetadata::new_uint(value),
+                docstring: None,
+            })
+        };
+        assert_eq!(
+            changes(
+                vec![constant(Type::UInt32, 5)],
+                vec![constant(Type::UInt32, 6)]
+            ),
+            vec!["[additive] const my_crate::MAX_RETRIES: value changed"]
+        );
+        assert_eq!(
+            changes(
+                vec![constant(Type::UInt32, 5)],
+                vec![constant(Type::UInt64, 5)]
+            ),
+            vec!["[source-breaking: all languages] const my_crate::MAX_RETRIES: type changed from UInt32 to UInt64"]
+        );
+        assert_eq!(
+            changes(vec![constant(Type::UInt32, 5)], vec![]),
+            vec!["[source-breaking: all languages] const my_crate::MAX_RETRIES: removed"]
+        );
+    }
+
+    #[test]
+    fn test_crate_filter() {
+        let mut other = FnMetadata {
+            module_path: "other_crate".into(),
+            name: "add".into(),
+            orig_name: None,
+            is_async: false,
+            inputs: vec![],
+            return_type: None,
+            throws: None,
+            checksum: Some(1),
+            docstring: None,
+        };
+        let old = vec![Metadata::Func(other.clone())];
+        other.checksum = Some(2);
+        let new = vec![Metadata::Func(other)];
+        assert!(compare_metadata(old, new, Some("my_crate"))
+            .unwrap()
+            .changes
+            .is_empty());
+    }
+
+    fn udl_file(module_path: &str) -> Metadata {
+        Metadata::UdlFile(uniffi_meta::UdlFile {
+            module_path: module_path.into(),
+            namespace: module_path.into(),
+            file_stub: module_path.into(),
+        })
+    }
+
+    #[test]
+    fn test_udl() {
+        // Items defined in UDL aren't in the library, so they can't be compared
+        let old = vec![udl_file("my_crate")];
+        let new = vec![udl_file("my_crate"), func("add", vec![], 1)];
+        let err = compare_metadata(old, new, None).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("`my_crate` defines items in `my_crate.udl`"));
+
+        // Crates that don't match the filter are skipped
+        let old = vec![udl_file("other_crate"), func("add", vec![], 1)];
+        let new = vec![udl_file("other_crate"), func("add", vec![], 2)];
+        assert_eq!(
+            compare_metadata(old, new, Some("my_crate"))
+                .unwrap()
+                .changes
+                .len(),
+            1
+        );
+    }
+}
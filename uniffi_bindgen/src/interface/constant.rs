@@ -0,0 +1,51 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! # Constant definitions for a `ComponentInterface`.
+//!
+//! Constants have no FFI representation, the bindings render their value as a literal.
+
+use uniffi_meta::Checksum;
+
+use super::{AsType, Literal, Type};
+
+#[derive(Debug, Clone, Checksum)]
+pub struct Constant {
+    pub(super) name: String,
+    pub(super) ty: Type,
+    pub(super) value: Literal,
+    #[checksum_ignore]
+    pub(super) docstring: Option<String>,
+}
+
+impl Constant {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &Literal {
+        &self.value
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
+}
+
+impl AsType for Constant {
+    fn as_type(&self) -> Type {
+        self.ty.clone()
+    }
+}
+
+impl From<uniffi_meta::ConstantMetadata> for Constant {
+    fn from(meta: uniffi_meta::ConstantMetadata) -> Self {
+        Self {
+            name: meta.name,
+            ty: meta.ty,
+            value: meta.value,
+            docstring: meta.docstring,
+        }
+    }
+}
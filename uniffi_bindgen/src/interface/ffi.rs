@@ -112,6 +112,8 @@ impl From<&Type> for FfiType {
             Type::Int64 => FfiType::Int64,
             Type::Float32 => FfiType::Float32,
             Type::Float64 => FfiType::Float64,
+            // 128-bit integers don't have a portable C representation, so they're serialized.
+            Type::UInt128 | Type::Int128 => FfiType::RustBuffer(None),
             // Booleans lower into an Int8, to work around a bug in JNA.
             Type::Boolean => FfiType::Int8,
             // Strings are always owned rust values.
@@ -120,10 +122,14 @@ impl From<&Type> for FfiType {
             // Byte strings are also always owned rust values.
             // We might add a separate type for borrowed byte strings in future as well.
             Type::Bytes => FfiType::RustBuffer(None),
+            // Owned bytes are passed as the `RustBuffer` for the underlying `Vec<u8>`, like strings.
+            Type::OwnedBytes => FfiType::RustBuffer(None),
             // Objects are pointers to an Arc<>
             Type::Object { .. } => FfiType::Handle,
-            // Callback interfaces are passed as opaque integer handles.
-            Type::CallbackInterface { .. } => FfiType::UInt64,
+            // Streams are pointers to an Arc<> of the type-erased stream state
+            Type::Stream { .. } => FfiType::Handle,
+            // Callback interfaces and closures are passed as opaque integer handles.
+            Type::CallbackInterface { .. } | Type::Closure { .. } => FfiType::UInt64,
             // Other types are serialized into a bytebuffer and deserialized on the other side.
             Type::Enum { name, module_path } | Type::Record { name, module_path } => {
                 FfiType::RustBuffer(Some(ExternalFfiMetadata {
@@ -135,8 +141,13 @@ impl From<&Type> for FfiType {
             | Type::Sequence { .. }
             | Type::Map { .. }
             | Type::Set { .. }
+            | Type::Tuple { .. }
+            | Type::Array { .. }
             | Type::Timestamp
-            | Type::Duration => FfiType::RustBuffer(None),
+            | Type::Duration
+            | Type::Uuid
+            | Type::Url
+            | Type::Date => FfiType::RustBuffer(None),
             Type::Custom {
                 builtin,
                 name,
@@ -178,6 +178,36 @@ impl From<uniffi_meta::FnMetadata> for Function {
     }
 }
 
+// Static methods are represented as functions, since they don't take a `self` argument.
+impl From<uniffi_meta::StaticMethodMetadata> for Function {
+    fn from(meta: uniffi_meta::StaticMethodMetadata) -> Self {
+        let ffi_name = meta.ffi_symbol_name();
+        let checksum_fn_name = meta.checksum_symbol_name();
+        let is_async = meta.is_async;
+        let return_type = meta.return_type;
+        let arguments = meta.inputs.into_iter().map(Into::into).collect();
+
+        let ffi_func = FfiFunction {
+            name: ffi_name,
+            is_async,
+            ..FfiFunction::default()
+        };
+
+        Self {
+            name: meta.name,
+            module_path: meta.module_path,
+            is_async,
+            arguments,
+            return_type,
+            ffi_func,
+            docstring: meta.docstring.clone(),
+            throws: meta.throws,
+            checksum_fn_name,
+            checksum: meta.checksum,
+        }
+    }
+}
+
 /// Represents an argument to a function/constructor/method call.
 ///
 /// Each argument has a name and a type, along with some optional metadata.
@@ -58,6 +58,8 @@ use universe::{TypeIterator, TypeUniverse};
 
 mod callbacks;
 pub use callbacks::CallbackInterface;
+mod constant;
+pub use constant::Constant;
 mod custom_type;
 pub use custom_type::CustomType;
 mod enum_;
@@ -82,8 +84,8 @@ pub use ffi::{
 pub use uniffi_meta::Radix;
 use uniffi_meta::{
     ConstructorMetadata, DefaultValueMetadata, LiteralMetadata, MethodMetadata, NamespaceMetadata,
-    ObjectMetadata, ObjectTraitImplMetadata, TraitMethodMetadata, UniffiTraitMetadata,
-    UNIFFI_CONTRACT_VERSION,
+    ObjectMetadata, ObjectTraitImplMetadata, StaticMethodMetadata, TraitMethodMetadata,
+    UniffiTraitMetadata, UNIFFI_CONTRACT_VERSION,
 };
 pub type Literal = LiteralMetadata;
 pub type DefaultValue = DefaultValueMetadata;
@@ -100,6 +102,7 @@ pub struct ComponentInterface {
     enums: Vec<Enum>,
     records: Vec<Record>,
     functions: Vec<Function>,
+    constants: Vec<Constant>,
     objects: Vec<Object>,
     custom_types: Vec<CustomType>,
     pub(crate) callback_interfaces: Vec<CallbackInterface>,
@@ -243,6 +246,11 @@ impl ComponentInterface {
         self.functions.iter().find(|f| f.name == name)
     }
 
+    /// Get the definitions for every Constant in the interface.
+    pub fn constant_definitions(&self) -> &[Constant] {
+        &self.constants
+    }
+
     /// Returns `true` if we have any objects in this interface.
     pub fn has_object_definitions(&self) -> bool {
         !self.objects.is_empty()
@@ -640,6 +648,45 @@ impl ComponentInterface {
         }
     }
 
+    /// Builtin FFI function to get the next item from a stream.
+    ///
+    /// This returns a Rust future handle, which should be driven using the `rust_buffer`
+    /// `rust_future_*` functions.
+    pub fn ffi_stream_next(&self) -> FfiFunction {
+        FfiFunction {
+            name: format!("ffi_{}_stream_next", self.ffi_namespace()),
+            is_async: false,
+            arguments: vec![FfiArgument {
+                name: "handle".to_owned(),
+                type_: FfiType::Handle,
+            }],
+            return_type: Some(FfiType::Handle),
+            has_rust_call_status_arg: false,
+            is_object_free_function: false,
+        }
+    }
+
+    /// Builtin FFI function for freeing a stream
+    pub fn ffi_stream_free(&self) -> FfiFunction {
+        FfiFunction {
+            name: format!("ffi_{}_stream_free", self.ffi_namespace()),
+            is_async: false,
+            arguments: vec![FfiArgument {
+                name: "handle".to_owned(),
+                type_: FfiType::Handle,
+            }],
+            return_type: None,
+            has_rust_call_status_arg: false,
+            is_object_free_function: false,
+        }
+    }
+
+    /// Does this interface contain functions that return streams?
+    pub fn has_streams(&self) -> bool {
+        self.iter_local_types()
+            .any(|t| matches!(t, Type::Stream { .. }))
+    }
+
     fn rust_future_ffi_fn_name(&self, base_name: &str, return_ffi_type: Option<FfiType>) -> String {
         let namespace = self.ffi_namespace();
         let return_type_name = FfiType::return_type_name(return_ffi_type.as_ref());
@@ -647,8 +694,11 @@ impl ComponentInterface {
     }
 
     /// Does this interface contain async functions?
+    ///
+    /// Streams count as async functions, since they're driven using Rust futures.
     pub fn has_async_fns(&self) -> bool {
         self.iter_ffi_function_definitions().any(|f| f.is_async())
+            || self.has_streams()
             || self
                 .callback_interfaces
                 .iter()
@@ -874,6 +924,7 @@ impl ComponentInterface {
                     self.ffi_rust_future_complete(return_type),
                 ]
             })
+            .chain([self.ffi_stream_next(), self.ffi_stream_free()])
     }
 
     /// List all API checksums to check
@@ -894,6 +945,11 @@ impl ComponentInterface {
                 .into_iter()
                 .map(|c| (c.checksum_fn_name(), c.checksum()))
         });
+        let static_method_checksums = self.objects.iter().flat_map(|o| {
+            o.static_methods()
+                .into_iter()
+                .map(|m| (m.checksum_fn_name(), m.checksum()))
+        });
         let callback_method_checksums = self.callback_interfaces.iter().flat_map(|cbi| {
             cbi.methods().into_iter().filter_map(|m| {
                 if m.checksum_fn_name().is_empty() {
@@ -907,6 +963,7 @@ impl ComponentInterface {
         func_checksums
             .chain(method_checksums)
             .chain(constructor_checksums)
+            .chain(static_method_checksums)
             .chain(callback_method_checksums)
             .map(|(fn_name, checksum)| (fn_name.to_string(), checksum))
     }
@@ -998,6 +1055,18 @@ impl ComponentInterface {
         Ok(())
     }
 
+    /// Called by `APIBuilder` impls to add a newly-parsed constant definition to the `ComponentInterface`.
+    pub(super) fn add_constant_definition(&mut self, defn: Constant) -> Result<()> {
+        if self.constants.iter().any(|c| c.name == defn.name) {
+            bail!("duplicate constant definition: \"{}\"", defn.name);
+        }
+        self.types
+            .add_known_type(&defn.as_type())
+            .with_context(|| format!("adding constant {defn:?}"))?;
+        self.constants.push(defn);
+        Ok(())
+    }
+
     pub(super) fn add_constructor_meta(&mut self, meta: ConstructorMetadata) -> Result<()> {
         let self_name = &meta.self_name;
 
@@ -1032,6 +1101,23 @@ impl ComponentInterface {
         Ok(())
     }
 
+    pub(super) fn add_static_method_meta(&mut self, meta: StaticMethodMetadata) -> Result<()> {
+        let self_name = &meta.self_name;
+
+        let Some(object) = get_object(&mut self.objects, self_name) else {
+            bail!("add_static_method_meta: object {} not found", self_name);
+        };
+        let defn: Function = meta.into();
+        self.types
+            .add_known_types(defn.iter_types())
+            .with_context(|| format!("adding static method {defn:?}"))?;
+        defn.throws_name()
+            .map(|n| self.errors.insert(n.to_string()));
+        object.static_methods.push(defn);
+
+        Ok(())
+    }
+
     pub(super) fn add_method_meta(&mut self, meta: MethodMetadata) -> Result<()> {
         let self_name = &meta.self_name;
 
@@ -1436,6 +1522,7 @@ fn type_names_in_type(ty: &Type) -> Vec<String> {
         Type::Box { inner_type }
         | Type::Optional { inner_type }
         | Type::Sequence { inner_type }
+        | Type::Array { inner_type, .. }
         | Type::Set { inner_type } => type_names_in_type(inner_type),
         Type::Map {
             key_type,
@@ -1684,6 +1771,7 @@ new definition: Enum {
             remote: false,
             constructors: Default::default(),
             methods: Default::default(),
+            static_methods: Default::default(),
             uniffi_traits: Default::default(),
             ffi_func_clone: Default::default(),
             trait_impls: Default::default(),
@@ -62,7 +62,7 @@ use uniffi_meta::{Checksum, ObjectTraitImplMetadata};
 
 use super::callbacks;
 use super::ffi::{FfiArgument, FfiCallbackFunction, FfiFunction, FfiStruct, FfiType};
-use super::function::{Argument, Callable};
+use super::function::{Argument, Callable, Function};
 use super::{AsType, ObjectImpl, Type, TypeIterator};
 
 /// An "object" is an opaque type that is passed around by reference, can
@@ -88,6 +88,8 @@ pub struct Object {
     pub(super) remote: bool,
     pub(super) constructors: Vec<Constructor>,
     pub(super) methods: Vec<Method>,
+    // Associated functions that don't take `self`.  These are represented as plain functions.
+    pub(super) static_methods: Vec<Function>,
     // The "trait" methods - they have a (presumably "well known") name, and
     // a regular method (albeit with a generated name)
     // XXX - this should really be a HashSet, but not enough transient types support hash to make it worthwhile now.
@@ -172,6 +174,10 @@ impl Object {
         self.methods.iter().collect()
     }
 
+    pub fn static_methods(&self) -> Vec<&Function> {
+        self.static_methods.iter().collect()
+    }
+
     pub fn get_method(&self, name: &str) -> Method {
         let matches: Vec<_> = self.methods.iter().filter(|m| m.name() == name).collect();
         match matches.len() {
@@ -221,6 +227,7 @@ impl Object {
             .chain(&self.ffi_init_callback)
             .chain(self.constructors.iter().map(|f| &f.ffi_func))
             .chain(self.methods.iter().map(|f| &f.ffi_func))
+            .chain(self.static_methods.iter().map(|f| &f.ffi_func))
             .chain(
                 self.uniffi_traits
                     .iter()
@@ -263,6 +270,9 @@ impl Object {
         for meth in self.methods.iter_mut() {
             meth.derive_ffi_func()?;
         }
+        for meth in self.static_methods.iter_mut() {
+            meth.derive_ffi_func()?;
+        }
         for ut in self.uniffi_traits.iter_mut() {
             ut.derive_ffi_func()?;
         }
@@ -312,6 +322,7 @@ impl Object {
                 .map(Method::iter_types)
                 .chain(self.uniffi_traits.iter().map(UniffiTrait::iter_types))
                 .chain(self.constructors.iter().map(Constructor::iter_types))
+                .chain(self.static_methods.iter().map(Function::iter_types))
                 .flatten(),
         )
     }
@@ -338,6 +349,7 @@ impl From<uniffi_meta::ObjectMetadata> for Object {
             remote: meta.remote,
             constructors: Default::default(),
             methods: Default::default(),
+            static_methods: Default::default(),
             uniffi_traits: Default::default(),
             trait_impls: Default::default(),
             ffi_func_clone: FfiFunction {
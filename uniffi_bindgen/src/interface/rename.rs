@@ -167,6 +167,7 @@ mod tests {
             module_path: "test_crate".to_string(),
             name: "OldRecord".to_string(),
             orig_name: None,
+            generic: None,
             remote: false,
             fields: vec![FieldMetadata {
                 name: "field".to_string(),
@@ -190,6 +191,7 @@ mod tests {
             module_path: "test_crate".to_string(),
             name: "OldObject".to_string(),
             orig_name: None,
+            generic: None,
             imp: ObjectImpl::Struct,
             remote: false,
             docstring: None,
@@ -202,6 +204,7 @@ mod tests {
             module_path: "test_crate".to_string(),
             name: "OldEnum".to_string(),
             orig_name: None,
+            generic: None,
             shape: EnumShape::Enum,
             discr_type: None,
             non_exhaustive: false,
@@ -136,13 +136,19 @@ fn normalize_type_module_path(ty: &Type) -> Type {
         | Type::Int32
         | Type::UInt64
         | Type::Int64
+        | Type::UInt128
+        | Type::Int128
         | Type::Float32
         | Type::Float64
         | Type::Boolean
         | Type::String
         | Type::Bytes
+        | Type::OwnedBytes
         | Type::Timestamp
-        | Type::Duration => ty.clone(),
+        | Type::Duration
+        | Type::Uuid
+        | Type::Url
+        | Type::Date => ty.clone(),
         Type::Object {
             name,
             imp,
@@ -180,6 +186,25 @@ fn normalize_type_module_path(ty: &Type) -> Type {
         Type::Set { inner_type } => Type::Set {
             inner_type: Box::new(normalize_type_module_path(inner_type)),
         },
+        Type::Stream { inner_type } => Type::Stream {
+            inner_type: Box::new(normalize_type_module_path(inner_type)),
+        },
+        Type::Array { inner_type, len } => Type::Array {
+            inner_type: Box::new(normalize_type_module_path(inner_type)),
+            len: *len,
+        },
+        Type::Tuple { types } => Type::Tuple {
+            types: types.iter().map(normalize_type_module_path).collect(),
+        },
+        Type::Closure {
+            arguments,
+            return_type,
+        } => Type::Closure {
+            arguments: arguments.iter().map(normalize_type_module_path).collect(),
+            return_type: return_type
+                .as_deref()
+                .map(|ty| Box::new(normalize_type_module_path(ty))),
+        },
         Type::Custom {
             name,
             builtin,
@@ -103,10 +103,12 @@ use std::process::Command;
 
 mod bindgen_paths;
 pub mod bindings;
+pub mod compat;
 mod global_config;
 pub mod interface;
 mod loader;
 pub mod macro_metadata;
+pub mod metadata_json;
 pub mod pipeline;
 pub mod scaffolding;
 #[cfg(feature = "test-util")]
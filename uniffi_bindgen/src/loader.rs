@@ -280,6 +280,11 @@ impl BindgenLoader {
         Ok(root)
     }
 
+    /// Get the version of a crate, if we can find it
+    pub fn crate_version(&self, crate_name: &str) -> Option<String> {
+        self.bindgen_paths.get_crate_version(crate_name)
+    }
+
     /// Get the basename for a source file
     ///
     /// This will remove any file extension.
@@ -77,6 +77,12 @@ fn add_item_to_ci(iface: &mut ComponentInterface, item: Metadata) -> anyhow::Res
         Metadata::Method(meta) => {
             iface.add_method_meta(meta)?;
         }
+        Metadata::StaticMethod(meta) => {
+            iface.add_static_method_meta(meta)?;
+        }
+        Metadata::Constant(meta) => {
+            iface.add_constant_definition(meta.into())?;
+        }
         Metadata::Record(meta) => {
             let ty = Type::Record {
                 name: meta.name.clone(),
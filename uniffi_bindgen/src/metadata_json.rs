@@ -0,0 +1,317 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Machine-readable JSON dumps of the collected metadata
+//!
+//! This is what powers `uniffi-bindgen dump`.  External tools can consume the JSON without linking
+//! to `uniffi_bindgen`, either as plain JSON or by deserializing it into the `uniffi_meta` types
+//! with that crate's `serde` feature enabled.
+//!
+//! The top-level object has this shape:
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "uniffi_version": "0.32.0",
+//!   "crates": {
+//!     "my_crate": {
+//!       "namespace": { "crate_name": "my_crate", "name": "my_namespace" },
+//!       "namespace_docstring": null,
+//!       "items": [ { "Func": { ... } }, ... ]
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! Items use the default serde representation of [uniffi_meta::Metadata].
+//! [METADATA_JSON_SCHEMA_VERSION] is bumped whenever the serialized shape of the metadata changes,
+//! including new fields and new enum variants, since [MetadataDump::from_json] only accepts an exact
+//! version match.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use uniffi_meta::{MetadataGroup, MetadataGroupMap};
+
+/// Current version of the JSON schema
+///
+/// Version 1 is the first released version.  Besides the original items, it includes constants,
+/// static methods, object properties, generic instantiations and these [uniffi_meta::Type]
+/// variants: `Tuple`, `Closure`, `Array`, `Stream`, `OwnedBytes`, `UInt128`, `Int128`, `Uuid`,
+/// `Url` and `Date`.
+///
+/// `test_snapshot` fails whenever the JSON for any type changes, which is the signal to bump this.
+pub const METADATA_JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Top-level JSON object
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MetadataDump {
+    pub schema_version: u32,
+    /// Version of UniFFI that generated the dump
+    pub uniffi_version: String,
+    /// Metadata for each crate, keyed by crate name
+    pub crates: BTreeMap<String, MetadataGroup>,
+}
+
+impl MetadataDump {
+    pub fn new(metadata: MetadataGroupMap) -> Self {
+        Self {
+            schema_version: METADATA_JSON_SCHEMA_VERSION,
+            uniffi_version: env!("CARGO_PKG_VERSION").to_string(),
+            crates: metadata.into_iter().collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("serializing metadata")
+    }
+
+    /// Parse a dump, failing if it was generated with a different schema version
+    pub fn from_json(json: &str) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Version {
+            schema_version: u32,
+        }
+
+        let version: Version = serde_json::from_str(json).context("reading schema version")?;
+        if version.schema_version != METADATA_JSON_SCHEMA_VERSION {
+            bail!(
+                "Unsupported metadata schema version: {} (expected {METADATA_JSON_SCHEMA_VERSION})",
+                version.schema_version
+            );
+        }
+        serde_json::from_str(json).context("deserializing metadata")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::{BTreeSet, HashMap};
+    use uniffi_meta::{
+        FnMetadata, FnParamMetadata, Metadata, NamespaceMetadata, ObjectImpl, TraitKind, Type,
+    };
+
+    fn metadata() -> MetadataGroupMap {
+        let namespace = NamespaceMetadata {
+            crate_name: "my_crate".into(),
+            name: "my_namespace".into(),
+        };
+        let func = Metadata::Func(FnMetadata {
+            module_path: "my_crate".into(),
+            name: "add".into(),
+            orig_name: None,
+            is_async: false,
+            inputs: vec![FnParamMetadata::simple("a", Type::UInt32)],
+            return_type: Some(Type::Optional {
+                inner_type: Box::new(Type::UInt32),
+            }),
+            throws: None,
+            checksum: Some(1234),
+            docstring: Some("Add things".into()),
+        });
+        HashMap::from([(
+            "my_crate".to_string(),
+            MetadataGroup {
+                namespace,
+                namespace_docstring: None,
+                items: BTreeSet::from([func]),
+            },
+        )])
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let json = MetadataDump::new(metadata()).to_json().unwrap();
+        let dump = MetadataDump::from_json(&json).unwrap();
+        assert_eq!(dump.schema_version, METADATA_JSON_SCHEMA_VERSION);
+        let group = &dump.crates["my_crate"];
+        assert_eq!(group.namespace.name, "my_namespace");
+        assert_eq!(group.items, metadata()["my_crate"].items);
+    }
+
+    #[test]
+    fn test_json_shape() {
+        let json = MetadataDump::new(metadata()).to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schema_version"], 1);
+        let func = &value["crates"]["my_crate"]["items"][0]["Func"];
+        assert_eq!(func["name"], "add");
+        assert_eq!(func["inputs"][0]["ty"], "UInt32");
+        assert_eq!(
+            func["return_type"]["Optional"]["inner_type"],
+            serde_json::json!("UInt32")
+        );
+    }
+
+    /// One of each [Type] variant
+    ///
+    /// The match makes this fail to compile when a variant is added, so that it gets added to the
+    /// snapshot.
+    fn all_types() -> Vec<Type> {
+        let types = vec![
+            Type::UInt8,
+            Type::Int8,
+            Type::UInt16,
+            Type::Int16,
+            Type::UInt32,
+            Type::Int32,
+            Type::UInt64,
+            Type::Int64,
+            Type::UInt128,
+            Type::Int128,
+            Type::Float32,
+            Type::Float64,
+            Type::Boolean,
+            Type::String,
+            Type::Bytes,
+            Type::OwnedBytes,
+            Type::Timestamp,
+            Type::Duration,
+            Type::Uuid,
+            Type::Url,
+            Type::Date,
+            Type::Object {
+                module_path: "my_crate".into(),
+                name: "MyObject".into(),
+                imp: ObjectImpl::Trait(TraitKind::Both),
+            },
+            Type::Record {
+                module_path: "my_crate".into(),
+                name: "MyRecord".into(),
+            },
+            Type::Enum {
+                module_path: "my_crate".into(),
+                name: "MyEnum".into(),
+            },
+            Type::CallbackInterface {
+                module_path: "my_crate".into(),
+                name: "MyCallback".into(),
+            },
+            Type::Box {
+                inner_type: Box::new(Type::UInt8),
+            },
+            Type::Optional {
+                inner_type: Box::new(Type::UInt8),
+            },
+            Type::Sequence {
+                inner_type: Box::new(Type::UInt8),
+            },
+            Type::Array {
+                inner_type: Box::new(Type::UInt8),
+                len: 32,
+            },
+            Type::Map {
+                key_type: Box::new(Type::String),
+                value_type: Box::new(Type::UInt8),
+            },
+            Type::Set {
+                inner_type: Box::new(Type::UInt8),
+            },
+            Type::Stream {
+                inner_type: Box::new(Type::UInt8),
+            },
+            Type::Tuple {
+                types: vec![Type::String, Type::UInt8],
+            },
+            Type::Closure {
+                arguments: vec![Type::String],
+                return_type: Some(Box::new(Type::UInt8)),
+            },
+            Type::Custom {
+                module_path: "my_crate".into(),
+                name: "MyCustom".into(),
+                builtin: Box::new(Type::String),
+            },
+        ];
+        for ty in &types {
+            match ty {
+                Type::UInt8
+                | Type::Int8
+                | Type::UInt16
+                | Type::Int16
+                | Type::UInt32
+                | Type::Int32
+                | Type::UInt64
+                | Type::Int64
+                | Type::UInt128
+                | Type::Int128
+                | Type::Float32
+                | Type::Float64
+                | Type::Boolean
+                | Type::String
+                | Type::Bytes
+                | Type::OwnedBytes
+                | Type::Timestamp
+                | Type::Duration
+                | Type::Uuid
+                | Type::Url
+                | Type::Date
+                | Type::Object { .. }
+                | Type::Record { .. }
+                | Type::Enum { .. }
+                | Type::CallbackInterface { .. }
+                | Type::Box { .. }
+                | Type::Optional { .. }
+                | Type::Sequence { .. }
+                | Type::Array { .. }
+                | Type::Map { .. }
+                | Type::Set { .. }
+                | Type::Stream { .. }
+                | Type::Tuple { .. }
+                | Type::Closure { .. }
+                | Type::Custom { .. } => (),
+            }
+        }
+        types
+    }
+
+    // If this fails, the JSON for a type changed.  Bump [METADATA_JSON_SCHEMA_VERSION] if that
+    // version was released, then update `metadata_json_snapshot.json` with the new output.
+    #[test]
+    fn test_snapshot() {
+        let namespace = NamespaceMetadata {
+            crate_name: "my_crate".into(),
+            name: "my_namespace".into(),
+        };
+        let func = Metadata::Func(FnMetadata {
+            module_path: "my_crate".into(),
+            name: "all_types".into(),
+            orig_name: None,
+            is_async: false,
+            inputs: all_types()
+                .into_iter()
+                .enumerate()
+                .map(|(i, ty)| FnParamMetadata::simple(&format!("arg{i}"), ty))
+                .collect(),
+            return_type: None,
+            throws: None,
+            checksum: None,
+            docstring: None,
+        });
+        let mut dump = MetadataDump::new(HashMap::from([(
+            "my_crate".to_string(),
+            MetadataGroup {
+                namespace,
+                namespace_docstring: None,
+                items: BTreeSet::from([func]),
+            },
+        )]));
+        // Don't fail every time the UniFFI version changes
+        dump.uniffi_version = "0.0.0".into();
+        let json = dump.to_json().unwrap();
+        let expected = include_str!("metadata_json_snapshot.json");
+        assert!(
+            json.trim() == expected.trim(),
+            "Metadata JSON changed, bump METADATA_JSON_SCHEMA_VERSION if needed and update the snapshot:\n{json}"
+        );
+    }
+
+    #[test]
+    fn test_schema_version_mismatch() {
+        let json = r#"{"schema_version": 999, "uniffi_version": "0.0.0", "crates": {}}"#;
+        assert!(MetadataDump::from_json(json).is_err());
+    }
+}
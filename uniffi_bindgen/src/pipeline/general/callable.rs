@@ -4,7 +4,9 @@
 
 //! Extract common data from Function/Method/Constructor into Callable
 
-use super::ffi_async_data::{constructor_async_data, function_async_data, method_async_data};
+use super::ffi_async_data::{
+    constructor_async_data, function_async_data, method_async_data, static_method_async_data,
+};
 use super::*;
 
 /// Zero-copy `&mut [u8]` / `[ByMutRef]` is unsound across an async boundary:
@@ -105,6 +107,42 @@ pub fn method_callable_with_kind(
     Ok(callable)
 }
 
+pub fn static_method_callable(meth: &initial::StaticMethod, context: &Context) -> Result<Callable> {
+    let ffi_func = RustFfiFunctionName(uniffi_meta::static_method_symbol_name(
+        &context.crate_name()?,
+        &context.current_type_name()?,
+        &meth.name,
+    ));
+    let kind = CallableKind::StaticMethod {
+        self_type: context.self_type()?,
+    };
+    let arguments = map_method_args(&meth.inputs, &meth.name, context)?;
+    let name = rename::method(meth.name.clone(), context)?;
+
+    let callable = Callable {
+        id: meth.id,
+        name,
+        orig_name: meth.orig_name.clone(),
+        arguments,
+        return_type: ReturnType {
+            ty: meth.return_type.clone().map_node(context)?,
+        },
+        throws_type: ThrowsType {
+            ty: meth.throws.clone().map_node(context)?,
+        },
+        checksum: meth.checksum,
+        async_data: static_method_async_data(meth, context)?,
+        ffi_func,
+        kind,
+    };
+    reject_async_by_mut_ref(
+        callable.async_data.is_some(),
+        &callable.orig_name,
+        &callable.arguments,
+    )?;
+    Ok(callable)
+}
+
 pub fn constructor_callable(cons: &initial::Constructor, context: &Context) -> Result<Callable> {
     let self_type = context.self_type()?;
     let ffi_func = RustFfiFunctionName(uniffi_meta::constructor_symbol_name(
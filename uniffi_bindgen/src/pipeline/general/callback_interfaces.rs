@@ -31,6 +31,8 @@ pub fn vtable(methods: &[initial::Method], context: &Context) -> Result<VTable>
             .iter()
             .enumerate()
             .map(|(i, meth)| {
+                // The vtable struct field is named after the method, see `vtable_struct`
+                let ffi_field_name = meth.name.clone();
                 let meth = Method {
                     callable: callable::method_callable_with_kind(
                         meth,
@@ -42,9 +44,11 @@ pub fn vtable(methods: &[initial::Method], context: &Context) -> Result<VTable>
                         context,
                     )?,
                     docstring: meth.docstring.clone(),
+                    property: None,
                 };
                 Ok(VTableMethod {
                     callable: meth.callable,
+                    ffi_field_name,
                     ffi_type: FfiType::Function(FfiFunctionTypeName(format!(
                         "CallbackInterface{}{}Method{i}",
                         namespace_name.to_upper_camel_case(),
@@ -301,7 +305,7 @@ fn vtable_method_ffi_defs(
                     ty: FfiType::Handle(HandleKind::ForeignFutureCallbackData),
                 },
                 FfiArgument {
-                    name: "uniffi_out_return".into(),
+                    name: "uniffi_out_dropped_callback".into(),
                     ty: FfiType::MutReference(Box::new(FfiType::Struct(FfiStructName(
                         "ForeignFutureDroppedCallbackStruct".to_owned(),
                     )))),
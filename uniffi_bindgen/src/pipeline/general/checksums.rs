@@ -52,6 +52,19 @@ pub fn checksums(namespace: &initial::Namespace) -> Result<Vec<Checksum>> {
                 )),
             });
         });
+        int.visit(|meth: &initial::StaticMethod| {
+            let Some(checksum) = meth.checksum else {
+                return;
+            };
+            checksums.push(Checksum {
+                checksum,
+                fn_name: RustFfiFunctionName(uniffi_meta::static_method_checksum_symbol_name(
+                    &namespace.crate_name,
+                    &interface_name,
+                    &meth.name,
+                )),
+            });
+        });
     });
 
     // Skip callback interfaces, since those don't get their checksums set currently.
@@ -0,0 +1,102 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! FFI info for closures
+//!
+//! See `uniffi_core/src/ffi/closure.rs` for how these work on the Rust side.
+
+use super::*;
+
+pub fn closure_vtable(namespace: &initial::Namespace) -> Option<ClosureVTable> {
+    if !has_closures(namespace) {
+        return None;
+    }
+    let crate_name = &namespace.crate_name;
+    Some(ClosureVTable {
+        struct_type: FfiType::Struct(FfiStructName("ClosureVTable".to_owned())),
+        init_fn: RustFfiFunctionName(format!("ffi_{crate_name}_init_closure_vtable")),
+        free_fn_type: FfiFunctionTypeName("ClosureFree".to_owned()),
+        call_fn_type: FfiFunctionTypeName("ClosureCall".to_owned()),
+        ffi_closure_call: RustFfiFunctionName(format!("ffi_{crate_name}_closure_call")),
+        ffi_closure_free: RustFfiFunctionName(format!("ffi_{crate_name}_closure_free")),
+    })
+}
+
+pub fn ffi_definitions(namespace: &initial::Namespace) -> Result<Vec<FfiDefinition>> {
+    let Some(vtable) = closure_vtable(namespace) else {
+        return Ok(vec![]);
+    };
+    let handle_type = FfiType::Handle(HandleKind::Closure);
+
+    Ok(vec![
+        FfiFunctionType {
+            name: vtable.free_fn_type.clone(),
+            arguments: vec![FfiArgument::new("handle", handle_type.clone())],
+            return_type: FfiReturnType { ty: None },
+            has_rust_call_status_arg: false,
+        }
+        .into(),
+        FfiFunctionType {
+            name: vtable.call_fn_type.clone(),
+            arguments: vec![
+                FfiArgument::new("handle", handle_type.clone()),
+                FfiArgument::new("args", FfiType::RustBuffer(None)),
+                FfiArgument::new(
+                    "uniffi_out_return",
+                    FfiType::MutReference(Box::new(FfiType::RustBuffer(None))),
+                ),
+            ],
+            return_type: FfiReturnType { ty: None },
+            has_rust_call_status_arg: true,
+        }
+        .into(),
+        FfiStruct {
+            name: FfiStructName("ClosureVTable".to_owned()),
+            fields: vec![
+                FfiField::new("uniffi_free", FfiType::Function(vtable.free_fn_type)),
+                FfiField::new("call", FfiType::Function(vtable.call_fn_type)),
+            ],
+        }
+        .into(),
+        FfiFunction {
+            name: vtable.init_fn,
+            arguments: vec![FfiArgument::new(
+                "vtable",
+                FfiType::Reference(Box::new(vtable.struct_type)),
+            )],
+            return_type: FfiReturnType { ty: None },
+            async_data: None,
+            has_rust_call_status_arg: false,
+            kind: FfiFunctionKind::RustVtableInit,
+        }
+        .into(),
+        FfiFunction {
+            name: vtable.ffi_closure_call,
+            arguments: vec![
+                FfiArgument::new("handle", handle_type.clone()),
+                FfiArgument::new("args", FfiType::RustBuffer(None)),
+            ],
+            return_type: FfiReturnType {
+                ty: Some(FfiType::RustBuffer(None)),
+            },
+            async_data: None,
+            has_rust_call_status_arg: true,
+            kind: FfiFunctionKind::ClosureCall,
+        }
+        .into(),
+        FfiFunction {
+            name: vtable.ffi_closure_free,
+            arguments: vec![FfiArgument::new("handle", handle_type)],
+            return_type: FfiReturnType { ty: None },
+            async_data: None,
+            has_rust_call_status_arg: false,
+            kind: FfiFunctionKind::ClosureFree,
+        }
+        .into(),
+    ])
+}
+
+fn has_closures(namespace: &initial::Namespace) -> bool {
+    namespace.has_descendant(|ty: &Type| matches!(ty, Type::Closure { .. }))
+}
@@ -32,6 +32,21 @@ pub fn method_async_data(meth: &initial::Method, context: &Context) -> Result<Op
     async_data(context, ffi_return_type.as_ref()).map(Some)
 }
 
+pub fn static_method_async_data(
+    meth: &initial::StaticMethod,
+    context: &Context,
+) -> Result<Option<AsyncData>> {
+    if !meth.is_async {
+        return Ok(None);
+    }
+    let ffi_return_type = meth
+        .return_type
+        .as_ref()
+        .map(|ty| ffi_types::ffi_type(ty, context))
+        .transpose()?;
+    async_data(context, ffi_return_type.as_ref()).map(Some)
+}
+
 pub fn constructor_async_data(
     cons: &initial::Constructor,
     interface_name: &str,
@@ -46,6 +61,13 @@ pub fn constructor_async_data(
     async_data(context, Some(&ffi_return_type)).map(Some)
 }
 
+/// AsyncData for the futures returned by `stream_next`
+///
+/// These always resolve to a `RustBuffer` containing the next item as an `Option<T>`.
+pub fn stream_async_data(context: &Context) -> Result<AsyncData> {
+    async_data(context, Some(&FfiType::RustBuffer(None)))
+}
+
 fn async_data(context: &Context, ffi_return_type: Option<&FfiType>) -> Result<AsyncData> {
     let crate_name = context.crate_name()?;
     let return_type_name = match ffi_return_type {
@@ -87,7 +109,8 @@ fn async_data(context: &Context, ffi_return_type: Option<&FfiType>) -> Result<As
             return_type_name.to_upper_camel_case()
         )),
         ffi_foreign_future_complete: FfiFunctionTypeName(format!(
-            "ForeignFutureComplete{struct_crate_name}{return_type_name}"
+            "ForeignFutureComplete{struct_crate_name}{}",
+            return_type_name.to_upper_camel_case()
         )),
     })
 }
@@ -50,6 +50,20 @@ pub fn ffi_definitions(
             ffi_definitions.push(ffi_def);
             Ok(())
         })?;
+        int.try_visit(|meth: &initial::StaticMethod| {
+            let name =
+                uniffi_meta::static_method_symbol_name(&crate_name, &interface_name, &meth.name);
+            let async_data = ffi_async_data::static_method_async_data(meth, context)?;
+            let ffi_def = ffi_def(
+                name,
+                &meth.inputs,
+                meth.return_type.as_ref(),
+                async_data,
+                context,
+            )?;
+            ffi_definitions.push(ffi_def);
+            Ok(())
+        })?;
         Ok(())
     })?;
     namespace.try_visit(|record: &initial::Record| {
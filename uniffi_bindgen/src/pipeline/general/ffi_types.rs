@@ -17,6 +17,8 @@ pub fn ffi_type(ty: &Type, context: &Context) -> Result<FfiType> {
         Type::Int64 => FfiType::Int64,
         Type::Float32 => FfiType::Float32,
         Type::Float64 => FfiType::Float64,
+        // 128-bit integers don't have a portable C representation, so they're serialized.
+        Type::UInt128 | Type::Int128 => FfiType::RustBuffer(None),
         // Booleans lower into an Int8, to work around a bug in JNA.
         Type::Boolean => FfiType::Int8,
         // Strings are always owned rust values.
@@ -25,6 +27,8 @@ pub fn ffi_type(ty: &Type, context: &Context) -> Result<FfiType> {
         // Byte strings are also always owned rust values.
         // We might add a separate type for borrowed byte strings in future as well.
         Type::Bytes => FfiType::RustBuffer(None),
+        // Owned bytes are passed as the `RustBuffer` for the underlying `Vec<u8>`, like strings.
+        Type::OwnedBytes => FfiType::RustBuffer(None),
         // Objects are pointers to an Arc<>
         Type::Interface {
             namespace,
@@ -32,6 +36,10 @@ pub fn ffi_type(ty: &Type, context: &Context) -> Result<FfiType> {
             imp,
             ..
         } => interface_ffi_type(namespace, name, imp)?,
+        // Streams are pointers to an Arc<> of the type-erased stream state
+        Type::Stream { .. } => FfiType::Handle(HandleKind::Stream),
+        // Closures are passed as opaque integer handles, like callback interfaces.
+        Type::Closure { .. } => FfiType::Handle(HandleKind::Closure),
         // Callback interfaces are passed as opaque integer handles.
         Type::CallbackInterface {
             namespace, name, ..
@@ -47,8 +55,13 @@ pub fn ffi_type(ty: &Type, context: &Context) -> Result<FfiType> {
         | Type::Sequence { .. }
         | Type::Map { .. }
         | Type::Set { .. }
+        | Type::Tuple { .. }
+        | Type::Array { .. }
         | Type::Timestamp
-        | Type::Duration => FfiType::RustBuffer(None),
+        | Type::Duration
+        | Type::Uuid
+        | Type::Url
+        | Type::Date => FfiType::RustBuffer(None),
         Type::Custom {
             namespace, builtin, ..
         } => {
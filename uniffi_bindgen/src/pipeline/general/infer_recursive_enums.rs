@@ -127,7 +127,9 @@ fn type_names_in_type(ty: &Type) -> Vec<String> {
         Type::Box { inner_type }
         | Type::Optional { inner_type }
         | Type::Sequence { inner_type }
-        | Type::Set { inner_type } => type_names_in_type(inner_type),
+        | Type::Array { inner_type, .. }
+        | Type::Set { inner_type }
+        | Type::Stream { inner_type } => type_names_in_type(inner_type),
         Type::Map {
             key_type,
             value_type,
@@ -136,6 +138,15 @@ fn type_names_in_type(ty: &Type) -> Vec<String> {
             names.extend(type_names_in_type(value_type));
             names
         }
+        Type::Tuple { types } => types.iter().flat_map(type_names_in_type).collect(),
+        Type::Closure {
+            arguments,
+            return_type,
+        } => arguments
+            .iter()
+            .chain(return_type.as_deref())
+            .flat_map(type_names_in_type)
+            .collect(),
         _ => vec![],
     }
 }
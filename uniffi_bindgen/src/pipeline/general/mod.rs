@@ -7,6 +7,7 @@
 mod callable;
 mod callback_interfaces;
 mod checksums;
+mod closures;
 mod context;
 mod default;
 mod enums;
@@ -20,6 +20,7 @@ pub fn map_namespace(namespace: initial::Namespace, context: &Context) -> Result
                 objects::ffi_definitions(&namespace, context)?,
                 callback_interfaces::ffi_definitions(&namespace, context)?,
                 rust_future::ffi_definitions(&namespace)?,
+                closures::ffi_definitions(&namespace)?,
             ]
             .into_iter()
             .flatten(),
@@ -59,6 +60,7 @@ pub fn map_namespace(namespace: initial::Namespace, context: &Context) -> Result
         ffi_rustbuffer_free: rust_buffer::rustbuffer_free_fn_name(context)?,
         ffi_rustbuffer_reserve: rust_buffer::rustbuffer_reserve_fn_name(context)?,
         ffi_uniffi_contract_version: checksums::ffi_uniffi_contract_version(&namespace),
+        closure_vtable: closures::closure_vtable(&namespace),
         correct_contract_version: uniffi_meta::UNIFFI_CONTRACT_VERSION.to_string(),
         crate_name: namespace.crate_name,
         config_toml: namespace.config_toml,
@@ -74,6 +76,17 @@ pub fn map_namespace(namespace: initial::Namespace, context: &Context) -> Result
                 },
             )
             .collect::<Result<Vec<_>>>()?,
+        constants: namespace
+            .constants
+            .into_iter()
+            .filter_map(
+                |c| match exclude::should_exclude_toplevel_item(&c.name, context) {
+                    Err(e) => Some(Err(e)),
+                    Ok(true) => None,
+                    Ok(false) => Some(c.map_node(context)),
+                },
+            )
+            .collect::<Result<Vec<_>>>()?,
         name: namespace.name,
     })
 }
@@ -8,6 +8,8 @@ use_prev_node!(initial::ChecksumMode);
 use_prev_node!(initial::EnumShape);
 use_prev_node!(initial::ObjectImpl);
 use_prev_node!(initial::PassBy);
+use_prev_node!(initial::PropertyKind);
+use_prev_node!(initial::PropertyMetadata);
 use_prev_node!(initial::Radix);
 use_prev_node!(initial::TraitKind);
 use_prev_node!(initial::Type, types::map_type);
@@ -55,6 +57,7 @@ pub struct Namespace {
     pub config_toml: Option<String>,
     pub docstring: Option<String>,
     pub functions: Vec<Function>,
+    pub constants: Vec<Constant>,
     pub type_definitions: Vec<TypeDefinition>,
     pub ffi_definitions: IndexSet<FfiDefinition>,
     /// Checksum functions
@@ -65,6 +68,8 @@ pub struct Namespace {
     pub ffi_rustbuffer_free: RustFfiFunctionName,
     pub ffi_rustbuffer_reserve: RustFfiFunctionName,
     pub ffi_uniffi_contract_version: RustFfiFunctionName,
+    /// FFI info shared by all closure types, this is only set if the namespace uses closures.
+    pub closure_vtable: Option<ClosureVTable>,
     // Correct contract version value
     pub correct_contract_version: String,
 }
@@ -78,6 +83,18 @@ pub struct Function {
     pub docstring: Option<String>,
 }
 
+/// Constant value, rendered as a literal by the bindings
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(initial::Constant))]
+pub struct Constant {
+    #[map_node(rename::func(self.name, context)?)]
+    pub name: String,
+    pub module_path: String,
+    pub ty: TypeNode,
+    pub value: Literal,
+    pub docstring: Option<String>,
+}
+
 #[derive(Debug, Clone, Node, MapNode)]
 #[map_node(from(initial::TypeDefinition))]
 pub enum TypeDefinition {
@@ -100,6 +117,14 @@ pub enum TypeDefinition {
     Map(MapType),
     #[map_node(added)]
     Set(SetType),
+    #[map_node(added)]
+    Stream(StreamType),
+    #[map_node(added)]
+    Tuple(TupleType),
+    #[map_node(added)]
+    Array(ArrayType),
+    #[map_node(added)]
+    Closure(ClosureType),
     /// User types that are defined in another crate
     #[map_node(added)]
     External(ExternalType),
@@ -119,6 +144,18 @@ pub struct Method {
     #[map_node(callable::method_callable(&self, context)?)]
     pub callable: Callable,
     pub docstring: Option<String>,
+    /// Set for property getters/setters, these are also listed in `Interface::properties`
+    pub property: Option<PropertyMetadata>,
+}
+
+/// Property exposed using getter/setter methods
+#[derive(Debug, Clone, Node)]
+pub struct Property {
+    pub name: String,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+    pub getter: Method,
+    pub setter: Option<Method>,
 }
 
 /// Common data from Function/Method/Constructor
@@ -147,6 +184,8 @@ pub enum CallableKind {
     },
     /// Interface constructor
     Constructor { self_type: TypeNode, primary: bool },
+    /// Interface static method, these don't take a `self` argument
+    StaticMethod { self_type: TypeNode },
     /// Method inside a VTable or a CallbackInterface
     ///
     /// For trait interfaces this only applies to the Callables inside the `vtable.methods` field.
@@ -352,8 +391,12 @@ pub struct Interface {
     pub docstring: Option<String>,
     #[map_node(objects::constructors(self.constructors, context)?)]
     pub constructors: Vec<Constructor>,
+    #[map_node(objects::properties(&self.methods, &self.imp, context)?)]
+    pub properties: Vec<Property>,
     #[map_node(objects::interface_methods(self.methods, context)?)]
     pub methods: Vec<Method>,
+    #[map_node(objects::static_methods(self.static_methods, context)?)]
+    pub static_methods: Vec<Method>,
     pub trait_impls: Vec<ObjectTraitImpl>,
     pub imp: ObjectImpl,
 }
@@ -397,6 +440,8 @@ pub struct VTableMethod {
     pub callable: Callable,
     /// FfiType::Function type that corresponds to the method
     pub ffi_type: FfiType,
+    /// Name of the VTable struct field that stores the function pointer for this method
+    pub ffi_field_name: String,
 }
 
 #[derive(Debug, Clone, Node, MapNode)]
@@ -451,6 +496,57 @@ pub struct SetType {
     pub self_type: TypeNode,
 }
 
+#[derive(Debug, Clone, Node, MapNode)]
+pub struct StreamType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+    pub ffi_stream_next: RustFfiFunctionName,
+    pub ffi_stream_free: RustFfiFunctionName,
+    /// Used to drive the futures returned by `ffi_stream_next`
+    pub async_data: AsyncData,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+pub struct TupleType {
+    pub elements: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+pub struct ClosureType {
+    pub arguments: Vec<TypeNode>,
+    pub return_type: ReturnType,
+    pub self_type: TypeNode,
+}
+
+/// FFI info for closures
+///
+/// Closures are type-erased at the FFI level: arguments and return values are serialized into
+/// `RustBuffer`s.  This means all closure types in a namespace share a single vtable and a single
+/// set of FFI functions.
+#[derive(Debug, Clone, Node, MapNode)]
+pub struct ClosureVTable {
+    /// Vtable struct for closures implemented by the foreign side.
+    pub struct_type: FfiType,
+    /// Rust FFI function to initialize the vtable.
+    pub init_fn: RustFfiFunctionName,
+    pub free_fn_type: FfiFunctionTypeName,
+    pub call_fn_type: FfiFunctionTypeName,
+    /// Rust FFI function to call a closure implemented in Rust
+    pub ffi_closure_call: RustFfiFunctionName,
+    /// Rust FFI function to free a closure implemented in Rust
+    pub ffi_closure_free: RustFfiFunctionName,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    /// True for `[u8; N]`, which bindings present as their native bytes type
+    pub is_byte_array: bool,
+    pub self_type: TypeNode,
+}
+
 #[derive(Debug, Clone, Node, MapNode)]
 pub struct ExternalType {
     pub namespace: String,
@@ -518,6 +614,10 @@ pub enum FfiFunctionKind {
     RustFutureComplete,
     RustFutureCancel,
     RustFutureFree,
+    StreamNext,
+    StreamFree,
+    ClosureCall,
+    ClosureFree,
     RustBufferFromBytes,
     RustBufferFree,
     RustBufferAlloc,
@@ -608,6 +708,9 @@ pub enum FfiType {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Node, MapNode)]
 pub enum HandleKind {
     RustFuture,
+    Stream,
+    /// Closure implemented by either Rust or the foreign side
+    Closure,
     ForeignFuture,
     ForeignFutureCallbackData,
     // Interface, trait interface, or callback interface
@@ -656,6 +759,10 @@ impl TypeDefinition {
             Self::Sequence(seq) => &seq.self_type.ty,
             Self::Map(map) => &map.self_type.ty,
             Self::Set(set) => &set.self_type.ty,
+            Self::Stream(stream) => &stream.self_type.ty,
+            Self::Tuple(tuple) => &tuple.self_type.ty,
+            Self::Array(array) => &array.self_type.ty,
+            Self::Closure(closure) => &closure.self_type.ty,
             Self::External(ext) => &ext.self_type.ty,
         }
     }
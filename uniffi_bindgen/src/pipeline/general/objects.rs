@@ -113,6 +113,7 @@ pub fn methods_with_kind(
         mapped.push(Method {
             callable: callable::method_callable_with_kind(&meth, create_kind(&meth), context)?,
             docstring: meth.docstring,
+            property: meth.property,
         })
     }
     Ok(mapped)
@@ -134,6 +135,117 @@ pub fn interface_methods(methods: Vec<initial::Method>, context: &Context) -> Re
     })
 }
 
+/// Group getter/setter methods into properties
+pub fn properties(
+    methods: &[initial::Method],
+    imp: &ObjectImpl,
+    context: &Context,
+) -> Result<Vec<Property>> {
+    let mut properties: IndexMap<String, Property> = IndexMap::new();
+    let mut setters = vec![];
+    for meth in methods {
+        let Some(prop) = &meth.property else {
+            continue;
+        };
+        if exclude::should_exclude_method(&meth.name, context)? {
+            continue;
+        }
+        if !imp.has_struct() {
+            bail!("{}: trait interfaces can't have properties", meth.name);
+        }
+        if meth.is_async || meth.throws.is_some() {
+            bail!(
+                "{}: property getters and setters can't be async or throw",
+                meth.name
+            );
+        }
+        match prop.kind {
+            PropertyKind::Getter => {
+                if !meth.inputs.is_empty() || meth.return_type.is_none() {
+                    bail!(
+                        "{}: property getters must take no arguments and return a value",
+                        meth.name
+                    );
+                }
+                let getter: Method = meth.clone().map_node(context)?;
+                let ty = getter
+                    .callable
+                    .return_type
+                    .ty
+                    .clone()
+                    .ok_or_else(|| anyhow!("{}: getter without a return type", meth.name))?;
+                let property = Property {
+                    name: prop.name.clone(),
+                    ty,
+                    docstring: meth.docstring.clone(),
+                    getter,
+                    setter: None,
+                };
+                if properties.insert(prop.name.clone(), property).is_some() {
+                    bail!(
+                        "{}: duplicate getter for property `{}`",
+                        meth.name,
+                        prop.name
+                    );
+                }
+            }
+            PropertyKind::Setter => {
+                if meth.inputs.len() != 1 || meth.return_type.is_some() {
+                    bail!(
+                        "{}: property setters must take a single argument and return nothing",
+                        meth.name
+                    );
+                }
+                setters.push((prop, meth));
+            }
+        }
+    }
+    for (prop, meth) in setters {
+        let Some(property) = properties.get_mut(&prop.name) else {
+            bail!(
+                "{}: setter for `{}` has no matching getter",
+                meth.name,
+                prop.name
+            );
+        };
+        if property.setter.is_some() {
+            bail!(
+                "{}: duplicate setter for property `{}`",
+                meth.name,
+                prop.name
+            );
+        }
+        let setter: Method = meth.clone().map_node(context)?;
+        if setter.callable.arguments[0].ty.ty != property.ty.ty {
+            bail!(
+                "{}: setter type doesn't match the getter for property `{}`",
+                meth.name,
+                prop.name
+            );
+        }
+        property.setter = Some(setter);
+    }
+    Ok(properties.into_values().collect())
+}
+
+pub fn static_methods(
+    static_methods: Vec<initial::StaticMethod>,
+    context: &Context,
+) -> Result<Vec<Method>> {
+    let mut mapped = Vec::with_capacity(static_methods.len());
+    for meth in static_methods {
+        if exclude::should_exclude_method(&meth.name, context)? {
+            continue;
+        }
+        mapped.push(Method {
+            callable: callable::static_method_callable(&meth, context)?,
+            docstring: meth.docstring,
+            property: None,
+        })
+    }
+    Ok(mapped)
+}
+
 pub fn callback_interface_methods(
     methods: Vec<initial::Method>,
     context: &Context,
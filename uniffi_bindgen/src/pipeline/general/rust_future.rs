@@ -64,6 +64,10 @@ pub fn ffi_definitions(namespace: &initial::Namespace) -> Result<Vec<FfiDefiniti
         let free_name = format!("ffi_{crate_name}_rust_future_free_{return_type_name}");
         ffi_defs.push(ffi_rust_future_free(free_name));
     }
+
+    // Streams are driven using the `rust_buffer` future functions
+    ffi_defs.push(ffi_stream_next(format!("ffi_{crate_name}_stream_next")));
+    ffi_defs.push(ffi_stream_free(format!("ffi_{crate_name}_stream_free")));
     Ok(ffi_defs)
 }
 
@@ -139,8 +143,44 @@ fn ffi_rust_future_free(symbol_name: String) -> FfiDefinition {
     .into()
 }
 
+fn ffi_stream_next(symbol_name: String) -> FfiDefinition {
+    FfiFunction {
+        name: RustFfiFunctionName(symbol_name),
+        async_data: None,
+        arguments: vec![FfiArgument {
+            name: "handle".to_owned(),
+            ty: FfiType::Handle(HandleKind::Stream),
+        }],
+        return_type: FfiReturnType {
+            ty: Some(FfiType::Handle(HandleKind::RustFuture)),
+        },
+        has_rust_call_status_arg: false,
+        kind: FfiFunctionKind::StreamNext,
+    }
+    .into()
+}
+
+fn ffi_stream_free(symbol_name: String) -> FfiDefinition {
+    FfiFunction {
+        name: RustFfiFunctionName(symbol_name),
+        async_data: None,
+        arguments: vec![FfiArgument {
+            name: "handle".to_owned(),
+            ty: FfiType::Handle(HandleKind::Stream),
+        }],
+        return_type: FfiReturnType { ty: None },
+        has_rust_call_status_arg: false,
+        kind: FfiFunctionKind::StreamFree,
+    }
+    .into()
+}
+
+/// Does the namespace need the RustFuture FFI functions?
+///
+/// This is true if there are any async functions or any functions that return streams.
 fn has_async_fns(namespace: &initial::Namespace) -> bool {
     namespace.has_descendant(|func: &initial::Function| func.is_async)
         || namespace.has_descendant(|meth: &initial::Method| meth.is_async)
         || namespace.has_descendant(|cons: &initial::Constructor| cons.is_async)
+        || namespace.has_descendant(|ty: &Type| matches!(ty, Type::Stream { .. }))
 }
@@ -141,6 +141,10 @@ impl DependencyLogic for TypeDefinitionDependencyLogic {
             | TypeDefinition::Sequence(SequenceType { self_type, .. })
             | TypeDefinition::Map(MapType { self_type, .. })
             | TypeDefinition::Set(SetType { self_type, .. })
+            | TypeDefinition::Stream(StreamType { self_type, .. })
+            | TypeDefinition::Tuple(TupleType { self_type, .. })
+            | TypeDefinition::Array(ArrayType { self_type, .. })
+            | TypeDefinition::Closure(ClosureType { self_type, .. })
             | TypeDefinition::Record(Record { self_type, .. })
             | TypeDefinition::Enum(Enum { self_type, .. })
             | TypeDefinition::Interface(Interface { self_type, .. })
@@ -158,12 +162,27 @@ impl DependencyLogic for TypeDefinitionDependencyLogic {
             TypeDefinition::Box(BoxedType { inner, .. })
             | TypeDefinition::Optional(OptionalType { inner, .. })
             | TypeDefinition::Sequence(SequenceType { inner, .. })
-            | TypeDefinition::Set(SetType { inner, .. }) => {
+            | TypeDefinition::Array(ArrayType { inner, .. })
+            | TypeDefinition::Set(SetType { inner, .. })
+            | TypeDefinition::Stream(StreamType { inner, .. }) => {
                 vec![inner.canonical_name.clone()]
             }
             TypeDefinition::Map(MapType { key, value, .. }) => {
                 vec![key.canonical_name.clone(), value.canonical_name.clone()]
             }
+            TypeDefinition::Tuple(TupleType { elements, .. }) => elements
+                .iter()
+                .map(|ty| ty.canonical_name.clone())
+                .collect(),
+            TypeDefinition::Closure(ClosureType {
+                arguments,
+                return_type,
+                ..
+            }) => arguments
+                .iter()
+                .chain(return_type.ty.as_ref())
+                .map(|ty| ty.canonical_name.clone())
+                .collect(),
             TypeDefinition::Record(r) => r
                 .fields
                 .iter()
@@ -181,6 +200,7 @@ impl DependencyLogic for TypeDefinitionDependencyLogic {
                     .chain(
                         i.methods
                             .iter()
+                            .chain(&i.static_methods)
                             .map(|meth| &meth.callable)
                             .chain(i.vtable.iter().flat_map(|vtable| {
                                 vtable.methods.iter().map(|meth| &meth.callable)
@@ -33,13 +33,19 @@ pub fn type_definitions(
             | Type::Int32
             | Type::UInt64
             | Type::Int64
+            | Type::UInt128
+            | Type::Int128
             | Type::Float32
             | Type::Float64
             | Type::Boolean
             | Type::String
             | Type::Bytes
+            | Type::OwnedBytes
             | Type::Timestamp
-            | Type::Duration => {
+            | Type::Duration
+            | Type::Uuid
+            | Type::Url
+            | Type::Date => {
                 type_definitions.push(TypeDefinition::Simple(ty.map_node(context)?));
             }
             Type::Box { inner_type } => {
@@ -76,6 +82,42 @@ pub fn type_definitions(
                     self_type,
                 }));
             }
+            Type::Array { inner_type, len } => {
+                type_definitions.push(TypeDefinition::Array(ArrayType {
+                    is_byte_array: matches!(*inner_type, Type::UInt8),
+                    inner: (*inner_type).map_node(context)?,
+                    len,
+                    self_type,
+                }));
+            }
+            Type::Tuple { types } => {
+                type_definitions.push(TypeDefinition::Tuple(TupleType {
+                    elements: types.map_node(context)?,
+                    self_type,
+                }));
+            }
+            Type::Closure {
+                arguments,
+                return_type,
+            } => {
+                type_definitions.push(TypeDefinition::Closure(ClosureType {
+                    arguments: arguments.map_node(context)?,
+                    return_type: ReturnType {
+                        ty: return_type.map(|ty| (*ty).map_node(context)).transpose()?,
+                    },
+                    self_type,
+                }));
+            }
+            Type::Stream { inner_type } => {
+                let crate_name = context.crate_name()?;
+                type_definitions.push(TypeDefinition::Stream(StreamType {
+                    inner: (*inner_type).map_node(context)?,
+                    self_type,
+                    ffi_stream_next: RustFfiFunctionName(format!("ffi_{crate_name}_stream_next")),
+                    ffi_stream_free: RustFfiFunctionName(format!("ffi_{crate_name}_stream_free")),
+                    async_data: ffi_async_data::stream_async_data(context)?,
+                }));
+            }
             Type::Record {
                 namespace: namespace_name,
                 name,
@@ -26,13 +26,19 @@ pub fn canonical_name(ty: &Type) -> String {
         Type::Int32 => "Int32".to_string(),
         Type::UInt64 => "UInt64".to_string(),
         Type::Int64 => "Int64".to_string(),
+        Type::UInt128 => "UInt128".to_string(),
+        Type::Int128 => "Int128".to_string(),
         Type::Float32 => "Float32".to_string(),
         Type::Float64 => "Float64".to_string(),
         Type::Boolean => "Boolean".to_string(),
         Type::String => "String".to_string(),
         Type::Bytes => "Bytes".to_string(),
+        Type::OwnedBytes => "OwnedBytes".to_string(),
         Type::Timestamp => "Timestamp".to_string(),
         Type::Duration => "Duration".to_string(),
+        Type::Uuid => "Uuid".to_string(),
+        Type::Url => "Url".to_string(),
+        Type::Date => "Date".to_string(),
         Type::Interface { name, .. }
         | Type::CallbackInterface { name, .. }
         | Type::Record { name, .. }
@@ -59,6 +65,31 @@ pub fn canonical_name(ty: &Type) -> String {
         Type::Set { inner_type } => {
             format!("Set{}", canonical_name(inner_type))
         }
+        Type::Stream { inner_type } => {
+            format!("Stream{}", canonical_name(inner_type))
+        }
+        Type::Array { inner_type, len } => {
+            format!("Array{len}{}", canonical_name(inner_type))
+        }
+        // Include the element count, otherwise nested tuples could collide.  For example
+        // `((A, B), C, D)` and `((A, B, C), D)`.
+        Type::Tuple { types } => format!(
+            "Tuple{}{}",
+            types.len(),
+            types.iter().map(canonical_name).collect::<String>()
+        ),
+        Type::Closure {
+            arguments,
+            return_type,
+        } => format!(
+            "Closure{}{}{}",
+            arguments.len(),
+            arguments.iter().map(canonical_name).collect::<String>(),
+            match return_type {
+                Some(ty) => canonical_name(ty),
+                None => "Void".to_string(),
+            }
+        ),
     }
 }
 
@@ -110,6 +141,31 @@ pub fn map_type(mut ty: Type, context: &Context) -> Result<Type> {
         Type::Set { inner_type } => Type::Set {
             inner_type: Box::new(map_type(*inner_type, context)?),
         },
+        Type::Stream { inner_type } => Type::Stream {
+            inner_type: Box::new(map_type(*inner_type, context)?),
+        },
+        Type::Array { inner_type, len } => Type::Array {
+            inner_type: Box::new(map_type(*inner_type, context)?),
+            len,
+        },
+        Type::Tuple { types } => Type::Tuple {
+            types: types
+                .into_iter()
+                .map(|ty| map_type(ty, context))
+                .collect::<Result<_>>()?,
+        },
+        Type::Closure {
+            arguments,
+            return_type,
+        } => Type::Closure {
+            arguments: arguments
+                .into_iter()
+                .map(|ty| map_type(ty, context))
+                .collect::<Result<_>>()?,
+            return_type: return_type
+                .map(|ty| map_type(*ty, context).map(Box::new))
+                .transpose()?,
+        },
         // All other types can be returned unchanged
         _ => ty,
     })
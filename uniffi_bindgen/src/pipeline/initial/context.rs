@@ -25,6 +25,8 @@ pub struct Context {
     pub constructors:
         BTreeMap<(String, String), BTreeMap<String, uniffi_meta::ConstructorMetadata>>,
     pub methods: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::MethodMetadata>>,
+    pub static_methods:
+        BTreeMap<(String, String), BTreeMap<String, uniffi_meta::StaticMethodMetadata>>,
     pub trait_methods:
         BTreeMap<(String, String), BTreeMap<String, uniffi_meta::TraitMethodMetadata>>,
     pub uniffi_traits:
@@ -85,7 +87,7 @@ impl Context {
             methods.sort_by_key(|tm| tm.index);
             methods
                 .iter()
-                .map(|meth| (*meth).clone().map_node(self))
+                .map(|meth| uniffi_meta::MethodMetadata::from((*meth).clone()).map_node(self))
                 .collect()
         } else {
             Ok(vec![])
@@ -111,6 +113,35 @@ impl Context {
         }
     }
 
+    pub fn static_methods_for_type(
+        &self,
+        module_path: &str,
+        type_name: &str,
+    ) -> Result<Vec<StaticMethod>> {
+        let crate_name = module_path.split("::").next().unwrap();
+        let child_key = (crate_name.to_string(), type_name.to_string());
+
+        if let Some(static_methods) = self.static_methods.get(&child_key) {
+            static_methods
+                .values()
+                .cloned()
+                .map(|meth| meth.map_node(self))
+                .collect()
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /// Static methods are only supported on interfaces, bail if any were defined for this type
+    pub fn check_no_static_methods(&self, module_path: &str, type_name: &str) -> Result<()> {
+        let crate_name = module_path.split("::").next().unwrap();
+        let child_key = (crate_name.to_string(), type_name.to_string());
+        if self.static_methods.contains_key(&child_key) {
+            bail!("{type_name}: static methods are only supported on objects");
+        }
+        Ok(())
+    }
+
     pub fn uniffi_traits_for_type(
         &self,
         module_path: &str,
@@ -24,6 +24,7 @@ pub struct UniffiMetaConverter {
     module_docstrings: BTreeMap<String, String>,
     module_toml: BTreeMap<String, toml::Table>,
     functions: BTreeMap<String, BTreeMap<String, uniffi_meta::FnMetadata>>,
+    constants: BTreeMap<String, BTreeMap<String, uniffi_meta::ConstantMetadata>>,
     records: BTreeMap<String, BTreeMap<String, uniffi_meta::RecordMetadata>>,
     callback_interfaces: BTreeMap<String, BTreeMap<String, uniffi_meta::CallbackInterfaceMetadata>>,
     enums: BTreeMap<String, BTreeMap<String, uniffi_meta::EnumMetadata>>,
@@ -32,6 +33,7 @@ pub struct UniffiMetaConverter {
     // Child items, keyed by module name + parent name
     constructors: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::ConstructorMetadata>>,
     methods: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::MethodMetadata>>,
+    static_methods: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::StaticMethodMetadata>>,
     trait_methods: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::TraitMethodMetadata>>,
     uniffi_traits: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::UniffiTraitMetadata>>,
     trait_impls: BTreeMap<
@@ -87,6 +89,7 @@ impl UniffiMetaConverter {
                         config_toml: None,
                         name: namespace.name,
                         functions: vec![],
+                        constants: vec![],
                         type_definitions: vec![],
                     },
                 )?;
@@ -98,6 +101,12 @@ impl UniffiMetaConverter {
                     .or_default()
                     .insert_unique(func.name.clone(), func)?;
             }
+            uniffi_meta::Metadata::Constant(constant) => {
+                self.constants
+                    .entry(module_path_to_crate_name(&constant.module_path))
+                    .or_default()
+                    .insert_unique(constant.name.clone(), constant)?;
+            }
             uniffi_meta::Metadata::Record(rec) => {
                 self.update_orig_names(&rec.module_path, &rec.orig_name, &rec.name);
                 self.records
@@ -175,6 +184,15 @@ impl UniffiMetaConverter {
                     .or_default()
                     .insert_unique(meth.name.clone(), meth)?;
             }
+            uniffi_meta::Metadata::StaticMethod(meth) => {
+                self.static_methods
+                    .entry((
+                        module_path_to_crate_name(&meth.module_path),
+                        meth.self_name.to_string(),
+                    ))
+                    .or_default()
+                    .insert_unique(meth.name.clone(), meth)?;
+            }
             uniffi_meta::Metadata::TraitMethod(meth) => {
                 self.trait_methods
                     .entry((
@@ -264,6 +282,7 @@ impl UniffiMetaConverter {
             module_path_map: self.module_path_map.clone(),
             constructors: self.constructors,
             methods: self.methods,
+            static_methods: self.static_methods,
             trait_methods: self.trait_methods,
             uniffi_traits: self.uniffi_traits,
             trait_impls: self.trait_impls,
@@ -305,6 +324,12 @@ impl UniffiMetaConverter {
                 namespace.functions.push(func.map_node(&context)?);
             }
         }
+        for (module_path, list) in self.constants {
+            let namespace = get_namespace(&self.module_path_map, &mut root, &module_path)?;
+            for constant in list.into_values() {
+                namespace.constants.push(constant.map_node(&context)?);
+            }
+        }
         for (module_path, list) in self.records {
             let namespace = get_namespace(&self.module_path_map, &mut root, &module_path)?;
             for rec in list.into_values() {
@@ -9,6 +9,8 @@ use super::*;
 use_prev_node!(uniffi_meta::EnumShape);
 use_prev_node!(uniffi_meta::ObjectImpl);
 use_prev_node!(uniffi_meta::PassBy);
+use_prev_node!(uniffi_meta::PropertyKind);
+use_prev_node!(uniffi_meta::PropertyMetadata);
 use_prev_node!(uniffi_meta::Radix);
 use_prev_node!(uniffi_meta::TraitKind);
 
@@ -31,6 +33,7 @@ pub struct Namespace {
     pub config_toml: Option<String>,
     pub docstring: Option<String>,
     pub functions: Vec<Function>,
+    pub constants: Vec<Constant>,
     pub type_definitions: Vec<TypeDefinition>,
 }
 #[derive(Debug, Clone, Node, MapNode)]
@@ -70,6 +73,16 @@ pub struct Function {
     pub docstring: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Node, MapNode)]
+#[map_node(from(uniffi_meta::ConstantMetadata))]
+pub struct Constant {
+    pub name: String,
+    pub module_path: String,
+    pub ty: Type,
+    pub value: Literal,
+    pub docstring: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Node, MapNode)]
 pub enum TypeDefinition {
     Interface(Interface),
@@ -96,7 +109,6 @@ pub struct Constructor {
 
 #[derive(Debug, Clone, PartialEq, Eq, Node, MapNode)]
 #[map_node(from(uniffi_meta::MethodMetadata))]
-#[map_node(from(uniffi_meta::TraitMethodMetadata))]
 pub struct Method {
     #[map_node(self.orig_name.unwrap_or_else(|| self.name.clone()))]
     pub orig_name: String,
@@ -110,6 +122,23 @@ pub struct Method {
     pub throws: Option<Type>,
     pub checksum: Option<u16>,
     pub docstring: Option<String>,
+    pub property: Option<PropertyMetadata>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Node, MapNode)]
+#[map_node(from(uniffi_meta::StaticMethodMetadata))]
+pub struct StaticMethod {
+    #[map_node(self.orig_name.unwrap_or_else(|| self.name.clone()))]
+    pub orig_name: String,
+    #[map_node(context.new_callable_id())]
+    pub id: u64,
+    pub name: String,
+    pub is_async: bool,
+    pub inputs: Vec<Argument>,
+    pub return_type: Option<Type>,
+    pub throws: Option<Type>,
+    pub checksum: Option<u16>,
+    pub docstring: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Node, MapNode)]
@@ -243,6 +272,7 @@ pub struct Variant {
 pub struct Interface {
     pub constructors: Vec<Constructor>,
     pub methods: Vec<Method>,
+    pub static_methods: Vec<StaticMethod>,
     pub uniffi_traits: Vec<UniffiTrait>,
     pub trait_impls: Vec<ObjectTraitImpl>,
     pub module_path: String,
@@ -305,13 +335,19 @@ pub enum Type {
     Int32,
     UInt64,
     Int64,
+    UInt128,
+    Int128,
     Float32,
     Float64,
     Boolean,
     String,
     Bytes,
+    OwnedBytes,
     Timestamp,
     Duration,
+    Uuid,
+    Url,
+    Date,
     Box {
         inner_type: Box<Type>,
     },
@@ -328,6 +364,20 @@ pub enum Type {
     Set {
         inner_type: Box<Type>,
     },
+    Stream {
+        inner_type: Box<Type>,
+    },
+    Tuple {
+        types: Vec<Type>,
+    },
+    Array {
+        inner_type: Box<Type>,
+        len: u32,
+    },
+    Closure {
+        arguments: Vec<Type>,
+        return_type: Option<Box<Type>>,
+    },
     // User defined types in the API
     Interface {
         namespace: String,
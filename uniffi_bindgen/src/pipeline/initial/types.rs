@@ -14,13 +14,19 @@ pub fn map_type(ty: uniffi_meta::Type, context: &Context) -> Result<Type> {
         uniffi_meta::Type::Int32 => Type::Int32,
         uniffi_meta::Type::UInt64 => Type::UInt64,
         uniffi_meta::Type::Int64 => Type::Int64,
+        uniffi_meta::Type::UInt128 => Type::UInt128,
+        uniffi_meta::Type::Int128 => Type::Int128,
         uniffi_meta::Type::Float32 => Type::Float32,
         uniffi_meta::Type::Float64 => Type::Float64,
         uniffi_meta::Type::Boolean => Type::Boolean,
         uniffi_meta::Type::String => Type::String,
         uniffi_meta::Type::Bytes => Type::Bytes,
+        uniffi_meta::Type::OwnedBytes => Type::OwnedBytes,
         uniffi_meta::Type::Timestamp => Type::Timestamp,
         uniffi_meta::Type::Duration => Type::Duration,
+        uniffi_meta::Type::Uuid => Type::Uuid,
+        uniffi_meta::Type::Url => Type::Url,
+        uniffi_meta::Type::Date => Type::Date,
         uniffi_meta::Type::Box { inner_type } => Type::Box {
             inner_type: inner_type.map_node(context)?,
         },
@@ -40,6 +46,23 @@ pub fn map_type(ty: uniffi_meta::Type, context: &Context) -> Result<Type> {
         uniffi_meta::Type::Set { inner_type } => Type::Set {
             inner_type: inner_type.map_node(context)?,
         },
+        uniffi_meta::Type::Stream { inner_type } => Type::Stream {
+            inner_type: inner_type.map_node(context)?,
+        },
+        uniffi_meta::Type::Tuple { types } => Type::Tuple {
+            types: types.map_node(context)?,
+        },
+        uniffi_meta::Type::Array { inner_type, len } => Type::Array {
+            inner_type: inner_type.map_node(context)?,
+            len,
+        },
+        uniffi_meta::Type::Closure {
+            arguments,
+            return_type,
+        } => Type::Closure {
+            arguments: arguments.map_node(context)?,
+            return_type: return_type.map_node(context)?,
+        },
         uniffi_meta::Type::Object {
             module_path,
             name,
@@ -79,6 +102,7 @@ pub fn map_type(ty: uniffi_meta::Type, context: &Context) -> Result<Type> {
 }
 
 pub fn map_record(input: uniffi_meta::RecordMetadata, context: &Context) -> Result<Record> {
+    context.check_no_static_methods(&input.module_path, &input.name)?;
     Ok(Record {
         constructors: context.constructors_for_type(&input.module_path, &input.name)?,
         methods: context.methods_for_type(&input.module_path, &input.name)?,
@@ -92,6 +116,7 @@ pub fn map_record(input: uniffi_meta::RecordMetadata, context: &Context) -> Resu
 }
 
 pub fn map_enum(input: uniffi_meta::EnumMetadata, context: &Context) -> Result<Enum> {
+    context.check_no_static_methods(&input.module_path, &input.name)?;
     Ok(Enum {
         constructors: context.constructors_for_type(&input.module_path, &input.name)?,
         methods: context.methods_for_type(&input.module_path, &input.name)?,
@@ -110,6 +135,7 @@ pub fn map_interface(input: uniffi_meta::ObjectMetadata, context: &Context) -> R
     Ok(Interface {
         constructors: context.constructors_for_type(&input.module_path, &input.name)?,
         methods: context.methods_for_type(&input.module_path, &input.name)?,
+        static_methods: context.static_methods_for_type(&input.module_path, &input.name)?,
         uniffi_traits: context.uniffi_traits_for_type(&input.module_path, &input.name)?,
         trait_impls: context.trait_impls_for_type(&input.module_path, &input.name)?,
         orig_name: input.orig_name.unwrap_or_else(|| input.name.clone()),
@@ -58,13 +58,19 @@ mod filters {
             Type::UInt32 => "u32".into(),
             Type::Int64 => "i64".into(),
             Type::UInt64 => "u64".into(),
+            Type::Int128 => "i128".into(),
+            Type::UInt128 => "u128".into(),
             Type::Float32 => "f32".into(),
             Type::Float64 => "f64".into(),
             Type::Boolean => "bool".into(),
             Type::String => "::std::string::String".into(),
             Type::Bytes => "::std::vec::Vec<u8>".into(),
+            Type::OwnedBytes => "::uniffi::OwnedBytes".into(),
             Type::Timestamp => "::std::time::SystemTime".into(),
             Type::Duration => "::std::time::Duration".into(),
+            Type::Uuid => "::uniffi::deps::uuid::Uuid".into(),
+            Type::Url => "::uniffi::deps::url::Url".into(),
+            Type::Date => "::uniffi::deps::chrono::NaiveDate".into(),
             Type::Enum { name, .. } | Type::Record { name, .. } => format!("r#{name}"),
             Type::Object { name, imp, .. } => {
                 format!("::std::sync::Arc<{}>", imp.rust_name_for(name))
@@ -93,6 +99,35 @@ mod filters {
                     type_rs_inner(inner_type)?
                 )
             }
+            Type::Stream { inner_type } => {
+                format!("::uniffi::Stream<{}>", type_rs_inner(inner_type)?)
+            }
+            Type::Array { inner_type, len } => {
+                format!("[{}; {len}]", type_rs_inner(inner_type)?)
+            }
+            Type::Tuple { types } => format!(
+                "({},)",
+                types
+                    .iter()
+                    .map(type_rs_inner)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+            Type::Closure {
+                arguments,
+                return_type,
+            } => format!(
+                "::std::boxed::Box<dyn Fn({}) -> {} + Send + Sync>",
+                arguments
+                    .iter()
+                    .map(type_rs_inner)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", "),
+                match return_type {
+                    Some(ty) => type_rs_inner(ty)?,
+                    None => "()".to_string(),
+                }
+            ),
             Type::Custom { name, .. } => format!("r#{name}"),
         })
     }
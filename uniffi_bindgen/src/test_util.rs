@@ -96,6 +96,8 @@ pub fn generate_sources(tempdir: &Utf8Path, language: bindings::TargetLanguage)
         bindings::TargetLanguage::Python => "src:uniffi-bindgen-tests-python",
         bindings::TargetLanguage::Swift => "src:uniffi-bindgen-tests-swift",
         bindings::TargetLanguage::Ruby => "src:uniffi-bindgen-tests-ruby",
+        bindings::TargetLanguage::TypeScript => "src:uniffi-bindgen-tests-typescript",
+        bindings::TargetLanguage::Cpp => "src:uniffi-bindgen-tests-cpp",
     };
 
     bindings::generate(bindings::GenerateOptions {
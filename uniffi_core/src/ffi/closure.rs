@@ -0,0 +1,315 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! This module implements passing closures across the FFI.
+//!
+//! `Box<dyn Fn(A, B) -> R + Send + Sync>` and `Arc<dyn Fn(A, B) -> R + Send + Sync>` can be used as
+//! arguments and return values, for closures with up to 6 arguments.
+//!
+//! Closures are type-erased, so that each crate only needs a single VTable and a single set of
+//! scaffolding functions to handle all closure types:
+//!
+//!   * The arguments are serialized into a `RustBuffer`, one after the other.
+//!   * The return value is serialized into a `RustBuffer`.  Unit return values are empty buffers.
+//!
+//! Foreign closures that are passed to Rust work like callback interfaces.  The foreign side
+//! stores the closure in a handle map and registers a [ClosureVTable] with
+//! `ffi_[crate]_init_closure_vtable`.  Rust calls the closure using the `call` method and frees
+//! it using `uniffi_free`.
+//!
+//! Rust closures that are passed to the foreign side are lowered into a [Handle] that points to a
+//! type-erased [RustClosure].  The foreign side calls it using [closure_call] and frees it using
+//! [closure_free].
+
+use std::sync::Arc;
+
+use anyhow::bail;
+use bytes::Buf;
+
+use crate::{
+    check_remaining, derive_ffi_traits, metadata, rust_call, FfiConverterArc, Handle, Lift,
+    LiftClosureReturn, LiftReturn, Lower, LowerClosureReturn, MetadataBuffer, RustBuffer,
+    RustCallError, RustCallStatus, TypeId, UniffiForeignPointerCell,
+};
+
+/// VTable for foreign closures
+///
+/// There's one of these for each crate, registered by the foreign bindings.
+#[repr(C)]
+pub struct ClosureVTable {
+    pub uniffi_free: extern "C" fn(handle: u64),
+    pub call: extern "C" fn(
+        handle: u64,
+        args: RustBuffer,
+        out_return: &mut RustBuffer,
+        out_call_status: &mut RustCallStatus,
+    ),
+}
+
+/// Get the [ClosureVTable] for a crate
+///
+/// This is implemented for each crate's `UniFfiTag` by `setup_scaffolding!`.
+pub trait ClosureVTableProvider {
+    fn closure_vtable_cell() -> &'static UniffiForeignPointerCell<ClosureVTable>;
+}
+
+/// A closure implemented by the foreign side
+struct ForeignClosure {
+    handle: u64,
+    vtable_cell: &'static UniffiForeignPointerCell<ClosureVTable>,
+}
+
+impl ForeignClosure {
+    fn new<UT: ClosureVTableProvider>(handle: u64) -> Self {
+        Self {
+            handle,
+            vtable_cell: UT::closure_vtable_cell(),
+        }
+    }
+
+    fn call<UT, R: LiftClosureReturn<UT>>(&self, args: Vec<u8>) -> R {
+        let vtable = self.vtable_cell.get();
+        let mut out_return = RustBuffer::new();
+        let mut call_status = RustCallStatus::default();
+        (vtable.call)(
+            self.handle,
+            RustBuffer::from_vec(args),
+            &mut out_return,
+            &mut call_status,
+        );
+        // Closures can't return errors, so this panics for anything but a successful call.
+        <() as LiftReturn<UT>>::lift_foreign_return((), call_status);
+        R::try_lift_closure_return(out_return)
+            .unwrap_or_else(|e| panic!("Failed to lift closure return value: {e}"))
+    }
+}
+
+impl Drop for ForeignClosure {
+    fn drop(&mut self) {
+        (self.vtable_cell.get().uniffi_free)(self.handle);
+    }
+}
+
+/// Type-erased Rust closure that the foreign handle points to.
+///
+/// The closure inputs the serialized arguments and outputs the serialized return value, which
+/// means this type doesn't depend on the closure signature or the `UniFfiTag`.
+pub struct RustClosure(Box<ErasedFn>);
+
+type ErasedFn = dyn Fn(&mut &[u8]) -> anyhow::Result<RustBuffer> + Send + Sync;
+
+impl RustClosure {
+    fn call(&self, args: Vec<u8>) -> anyhow::Result<RustBuffer> {
+        let mut buf = args.as_slice();
+        let ret = (self.0)(&mut buf)?;
+        match buf.remaining() {
+            0 => Ok(ret),
+            n => bail!("junk data left in buffer after lifting closure arguments (count: {n})"),
+        }
+    }
+
+    fn into_handle(self) -> u64 {
+        Handle::from_arc(Arc::new(self)).as_raw()
+    }
+}
+
+macro_rules! impl_ffi_traits_for_closure {
+    ($len:literal $(, $A:ident $a:ident)*) => {
+        unsafe impl<UT, $($A,)* R> Lift<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync>
+        where
+            UT: ClosureVTableProvider + 'static,
+            $($A: Lower<UT> + 'static,)*
+            R: LiftClosureReturn<UT> + 'static,
+        {
+            type FfiType = u64;
+
+            fn try_lift(v: u64) -> anyhow::Result<Self> {
+                let closure = ForeignClosure::new::<UT>(v);
+                Ok(Box::new(move |$($a: $A),*| {
+                    #[allow(unused_mut)]
+                    let mut args = Vec::new();
+                    $(<$A as Lower<UT>>::write($a, &mut args);)*
+                    closure.call::<UT, R>(args)
+                }))
+            }
+
+            fn try_read(buf: &mut &[u8]) -> anyhow::Result<Self> {
+                check_remaining(buf, 8)?;
+                <Self as Lift<UT>>::try_lift(buf.get_u64())
+            }
+        }
+
+        unsafe impl<UT, $($A,)* R> Lower<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync>
+        where
+            UT: 'static,
+            $($A: Lift<UT> + 'static,)*
+            R: LowerClosureReturn<UT> + 'static,
+        {
+            type FfiType = u64;
+
+            fn lower(obj: Self) -> u64 {
+                RustClosure(Box::new(move |_args: &mut &[u8]| {
+                    $(let $a = <$A as Lift<UT>>::try_read(_args)?;)*
+                    Ok(<R as LowerClosureReturn<UT>>::lower_closure_return(obj($($a),*)))
+                }))
+                .into_handle()
+            }
+
+            fn write(obj: Self, buf: &mut Vec<u8>) {
+                buf.extend(<Self as Lower<UT>>::lower(obj).to_be_bytes())
+            }
+        }
+
+        impl<UT, $($A,)* R> TypeId<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync>
+        where
+            $($A: TypeId<UT>,)*
+            R: TypeId<UT>,
+        {
+            const TYPE_ID_META: MetadataBuffer = <dyn Fn($($A),*) -> R + Send + Sync as TypeId<UT>>::TYPE_ID_META;
+        }
+
+        impl<UT, $($A,)* R> TypeId<UT> for dyn Fn($($A),*) -> R + Send + Sync
+        where
+            $($A: TypeId<UT>,)*
+            R: TypeId<UT>,
+        {
+            const TYPE_ID_META: MetadataBuffer = MetadataBuffer::from_code(metadata::codes::TYPE_CLOSURE)
+                .concat_value($len)
+                $(.concat($A::TYPE_ID_META))*
+                .concat(R::TYPE_ID_META);
+        }
+
+        derive_ffi_traits!(impl<UT, $($A,)* R> LowerReturn<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync> where Box<dyn Fn($($A),*) -> R + Send + Sync>: Lower<UT>);
+        derive_ffi_traits!(impl<UT, $($A,)* R> LiftReturn<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync> where Box<dyn Fn($($A),*) -> R + Send + Sync>: Lift<UT>);
+        derive_ffi_traits!(impl<UT, $($A,)* R> LiftRef<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync> where Box<dyn Fn($($A),*) -> R + Send + Sync>: Lift<UT>);
+
+        // `Arc` closures go through `FfiConverterArc`, which means they need to support both
+        // lifting and lowering.
+        unsafe impl<UT, $($A,)* R> FfiConverterArc<UT> for dyn Fn($($A),*) -> R + Send + Sync
+        where
+            UT: ClosureVTableProvider + 'static,
+            $($A: Lift<UT> + Lower<UT> + TypeId<UT> + 'static,)*
+            R: LiftClosureReturn<UT> + LowerClosureReturn<UT> + TypeId<UT> + 'static,
+        {
+            type FfiType = u64;
+
+            fn lower(obj: Arc<Self>) -> u64 {
+                <Box<Self> as Lower<UT>>::lower(Box::new(move |$($a: $A),*| obj($($a),*)))
+            }
+
+            fn try_lift(v: u64) -> anyhow::Result<Arc<Self>> {
+                <Box<Self> as Lift<UT>>::try_lift(v).map(Arc::from)
+            }
+
+            fn write(obj: Arc<Self>, buf: &mut Vec<u8>) {
+                buf.extend(<Self as FfiConverterArc<UT>>::lower(obj).to_be_bytes())
+            }
+
+            fn try_read(buf: &mut &[u8]) -> anyhow::Result<Arc<Self>> {
+                check_remaining(buf, 8)?;
+                <Self as FfiConverterArc<UT>>::try_lift(buf.get_u64())
+            }
+
+            const TYPE_ID_META: MetadataBuffer = <Self as TypeId<UT>>::TYPE_ID_META;
+        }
+    };
+}
+
+impl_ffi_traits_for_closure!(0);
+impl_ffi_traits_for_closure!(1, A a);
+impl_ffi_traits_for_closure!(2, A a, B b);
+impl_ffi_traits_for_closure!(3, A a, B b, C c);
+impl_ffi_traits_for_closure!(4, A a, B b, C c, D d);
+impl_ffi_traits_for_closure!(5, A a, B b, C c, D d, E e);
+impl_ffi_traits_for_closure!(6, A a, B b, C c, D d, E e, F f);
+
+// === Public FFI API ===
+
+/// Call a Rust closure
+///
+/// `args` contains the serialized arguments and the return value is the serialized return value.
+///
+/// # Safety
+///
+/// The handle must have been created by lowering a Rust closure and must not previously have been
+/// passed to [closure_free].
+pub unsafe fn closure_call(
+    handle: u64,
+    args: RustBuffer,
+    call_status: &mut RustCallStatus,
+) -> RustBuffer {
+    trace!("closure_call: {handle:x}");
+    let closure = Handle::from_raw_unchecked(handle).into_arc_borrowed::<RustClosure>();
+    // Panics are caught and reported back to the foreign side, like for regular Rust calls.
+    let closure = std::panic::AssertUnwindSafe(closure);
+    rust_call(call_status, move || {
+        closure
+            .call(args.destroy_into_vec())
+            .map_err(|e| RustCallError::InternalError(e.to_string()))
+    })
+}
+
+/// Free a Rust closure
+///
+/// # Safety
+///
+/// The handle must have been created by lowering a Rust closure and must not previously have been
+/// passed to [closure_free].
+pub unsafe fn closure_free(handle: u64) {
+    trace!("closure_free: {handle:x}");
+    drop(Handle::from_raw_unchecked(handle).into_arc::<RustClosure>())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{RustCallStatusCode, UniFfiTag};
+
+    type AddFn = Box<dyn Fn(u32, u32) -> u32 + Send + Sync>;
+
+    fn lower_add_fn() -> u64 {
+        <AddFn as Lower<UniFfiTag>>::lower(Box::new(|a, b| a + b))
+    }
+
+    fn serialize_args(args: &[u32]) -> RustBuffer {
+        let mut buf = Vec::new();
+        for arg in args {
+            <u32 as Lower<UniFfiTag>>::write(*arg, &mut buf);
+        }
+        RustBuffer::from_vec(buf)
+    }
+
+    #[test]
+    fn test_closure_call() {
+        let handle = lower_add_fn();
+        let mut call_status = RustCallStatus::default();
+        let ret = unsafe { closure_call(handle, serialize_args(&[1, 2]), &mut call_status) };
+        assert_eq!(call_status.code, RustCallStatusCode::Success);
+        assert_eq!(
+            <u32 as Lift<UniFfiTag>>::try_lift_from_rust_buffer(ret).unwrap(),
+            3
+        );
+        // Closures can be called multiple times
+        let ret = unsafe { closure_call(handle, serialize_args(&[3, 4]), &mut call_status) };
+        assert_eq!(call_status.code, RustCallStatusCode::Success);
+        assert_eq!(
+            <u32 as Lift<UniFfiTag>>::try_lift_from_rust_buffer(ret).unwrap(),
+            7
+        );
+        unsafe { closure_free(handle) };
+    }
+
+    #[test]
+    fn test_closure_call_with_bad_args() {
+        let handle = lower_add_fn();
+        let mut call_status = RustCallStatus::default();
+        unsafe { closure_call(handle, serialize_args(&[1]), &mut call_status) };
+        assert_eq!(call_status.code, RustCallStatusCode::UnexpectedError);
+
+        let mut call_status = RustCallStatus::default();
+        unsafe { closure_call(handle, serialize_args(&[1, 2, 3]), &mut call_status) };
+        assert_eq!(call_status.code, RustCallStatusCode::UnexpectedError);
+        unsafe { closure_free(handle) };
+    }
+}
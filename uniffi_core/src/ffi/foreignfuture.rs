@@ -17,7 +17,7 @@
 //!     Future is dropped in Rust.  For languages that support it, this can be hooked up to
 //!     cancelling the async task for the method.
 
-use crate::{oneshot, LiftReturn, RustCallStatus};
+use crate::{oneshot, FfiCallKind, FfiCallTimer, LiftReturn, RustCallStatus};
 
 /// Callback that's passed to a foreign async functions.
 ///
@@ -64,11 +64,16 @@ impl Drop for ForeignFutureDroppedCallbackStruct {
 
 unsafe impl Send for ForeignFutureDroppedCallbackStruct {}
 
-pub async fn foreign_async_call<F, T, UT>(call_scaffolding_function: F) -> T
+/// Call an async foreign method and await the result
+///
+/// `name` is reported to the [FfiCallObserver](crate::FfiCallObserver), it's formatted as
+/// `[TraitName]::[method_name]`.
+pub async fn foreign_async_call<F, T, UT>(name: &'static str, call_scaffolding_function: F) -> T
 where
     F: FnOnce(ForeignFutureCallback<T::ReturnType>, u64, &mut ForeignFutureDroppedCallbackStruct),
     T: LiftReturn<UT>,
 {
+    let call_timer = FfiCallTimer::start(name, FfiCallKind::CallbackInterface);
     // Create a oneshot channel that will receive the result of the callback method
     let (sender, receiver) = oneshot::channel::<ForeignFutureResult<T::ReturnType>>();
     // Create complete callback/data from the oneshot channel
@@ -87,6 +92,7 @@ where
     );
     // Await the result and use it to return a value
     let result = receiver.await;
+    call_timer.finish(&result.call_status.code);
     T::lift_foreign_return(result.return_value, result.call_status)
 }
 
@@ -128,6 +134,7 @@ mod test {
                 let callback_info = callback_info.clone();
                 let future_dropped_call_count = future_dropped_call_count.clone();
                 Box::pin(foreign_async_call::<_, String, crate::UniFfiTag>(
+                    "Trait::method",
                     move |callback, data, out_dropped_callback| {
                         callback_info.set((callback, data)).unwrap();
                         *out_dropped_callback = ForeignFutureDroppedCallbackStruct {
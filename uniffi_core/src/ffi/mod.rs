@@ -5,6 +5,7 @@
 //! Types that can cross the FFI boundary.
 
 pub mod callbackinterface;
+pub mod closure;
 pub mod ffibuffer;
 pub mod ffidefault;
 #[cfg(feature = "scaffolding-ffi-buffer-fns")]
@@ -13,11 +14,14 @@ pub mod foreignbytes;
 pub mod foreigncallbacks;
 pub mod foreignfuture;
 pub mod handle;
+pub mod observer;
 pub mod rustbuffer;
 pub mod rustcalls;
 pub mod rustfuture;
+pub mod stream;
 
 pub use callbackinterface::*;
+pub use closure::*;
 pub use ffidefault::FfiDefault;
 #[cfg(feature = "scaffolding-ffi-buffer-fns")]
 pub use ffiserialize::FfiSerialize;
@@ -25,6 +29,8 @@ pub use foreignbytes::*;
 pub use foreigncallbacks::*;
 pub use foreignfuture::*;
 pub use handle::*;
+pub use observer::*;
 pub use rustbuffer::*;
 pub use rustcalls::*;
 pub use rustfuture::*;
+pub use stream::*;
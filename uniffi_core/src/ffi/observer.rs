@@ -0,0 +1,190 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Runtime hooks for observing FFI calls
+//!
+//! Unlike the `ffi-trace` feature, this works in release builds and can be turned on and off
+//! at runtime.  Install an observer with [set_ffi_call_observer] and it will be called once for
+//! each completed FFI call:
+//!
+//!   - Scaffolding calls, where the foreign code calls an exported Rust function, method or
+//!     constructor.  For async functions, the duration covers the time between creating the
+//!     future and the foreign code completing it.
+//!   - Callback interface calls, where Rust calls a method implemented by the foreign code.
+//!
+//! When no observer is installed, the only overhead is an atomic load per call.
+
+use crate::RustCallStatusCode;
+use std::{
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, Instant},
+};
+
+/// Which direction an observed FFI call went
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FfiCallKind {
+    /// The foreign code called into a Rust scaffolding function
+    Scaffolding,
+    /// Rust called into a foreign callback interface method
+    CallbackInterface,
+}
+
+/// A completed FFI call, passed to the [FfiCallObserver]
+#[derive(Debug)]
+pub struct FfiCallEvent<'a> {
+    /// For scaffolding calls, the name of the scaffolding function.
+    /// For callback interface calls, `[TraitName]::[method_name]`.
+    pub name: &'a str,
+    pub kind: FfiCallKind,
+    pub duration: Duration,
+    /// How the call completed.  Panics are reported as [RustCallStatusCode::UnexpectedError].
+    pub status: RustCallStatusCode,
+}
+
+/// Receives [FfiCallEvent]s, see [set_ffi_call_observer]
+///
+/// This is implemented for all `Fn(&FfiCallEvent)` closures that are `Send + Sync`.
+pub trait FfiCallObserver: Send + Sync {
+    fn on_ffi_call(&self, event: &FfiCallEvent<'_>);
+}
+
+impl<F> FfiCallObserver for F
+where
+    F: Fn(&FfiCallEvent<'_>) + Send + Sync,
+{
+    fn on_ffi_call(&self, event: &FfiCallEvent<'_>) {
+        self(event)
+    }
+}
+
+static OBSERVER_INSTALLED: AtomicBool = AtomicBool::new(false);
+static OBSERVER: RwLock<Option<Arc<dyn FfiCallObserver>>> = RwLock::new(None);
+
+/// Install an observer that's called after each FFI call, replacing any previous observer.
+///
+/// The observer is called on the thread that made the call, so it should return quickly.
+/// Panics inside the observer are caught and ignored.
+pub fn set_ffi_call_observer(observer: impl FfiCallObserver + 'static) {
+    *OBSERVER.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(observer));
+    OBSERVER_INSTALLED.store(true, Ordering::Release);
+}
+
+/// Remove the observer installed with [set_ffi_call_observer]
+pub fn clear_ffi_call_observer() {
+    OBSERVER_INSTALLED.store(false, Ordering::Release);
+    *OBSERVER.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Times a single FFI call and reports it to the observer
+///
+/// This is used by the generated scaffolding code and is not intended to be used directly.
+#[doc(hidden)]
+pub struct FfiCallTimer {
+    name: &'static str,
+    kind: FfiCallKind,
+    // `None` if there was no observer installed when the call started.
+    start: Option<Instant>,
+}
+
+impl FfiCallTimer {
+    pub fn start(name: &'static str, kind: FfiCallKind) -> Self {
+        let start = OBSERVER_INSTALLED
+            .load(Ordering::Acquire)
+            .then(Instant::now);
+        Self { name, kind, start }
+    }
+
+    pub fn finish(self, status: &RustCallStatusCode) {
+        let Some(start) = self.start else {
+            return;
+        };
+        let duration = start.elapsed();
+        // Clone the observer out of the lock, so that it can call `set_ffi_call_observer`
+        // without deadlocking.
+        let observer = match OBSERVER.read() {
+            Ok(observer) => observer.clone(),
+            Err(_) => return,
+        };
+        if let Some(observer) = observer {
+            let event = FfiCallEvent {
+                name: self.name,
+                kind: self.kind,
+                duration,
+                status: *status,
+            };
+            // We may be inside an `extern "C"` function, where unwinding would abort.
+            let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| observer.on_ffi_call(&event)));
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use std::sync::Mutex;
+
+    // The observer is global, so make sure only one test uses it at once.
+    pub(crate) static OBSERVER_TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    pub(crate) fn record_events() -> Arc<Mutex<Vec<(String, FfiCallKind, RustCallStatusCode)>>> {
+        let events = Arc::new(Mutex::new(vec![]));
+        let events2 = Arc::clone(&events);
+        set_ffi_call_observer(move |event: &FfiCallEvent<'_>| {
+            events2
+                .lock()
+                .unwrap()
+                .push((event.name.to_string(), event.kind, event.status));
+        });
+        events
+    }
+
+    #[test]
+    fn test_observer() {
+        let _guard = OBSERVER_TEST_LOCK.lock().unwrap();
+
+        // No observer installed, nothing is recorded
+        FfiCallTimer::start("before", FfiCallKind::Scaffolding)
+            .finish(&RustCallStatusCode::Success);
+
+        let events = record_events();
+        FfiCallTimer::start("scaffolding", FfiCallKind::Scaffolding)
+            .finish(&RustCallStatusCode::Error);
+        FfiCallTimer::start("Trait::method", FfiCallKind::CallbackInterface)
+            .finish(&RustCallStatusCode::Success);
+        // Calls that started before the observer was cleared aren't reported
+        let timer = FfiCallTimer::start("cleared", FfiCallKind::Scaffolding);
+        clear_ffi_call_observer();
+        timer.finish(&RustCallStatusCode::Success);
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                (
+                    "scaffolding".to_string(),
+                    FfiCallKind::Scaffolding,
+                    RustCallStatusCode::Error
+                ),
+                (
+                    "Trait::method".to_string(),
+                    FfiCallKind::CallbackInterface,
+                    RustCallStatusCode::Success
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_observer_panic() {
+        let _guard = OBSERVER_TEST_LOCK.lock().unwrap();
+
+        set_ffi_call_observer(|_: &FfiCallEvent<'_>| panic!("observer panic"));
+        FfiCallTimer::start("scaffolding", FfiCallKind::Scaffolding)
+            .finish(&RustCallStatusCode::Success);
+        clear_ffi_call_observer();
+    }
+}
@@ -11,7 +11,7 @@
 //!    - Adapting the result of `Return::lower_return()` into either a return value or an
 //!      exception
 
-use crate::{FfiDefault, Lower, RustBuffer, UniFfiTag};
+use crate::{FfiCallKind, FfiCallTimer, FfiDefault, Lower, RustBuffer, UniFfiTag};
 use std::mem::ManuallyDrop;
 use std::panic;
 
@@ -80,7 +80,7 @@ impl RustCallStatus {
 /// Result of a FFI call to a Rust function
 /// Value is signed to avoid Kotlin's experimental unsigned types.
 #[repr(i8)]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RustCallStatusCode {
     /// Successful call.
     Success = 0,
@@ -169,6 +169,24 @@ where
     rust_call_with_out_status(out_status, callback).unwrap_or_else(R::ffi_default)
 }
 
+/// Handle a scaffolding call and report it to the [FfiCallObserver](crate::FfiCallObserver)
+///
+/// This works exactly like [rust_call].  `name` is the name of the scaffolding function.
+pub fn rust_call_observed<F, R>(
+    name: &'static str,
+    out_status: &mut RustCallStatus,
+    callback: F,
+) -> R
+where
+    F: panic::UnwindSafe + FnOnce() -> Result<R, RustCallError>,
+    R: FfiDefault,
+{
+    let timer = FfiCallTimer::start(name, FfiCallKind::Scaffolding);
+    let return_value = rust_call(out_status, callback);
+    timer.finish(&out_status.code);
+    return_value
+}
+
 /// Result of making a Rust call
 ///
 /// The `Ok` side stores successful call results
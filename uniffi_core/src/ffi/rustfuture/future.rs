@@ -79,7 +79,9 @@ use super::{
     FutureLowerReturn, RustFutureCallback, RustFutureContinuationBoundCallback, RustFuturePoll,
     Scheduler, UniffiCompatibleFuture,
 };
-use crate::{try_rust_call, FfiDefault, LiftArgsError, RustCallResult, RustCallStatus};
+use crate::{
+    try_rust_call, FfiCallTimer, FfiDefault, LiftArgsError, RustCallResult, RustCallStatus,
+};
 use std::{
     future, panic,
     pin::{pin, Pin},
@@ -103,6 +105,8 @@ struct WrappedFuture<FfiType> {
     // Both the `Ok` and `Err` side are easy to pass back across the FFI.
     future: Option<Pin<Box<dyn UniffiCompatibleFuture<RustCallResult<FfiType>>>>>,
     result: Option<Result<FfiType, RustCallStatus>>,
+    // Reports the call to the `FfiCallObserver` when it's completed.
+    call_timer: Option<FfiCallTimer>,
 }
 
 impl<FfiType> WrappedFuture<FfiType> {
@@ -137,6 +141,7 @@ impl<FfiType> WrappedFuture<FfiType> {
         Self {
             future: Some(Box::pin(wrapped_future)),
             result: None,
+            call_timer: None,
         }
     }
 
@@ -169,6 +174,9 @@ impl<FfiType> WrappedFuture<FfiType> {
             Some(Err(call_status)) => *out_status = call_status,
             None => *out_status = RustCallStatus::cancelled(),
         }
+        if let Some(call_timer) = self.call_timer.take() {
+            call_timer.finish(&out_status.code);
+        }
         self.free();
         return_value
     }
@@ -176,6 +184,7 @@ impl<FfiType> WrappedFuture<FfiType> {
     fn free(&mut self) {
         self.future = None;
         self.result = None;
+        self.call_timer = None;
     }
 }
 
@@ -202,6 +211,12 @@ where
         }
     }
 
+    /// Report this future to the `FfiCallObserver` when it's completed
+    pub fn with_call_timer(mut self, call_timer: FfiCallTimer) -> Self {
+        self.future.get_mut().unwrap().call_timer = Some(call_timer);
+        self
+    }
+
     pub fn poll(self: Arc<Self>, callback: Callback) {
         let cancelled = self.is_cancelled();
         let ready = cancelled || {
@@ -10,7 +10,9 @@ mod scheduler;
 #[cfg(test)]
 mod tests;
 
-use crate::{FfiDefault, Handle, LiftArgsError, LowerReturn, RustCallStatus};
+use crate::{
+    FfiCallKind, FfiCallTimer, FfiDefault, Handle, LiftArgsError, LowerReturn, RustCallStatus,
+};
 pub(crate) use future::RustFuture;
 pub use scheduler::{RustFutureCallback, Scheduler};
 
@@ -123,6 +125,24 @@ where
     trace_and_return!(handle, "rust_future_new: {handle:?}")
 }
 
+/// Create a new [Handle] for a Rust future and report it to the
+/// [FfiCallObserver](crate::FfiCallObserver) when it's completed.
+///
+/// `name` is the name of the scaffolding function.
+pub fn rust_future_new_observed<F, T, UT>(name: &'static str, future: F, tag: UT) -> Handle
+where
+    F: UniffiCompatibleFuture<Result<T, LiftArgsError>> + 'static,
+    T: FutureLowerReturn<UT> + 'static,
+{
+    let call_timer = FfiCallTimer::start(name, FfiCallKind::Scaffolding);
+    let rust_future = Arc::new(
+        RustFuture::<_, RustFutureContinuationBoundCallback>::new(future, tag)
+            .with_call_timer(call_timer),
+    );
+    let handle = Handle::from_arc(rust_future);
+    trace_and_return!(handle, "rust_future_new: {handle:?}")
+}
+
 /// Poll a Rust future
 ///
 /// When the future is ready to progress the continuation will be called with the `data` value and
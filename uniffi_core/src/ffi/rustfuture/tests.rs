@@ -266,3 +266,50 @@ fn test_wake_during_poll() {
         "All done"
     );
 }
+
+#[test]
+fn test_observer() {
+    use crate::{clear_ffi_call_observer, ffi::observer::test::*, FfiCallKind};
+
+    let _guard = OBSERVER_TEST_LOCK.lock().unwrap();
+    let events = record_events();
+
+    let (sender, rust_future) = channel();
+    let rust_future = Arc::new(
+        Arc::into_inner(rust_future)
+            .unwrap()
+            .with_call_timer(FfiCallTimer::start("success", FfiCallKind::Scaffolding)),
+    );
+    poll(&rust_future);
+    sender.send(Ok("All done".into()));
+    poll(&rust_future);
+    // The call isn't reported until it's completed
+    assert_eq!(events.lock().unwrap().len(), 0);
+    complete(rust_future);
+
+    let (_sender, rust_future) = channel();
+    let rust_future = Arc::new(
+        Arc::into_inner(rust_future)
+            .unwrap()
+            .with_call_timer(FfiCallTimer::start("cancelled", FfiCallKind::Scaffolding)),
+    );
+    rust_future.cancel();
+    complete(rust_future);
+    clear_ffi_call_observer();
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            (
+                "success".to_string(),
+                FfiCallKind::Scaffolding,
+                RustCallStatusCode::Success
+            ),
+            (
+                "cancelled".to_string(),
+                FfiCallKind::Scaffolding,
+                RustCallStatusCode::Cancelled
+            ),
+        ]
+    );
+}
@@ -0,0 +1,162 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! This module implements returning an asynchronous stream of values to the foreign side.
+//!
+//! Streams are built on top of the Rust future code from [crate::ffi::rustfuture]:
+//!
+//!   * Exported functions return a [Stream], which gets lowered into a [Handle].
+//!   * The foreign side calls [stream_next] to get a RustFuture handle for the next item.
+//!     This future is driven using the normal `rust_future_*_rust_buffer` functions.
+//!   * The `RustBuffer` returned by the future contains the item serialized as an `Option<T>`.
+//!     `None` means the stream is exhausted.
+//!   * The foreign side calls [stream_free] once it's done with the stream.  This drops the Rust
+//!     stream, which is how cancellation works.  Any in-progress `stream_next` future can be
+//!     cancelled using the normal `rust_future_cancel` function.
+
+use std::{
+    future::poll_fn,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use futures_core::Stream as FuturesStream;
+
+use crate::{
+    metadata, rust_future_new, Handle, Lower, LowerReturn, MetadataBuffer, RustBuffer,
+    RustCallError, TypeId,
+};
+
+/// Asynchronous stream of values that can be returned across the FFI
+///
+/// Wrap any `futures_core::Stream` with [Stream::new] and return it from an exported function.
+/// It will be presented to the foreign side as a `Flow` in Kotlin, an `AsyncSequence` in Swift
+/// and an async iterator in Python.
+pub struct Stream<T> {
+    inner: Pin<Box<dyn FuturesStream<Item = T> + Send>>,
+}
+
+impl<T> Stream<T> {
+    pub fn new(stream: impl FuturesStream<Item = T> + Send + 'static) -> Self {
+        Self {
+            inner: Box::pin(stream),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Stream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Stream").finish_non_exhaustive()
+    }
+}
+
+/// Type-erased stream state that the foreign handle points to.
+///
+/// Items are lowered as soon as they're produced, which means this type doesn't depend on `T`
+/// or the `UniFfiTag`.
+struct StreamState {
+    inner: Mutex<Pin<Box<dyn FuturesStream<Item = RustBuffer> + Send>>>,
+}
+
+impl StreamState {
+    async fn next(&self) -> StreamItem {
+        let item = poll_fn(|cx| self.inner.lock().unwrap().as_mut().poll_next(cx)).await;
+        // Stream exhausted, send `None` to the foreign side.
+        StreamItem(item.unwrap_or_else(|| RustBuffer::from_vec(vec![0])))
+    }
+}
+
+/// Result of a [stream_next] future
+struct StreamItem(RustBuffer);
+
+unsafe impl<UT> LowerReturn<UT> for StreamItem {
+    type ReturnType = RustBuffer;
+
+    fn lower_return(v: Self) -> Result<Self::ReturnType, RustCallError> {
+        Ok(v.0)
+    }
+}
+
+unsafe impl<UT, T> LowerReturn<UT> for Stream<T>
+where
+    T: Lower<UT> + Send + 'static,
+    UT: 'static,
+{
+    type ReturnType = Handle;
+
+    fn lower_return(v: Self) -> Result<Self::ReturnType, RustCallError> {
+        let mut inner = v.inner;
+        let lowered = poll_fn_stream(move |cx| {
+            inner
+                .as_mut()
+                .poll_next(cx)
+                .map(|item| item.map(|v| <Option<T> as Lower<UT>>::lower(Some(v))))
+        });
+        let state = Arc::new(StreamState {
+            inner: Mutex::new(Box::pin(lowered)),
+        });
+        Ok(Handle::from_arc(state))
+    }
+}
+
+impl<UT, T: TypeId<UT>> TypeId<UT> for Stream<T> {
+    const TYPE_ID_META: MetadataBuffer =
+        MetadataBuffer::from_code(metadata::codes::TYPE_STREAM).concat(T::TYPE_ID_META);
+}
+
+/// Create a `futures_core::Stream` from a `poll_next` closure
+fn poll_fn_stream<T, F>(f: F) -> PollFnStream<F>
+where
+    F: FnMut(&mut std::task::Context<'_>) -> std::task::Poll<Option<T>>,
+{
+    PollFnStream(f)
+}
+
+struct PollFnStream<F>(F);
+
+impl<F> Unpin for PollFnStream<F> {}
+
+impl<T, F> FuturesStream for PollFnStream<F>
+where
+    F: FnMut(&mut std::task::Context<'_>) -> std::task::Poll<Option<T>>,
+{
+    type Item = T;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<T>> {
+        (self.0)(cx)
+    }
+}
+
+// === Public FFI API ===
+
+/// Get the next item from a stream
+///
+/// Returns a RustFuture handle, that should be driven using the `rust_future_*_rust_buffer`
+/// functions.  The future resolves to the next item, serialized as an `Option<T>`.
+///
+/// # Safety
+///
+/// The [Handle] must have been created by lowering a [Stream] and must not previously have been
+/// passed to [stream_free].
+pub unsafe fn stream_next(handle: Handle) -> Handle {
+    trace!("stream_next: {handle:?}");
+    let state = Handle::into_arc_borrowed::<StreamState>(handle);
+    rust_future_new::<_, StreamItem, _>(async move { Ok(state.next().await) }, ())
+}
+
+/// Free a stream, dropping the underlying Rust stream.
+///
+/// Any in-progress [stream_next] futures keep the stream alive until they are freed.
+///
+/// # Safety
+///
+/// The [Handle] must have been created by lowering a [Stream] and must not previously have been
+/// passed to [stream_free].
+pub unsafe fn stream_free(handle: Handle) {
+    trace!("stream_free: {handle:?}");
+    drop(Handle::into_arc::<StreamState>(handle))
+}
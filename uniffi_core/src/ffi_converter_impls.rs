@@ -23,8 +23,9 @@
 /// "UT" means an arbitrary `UniFfiTag` type.
 use crate::{
     check_remaining, derive_ffi_traits, ffi_converter_rust_buffer_lift_and_lower, metadata,
-    ConvertError, FfiConverter, Lift, LiftRef, LiftReturn, Lower, LowerError, LowerReturn,
-    MetadataBuffer, Result, RustBuffer, RustCallError, TypeId, UnexpectedUniFFICallbackError,
+    ConvertError, FfiConverter, Lift, LiftClosureReturn, LiftRef, LiftReturn, Lower,
+    LowerClosureReturn, LowerError, LowerReturn, MetadataBuffer, OwnedBytes, Result, RustBuffer,
+    RustCallError, TypeId, UnexpectedUniFFICallbackError,
 };
 use anyhow::bail;
 use bytes::buf::{Buf, BufMut};
@@ -78,6 +79,32 @@ impl_ffi_converter_for_num_primitive!(i64, metadata::codes::TYPE_I64, get_i64, p
 impl_ffi_converter_for_num_primitive!(f32, metadata::codes::TYPE_F32, get_f32, put_f32);
 impl_ffi_converter_for_num_primitive!(f64, metadata::codes::TYPE_F64, get_f64, put_f64);
 
+/// Support for passing 128-bit integers via the FFI.
+///
+/// There's no portable C representation for 128-bit integers, so they're passed by
+/// serializing to a buffer. They're represented on the buffer as 16 big-endian bytes.
+macro_rules! impl_ffi_converter_for_128bit_primitive {
+    ($T:ty, $type_code:expr, $get:ident, $put:ident) => {
+        unsafe impl<UT> FfiConverter<UT> for $T {
+            ffi_converter_rust_buffer_lift_and_lower!(UT);
+
+            fn write(obj: $T, buf: &mut Vec<u8>) {
+                buf.$put(obj);
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<$T> {
+                check_remaining(buf, 16)?;
+                Ok(buf.$get())
+            }
+
+            const TYPE_ID_META: MetadataBuffer = MetadataBuffer::from_code($type_code);
+        }
+    };
+}
+
+impl_ffi_converter_for_128bit_primitive!(u128, metadata::codes::TYPE_U128, get_u128, put_u128);
+impl_ffi_converter_for_128bit_primitive!(i128, metadata::codes::TYPE_I128, get_i128, put_i128);
+
 /// Support for passing boolean values via the FFI.
 ///
 /// Booleans are passed as an `i8` in order to avoid problems with handling
@@ -166,6 +193,46 @@ unsafe impl<UT> FfiConverter<UT> for String {
     const TYPE_ID_META: MetadataBuffer = MetadataBuffer::from_code(metadata::codes::TYPE_STRING);
 }
 
+/// Support for passing owned bytes via the FFI without copying.
+///
+/// Like `String`, this passes the `RustBuffer` for the underlying `Vec<u8>` directly,
+/// so the foreign code can wrap the bytes rather than copying them out.
+///
+/// When serialized in a buffer, `OwnedBytes` is represented the same way as `Vec<u8>`:
+/// an i32 byte length followed by the bytes.
+unsafe impl<UT> FfiConverter<UT> for OwnedBytes {
+    type FfiType = RustBuffer;
+
+    fn lower(obj: OwnedBytes) -> Self::FfiType {
+        RustBuffer::from_vec(obj.0)
+    }
+
+    // The argument here *must* be a uniquely-owned `RustBuffer`, either previously obtained
+    // from `lower` above or allocated by the foreign code using `rustbuffer_alloc`.
+    fn try_lift(v: Self::FfiType) -> Result<OwnedBytes> {
+        Ok(OwnedBytes(v.destroy_into_vec()))
+    }
+
+    fn write(obj: OwnedBytes, buf: &mut Vec<u8>) {
+        // TODO: it would be nice not to panic here.
+        let len = i32::try_from(obj.len()).unwrap();
+        buf.put_i32(len);
+        buf.put(obj.as_slice());
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<OwnedBytes> {
+        check_remaining(buf, 4)?;
+        let len = usize::try_from(buf.get_i32())?;
+        check_remaining(buf, len)?;
+        let bytes = buf.chunk()[..len].to_vec();
+        buf.advance(len);
+        Ok(OwnedBytes(bytes))
+    }
+
+    const TYPE_ID_META: MetadataBuffer =
+        MetadataBuffer::from_code(metadata::codes::TYPE_OWNED_BYTES);
+}
+
 /// Support for passing timestamp values via the FFI.
 ///
 /// Timestamps values are currently always passed by serializing to a buffer.
@@ -380,6 +447,72 @@ impl<UT, T: TypeId<UT>> TypeId<UT> for Vec<T> {
         MetadataBuffer::from_code(metadata::codes::TYPE_VEC).concat(T::TYPE_ID_META);
 }
 
+// Support for passing fixed-size arrays via the FFI.
+//
+// Arrays are serialized the same way as vectors, with an `i32` item count followed by each item.
+// The length is part of the type metadata, so the foreign side can check it before lowering.  We
+// also check it when lifting, since the foreign code could still send the wrong number of items.
+
+unsafe impl<UT, T: Lower<UT>, const N: usize> Lower<UT> for [T; N] {
+    type FfiType = RustBuffer;
+
+    fn write(obj: [T; N], buf: &mut Vec<u8>) {
+        buf.put_i32(Self::LEN);
+        for item in obj {
+            <T as Lower<UT>>::write(item, buf);
+        }
+    }
+
+    fn lower(obj: [T; N]) -> RustBuffer {
+        Self::lower_into_rust_buffer(obj)
+    }
+}
+
+unsafe impl<UT, T: Lift<UT>, const N: usize> Lift<UT> for [T; N] {
+    type FfiType = RustBuffer;
+
+    fn try_read(buf: &mut &[u8]) -> Result<[T; N]> {
+        check_remaining(buf, 4)?;
+        let len = buf.get_i32();
+        if len != Self::LEN {
+            bail!("Expected an array of length {N}, but got {len} items");
+        }
+        let items = (0..N)
+            .map(|_| <T as Lift<UT>>::try_read(buf))
+            .collect::<Result<Vec<_>>>()?;
+        match items.try_into() {
+            Ok(array) => Ok(array),
+            Err(_) => unreachable!("items has exactly N elements"),
+        }
+    }
+
+    fn try_lift(buf: RustBuffer) -> Result<[T; N]> {
+        Self::try_lift_from_rust_buffer(buf)
+    }
+}
+
+impl<UT, T: TypeId<UT>, const N: usize> TypeId<UT> for [T; N] {
+    const TYPE_ID_META: MetadataBuffer = MetadataBuffer::from_code(metadata::codes::TYPE_ARRAY)
+        .concat_u32(Self::LEN as u32)
+        .concat(T::TYPE_ID_META);
+}
+
+/// Array lengths, checked at compile time
+trait ArrayLen {
+    /// `N` as an `i32`, to match the item count that we write for sequences.
+    const LEN: i32;
+}
+
+impl<T, const N: usize> ArrayLen for [T; N] {
+    const LEN: i32 = {
+        assert!(
+            N <= i32::MAX as usize,
+            "UniFFI limits arrays to i32::MAX items"
+        );
+        N as i32
+    };
+}
+
 unsafe impl<K, V, UT> Lower<UT> for HashMap<K, V>
 where
     K: Lower<UT> + std::hash::Hash + Eq,
@@ -444,10 +577,13 @@ derive_ffi_traits!(blanket u32);
 derive_ffi_traits!(blanket i32);
 derive_ffi_traits!(blanket u64);
 derive_ffi_traits!(blanket i64);
+derive_ffi_traits!(blanket u128);
+derive_ffi_traits!(blanket i128);
 derive_ffi_traits!(blanket f32);
 derive_ffi_traits!(blanket f64);
 derive_ffi_traits!(blanket bool);
 derive_ffi_traits!(blanket String);
+derive_ffi_traits!(blanket OwnedBytes);
 derive_ffi_traits!(blanket Duration);
 derive_ffi_traits!(blanket SystemTime);
 
@@ -470,6 +606,63 @@ derive_ffi_traits!(impl<T, UT> LowerError<UT> for Vec<T> where Vec<T>: Lower<UT>
 derive_ffi_traits!(impl<T, UT> LiftReturn<UT> for Vec<T> where Vec<T>: Lift<UT>);
 derive_ffi_traits!(impl<T, UT> LiftRef<UT> for Vec<T> where Vec<T>: Lift<UT>);
 
+// `derive_ffi_traits!` doesn't support const generics, so we write these out for arrays
+unsafe impl<T, UT, const N: usize> LowerReturn<UT> for [T; N]
+where
+    T: Lower<UT>,
+{
+    type ReturnType = RustBuffer;
+
+    fn lower_return(v: Self) -> Result<Self::ReturnType, RustCallError> {
+        Ok(<Self as Lower<UT>>::lower(v))
+    }
+}
+
+unsafe impl<T, UT, const N: usize> LowerError<UT> for [T; N]
+where
+    T: Lower<UT>,
+{
+    fn lower_error(obj: Self) -> RustBuffer {
+        <Self as Lower<UT>>::lower_into_rust_buffer(obj)
+    }
+}
+
+unsafe impl<T, UT, const N: usize> LiftReturn<UT> for [T; N]
+where
+    T: Lift<UT>,
+{
+    type ReturnType = RustBuffer;
+
+    fn try_lift_successful_return(v: Self::ReturnType) -> Result<Self> {
+        <Self as Lift<UT>>::try_lift(v)
+    }
+}
+
+unsafe impl<T, UT, const N: usize> LowerClosureReturn<UT> for [T; N]
+where
+    T: Lower<UT>,
+{
+    fn lower_closure_return(v: Self) -> RustBuffer {
+        <Self as Lower<UT>>::lower_into_rust_buffer(v)
+    }
+}
+
+unsafe impl<T, UT, const N: usize> LiftClosureReturn<UT> for [T; N]
+where
+    T: Lift<UT>,
+{
+    fn try_lift_closure_return(buf: RustBuffer) -> Result<Self> {
+        <Self as Lift<UT>>::try_lift_from_rust_buffer(buf)
+    }
+}
+
+unsafe impl<T, UT, const N: usize> LiftRef<UT> for [T; N]
+where
+    T: Lift<UT>,
+{
+    type LiftType = Self;
+}
+
 derive_ffi_traits!(impl<K, V, UT> LowerReturn<UT> for HashMap<K, V> where HashMap<K, V>: Lower<UT>);
 derive_ffi_traits!(impl<K, V, UT> LowerError<UT> for HashMap<K, V> where HashMap<K, V>: Lower<UT>);
 derive_ffi_traits!(impl<K, V, UT> LiftReturn<UT> for HashMap<K, V> where HashMap<K, V>: Lift<UT>);
@@ -529,6 +722,62 @@ derive_ffi_traits!(impl<T, UT> LowerError<UT> for HashSet<T> where HashSet<T>: L
 derive_ffi_traits!(impl<T, UT> LiftReturn<UT> for HashSet<T> where HashSet<T>: Lift<UT>);
 derive_ffi_traits!(impl<T, UT> LiftRef<UT> for HashSet<T> where HashSet<T>: Lift<UT>);
 
+// Support for passing tuples via the FFI.
+//
+// Tuples are always passed by serializing to a buffer, we write each element in turn.
+// The element count is known statically by both sides, so there's no need to write it.
+macro_rules! impl_ffi_traits_for_tuple {
+    ($len:literal; $($idx:tt $T:ident),+) => {
+        unsafe impl<UT, $($T: Lower<UT>),+> Lower<UT> for ($($T,)+) {
+            type FfiType = RustBuffer;
+
+            fn write(obj: ($($T,)+), buf: &mut Vec<u8>) {
+                $(<$T as Lower<UT>>::write(obj.$idx, buf);)+
+            }
+
+            fn lower(obj: ($($T,)+)) -> RustBuffer {
+                Self::lower_into_rust_buffer(obj)
+            }
+        }
+
+        unsafe impl<UT, $($T: Lift<UT>),+> Lift<UT> for ($($T,)+) {
+            type FfiType = RustBuffer;
+
+            fn try_read(buf: &mut &[u8]) -> Result<($($T,)+)> {
+                Ok(($(<$T as Lift<UT>>::try_read(buf)?,)+))
+            }
+
+            fn try_lift(buf: RustBuffer) -> Result<($($T,)+)> {
+                Self::try_lift_from_rust_buffer(buf)
+            }
+        }
+
+        impl<UT, $($T: TypeId<UT>),+> TypeId<UT> for ($($T,)+) {
+            const TYPE_ID_META: MetadataBuffer =
+                MetadataBuffer::from_code(metadata::codes::TYPE_TUPLE)
+                    .concat_value($len)
+                    $(.concat($T::TYPE_ID_META))+;
+        }
+
+        derive_ffi_traits!(impl<UT, $($T),+> LowerReturn<UT> for ($($T,)+) where ($($T,)+): Lower<UT>);
+        derive_ffi_traits!(impl<UT, $($T),+> LowerError<UT> for ($($T,)+) where ($($T,)+): Lower<UT>);
+        derive_ffi_traits!(impl<UT, $($T),+> LiftReturn<UT> for ($($T,)+) where ($($T,)+): Lift<UT>);
+        derive_ffi_traits!(impl<UT, $($T),+> LiftRef<UT> for ($($T,)+) where ($($T,)+): Lift<UT>);
+    };
+}
+
+impl_ffi_traits_for_tuple!(2; 0 T0, 1 T1);
+impl_ffi_traits_for_tuple!(3; 0 T0, 1 T1, 2 T2);
+impl_ffi_traits_for_tuple!(4; 0 T0, 1 T1, 2 T2, 3 T3);
+impl_ffi_traits_for_tuple!(5; 0 T0, 1 T1, 2 T2, 3 T3, 4 T4);
+impl_ffi_traits_for_tuple!(6; 0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5);
+impl_ffi_traits_for_tuple!(7; 0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6);
+impl_ffi_traits_for_tuple!(8; 0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7);
+impl_ffi_traits_for_tuple!(9; 0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8);
+impl_ffi_traits_for_tuple!(10; 0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9);
+impl_ffi_traits_for_tuple!(11; 0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10);
+impl_ffi_traits_for_tuple!(12; 0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11);
+
 // For Arc we derive all the traits, but have to write it all out because we need an unsized T bound
 derive_ffi_traits!(impl<T, UT> Lower<UT> for Arc<T> where Arc<T>: FfiConverter<UT>, T: ?Sized);
 derive_ffi_traits!(impl<T, UT> Lift<UT> for Arc<T> where Arc<T>: FfiConverter<UT>, T: ?Sized);
@@ -556,6 +805,23 @@ unsafe impl<UT> LiftReturn<UT> for () {
     }
 }
 
+// Closures always return a `RustBuffer`, unit values serialize to nothing.
+
+unsafe impl<UT> LowerClosureReturn<UT> for () {
+    fn lower_closure_return(_: ()) -> RustBuffer {
+        RustBuffer::new()
+    }
+}
+
+unsafe impl<UT> LiftClosureReturn<UT> for () {
+    fn try_lift_closure_return(buf: RustBuffer) -> Result<()> {
+        match buf.destroy_into_vec().len() {
+            0 => Ok(()),
+            n => bail!("junk data left in buffer after lifting (count: {n})"),
+        }
+    }
+}
+
 impl<UT> TypeId<UT> for () {
     const TYPE_ID_META: MetadataBuffer = MetadataBuffer::from_code(metadata::codes::TYPE_UNIT);
 }
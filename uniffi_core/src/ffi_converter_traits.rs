@@ -26,6 +26,8 @@
 //! There's also:
 //!   - [TypeId], which is implemented for all types that implement any of the above traits.
 //!   - [ConvertError], which is implement for errors that can be used in callback interfaces.
+//!   - [LowerClosureReturn] and [LiftClosureReturn], which are derived alongside [LowerReturn]
+//!     and [LiftReturn] and handle closure return values.
 //!
 //! The `derive_ffi_traits` macro can be used to derive the specific traits from the general ones.
 //! Here's the main ways we implement these traits:
@@ -395,6 +397,41 @@ pub unsafe trait LiftReturn<UT>: Sized {
     }
 }
 
+/// Return values from Rust closures
+///
+/// Closures are type-erased, so their return values are always serialized into a `RustBuffer`.
+/// This is derived alongside [LowerReturn] for types that implement [Lower] and implemented for
+/// `()`, which serializes to nothing.
+///
+/// ## Safety
+///
+/// All traits are unsafe (implementing it requires `unsafe impl`) because we can't guarantee
+/// that it's safe to pass your type out to foreign-language code and back again. Buggy
+/// implementations of this trait might violate some assumptions made by the generated code,
+/// or might not match with the corresponding code in the generated foreign-language bindings.
+/// These traits should not be used directly, only in generated code, and the generated code should
+/// have fixture tests to test that everything works correctly together.
+pub unsafe trait LowerClosureReturn<UT>: Sized {
+    fn lower_closure_return(v: Self) -> RustBuffer;
+}
+
+/// Return values from foreign closures
+///
+/// This is the counterpart of [LowerClosureReturn].  It's derived alongside [LiftReturn] for types
+/// that implement [Lift] and implemented for `()`.
+///
+/// ## Safety
+///
+/// All traits are unsafe (implementing it requires `unsafe impl`) because we can't guarantee
+/// that it's safe to pass your type out to foreign-language code and back again. Buggy
+/// implementations of this trait might violate some assumptions made by the generated code,
+/// or might not match with the corresponding code in the generated foreign-language bindings.
+/// These traits should not be used directly, only in generated code, and the generated code should
+/// have fixture tests to test that everything works correctly together.
+pub unsafe trait LiftClosureReturn<UT>: Sized {
+    fn try_lift_closure_return(buf: RustBuffer) -> Result<Self>;
+}
+
 /// Lift references
 ///
 /// This is usually derived from [Lift] and also implemented for the inner `T` value of smart
@@ -411,6 +448,11 @@ pub unsafe trait LiftReturn<UT>: Sized {
 /// `&T` using the Arc.
 pub unsafe trait LiftRef<UT> {
     type LiftType: Lift<UT> + Borrow<Self>;
+
+    /// True if `LiftType` holds the object's lock, which is the case for objects with
+    /// `#[uniffi(mutex)]` or `#[uniffi(rwlock)]`.  Async methods can't be exported on these
+    /// objects, since the lock would be held while the future is suspended.
+    const LOCKED: bool = false;
 }
 
 /// Type ID metadata
@@ -572,6 +614,13 @@ macro_rules! derive_ffi_traits {
                 ::std::result::Result::Ok(<Self as $crate::Lower<$ut>>::lower(v))
             }
         }
+
+        unsafe impl $(<$($generic),*>)* $crate::LowerClosureReturn<$ut> for $ty $(where $($where)*)*
+        {
+            fn lower_closure_return(v: Self) -> $crate::RustBuffer {
+                <Self as $crate::Lower<$ut>>::lower_into_rust_buffer(v)
+            }
+        }
     };
 
     (impl $(<$($generic:ident),*>)? $(::uniffi::)? LowerError<$ut:path> for $ty:ty $(where $($where:tt)*)?) => {
@@ -592,6 +641,13 @@ macro_rules! derive_ffi_traits {
                 <Self as $crate::Lift<$ut>>::try_lift(v)
             }
         }
+
+        unsafe impl $(<$($generic),*>)* $crate::LiftClosureReturn<$ut> for $ty $(where $($where)*)*
+        {
+            fn try_lift_closure_return(buf: $crate::RustBuffer) -> $crate::Result<Self> {
+                <Self as $crate::Lift<$ut>>::try_lift_from_rust_buffer(buf)
+            }
+        }
     };
 
     (impl $(<$($generic:ident),*>)? $(::uniffi::)? LiftRef<$ut:path> for $ty:ty $(where $($where:tt)*)?) => {
@@ -82,17 +82,23 @@ pub mod ffi;
 mod ffi_converter_impls;
 mod ffi_converter_traits;
 pub mod metadata;
+mod object_lock;
 pub mod oneshot;
+#[doc(hidden)]
+pub mod optional_builtins;
+mod owned_bytes;
 
 pub use arc_or_owned::ArcOrOwned;
 pub use custom_types::CustomType;
+pub use object_lock::{LockedObject, ObjectLock, ObjectReadGuard, ObjectWriteGuard};
+pub use owned_bytes::OwnedBytes;
 
 #[cfg(feature = "scaffolding-ffi-buffer-fns")]
 pub use ffi::ffiserialize::FfiBufferElement;
 pub use ffi::*;
 pub use ffi_converter_traits::{
-    ConvertError, FfiConverter, FfiConverterArc, HandleAlloc, Lift, LiftRef, LiftReturn, Lower,
-    LowerError, LowerReturn, TypeId,
+    ConvertError, FfiConverter, FfiConverterArc, HandleAlloc, Lift, LiftClosureReturn, LiftRef,
+    LiftReturn, Lower, LowerClosureReturn, LowerError, LowerReturn, TypeId,
 };
 pub use metadata::*;
 
@@ -105,7 +111,15 @@ pub mod deps {
     pub use async_compat;
     pub use async_trait;
     pub use bytes;
+    #[cfg(feature = "chrono")]
+    pub use chrono;
     pub use static_assertions;
+    #[cfg(feature = "time")]
+    pub use time;
+    #[cfg(feature = "url")]
+    pub use url;
+    #[cfg(feature = "uuid")]
+    pub use uuid;
 }
 
 const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -229,7 +243,7 @@ macro_rules! ffi_converter_rust_buffer_lift_and_lower {
 
 #[cfg(test)]
 mod test {
-    use super::{FfiConverter, UniFfiTag};
+    use super::{FfiConverter, Lift, Lower, OwnedBytes, UniFfiTag};
     use std::time::{Duration, SystemTime};
 
     #[test]
@@ -256,6 +270,84 @@ mod test {
             "Expected results after lowering and lifting to be equal"
         )
     }
+
+    #[test]
+    fn int128_roundtrip() {
+        for expected in [u128::MIN, 1, u64::MAX as u128 + 1, u128::MAX] {
+            let result =
+                <u128 as FfiConverter<UniFfiTag>>::try_lift(
+                    <u128 as FfiConverter<UniFfiTag>>::lower(expected),
+                )
+                .expect("Failed to lift!");
+            assert_eq!(expected, result);
+        }
+        for expected in [i128::MIN, -1, i64::MIN as i128 - 1, i128::MAX] {
+            let result =
+                <i128 as FfiConverter<UniFfiTag>>::try_lift(
+                    <i128 as FfiConverter<UniFfiTag>>::lower(expected),
+                )
+                .expect("Failed to lift!");
+            assert_eq!(expected, result);
+        }
+    }
+
+    #[test]
+    fn owned_bytes_lower_without_copying() {
+        let bytes = OwnedBytes(vec![1, 2, 3]);
+        let data = bytes.as_ptr();
+        let rbuf = <OwnedBytes as FfiConverter<UniFfiTag>>::lower(bytes);
+        assert_eq!(rbuf.data_pointer(), data);
+        assert_eq!(rbuf.len(), 3);
+        let result = <OwnedBytes as FfiConverter<UniFfiTag>>::try_lift(rbuf).unwrap();
+        assert_eq!(result.as_ptr(), data);
+        assert_eq!(result, OwnedBytes(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn owned_bytes_serialize_like_vec() {
+        let mut buf = vec![];
+        <OwnedBytes as FfiConverter<UniFfiTag>>::write(OwnedBytes(vec![1, 2, 3]), &mut buf);
+        let mut vec_buf = vec![];
+        <Vec<u8> as Lower<UniFfiTag>>::write(vec![1, 2, 3], &mut vec_buf);
+        assert_eq!(buf, vec_buf);
+        let result = <OwnedBytes as FfiConverter<UniFfiTag>>::try_read(&mut buf.as_slice());
+        assert_eq!(result.unwrap(), OwnedBytes(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn tuple_roundtrip() {
+        let expected = ("hello".to_string(), 42u32, Some(true));
+        let rbuf = <(String, u32, Option<bool>) as Lower<UniFfiTag>>::lower(expected.clone());
+        let result = <(String, u32, Option<bool>) as Lift<UniFfiTag>>::try_lift(rbuf)
+            .expect("Failed to lift!");
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn tuple_serialize_elements_in_order() {
+        let mut buf = vec![];
+        <(u8, i16) as Lower<UniFfiTag>>::write((1, -2), &mut buf);
+        assert_eq!(buf, vec![1, 0xff, 0xfe]);
+    }
+
+    #[test]
+    fn array_roundtrip() {
+        let expected = [1u32, 2, 3];
+        let rbuf = <[u32; 3] as Lower<UniFfiTag>>::lower(expected);
+        let result = <[u32; 3] as Lift<UniFfiTag>>::try_lift(rbuf).expect("Failed to lift!");
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn array_length_mismatch() {
+        let mut buf = vec![];
+        <Vec<u8> as Lower<UniFfiTag>>::write(vec![1, 2, 3], &mut buf);
+        let result = <[u8; 4] as Lift<UniFfiTag>>::try_read(&mut buf.as_slice());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Expected an array of length 4, but got 3 items"
+        );
+    }
 }
 
 #[cfg(test)]
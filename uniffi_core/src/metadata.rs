@@ -41,6 +41,8 @@ pub mod codes {
     pub const TRAIT_INTERFACE: u8 = 12;
     pub const OBJECT_TRAIT_IMPL: u8 = 14;
     pub const CUSTOM_TYPE: u8 = 15;
+    pub const STATIC_METHOD: u8 = 16;
+    pub const CONSTANT: u8 = 17;
 
     // Type codes
     pub const TYPE_U8: u8 = 0;
@@ -70,6 +72,16 @@ pub mod codes {
     pub const TYPE_TRAIT_INTERFACE: u8 = 24;
     pub const TYPE_BOX: u8 = 26;
     pub const TYPE_HASH_SET: u8 = 27;
+    pub const TYPE_U128: u8 = 28;
+    pub const TYPE_I128: u8 = 29;
+    pub const TYPE_STREAM: u8 = 30;
+    pub const TYPE_OWNED_BYTES: u8 = 31;
+    pub const TYPE_TUPLE: u8 = 32;
+    pub const TYPE_CLOSURE: u8 = 33;
+    pub const TYPE_ARRAY: u8 = 34;
+    pub const TYPE_UUID: u8 = 35;
+    pub const TYPE_URL: u8 = 36;
+    pub const TYPE_DATE: u8 = 37;
     pub const TYPE_UNIT: u8 = 255;
 
     // TraitKind sub-codes.
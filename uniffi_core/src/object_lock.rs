@@ -0,0 +1,388 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Support for objects declared with `#[uniffi(mutex)]` or `#[uniffi(rwlock)]`
+//!
+//! Regular objects are shared with the foreign code as an `Arc<T>`, which is why they need to be
+//! `Sync` and can only have `&self` methods.  Objects with a lock attribute are shared as an
+//! `Arc<ObjectLock<T>>` instead.  When a method is called, the scaffolding lifts a guard for the
+//! lock, which is held for the duration of the call.  `&mut self` methods get an exclusive guard,
+//! `&self` methods get a shared guard for `rwlock` objects and an exclusive guard for `mutex`
+//! objects.  Async methods would hold the guard while the future is suspended, so the generated
+//! code rejects them using [crate::LiftRef::LOCKED].
+//!
+//! Calls from the foreign code often come back into Rust on the same thread, for example when a
+//! method invokes a callback interface that calls another method on the object.  With a regular
+//! lock, this would deadlock.  `ObjectLock` tracks which threads hold it and fails the call
+//! instead.
+
+use std::{
+    borrow::{Borrow, BorrowMut},
+    cell::UnsafeCell,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+    thread::ThreadId,
+};
+
+use anyhow::bail;
+
+use crate::{check_remaining, FfiConverterArc, Handle, Lift, MetadataBuffer, Result, TypeId};
+
+/// Objects that are shared with the foreign code inside an [ObjectLock]
+///
+/// This is implemented by `#[derive(uniffi::Object)]` for objects with a `#[uniffi(mutex)]` or
+/// `#[uniffi(rwlock)]` attribute.
+///
+/// ## Safety
+///
+/// If `SHARED_READS` is true, then multiple threads can access the object through `&self` at the
+/// same time, so `Self` must be `Sync`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` doesn't have a lock, so it can't have `&mut self` methods",
+    note = "add `#[uniffi(mutex)]` or `#[uniffi(rwlock)]` to the object"
+)]
+pub unsafe trait LockedObject: Send + 'static {
+    /// Name of the object, used in error messages
+    const NAME: &'static str;
+    /// Allow multiple `&self` calls at the same time, like a `RwLock`
+    const SHARED_READS: bool;
+}
+
+/// Lock that wraps objects declared with `#[uniffi(mutex)]` or `#[uniffi(rwlock)]`
+///
+/// Exported functions can pass these objects as `Arc<ObjectLock<T>>`, use [ObjectLock::read]
+/// and [ObjectLock::write] to access the object from Rust.
+pub struct ObjectLock<T> {
+    state: Mutex<LockState>,
+    released: Condvar,
+    value: UnsafeCell<T>,
+}
+
+#[derive(Default)]
+struct LockState {
+    /// Thread holding the exclusive lock
+    writer: Option<ThreadId>,
+    /// Threads holding a shared lock, repeated if a thread holds more than one
+    readers: Vec<ThreadId>,
+    /// Set when a thread panics while holding the exclusive lock
+    poisoned: bool,
+}
+
+// Safety: the lock only hands out `&mut T` to one thread at a time.  `&T` is only handed out to
+// multiple threads if `T::SHARED_READS` is true, which requires `T: Sync`.
+unsafe impl<T: LockedObject> Sync for ObjectLock<T> {}
+
+impl<T: LockedObject> ObjectLock<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            state: Mutex::default(),
+            released: Condvar::new(),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consume the lock, returning the object
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+
+    /// Lock the object for a `&self` call
+    ///
+    /// This blocks until the lock is available and fails if the current thread already holds an
+    /// exclusive lock, or if the object was poisoned.
+    pub fn read(self: &Arc<Self>) -> Result<ObjectReadGuard<T>> {
+        let exclusive = !T::SHARED_READS;
+        Ok(ObjectReadGuard {
+            thread: self.acquire(exclusive)?,
+            lock: Arc::clone(self),
+            exclusive,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Lock the object for a `&mut self` call
+    ///
+    /// This blocks until the lock is available and fails if the current thread already holds any
+    /// lock, or if the object was poisoned.
+    pub fn write(self: &Arc<Self>) -> Result<ObjectWriteGuard<T>> {
+        Ok(ObjectWriteGuard {
+            thread: self.acquire(true)?,
+            lock: Arc::clone(self),
+            _marker: PhantomData,
+        })
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, LockState> {
+        // `LockState` is always consistent, so it's fine to ignore poisoning.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn acquire(&self, exclusive: bool) -> Result<ThreadId> {
+        let thread = std::thread::current().id();
+        let mut state = self.lock_state();
+        loop {
+            if state.poisoned {
+                bail!("{} was poisoned by a panic in an earlier call", T::NAME);
+            }
+            if state.writer == Some(thread) || (exclusive && state.readers.contains(&thread)) {
+                bail!(
+                    "{} is already locked by this thread, was it called re-entrantly?",
+                    T::NAME
+                );
+            }
+            if state.writer.is_none() && (!exclusive || state.readers.is_empty()) {
+                break;
+            }
+            state = self
+                .released
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        if exclusive {
+            state.writer = Some(thread);
+        } else {
+            state.readers.push(thread);
+        }
+        Ok(thread)
+    }
+
+    fn release(&self, thread: ThreadId, exclusive: bool) {
+        let mut state = self.lock_state();
+        if exclusive {
+            state.writer = None;
+            if std::thread::panicking() {
+                state.poisoned = true;
+            }
+        } else if let Some(i) = state.readers.iter().position(|t| *t == thread) {
+            state.readers.swap_remove(i);
+        }
+        drop(state);
+        self.released.notify_all();
+    }
+}
+
+/// Guard for `&self` calls, see [ObjectLock::read]
+pub struct ObjectReadGuard<T: LockedObject> {
+    lock: Arc<ObjectLock<T>>,
+    thread: ThreadId,
+    exclusive: bool,
+    // Only `Sync` if `T` is
+    _marker: PhantomData<T>,
+}
+
+impl<T: LockedObject> Deref for ObjectReadGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // Safety: we hold a shared lock, so nothing has a `&mut T`
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T: LockedObject> Borrow<T> for ObjectReadGuard<T> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T: LockedObject> Drop for ObjectReadGuard<T> {
+    fn drop(&mut self) {
+        self.lock.release(self.thread, self.exclusive);
+    }
+}
+
+/// Guard for `&mut self` calls, see [ObjectLock::write]
+pub struct ObjectWriteGuard<T: LockedObject> {
+    lock: Arc<ObjectLock<T>>,
+    thread: ThreadId,
+    // Only `Sync` if `T` is
+    _marker: PhantomData<T>,
+}
+
+impl<T: LockedObject> Deref for ObjectWriteGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // Safety: we hold the exclusive lock
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T: LockedObject> DerefMut for ObjectWriteGuard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // Safety: we hold the exclusive lock
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T: LockedObject> Borrow<T> for ObjectWriteGuard<T> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T: LockedObject> BorrowMut<T> for ObjectWriteGuard<T> {
+    fn borrow_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T: LockedObject> Drop for ObjectWriteGuard<T> {
+    fn drop(&mut self) {
+        self.lock.release(self.thread, true);
+    }
+}
+
+/// The handle for locked objects points to an `Arc<ObjectLock<T>>`.
+///
+/// This works like the `FfiConverterArc` impl that `#[derive(uniffi::Object)]` generates for
+/// regular objects.
+unsafe impl<T, UT> FfiConverterArc<UT> for ObjectLock<T>
+where
+    T: LockedObject + TypeId<UT>,
+{
+    type FfiType = Handle;
+
+    fn lower(obj: Arc<Self>) -> Handle {
+        crate::trace!("lower: {} {:?}", T::NAME, Arc::as_ptr(&obj));
+        Handle::from_arc(obj)
+    }
+
+    fn try_lift(handle: Handle) -> Result<Arc<Self>> {
+        crate::trace!("lift: {} ({:?})", T::NAME, handle);
+        Ok(unsafe { handle.into_arc() })
+    }
+
+    fn write(obj: Arc<Self>, buf: &mut Vec<u8>) {
+        bytes::BufMut::put_u64(buf, <Self as FfiConverterArc<UT>>::lower(obj).as_raw());
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<Arc<Self>> {
+        check_remaining(buf, 8)?;
+        <Self as FfiConverterArc<UT>>::try_lift(Handle::from_raw_unchecked(bytes::Buf::get_u64(
+            buf,
+        )))
+    }
+
+    const TYPE_ID_META: MetadataBuffer = <T as TypeId<UT>>::TYPE_ID_META;
+}
+
+/// Lift guards by lifting the `Arc<ObjectLock<T>>`, then locking it
+macro_rules! impl_lift_for_guard {
+    ($guard:ident, $lock_fn:ident) => {
+        unsafe impl<T, UT> Lift<UT> for $guard<T>
+        where
+            T: LockedObject + TypeId<UT>,
+        {
+            type FfiType = Handle;
+
+            fn try_lift(handle: Handle) -> Result<Self> {
+                <Arc<ObjectLock<T>> as Lift<UT>>::try_lift(handle)?.$lock_fn()
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<Self> {
+                <Arc<ObjectLock<T>> as Lift<UT>>::try_read(buf)?.$lock_fn()
+            }
+        }
+
+        impl<T, UT> TypeId<UT> for $guard<T>
+        where
+            T: LockedObject + TypeId<UT>,
+        {
+            const TYPE_ID_META: MetadataBuffer = <T as TypeId<UT>>::TYPE_ID_META;
+        }
+    };
+}
+
+impl_lift_for_guard!(ObjectReadGuard, read);
+impl_lift_for_guard!(ObjectWriteGuard, write);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Mutexed(u32);
+
+    unsafe impl LockedObject for Mutexed {
+        const NAME: &'static str = "Mutexed";
+        const SHARED_READS: bool = false;
+    }
+
+    struct RwLocked(u32);
+
+    unsafe impl LockedObject for RwLocked {
+        const NAME: &'static str = "RwLocked";
+        const SHARED_READS: bool = true;
+    }
+
+    #[test]
+    fn test_mutex() {
+        let lock = Arc::new(ObjectLock::new(Mutexed(0)));
+        lock.write().unwrap().0 += 1;
+        let guard = lock.read().unwrap();
+        assert_eq!(guard.0, 1);
+        // Mutex objects are always locked exclusively
+        assert!(lock.read().is_err());
+        assert!(lock.write().is_err());
+        drop(guard);
+        assert!(lock.read().is_ok());
+    }
+
+    #[test]
+    fn test_rwlock() {
+        let lock = Arc::new(ObjectLock::new(RwLocked(0)));
+        let guard = lock.read().unwrap();
+        // Nested reads are fine, but not writes
+        assert_eq!(lock.read().unwrap().0, 0);
+        assert!(lock.write().is_err());
+        drop(guard);
+        let mut guard = lock.write().unwrap();
+        guard.0 = 1;
+        assert!(lock.read().is_err());
+        drop(guard);
+        assert_eq!(lock.read().unwrap().0, 1);
+    }
+
+    #[test]
+    fn test_threads() {
+        let lock = Arc::new(ObjectLock::new(Mutexed(0)));
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let lock = Arc::clone(&lock);
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        lock.write().unwrap().0 += 1;
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(lock.read().unwrap().0, 4000);
+    }
+
+    #[test]
+    fn test_poison() {
+        let lock = Arc::new(ObjectLock::new(RwLocked(0)));
+        let lock2 = Arc::clone(&lock);
+        std::thread::spawn(move || {
+            let _guard = lock2.read().unwrap();
+            panic!("panic while holding a shared lock");
+        })
+        .join()
+        .unwrap_err();
+        assert!(lock.read().is_ok());
+
+        let lock2 = Arc::clone(&lock);
+        std::thread::spawn(move || {
+            let _guard = lock2.write().unwrap();
+            panic!("panic while holding the exclusive lock");
+        })
+        .join()
+        .unwrap_err();
+        assert!(lock.read().is_err());
+    }
+}
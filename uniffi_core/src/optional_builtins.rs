@@ -0,0 +1,310 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Support for types from popular third-party crates.
+//!
+//! Each of these is behind a cargo feature named after the crate, so consumers only pull in the
+//! dependencies they actually use.  The foreign bindings map these types to their native
+//! equivalents, for example `uuid::Uuid` becomes `java.util.UUID` in Kotlin.
+//!
+//! Enabling a feature doesn't implement anything by itself.  Each crate opts in with
+//! [use_builtin_types](crate::use_builtin_types), which implements the FFI traits for its local
+//! `UniFfiTag`.  That keeps the features additive: a crate that defines a custom type for
+//! `url::Url` keeps compiling when another crate in the build enables the `url` feature.
+//!
+//! Date-time types are passed exactly like `SystemTime`, so the bindings see them as a plain
+//! `Timestamp`.
+
+#[allow(unused_imports)]
+use crate::{check_remaining, Lift, Lower, Result, UniFfiTag};
+#[allow(unused_imports)]
+use anyhow::anyhow;
+#[allow(unused_imports)]
+use bytes::buf::{Buf, BufMut};
+
+/// Implement the FFI traits for types from third-party crates, for the local `UniFfiTag`
+///
+/// Each argument is the name of a crate, which must also be enabled as a `uniffi` cargo feature:
+///
+///   * `uuid`: `uuid::Uuid`
+///   * `url`: `url::Url`
+///   * `chrono`: `chrono::DateTime<Utc>` and `chrono::NaiveDate`
+///   * `time`: `time::OffsetDateTime`
+///
+/// ```ignore
+/// uniffi::use_builtin_types!(uuid, chrono);
+/// ```
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! use_builtin_types {
+    ($($krate:ident),+ $(,)?) => {
+        $($crate::use_builtin_types!(@crate $krate);)+
+    };
+
+    (@crate uuid) => {
+        $crate::use_builtin_types!(
+            @impl $crate::deps::uuid::Uuid,
+            write_uuid,
+            read_uuid,
+            TYPE_UUID
+        );
+    };
+
+    (@crate url) => {
+        $crate::use_builtin_types!(@impl $crate::deps::url::Url, write_url, read_url, TYPE_URL);
+    };
+
+    (@crate chrono) => {
+        $crate::use_builtin_types!(
+            @impl $crate::deps::chrono::DateTime<$crate::deps::chrono::Utc>,
+            write_chrono_date_time,
+            read_chrono_date_time,
+            TYPE_SYSTEM_TIME
+        );
+        $crate::use_builtin_types!(
+            @impl $crate::deps::chrono::NaiveDate,
+            write_chrono_naive_date,
+            read_chrono_naive_date,
+            TYPE_DATE
+        );
+    };
+
+    (@crate time) => {
+        $crate::use_builtin_types!(
+            @impl $crate::deps::time::OffsetDateTime,
+            write_offset_date_time,
+            read_offset_date_time,
+            TYPE_SYSTEM_TIME
+        );
+    };
+
+    (@crate $other:ident) => {
+        ::std::compile_error!(::std::concat!(
+            "Unknown builtin type crate: `",
+            ::std::stringify!($other),
+            "`, expected one of `uuid`, `url`, `chrono` or `time`",
+        ));
+    };
+
+    (@impl $ty:ty, $write:ident, $read:ident, $code:ident) => {
+        unsafe impl $crate::FfiConverter<crate::UniFfiTag> for $ty {
+            $crate::ffi_converter_rust_buffer_lift_and_lower!(crate::UniFfiTag);
+
+            fn write(obj: $ty, buf: &mut ::std::vec::Vec<u8>) {
+                $crate::optional_builtins::$write(obj, buf)
+            }
+
+            fn try_read(buf: &mut &[u8]) -> $crate::Result<$ty> {
+                $crate::optional_builtins::$read(buf)
+            }
+
+            const TYPE_ID_META: $crate::MetadataBuffer =
+                $crate::MetadataBuffer::from_code($crate::metadata::codes::$code);
+        }
+
+        $crate::derive_ffi_traits!(local $ty);
+    };
+}
+
+/// UUIDs are represented on the buffer by their 16 bytes, in big-endian order.
+#[cfg(feature = "uuid")]
+pub fn write_uuid(obj: uuid::Uuid, buf: &mut Vec<u8>) {
+    buf.put_slice(obj.as_bytes());
+}
+
+#[cfg(feature = "uuid")]
+pub fn read_uuid(buf: &mut &[u8]) -> Result<uuid::Uuid> {
+    check_remaining(buf, 16)?;
+    let mut bytes = [0u8; 16];
+    buf.copy_to_slice(&mut bytes);
+    Ok(uuid::Uuid::from_bytes(bytes))
+}
+
+/// URLs are represented on the buffer like a `String`.  Lifting a string that isn't a valid URL
+/// fails.
+#[cfg(feature = "url")]
+pub fn write_url(obj: url::Url, buf: &mut Vec<u8>) {
+    <String as Lower<UniFfiTag>>::write(obj.into(), buf);
+}
+
+#[cfg(feature = "url")]
+pub fn read_url(buf: &mut &[u8]) -> Result<url::Url> {
+    Ok(url::Url::parse(&<String as Lift<UniFfiTag>>::try_read(
+        buf,
+    )?)?)
+}
+
+#[cfg(feature = "chrono")]
+pub fn write_chrono_date_time(obj: chrono::DateTime<chrono::Utc>, buf: &mut Vec<u8>) {
+    <std::time::SystemTime as Lower<UniFfiTag>>::write(obj.into(), buf);
+}
+
+/// Lifting a timestamp that `DateTime` can't represent fails.
+#[cfg(feature = "chrono")]
+pub fn read_chrono_date_time(buf: &mut &[u8]) -> Result<chrono::DateTime<chrono::Utc>> {
+    use chrono::TimeZone;
+    let nanos = read_timestamp_nanos(buf)?;
+    let seconds = i64::try_from(nanos.div_euclid(NANOS_PER_SECOND))?;
+    let subsec_nanos = nanos.rem_euclid(NANOS_PER_SECOND) as u32;
+    chrono::Utc
+        .timestamp_opt(seconds, subsec_nanos)
+        .single()
+        .ok_or_else(|| anyhow!("Timestamp out of range: {nanos} nanoseconds since the epoch"))
+}
+
+/// Dates are represented on the buffer by an i32 that counts the days since 1970-01-01.
+#[cfg(feature = "chrono")]
+pub fn write_chrono_naive_date(obj: chrono::NaiveDate, buf: &mut Vec<u8>) {
+    use chrono::Datelike;
+    buf.put_i32(obj.num_days_from_ce() - DAYS_FROM_CE_TO_UNIX_EPOCH);
+}
+
+#[cfg(feature = "chrono")]
+pub fn read_chrono_naive_date(buf: &mut &[u8]) -> Result<chrono::NaiveDate> {
+    check_remaining(buf, 4)?;
+    let days = buf.get_i32();
+    days.checked_add(DAYS_FROM_CE_TO_UNIX_EPOCH)
+        .and_then(chrono::NaiveDate::from_num_days_from_ce_opt)
+        .ok_or_else(|| anyhow!("Date out of range: {days} days since the epoch"))
+}
+
+/// Days from 0001-01-01 (day 1 of the common era) to 1970-01-01
+#[cfg(feature = "chrono")]
+const DAYS_FROM_CE_TO_UNIX_EPOCH: i32 = 719_163;
+
+/// Only the instant is passed across the FFI, lifted values always have a UTC offset.
+#[cfg(feature = "time")]
+pub fn write_offset_date_time(obj: time::OffsetDateTime, buf: &mut Vec<u8>) {
+    <std::time::SystemTime as Lower<UniFfiTag>>::write(obj.into(), buf);
+}
+
+/// Lifting a timestamp that `OffsetDateTime` can't represent fails.
+#[cfg(feature = "time")]
+pub fn read_offset_date_time(buf: &mut &[u8]) -> Result<time::OffsetDateTime> {
+    Ok(time::OffsetDateTime::from_unix_timestamp_nanos(
+        read_timestamp_nanos(buf)?,
+    )?)
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Read a timestamp written by the `SystemTime` FFI converter, as nanoseconds since the epoch
+///
+/// `SystemTime` panics when the result is out of range, so this decodes the value directly.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn read_timestamp_nanos(buf: &mut &[u8]) -> Result<i128> {
+    check_remaining(buf, 12)?;
+    let seconds = buf.get_i64();
+    let nanos = buf.get_u32();
+    if i128::from(nanos) >= NANOS_PER_SECOND {
+        anyhow::bail!("Invalid timestamp: {nanos} nanoseconds");
+    }
+    // The sign of `seconds` applies to the whole value, including the nanoseconds.
+    let magnitude = i128::from(seconds.unsigned_abs()) * NANOS_PER_SECOND + i128::from(nanos);
+    Ok(if seconds >= 0 { magnitude } else { -magnitude })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Lift, Lower, UniFfiTag};
+
+    #[cfg(feature = "uuid")]
+    crate::use_builtin_types!(uuid);
+    #[cfg(feature = "url")]
+    crate::use_builtin_types!(url);
+    #[cfg(feature = "chrono")]
+    crate::use_builtin_types!(chrono);
+    #[cfg(feature = "time")]
+    crate::use_builtin_types!(time);
+
+    #[allow(dead_code)]
+    fn roundtrip<T: Lift<UniFfiTag> + Lower<UniFfiTag>>(value: T) -> T {
+        let mut buf = vec![];
+        <T as Lower<UniFfiTag>>::write(value, &mut buf);
+        let mut slice = buf.as_slice();
+        let result = <T as Lift<UniFfiTag>>::try_read(&mut slice).expect("Failed to lift!");
+        assert!(slice.is_empty());
+        result
+    }
+
+    /// Serialize a timestamp the same way the `SystemTime` converter does
+    #[allow(dead_code)]
+    fn timestamp_buf(seconds: i64, nanos: u32) -> Vec<u8> {
+        let mut buf = seconds.to_be_bytes().to_vec();
+        buf.extend(nanos.to_be_bytes());
+        buf
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_roundtrip() {
+        let uuid = uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        assert_eq!(roundtrip(uuid), uuid);
+        let mut buf = vec![];
+        <uuid::Uuid as Lower<UniFfiTag>>::write(uuid, &mut buf);
+        assert_eq!(buf[..2], [0x67, 0xe5]);
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn url_roundtrip() {
+        let url = url::Url::parse("https://example.com/path?q=1").unwrap();
+        assert_eq!(roundtrip(url.clone()), url);
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn invalid_url() {
+        let mut buf = vec![];
+        <String as Lower<UniFfiTag>>::write("not a url".to_string(), &mut buf);
+        assert!(<url::Url as Lift<UniFfiTag>>::try_read(&mut buf.as_slice()).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_roundtrip() {
+        use chrono::TimeZone;
+        let datetime = chrono::Utc.timestamp_opt(-1_000_000, 123_456_789).unwrap();
+        assert_eq!(roundtrip(datetime), datetime);
+
+        let date = chrono::NaiveDate::from_ymd_opt(1969, 7, 20).unwrap();
+        assert_eq!(roundtrip(date), date);
+        let mut buf = vec![];
+        <chrono::NaiveDate as Lower<UniFfiTag>>::write(date, &mut buf);
+        assert_eq!(buf, (-165i32).to_be_bytes());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_out_of_range() {
+        type DateTime = chrono::DateTime<chrono::Utc>;
+        let buf = timestamp_buf(i64::MAX, 0);
+        assert!(<DateTime as Lift<UniFfiTag>>::try_read(&mut buf.as_slice()).is_err());
+        let buf = timestamp_buf(i64::MIN, 999_999_999);
+        assert!(<DateTime as Lift<UniFfiTag>>::try_read(&mut buf.as_slice()).is_err());
+        let buf = timestamp_buf(0, 1_000_000_000);
+        assert!(<DateTime as Lift<UniFfiTag>>::try_read(&mut buf.as_slice()).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_roundtrip() {
+        let datetime =
+            time::OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_123_456_789).unwrap();
+        assert_eq!(roundtrip(datetime), datetime);
+        let datetime = time::OffsetDateTime::from_unix_timestamp_nanos(-1_500_000_000).unwrap();
+        assert_eq!(roundtrip(datetime), datetime);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_out_of_range() {
+        type OffsetDateTime = time::OffsetDateTime;
+        let buf = timestamp_buf(i64::MAX, 0);
+        assert!(<OffsetDateTime as Lift<UniFfiTag>>::try_read(&mut buf.as_slice()).is_err());
+        let buf = timestamp_buf(i64::MIN, 0);
+        assert!(<OffsetDateTime as Lift<UniFfiTag>>::try_read(&mut buf.as_slice()).is_err());
+    }
+}
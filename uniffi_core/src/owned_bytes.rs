@@ -0,0 +1,52 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::ops::{Deref, DerefMut};
+
+/// Owned bytes that are passed across the FFI without copying
+///
+/// `Vec<u8>` values are serialized into a fresh buffer when they cross the FFI, which means
+/// the data gets copied on both sides. When an `OwnedBytes` is returned from Rust, the
+/// underlying `Vec<u8>` is handed to the foreign code as a `RustBuffer`, which wraps the
+/// allocation directly and frees it once the foreign object is no longer used.
+/// When passed as an argument, the foreign code allocates the `RustBuffer` and Rust takes
+/// ownership of it.
+///
+/// This only applies when the value is passed directly. When `OwnedBytes` is nested inside
+/// another type (a record field, `Option<OwnedBytes>`, etc.) it's serialized the same way
+/// `Vec<u8>` is.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OwnedBytes(pub Vec<u8>);
+
+impl OwnedBytes {
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for OwnedBytes {
+    fn from(v: Vec<u8>) -> Self {
+        Self(v)
+    }
+}
+
+impl From<OwnedBytes> for Vec<u8> {
+    fn from(bytes: OwnedBytes) -> Self {
+        bytes.0
+    }
+}
+
+impl Deref for OwnedBytes {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Vec<u8> {
+        &self.0
+    }
+}
+
+impl DerefMut for OwnedBytes {
+    fn deref_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.0
+    }
+}
@@ -105,7 +105,7 @@ impl DeriveOptions {
     }
 
     /// Generate a call to `derive_ffi_traits!` that will derive all the FFI traits
-    pub fn derive_all_ffi_traits(&self, ty: &Ident) -> TokenStream {
+    pub fn derive_all_ffi_traits(&self, ty: &impl ToTokens) -> TokenStream {
         if self.local_tag {
             quote! { ::uniffi::derive_ffi_traits!(local #ty); }
         } else {
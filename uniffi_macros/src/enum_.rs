@@ -8,6 +8,7 @@ use syn::{
 use crate::{
     default::default_value_metadata_calls,
     ffiops,
+    generics::{self, GenericInstance},
     record::FieldAttributeArguments,
     util::{
         create_metadata_items, either_attribute_arg, extract_docstring, ident_to_string, kw,
@@ -26,10 +27,11 @@ pub struct EnumItem {
     discr_type: Option<Ident>,
     non_exhaustive: bool,
     attr: EnumAttr,
+    instance: Option<GenericInstance>,
 }
 
 impl EnumItem {
-    pub fn new(input: DeriveInput) -> syn::Result<Self> {
+    pub fn new(input: DeriveInput, instance: Option<GenericInstance>) -> syn::Result<Self> {
         let enum_ = match input.data {
             Data::Enum(e) => e,
             _ => {
@@ -39,13 +41,16 @@ impl EnumItem {
                 ))
             }
         };
+        let attr: EnumAttr = input.attrs.parse_uniffi_attr_args()?;
+        generics::check_not_renamed(&attr.name, instance.as_ref())?;
         Ok(Self {
             enum_,
             ident: input.ident,
             docstring: extract_docstring(&input.attrs)?,
             discr_type: Self::extract_repr(&input.attrs)?,
             non_exhaustive: Self::extract_non_exhaustive(&input.attrs),
-            attr: input.attrs.parse_uniffi_attr_args()?,
+            attr,
+            instance,
         })
     }
 
@@ -97,6 +102,10 @@ impl EnumItem {
         &self.ident
     }
 
+    pub fn self_type(&self) -> TokenStream {
+        generics::self_type(&self.ident, self.instance.as_ref())
+    }
+
     pub fn enum_(&self) -> &DataEnum {
         &self.enum_
     }
@@ -114,9 +123,10 @@ impl EnumItem {
     }
 
     pub fn foreign_name(&self) -> String {
-        match &self.attr.name {
-            Some(name) => name.clone(),
-            None => ident_to_string(&self.ident),
+        match (&self.instance, &self.attr.name) {
+            (Some(instance), _) => instance.name.clone(),
+            (None, Some(name)) => name.clone(),
+            (None, None) => ident_to_string(&self.ident),
         }
     }
 
@@ -124,6 +134,10 @@ impl EnumItem {
         orig_name_metadata(self.attr.name.is_some(), &self.ident)
     }
 
+    pub fn generic_metadata(&self) -> TokenStream {
+        GenericInstance::metadata_calls(self.instance.as_ref())
+    }
+
     pub fn is_flat_error(&self) -> bool {
         self.attr.flat_error.is_some()
     }
@@ -134,17 +148,19 @@ impl EnumItem {
 }
 
 pub fn expand_enum(input: DeriveInput, options: DeriveOptions) -> syn::Result<TokenStream> {
-    let item = EnumItem::new(input)?;
-    item.check_attributes_valid_for_enum()?;
-    let ffi_converter_impl = enum_ffi_converter_impl(&item, &options);
-
-    let meta_static_var = options
-        .generate_metadata
-        .then(|| enum_meta_static_var(&item).unwrap_or_else(syn::Error::into_compile_error));
-
-    Ok(quote! {
-        #ffi_converter_impl
-        #meta_static_var
+    generics::expand_instances(input, |input, instance| {
+        let item = EnumItem::new(input, instance)?;
+        item.check_attributes_valid_for_enum()?;
+        let ffi_converter_impl = enum_ffi_converter_impl(&item, &options);
+
+        let meta_static_var = options
+            .generate_metadata
+            .then(|| enum_meta_static_var(&item).unwrap_or_else(syn::Error::into_compile_error));
+
+        Ok(quote! {
+            #ffi_converter_impl
+            #meta_static_var
+        })
     })
 }
 
@@ -173,9 +189,9 @@ fn enum_or_error_ffi_converter_impl(
     metadata_type_code: TokenStream,
 ) -> TokenStream {
     let name = &item.foreign_name();
-    let ident = item.ident();
-    let impl_spec = options.ffi_impl_header("FfiConverter", ident);
-    let derive_ffi_traits = options.derive_all_ffi_traits(ident);
+    let self_ty = item.self_type();
+    let impl_spec = options.ffi_impl_header("FfiConverter", &self_ty);
+    let derive_ffi_traits = options.derive_all_ffi_traits(&self_ty);
     let mut write_match_arms: Vec<_> = item
         .enum_()
         .variants
@@ -274,6 +290,7 @@ fn enum_or_error_ffi_converter_impl(
 pub(crate) fn enum_meta_static_var(item: &EnumItem) -> syn::Result<TokenStream> {
     let name = &item.foreign_name();
     let orig_name_calls = &item.orig_name_metadata();
+    let generic_calls = &item.generic_metadata();
     let non_exhaustive = item.is_non_exhaustive();
     let docstring = item.docstring();
     let shape = EnumShape::Enum.as_u8();
@@ -283,6 +300,7 @@ pub(crate) fn enum_meta_static_var(item: &EnumItem) -> syn::Result<TokenStream>
             .concat_str(module_path!())
             .concat_str(#name)
             #orig_name_calls
+            #generic_calls
             .concat_value(#shape)
     };
     metadata_expr.extend(match item.discr_type() {
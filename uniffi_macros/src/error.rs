@@ -5,7 +5,7 @@ use uniffi_meta::EnumShape;
 
 use crate::{
     enum_::{rich_error_ffi_converter_impl, variant_metadata, EnumItem, VariantAttr},
-    ffiops,
+    ffiops, generics,
     util::{
         create_metadata_items, extract_docstring, ident_to_string, orig_name_metadata,
         try_metadata_value_from_usize, AttributeSliceExt,
@@ -14,29 +14,31 @@ use crate::{
 };
 
 pub fn expand_error(input: DeriveInput, options: DeriveOptions) -> syn::Result<TokenStream> {
-    let enum_item = EnumItem::new(input)?;
-    let ffi_converter_impl = error_ffi_converter_impl(&enum_item, &options)?;
-    let meta_static_var = options
-        .generate_metadata
-        .then(|| error_meta_static_var(&enum_item).unwrap_or_else(syn::Error::into_compile_error));
-
-    let variant_errors: TokenStream = enum_item
-        .enum_()
-        .variants
-        .iter()
-        .flat_map(|variant| {
-            variant
-                .fields
-                .iter()
-                .flat_map(|field| field.attrs.uniffi_attr_args_not_allowed_here())
-        })
-        .map(syn::Error::into_compile_error)
-        .collect();
+    generics::expand_instances(input, |input, instance| {
+        let enum_item = EnumItem::new(input, instance)?;
+        let ffi_converter_impl = error_ffi_converter_impl(&enum_item, &options)?;
+        let meta_static_var = options.generate_metadata.then(|| {
+            error_meta_static_var(&enum_item).unwrap_or_else(syn::Error::into_compile_error)
+        });
 
-    Ok(quote! {
-        #ffi_converter_impl
-        #meta_static_var
-        #variant_errors
+        let variant_errors: TokenStream = enum_item
+            .enum_()
+            .variants
+            .iter()
+            .flat_map(|variant| {
+                variant
+                    .fields
+                    .iter()
+                    .flat_map(|field| field.attrs.uniffi_attr_args_not_allowed_here())
+            })
+            .map(syn::Error::into_compile_error)
+            .collect();
+
+        Ok(quote! {
+            #ffi_converter_impl
+            #meta_static_var
+            #variant_errors
+        })
     })
 }
 
@@ -54,11 +56,11 @@ fn error_ffi_converter_impl(item: &EnumItem, options: &DeriveOptions) -> syn::Re
 // We lower the to_string() value unconditionally, whether the enum has associated data or not.
 fn flat_error_ffi_converter_impl(item: &EnumItem, options: &DeriveOptions) -> TokenStream {
     let name = &item.foreign_name();
-    let ident = item.ident();
-    let lower_impl_spec = options.ffi_impl_header("Lower", ident);
-    let lift_impl_spec = options.ffi_impl_header("Lift", ident);
-    let type_id_impl_spec = options.ffi_impl_header("TypeId", ident);
-    let derive_ffi_traits = options.derive_ffi_traits(ident, &["LowerError", "ConvertError"]);
+    let self_ty = item.self_type();
+    let lower_impl_spec = options.ffi_impl_header("Lower", &self_ty);
+    let lift_impl_spec = options.ffi_impl_header("Lift", &self_ty);
+    let type_id_impl_spec = options.ffi_impl_header("TypeId", &self_ty);
+    let derive_ffi_traits = options.derive_ffi_traits(&self_ty, &["LowerError", "ConvertError"]);
 
     let lower_impl = {
         let mut match_arms: Vec<_> = item
@@ -173,6 +175,7 @@ fn flat_error_ffi_converter_impl(item: &EnumItem, options: &DeriveOptions) -> To
 pub(crate) fn error_meta_static_var(item: &EnumItem) -> syn::Result<TokenStream> {
     let name = &item.foreign_name();
     let orig_name_calls = &item.orig_name_metadata();
+    let generic_calls = &item.generic_metadata();
     let non_exhaustive = item.is_non_exhaustive();
     let docstring = item.docstring();
     let flat = item.is_flat_error();
@@ -182,6 +185,7 @@ pub(crate) fn error_meta_static_var(item: &EnumItem) -> syn::Result<TokenStream>
                 .concat_str(module_path!())
                 .concat_str(#name)
                 #orig_name_calls
+                #generic_calls
                 .concat_value(#shape)
                 .concat_bool(false) // discr_type: None
     };
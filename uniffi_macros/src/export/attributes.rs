@@ -13,7 +13,7 @@ use syn::{
     punctuated::Punctuated,
     Attribute, Ident, LitStr, Meta, Path, PathArguments, PathSegment, Token,
 };
-use uniffi_meta::UniffiTraitDiscriminants;
+use uniffi_meta::{PropertyKind, UniffiTraitDiscriminants};
 
 /// Attribute arguments for `#[uniffi::export]` on traits.
 ///
@@ -305,6 +305,7 @@ impl ToTokens for AsyncRuntime {
 #[derive(Default)]
 pub(super) struct ExportedImplFnAttributes {
     pub constructor: bool,
+    pub property: Option<PropertyKind>,
     pub args: ExportFnArgs,
 }
 
@@ -367,6 +368,12 @@ impl ExportedImplFnAttributes {
                         "duplicate constructor attribute",
                     ));
                 }
+                if self.property.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "confused constructor/getter/setter attributes",
+                    ));
+                }
                 self.constructor = true;
             }
             "method" => {
@@ -377,6 +384,19 @@ impl ExportedImplFnAttributes {
                     ));
                 }
             }
+            "getter" | "setter" => {
+                if self.constructor || self.property.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "confused constructor/getter/setter attributes",
+                    ));
+                }
+                self.property = Some(if snd.ident == "getter" {
+                    PropertyKind::Getter
+                } else {
+                    PropertyKind::Setter
+                });
+            }
             _ => return Err(syn::Error::new_spanned(snd, "unknown uniffi attribute")),
         }
 
@@ -44,6 +44,7 @@ pub(super) fn trait_impl(
                 "Constructors not allowed in trait interfaces",
             )),
             ImplItem::Method(sig) => Ok(sig),
+            ImplItem::StaticMethod(_) => unreachable!("traits have no static methods"),
         })
         .collect::<syn::Result<Vec<_>>>()?;
 
@@ -211,19 +212,21 @@ fn gen_method_impl(sig: &FnSignature, vtable_cell: &Ident) -> syn::Result<TokenS
         ..
     } = sig;
 
-    if !matches!(kind, FnKind::TraitMethod { .. }) {
+    let FnKind::TraitMethod { self_ident, .. } = kind else {
         return Err(syn::Error::new(
             *span,
             format!(
                 "Internal UniFFI error: Unexpected function kind for callback interface {name}: {kind:?}",
             ),
         ));
-    }
+    };
+    // Name reported to the `FfiCallObserver`
+    let observer_name = format!("{}::{name}", ident_to_string(self_ident));
 
     let self_param = match receiver {
         Some(ReceiverArg::Ref) => quote! { &self },
         Some(ReceiverArg::Arc) => quote! { self: Arc<Self> },
-        None => {
+        Some(ReceiverArg::RefMut) | None => {
             return Err(syn::Error::new(
                 *span,
                 "callback interface methods must take &self as their first argument",
@@ -247,7 +250,9 @@ fn gen_method_impl(sig: &FnSignature, vtable_cell: &Ident) -> syn::Result<TokenS
                 let vtable = #vtable_cell.get();
                 let mut uniffi_call_status: ::uniffi::RustCallStatus = ::std::default::Default::default();
                 let mut uniffi_return_value: #lift_return_type = ::uniffi::FfiDefault::ffi_default();
+                let uniffi_call_timer = ::uniffi::FfiCallTimer::start(#observer_name, ::uniffi::FfiCallKind::CallbackInterface);
                 (vtable.#ident)(self.handle, #(#lower_exprs,)* &mut uniffi_return_value, &mut uniffi_call_status);
+                uniffi_call_timer.finish(&uniffi_call_status.code);
                 #lift_foreign_return(uniffi_return_value, uniffi_call_status)
             }
         })
@@ -256,6 +261,7 @@ fn gen_method_impl(sig: &FnSignature, vtable_cell: &Ident) -> syn::Result<TokenS
             async fn #ident(#self_param, #(#params),*) -> #return_ty {
                 let vtable = #vtable_cell.get();
                 ::uniffi::foreign_async_call::<_, #return_ty, crate::UniFfiTag>(
+                    #observer_name,
                     move |uniffi_future_callback, uniffi_future_callback_data, uniffi_foreign_future_dropped_callback| {
                         (vtable.#ident)(
                             self.handle,
@@ -0,0 +1,117 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Expr, ExprLit, Lit, Type, UnOp};
+
+use crate::util::{create_metadata_items, ident_to_string};
+
+// Constants don't have any scaffolding, they're rendered as literals in the bindings.
+pub(super) fn gen_constant_metadata(
+    ident: Ident,
+    ty: Type,
+    expr: Expr,
+    docstring: String,
+    udl_mode: bool,
+) -> syn::Result<TokenStream> {
+    if udl_mode {
+        return Ok(quote! {});
+    }
+    let name = ident_to_string(&ident);
+    // Reference types, like `&str`, are stored as their owned equivalent.
+    let type_id_meta = match &ty {
+        Type::Reference(r) => {
+            let elem = &r.elem;
+            quote! {
+                <<#elem as ::uniffi::LiftRef<crate::UniFfiTag>>::LiftType as ::uniffi::TypeId<crate::UniFfiTag>>::TYPE_ID_META
+            }
+        }
+        _ => quote! { <#ty as ::uniffi::TypeId<crate::UniFfiTag>>::TYPE_ID_META },
+    };
+    let literal_calls = literal_metadata_calls(&expr)?;
+    let metadata_expr = quote! {
+        ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::CONSTANT)
+            .concat_str(module_path!())
+            .concat_str(#name)
+            .concat(#type_id_meta)
+            #literal_calls
+            .concat_long_str(#docstring)
+    };
+    Ok(create_metadata_items(
+        "constant",
+        &name,
+        metadata_expr,
+        None,
+    ))
+}
+
+fn literal_metadata_calls(expr: &Expr) -> syn::Result<TokenStream> {
+    match expr {
+        Expr::Group(group) => literal_metadata_calls(&group.expr),
+        Expr::Paren(paren) => literal_metadata_calls(&paren.expr),
+        Expr::Lit(ExprLit { lit, .. }) => lit_metadata_calls(lit, false),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => match &*unary.expr {
+            Expr::Lit(ExprLit {
+                lit: lit @ (Lit::Int(_) | Lit::Float(_)),
+                ..
+            }) => lit_metadata_calls(lit, true),
+            _ => Err(unsupported_value(expr)),
+        },
+        Expr::Path(path) if path.path.is_ident("None") => Ok(quote! {
+            .concat_value(::uniffi::metadata::codes::LIT_NONE)
+        }),
+        Expr::Call(call)
+            if matches!(&*call.func, Expr::Path(path) if path.path.is_ident("Some"))
+                && call.args.len() == 1 =>
+        {
+            let inner_calls = literal_metadata_calls(&call.args[0])?;
+            Ok(quote! {
+                .concat_value(::uniffi::metadata::codes::LIT_SOME)
+                .concat_value(::uniffi::metadata::codes::DEFVALUE_LITERAL)
+                #inner_calls
+            })
+        }
+        _ => Err(unsupported_value(expr)),
+    }
+}
+
+fn lit_metadata_calls(lit: &Lit, negative: bool) -> syn::Result<TokenStream> {
+    let sign = if negative { "-" } else { "" };
+    match lit {
+        Lit::Str(s) => Ok(quote! {
+            .concat_value(::uniffi::metadata::codes::LIT_STR)
+            .concat_str(#s)
+        }),
+        Lit::Int(i) => {
+            let digits = format!("{sign}{}", i.base10_digits());
+            Ok(quote! {
+                .concat_value(::uniffi::metadata::codes::LIT_INT)
+                .concat_str(#digits)
+            })
+        }
+        Lit::Float(f) => {
+            let digits = format!("{sign}{}", f.base10_digits());
+            Ok(quote! {
+                .concat_value(::uniffi::metadata::codes::LIT_FLOAT)
+                .concat_str(#digits)
+            })
+        }
+        Lit::Bool(b) => Ok(quote! {
+            .concat_value(::uniffi::metadata::codes::LIT_BOOL)
+            .concat_bool(#b)
+        }),
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "this type of literal is not currently supported in exported constants",
+        )),
+    }
+}
+
+fn unsupported_value(expr: &Expr) -> syn::Error {
+    syn::Error::new_spanned(
+        expr,
+        "exported constants must be initialized with a literal, `None` or `Some(literal)`",
+    )
+}
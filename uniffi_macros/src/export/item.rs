@@ -48,6 +48,12 @@ pub(super) enum ExportItem {
         self_ident: Ident,
         uniffi_traits: Vec<UniffiTraitDiscriminants>,
     },
+    Constant {
+        ident: Ident,
+        ty: Box<syn::Type>,
+        expr: Box<syn::Expr>,
+        docstring: String,
+    },
 }
 
 impl ExportItem {
@@ -63,7 +69,8 @@ impl ExportItem {
             syn::Item::Trait(item) => Self::from_trait(item, attr_args),
             syn::Item::Struct(item) => Self::from_struct(item, attr_args),
             syn::Item::Enum(item) => Self::from_enum(item, attr_args),
-            // FIXME: Support const / static?
+            syn::Item::Const(item) => Self::from_const(item, attr_args),
+            // FIXME: Support static?
             _ => Err(syn::Error::new(
                 Span::call_site(),
                 "unsupported item: This block doesn't support `uniffi::export`",
@@ -71,6 +78,22 @@ impl ExportItem {
         }
     }
 
+    pub fn from_const(item: syn::ItemConst, attr_args: TokenStream) -> syn::Result<Self> {
+        syn::parse::<syn::parse::Nothing>(attr_args)?;
+        if !item.generics.params.is_empty() || item.generics.where_clause.is_some() {
+            return Err(syn::Error::new_spanned(
+                &item.generics,
+                "generic constants are not supported by uniffi::export",
+            ));
+        }
+        Ok(Self::Constant {
+            docstring: extract_docstring(&item.attrs)?,
+            ident: item.ident,
+            ty: item.ty,
+            expr: item.expr,
+        })
+    }
+
     pub fn from_impl(item: syn::ItemImpl, attr_args: TokenStream) -> syn::Result<Self> {
         let args: ExportImplArgs = syn::parse(attr_args)?;
         if !item.generics.params.is_empty() || item.generics.where_clause.is_some() {
@@ -99,6 +122,7 @@ impl ExportItem {
             }
         };
 
+        let item_is_trait_impl = item.trait_.is_some();
         let items = item
             .items
             .into_iter()
@@ -122,6 +146,13 @@ impl ExportItem {
                     self_ident.clone()
                 };
 
+                if attrs.property.is_some() && impl_fn.sig.receiver().is_none() {
+                    return Err(syn::Error::new_spanned(
+                        impl_fn.sig,
+                        "property getters and setters must take `self`",
+                    ));
+                }
+
                 let item = if attrs.constructor {
                     ImplItem::Constructor(FnSignature::new_constructor(
                         self_ident.clone(),
@@ -130,14 +161,31 @@ impl ExportItem {
                         attrs.args,
                         docstring,
                     )?)
-                } else {
-                    ImplItem::Method(FnSignature::new_method(
+                } else if impl_fn.sig.receiver().is_none() {
+                    if item_is_trait_impl {
+                        return Err(syn::Error::new_spanned(
+                            impl_fn.sig,
+                            "associated functions are not supported in trait impl blocks",
+                        ));
+                    }
+                    ImplItem::StaticMethod(FnSignature::new_static_method(
                         self_ident.clone(),
                         foreign_self_ident,
                         impl_fn.sig,
                         attrs.args,
                         docstring,
                     )?)
+                } else {
+                    ImplItem::Method(
+                        FnSignature::new_method(
+                            self_ident.clone(),
+                            foreign_self_ident,
+                            impl_fn.sig,
+                            attrs.args,
+                            docstring,
+                        )?
+                        .with_property(attrs.property)?,
+                    )
                 };
 
                 Ok(item)
@@ -222,6 +270,11 @@ impl ExportItem {
                         tim,
                         "exported traits can not have constructors",
                     ));
+                } else if attrs.property.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        tim,
+                        "exported traits can not have property getters or setters",
+                    ));
                 } else {
                     ImplItem::Method(FnSignature::new_trait_method(
                         self_ident.clone(),
@@ -280,6 +333,7 @@ impl ExportItem {
 pub(super) enum ImplItem {
     Constructor(FnSignature),
     Method(FnSignature),
+    StaticMethod(FnSignature),
 }
 
 fn type_as_type_path(ty: &syn::Type) -> syn::Result<&syn::TypePath> {
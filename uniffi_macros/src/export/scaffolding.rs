@@ -64,6 +64,30 @@ pub(super) fn gen_constructor_scaffolding(
     })
 }
 
+pub(super) fn gen_static_method_scaffolding(
+    sig: FnSignature,
+    ar: Option<&AsyncRuntime>,
+    udl_mode: bool,
+) -> syn::Result<TokenStream> {
+    if !sig.is_async {
+        if let Some(async_runtime) = ar {
+            return Err(syn::Error::new_spanned(
+                async_runtime,
+                "this attribute is only allowed on async functions",
+            ));
+        }
+    }
+    let metadata_items = (!udl_mode).then(|| {
+        sig.metadata_items()
+            .unwrap_or_else(syn::Error::into_compile_error)
+    });
+    let scaffolding_func = gen_ffi_function(&sig, ar, udl_mode, None)?;
+    Ok(quote! {
+        #scaffolding_func
+        #metadata_items
+    })
+}
+
 pub(super) fn gen_method_scaffolding(
     sig: FnSignature,
     ar: Option<&AsyncRuntime>,
@@ -138,7 +162,19 @@ impl ScaffoldingBits {
             quote! { #self_ident }
         };
 
-        let ref_type = ffiops::lift_ref_type(&self_type);
+        let receiver = sig.require_receiver()?;
+        let ref_type = match receiver {
+            // `&mut self` methods lock the object exclusively.  This only compiles for objects
+            // with a `mutex` or `rwlock` attribute.
+            ReceiverArg::RefMut if is_trait => {
+                return Err(syn::Error::new(
+                    sig.span,
+                    "trait interface methods can't take `&mut self`",
+                ));
+            }
+            ReceiverArg::RefMut => quote! { ::uniffi::ObjectWriteGuard<#self_type> },
+            ReceiverArg::Ref | ReceiverArg::Arc => ffiops::lift_ref_type(&self_type),
+        };
         let lift_type = ffiops::lift_type(&ref_type);
         let try_lift = ffiops::try_lift(&ref_type);
 
@@ -153,8 +189,8 @@ impl ScaffoldingBits {
         let call_params = sig.rust_call_params(true);
         let rust_fn_call = if is_trait {
             // For traits use the fully-qualified function name to disambiguate
-            let receiver_expr = match sig.require_receiver()? {
-                ReceiverArg::Ref => quote! { &*uniffi_args.0 },
+            let receiver_expr = match receiver {
+                ReceiverArg::Ref | ReceiverArg::RefMut => quote! { &*uniffi_args.0 },
                 ReceiverArg::Arc => quote! { uniffi_args.0 },
             };
             quote! { <dyn #self_ident as #self_ident>::#ident(#receiver_expr, #call_params) }
@@ -183,6 +219,26 @@ impl ScaffoldingBits {
         })
     }
 
+    fn new_for_static_method(sig: &FnSignature, self_ident: &Ident, udl_mode: bool) -> Self {
+        let ident = &sig.ident;
+        let call_params = sig.rust_call_params(false);
+        let rust_fn_call = quote! { #self_ident::#ident(#call_params) };
+        // UDL mode adds an extra conversion (#1749)
+        let convert_result = if udl_mode && sig.looks_like_result {
+            quote! { uniffi_result.map_err(::std::convert::Into::into) }
+        } else {
+            quote! { uniffi_result }
+        };
+
+        Self {
+            param_names: sig.scaffolding_param_names().collect(),
+            param_types: sig.scaffolding_param_types().collect(),
+            lift_closure: sig.lift_closure(None),
+            rust_fn_call,
+            convert_result,
+        }
+    }
+
     fn new_for_constructor(sig: &FnSignature, self_ident: &Ident, udl_mode: bool) -> Self {
         let ident = &sig.ident;
         let call_params = sig.rust_call_params(false);
@@ -234,6 +290,9 @@ pub(super) fn gen_ffi_function(
         FnKind::Constructor { self_ident, .. } => {
             ScaffoldingBits::new_for_constructor(sig, self_ident, udl_mode)
         }
+        FnKind::StaticMethod { self_ident, .. } => {
+            ScaffoldingBits::new_for_static_method(sig, self_ident, udl_mode)
+        }
     };
 
     let ffi_ident = sig.scaffolding_fn_ident()?;
@@ -244,15 +303,15 @@ pub(super) fn gen_ffi_function(
     let lower_return = ffiops::lower_return(return_ty);
     let handle_failed_lift = ffiops::lower_return_handle_failed_lift(return_ty);
     let use_trait = use_trait.map(|tr| quote! { use #tr; });
+    let uniffi_args_binding = if sig.has_mut_ref_bytes() || sig.has_mut_receiver() {
+        quote! { mut uniffi_args }
+    } else {
+        quote! { uniffi_args }
+    };
 
     Ok(if !sig.is_async {
         let scaffolding_fn_ffi_buffer_version =
             ffi_buffer_scaffolding_fn(&ffi_ident, &ffi_return_ty, &param_types, true);
-        let uniffi_args_binding = if sig.has_mut_ref_bytes() {
-            quote! { mut uniffi_args }
-        } else {
-            quote! { uniffi_args }
-        };
         quote! {
             #[doc(hidden)]
             #[unsafe(no_mangle)]
@@ -263,7 +322,7 @@ pub(super) fn gen_ffi_function(
                 #use_trait
                 ::uniffi::deps::trace!("calling: {}", #ffi_fn_name);
                 let uniffi_lift_args = #lift_closure;
-                ::uniffi::rust_call(call_status, || {
+                ::uniffi::rust_call_observed(#ffi_fn_name, call_status, || {
                     let result = match uniffi_lift_args() {
                         ::std::result::Result::Ok(#uniffi_args_binding) => {
                             ::uniffi::deps::trace!("lift_args success: {}", #ffi_fn_name);
@@ -291,17 +350,21 @@ pub(super) fn gen_ffi_function(
         }
         let scaffolding_fn_ffi_buffer_version =
             ffi_buffer_scaffolding_fn(&ffi_ident, &quote! { ::uniffi::Handle}, &param_types, false);
+        let lock_checks = sig.async_lock_checks();
 
         quote! {
+            #lock_checks
+
             #[doc(hidden)]
             #[unsafe(no_mangle)]
             pub extern "C" fn #ffi_ident(#(#param_names: #param_types,)*) -> ::uniffi::Handle {
                 ::uniffi::deps::trace!("calling: {}", #name);
                 let uniffi_lifted_args = (#lift_closure)();
-                ::uniffi::rust_future_new::<_, #return_ty, _>(
+                ::uniffi::rust_future_new_observed::<_, #return_ty, _>(
+                    #ffi_fn_name,
                     async move {
                         match uniffi_lifted_args {
-                            ::std::result::Result::Ok(uniffi_args) => {
+                            ::std::result::Result::Ok(#uniffi_args_binding) => {
                                 let uniffi_result = #future_expr.await;
                                 Ok(#convert_result)
                             }
@@ -13,6 +13,7 @@ use crate::{
         attributes::ExportTraitArgs, callback_interface, gen_method_scaffolding, item::ImplItem,
     },
     ffiops,
+    generics::GenericInstance,
     object::interface_meta_static_var,
     util::{ident_to_string, tagged_impl_header, wasm_single_threaded_annotation},
 };
@@ -106,6 +107,7 @@ pub(super) fn gen_trait_scaffolding(
         interface_meta_static_var(
             &ident_to_string(&self_ident),
             orig_name_metadata,
+            GenericInstance::metadata_calls(None),
             ObjectImpl::Trait(trait_kind),
             docstring.as_str(),
         )
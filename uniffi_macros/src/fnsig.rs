@@ -12,9 +12,9 @@ use crate::{
     },
 };
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::{spanned::Spanned, FnArg, Ident, Pat, Receiver, ReturnType, Type};
-use uniffi_meta::PassBy;
+use uniffi_meta::{PassBy, PropertyKind, PropertyMetadata};
 
 /// Syntactic check for `&[u8]`. Matches the bare identifier `u8` only —
 /// fully-qualified paths like `&[::std::primitive::u8]` or user-defined
@@ -49,6 +49,8 @@ pub(crate) struct FnSignature {
     // In general, it's not reliable because it fails for type aliases.
     pub looks_like_result: bool,
     pub docstring: String,
+    // Set for methods marked with `#[uniffi::getter]` or `#[uniffi::setter]`
+    pub property: Option<PropertyKind>,
 }
 
 impl FnSignature {
@@ -96,6 +98,24 @@ impl FnSignature {
         )
     }
 
+    pub(crate) fn new_static_method(
+        self_ident: Ident,
+        foreign_self_ident: Ident,
+        sig: syn::Signature,
+        args: ExportFnArgs,
+        docstring: String,
+    ) -> syn::Result<Self> {
+        Self::new(
+            FnKind::StaticMethod {
+                self_ident,
+                foreign_self_ident,
+            },
+            sig,
+            args,
+            docstring,
+        )
+    }
+
     pub(crate) fn new_trait_method(
         self_ident: Ident,
         sig: syn::Signature,
@@ -169,6 +189,15 @@ impl FnSignature {
             ));
         }
 
+        if is_async && matches!(receiver, Some(ReceiverArg::RefMut)) {
+            return Err(syn::Error::new(
+                span,
+                "async methods can't take `&mut self`: the object would stay locked while the \
+                 future is suspended. Use a synchronous method, or `&self` with interior \
+                 mutability instead.",
+            ));
+        }
+
         if !is_async && export_fn_args.async_runtime.is_some() {
             return Err(syn::Error::new(
                 export_fn_args.async_runtime.span(),
@@ -192,9 +221,46 @@ impl FnSignature {
             return_ty: output,
             looks_like_result,
             docstring,
+            property: None,
         })
     }
 
+    /// Mark this method as a property accessor, checking that the signature fits.
+    pub(crate) fn with_property(mut self, kind: Option<PropertyKind>) -> syn::Result<Self> {
+        let Some(kind) = kind else {
+            return Ok(self);
+        };
+        if self.is_async {
+            return Err(syn::Error::new(
+                self.span,
+                "property getters and setters can't be async",
+            ));
+        }
+        let return_ty = &self.return_ty;
+        let returns_unit = syn::parse2::<Type>(return_ty.clone())
+            .is_ok_and(|ty| matches!(ty, Type::Tuple(t) if t.elems.is_empty()));
+        match kind {
+            PropertyKind::Getter if !self.args.is_empty() || returns_unit => {
+                return Err(syn::Error::new(
+                    self.span,
+                    "property getters must take no arguments and return a value",
+                ));
+            }
+            PropertyKind::Setter if self.args.len() != 1 || !returns_unit => {
+                return Err(syn::Error::new(
+                    self.span,
+                    "property setters must take a single argument and return nothing",
+                ));
+            }
+            _ => (),
+        }
+        // Check that we can derive a property name from the method name
+        PropertyMetadata::from_method_name(&self.name, kind)
+            .map_err(|e| syn::Error::new(self.span, e))?;
+        self.property = Some(kind);
+        Ok(self)
+    }
+
     /// Generate a closure that tries to lift all arguments into a tuple.
     ///
     /// The closure moves all scaffolding arguments into itself and returns:
@@ -275,6 +341,12 @@ impl FnSignature {
                 let object_name = ident_to_string(foreign_self_ident);
                 uniffi_meta::constructor_symbol_name(&self.mod_path, &object_name, name)
             }
+            FnKind::StaticMethod {
+                foreign_self_ident, ..
+            } => {
+                let object_name = ident_to_string(foreign_self_ident);
+                uniffi_meta::static_method_symbol_name(&self.mod_path, &object_name, name)
+            }
         };
         Ok(Ident::new(&name, Span::call_site()))
     }
@@ -333,6 +405,16 @@ impl FnSignature {
                 foreign_self_ident, ..
             } => {
                 let object_name = ident_to_string(foreign_self_ident);
+                let property = match self.property {
+                    Some(kind) => {
+                        let prop = PropertyMetadata::from_method_name(name, kind)
+                            .map_err(|e| syn::Error::new(self.span, e))?;
+                        let kind = prop.kind.as_u8();
+                        let name = prop.name;
+                        quote! { .concat_value(#kind).concat_str(#name) }
+                    }
+                    None => quote! { .concat_value(0) },
+                };
                 Ok(quote! {
                     ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::METHOD)
                         .concat_str(module_path!())
@@ -344,6 +426,7 @@ impl FnSignature {
                         #(#arg_metadata_calls)*
                         .concat(#type_id_meta)
                         .concat_long_str(#docstring)
+                        #property
                 })
             }
 
@@ -381,6 +464,24 @@ impl FnSignature {
                         .concat_long_str(#docstring)
                 })
             }
+
+            FnKind::StaticMethod {
+                foreign_self_ident, ..
+            } => {
+                let object_name = ident_to_string(foreign_self_ident);
+                Ok(quote! {
+                    ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::STATIC_METHOD)
+                        .concat_str(module_path!())
+                        .concat_str(#object_name)
+                        .concat_str(#name)
+                        #orig_name
+                        .concat_bool(#is_async)
+                        .concat_value(#args_len)
+                        #(#arg_metadata_calls)*
+                        .concat(#type_id_meta)
+                        .concat_long_str(#docstring)
+                })
+            }
         }
     }
 
@@ -427,6 +528,18 @@ impl FnSignature {
                     Some(self.checksum_symbol_name()),
                 ))
             }
+
+            FnKind::StaticMethod {
+                foreign_self_ident, ..
+            } => {
+                let object_name = ident_to_string(foreign_self_ident);
+                Ok(create_metadata_items(
+                    "static_method",
+                    &format!("{object_name}_{name}"),
+                    self.metadata_expr()?,
+                    Some(self.checksum_symbol_name()),
+                ))
+            }
         }
     }
 
@@ -451,6 +564,12 @@ impl FnSignature {
                 let object_name = ident_to_string(foreign_self_ident);
                 uniffi_meta::constructor_checksum_symbol_name(&self.mod_path, &object_name, name)
             }
+            FnKind::StaticMethod {
+                foreign_self_ident, ..
+            } => {
+                let object_name = ident_to_string(foreign_self_ident);
+                uniffi_meta::static_method_checksum_symbol_name(&self.mod_path, &object_name, name)
+            }
         }
     }
 
@@ -459,6 +578,45 @@ impl FnSignature {
     pub fn has_mut_ref_bytes(&self) -> bool {
         self.args.iter().any(|arg| arg.pass_by.is_mut_ref())
     }
+
+    /// True if this method takes `&mut self`, which also requires a `mut` binding.
+    pub fn has_mut_receiver(&self) -> bool {
+        matches!(self.receiver, Some(ReceiverArg::RefMut))
+    }
+
+    /// Compile-time checks that an async function doesn't borrow an object with a lock
+    ///
+    /// Borrowed objects are lifted before the future starts, so the lock would be held until it
+    /// completes.  Any other call on the object from the same thread would fail while the future
+    /// is suspended.  We can't tell if an object has a lock from the signature, so this generates
+    /// const assertions using `LiftRef::LOCKED` for the receiver and each borrowed argument.
+    pub(crate) fn async_lock_checks(&self) -> TokenStream {
+        if !self.is_async {
+            return quote! {};
+        }
+        let receiver_type = match &self.kind {
+            FnKind::Method { self_ident, .. } if self.receiver.is_some() => {
+                Some(quote! { #self_ident })
+            }
+            _ => None,
+        };
+        let arg_types = self.args.iter().filter_map(|arg| match &arg.ref_type {
+            Some(ty) if !is_u8_slice(ty) => Some(quote! { #ty }),
+            _ => None,
+        });
+        let checks = receiver_type.into_iter().chain(arg_types).map(|ty| {
+            quote_spanned! { self.span =>
+                const _: () = ::std::assert!(
+                    !<#ty as ::uniffi::LiftRef<crate::UniFfiTag>>::LOCKED,
+                    ::std::concat!(
+                        "`", ::std::stringify!(#ty), "` has a lock, so async functions can't \
+                         borrow it: the lock would be held while the future is suspended",
+                    ),
+                );
+            }
+        });
+        quote! { #(#checks)* }
+    }
 }
 
 pub(crate) struct Arg {
@@ -494,11 +652,16 @@ impl Arg {
 #[derive(Clone)]
 pub(crate) enum ReceiverArg {
     Ref,
+    /// `&mut self`, only supported for objects with a lock
+    RefMut,
     Arc,
 }
 
 impl From<Receiver> for ReceiverArg {
     fn from(receiver: Receiver) -> Self {
+        if receiver.reference.is_some() && receiver.mutability.is_some() {
+            return ReceiverArg::RefMut;
+        }
         if let Type::Path(p) = *receiver.ty {
             if let Some(segment) = p.path.segments.last() {
                 // This comparison will fail if a user uses a typedef for Arc.  Maybe we could
@@ -611,6 +774,10 @@ pub(crate) enum FnKind {
         self_ident: Ident,
         foreign_self_ident: Ident,
     },
+    StaticMethod {
+        self_ident: Ident,
+        foreign_self_ident: Ident,
+    },
     TraitMethod {
         self_ident: Ident,
         index: u32,
@@ -0,0 +1,291 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Support for generic records, enums and objects
+//!
+//! UniFFI can't export generic types directly, instead users list the concrete instantiations with
+//! `#[uniffi::instantiate(Page<User>, Page<Order> = "OrderPage")]`.  The derive macros then
+//! expand once for each instantiation, as if it was a separate non-generic item.
+
+use std::collections::HashMap;
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    visit_mut::VisitMut,
+    Attribute, Data, DeriveInput, GenericArgument, GenericParam, Generics, LitStr, PathArguments,
+    Token, Type,
+};
+
+use crate::{ffiops, util::ident_to_string};
+
+/// A concrete instantiation of a generic item
+pub struct GenericInstance {
+    /// Concrete type, for example `Page<User>`
+    pub self_ty: Type,
+    /// Name of the exported type
+    pub name: String,
+    /// Name of the generic item, for example `Page`
+    pub generic_name: String,
+    /// Type arguments, for example `[User]`
+    pub args: Vec<Type>,
+}
+
+impl GenericInstance {
+    /// Metadata calls for the `generic` field of the item's metadata
+    pub fn metadata_calls(instance: Option<&Self>) -> TokenStream {
+        match instance {
+            None => quote! { .concat_bool(false) },
+            Some(instance) => {
+                let generic_name = &instance.generic_name;
+                // Checked in `instantiation_args`
+                let args_len = instance.args.len() as u8;
+                let type_id_metas = instance.args.iter().map(ffiops::type_id_meta);
+                quote! {
+                    .concat_bool(true)
+                    .concat_str(#generic_name)
+                    .concat_value(#args_len)
+                    #(.concat(#type_id_metas))*
+                }
+            }
+        }
+    }
+}
+
+/// Expand a derive for an item that may be generic
+///
+/// For non-generic items, this calls `expand` once.  For generic items, this calls `expand` once
+/// for each `#[uniffi::instantiate]` entry, passing in a copy of the item with the type parameters
+/// replaced by the concrete types.
+pub fn expand_instances(
+    input: DeriveInput,
+    expand: impl Fn(DeriveInput, Option<GenericInstance>) -> syn::Result<TokenStream>,
+) -> syn::Result<TokenStream> {
+    let instantiations = parse_instantiate_attrs(&input.attrs)?;
+    if input.generics.params.is_empty() {
+        return match instantiations.first() {
+            Some(inst) => Err(syn::Error::new_spanned(
+                &inst.ty,
+                "`uniffi::instantiate` is only allowed on generic items",
+            )),
+            None => expand(input, None),
+        };
+    }
+    let type_params = type_params(&input.generics)?;
+    if instantiations.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "generic items must list their instantiations with `#[uniffi::instantiate(...)]`",
+        ));
+    }
+    instantiations
+        .into_iter()
+        .map(|inst| {
+            let args = instantiation_args(&input.ident, &inst.ty, type_params.len())?;
+            let name = match inst.name {
+                Some(name) => name.value(),
+                None => default_instance_name(&inst.ty)?,
+            };
+            let mut item = input.clone();
+            item.generics = Generics::default();
+            let mut visitor = SubstituteTypeParams(
+                type_params
+                    .iter()
+                    .cloned()
+                    .zip(args.iter().cloned())
+                    .collect(),
+            );
+            match &mut item.data {
+                Data::Struct(s) => visitor.visit_fields_mut(&mut s.fields),
+                Data::Enum(e) => {
+                    for variant in &mut e.variants {
+                        visitor.visit_fields_mut(&mut variant.fields);
+                    }
+                }
+                Data::Union(_) => (),
+            }
+            expand(
+                item,
+                Some(GenericInstance {
+                    self_ty: inst.ty,
+                    name,
+                    generic_name: ident_to_string(&input.ident),
+                    args,
+                }),
+            )
+        })
+        .collect()
+}
+
+/// A single entry in `#[uniffi::instantiate(...)]`
+struct Instantiation {
+    ty: Type,
+    name: Option<LitStr>,
+}
+
+impl Parse for Instantiation {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        let name = if input.peek(Token![=]) {
+            let _: Token![=] = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self { ty, name })
+    }
+}
+
+fn is_instantiate_attr(attr: &Attribute) -> bool {
+    let segments = &attr.path().segments;
+    segments.last().is_some_and(|s| s.ident == "instantiate")
+        && (segments.len() == 1 || (segments.len() == 2 && segments[0].ident == "uniffi"))
+}
+
+fn parse_instantiate_attrs(attrs: &[Attribute]) -> syn::Result<Vec<Instantiation>> {
+    let mut instantiations = vec![];
+    for attr in attrs.iter().filter(|a| is_instantiate_attr(a)) {
+        instantiations.extend(
+            attr.parse_args_with(Punctuated::<Instantiation, Token![,]>::parse_terminated)?,
+        );
+    }
+    Ok(instantiations)
+}
+
+fn type_params(generics: &Generics) -> syn::Result<Vec<Ident>> {
+    generics
+        .params
+        .iter()
+        .map(|p| match p {
+            GenericParam::Type(t) => Ok(t.ident.clone()),
+            _ => Err(syn::Error::new_spanned(
+                p,
+                "only type parameters are supported for generic UniFFI items",
+            )),
+        })
+        .collect()
+}
+
+/// Check that `ty` is an instantiation of `ident` and return the type arguments
+fn instantiation_args(ident: &Ident, ty: &Type, param_count: usize) -> syn::Result<Vec<Type>> {
+    let last_segment = match ty {
+        Type::Path(p) if p.qself.is_none() => p.path.segments.last(),
+        _ => None,
+    };
+    let Some(segment) = last_segment.filter(|s| s.ident == *ident) else {
+        return Err(syn::Error::new_spanned(
+            ty,
+            format!("expected an instantiation of `{ident}`"),
+        ));
+    };
+    let args: Vec<Type> = match &segment.arguments {
+        PathArguments::AngleBracketed(a) => a
+            .args
+            .iter()
+            .map(|arg| match arg {
+                GenericArgument::Type(t) => Ok(t.clone()),
+                _ => Err(syn::Error::new_spanned(arg, "expected a type argument")),
+            })
+            .collect::<syn::Result<_>>()?,
+        _ => vec![],
+    };
+    if args.len() != param_count {
+        return Err(syn::Error::new_spanned(
+            ty,
+            format!(
+                "expected {param_count} type argument(s), found {}",
+                args.len()
+            ),
+        ));
+    }
+    if args.len() > u8::MAX as usize {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "UniFFI limits generic items to 255 type arguments",
+        ));
+    }
+    Ok(args)
+}
+
+/// Derive a name for an instantiation by joining the type names: `Page<User>` -> `PageUser`
+fn default_instance_name(ty: &Type) -> syn::Result<String> {
+    let segment = match ty {
+        Type::Path(p) if p.qself.is_none() => p.path.segments.last(),
+        _ => None,
+    };
+    let Some(segment) = segment else {
+        return Err(syn::Error::new(
+            ty.span(),
+            format!(
+                "can't derive a name for `{}`, name the instantiation with `= \"Name\"`",
+                ty.to_token_stream()
+            ),
+        ));
+    };
+    let ident = ident_to_string(&segment.ident);
+    let mut name: String = ident
+        .split('_')
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect();
+    if let PathArguments::AngleBracketed(a) = &segment.arguments {
+        for arg in &a.args {
+            match arg {
+                GenericArgument::Type(t) => name.push_str(&default_instance_name(t)?),
+                _ => return Err(syn::Error::new_spanned(arg, "expected a type argument")),
+            }
+        }
+    }
+    Ok(name)
+}
+
+/// Replaces type parameters with concrete types
+struct SubstituteTypeParams(HashMap<Ident, Type>);
+
+impl VisitMut for SubstituteTypeParams {
+    fn visit_type_mut(&mut self, i: &mut Type) {
+        match i {
+            Type::Path(p) if p.qself.is_none() => {
+                if let Some(ty) = p.path.get_ident().and_then(|ident| self.0.get(ident)) {
+                    *i = ty.clone();
+                } else {
+                    syn::visit_mut::visit_type_mut(self, i)
+                }
+            }
+            _ => syn::visit_mut::visit_type_mut(self, i),
+        }
+    }
+}
+
+/// Check that a generic item isn't also renamed with `#[uniffi(name = "...")]`
+pub fn check_not_renamed(
+    name: &Option<String>,
+    instance: Option<&GenericInstance>,
+) -> syn::Result<()> {
+    match (name, instance) {
+        (Some(_), Some(instance)) => Err(syn::Error::new_spanned(
+            &instance.self_ty,
+            "generic items can't be renamed, name each instantiation instead: \
+             `#[uniffi::instantiate(Foo<Bar> = \"Name\")]`",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Get the concrete self type for an item
+pub fn self_type(ident: &Ident, instance: Option<&GenericInstance>) -> TokenStream {
+    match instance {
+        Some(instance) => instance.self_ty.to_token_stream(),
+        None => ident.to_token_stream(),
+    }
+}
@@ -23,6 +23,7 @@ mod error;
 mod export;
 mod ffiops;
 mod fnsig;
+mod generics;
 mod object;
 mod record;
 mod remote;
@@ -385,6 +386,30 @@ pub fn method(_attrs: TokenStream, input: TokenStream) -> TokenStream {
     input
 }
 
+/// An attribute for methods exposed as property getters.
+///
+/// Everything above applies here too.
+#[proc_macro_attribute]
+pub fn getter(_attrs: TokenStream, input: TokenStream) -> TokenStream {
+    input
+}
+
+/// An attribute for methods exposed as property setters.
+///
+/// Everything above applies here too.
+#[proc_macro_attribute]
+pub fn setter(_attrs: TokenStream, input: TokenStream) -> TokenStream {
+    input
+}
+
+/// An attribute listing the instantiations of a generic record, enum or object.
+///
+/// This is read by the derive macros, which must come before it on the item.
+#[proc_macro_attribute]
+pub fn instantiate(_attrs: TokenStream, input: TokenStream) -> TokenStream {
+    input
+}
+
 /// Attribute for trait interfaces defined in UDL
 #[proc_macro_attribute]
 pub fn trait_interface(_attr_args: TokenStream, input: TokenStream) -> TokenStream {
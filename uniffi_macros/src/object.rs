@@ -4,6 +4,7 @@ use syn::DeriveInput;
 
 use crate::{
     ffiops,
+    generics::{self, GenericInstance},
     util::{
         create_metadata_items, either_attribute_arg, extract_docstring, ident_to_string, kw,
         mod_path, orig_name_metadata, wasm_single_threaded_annotation, AttributeSliceExt,
@@ -18,6 +19,8 @@ use uniffi_meta::{ObjectImpl, TraitKind};
 #[derive(Clone, Default)]
 pub struct ObjectAttr {
     pub name: Option<String>,
+    pub mutex: Option<kw::mutex>,
+    pub rwlock: Option<kw::rwlock>,
 }
 
 impl UniffiAttributeArgs for ObjectAttr {
@@ -27,7 +30,20 @@ impl UniffiAttributeArgs for ObjectAttr {
             let _: kw::name = input.parse()?;
             let _: Token![=] = input.parse()?;
             let name = Some(input.parse::<LitStr>()?.value());
-            Ok(Self { name })
+            Ok(Self {
+                name,
+                ..Self::default()
+            })
+        } else if lookahead.peek(kw::mutex) {
+            Ok(Self {
+                mutex: input.parse()?,
+                ..Self::default()
+            })
+        } else if lookahead.peek(kw::rwlock) {
+            Ok(Self {
+                rwlock: input.parse()?,
+                ..Self::default()
+            })
         } else {
             Err(syn::Error::new(
                 input.span(),
@@ -37,9 +53,18 @@ impl UniffiAttributeArgs for ObjectAttr {
     }
 
     fn merge(self, other: Self) -> syn::Result<Self> {
-        Ok(Self {
+        let merged = Self {
             name: either_attribute_arg(self.name, other.name)?,
-        })
+            mutex: either_attribute_arg(self.mutex, other.mutex)?,
+            rwlock: either_attribute_arg(self.rwlock, other.rwlock)?,
+        };
+        if let (Some(_), Some(rwlock)) = (&merged.mutex, &merged.rwlock) {
+            return Err(syn::Error::new(
+                rwlock.span,
+                "`mutex` and `rwlock` can't be used together",
+            ));
+        }
+        Ok(merged)
     }
 }
 
@@ -48,15 +73,18 @@ struct ObjectItem {
     ident: Ident,
     docstring: String,
     attr: ObjectAttr,
+    instance: Option<GenericInstance>,
 }
 
 impl ObjectItem {
-    fn new(input: DeriveInput) -> syn::Result<Self> {
+    fn new(input: DeriveInput, instance: Option<GenericInstance>) -> syn::Result<Self> {
         let attr = input.attrs.parse_uniffi_attr_args::<ObjectAttr>()?;
+        generics::check_not_renamed(&attr.name, instance.as_ref())?;
         Ok(Self {
             ident: input.ident,
             docstring: extract_docstring(&input.attrs)?,
             attr,
+            instance,
         })
     }
 
@@ -64,45 +92,85 @@ impl ObjectItem {
         &self.ident
     }
 
+    fn self_type(&self) -> TokenStream {
+        generics::self_type(&self.ident, self.instance.as_ref())
+    }
+
     fn name(&self) -> String {
         ident_to_string(&self.ident)
     }
 
     fn foreign_name(&self) -> String {
-        match &self.attr.name {
-            Some(name) => name.clone(),
-            None => self.name(),
+        match (&self.instance, &self.attr.name) {
+            (Some(instance), _) => instance.name.clone(),
+            (None, Some(name)) => name.clone(),
+            (None, None) => self.name(),
         }
     }
 
     fn docstring(&self) -> &str {
         self.docstring.as_str()
     }
+
+    /// Is this object wrapped in an `ObjectLock`?
+    fn is_locked(&self) -> bool {
+        self.attr.mutex.is_some() || self.attr.rwlock.is_some()
+    }
+
+    /// Rust type that the object handles point to
+    fn arc_inner_type(&self) -> TokenStream {
+        let self_type = self.self_type();
+        if self.is_locked() {
+            quote! { ::uniffi::ObjectLock<#self_type> }
+        } else {
+            self_type
+        }
+    }
 }
 
 pub fn expand_object(input: DeriveInput, options: DeriveOptions) -> syn::Result<TokenStream> {
     let module_path = mod_path()?;
-    let object = ObjectItem::new(input)?;
+    generics::expand_instances(input, |input, instance| {
+        expand_object_item(&module_path, ObjectItem::new(input, instance)?, &options)
+    })
+}
+
+fn expand_object_item(
+    module_path: &str,
+    object: ObjectItem,
+    options: &DeriveOptions,
+) -> syn::Result<TokenStream> {
+    if object.is_locked() && options.local_tag {
+        return Err(syn::Error::new(
+            object.ident().span(),
+            "remote objects can't use `mutex` or `rwlock`",
+        ));
+    }
     let name = &object.foreign_name();
-    let ident = object.ident();
+    let arc_inner_type = &object.arc_inner_type();
     let clone_fn_ident = Ident::new(
-        &uniffi_meta::clone_fn_symbol_name(&module_path, name),
+        &uniffi_meta::clone_fn_symbol_name(module_path, name),
         Span::call_site(),
     );
     let free_fn_ident = Ident::new(
-        &uniffi_meta::free_fn_symbol_name(&module_path, name),
+        &uniffi_meta::free_fn_symbol_name(module_path, name),
         Span::call_site(),
     );
     let meta_static_var = options.generate_metadata.then(|| {
         interface_meta_static_var(
             name,
-            orig_name_metadata(object.attr.name.is_some(), ident),
+            orig_name_metadata(object.attr.name.is_some(), object.ident()),
+            GenericInstance::metadata_calls(object.instance.as_ref()),
             ObjectImpl::Struct,
             object.docstring(),
         )
         .unwrap_or_else(syn::Error::into_compile_error)
     });
-    let interface_impl = interface_impl(&object, &options);
+    let interface_impl = if object.is_locked() {
+        locked_interface_impl(&object, options)
+    } else {
+        interface_impl(&object, options)
+    };
 
     Ok(quote! {
         #[doc(hidden)]
@@ -114,7 +182,7 @@ pub fn expand_object(input: DeriveInput, options: DeriveOptions) -> syn::Result<
             ::uniffi::deps::trace!("clone: {} ({:?})", #name, handle);
             ::uniffi::rust_call(call_status, || {
                 unsafe {
-                    handle.clone_arc_handle::<#ident>()
+                    handle.clone_arc_handle::<#arc_inner_type>()
                 };
                 ::std::result::Result::Ok(handle)
             })
@@ -129,7 +197,7 @@ pub fn expand_object(input: DeriveInput, options: DeriveOptions) -> syn::Result<
             ::uniffi::deps::trace!("free: {} ({:?})", #name, handle);
             ::uniffi::rust_call(call_status, || {
                 ::std::mem::drop(unsafe {
-                    handle.into_arc::<#ident>()
+                    handle.into_arc::<#arc_inner_type>()
                 });
                 ::std::result::Result::Ok(())
             });
@@ -142,7 +210,7 @@ pub fn expand_object(input: DeriveInput, options: DeriveOptions) -> syn::Result<
 
 fn interface_impl(object: &ObjectItem, options: &DeriveOptions) -> TokenStream {
     let name = object.foreign_name();
-    let ident = object.ident();
+    let ident = &object.self_type();
     let impl_spec = options.ffi_impl_header("FfiConverterArc", ident);
     let lower_return_impl_spec = options.ffi_impl_header("LowerReturn", ident);
     let lower_error_impl_spec = options.ffi_impl_header("LowerError", ident);
@@ -253,9 +321,67 @@ fn interface_impl(object: &ObjectItem, options: &DeriveOptions) -> TokenStream {
     }
 }
 
+/// FFI trait impls for objects wrapped in an `ObjectLock`
+///
+/// The handles point to an `Arc<ObjectLock<T>>`, which gets its `FfiConverter` impl from
+/// `uniffi_core`.  Here we implement the traits needed to return `T` from constructors and to lift
+/// `&T` by locking the object.
+fn locked_interface_impl(object: &ObjectItem, options: &DeriveOptions) -> TokenStream {
+    let name = object.foreign_name();
+    let ident = &object.self_type();
+    let shared_reads = object.attr.rwlock.is_some();
+    let lower_return_impl_spec = options.ffi_impl_header("LowerReturn", ident);
+    let type_id_impl_spec = options.ffi_impl_header("TypeId", ident);
+    let lift_ref_impl_spec = options.ffi_impl_header("LiftRef", ident);
+    let lock_type = quote! { ::std::sync::Arc<::uniffi::ObjectLock<Self>> };
+    let lower_return_type_lock = ffiops::lower_return_type(&lock_type);
+    let lower_return_lock = ffiops::lower_return(&lock_type);
+    let single_threaded_annotation = wasm_single_threaded_annotation();
+    // `rwlock` objects can be accessed from multiple threads at once, so they must be `Sync`
+    let required_traits = if shared_reads {
+        quote! { ::core::marker::Sync, ::core::marker::Send }
+    } else {
+        quote! { ::core::marker::Send }
+    };
+
+    quote! {
+        #single_threaded_annotation
+        ::uniffi::deps::static_assertions::assert_impl_all!(#ident: #required_traits);
+
+        ::uniffi::deps::static_assertions::const_assert!(::std::mem::size_of::<*const ()>() <= 8);
+
+        #[doc(hidden)]
+        #[automatically_derived]
+        unsafe impl ::uniffi::LockedObject for #ident {
+            const NAME: &'static str = #name;
+            const SHARED_READS: bool = #shared_reads;
+        }
+
+        unsafe #lower_return_impl_spec {
+            type ReturnType = #lower_return_type_lock;
+
+            fn lower_return(obj: Self) -> ::std::result::Result<Self::ReturnType, ::uniffi::RustCallError> {
+                #lower_return_lock(::std::sync::Arc::new(::uniffi::ObjectLock::new(obj)))
+            }
+        }
+
+        unsafe #lift_ref_impl_spec {
+            type LiftType = ::uniffi::ObjectReadGuard<Self>;
+            const LOCKED: bool = true;
+        }
+
+        #type_id_impl_spec {
+            const TYPE_ID_META: ::uniffi::MetadataBuffer = ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::TYPE_INTERFACE)
+                .concat_str(module_path!())
+                .concat_str(#name);
+        }
+    }
+}
+
 pub(crate) fn interface_meta_static_var(
     name: &str,
     orig_name_metadata: TokenStream,
+    generic_metadata: TokenStream,
     imp: ObjectImpl,
     docstring: &str,
 ) -> syn::Result<TokenStream> {
@@ -280,6 +406,7 @@ pub(crate) fn interface_meta_static_var(
                 .concat_str(module_path!())
                 .concat_str(#name)
                 #orig_name_metadata
+                #generic_metadata
                 .concat_long_str(#docstring)
                 #trait_kind_concat
         },
@@ -5,6 +5,7 @@ use syn::{parse::ParseStream, Data, DataStruct, DeriveInput, Field, LitStr, Toke
 use crate::{
     default::{default_value_metadata_calls, DefaultValue},
     ffiops,
+    generics::{self, GenericInstance},
     util::{
         create_metadata_items, either_attribute_arg, extract_docstring, ident_to_string, kw,
         orig_name_metadata, try_metadata_value_from_usize, try_read_field, AttributeSliceExt,
@@ -48,10 +49,11 @@ struct RecordItem {
     record: DataStruct,
     docstring: String,
     attr: RecordAttr,
+    instance: Option<GenericInstance>,
 }
 
 impl RecordItem {
-    fn new(input: DeriveInput) -> syn::Result<Self> {
+    fn new(input: DeriveInput, instance: Option<GenericInstance>) -> syn::Result<Self> {
         let record = match input.data {
             Data::Struct(s) => s,
             _ => {
@@ -62,22 +64,25 @@ impl RecordItem {
             }
         };
         let attr = input.attrs.parse_uniffi_attr_args::<RecordAttr>()?;
+        generics::check_not_renamed(&attr.name, instance.as_ref())?;
         Ok(Self {
             ident: input.ident,
             record,
             docstring: extract_docstring(&input.attrs)?,
             attr,
+            instance,
         })
     }
 
-    fn ident(&self) -> &Ident {
-        &self.ident
+    fn self_type(&self) -> TokenStream {
+        generics::self_type(&self.ident, self.instance.as_ref())
     }
 
     fn foreign_name(&self) -> String {
-        match &self.attr.name {
-            Some(name) => name.clone(),
-            None => ident_to_string(&self.ident),
+        match (&self.instance, &self.attr.name) {
+            (Some(instance), _) => instance.name.clone(),
+            (None, Some(name)) => name.clone(),
+            (None, None) => ident_to_string(&self.ident),
         }
     }
 
@@ -91,16 +96,18 @@ impl RecordItem {
 }
 
 pub fn expand_record(input: DeriveInput, options: DeriveOptions) -> syn::Result<TokenStream> {
-    let record = RecordItem::new(input)?;
-    let ffi_converter =
-        record_ffi_converter_impl(&record, &options).unwrap_or_else(syn::Error::into_compile_error);
-    let meta_static_var = options
-        .generate_metadata
-        .then(|| record_meta_static_var(&record).unwrap_or_else(syn::Error::into_compile_error));
-
-    Ok(quote! {
-        #ffi_converter
-        #meta_static_var
+    generics::expand_instances(input, |input, instance| {
+        let record = RecordItem::new(input, instance)?;
+        let ffi_converter = record_ffi_converter_impl(&record, &options)
+            .unwrap_or_else(syn::Error::into_compile_error);
+        let meta_static_var = options.generate_metadata.then(|| {
+            record_meta_static_var(&record).unwrap_or_else(syn::Error::into_compile_error)
+        });
+
+        Ok(quote! {
+            #ffi_converter
+            #meta_static_var
+        })
     })
 }
 
@@ -108,9 +115,9 @@ fn record_ffi_converter_impl(
     record: &RecordItem,
     options: &DeriveOptions,
 ) -> syn::Result<TokenStream> {
-    let ident = record.ident();
-    let impl_spec = options.ffi_impl_header("FfiConverter", ident);
-    let derive_ffi_traits = options.derive_all_ffi_traits(ident);
+    let self_ty = record.self_type();
+    let impl_spec = options.ffi_impl_header("FfiConverter", &self_ty);
+    let derive_ffi_traits = options.derive_all_ffi_traits(&self_ty);
     let name = &record.foreign_name();
     let write_impl: TokenStream = record.struct_().fields.iter().map(write_field).collect();
     let try_read_fields: TokenStream = record.struct_().fields.iter().map(try_read_field).collect();
@@ -190,6 +197,7 @@ impl UniffiAttributeArgs for FieldAttributeArguments {
 fn record_meta_static_var(record: &RecordItem) -> syn::Result<TokenStream> {
     let name = &record.foreign_name();
     let rec_orig_name_metadata = orig_name_metadata(record.attr.name.is_some(), &record.ident);
+    let generic_metadata = GenericInstance::metadata_calls(record.instance.as_ref());
     let docstring = record.docstring();
     let fields_len = try_metadata_value_from_usize(
         record.struct_().fields.len(),
@@ -237,6 +245,7 @@ fn record_meta_static_var(record: &RecordItem) -> syn::Result<TokenStream> {
                 .concat_str(module_path!())
                 .concat_str(#name)
                 #rec_orig_name_metadata
+                #generic_metadata
                 .concat_value(#fields_len)
                 #concat_fields
                 .concat_long_str(#docstring)
@@ -25,6 +25,12 @@ pub fn setup_scaffolding(namespace: String) -> Result<TokenStream> {
         format_ident!("ffi_{normalized_module_path}_rustbuffer_reserve");
     let reexport_hack_ident = format_ident!("{normalized_module_path}_uniffi_reexport_hack");
     let ffi_rust_future_scaffolding_fns = rust_future_scaffolding_fns(&normalized_module_path);
+    let ffi_stream_next_ident = format_ident!("ffi_{normalized_module_path}_stream_next");
+    let ffi_stream_free_ident = format_ident!("ffi_{normalized_module_path}_stream_free");
+    let ffi_init_closure_vtable_ident =
+        format_ident!("ffi_{normalized_module_path}_init_closure_vtable");
+    let ffi_closure_call_ident = format_ident!("ffi_{normalized_module_path}_closure_call");
+    let ffi_closure_free_ident = format_ident!("ffi_{normalized_module_path}_closure_free");
 
     Ok(quote! {
         // Unit struct to parameterize the FfiConverter trait.
@@ -107,6 +113,65 @@ pub fn setup_scaffolding(namespace: String) -> Result<TokenStream> {
 
         #ffi_rust_future_scaffolding_fns
 
+        // Stream support.  `stream_next` returns a RustFuture handle which is driven using the
+        // `rust_future_*_rust_buffer` functions above.
+        //
+        // See `uniffi/src/ffi/stream.rs` for documentation on these functions
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn #ffi_stream_next_ident(handle: ::uniffi::Handle) -> ::uniffi::Handle {
+            ::uniffi::ffi::stream_next(handle)
+        }
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn #ffi_stream_free_ident(handle: ::uniffi::Handle) {
+            ::uniffi::ffi::stream_free(handle)
+        }
+
+        // Closure support.  All closure types share a single type-erased VTable and set of
+        // scaffolding functions.
+        //
+        // See `uniffi_core/src/ffi/closure.rs` for documentation on these functions
+
+        static UNIFFI_CLOSURE_VTABLE_CELL: ::uniffi::UniffiForeignPointerCell<::uniffi::ClosureVTable> =
+            ::uniffi::UniffiForeignPointerCell::<::uniffi::ClosureVTable>::new();
+
+        #[doc(hidden)]
+        impl ::uniffi::ClosureVTableProvider for UniFfiTag {
+            fn closure_vtable_cell() -> &'static ::uniffi::UniffiForeignPointerCell<::uniffi::ClosureVTable> {
+                &UNIFFI_CLOSURE_VTABLE_CELL
+            }
+        }
+
+        #[allow(missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub extern "C" fn #ffi_init_closure_vtable_ident(vtable: ::std::ptr::NonNull<::uniffi::ClosureVTable>) {
+            UNIFFI_CLOSURE_VTABLE_CELL.set(vtable);
+        }
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn #ffi_closure_call_ident(
+            handle: ::std::primitive::u64,
+            args: ::uniffi::RustBuffer,
+            call_status: &mut ::uniffi::RustCallStatus,
+        ) -> ::uniffi::RustBuffer {
+            ::uniffi::ffi::closure_call(handle, args, call_status)
+        }
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn #ffi_closure_free_ident(handle: ::std::primitive::u64) {
+            ::uniffi::ffi::closure_free(handle)
+        }
+
         // Code to re-export the UniFFI scaffolding functions.
         //
         // Some build environments won't always re-export the functions from dependencies.
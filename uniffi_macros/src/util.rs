@@ -277,6 +277,8 @@ pub mod kw {
     syn::custom_keyword!(Enum);
     syn::custom_keyword!(Error);
     syn::custom_keyword!(Object);
+    syn::custom_keyword!(mutex);
+    syn::custom_keyword!(rwlock);
     syn::custom_keyword!(Debug);
     syn::custom_keyword!(Display);
     syn::custom_keyword!(Eq);
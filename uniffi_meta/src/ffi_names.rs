@@ -62,6 +62,14 @@ pub fn init_callback_vtable_fn_symbol_name(
     format!("uniffi_{namespace}_fn_init_callback_vtable_{callback_interface_name}")
 }
 
+/// FFI symbol name for an object's static method
+pub fn static_method_symbol_name(module_path: &str, object_name: &str, name: &str) -> String {
+    let namespace = crate_name(module_path).replace("::", "__");
+    let object_name = object_name.to_ascii_lowercase();
+    let name = name.to_ascii_lowercase();
+    format!("uniffi_{namespace}_fn_static_{object_name}_{name}")
+}
+
 /// FFI checksum symbol name for a top-level function
 pub fn fn_checksum_symbol_name(module_path: &str, name: &str) -> String {
     let namespace = crate_name(module_path).replace("::", "__");
@@ -89,6 +97,18 @@ pub fn method_checksum_symbol_name(module_path: &str, object_name: &str, name: &
     format!("uniffi_{namespace}_checksum_method_{object_name}_{name}")
 }
 
+/// FFI checksum symbol name for an object's static method
+pub fn static_method_checksum_symbol_name(
+    module_path: &str,
+    object_name: &str,
+    name: &str,
+) -> String {
+    let namespace = crate_name(module_path).replace("::", "__");
+    let object_name = object_name.to_ascii_lowercase();
+    let name = name.to_ascii_lowercase();
+    format!("uniffi_{namespace}_checksum_static_{object_name}_{name}")
+}
+
 /// Get the symbol name for a FFI-buffer version of a function
 pub fn ffi_buffer_symbol_name(fn_name: &str) -> String {
     match fn_name.strip_prefix("uniffi_") {
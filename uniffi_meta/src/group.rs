@@ -61,6 +61,7 @@ pub fn group_metadata(group_map: &mut MetadataGroupMap, items: Vec<Metadata>) ->
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MetadataGroup {
     pub namespace: NamespaceMetadata,
     pub namespace_docstring: Option<String>,
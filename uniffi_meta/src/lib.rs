@@ -47,6 +47,12 @@ impl Checksum for u64 {
     }
 }
 
+impl Checksum for u32 {
+    fn checksum<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.to_le_bytes());
+    }
+}
+
 impl Checksum for i64 {
     fn checksum<H: Hasher>(&self, state: &mut H) {
         state.write(&self.to_le_bytes());
@@ -119,6 +125,7 @@ impl Checksum for &str {
 //
 // This is used to match up the macro metadata with the UDL items.
 #[derive(Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NamespaceMetadata {
     pub crate_name: String,
     pub name: String,
@@ -128,6 +135,7 @@ pub struct NamespaceMetadata {
 //
 // This is to find the UDL files in library mode generation
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UdlFile {
     // The module path specified when the UDL file was parsed.
     pub module_path: String,
@@ -137,6 +145,7 @@ pub struct UdlFile {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FnMetadata {
     pub module_path: String,
     pub name: String,
@@ -161,6 +170,7 @@ impl FnMetadata {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstructorMetadata {
     pub module_path: String,
     pub self_name: String,
@@ -189,6 +199,7 @@ impl ConstructorMetadata {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodMetadata {
     pub module_path: String,
     pub self_name: String,
@@ -202,6 +213,8 @@ pub struct MethodMetadata {
     pub takes_self_by_arc: bool, // unused except by rust udl bindgen.
     pub checksum: Option<u16>,
     pub docstring: Option<String>,
+    // Set for methods exposed as property accessors
+    pub property: Option<PropertyMetadata>,
 }
 
 impl MethodMetadata {
@@ -214,7 +227,35 @@ impl MethodMetadata {
     }
 }
 
+/// An associated function without a `self` parameter, exposed as a static method on the object
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StaticMethodMetadata {
+    pub module_path: String,
+    pub self_name: String,
+    pub name: String,
+    // Original name, if this was renamed
+    pub orig_name: Option<String>,
+    pub is_async: bool,
+    pub inputs: Vec<FnParamMetadata>,
+    pub return_type: Option<Type>,
+    pub throws: Option<Type>,
+    pub checksum: Option<u16>,
+    pub docstring: Option<String>,
+}
+
+impl StaticMethodMetadata {
+    pub fn ffi_symbol_name(&self) -> String {
+        static_method_symbol_name(&self.module_path, &self.self_name, &self.name)
+    }
+
+    pub fn checksum_symbol_name(&self) -> String {
+        static_method_checksum_symbol_name(&self.module_path, &self.self_name, &self.name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TraitMethodMetadata {
     pub module_path: String,
     pub trait_name: String,
@@ -257,11 +298,59 @@ impl From<TraitMethodMetadata> for MethodMetadata {
             takes_self_by_arc: meta.takes_self_by_arc,
             checksum: meta.checksum,
             docstring: meta.docstring,
+            property: None,
+        }
+    }
+}
+
+/// A method exposed as a getter or setter for a property on the object
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Node, MapNode)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropertyMetadata {
+    pub name: String,
+    pub kind: PropertyKind,
+}
+
+impl PropertyMetadata {
+    /// Derive the property from the name of the accessor method.
+    ///
+    /// Getters are named either `foo` or `get_foo`, setters must be named `set_foo`.
+    pub fn from_method_name(method_name: &str, kind: PropertyKind) -> anyhow::Result<Self> {
+        let name = match kind {
+            PropertyKind::Getter => method_name.strip_prefix("get_").unwrap_or(method_name),
+            PropertyKind::Setter => match method_name.strip_prefix("set_") {
+                Some(name) => name,
+                None => anyhow::bail!("setter names must start with `set_` ({method_name})"),
+            },
+        };
+        if name.is_empty() {
+            anyhow::bail!("can't derive a property name from `{method_name}`");
+        }
+        Ok(Self {
+            name: name.to_string(),
+            kind,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Node, MapNode)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PropertyKind {
+    Getter,
+    Setter,
+}
+
+impl PropertyKind {
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            PropertyKind::Getter => 1,
+            PropertyKind::Setter => 2,
         }
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FnParamMetadata {
     pub name: String,
     pub ty: Type,
@@ -283,6 +372,7 @@ impl FnParamMetadata {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Checksum)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LiteralMetadata {
     Boolean(bool),
     String(String),
@@ -316,6 +406,7 @@ impl LiteralMetadata {
 // Represent the radix of integer literal values.
 // We preserve the radix into the generated bindings for readability reasons.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Checksum, Node, MapNode)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Radix {
     Decimal = 10,
     Octal = 8,
@@ -323,6 +414,7 @@ pub enum Radix {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Checksum)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DefaultValueMetadata {
     // unspecified default value
     Default,
@@ -330,18 +422,33 @@ pub enum DefaultValueMetadata {
     Literal(LiteralMetadata),
 }
 
+/// A constant value, rendered as a literal in the bindings
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConstantMetadata {
+    pub module_path: String,
+    pub name: String,
+    pub ty: Type,
+    pub value: LiteralMetadata,
+    pub docstring: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RecordMetadata {
     pub module_path: String,
     pub name: String,
     // Original name, if this was renamed
     pub orig_name: Option<String>,
+    // Set if this is an instantiation of a generic type
+    pub generic: Option<GenericInstanceMetadata>,
     pub remote: bool, // only used when generating scaffolding from UDL
     pub fields: Vec<FieldMetadata>,
     pub docstring: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FieldMetadata {
     pub name: String,
     // Original name, if this was renamed
@@ -352,6 +459,7 @@ pub struct FieldMetadata {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Checksum, Node, MapNode)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EnumShape {
     Enum,
     Error { flat: bool },
@@ -381,11 +489,14 @@ impl EnumShape {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumMetadata {
     pub module_path: String,
     pub name: String,
     // Original name, if this was renamed
     pub orig_name: Option<String>,
+    // Set if this is an instantiation of a generic type
+    pub generic: Option<GenericInstanceMetadata>,
     pub shape: EnumShape,
     pub remote: bool, // only used when generating scaffolding from UDL
     pub variants: Vec<VariantMetadata>,
@@ -395,6 +506,7 @@ pub struct EnumMetadata {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VariantMetadata {
     pub name: String,
     // Original name, if this was renamed
@@ -405,17 +517,33 @@ pub struct VariantMetadata {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectMetadata {
     pub module_path: String,
     pub name: String,
     // Original name, if this was renamed
     pub orig_name: Option<String>,
+    // Set if this is an instantiation of a generic type
+    pub generic: Option<GenericInstanceMetadata>,
     pub remote: bool, // only used when generating scaffolding from UDL
     pub imp: types::ObjectImpl,
     pub docstring: Option<String>,
 }
 
+/// A concrete instantiation of a generic Rust type, listed with `#[uniffi::instantiate]`
+///
+/// Each instantiation is exported as its own type, this records where it came from.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericInstanceMetadata {
+    /// Name of the generic Rust type, for example `Page` for `Page<User>`
+    pub name: String,
+    /// Concrete type arguments, for example `[User]` for `Page<User>`
+    pub args: Vec<Type>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CallbackInterfaceMetadata {
     pub module_path: String,
     pub name: String,
@@ -444,6 +572,7 @@ impl ObjectMetadata {
 /// implementations for now.
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UniffiTraitMetadata {
     Debug {
         fmt: MethodMetadata,
@@ -524,6 +653,7 @@ impl UniffiTraitDiscriminants {
 /// This notes that a type implements a Trait.
 /// eg, an `impl Tr for Ob` block.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectTraitImplMetadata {
     pub ty: Type,
     pub trait_ty: Type,
@@ -540,6 +670,7 @@ impl Checksum for ObjectTraitImplMetadata {
 ///
 /// It's only used by `uniffi_parse_rs`.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FromUnexpectedCallbackErrorImplMetadata {
     pub ty: Type,
 }
@@ -551,6 +682,7 @@ impl Checksum for FromUnexpectedCallbackErrorImplMetadata {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CustomTypeMetadata {
     pub module_path: String,
     pub name: String,
@@ -572,6 +704,7 @@ pub fn checksum<T: Checksum>(val: &T) -> u16 {
 /// Enum covering all the possible metadata types
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Metadata {
     Namespace(NamespaceMetadata),
     UdlFile(UdlFile),
@@ -582,6 +715,8 @@ pub enum Metadata {
     Enum(EnumMetadata),
     Constructor(ConstructorMetadata),
     Method(MethodMetadata),
+    StaticMethod(StaticMethodMetadata),
+    Constant(ConstantMetadata),
     TraitMethod(TraitMethodMetadata),
     CustomType(CustomTypeMetadata),
     UniffiTrait(UniffiTraitMetadata),
@@ -601,6 +736,8 @@ impl Metadata {
             Metadata::Func(meta) => &meta.module_path,
             Metadata::Constructor(meta) => &meta.module_path,
             Metadata::Method(meta) => &meta.module_path,
+            Metadata::StaticMethod(meta) => &meta.module_path,
+            Metadata::Constant(meta) => &meta.module_path,
             Metadata::Record(meta) => &meta.module_path,
             Metadata::Enum(meta) => &meta.module_path,
             Metadata::Object(meta) => &meta.module_path,
@@ -646,6 +783,18 @@ impl From<MethodMetadata> for Metadata {
     }
 }
 
+impl From<StaticMethodMetadata> for Metadata {
+    fn from(m: StaticMethodMetadata) -> Self {
+        Self::StaticMethod(m)
+    }
+}
+
+impl From<ConstantMetadata> for Metadata {
+    fn from(c: ConstantMetadata) -> Self {
+        Self::Constant(c)
+    }
+}
+
 impl From<RecordMetadata> for Metadata {
     fn from(r: RecordMetadata) -> Self {
         Self::Record(r)
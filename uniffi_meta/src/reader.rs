@@ -51,6 +51,8 @@ impl<'a> MetadataReader<'a> {
             codes::FUNC => self.read_func()?.into(),
             codes::CONSTRUCTOR => self.read_constructor()?.into(),
             codes::METHOD => self.read_method()?.into(),
+            codes::STATIC_METHOD => self.read_static_method()?.into(),
+            codes::CONSTANT => self.read_constant()?.into(),
             codes::RECORD => self.read_record()?.into(),
             codes::ENUM => self.read_enum()?.into(),
             codes::INTERFACE => self.read_object(ObjectImpl::Struct)?.into(),
@@ -149,12 +151,18 @@ impl<'a> MetadataReader<'a> {
             codes::TYPE_I32 => Type::Int32,
             codes::TYPE_U64 => Type::UInt64,
             codes::TYPE_I64 => Type::Int64,
+            codes::TYPE_U128 => Type::UInt128,
+            codes::TYPE_I128 => Type::Int128,
             codes::TYPE_F32 => Type::Float32,
             codes::TYPE_F64 => Type::Float64,
             codes::TYPE_BOOL => Type::Boolean,
             codes::TYPE_STRING => Type::String,
+            codes::TYPE_OWNED_BYTES => Type::OwnedBytes,
             codes::TYPE_DURATION => Type::Duration,
             codes::TYPE_SYSTEM_TIME => Type::Timestamp,
+            codes::TYPE_UUID => Type::Uuid,
+            codes::TYPE_URL => Type::Url,
+            codes::TYPE_DATE => Type::Date,
             codes::TYPE_RECORD => Type::Record {
                 module_path: self.read_string()?,
                 name: self.read_string()?,
@@ -190,6 +198,9 @@ impl<'a> MetadataReader<'a> {
             codes::TYPE_OPTION => Type::Optional {
                 inner_type: Box::new(self.read_type()?),
             },
+            codes::TYPE_STREAM => Type::Stream {
+                inner_type: Box::new(self.read_type()?),
+            },
             codes::TYPE_BOX => Type::Box {
                 inner_type: Box::new(self.read_type()?),
             },
@@ -210,6 +221,26 @@ impl<'a> MetadataReader<'a> {
             codes::TYPE_HASH_SET => Type::Set {
                 inner_type: Box::new(self.read_type()?),
             },
+            codes::TYPE_ARRAY => {
+                let len = self.read_u32()?;
+                Type::Array {
+                    inner_type: Box::new(self.read_type()?),
+                    len,
+                }
+            }
+            codes::TYPE_TUPLE => {
+                let len = self.read_u8()?;
+                Type::Tuple {
+                    types: (0..len).map(|_| self.read_type()).collect::<Result<_>>()?,
+                }
+            }
+            codes::TYPE_CLOSURE => {
+                let len = self.read_u8()?;
+                Type::Closure {
+                    arguments: (0..len).map(|_| self.read_type()).collect::<Result<_>>()?,
+                    return_type: self.read_optional_type()?.map(Box::new),
+                }
+            }
             codes::TYPE_UNIT => bail!("Unexpected TYPE_UNIT"),
             codes::TYPE_RESULT => bail!("Unexpected TYPE_RESULT"),
             _ => bail!("Unexpected metadata type code: {value:?}"),
@@ -306,6 +337,7 @@ impl<'a> MetadataReader<'a> {
         let inputs = self.read_inputs()?;
         let (return_type, throws) = self.read_return_type()?;
         let docstring = self.read_optional_long_string()?;
+        let property = self.read_property()?;
         Ok(MethodMetadata {
             module_path: self_module_path,
             self_name,
@@ -318,6 +350,56 @@ impl<'a> MetadataReader<'a> {
             takes_self_by_arc: false, // not emitted by macros
             checksum: self.calc_checksum(),
             docstring,
+            property,
+        })
+    }
+
+    fn read_property(&mut self) -> Result<Option<PropertyMetadata>> {
+        let kind = match self.read_u8()? {
+            0 => return Ok(None),
+            1 => PropertyKind::Getter,
+            2 => PropertyKind::Setter,
+            v => bail!("Unexpected property kind: {v}"),
+        };
+        let name = self.read_string()?;
+        Ok(Some(PropertyMetadata { name, kind }))
+    }
+
+    fn read_static_method(&mut self) -> Result<StaticMethodMetadata> {
+        let module_path = self.read_string()?;
+        let self_name = self.read_string()?;
+        let name = self.read_string()?;
+        let orig_name = self.read_optional_string()?;
+        let is_async = self.read_bool()?;
+        let inputs = self.read_inputs()?;
+        let (return_type, throws) = self.read_return_type()?;
+        let docstring = self.read_optional_long_string()?;
+        Ok(StaticMethodMetadata {
+            module_path,
+            self_name,
+            name,
+            orig_name,
+            is_async,
+            inputs,
+            return_type,
+            throws,
+            checksum: self.calc_checksum(),
+            docstring,
+        })
+    }
+
+    fn read_constant(&mut self) -> Result<ConstantMetadata> {
+        let module_path = self.read_string()?;
+        let name = self.read_string()?;
+        let ty = self.read_type()?;
+        let value = self.read_literal(&name, &ty)?;
+        let docstring = self.read_optional_long_string()?;
+        Ok(ConstantMetadata {
+            module_path,
+            name,
+            ty,
+            value,
+            docstring,
         })
     }
 
@@ -326,6 +408,7 @@ impl<'a> MetadataReader<'a> {
             module_path: self.read_string()?,
             name: self.read_string()?,
             orig_name: self.read_optional_string()?,
+            generic: self.read_generic_instance()?,
             remote: false, // only used when generating scaffolding from UDL
             fields: self.read_fields()?,
             docstring: self.read_optional_long_string()?,
@@ -336,6 +419,7 @@ impl<'a> MetadataReader<'a> {
         let module_path = self.read_string()?;
         let name = self.read_string()?;
         let orig_name = self.read_optional_string()?;
+        let generic = self.read_generic_instance()?;
         let shape = EnumShape::from(self.read_u8()?)?;
         let discr_type = if self.read_bool()? {
             Some(self.read_type()?)
@@ -351,6 +435,7 @@ impl<'a> MetadataReader<'a> {
             module_path,
             name,
             orig_name,
+            generic,
             shape,
             remote: false, // only used when generating scaffolding from UDL
             discr_type,
@@ -374,12 +459,25 @@ impl<'a> MetadataReader<'a> {
             module_path: self.read_string()?,
             name: self.read_string()?,
             orig_name: self.read_optional_string()?,
+            generic: self.read_generic_instance()?,
             remote: false, // only used when generating scaffolding from UDL
             imp,
             docstring: self.read_optional_long_string()?,
         })
     }
 
+    fn read_generic_instance(&mut self) -> Result<Option<GenericInstanceMetadata>> {
+        if !self.read_bool()? {
+            return Ok(None);
+        }
+        let name = self.read_string()?;
+        let len = self.read_u8()?;
+        let args = (0..len)
+            .map(|_| self.read_type())
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(GenericInstanceMetadata { name, args }))
+    }
+
     fn read_custom_type(&mut self) -> Result<CustomTypeMetadata> {
         Ok(CustomTypeMetadata {
             module_path: self.read_string()?,
@@ -21,6 +21,7 @@ use crate::Checksum;
 use uniffi_pipeline::{MapNode, Node};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Checksum, Ord, PartialOrd, Node, MapNode)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObjectImpl {
     // A single Rust type
     Struct,
@@ -30,6 +31,7 @@ pub enum ObjectImpl {
 
 /// Controls who can provide implementations of an exported trait
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Checksum, Ord, PartialOrd, Node, MapNode)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TraitKind {
     /// Only Rust types implement this trait
     RustOnly,
@@ -81,6 +83,7 @@ impl TraitKind {
 /// At this level we identify user-defined types by name, without knowing any details
 /// of their internal structure apart from what type of thing they are (record, enum, etc).
 #[derive(Debug, Clone, Eq, PartialEq, Checksum, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
     // Primitive types.
     UInt8,
@@ -91,13 +94,23 @@ pub enum Type {
     Int32,
     UInt64,
     Int64,
+    UInt128,
+    Int128,
     Float32,
     Float64,
     Boolean,
     String,
     Bytes,
+    /// Bytes that are passed without copying when they cross the FFI directly, via `uniffi::OwnedBytes`.
+    OwnedBytes,
     Timestamp,
     Duration,
+    /// A `uuid::Uuid`, with the `uuid` feature enabled.
+    Uuid,
+    /// A `url::Url`, with the `url` feature enabled.
+    Url,
+    /// A calendar date without a time or timezone, like `chrono::NaiveDate`.
+    Date,
     Object {
         // The module path to the object
         module_path: String,
@@ -131,6 +144,11 @@ pub enum Type {
     Sequence {
         inner_type: Box<Type>,
     },
+    /// A fixed-size array, like `[u8; 32]`.
+    Array {
+        inner_type: Box<Type>,
+        len: u32,
+    },
     Map {
         key_type: Box<Type>,
         value_type: Box<Type>,
@@ -138,6 +156,19 @@ pub enum Type {
     Set {
         inner_type: Box<Type>,
     },
+    /// An asynchronous stream of values, returned from Rust via `uniffi::Stream<T>`.
+    Stream {
+        inner_type: Box<Type>,
+    },
+    /// An anonymous tuple, like `(String, u32)`.  These always have at least 2 elements.
+    Tuple {
+        types: Vec<Type>,
+    },
+    /// A closure, like `Box<dyn Fn(String) -> u32 + Send + Sync>`.
+    Closure {
+        arguments: Vec<Type>,
+        return_type: Option<Box<Type>>,
+    },
     // Custom type on the scaffolding side
     Custom {
         module_path: String,
@@ -157,11 +188,23 @@ impl Type {
         match self {
             Type::Optional { inner_type }
             | Type::Sequence { inner_type }
-            | Type::Set { inner_type } => inner_type.iter_types(),
+            | Type::Array { inner_type, .. }
+            | Type::Set { inner_type }
+            | Type::Stream { inner_type } => inner_type.iter_types(),
             Type::Map {
                 key_type,
                 value_type,
             } => Box::new(key_type.iter_types().chain(value_type.iter_types())),
+            Type::Tuple { types } => Box::new(types.iter().flat_map(Type::iter_types)),
+            Type::Closure {
+                arguments,
+                return_type,
+            } => Box::new(
+                arguments
+                    .iter()
+                    .chain(return_type.as_deref())
+                    .flat_map(Type::iter_types),
+            ),
             Type::Custom { builtin, .. } => builtin.iter_types(),
             _ => Box::new(std::iter::empty()),
         }
@@ -215,7 +258,9 @@ impl Type {
         match self {
             Type::Optional { inner_type }
             | Type::Sequence { inner_type }
-            | Type::Set { inner_type } => {
+            | Type::Array { inner_type, .. }
+            | Type::Set { inner_type }
+            | Type::Stream { inner_type } => {
                 inner_type.rename_recursive(name_transformer);
             }
             Type::Map {
@@ -226,6 +271,19 @@ impl Type {
                 key_type.rename_recursive(name_transformer);
                 value_type.rename_recursive(name_transformer);
             }
+            Type::Tuple { types } => {
+                for ty in types {
+                    ty.rename_recursive(name_transformer);
+                }
+            }
+            Type::Closure {
+                arguments,
+                return_type,
+            } => {
+                for ty in arguments.iter_mut().chain(return_type.as_deref_mut()) {
+                    ty.rename_recursive(name_transformer);
+                }
+            }
             Type::Custom { builtin, .. } => {
                 builtin.rename_recursive(name_transformer);
             }
@@ -264,6 +322,7 @@ pub type TypeIterator<'a> = Box<dyn Iterator<Item = &'a Type> + 'a>;
 
 /// How a value is passed to a function or method.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord, Checksum, MapNode, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PassBy {
     Value,
     Ref,
@@ -3,6 +3,7 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use syn::{Attribute, LitStr, Meta};
+use uniffi_meta::PropertyKind;
 
 use crate::{
     attrs::{extract_docstring, meta_is_uniffi_export, meta_matches_uniffi_attr, DefaultMap},
@@ -70,6 +71,7 @@ pub struct MethodAttributes {
     pub name: Option<String>,
     pub docstring: Option<String>,
     pub async_runtime: Option<LitStr>,
+    pub property: Option<PropertyKind>,
 }
 
 impl MethodAttributes {
@@ -79,7 +81,15 @@ impl MethodAttributes {
             return Ok(None);
         };
         for meta in metas {
-            if meta_matches_uniffi_attr(&meta, "method") {
+            if meta_matches_uniffi_attr(&meta, "getter") {
+                parsed.property = Some(PropertyKind::Getter);
+            } else if meta_matches_uniffi_attr(&meta, "setter") {
+                parsed.property = Some(PropertyKind::Setter);
+            }
+            if meta_matches_uniffi_attr(&meta, "method")
+                || meta_matches_uniffi_attr(&meta, "getter")
+                || meta_matches_uniffi_attr(&meta, "setter")
+            {
                 if let Meta::List(list) = meta {
                     list.parse_nested_meta(|meta| {
                         if meta.path.is_ident("default") {
@@ -0,0 +1,47 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use syn::{Attribute, Meta};
+
+use crate::{
+    attrs::{extract_docstring, meta_is_uniffi_export},
+    paths::LookupCache,
+    CompileEnv, Ir, RPath,
+};
+
+#[derive(Clone, Default)]
+pub struct ConstantAttributes {
+    pub docstring: Option<String>,
+}
+
+impl ConstantAttributes {
+    pub fn parse<'ir>(
+        ir: &'ir Ir,
+        cache: &mut LookupCache<'ir>,
+        module_path: &RPath<'ir>,
+        env: &CompileEnv,
+        attrs: &[Attribute],
+    ) -> syn::Result<Option<Self>> {
+        let mut parsed = Self::default();
+        let Some(metas) = env.parse_attrs(attrs)? else {
+            return Ok(None);
+        };
+        if !metas
+            .iter()
+            .any(|meta| meta_is_uniffi_export(module_path, ir, cache, meta))
+        {
+            return Ok(None);
+        }
+        for meta in metas {
+            if meta_is_uniffi_export(module_path, ir, cache, &meta) {
+                if let Meta::List(list) = meta {
+                    list.parse_nested_meta(|meta| Err(meta.error("Invalid attribute")))?;
+                }
+            } else if meta.path().is_ident("doc") {
+                extract_docstring(&mut parsed.docstring, &meta);
+            }
+        }
+        Ok(Some(parsed))
+    }
+}
@@ -9,7 +9,7 @@ use syn::{
     bracketed,
     meta::ParseNestedMeta,
     parenthesized,
-    parse::{Nothing, ParseStream},
+    parse::{Nothing, Parse, ParseStream},
     token::Bracket,
     Ident, Lit,
 };
@@ -115,6 +115,16 @@ impl Default {
     }
 }
 
+impl Parse for Default {
+    /// Parse a bare value, for example the initializer of a constant
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            span: input.span(),
+            kind: DefaultKind::parse_value(input)?,
+        })
+    }
+}
+
 impl DefaultKind {
     fn parse(meta: ParseNestedMeta) -> syn::Result<Self> {
         if meta.input.is_empty() {
@@ -3,6 +3,7 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 mod callables;
+mod constants;
 mod defaults;
 mod docstring;
 mod enums;
@@ -15,6 +16,7 @@ use proc_macro2::Span;
 use syn::{spanned::Spanned, Meta, Path};
 
 pub use callables::{ConstructorAttributes, FunctionAttributes, MethodAttributes};
+pub use constants::ConstantAttributes;
 pub use defaults::{Default, DefaultMap};
 pub use docstring::extract_docstring;
 pub use enums::{EnumAttributes, VariantAttributes};
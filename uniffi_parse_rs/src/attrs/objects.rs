@@ -49,6 +49,9 @@ impl ObjectAttributes {
                             let name: LitStr = meta.input.parse()?;
                             parsed.name = Some(name.value());
                             Ok(())
+                        } else if meta.path.is_ident("mutex") || meta.path.is_ident("rwlock") {
+                            // Locks only affect the Rust scaffolding, not the metadata
+                            Ok(())
                         } else {
                             Err(meta.error("Invalid attribute"))
                         }
@@ -76,6 +76,7 @@ impl UniffiTraitAttrs {
                     takes_self_by_arc: false,
                     checksum: None,
                     docstring: None,
+                    property: None,
                 },
             });
         }
@@ -93,6 +94,7 @@ impl UniffiTraitAttrs {
                     takes_self_by_arc: false,
                     checksum: None,
                     docstring: None,
+                    property: None,
                 },
             });
         }
@@ -116,6 +118,7 @@ impl UniffiTraitAttrs {
                     takes_self_by_arc: false,
                     checksum: None,
                     docstring: None,
+                    property: None,
                 },
                 ne: uniffi_meta::MethodMetadata {
                     module_path: module_path.clone(),
@@ -135,6 +138,7 @@ impl UniffiTraitAttrs {
                     takes_self_by_arc: false,
                     checksum: None,
                     docstring: None,
+                    property: None,
                 },
             });
         }
@@ -152,6 +156,7 @@ impl UniffiTraitAttrs {
                     takes_self_by_arc: false,
                     checksum: None,
                     docstring: None,
+                    property: None,
                 },
             });
         }
@@ -175,6 +180,7 @@ impl UniffiTraitAttrs {
                     takes_self_by_arc: false,
                     checksum: None,
                     docstring: None,
+                    property: None,
                 },
             });
         }
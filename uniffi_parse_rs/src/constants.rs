@@ -0,0 +1,74 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use syn::{spanned::Spanned, Ident, ItemConst};
+use uniffi_meta::DefaultValueMetadata;
+
+use crate::{
+    attrs::{ConstantAttributes, Default},
+    paths::LookupCache,
+    Error,
+    ErrorKind::*,
+    Ir, RPath, Result, Visibility,
+};
+
+#[derive(Clone)]
+pub struct Constant {
+    pub attrs: ConstantAttributes,
+    pub ident: Ident,
+    pub vis: Visibility,
+    pub ty: syn::Type,
+    pub value: Default,
+}
+
+impl Constant {
+    pub fn parse(attrs: ConstantAttributes, c: ItemConst) -> syn::Result<Self> {
+        if !c.generics.params.is_empty() {
+            return Err(syn::Error::new(
+                c.generics.span(),
+                "Generic constants are not supported",
+            ));
+        }
+        Ok(Self {
+            attrs,
+            ident: c.ident,
+            vis: c.vis.into(),
+            ty: *c.ty,
+            value: syn::parse2(quote::ToTokens::to_token_stream(&c.expr))?,
+        })
+    }
+
+    pub fn const_metadata<'ir>(
+        &self,
+        ir: &'ir Ir,
+        cache: &mut LookupCache<'ir>,
+        item_path: RPath<'ir>,
+    ) -> Result<uniffi_meta::ConstantMetadata> {
+        let module_path = item_path.parent()?;
+        let names = item_path.public_path_to_item(ir, cache)?;
+        // `resolve_arg` handles borrowed types like `&str`
+        let ty = module_path.resolve_arg(ir, cache, &self.ty, None)?.ty;
+        let value = match self
+            .value
+            .create_default_value_metadata(module_path.file_id(), &ty)?
+        {
+            DefaultValueMetadata::Literal(lit) => lit,
+            DefaultValueMetadata::Default => {
+                return Err(Error::new(
+                    module_path.file_id(),
+                    self.ty.span(),
+                    InvalidDefault,
+                ))
+            }
+        };
+
+        Ok(uniffi_meta::ConstantMetadata {
+            module_path: names.module_path,
+            name: names.name,
+            ty,
+            value,
+            docstring: self.attrs.docstring.clone(),
+        })
+    }
+}
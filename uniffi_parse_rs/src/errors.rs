@@ -89,6 +89,8 @@ pub enum ErrorKind {
     MultipleEnumDerives,
     #[error("Invalid attribute")]
     InvalidAttr,
+    #[error("Invalid property accessor: {0}")]
+    InvalidProperty(String),
     #[error("Invalid repr type")]
     InvalidRepr,
     #[error("Invalid discriminant")]
@@ -9,7 +9,7 @@ use crate::{
     paths::LookupCache,
     CompileEnv, Constructor, Error,
     ErrorKind::*,
-    Ir, Method, RPath, Result,
+    Ir, Method, RPath, Result, StaticMethod,
 };
 
 pub struct Impl {
@@ -17,18 +17,24 @@ pub struct Impl {
     pub self_type: syn::Type,
     pub constructors: Vec<Constructor>,
     pub methods: Vec<Method>,
+    pub static_methods: Vec<StaticMethod>,
 }
 
 impl Impl {
     pub fn parse(env: &CompileEnv, attrs: ImplAttributes, imp: ItemImpl) -> syn::Result<Self> {
         let mut constructors = vec![];
         let mut methods = vec![];
+        let mut static_methods = vec![];
         for item in imp.items {
             if let ImplItem::Fn(f) = item {
                 if let Some(attrs) = ConstructorAttributes::parse(env, &f.attrs)? {
                     constructors.push(Constructor::parse(attrs, f)?);
                 } else if let Some(attrs) = MethodAttributes::parse(env, &f.attrs)? {
-                    methods.push(Method::parse(attrs, f)?);
+                    if f.sig.receiver().is_some() {
+                        methods.push(Method::parse(attrs, f)?);
+                    } else {
+                        static_methods.push(StaticMethod::parse(attrs, f)?);
+                    }
                 }
             }
         }
@@ -38,6 +44,7 @@ impl Impl {
             self_type: *imp.self_ty,
             constructors,
             methods,
+            static_methods,
         })
     }
 
@@ -72,6 +79,12 @@ impl Impl {
                     .into(),
             );
         }
+        for m in self.static_methods.iter() {
+            items.push(
+                m.to_static_method_metadata(ir, cache, module_path, &self_name, &self_ty)?
+                    .into(),
+            );
+        }
         Ok(items)
     }
 }
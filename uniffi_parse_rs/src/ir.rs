@@ -11,12 +11,12 @@ use uniffi_meta::MetadataGroup;
 
 use crate::{
     attrs::{
-        EnumAttributes, FunctionAttributes, ImplAttributes, ObjectAttributes, RecordAttributes,
-        TraitAttributes,
+        ConstantAttributes, EnumAttributes, FunctionAttributes, ImplAttributes, ObjectAttributes,
+        RecordAttributes, TraitAttributes,
     },
     macros::maybe_resolve_macro,
     paths::LookupCache,
-    CompileEnv, Enum, Error,
+    CompileEnv, Constant, Enum, Error,
     ErrorKind::*,
     Function, Impl, Item, MetadataGroupMap, Module, Object, RPath, Record, Result, Trait,
 };
@@ -231,6 +231,15 @@ impl Ir {
                     None
                 }
             }
+            syn::Item::Const(c) => {
+                if let Some(attrs) =
+                    ConstantAttributes::parse(self, cache, module_path, env, &c.attrs)?
+                {
+                    Some(Item::Const(Constant::parse(attrs, c)?))
+                } else {
+                    None
+                }
+            }
             syn::Item::Struct(st) => {
                 if let Some(attrs) =
                     RecordAttributes::parse(self, cache, module_path, env, &st.attrs)?
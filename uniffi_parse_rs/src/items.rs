@@ -7,7 +7,8 @@ use std::fmt;
 use syn::{ext::IdentExt, Ident, ItemType, LitStr, Path};
 
 use crate::{
-    CustomType, Enum, Function, Impl, Module, Object, Record, Trait, UseGlob, UseItem, Visibility,
+    Constant, CustomType, Enum, Function, Impl, Module, Object, Record, Trait, UseGlob, UseItem,
+    Visibility,
 };
 
 /// Item enum
@@ -20,6 +21,7 @@ pub enum Item {
     Enum(Enum),
     Object(Object),
     Fn(Function),
+    Const(Constant),
     Impl(Impl),
     Trait(Trait),
     Type(ItemType),
@@ -59,10 +61,19 @@ pub enum BuiltinItem {
     Int32,
     UInt64,
     Int64,
+    UInt128,
+    Int128,
     Float32,
     Float64,
     SystemTime,
     Duration,
+    OwnedBytes,
+    Uuid,
+    Url,
+    /// `chrono::DateTime`, which is only supported with the `Utc` timezone
+    ChronoDateTime,
+    ChronoNaiveDate,
+    OffsetDateTime,
     Vec,
     HashMap,
     HashSet,
@@ -70,6 +81,7 @@ pub enum BuiltinItem {
     Result,
     Arc,
     Box,
+    Stream,
     From,
     UnexpectedUniFFICallbackError,
     UniffiMacro(&'static str),
@@ -86,7 +98,9 @@ impl BuiltinItem {
                 | Self::Option
                 | Self::Arc
                 | Self::Box
+                | Self::Stream
                 | Self::Result
+                | Self::ChronoDateTime
         )
     }
 }
@@ -99,6 +113,7 @@ impl Item {
             Item::Enum(en) => Some(en.ident.unraw()),
             Item::Object(o) => Some(o.ident.unraw()),
             Item::Fn(func) => Some(func.ident.unraw()),
+            Item::Const(c) => Some(c.ident.unraw()),
             Item::Trait(tr) => Some(tr.ident.unraw()),
             Item::Type(ty) => Some(ty.ident.unraw()),
             Item::CustomType(c) => Some(c.ident.unraw()),
@@ -136,6 +151,7 @@ impl Item {
             Self::Enum(e) => e.vis,
             Self::Object(o) => o.vis,
             Self::Fn(f) => f.vis,
+            Self::Const(c) => c.vis,
             Self::Trait(t) => t.vis,
             Self::UseItem(u) => u.vis,
             Self::UseGlob(u) => u.vis,
@@ -187,6 +203,7 @@ impl fmt::Debug for Item {
                 .field(&o.ident.to_string())
                 .finish(),
             Self::Fn(func) => f.debug_tuple("Fn").field(&func.ident.to_string()).finish(),
+            Self::Const(c) => f.debug_tuple("Const").field(&c.ident.to_string()).finish(),
             Self::Trait(tr) => f
                 .debug_struct("Trait")
                 .field("name", &tr.ident.to_string())
@@ -195,7 +212,7 @@ impl fmt::Debug for Item {
                 .debug_tuple("Impl")
                 .field(&format!(
                     "<{} items>",
-                    imp.constructors.len() + imp.methods.len()
+                    imp.constructors.len() + imp.methods.len() + imp.static_methods.len()
                 ))
                 .finish(),
             Self::Type(ty) => f.debug_tuple("Type").field(&ty.ident.to_string()).finish(),
@@ -10,6 +10,7 @@ pub use uniffi_meta::MetadataGroup;
 
 mod attrs;
 mod compile_env;
+mod constants;
 mod custom_types;
 mod enums;
 mod errors;
@@ -32,6 +33,7 @@ mod types;
 mod use_;
 
 pub use compile_env::CompileEnv;
+pub use constants::Constant;
 pub use custom_types::CustomType;
 pub use enums::{Enum, Variant};
 pub use errors::{Error, ErrorContext, ErrorKind};
@@ -40,7 +42,7 @@ pub use impls::Impl;
 pub use ir::Ir;
 pub use items::{BuiltinItem, Item};
 pub use modules::Module;
-pub use objects::{Constructor, Method, Object, SelfArg};
+pub use objects::{Constructor, Method, Object, SelfArg, StaticMethod};
 pub use paths::{ChildItem, Namespace, RPath, Visibility};
 pub use public_paths::ItemNames;
 pub use records::{Field, Record};
@@ -179,6 +179,7 @@ impl Module {
     fn parse_non_module_item(&mut self, item: syn::Item) -> syn::Result<()> {
         match item {
             syn::Item::Fn(_)
+            | syn::Item::Const(_)
             | syn::Item::Struct(_)
             | syn::Item::Enum(_)
             | syn::Item::Trait(_)
@@ -223,6 +224,12 @@ impl Module {
                             .into(),
                     );
                 }
+                Item::Const(c) => {
+                    metadata.items.insert(
+                        c.const_metadata(ir, cache, module_path.append_child(item))?
+                            .into(),
+                    );
+                }
                 Item::Record(rec) => {
                     let meta = rec.record_metadata(ir, cache, module_path.append_child(item))?;
                     metadata.items.extend(
@@ -40,6 +40,15 @@ pub struct Method {
     pub return_type: ReturnType,
 }
 
+#[derive(Clone)]
+pub struct StaticMethod {
+    pub attrs: MethodAttributes,
+    pub is_async: bool,
+    pub ident: Ident,
+    pub args: Vec<Argument>,
+    pub return_type: ReturnType,
+}
+
 #[derive(Clone)]
 pub struct SelfArg {
     receiver: Receiver,
@@ -65,6 +74,7 @@ impl Object {
             module_path: names.module_path,
             name: names.name,
             orig_name: names.orig_name,
+            generic: None,
             remote: self.attrs.remote,
             docstring: self.attrs.docstring.clone(),
             imp: ObjectImpl::Struct,
@@ -165,6 +175,18 @@ impl Method {
             None => (item_name, None),
             Some(name) => (name.clone(), Some(item_name)),
         };
+        let property = self
+            .attrs
+            .property
+            .map(|kind| uniffi_meta::PropertyMetadata::from_method_name(&name, kind))
+            .transpose()
+            .map_err(|e| {
+                Error::new(
+                    module_path.file_id(),
+                    self.ident.span(),
+                    InvalidProperty(e.to_string()),
+                )
+            })?;
 
         Ok(uniffi_meta::MethodMetadata {
             module_path: module_path.path_string(),
@@ -192,6 +214,69 @@ impl Method {
             // Method checksums are not supported, we can implement an improved system by
             // checksumming the entire interface and having a single checksum
             checksum: None,
+            property,
+        })
+    }
+}
+
+impl StaticMethod {
+    pub fn parse(attrs: MethodAttributes, f: ImplItemFn) -> syn::Result<Self> {
+        Ok(Self {
+            attrs,
+            is_async: f.sig.asyncness.is_some(),
+            ident: f.sig.ident,
+            args: f
+                .sig
+                .inputs
+                .into_iter()
+                .map(Argument::parse)
+                .collect::<syn::Result<Vec<_>>>()?,
+            return_type: ReturnType::parse(f.sig.output)?,
+        })
+    }
+
+    pub fn to_static_method_metadata<'ir>(
+        &self,
+        ir: &'ir Ir,
+        cache: &mut LookupCache<'ir>,
+        module_path: &RPath<'ir>,
+        self_name: &str,
+        self_ty: &uniffi_meta::Type,
+    ) -> Result<uniffi_meta::StaticMethodMetadata> {
+        let (return_type, throws) =
+            self.return_type
+                .return_type_and_throws_for_method(ir, cache, module_path, self_ty)?;
+        let item_name = self.ident.unraw().to_string();
+        let (name, orig_name) = match &self.attrs.name {
+            None => (item_name, None),
+            Some(name) => (name.clone(), Some(item_name)),
+        };
+
+        Ok(uniffi_meta::StaticMethodMetadata {
+            module_path: module_path.path_string(),
+            self_name: self_name.to_string(),
+            name,
+            orig_name,
+            docstring: self.attrs.docstring.clone(),
+            is_async: self.is_async,
+            inputs: self
+                .args
+                .iter()
+                .map(|arg| {
+                    arg.create_method_metadata(
+                        ir,
+                        cache,
+                        module_path,
+                        &self.attrs.defaults,
+                        self_ty,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?,
+            return_type,
+            throws,
+            // Method checksums are not supported, we can implement an improved system by
+            // checksumming the entire interface and having a single checksum
+            checksum: None,
         })
     }
 }
@@ -127,13 +127,22 @@ impl<'ir> RPath<'ir> {
                     BuiltinItem::Int32 => path.push_str("i32"),
                     BuiltinItem::UInt64 => path.push_str("u64"),
                     BuiltinItem::Int64 => path.push_str("i64"),
+                    BuiltinItem::UInt128 => path.push_str("u128"),
+                    BuiltinItem::Int128 => path.push_str("i128"),
                     BuiltinItem::Float32 => path.push_str("f32"),
                     BuiltinItem::Float64 => path.push_str("f64"),
                     BuiltinItem::SystemTime => path.push_str("Timestamp"),
                     BuiltinItem::Duration => path.push_str("Duration"),
+                    BuiltinItem::OwnedBytes => path.push_str("OwnedBytes"),
+                    BuiltinItem::Uuid => path.push_str("Uuid"),
+                    BuiltinItem::Url => path.push_str("Url"),
+                    BuiltinItem::ChronoDateTime => path.push_str("DateTime"),
+                    BuiltinItem::ChronoNaiveDate => path.push_str("NaiveDate"),
+                    BuiltinItem::OffsetDateTime => path.push_str("OffsetDateTime"),
                     BuiltinItem::Vec => path.push_str("Vec"),
                     BuiltinItem::Arc => path.push_str("Arc"),
                     BuiltinItem::Box => path.push_str("Box"),
+                    BuiltinItem::Stream => path.push_str("Stream"),
                     BuiltinItem::HashMap => path.push_str("HashMap"),
                     BuiltinItem::HashSet => path.push_str("HashSet"),
                     BuiltinItem::Option => path.push_str("Option"),
@@ -617,7 +626,7 @@ impl Namespace {
                 ),
                 _ => false,
             },
-            Self::Value => matches!(item, Item::Fn(_)),
+            Self::Value => matches!(item, Item::Fn(_) | Item::Const(_)),
             Self::Macro => match item {
                 Item::Builtin(builtin) => {
                     matches!(
@@ -704,6 +713,12 @@ fn get_builtin_item(path: &Path) -> Option<&'static Item> {
         "i64" | "std::primitive::i64" | "core::primitive::i64" => {
             Some(&Item::Builtin(BuiltinItem::Int64))
         }
+        "u128" | "std::primitive::u128" | "core::primitive::u128" => {
+            Some(&Item::Builtin(BuiltinItem::UInt128))
+        }
+        "i128" | "std::primitive::i128" | "core::primitive::i128" => {
+            Some(&Item::Builtin(BuiltinItem::Int128))
+        }
         "f32" | "std::primitive::f32" | "core::primitive::f32" => {
             Some(&Item::Builtin(BuiltinItem::Float32))
         }
@@ -722,6 +737,13 @@ fn get_builtin_item(path: &Path) -> Option<&'static Item> {
         "std::sync::Arc" => Some(&Item::Builtin(BuiltinItem::Arc)),
         "std::time::SystemTime" => Some(&Item::Builtin(BuiltinItem::SystemTime)),
         "std::time::Duration" => Some(&Item::Builtin(BuiltinItem::Duration)),
+        "uniffi::Stream" => Some(&Item::Builtin(BuiltinItem::Stream)),
+        "uniffi::OwnedBytes" => Some(&Item::Builtin(BuiltinItem::OwnedBytes)),
+        "uuid::Uuid" => Some(&Item::Builtin(BuiltinItem::Uuid)),
+        "url::Url" => Some(&Item::Builtin(BuiltinItem::Url)),
+        "chrono::DateTime" => Some(&Item::Builtin(BuiltinItem::ChronoDateTime)),
+        "chrono::NaiveDate" => Some(&Item::Builtin(BuiltinItem::ChronoNaiveDate)),
+        "time::OffsetDateTime" => Some(&Item::Builtin(BuiltinItem::OffsetDateTime)),
         "uniffi::custom_type" => Some(&Item::Builtin(BuiltinItem::UniffiMacro("custom_type"))),
         "uniffi::custom_newtype" => {
             Some(&Item::Builtin(BuiltinItem::UniffiMacro("custom_newtype")))
@@ -1052,6 +1074,7 @@ pub mod tests {
                 module_path: "raw_idents".into(),
                 name: "Record".into(),
                 orig_name: None,
+                generic: None,
                 remote: false,
                 fields: vec![],
                 docstring: None,
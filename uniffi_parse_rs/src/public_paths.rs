@@ -60,7 +60,7 @@ impl<'ir> RPath<'ir> {
         let module_path = self.parent()?;
 
         let namespace = match item {
-            Item::Fn(_) => Namespace::Value,
+            Item::Fn(_) | Item::Const(_) => Namespace::Value,
             _ => Namespace::Type,
         };
 
@@ -15,7 +15,9 @@ pub struct Record {
     d: HashMap<u32, String>,
     e: HashSet<String>,
     #[uniffi(default = "test")]
-    f: CustomType
+    f: CustomType,
+    g: (String, u32),
+    h: [u8; 32],
 }
 
 mod submod {
@@ -56,6 +58,16 @@ pub enum i8Enum {
 /// Function docstring
 pub fn func(a: u8, r: Record, e: &submod::Enum, s: &str) { }
 
+/// Constant docstring
+#[uniffi::export]
+pub const MAX_RETRIES: u32 = 5;
+
+#[uniffi::export]
+pub const GREETING: &str = "hello";
+
+#[uniffi::export]
+pub const OFFSET: Option<i32> = Some(-1);
+
 /// Object docstring
 #[derive(uniffi::Object)]
 #[uniffi(name="ObjectRenamed")]
@@ -110,6 +122,21 @@ impl Object {
     pub fn meth3(self: Arc<Self>, s: String) -> Result<()> {
         todo!()
     }
+
+    /// Static method docstring
+    pub fn static_meth(a: u8) -> Result<Arc<Self>> {
+        todo!()
+    }
+
+    #[uniffi::getter]
+    pub fn get_prop(&self) -> u32 {
+        todo!()
+    }
+
+    #[uniffi::setter]
+    pub fn set_prop(&self, value: u32) {
+        todo!()
+    }
 }
 
 #[uniffi::export]
@@ -82,6 +82,7 @@ impl Trait {
                         module_path: names.module_path.clone(),
                         name: names.name.clone(),
                         orig_name: names.orig_name,
+                        generic: None,
                         docstring: self.attrs.docstring.clone(),
                         imp: uniffi_meta::ObjectImpl::Trait(kind),
                         remote: false,
@@ -50,6 +50,8 @@ pub enum Type {
     Int32,
     UInt64,
     Int64,
+    UInt128,
+    Int128,
     Float32,
     Float64,
     Boolean,
@@ -57,6 +59,11 @@ pub enum Type {
     Str,
     SystemTime,
     Duration,
+    OwnedBytes,
+    Uuid,
+    Url,
+    /// Calendar date, without a time or timezone
+    Date,
     // Types defined in the component API, each of which has a string name.
     Record {
         module_path: String,
@@ -86,6 +93,15 @@ pub enum Type {
     HashMap(Box<Type>, Box<Type>),
     HashSet(Box<Type>),
     Result(Box<Type>, Box<Type>),
+    Stream(Box<Type>),
+    Tuple(Vec<Type>),
+    /// `[T; N]`, the length must be an integer literal
+    Array(Box<Type>, u32),
+    /// `dyn Fn(A, B) -> R`, this is only valid inside a `Box` or `Arc`
+    Closure {
+        arguments: Vec<Type>,
+        return_type: Option<Box<Type>>,
+    },
     // Custom type on the scaffolding side
     Custom {
         module_path: String,
@@ -122,12 +138,18 @@ impl Type {
             Type::Int32 => Ok(uniffi_meta::Type::Int32),
             Type::UInt64 => Ok(uniffi_meta::Type::UInt64),
             Type::Int64 => Ok(uniffi_meta::Type::Int64),
+            Type::UInt128 => Ok(uniffi_meta::Type::UInt128),
+            Type::Int128 => Ok(uniffi_meta::Type::Int128),
             Type::Float32 => Ok(uniffi_meta::Type::Float32),
             Type::Float64 => Ok(uniffi_meta::Type::Float64),
             Type::Boolean => Ok(uniffi_meta::Type::Boolean),
             Type::String => Ok(uniffi_meta::Type::String),
             Type::SystemTime => Ok(uniffi_meta::Type::Timestamp),
             Type::Duration => Ok(uniffi_meta::Type::Duration),
+            Type::OwnedBytes => Ok(uniffi_meta::Type::OwnedBytes),
+            Type::Uuid => Ok(uniffi_meta::Type::Uuid),
+            Type::Url => Ok(uniffi_meta::Type::Url),
+            Type::Date => Ok(uniffi_meta::Type::Date),
             Type::Record { module_path, name } => {
                 Ok(uniffi_meta::Type::Record { module_path, name })
             }
@@ -146,12 +168,29 @@ impl Type {
             Type::HashSet(key) => Ok(uniffi_meta::Type::Set {
                 inner_type: Box::new((*key).try_into_uniffi_meta(source, span, self_ty)?),
             }),
+            Type::Stream(inner) => Ok(uniffi_meta::Type::Stream {
+                inner_type: Box::new((*inner).try_into_uniffi_meta(source, span, self_ty)?),
+            }),
+            // UniFFI supports tuples with 2-12 elements, matching the `FfiConverter` impls.
+            Type::Tuple(types) if (2..=12).contains(&types.len()) => Ok(uniffi_meta::Type::Tuple {
+                types: types
+                    .into_iter()
+                    .map(|ty| ty.try_into_uniffi_meta(source, span, self_ty))
+                    .collect::<Result<_>>()?,
+            }),
+            Type::Array(inner, len) => Ok(uniffi_meta::Type::Array {
+                inner_type: Box::new((*inner).try_into_uniffi_meta(source, span, self_ty)?),
+                len,
+            }),
             Type::Object { module_path, name } => Ok(uniffi_meta::Type::Object {
                 module_path,
                 name,
                 imp: uniffi_meta::ObjectImpl::Struct,
             }),
             Type::Arc(inner) => match *inner {
+                closure @ Type::Closure { .. } => {
+                    closure.closure_into_uniffi_meta(source, span, self_ty)
+                }
                 Type::Object { module_path, name } => Ok(uniffi_meta::Type::Object {
                     module_path,
                     name,
@@ -181,6 +220,9 @@ impl Type {
                 Type::Udl(uniffi_meta::Type::CallbackInterface { module_path, name }) => {
                     Ok(uniffi_meta::Type::CallbackInterface { module_path, name })
                 }
+                closure @ Type::Closure { .. } => {
+                    closure.closure_into_uniffi_meta(source, span, self_ty)
+                }
                 ty => Ok(uniffi_meta::Type::Box {
                     inner_type: Box::new(ty.try_into_uniffi_meta(source, span, self_ty)?),
                 }),
@@ -205,6 +247,34 @@ impl Type {
             _ => Err(Error::new(source, span, InvalidType)),
         }
     }
+
+    fn closure_into_uniffi_meta(
+        self,
+        source: FileId,
+        span: Span,
+        self_ty: Option<&uniffi_meta::Type>,
+    ) -> Result<uniffi_meta::Type> {
+        let Type::Closure {
+            arguments,
+            return_type,
+        } = self
+        else {
+            return Err(Error::new(source, span, InvalidType));
+        };
+        // This matches the number of arguments supported by the `FfiConverter` impls.
+        if arguments.len() > 6 {
+            return Err(Error::new(source, span, InvalidType));
+        }
+        Ok(uniffi_meta::Type::Closure {
+            arguments: arguments
+                .into_iter()
+                .map(|ty| ty.try_into_uniffi_meta(source, span, self_ty))
+                .collect::<Result<_>>()?,
+            return_type: return_type
+                .map(|ty| ty.try_into_uniffi_meta(source, span, self_ty).map(Box::new))
+                .transpose()?,
+        })
+    }
 }
 
 fn trait_to_uniffi_meta(
@@ -334,7 +404,8 @@ impl<'ir> RPath<'ir> {
         syn_ty: &syn::Type,
     ) -> Result<SelfType> {
         match self.resolve_type(ir, cache, syn_ty)? {
-            Type::Ref { mutable: false, ty } if ty.is_self() => Ok(SelfType {
+            // `&mut self` is only allowed for objects with a lock, the macros check that for us
+            Type::Ref { ty, .. } if ty.is_self() => Ok(SelfType {
                 takes_self_by_arc: false,
             }),
             Type::Arc(ty) if ty.is_self() => Ok(SelfType {
@@ -370,6 +441,24 @@ impl<'ir> RPath<'ir> {
                 ty: Box::new(self._resolve_type(ir, cache, &ty_ref.elem, context)?),
             }),
             syn::Type::Tuple(tuple) if tuple.elems.is_empty() => Ok(Type::Unit),
+            syn::Type::Tuple(tuple) => Ok(Type::Tuple(
+                tuple
+                    .elems
+                    .iter()
+                    .map(|elem| self._resolve_type(ir, cache, elem, context))
+                    .collect::<Result<_>>()?,
+            )),
+            syn::Type::Array(ty_array) => match &ty_array.len {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(len),
+                    ..
+                }) => Ok(Type::Array(
+                    Box::new(self._resolve_type(ir, cache, &ty_array.elem, context)?),
+                    len.base10_parse()
+                        .map_err(|_| Error::new(self.file_id(), len.span(), InvalidType))?,
+                )),
+                len => Err(Error::new(self.file_id(), len.span(), InvalidType)),
+            },
             syn::Type::Slice(ty_slice) => Ok(Type::Slice(Box::new(self._resolve_type(
                 ir,
                 cache,
@@ -447,10 +536,25 @@ impl<'ir> RPath<'ir> {
                             BuiltinItem::Int32 => Type::Int32,
                             BuiltinItem::UInt64 => Type::UInt64,
                             BuiltinItem::Int64 => Type::Int64,
+                            BuiltinItem::UInt128 => Type::UInt128,
+                            BuiltinItem::Int128 => Type::Int128,
                             BuiltinItem::Float32 => Type::Float32,
                             BuiltinItem::Float64 => Type::Float64,
                             BuiltinItem::SystemTime => Type::SystemTime,
                             BuiltinItem::Duration => Type::Duration,
+                            BuiltinItem::OwnedBytes => Type::OwnedBytes,
+                            BuiltinItem::Uuid => Type::Uuid,
+                            BuiltinItem::Url => Type::Url,
+                            BuiltinItem::ChronoNaiveDate => Type::Date,
+                            // Date-times are passed across the FFI like `SystemTime`
+                            BuiltinItem::OffsetDateTime => Type::SystemTime,
+                            BuiltinItem::ChronoDateTime => {
+                                // The timezone isn't a UniFFI type, so don't try to resolve it.
+                                // Only `DateTime<Utc>` implements `FfiConverter`, so other
+                                // timezones will fail when the scaffolding is compiled.
+                                generics.get_args_and_check_len(self.file_id(), 1)?;
+                                Type::SystemTime
+                            }
                             BuiltinItem::UnexpectedUniFFICallbackError => {
                                 Type::UnexpectedUniFFICallbackError
                             }
@@ -478,6 +582,10 @@ impl<'ir> RPath<'ir> {
                                 let key = generics.resolve1(ir, cache, self)?;
                                 Type::HashSet(Box::new(key))
                             }
+                            BuiltinItem::Stream => {
+                                let inner = generics.resolve1(ir, cache, self)?;
+                                Type::Stream(Box::new(inner))
+                            }
                             BuiltinItem::Result => {
                                 let (ok, err) = generics.resolve2(ir, cache, self)?;
                                 Type::Result(Box::new(ok), Box::new(err))
@@ -503,6 +611,9 @@ impl<'ir> RPath<'ir> {
                 }
             }
             syn::Type::TraitObject(ty_trait) => {
+                if let Some(closure) = self.resolve_closure_type(ir, cache, ty_trait, context)? {
+                    return Ok(closure);
+                }
                 let trait_bounds = ty_trait
                     .bounds
                     .iter()
@@ -561,6 +672,50 @@ impl<'ir> RPath<'ir> {
             _ => Err(Error::new(self.file_id(), ty.span(), InvalidType)),
         }
     }
+
+    /// Resolve `dyn Fn(A, B) -> R + Send + Sync`
+    ///
+    /// Returns `None` if the trait object doesn't have an `Fn` bound.
+    fn resolve_closure_type(
+        &self,
+        ir: &'ir Ir,
+        cache: &mut LookupCache<'ir>,
+        ty_trait: &syn::TypeTraitObject,
+        context: &mut ResolveTypeContext,
+    ) -> Result<Option<Type>> {
+        let fn_args = ty_trait
+            .bounds
+            .iter()
+            .find_map(|param_bound| match param_bound {
+                TypeParamBound::Trait(trait_bound) => {
+                    let segment = trait_bound.path.segments.last()?;
+                    match &segment.arguments {
+                        PathArguments::Parenthesized(args) if segment.ident == "Fn" => Some(args),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            });
+        let Some(fn_args) = fn_args else {
+            return Ok(None);
+        };
+        let arguments = fn_args
+            .inputs
+            .iter()
+            .map(|ty| self._resolve_type(ir, cache, ty, context))
+            .collect::<Result<_>>()?;
+        let return_type = match &fn_args.output {
+            syn::ReturnType::Default => None,
+            syn::ReturnType::Type(_, ty) => match self._resolve_type(ir, cache, ty, context)? {
+                Type::Unit => None,
+                ty => Some(Box::new(ty)),
+            },
+        };
+        Ok(Some(Type::Closure {
+            arguments,
+            return_type,
+        }))
+    }
 }
 
 /// Context for `resolve_type` this tracks data needed for recursive calls via type aliases
@@ -862,6 +1017,14 @@ pub mod tests {
             run_resolve_type(&ir, &mut cache, "types", "u64"),
             Ok(Type::UInt64)
         );
+        assert_eq!(
+            run_resolve_type(&ir, &mut cache, "types", "i128"),
+            Ok(Type::Int128)
+        );
+        assert_eq!(
+            run_resolve_type(&ir, &mut cache, "types", "u128"),
+            Ok(Type::UInt128)
+        );
         assert_eq!(
             run_resolve_type(&ir, &mut cache, "types", "f32"),
             Ok(Type::Float32)
@@ -882,6 +1045,30 @@ pub mod tests {
             run_resolve_type(&ir, &mut cache, "types", "std::time::Duration"),
             Ok(Type::Duration)
         );
+        assert_eq!(
+            run_resolve_type(&ir, &mut cache, "types", "uniffi::OwnedBytes"),
+            Ok(Type::OwnedBytes)
+        );
+        assert_eq!(
+            run_resolve_type(&ir, &mut cache, "types", "uuid::Uuid"),
+            Ok(Type::Uuid)
+        );
+        assert_eq!(
+            run_resolve_type(&ir, &mut cache, "types", "url::Url"),
+            Ok(Type::Url)
+        );
+        assert_eq!(
+            run_resolve_type(&ir, &mut cache, "types", "chrono::NaiveDate"),
+            Ok(Type::Date)
+        );
+        assert_eq!(
+            run_resolve_type(&ir, &mut cache, "types", "chrono::DateTime<chrono::Utc>"),
+            Ok(Type::SystemTime)
+        );
+        assert_eq!(
+            run_resolve_type(&ir, &mut cache, "types", "time::OffsetDateTime"),
+            Ok(Type::SystemTime)
+        );
         // Builtin type using an alias
         assert_eq!(
             run_resolve_type(&ir, &mut cache, "types", "RenamedU64"),
@@ -1034,6 +1221,45 @@ pub mod tests {
             run_resolve_type(&ir, &mut cache, "types", "std::collections::HashSet<u32>"),
             Ok(Type::HashSet(Box::new(Type::UInt32)))
         );
+        assert_eq!(
+            run_resolve_type(&ir, &mut cache, "types", "uniffi::Stream<u32>"),
+            Ok(Type::Stream(Box::new(Type::UInt32)))
+        );
+        assert_eq!(
+            run_resolve_type(&ir, &mut cache, "types", "(String, Vec<u32>)"),
+            Ok(Type::Tuple(vec![
+                Type::String,
+                Type::Vec(Box::new(Type::UInt32))
+            ]))
+        );
+        assert_eq!(
+            run_resolve_type(
+                &ir,
+                &mut cache,
+                "types",
+                "Box<dyn Fn(u32, String) -> Vec<u32> + Send + Sync>"
+            ),
+            Ok(Type::Box(Box::new(Type::Closure {
+                arguments: vec![Type::UInt32, Type::String],
+                return_type: Some(Box::new(Type::Vec(Box::new(Type::UInt32)))),
+            })))
+        );
+        assert_eq!(
+            run_resolve_type(
+                &ir,
+                &mut cache,
+                "types",
+                "std::sync::Arc<dyn Fn() + Send + Sync>"
+            ),
+            Ok(Type::Arc(Box::new(Type::Closure {
+                arguments: vec![],
+                return_type: None,
+            })))
+        );
+        assert_eq!(
+            run_resolve_type(&ir, &mut cache, "types", "[u8; 32]"),
+            Ok(Type::Array(Box::new(Type::UInt8), 32))
+        );
         // Resolution edge case, the generic type is in a different module than its arguments
         assert_eq!(
             run_resolve_type(
@@ -1413,6 +1639,7 @@ pub mod tests {
                     module_path: "udl_types".into(),
                     name: "UdlRecord".into(),
                     orig_name: None,
+                    generic: None,
                     remote: false,
                     fields: vec![],
                     docstring: None,
@@ -1422,6 +1649,7 @@ pub mod tests {
                     module_path: "udl_types".into(),
                     name: "UdlObject".into(),
                     orig_name: None,
+                    generic: None,
                     remote: false,
                     docstring: None,
                     imp: uniffi_meta::ObjectImpl::Struct,
@@ -1431,6 +1659,7 @@ pub mod tests {
                     module_path: "udl_types".into(),
                     name: "UdlTrait".into(),
                     orig_name: None,
+                    generic: None,
                     remote: false,
                     docstring: None,
                     imp: uniffi_meta::ObjectImpl::Trait(TraitKind::RustOnly),
@@ -1711,6 +1940,7 @@ pub mod tests {
                 module_path: "raw_idents".into(),
                 name: "Record".into(),
                 orig_name: None,
+                generic: None,
                 remote: false,
                 fields: vec![],
                 docstring: None,
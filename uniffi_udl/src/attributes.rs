@@ -15,7 +15,7 @@
 //! if we grow significantly more complicated attribute handling.
 
 use anyhow::{bail, Result};
-use uniffi_meta::{Checksum, ObjectImpl, PassBy, TraitKind};
+use uniffi_meta::{Checksum, ObjectImpl, PassBy, PropertyKind, TraitKind};
 
 /// Represents an attribute parsed from UDL, like `[ByRef]` or `[Throws]`.
 ///
@@ -43,6 +43,9 @@ pub(super) enum Attribute {
     WithForeign,
     Async,
     NonExhaustive,
+    // Methods exposed as property accessors
+    Getter,
+    Setter,
 }
 
 impl Attribute {
@@ -74,6 +77,8 @@ impl TryFrom<&weedle::attribute::ExtendedAttribute<'_>> for Attribute {
                 "Async" => Ok(Attribute::Async),
                 "NonExhaustive" => Ok(Attribute::NonExhaustive),
                 "Remote" => Ok(Attribute::Remote),
+                "Getter" => Ok(Attribute::Getter),
+                "Setter" => Ok(Attribute::Setter),
                 _ => anyhow::bail!("ExtendedAttributeNoArgs not supported: {:?}", (attr.0).0),
             },
             // Matches assignment-style attributes like ["Throws=Error"]
@@ -540,6 +545,14 @@ impl MethodAttributes {
             .iter()
             .any(|attr| matches!(attr, Attribute::SelfType(SelfType::ByArc)))
     }
+
+    pub(super) fn get_property_kind(&self) -> Option<PropertyKind> {
+        self.0.iter().find_map(|attr| match attr {
+            Attribute::Getter => Some(PropertyKind::Getter),
+            Attribute::Setter => Some(PropertyKind::Setter),
+            _ => None,
+        })
+    }
 }
 
 impl FromIterator<Attribute> for MethodAttributes {
@@ -554,9 +567,18 @@ impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for MethodAttributes
         weedle_attributes: &weedle::attribute::ExtendedAttributeList<'_>,
     ) -> Result<Self, Self::Error> {
         let attrs = parse_attributes(weedle_attributes, |attr| match attr {
-            Attribute::SelfType(_) | Attribute::Throws(_) | Attribute::Async => Ok(()),
+            Attribute::SelfType(_)
+            | Attribute::Throws(_)
+            | Attribute::Async
+            | Attribute::Getter
+            | Attribute::Setter => Ok(()),
             _ => bail!(format!("{attr:?} not supported for methods")),
         })?;
+        if attrs.iter().any(|a| matches!(a, Attribute::Getter))
+            && attrs.iter().any(|a| matches!(a, Attribute::Setter))
+        {
+            bail!("a method can't be both a getter and a setter");
+        }
         Ok(Self(attrs))
     }
 }
@@ -826,6 +848,29 @@ mod test {
         assert!(attrs.get_self_by_arc());
         assert!(attrs.get_throws_err().is_none());
         assert!(!attrs.is_async());
+        assert!(attrs.get_property_kind().is_none());
+
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[Getter]").unwrap();
+        let attrs = MethodAttributes::try_from(&node).unwrap();
+        assert!(matches!(
+            attrs.get_property_kind(),
+            Some(PropertyKind::Getter)
+        ));
+
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[Setter]").unwrap();
+        let attrs = MethodAttributes::try_from(&node).unwrap();
+        assert!(matches!(
+            attrs.get_property_kind(),
+            Some(PropertyKind::Setter)
+        ));
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Getter, Setter]").unwrap();
+        let err = MethodAttributes::try_from(&node).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "a method can't be both a getter and a setter"
+        );
     }
 
     #[test]
@@ -212,8 +212,8 @@ impl APIBuilder for weedle::NamespaceDefinition<'_> {
             bail!("duplicate namespace definition");
         }
         ci.types.namespace_docstring = self.docstring.as_ref().map(|v| convert_docstring(&v.0));
-        for func in self.members.body.convert(ci)? {
-            ci.add_definition(func.into())?;
+        for item in self.members.body.convert(ci)? {
+            ci.add_definition(item)?;
         }
         Ok(())
     }
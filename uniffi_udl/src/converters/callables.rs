@@ -11,8 +11,8 @@ use crate::InterfaceCollector;
 use anyhow::{bail, Result};
 
 use uniffi_meta::{
-    ConstructorMetadata, DefaultValueMetadata, FieldMetadata, FnMetadata, FnParamMetadata,
-    MethodMetadata, TraitMethodMetadata,
+    ConstantMetadata, ConstructorMetadata, DefaultValueMetadata, FieldMetadata, FnMetadata,
+    FnParamMetadata, Metadata, MethodMetadata, PropertyMetadata, TraitMethodMetadata,
 };
 
 /// Zero-copy `[ByMutRef] bytes` is unsound across an async boundary: Rust may
@@ -84,10 +84,15 @@ impl APIConverter<FnParamMetadata> for weedle::argument::SingleArgument<'_> {
     }
 }
 
-impl APIConverter<FnMetadata> for weedle::namespace::NamespaceMember<'_> {
-    fn convert(&self, ci: &mut InterfaceCollector) -> Result<FnMetadata> {
+impl APIConverter<Metadata> for weedle::namespace::NamespaceMember<'_> {
+    fn convert(&self, ci: &mut InterfaceCollector) -> Result<Metadata> {
         match self {
-            weedle::namespace::NamespaceMember::Operation(f) => f.convert(ci),
+            weedle::namespace::NamespaceMember::Operation(f) => {
+                APIConverter::<FnMetadata>::convert(f, ci).map(Into::into)
+            }
+            weedle::namespace::NamespaceMember::Const(c) => {
+                APIConverter::<ConstantMetadata>::convert(c, ci).map(Into::into)
+            }
             _ => bail!("no support for namespace member type {:?} yet", self),
         }
     }
@@ -184,6 +189,10 @@ impl APIConverter<MethodMetadata> for weedle::interface::OperationInterfaceMembe
                 name
             }
         };
+        let property = attributes
+            .get_property_kind()
+            .map(|kind| PropertyMetadata::from_method_name(&name, kind))
+            .transpose()?;
         let inputs = self.args.body.list.convert(ci)?;
         reject_async_by_mut_ref(is_async, &inputs)?;
         Ok(MethodMetadata {
@@ -199,6 +208,7 @@ impl APIConverter<MethodMetadata> for weedle::interface::OperationInterfaceMembe
             takes_self_by_arc,
             checksum: None,
             docstring: self.docstring.as_ref().map(|v| convert_docstring(&v.0)),
+            property,
         })
     }
 }
@@ -214,6 +224,9 @@ impl APIConverter<TraitMethodMetadata> for weedle::interface::OperationInterface
         let return_type = ci.resolve_return_type_expression(&self.return_type)?;
         let attributes = MethodAttributes::try_from(self.attributes.as_ref())?;
         let is_async = attributes.is_async();
+        if attributes.get_property_kind().is_some() {
+            bail!("getters and setters are not supported on trait interfaces");
+        }
 
         let throws = match attributes.get_throws_err() {
             Some(name) => match ci.get_type(name) {
@@ -72,6 +72,7 @@ impl APIConverter<ObjectMetadata> for weedle::InterfaceDefinition<'_> {
                 takes_self_by_arc: false,
                 checksum: None,
                 docstring: None,
+                property: None,
             })
         };
         // Trait methods are in the Metadata.
@@ -150,6 +151,7 @@ impl APIConverter<ObjectMetadata> for weedle::InterfaceDefinition<'_> {
             module_path: ci.module_path(),
             name: object_name.to_string(),
             orig_name: None,
+            generic: None,
             remote: attributes.contains_remote(),
             imp: object_impl,
             docstring: self.docstring.as_ref().map(|v| convert_docstring(&v.0)),
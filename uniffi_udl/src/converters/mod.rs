@@ -6,9 +6,9 @@ use crate::{attributes::DictionaryAttributes, literal::convert_default_value, In
 use anyhow::{bail, Result};
 
 use uniffi_meta::{
-    CallbackInterfaceMetadata, DefaultValueMetadata, FieldMetadata, FnParamMetadata,
-    MethodMetadata, PassBy, RecordMetadata, TraitMethodMetadata, Type, UniffiTraitMetadata,
-    VariantMetadata,
+    CallbackInterfaceMetadata, ConstantMetadata, DefaultValueMetadata, FieldMetadata,
+    FnParamMetadata, MethodMetadata, PassBy, RecordMetadata, TraitMethodMetadata, Type,
+    UniffiTraitMetadata, VariantMetadata,
 };
 
 mod callables;
@@ -115,6 +115,7 @@ impl APIConverter<RecordMetadata> for weedle::DictionaryDefinition<'_> {
             module_path: ci.module_path(),
             name: self.identifier.0.to_string(),
             orig_name: None,
+            generic: None,
             remote: attributes.contains_remote(),
             fields: self.members.body.convert(ci)?,
             docstring: self.docstring.as_ref().map(|v| convert_docstring(&v.0)),
@@ -144,6 +145,22 @@ impl APIConverter<FieldMetadata> for weedle::dictionary::DictionaryMember<'_> {
     }
 }
 
+impl APIConverter<ConstantMetadata> for weedle::namespace::ConstNamespaceMember<'_> {
+    fn convert(&self, ci: &mut InterfaceCollector) -> Result<ConstantMetadata> {
+        if self.attributes.is_some() {
+            bail!("constant attributes are not supported yet");
+        }
+        let ty = ci.resolve_type_expression(&self.type_)?;
+        Ok(ConstantMetadata {
+            module_path: ci.module_path(),
+            name: self.identifier.0.to_string(),
+            value: convert_default_value(&self.value, &ty)?,
+            ty,
+            docstring: self.docstring.as_ref().map(|v| convert_docstring(&v.0)),
+        })
+    }
+}
+
 impl APIConverter<CallbackInterfaceMetadata> for weedle::CallbackInterfaceDefinition<'_> {
     fn convert(&self, ci: &mut InterfaceCollector) -> Result<CallbackInterfaceMetadata> {
         if self.attributes.is_some() {
@@ -205,6 +222,7 @@ fn make_uniffi_traits(
             takes_self_by_arc: false,
             checksum: None,
             docstring: None,
+            property: None,
         })
     };
 
@@ -333,4 +351,60 @@ mod test {
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn test_constants() {
+        const UDL: &str = r#"
+            namespace test{
+                /// The maximum number of retries
+                const u32 MAX_RETRIES = 0x10;
+                const string? GREETING = "hello";
+            };
+        "#;
+        let mut ci = InterfaceCollector::from_webidl(UDL, "crate-name").unwrap();
+        assert_eq!(ci.items.len(), 2);
+        match &ci.items.pop_first().unwrap() {
+            Metadata::Constant(constant) => {
+                assert_eq!(constant.name, "GREETING");
+                assert_eq!(
+                    constant.ty,
+                    Type::Optional {
+                        inner_type: Box::new(Type::String)
+                    }
+                );
+                assert!(matches!(
+                    &constant.value,
+                    LiteralMetadata::Some { inner } if **inner == DefaultValueMetadata::Literal(
+                        LiteralMetadata::String("hello".to_string())
+                    )
+                ));
+            }
+            _ => unreachable!(),
+        }
+        match &ci.items.pop_first().unwrap() {
+            Metadata::Constant(constant) => {
+                assert_eq!(constant.name, "MAX_RETRIES");
+                assert_eq!(constant.ty, Type::UInt32);
+                assert_eq!(
+                    constant.value,
+                    LiteralMetadata::UInt(16, Radix::Hexadecimal, Type::UInt32)
+                );
+                assert_eq!(
+                    constant.docstring.as_deref(),
+                    Some("The maximum number of retries")
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_constant_type_mismatch() {
+        const UDL: &str = r#"
+            namespace test{
+                const u32 MAX_RETRIES = "five";
+            };
+        "#;
+        assert!(InterfaceCollector::from_webidl(UDL, "crate-name").is_err());
+    }
 }
@@ -205,6 +205,8 @@ pub(crate) fn resolve_builtin_type(name: &str) -> Option<Type> {
         "i32" => Some(Type::Int32),
         "u64" => Some(Type::UInt64),
         "i64" => Some(Type::Int64),
+        "u128" => Some(Type::UInt128),
+        "i128" => Some(Type::Int128),
         "f32" => Some(Type::Float32),
         "f64" => Some(Type::Float64),
         "timestamp" => Some(Type::Timestamp),
@@ -1,6 +1,7 @@
 use crate::argument::ArgumentList;
 use crate::attribute::ExtendedAttributeList;
 use crate::common::{Docstring, Identifier, Parenthesized};
+use crate::literal::DefaultValue;
 use crate::types::{AttributedType, ReturnType};
 
 /// Parses namespace members declaration
@@ -9,6 +10,19 @@ pub type NamespaceMembers<'a> = Vec<NamespaceMember<'a>>;
 ast_types! {
     /// Parses namespace member declaration
     enum NamespaceMember<'a> {
+        /// Parses `[attributes]? const attributedtype identifier = value;`
+        ///
+        /// Unlike WebIDL interface constants, this allows any type and default value
+        Const(struct ConstNamespaceMember<'a> {
+            docstring: Option<Docstring>,
+            attributes: Option<ExtendedAttributeList<'a>>,
+            const_: term!(const),
+            type_: AttributedType<'a>,
+            identifier: Identifier<'a>,
+            assign: term!(=),
+            value: DefaultValue<'a>,
+            semi_colon: term!(;),
+        }),
         /// Parses `[attributes]? returntype identifier? (( args ));`
         ///
         /// (( )) means ( ) chars
@@ -45,6 +59,13 @@ mod test {
         identifier.0 == "name";
     });
 
+    test!(should_parse_const_namespace_member { "const u32 MAX_RETRIES = 5;" =>
+        "";
+        ConstNamespaceMember;
+        attributes.is_none();
+        identifier.0 == "MAX_RETRIES";
+    });
+
     test!(should_parse_operation_namespace_member { "short (long a, long b);" =>
         "";
         OperationNamespaceMember;